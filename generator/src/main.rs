@@ -35,8 +35,10 @@ fn main() {
     parser.parse();
     let mut sys_out = File::create(manifest_dir.join("../sys/src/generated.rs")).unwrap();
     let mut hl_out = File::create(manifest_dir.join("../openxr/src/generated.rs")).unwrap();
+    let mut trace_out = File::create(manifest_dir.join("../openxr/src/ffi_trace.rs")).unwrap();
     write!(sys_out, "{}", parser.generate_sys()).unwrap();
     write!(hl_out, "{}", parser.generate_hl()).unwrap();
+    write!(trace_out, "{}", parser.generate_trace()).unwrap();
 }
 
 struct Parser {
@@ -550,7 +552,9 @@ impl Parser {
         let struct_name = attr(attrs, "name").unwrap();
         let mut members = Vec::new();
         let mut ty = None;
-        let mut mut_next = false;
+        // Some returned-only structs are declared with a `const` next pointer, but are written by
+        // the runtime all the same
+        let mut mut_next = attr(attrs, "returnedonly") == Some("true");
         loop {
             use XmlEvent::*;
             match self.reader.next().expect("failed to parse XML") {
@@ -922,6 +926,12 @@ impl Parser {
                     Self::#ident => Some(#name)
                 })
             });
+            // Values added by hand in sys/src/manual.rs are named there
+            let unknown_name = match &name[..] {
+                "XrStructureType" => quote! { crate::manual::structure_type_name(*self) },
+                "XrResult" => quote! { crate::manual::result_name(*self) },
+                _ => quote! { None },
+            };
             let result_extras = if name == "XrResult" {
                 let cases = e.values.iter().map(|v| {
                     let ident = xr_enum_value_name(name, &v.name);
@@ -931,7 +941,7 @@ impl Parser {
                             let mut reason = x.to_string();
                             reason.get_mut(0..1).unwrap().make_ascii_lowercase();
                             reason = reason.trim_end_matches('.').to_string();
-                            reason
+                            format!("{} ({})", reason, ident)
                         },
                     );
                     quote! {
@@ -945,10 +955,9 @@ impl Parser {
                                 #(#cases,)*
                                 _ => None,
                             };
-                            if let Some(reason) = reason {
-                                fmt.pad(reason)
-                            } else {
-                                write!(fmt, "unknown error (code {})", self.0)
+                            match reason {
+                                Some(reason) => fmt.pad(reason),
+                                None => crate::manual::fmt_result(*self, fmt),
                             }
                         }
                     }
@@ -960,19 +969,19 @@ impl Parser {
             quote! {
                 #[doc = #doc]
                 #[repr(transparent)]
-                #[derive(Copy, Clone, Eq, PartialEq)]
+                #[derive(Copy, Clone, Eq, PartialEq, Hash)]
                 pub struct #ident(i32);
                 impl #ident {
                     #(#values)*
 
-                    pub fn from_raw(x: i32) -> Self { Self(x) }
+                    pub const fn from_raw(x: i32) -> Self { Self(x) }
                     pub fn into_raw(self) -> i32 { self.0 }
                 }
                 impl fmt::Debug for #ident {
                     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
                         let name = match *self {
                             #(#debug_cases,)*
-                            _ => None,
+                            _ => #unknown_name,
                         };
                         fmt_enum(fmt, self.0, name)
                     }
@@ -1018,7 +1027,7 @@ impl Parser {
             quote! {
                 #[doc = #doc]
                 #[repr(transparent)]
-                #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+                #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
                 pub struct #ident(u64);
                 impl #ident {
                     #(#values)*
@@ -1239,6 +1248,124 @@ impl Parser {
         }
     }
 
+    /// Generate logging wrappers for the `ffi-trace` feature of the high-level crate
+    fn generate_trace(&self) -> TokenStream {
+        let commands = self
+            .commands
+            .iter()
+            .chain(
+                self.cmd_aliases
+                    .iter()
+                    .map(|(name, target)| (name, self.commands.get(target).unwrap())),
+            )
+            .filter(|(_, command)| match command.extension {
+                Some(ref ext) => !self.disabled_exts.contains(ext),
+                None => true,
+            });
+
+        let (wrappers, arms) = commands
+            .map(|(name, command)| {
+                let ident = xr_command_name(name);
+                let fn_ident = Ident::new(&ident.to_string().to_snake_case(), Span::call_site());
+                let static_ident = Ident::new(
+                    &ident.to_string().to_shouty_snake_case(),
+                    Span::call_site(),
+                );
+                let param_idents = command
+                    .params
+                    .iter()
+                    .map(|param| xr_var_name(&param.name))
+                    .collect::<Vec<_>>();
+                let params = command.params.iter().map(|param| {
+                    let ident = xr_var_name(&param.name);
+                    let ty = xr_arg_ty(self.api_aliases.as_ref(), param);
+                    quote! { #ident: #ty }
+                });
+                let format = format!(
+                    "{}({}) -> {{:?}}",
+                    name,
+                    param_idents
+                        .iter()
+                        .map(|x| format!("{}: {{:?}}", x))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let c_name = c_name(name);
+                let conditions = conditions(name, command.extension.as_ref().map(|x| &x[..]));
+                let wrapper = quote! {
+                    #conditions
+                    static #static_ident: AtomicUsize = AtomicUsize::new(0);
+                    #conditions
+                    unsafe extern "system" fn #fn_ident(#(#params),*) -> Result {
+                        let real = mem::transmute::<usize, pfn::#ident>(#static_ident.load(Ordering::Relaxed));
+                        let result = real(#(#param_idents),*);
+                        tracing::trace!(target: "openxr::ffi", #format, #(#param_idents,)* result);
+                        result
+                    }
+                };
+                let arm = quote! {
+                    #conditions
+                    #c_name => substitute(
+                        &#static_ident,
+                        f,
+                        mem::transmute::<pfn::#ident, pfn::VoidFunction>(#fn_ident),
+                    ),
+                };
+                (wrapper, arm)
+            })
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        quote! {
+            //! Automatically generated code; do not edit!
+
+            #![allow(unused, non_snake_case, clippy::too_many_arguments)]
+            use std::ffi::CStr;
+            use std::mem;
+            use std::os::raw::{c_char, c_void};
+            use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+            use libc::{timespec, wchar_t};
+            use sys::platform::*;
+            use sys::*;
+
+            // Mirrors the private alias in openxr-sys
+            type DevicePcmSampleRateGetInfoFB = DevicePcmSampleRateStateFB;
+
+            /// Substitute a wrapper that logs each call to `f`, the implementation of the function `name`
+            ///
+            /// Functions without a wrapper, and wrappers themselves, are returned unchanged. Each wrapper
+            /// forwards to a single process-wide implementation: the first one it was substituted for.
+            /// Any other implementation of the same function, e.g. one loaded from a second runtime, is
+            /// returned unchanged and its calls aren't logged, with a warning the first time that happens.
+            pub unsafe fn wrap(name: &CStr, f: pfn::VoidFunction) -> pfn::VoidFunction {
+                match name.to_bytes_with_nul() {
+                    #(#arms)*
+                    _ => f,
+                }
+            }
+
+            /// Bind `wrapper` to `f` through `real`, unless it's already bound to something else
+            fn substitute(real: &AtomicUsize, f: pfn::VoidFunction, wrapper: pfn::VoidFunction) -> pfn::VoidFunction {
+                if f as usize == wrapper as usize {
+                    return wrapper;
+                }
+                match real.compare_exchange(0, f as usize, Ordering::Relaxed, Ordering::Relaxed) {
+                    Ok(_) => wrapper,
+                    Err(x) if x == f as usize => wrapper,
+                    Err(_) => {
+                        static WARNED: AtomicBool = AtomicBool::new(false);
+                        if !WARNED.swap(true, Ordering::Relaxed) {
+                            tracing::warn!(target: "openxr::ffi", "a second OpenXR runtime was loaded; its calls will not be traced");
+                        }
+                        f
+                    }
+                }
+            }
+
+            #(#wrappers)*
+        }
+    }
+
     /// Generate high-level code
     #[allow(clippy::cognitive_complexity)] // TODO
     fn generate_hl(&self) -> TokenStream {
@@ -1264,6 +1391,8 @@ impl Parser {
         let mut ext_set_names = Vec::new();
         let mut ext_set_fields = Vec::new();
         let mut ext_set_inits = Vec::new();
+        let mut ext_version_fields = Vec::new();
+        let mut ext_version_inits = Vec::new();
         for (tag_name, tag) in &self.extensions {
             for ext in &tag.extensions {
                 if self.disabled_exts.contains(&ext.name) {
@@ -1305,6 +1434,8 @@ impl Parser {
                 let conds5 = conds.clone();
                 let conds6 = conds.clone();
                 let conds7 = conds.clone();
+                let conds8 = conds.clone();
+                let conds9 = conds.clone();
                 let load = if ext.commands.is_empty() {
                     quote! {}
                 } else {
@@ -1363,6 +1494,14 @@ impl Parser {
                     #conds7
                     pub #field_ident: bool,
                 });
+                ext_version_fields.push(quote! {
+                    #conds8
+                    pub #field_ident: Option<u32>,
+                });
+                ext_version_inits.push(quote! {
+                    #conds9
+                    raw::#ty_ident::NAME if required.#field_ident => { out.#field_ident = Some(ext.extension_version); }
+                });
             }
         }
 
@@ -1399,6 +1538,7 @@ impl Parser {
         let mut event_cases = Vec::new();
         let mut event_decodes = Vec::new();
         let mut event_readers = Vec::new();
+        let mut event_serializes = Vec::new();
         for (raw_name, evt) in self.structs.iter().filter(|(name, _)| {
             name.starts_with("XrEventData")
                 && !name.ends_with("BaseHeader")
@@ -1428,8 +1568,19 @@ impl Parser {
                     }
                 }
             });
+            let index = event_serializes.len() as u32;
+            event_serializes.push(if evt.members.len() <= 2 {
+                quote! {
+                    Event::#ident => serializer.serialize_unit_variant("Event", #index, #name),
+                }
+            } else {
+                quote! {
+                    Event::#ident(ref e) => serializer.serialize_newtype_variant("Event", #index, #name, e),
+                }
+            });
             event_readers.push(self.generate_reader(&ident, &raw_ident, evt));
         }
+        let raw_index = event_serializes.len() as u32;
 
         let mut struct_meta = HashMap::<&str, StructMeta>::new();
         for (name, s) in &self.structs {
@@ -1471,6 +1622,7 @@ impl Parser {
 
             #![allow(clippy::wrong_self_convention, clippy::transmute_ptr_to_ptr)]
             use std::borrow::Cow;
+            use std::fmt;
             use std::mem::MaybeUninit;
             pub use sys::{#(#reexports),*};
             pub use sys::platform::{EGLenum, VkFilter, VkSamplerMipmapMode, VkSamplerAddressMode, VkComponentSwizzle};
@@ -1519,6 +1671,8 @@ impl Parser {
             #[derive(Default, Copy, Clone)]
             pub struct InstanceExtensions {
                 #(#ext_fields)*
+                /// Revisions of the enabled extensions
+                pub versions: ExtensionVersions,
             }
 
             impl InstanceExtensions {
@@ -1530,20 +1684,52 @@ impl Parser {
                 pub unsafe fn load(entry: &Entry, instance: sys::Instance, required: &ExtensionSet) -> Result<Self> {
                     Ok(Self {
                         #(#ext_field_inits)*
+                        // Versions are informational; don't fail instance creation over them
+                        versions: entry
+                            .enumerate_extension_properties()
+                            .map(|x| ExtensionVersions::from_properties(&x, required))
+                            .unwrap_or_default(),
                     })
                 }
             }
 
-            #[derive(Copy, Clone)]
+            /// Spec versions reported by the runtime for enabled extensions
+            ///
+            /// Fields are `None` for extensions that weren't enabled, or that the runtime itself doesn't
+            /// advertise (e.g. because they're provided by an API layer). All fields are `None` if the
+            /// runtime's extensions couldn't be enumerated.
+            #[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+            #[non_exhaustive]
+            pub struct ExtensionVersions {
+                #(#ext_version_fields)*
+            }
+
+            impl ExtensionVersions {
+                pub(crate) fn from_properties(properties: &[sys::ExtensionProperties], required: &ExtensionSet) -> Self {
+                    let mut out = Self::default();
+                    for ext in properties {
+                        match crate::fixed_str_bytes(&ext.extension_name) {
+                            #(#ext_version_inits)*
+                            _ => {}
+                        }
+                    }
+                    out
+                }
+            }
+
+            #[derive(Copy, Clone, Debug)]
             #[non_exhaustive]
             pub enum Event<'a> {
                 #(#event_cases,)*
+                /// An event of a type these bindings don't decode, e.g. from an unknown extension
+                Raw(RawEvent<'a>),
             }
 
             impl<'a> Event<'a> {
                 /// Decode an event
                 ///
-                /// Returns `None` if the event type is not recognized, e.g. if it's from an unknown extension
+                /// Events of unrecognized types, e.g. from an unknown extension, decode as `Event::Raw`,
+                /// so this currently never returns `None`.
                 ///
                 /// # Safety
                 ///
@@ -1553,11 +1739,22 @@ impl Parser {
                     let raw = raw.as_ptr();
                     Some(match (raw as *const sys::BaseInStructure).read().ty {
                         #(#event_decodes)*
-                        _ => { return None; }
+                        _ => Event::Raw(RawEvent::new(&*raw)),
                     })
                 }
             }
 
+            /// Serialized as externally tagged, e.g. `{"SessionStateChanged": {"session": 1, ...}}`
+            #[cfg(feature = "serde")]
+            impl serde::Serialize for Event<'_> {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                    match *self {
+                        #(#event_serializes)*
+                        Event::Raw(ref e) => serializer.serialize_newtype_variant("Event", #raw_index, "Raw", e),
+                    }
+                }
+            }
+
             #(#event_readers)*
 
             pub mod raw {
@@ -1771,9 +1968,15 @@ impl Parser {
                         if m.ty != "char" {
                             return None;
                         }
+                        // Localized text may be clamped, but identifiers must be used verbatim
+                        let place = if m.name.starts_with("localized") {
+                            quote! { place_cstr_truncated }
+                        } else {
+                            quote! { place_cstr }
+                        };
                         (
                             quote! { &str },
-                            quote! { place_cstr(&mut self.inner.#ident, value); },
+                            quote! { #place(&mut self.inner.#ident, value); },
                         )
                     } else if let Some(ref len) = m.len {
                         let mut inner = m.clone();
@@ -1962,11 +2165,42 @@ impl Parser {
             })
         });
 
+        let field_idents = s
+            .members
+            .iter()
+            .filter(|m| m.name != "type" && m.name != "next" && !lens.contains(&m.name[..]))
+            .map(|m| xr_var_name(&m.name))
+            .collect::<Vec<_>>();
+        let field_names = field_idents
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+        let field_count = field_idents.len();
+
         let sys_raw_ident_str = format!("[sys::{}]", raw_ident);
+        let ident_str = ident.to_string();
         quote! {
             #[derive(Copy, Clone)]
             pub struct #ident<'a>(&'a sys::#raw_ident);
 
+            impl fmt::Debug for #ident<'_> {
+                fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt.debug_struct(#ident_str)
+                        #(.field(#field_names, &event_fields::Field(&self.#field_idents())))*
+                        .finish()
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl serde::Serialize for #ident<'_> {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                    use serde::ser::SerializeStruct;
+                    let mut s = serializer.serialize_struct(#ident_str, #field_count)?;
+                    #(s.serialize_field(#field_names, &event_fields::Field(&self.#field_idents()))?;)*
+                    s.end()
+                }
+            }
+
             impl<'a> #ident<'a> {
                 #[inline]
                 /// # Safety
@@ -2259,6 +2493,9 @@ fn conditions(name: &str, ext: Option<&str>) -> TokenStream {
     if name.contains("android") {
         conditions.push(quote! { target_os = "android" });
     }
+    if name.contains("metal") {
+        conditions.push(quote! { target_vendor = "apple" });
+    }
     match conditions.len() {
         0 => quote! {},
         1 => quote! { #[cfg(#(#conditions)*)] },
@@ -0,0 +1,110 @@
+//! Drive a session without graphics through its whole lifecycle, tracking the headset for a fixed
+//! number of frames.
+//!
+//! Run with `--features null-runtime` to use the built-in null runtime instead of a real one.
+
+use openxr as xr;
+
+const FRAMES: u32 = 90;
+
+fn main() {
+    #[cfg(feature = "null-runtime")]
+    let entry = xr::Entry::null();
+    #[cfg(all(not(feature = "null-runtime"), feature = "linked"))]
+    let entry = xr::Entry::linked();
+    #[cfg(all(not(feature = "null-runtime"), not(feature = "linked")))]
+    let entry = unsafe {
+        xr::Entry::load()
+            .expect("couldn't find the OpenXR loader; try enabling the \"static\" feature")
+    };
+
+    let available_extensions = entry.enumerate_extensions().unwrap();
+    assert!(
+        available_extensions.mnd_headless,
+        "XR_MND_headless is not supported"
+    );
+    let mut enabled_extensions = xr::ExtensionSet::default();
+    enabled_extensions.mnd_headless = true;
+    let instance = entry
+        .create_instance(
+            &xr::ApplicationInfo {
+                application_name: "openxrs headless",
+                ..Default::default()
+            },
+            &enabled_extensions,
+            &[],
+        )
+        .unwrap();
+    let instance_props = instance.properties().unwrap();
+    println!(
+        "loaded instance: {} v{}",
+        instance_props.runtime_name, instance_props.runtime_version
+    );
+    let system = instance
+        .system(xr::FormFactor::HEAD_MOUNTED_DISPLAY)
+        .unwrap();
+
+    let (session, frame_wait, frame_stream) = unsafe {
+        instance
+            .create_session::<xr::Headless>(system, &())
+            .unwrap()
+    };
+    let mut frame_loop = xr::FrameLoop::new(frame_wait, frame_stream);
+    let mut lifecycle = xr::SessionLifecycle::default();
+    let stage = session
+        .create_reference_space(xr::ReferenceSpaceType::STAGE, xr::Posef::IDENTITY)
+        .unwrap();
+    let view = session
+        .create_reference_space(xr::ReferenceSpaceType::VIEW, xr::Posef::IDENTITY)
+        .unwrap();
+
+    let mut event_storage = xr::EventDataBuffer::new();
+    let mut frames = 0;
+    'main_loop: loop {
+        while let Some(event) = instance.poll_event(&mut event_storage).unwrap() {
+            if let Some(state) = lifecycle.handle_event(&event) {
+                println!("entered state {:?}", state);
+                match state {
+                    xr::SessionState::READY => {
+                        session
+                            .begin(xr::ViewConfigurationType::PRIMARY_STEREO)
+                            .unwrap();
+                    }
+                    xr::SessionState::STOPPING => {
+                        session.end().unwrap();
+                    }
+                    xr::SessionState::EXITING | xr::SessionState::LOSS_PENDING => {
+                        break 'main_loop;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let state = lifecycle.state();
+        if state != xr::SessionState::SYNCHRONIZED
+            && state != xr::SessionState::VISIBLE
+            && state != xr::SessionState::FOCUSED
+        {
+            continue;
+        }
+
+        let decision = lifecycle.throttle(frame_loop.begin().unwrap());
+        let frame_state = *decision.state();
+        let location = view
+            .locate(&stage, frame_state.predicted_display_time)
+            .unwrap();
+        if frames % 30 == 0 {
+            println!("head position: {:?}", location.pose.position);
+        }
+        frame_loop
+            .skip(&frame_state, xr::EnvironmentBlendMode::OPAQUE)
+            .unwrap();
+
+        frames += 1;
+        if frames == FRAMES {
+            session.request_exit().unwrap();
+        }
+    }
+    println!("exited after {} frames", frames);
+}
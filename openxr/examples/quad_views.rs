@@ -0,0 +1,64 @@
+//! Print the focus/context layout of the `PRIMARY_QUAD_VARJO` view configuration, and how to
+//! allocate swapchains for it.
+
+use openxr as xr;
+
+fn main() {
+    #[cfg(feature = "linked")]
+    let entry = xr::Entry::linked();
+    #[cfg(not(feature = "linked"))]
+    let entry = unsafe {
+        xr::Entry::load()
+            .expect("couldn't find the OpenXR loader; try enabling the \"static\" feature")
+    };
+
+    let available_extensions = entry.enumerate_extensions().unwrap();
+    if !available_extensions.varjo_quad_views {
+        println!("XR_VARJO_quad_views is not supported by this runtime");
+        return;
+    }
+    let mut enabled_extensions = xr::ExtensionSet::default();
+    enabled_extensions.varjo_quad_views = true;
+    let instance = entry
+        .create_instance(
+            &xr::ApplicationInfo {
+                application_name: "openxrs quad views",
+                ..Default::default()
+            },
+            &enabled_extensions,
+            &[],
+        )
+        .unwrap();
+    let system = instance
+        .system(xr::FormFactor::HEAD_MOUNTED_DISPLAY)
+        .unwrap();
+
+    let layout = instance.quad_view_layout_varjo(system).unwrap();
+    for (index, view) in layout.context.iter().chain(&layout.focus).enumerate() {
+        println!(
+            "view {} ({:?}): {}x{}",
+            index,
+            xr::QuadViewRegionVARJO::of_view(index),
+            view.recommended_image_rect_width,
+            view.recommended_image_rect_height
+        );
+    }
+
+    // Context and focus views differ greatly in resolution, so each pair gets its own two-layer
+    // array swapchain. Every frame, render both eyes of each region into its swapchain, locate the
+    // four views with `PRIMARY_QUAD_VARJO`, and submit a single projection layer whose views come
+    // from `QuadViewLayoutVARJO::projection_views`.
+    for &region in &[
+        xr::QuadViewRegionVARJO::Context,
+        xr::QuadViewRegionVARJO::Focus,
+    ] {
+        let extent = layout.recommended_extent(region);
+        println!(
+            "{:?} swapchain: {}x{}, 2 array layers, {} samples",
+            region,
+            extent.width,
+            extent.height,
+            layout.recommended_sample_count(region)
+        );
+    }
+}
@@ -346,32 +346,27 @@ pub fn main() {
             .create_action_set("input", "input pose information", 0)
             .unwrap();
 
-        let right_action = action_set
-            .create_action::<xr::Posef>("right_hand", "Right Hand Controller", &[])
-            .unwrap();
-        let left_action = action_set
-            .create_action::<xr::Posef>("left_hand", "Left Hand Controller", &[])
-            .unwrap();
+        let hand_action =
+            xr::HandedAction::<xr::Posef>::new(&action_set, "hand", "Hand Controller").unwrap();
 
         // Bind our actions to input devices using the given profile
         // If you want to access inputs specific to a particular device you may specify a different
         // interaction profile
+        use xr::interaction_profiles::{KHR_SIMPLE_CONTROLLER, LEFT_HAND, RIGHT_HAND};
         xr_instance
             .suggest_interaction_profile_bindings(
-                xr_instance
-                    .string_to_path("/interaction_profiles/khr/simple_controller")
-                    .unwrap(),
+                KHR_SIMPLE_CONTROLLER.path(&xr_instance).unwrap(),
                 &[
                     xr::Binding::new(
-                        &right_action,
-                        xr_instance
-                            .string_to_path("/user/hand/right/input/grip/pose")
+                        hand_action.action(),
+                        KHR_SIMPLE_CONTROLLER
+                            .binding(&xr_instance, RIGHT_HAND, "/input/grip/pose")
                             .unwrap(),
                     ),
                     xr::Binding::new(
-                        &left_action,
-                        xr_instance
-                            .string_to_path("/user/hand/left/input/grip/pose")
+                        hand_action.action(),
+                        KHR_SIMPLE_CONTROLLER
+                            .binding(&xr_instance, LEFT_HAND, "/input/grip/pose")
                             .unwrap(),
                     ),
                 ],
@@ -382,11 +377,11 @@ pub fn main() {
         session.attach_action_sets(&[&action_set]).unwrap();
 
         // Create an action space for each device we want to locate
-        let right_space = right_action
-            .create_space(session.clone(), xr::Path::NULL, xr::Posef::IDENTITY)
+        let right_space = hand_action
+            .create_space(&session, xr::Hand::RIGHT, xr::Posef::IDENTITY)
             .unwrap();
-        let left_space = left_action
-            .create_space(session.clone(), xr::Path::NULL, xr::Posef::IDENTITY)
+        let left_space = hand_action
+            .create_space(&session, xr::Hand::LEFT, xr::Posef::IDENTITY)
             .unwrap();
 
         // OpenXR uses a couple different types of reference frames for positioning content; we need
@@ -439,7 +434,7 @@ pub fn main() {
                 // can't necessarily exit instantly. Instead, we must notify the runtime of our
                 // intent and wait for it to tell us when we're actually done.
                 match session.request_exit() {
-                    Ok(()) => {}
+                    Ok(_) => {}
                     Err(xr::sys::Result::ERROR_SESSION_NOT_RUNNING) => break,
                     Err(e) => panic!("{}", e),
                 }
@@ -517,12 +512,15 @@ pub fn main() {
                 // Create a swapchain for the viewpoints! A swapchain is a set of texture buffers
                 // used for displaying to screen, typically this is a backbuffer and a front buffer,
                 // one for rendering data to, and one for displaying on-screen.
+                //
+                // With multiview, each view gets its own layer of an array image.
+                let layout = xr::SwapchainLayout::new(xr::ViewLayout::Multiview, &views);
                 let resolution = vk::Extent2D {
-                    width: views[0].recommended_image_rect_width,
-                    height: views[0].recommended_image_rect_height,
+                    width: layout.width(),
+                    height: layout.height(),
                 };
                 let handle = session
-                    .create_swapchain(&xr::SwapchainCreateInfo {
+                    .create_swapchain(&layout.swapchain_create_info(xr::SwapchainCreateInfo {
                         create_flags: xr::SwapchainCreateFlags::EMPTY,
                         usage_flags: xr::SwapchainUsageFlags::COLOR_ATTACHMENT
                             | xr::SwapchainUsageFlags::SAMPLED,
@@ -531,12 +529,12 @@ pub fn main() {
                         // so we hardcode this to 1. If we used a proper multisampling setup, we
                         // could set this to `views[0].recommended_swapchain_sample_count`.
                         sample_count: 1,
-                        width: resolution.width,
-                        height: resolution.height,
+                        width: 0,
+                        height: 0,
                         face_count: 1,
-                        array_size: VIEW_COUNT,
+                        array_size: 0,
                         mip_count: 1,
-                    })
+                    }))
                     .unwrap();
 
                 // We'll want to track our own information about the swapchain, so we can draw stuff
@@ -544,6 +542,7 @@ pub fn main() {
                 let images = handle.enumerate_images().unwrap();
                 Swapchain {
                     handle,
+                    layout,
                     resolution,
                     buffers: images
                         .into_iter()
@@ -617,17 +616,28 @@ pub fn main() {
                 vk::SubpassContents::INLINE,
             );
 
+            // Multiview renders every view with the same viewport and scissor, so the first view's
+            // region serves for all of them.
+            let region = swapchain.layout.views()[0];
+            let viewport = region.viewport();
+            let scissor = region.scissor();
             let viewports = [vk::Viewport {
-                x: 0.0,
-                y: 0.0,
-                width: swapchain.resolution.width as f32,
-                height: swapchain.resolution.height as f32,
+                x: viewport.x,
+                y: viewport.y,
+                width: viewport.width,
+                height: viewport.height,
                 min_depth: 0.0,
                 max_depth: 1.0,
             }];
             let scissors = [vk::Rect2D {
-                offset: vk::Offset2D { x: 0, y: 0 },
-                extent: swapchain.resolution,
+                offset: vk::Offset2D {
+                    x: scissor.offset.x,
+                    y: scissor.offset.y,
+                },
+                extent: vk::Extent2D {
+                    width: scissor.extent.width as u32,
+                    height: scissor.extent.height as u32,
+                },
             }];
             vk_device.cmd_set_viewport(cmd, 0, &viewports);
             vk_device.cmd_set_scissor(cmd, 0, &scissors);
@@ -653,7 +663,7 @@ pub fn main() {
                 .unwrap();
 
             let mut printed = false;
-            if left_action.is_active(&session, xr::Path::NULL).unwrap() {
+            if hand_action.is_active(&session, xr::Hand::LEFT).unwrap() {
                 print!(
                     "Left Hand: ({:0<12},{:0<12},{:0<12}), ",
                     left_location.pose.position.x,
@@ -663,7 +673,7 @@ pub fn main() {
                 printed = true;
             }
 
-            if right_action.is_active(&session, xr::Path::NULL).unwrap() {
+            if hand_action.is_active(&session, xr::Hand::RIGHT).unwrap() {
                 print!(
                     "Right Hand: ({:0<12},{:0<12},{:0<12})",
                     right_location.pose.position.x,
@@ -687,7 +697,10 @@ pub fn main() {
 
             // Wait until the image is available to render to before beginning work on the GPU. The
             // compositor could still be reading from it.
-            swapchain.handle.wait_image(xr::Duration::INFINITE).unwrap();
+            swapchain
+                .handle
+                .wait_image_with_policy(&xr::SwapchainWaitPolicy::default())
+                .unwrap();
 
             // Submit commands to the GPU, then tell OpenXR we're done with our part.
             vk_device
@@ -700,13 +713,7 @@ pub fn main() {
             swapchain.handle.release_image().unwrap();
 
             // Tell OpenXR what to present for this frame
-            let rect = xr::Rect2Di {
-                offset: xr::Offset2Di { x: 0, y: 0 },
-                extent: xr::Extent2Di {
-                    width: swapchain.resolution.width as _,
-                    height: swapchain.resolution.height as _,
-                },
-            };
+            let regions = swapchain.layout.views();
             frame_stream
                 .end(
                     xr_frame_state.predicted_display_time,
@@ -716,21 +723,11 @@ pub fn main() {
                             xr::CompositionLayerProjectionView::new()
                                 .pose(views[0].pose)
                                 .fov(views[0].fov)
-                                .sub_image(
-                                    xr::SwapchainSubImage::new()
-                                        .swapchain(&swapchain.handle)
-                                        .image_array_index(0)
-                                        .image_rect(rect),
-                                ),
+                                .sub_image(regions[0].sub_image(&swapchain.handle)),
                             xr::CompositionLayerProjectionView::new()
                                 .pose(views[1].pose)
                                 .fov(views[1].fov)
-                                .sub_image(
-                                    xr::SwapchainSubImage::new()
-                                        .swapchain(&swapchain.handle)
-                                        .image_array_index(1)
-                                        .image_rect(rect),
-                                ),
+                                .sub_image(regions[1].sub_image(&swapchain.handle)),
                         ]),
                     ],
                 )
@@ -739,18 +736,22 @@ pub fn main() {
         }
 
         // OpenXR MUST be allowed to clean up before we destroy Vulkan resources it could touch, so
-        // first we must drop all its handles.
-        drop((
-            session,
-            frame_wait,
-            frame_stream,
-            stage,
-            action_set,
-            left_space,
-            right_space,
-            left_action,
-            right_action,
-        ));
+        // first we must destroy all its handles, children before parents.
+        let mut shutdown = xr::Shutdown::new();
+        shutdown
+            .push(session)
+            .push(frame_wait)
+            .push(frame_stream)
+            .push(stage)
+            .push(action_set)
+            .push(left_space)
+            .push(right_space)
+            .push(hand_action);
+        let swapchain = swapchain.map(|swapchain| {
+            shutdown.push(swapchain.handle);
+            swapchain.buffers
+        });
+        shutdown.finish().unwrap();
 
         // Ensure all in-flight frames are finished before destroying resources they might use
         vk_device.wait_for_fences(&fences, true, !0).unwrap();
@@ -758,8 +759,8 @@ pub fn main() {
             vk_device.destroy_fence(fence, None);
         }
 
-        if let Some(swapchain) = swapchain {
-            for buffer in swapchain.buffers {
+        if let Some(buffers) = swapchain {
+            for buffer in buffers {
                 vk_device.destroy_framebuffer(buffer.framebuffer, None);
                 vk_device.destroy_image_view(buffer.color, None);
             }
@@ -783,6 +784,7 @@ const VIEW_TYPE: xr::ViewConfigurationType = xr::ViewConfigurationType::PRIMARY_
 struct Swapchain {
     handle: xr::Swapchain<xr::Vulkan>,
     buffers: Vec<Framebuffer>,
+    layout: xr::SwapchainLayout,
     resolution: vk::Extent2D,
 }
 
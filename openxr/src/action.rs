@@ -52,6 +52,29 @@ impl<T: ActionTy> Action<T> {
         })
     }
 
+    /// Input sources currently bound to this action, with names suitable for showing the user,
+    /// e.g. in a rebinding menu
+    ///
+    /// `which_components` selects the parts of each localized name, e.g.
+    /// `InputSourceLocalizedNameFlags::USER_PATH | InputSourceLocalizedNameFlags::COMPONENT` for
+    /// "Left Hand Trigger".
+    pub fn bound_sources_localized<G>(
+        &self,
+        session: &Session<G>,
+        which_components: InputSourceLocalizedNameFlags,
+    ) -> Result<Vec<BoundSource>> {
+        self.bound_sources(session)?
+            .into_iter()
+            .map(|path| {
+                Ok(BoundSource {
+                    path,
+                    name: self.instance().path_to_string(path)?,
+                    localized_name: session.input_source_localized_name(path, which_components)?,
+                })
+            })
+            .collect()
+    }
+
     // Private helper
     #[inline]
     fn fp(&self) -> &raw::Instance {
@@ -163,6 +186,16 @@ pub trait ActionTy: Sized {
     const TYPE: ActionType;
 }
 
+/// An input source bound to an action, from [`Action::bound_sources_localized`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundSource {
+    pub path: Path,
+    /// The path as a string, e.g. `/user/hand/left/input/trigger/value`
+    pub name: String,
+    /// Name of the source in the current system locale
+    pub localized_name: String,
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct ActionState<T: ActionInput> {
     pub current_state: T,
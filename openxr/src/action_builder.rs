@@ -0,0 +1,231 @@
+use std::fmt;
+
+use crate::*;
+
+/// Creates an [`ActionSet`], checking its name before it reaches the runtime
+///
+/// The localized name defaults to the name.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn f(instance: &openxr::Instance) -> Result<(), openxr::ActionBuilderError> {
+/// let set = openxr::ActionSetBuilder::new("gameplay")
+///     .localized_name("Gameplay")
+///     .priority(1)
+///     .create(instance)?;
+/// let fire = openxr::ActionBuilder::new("fire")
+///     .localized_name("Fire")
+///     .subaction_path(openxr::interaction_profiles::LEFT_HAND)
+///     .subaction_path(openxr::interaction_profiles::RIGHT_HAND)
+///     .create::<bool>(&set)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct ActionSetBuilder<'a> {
+    name: &'a str,
+    localized_name: Option<&'a str>,
+    priority: u32,
+}
+
+impl<'a> ActionSetBuilder<'a> {
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            localized_name: None,
+            priority: 0,
+        }
+    }
+
+    /// The name shown to the user, truncated to fit `MAX_LOCALIZED_ACTION_SET_NAME_SIZE`
+    pub fn localized_name(mut self, localized_name: &'a str) -> Self {
+        self.localized_name = Some(localized_name);
+        self
+    }
+
+    /// Sets with higher priority take precedence over others bound to the same input
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn create(
+        &self,
+        instance: &Instance,
+    ) -> std::result::Result<ActionSet, ActionBuilderError> {
+        let localized_name = self.localized_name.unwrap_or(self.name);
+        check_name(self.name, sys::MAX_ACTION_SET_NAME_SIZE)?;
+        check_localized_name(localized_name)?;
+        instance
+            .create_action_set(self.name, localized_name, self.priority)
+            .map_err(|e| ActionBuilderError::from_runtime(e, self.name, localized_name))
+    }
+}
+
+/// Creates an [`Action`], checking its name and subaction paths before they reach the runtime
+///
+/// The localized name defaults to the name.
+#[derive(Debug, Clone)]
+pub struct ActionBuilder<'a> {
+    name: &'a str,
+    localized_name: Option<&'a str>,
+    subaction_paths: Vec<&'a str>,
+}
+
+impl<'a> ActionBuilder<'a> {
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            localized_name: None,
+            subaction_paths: Vec::new(),
+        }
+    }
+
+    /// The name shown to the user, truncated to fit `MAX_LOCALIZED_ACTION_NAME_SIZE`
+    pub fn localized_name(mut self, localized_name: &'a str) -> Self {
+        self.localized_name = Some(localized_name);
+        self
+    }
+
+    /// Allow the action's state to be queried separately for the top level user path `path`,
+    /// e.g. `/user/hand/left`
+    pub fn subaction_path(mut self, path: &'a str) -> Self {
+        self.subaction_paths.push(path);
+        self
+    }
+
+    /// Fails with `ERROR_ACTIONSETS_ALREADY_ATTACHED` if `set` has been attached to a session.
+    pub fn create<T: ActionTy>(
+        &self,
+        set: &ActionSet,
+    ) -> std::result::Result<Action<T>, ActionBuilderError> {
+        let localized_name = self.localized_name.unwrap_or(self.name);
+        check_name(self.name, sys::MAX_ACTION_NAME_SIZE)?;
+        check_localized_name(localized_name)?;
+        let instance = set.instance();
+        let mut paths = Vec::with_capacity(self.subaction_paths.len());
+        for (i, &path) in self.subaction_paths.iter().enumerate() {
+            if !is_path_well_formed(path) {
+                return Err(ActionBuilderError::InvalidSubactionPath(path.into()));
+            }
+            if self.subaction_paths[..i].contains(&path) {
+                return Err(ActionBuilderError::DuplicateSubactionPath(path.into()));
+            }
+            paths.push(
+                instance
+                    .string_to_path(path)
+                    .map_err(|e| ActionBuilderError::from_runtime(e, path, path))?,
+            );
+        }
+        set.create_action(self.name, localized_name, &paths)
+            .map_err(|e| ActionBuilderError::from_runtime(e, self.name, localized_name))
+    }
+}
+
+/// Failure of [`ActionSetBuilder::create`] or [`ActionBuilder::create`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionBuilderError {
+    /// The name is empty or contains characters other than lowercase ASCII letters, digits, `-`,
+    /// `_` and `.`
+    InvalidName(String),
+    /// The name doesn't fit in the given number of bytes, including the trailing null
+    NameTooLong(String, usize),
+    EmptyLocalizedName,
+    /// Another action set, or action in the same set, already has this name
+    DuplicateName(String),
+    /// Another action set, or action in the same set, already has this localized name
+    DuplicateLocalizedName(String),
+    /// The path isn't of the form `/user/hand/left`
+    InvalidSubactionPath(String),
+    DuplicateSubactionPath(String),
+    Runtime(sys::Result),
+}
+
+impl ActionBuilderError {
+    fn from_runtime(result: sys::Result, name: &str, localized_name: &str) -> Self {
+        use ActionBuilderError::*;
+        match result {
+            sys::Result::ERROR_NAME_INVALID => InvalidName(name.into()),
+            sys::Result::ERROR_NAME_DUPLICATED => DuplicateName(name.into()),
+            sys::Result::ERROR_LOCALIZED_NAME_INVALID => EmptyLocalizedName,
+            sys::Result::ERROR_LOCALIZED_NAME_DUPLICATED => {
+                DuplicateLocalizedName(localized_name.into())
+            }
+            // Only subaction paths are parsed
+            sys::Result::ERROR_PATH_FORMAT_INVALID => InvalidSubactionPath(name.into()),
+            _ => Runtime(result),
+        }
+    }
+}
+
+impl fmt::Display for ActionBuilderError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ActionBuilderError::*;
+        match self {
+            InvalidName(x) => write!(
+                fmt,
+                "invalid name {:?}: must be non-empty and contain only lowercase letters, digits, \
+                 '-', '_' and '.'",
+                x
+            ),
+            NameTooLong(x, max) => write!(fmt, "name {:?} is longer than {} bytes", x, max - 1),
+            EmptyLocalizedName => fmt.write_str("localized name is empty"),
+            DuplicateName(x) => write!(fmt, "name {:?} is already in use", x),
+            DuplicateLocalizedName(x) => write!(fmt, "localized name {:?} is already in use", x),
+            InvalidSubactionPath(x) => write!(fmt, "invalid subaction path {:?}", x),
+            DuplicateSubactionPath(x) => write!(fmt, "subaction path {:?} given twice", x),
+            Runtime(x) => fmt::Display::fmt(x, fmt),
+        }
+    }
+}
+
+impl std::error::Error for ActionBuilderError {}
+
+impl From<ActionBuilderError> for sys::Result {
+    fn from(e: ActionBuilderError) -> Self {
+        use ActionBuilderError::*;
+        match e {
+            InvalidName(_) | NameTooLong(..) => sys::Result::ERROR_NAME_INVALID,
+            EmptyLocalizedName => sys::Result::ERROR_LOCALIZED_NAME_INVALID,
+            DuplicateName(_) => sys::Result::ERROR_NAME_DUPLICATED,
+            DuplicateLocalizedName(_) => sys::Result::ERROR_LOCALIZED_NAME_DUPLICATED,
+            InvalidSubactionPath(_) => sys::Result::ERROR_PATH_FORMAT_INVALID,
+            DuplicateSubactionPath(_) => sys::Result::ERROR_PATH_UNSUPPORTED,
+            Runtime(x) => x,
+        }
+    }
+}
+
+fn check_name(name: &str, max: usize) -> std::result::Result<(), ActionBuilderError> {
+    if name.is_empty() || !name.bytes().all(is_path_byte) {
+        return Err(ActionBuilderError::InvalidName(name.into()));
+    }
+    if name.len() >= max {
+        return Err(ActionBuilderError::NameTooLong(name.into(), max));
+    }
+    Ok(())
+}
+
+fn check_localized_name(localized_name: &str) -> std::result::Result<(), ActionBuilderError> {
+    if localized_name.split('\0').next().unwrap().is_empty() {
+        return Err(ActionBuilderError::EmptyLocalizedName);
+    }
+    Ok(())
+}
+
+/// Whether `path` is a sequence of `/`-prefixed, non-empty components made of path bytes, not all
+/// periods
+fn is_path_well_formed(path: &str) -> bool {
+    path.len() < sys::MAX_PATH_LENGTH
+        && path.starts_with('/')
+        && path[1..].split('/').all(|component| {
+            !component.is_empty()
+                && component.bytes().all(is_path_byte)
+                && !component.bytes().all(|x| x == b'.')
+        })
+}
+
+fn is_path_byte(x: u8) -> bool {
+    matches!(x, b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.')
+}
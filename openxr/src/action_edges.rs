@@ -0,0 +1,121 @@
+use crate::*;
+
+impl ActionState<bool> {
+    /// Whether the action became pressed in the latest [`Session::sync_actions`]
+    #[inline]
+    pub fn just_pressed(&self) -> bool {
+        self.is_active && self.changed_since_last_sync && self.current_state
+    }
+
+    /// Whether the action became released in the latest [`Session::sync_actions`]
+    ///
+    /// An action which stops being active while pressed, e.g. because its controller was turned
+    /// off, reads as released without being reported as changed; use [`ActionEdges`] to catch
+    /// that too.
+    #[inline]
+    pub fn just_released(&self) -> bool {
+        self.is_active && self.changed_since_last_sync && !self.current_state
+    }
+}
+
+/// Tracks the state of an action across syncs to report edges, like a button being pressed
+///
+/// Call [`ActionEdges::update`] once after each [`Session::sync_actions`]. Inactive actions read
+/// as their default value, so releasing a button and losing track of its controller look alike.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn f(
+/// #     session: &openxr::Session<openxr::Headless>,
+/// #     fire: &openxr::Action<bool>,
+/// #     active_set: openxr::ActiveActionSet<'_>,
+/// # ) -> openxr::Result<()> {
+/// let mut fire = openxr::ActionEdges::new(fire, openxr::Path::NULL);
+/// // Each frame
+/// session.sync_actions(&[active_set])?;
+/// fire.update(session)?;
+/// if fire.just_pressed() {
+///     // ...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ActionEdges<T: ActionInput> {
+    action: Action<T>,
+    subaction_path: Path,
+    previous: T,
+    current: T,
+}
+
+impl<T: ActionInput + Copy + Default> ActionEdges<T> {
+    pub fn new(action: &Action<T>, subaction_path: Path) -> Self {
+        Self {
+            action: action.clone(),
+            subaction_path,
+            previous: T::default(),
+            current: T::default(),
+        }
+    }
+
+    /// Read the action's state after a sync, remembering the one before it
+    pub fn update<G>(&mut self, session: &Session<G>) -> Result<ActionState<T>> {
+        let state = self.action.state(session, self.subaction_path)?;
+        self.previous = self.current;
+        self.current = if state.is_active {
+            state.current_state
+        } else {
+            T::default()
+        };
+        Ok(state)
+    }
+
+    /// The value read by the latest update
+    #[inline]
+    pub fn current(&self) -> T {
+        self.current
+    }
+
+    /// The value read by the update before the latest
+    #[inline]
+    pub fn previous(&self) -> T {
+        self.previous
+    }
+
+    #[inline]
+    pub fn action(&self) -> &Action<T> {
+        &self.action
+    }
+}
+
+impl ActionEdges<bool> {
+    #[inline]
+    pub fn is_pressed(&self) -> bool {
+        self.current
+    }
+
+    #[inline]
+    pub fn just_pressed(&self) -> bool {
+        !self.previous && self.current
+    }
+
+    #[inline]
+    pub fn just_released(&self) -> bool {
+        self.previous && !self.current
+    }
+}
+
+impl ActionEdges<f32> {
+    /// Whether the value rose to at least `threshold` in the latest update, e.g. a trigger being
+    /// pulled
+    #[inline]
+    pub fn just_crossed_above(&self, threshold: f32) -> bool {
+        self.previous < threshold && self.current >= threshold
+    }
+
+    /// Whether the value fell below `threshold` in the latest update
+    #[inline]
+    pub fn just_crossed_below(&self, threshold: f32) -> bool {
+        self.previous >= threshold && self.current < threshold
+    }
+}
@@ -0,0 +1,115 @@
+//! Short histories of action states, for recognizing gestures like double clicks and holds.
+//!
+//! Action states only describe the latest [`Session::sync_actions`]. An [`ActionHistory`] keeps
+//! the states observed by a bounded number of previous syncs, recorded by `sync_actions` itself
+//! for as long as the history is alive.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use crate::*;
+
+/// The states of an action observed by recent calls to [`Session::sync_actions`]
+///
+/// Clones share the same history. Recording stops once every clone has been dropped.
+pub struct ActionHistory<T: ActionInput> {
+    inner: Arc<Mutex<History<T>>>,
+}
+
+struct History<T: ActionInput> {
+    action: Action<T>,
+    subaction_path: Path,
+    states: VecDeque<ActionState<T>>,
+    capacity: usize,
+}
+
+impl<T: ActionInput + Copy + Send + 'static> ActionHistory<T> {
+    /// Record the state of `action` for `subaction_path` after each sync of `session`, keeping
+    /// the latest `capacity` states
+    pub fn new<G>(
+        session: &Session<G>,
+        action: &Action<T>,
+        subaction_path: Path,
+        capacity: usize,
+    ) -> Self {
+        assert!(
+            capacity > 0,
+            "an action history needs room for at least one state"
+        );
+        let inner = Arc::new(Mutex::new(History {
+            action: action.clone(),
+            subaction_path,
+            states: VecDeque::with_capacity(capacity),
+            capacity,
+        }));
+        let weak = Arc::downgrade(&inner);
+        session.inner.action_histories.lock().unwrap().push(weak);
+        Self { inner }
+    }
+}
+
+impl<T: ActionInput + Copy> ActionHistory<T> {
+    /// Recorded states, from oldest to newest
+    pub fn states(&self) -> Vec<ActionState<T>> {
+        self.lock().states.iter().copied().collect()
+    }
+
+    /// The state recorded by the most recent sync
+    pub fn latest(&self) -> Option<ActionState<T>> {
+        self.lock().states.back().copied()
+    }
+
+    /// Recorded states that differed from the state at the sync before them, from oldest to
+    /// newest
+    ///
+    /// Each change happened at its `last_change_time`.
+    pub fn changes(&self) -> Vec<ActionState<T>> {
+        self.lock()
+            .states
+            .iter()
+            .filter(|x| x.changed_since_last_sync)
+            .copied()
+            .collect()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity
+    }
+
+    /// Forget all recorded states, e.g. after a gesture has been recognized
+    pub fn clear(&self) {
+        self.lock().states.clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, History<T>> {
+        self.inner.lock().unwrap()
+    }
+}
+
+impl<T: ActionInput> Clone for ActionHistory<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// A history to be recorded into after each sync
+pub(crate) trait RecordActionHistory: Send + Sync {
+    fn record(&self, session: &Session<AnyGraphics>) -> Result<()>;
+}
+
+impl<T: ActionInput + Send> RecordActionHistory for Mutex<History<T>> {
+    fn record(&self, session: &Session<AnyGraphics>) -> Result<()> {
+        let mut history = self.lock().unwrap();
+        let state = history.action.state(session, history.subaction_path)?;
+        if history.states.len() == history.capacity {
+            history.states.pop_front();
+        }
+        history.states.push_back(state);
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 
 use crate::*;
 
@@ -16,7 +19,11 @@ impl ActionSet {
     #[inline]
     pub unsafe fn from_raw(instance: Instance, handle: sys::ActionSet) -> Self {
         Self {
-            inner: Arc::new(ActionSetInner { instance, handle }),
+            inner: Arc::new(ActionSetInner {
+                instance,
+                handle,
+                attached: AtomicBool::new(false),
+            }),
         }
     }
 
@@ -38,7 +45,20 @@ impl ActionSet {
         self.instance().set_name_raw(self.as_raw().into_raw(), name)
     }
 
+    /// Whether this set has been attached to a session, after which no more actions can be
+    /// created in it
+    #[inline]
+    pub fn is_attached(&self) -> bool {
+        self.inner.attached.load(Ordering::Relaxed)
+    }
+
     /// Create a new logical input action
+    ///
+    /// `localized_name` is truncated to the last whole character that fits in
+    /// `MAX_LOCALIZED_ACTION_NAME_SIZE - 1` bytes.
+    ///
+    /// Fails with `ERROR_ACTIONSETS_ALREADY_ATTACHED` if the set has been attached to a session;
+    /// see [`Session::attach_action_sets`].
     #[inline]
     pub fn create_action<T: ActionTy>(
         &self,
@@ -46,6 +66,9 @@ impl ActionSet {
         localized_name: &str,
         subaction_paths: &[Path],
     ) -> Result<Action<T>> {
+        if self.is_attached() {
+            return Err(sys::Result::ERROR_ACTIONSETS_ALREADY_ATTACHED);
+        }
         let info = builder::ActionCreateInfo::new()
             .action_name(name)
             .localized_action_name(localized_name)
@@ -68,6 +91,10 @@ impl ActionSet {
         self.inner.instance.fp()
     }
 
+    pub(crate) fn mark_attached(&self) {
+        self.inner.attached.store(true, Ordering::Relaxed);
+    }
+
     // Private because safety requires that only one copy of the `ActionSet` exist externally.
     pub(crate) fn clone(&self) -> Self {
         Self {
@@ -79,6 +106,7 @@ impl ActionSet {
 struct ActionSetInner {
     instance: Instance,
     handle: sys::ActionSet,
+    attached: AtomicBool,
 }
 
 impl Drop for ActionSetInner {
@@ -0,0 +1,158 @@
+//! Android permissions guarding tracking features.
+//!
+//! On Android, runtimes only expose eye, face and body tracking and scene data to applications
+//! holding the matching runtime permission. Without it, creating a tracker typically fails with
+//! an `ERROR_FEATURE_UNSUPPORTED` that doesn't say why. Checking an [`AndroidPermission`] first,
+//! e.g. through [`AndroidPermission::gate`], yields a [`PermissionError`] naming the permission
+//! to request instead.
+//!
+//! Permissions must also be declared in the application's manifest, and requested from the user
+//! through the Android SDK; this module only checks whether they have been granted.
+
+use std::fmt;
+
+use crate::*;
+
+/// A runtime permission an XR feature depends on
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AndroidPermission {
+    /// Required by `XR_FB_eye_tracking_social`
+    EyeTracking,
+    /// Required by `XR_FB_face_tracking2`
+    FaceTracking,
+    /// Required by `XR_FB_scene` and `XR_FB_scene_capture`
+    Scene,
+    /// Required by `XR_FB_body_tracking` and `XR_META_body_tracking_full_body`
+    BodyTracking,
+}
+
+impl AndroidPermission {
+    pub const ALL: [AndroidPermission; 4] = [
+        AndroidPermission::EyeTracking,
+        AndroidPermission::FaceTracking,
+        AndroidPermission::Scene,
+        AndroidPermission::BodyTracking,
+    ];
+
+    /// The permission's name, as declared in `AndroidManifest.xml`
+    pub fn name(self) -> &'static str {
+        match self {
+            AndroidPermission::EyeTracking => "com.oculus.permission.EYE_TRACKING",
+            AndroidPermission::FaceTracking => "com.oculus.permission.FACE_TRACKING",
+            AndroidPermission::Scene => "com.oculus.permission.USE_SCENE",
+            AndroidPermission::BodyTracking => "com.oculus.permission.BODY_TRACKING",
+        }
+    }
+
+    /// Permissions needed by the extensions enabled in `extensions`
+    pub fn required_by(extensions: &ExtensionSet) -> Vec<AndroidPermission> {
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(|permission| match permission {
+                AndroidPermission::EyeTracking => extensions.fb_eye_tracking_social,
+                AndroidPermission::FaceTracking => extensions.fb_face_tracking2,
+                AndroidPermission::Scene => extensions.fb_scene || extensions.fb_scene_capture,
+                AndroidPermission::BodyTracking => {
+                    extensions.fb_body_tracking || extensions.meta_body_tracking_full_body
+                }
+            })
+            .collect()
+    }
+
+    /// Whether the application has been granted this permission
+    ///
+    /// Always true on other platforms, which have no such permissions.
+    pub fn is_granted(self) -> Result<bool, PermissionError> {
+        #[cfg(target_os = "android")]
+        {
+            check_self_permission(self.name()).map_err(|e| PermissionError::Query(e.to_string()))
+        }
+        #[cfg(not(target_os = "android"))]
+        {
+            Ok(true)
+        }
+    }
+
+    /// Fail with [`PermissionError::Denied`] unless the permission has been granted
+    pub fn require(self) -> Result<(), PermissionError> {
+        if self.is_granted()? {
+            Ok(())
+        } else {
+            Err(PermissionError::Denied(self))
+        }
+    }
+
+    /// Call `create` if the permission has been granted
+    ///
+    /// ```no_run
+    /// # fn f<G>(session: &openxr::Session<G>) -> Result<(), openxr::PermissionError> {
+    /// use openxr::AndroidPermission;
+    /// let _tracker = AndroidPermission::EyeTracking.gate(|| session.create_eye_tracker_social())?;
+    /// # Ok(()) }
+    /// ```
+    pub fn gate<T>(self, create: impl FnOnce() -> Result<T>) -> Result<T, PermissionError> {
+        self.require()?;
+        create().map_err(PermissionError::Runtime)
+    }
+}
+
+/// Failure to use a feature guarded by an [`AndroidPermission`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PermissionError {
+    /// The application hasn't been granted the permission
+    Denied(AndroidPermission),
+    /// The permission couldn't be checked through JNI
+    Query(String),
+    /// The permission was granted, but the runtime reported an error
+    Runtime(sys::Result),
+}
+
+impl fmt::Display for PermissionError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Denied(permission) => write!(
+                fmt,
+                "the {} permission has not been granted; declare it in the manifest and request it at run time",
+                permission.name()
+            ),
+            Self::Query(e) => write!(fmt, "failed to check permission: {}", e),
+            Self::Runtime(e) => e.fmt(fmt),
+        }
+    }
+}
+
+impl std::error::Error for PermissionError {}
+
+impl From<PermissionError> for sys::Result {
+    /// Collapse into an OpenXR result, mapping missing permissions to `ERROR_FEATURE_UNSUPPORTED`
+    /// as runtimes do
+    fn from(e: PermissionError) -> Self {
+        match e {
+            PermissionError::Denied(_) => sys::Result::ERROR_FEATURE_UNSUPPORTED,
+            PermissionError::Query(_) => sys::Result::ERROR_RUNTIME_FAILURE,
+            PermissionError::Runtime(e) => e,
+        }
+    }
+}
+
+/// `Context.checkSelfPermission(name) == PackageManager.PERMISSION_GRANTED` for the current
+/// activity
+#[cfg(target_os = "android")]
+fn check_self_permission(name: &str) -> jni::errors::Result<bool> {
+    const PERMISSION_GRANTED: i32 = 0;
+    let context = ndk_context::android_context();
+    let vm = unsafe { jni::JavaVM::from_raw(context.vm().cast())? };
+    let mut env = vm.attach_current_thread()?;
+    let activity = unsafe { jni::objects::JObject::from_raw(context.context().cast()) };
+    let name = env.new_string(name)?;
+    let result = env
+        .call_method(
+            &activity,
+            "checkSelfPermission",
+            "(Ljava/lang/String;)I",
+            &[(&name).into()],
+        )?
+        .i()?;
+    Ok(result == PERMISSION_GRANTED)
+}
@@ -0,0 +1,74 @@
+use std::time;
+
+use crate::*;
+
+/// Derives animation time steps from the runtime's predicted display times
+///
+/// Animations advanced by wall-clock time between loop iterations judder in XR, because each
+/// frame is shown at its predicted display time rather than when it was rendered. Stepping by the
+/// difference between consecutive [`FrameState::predicted_display_time`]s instead matches what the
+/// user sees. `AnimationClock` rounds that difference to a whole number of display periods,
+/// removing prediction jitter while still advancing by several periods after discarded or
+/// reprojected frames, and caps it so that animations don't leap after the session was paused.
+#[derive(Debug, Clone)]
+pub struct AnimationClock {
+    last: Option<Time>,
+    delta: time::Duration,
+    elapsed: time::Duration,
+    max_periods: u32,
+}
+
+impl AnimationClock {
+    /// Create a clock that advances by at most `max_periods` display periods per frame
+    pub fn new(max_periods: u32) -> Self {
+        assert!(max_periods > 0, "max_periods must be nonzero");
+        Self {
+            last: None,
+            delta: time::Duration::ZERO,
+            elapsed: time::Duration::ZERO,
+            max_periods,
+        }
+    }
+
+    /// Advance to the frame described by `state`, returning the time step to animate it by
+    ///
+    /// The first frame after creation or [`AnimationClock::reset`] advances by one display period.
+    pub fn update(&mut self, state: &FrameState) -> time::Duration {
+        let period = state.predicted_display_period.as_nanos().max(1);
+        let periods = match self.last {
+            // Round to the nearest period, counting at least one for repeated or early times
+            Some(last) => ((state.predicted_display_time - last).as_nanos() + period / 2) / period,
+            None => 1,
+        };
+        let periods = periods.clamp(1, i64::from(self.max_periods));
+        self.last = Some(state.predicted_display_time);
+        self.delta = time::Duration::from_nanos((periods * period) as u64);
+        self.elapsed += self.delta;
+        self.delta
+    }
+
+    /// The time step returned by the latest [`AnimationClock::update`]
+    #[inline]
+    pub fn delta(&self) -> time::Duration {
+        self.delta
+    }
+
+    /// The sum of all time steps since creation
+    #[inline]
+    pub fn elapsed(&self) -> time::Duration {
+        self.elapsed
+    }
+
+    /// Treat the next frame as the first, e.g. when the session resumes after being idle
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.delta = time::Duration::ZERO;
+    }
+}
+
+impl Default for AnimationClock {
+    /// Advance by at most 4 display periods per frame
+    fn default() -> Self {
+        Self::new(4)
+    }
+}
@@ -0,0 +1,156 @@
+//! Recording of OpenXR calls through the `XR_APILAYER_LUNARG_api_dump` layer
+//!
+//! The layer ships with the OpenXR SDK and logs every call made through it, along with its
+//! parameters. [`ApiDump`] configures it and [`parse_api_dump`] reads back its text output, e.g. to
+//! compare the calls made by a test against a known-good recording.
+
+use std::{env, path::PathBuf};
+
+use crate::*;
+
+/// Configuration for the `XR_APILAYER_LUNARG_api_dump` layer
+///
+/// The layer reads its settings from the environment when an instance is created with it enabled,
+/// so [`ApiDump::configure`] must be called before [`Entry::create_instance`], passing
+/// [`ApiDump::LAYER_NAME`] in its `layers`.
+#[derive(Debug, Clone, Default)]
+pub struct ApiDump {
+    /// Format of the log
+    pub format: ApiDumpFormat,
+    /// File to write the log to, or `None` for standard output
+    pub output: Option<PathBuf>,
+}
+
+impl ApiDump {
+    pub const LAYER_NAME: &'static str = "XR_APILAYER_LUNARG_api_dump";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the layer is installed
+    pub fn is_available(entry: &Entry) -> Result<bool> {
+        Ok(entry
+            .enumerate_layers()?
+            .iter()
+            .any(|x| x.layer_name == Self::LAYER_NAME))
+    }
+
+    /// Set the environment variables read by the layer
+    ///
+    /// Affects every instance subsequently created by this process with the layer enabled.
+    pub fn configure(&self) {
+        env::set_var("XR_API_DUMP_EXPORT_TYPE", self.format.as_str());
+        match self.output {
+            Some(ref path) => env::set_var("XR_API_DUMP_FILE_NAME", path),
+            None => env::remove_var("XR_API_DUMP_FILE_NAME"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum ApiDumpFormat {
+    /// Plain text, as understood by [`parse_api_dump`]
+    #[default]
+    Text,
+    Html,
+}
+
+impl ApiDumpFormat {
+    fn as_str(self) -> &'static str {
+        match self {
+            ApiDumpFormat::Text => "text",
+            ApiDumpFormat::Html => "html",
+        }
+    }
+}
+
+/// A single call recorded by the api_dump layer
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ApiDumpCall {
+    /// Return type, e.g. `XrResult`
+    pub return_type: String,
+    /// Function name, e.g. `xrCreateInstance`
+    pub function: String,
+    pub params: Vec<ApiDumpParam>,
+}
+
+/// A parameter, or a member of a structure reachable from a parameter, of an [`ApiDumpCall`]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ApiDumpParam {
+    pub ty: String,
+    /// Name, with the path taken from the parameter for structure members, e.g. `createInfo->type`
+    pub name: String,
+    pub value: String,
+}
+
+impl ApiDumpParam {
+    /// Whether the value is an address or handle, which generally differs from run to run
+    pub fn is_address(&self) -> bool {
+        self.value.starts_with("0x")
+    }
+}
+
+impl ApiDumpCall {
+    /// Render the call one line per parameter, with addresses and handles masked
+    ///
+    /// Suitable for diffing call sequences across runs.
+    pub fn normalized(&self) -> String {
+        let mut out = format!("{} {}\n", self.return_type, self.function);
+        for param in &self.params {
+            let value = if param.is_address() {
+                "0x?"
+            } else {
+                &param.value
+            };
+            out.push_str(&format!("    {} {} = {}\n", param.ty, param.name, value));
+        }
+        out
+    }
+}
+
+/// Parse the text output of the api_dump layer
+///
+/// Each call begins with an unindented line giving its return type and name, followed by one
+/// indented `type name = value` line per parameter. Lines that fit neither form are ignored.
+pub fn parse_api_dump(text: &str) -> Vec<ApiDumpCall> {
+    let mut calls = Vec::<ApiDumpCall>::new();
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace) {
+            let header = line.trim_end().trim_end_matches('(');
+            if let Some(split) = header.rfind(' ') {
+                let function = &header[split + 1..];
+                if function.starts_with("xr") {
+                    calls.push(ApiDumpCall {
+                        return_type: header[..split].trim().into(),
+                        function: function.into(),
+                        params: Vec::new(),
+                    });
+                }
+            }
+            continue;
+        }
+        let call = match calls.last_mut() {
+            Some(x) => x,
+            None => continue,
+        };
+        let line = line.trim();
+        let (decl, value) = match line.find(" = ") {
+            Some(i) => (&line[..i], &line[i + 3..]),
+            None => (line, ""),
+        };
+        let (ty, name) = match decl.rfind(' ') {
+            Some(i) => (&decl[..i], &decl[i + 1..]),
+            None => continue,
+        };
+        call.params.push(ApiDumpParam {
+            ty: ty.trim().into(),
+            name: name.into(),
+            value: value.into(),
+        });
+    }
+    calls
+}
@@ -0,0 +1,160 @@
+//! Modifications to suggested bindings, via [`XR_KHR_binding_modification`].
+//!
+//! Extensions define structures describing how a binding should behave, such as the thresholds
+//! at which an analog input is considered clicked. They are passed alongside the bindings they
+//! modify to [`Instance::suggest_interaction_profile_bindings_with_modifications`].
+//!
+//! [`XR_KHR_binding_modification`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_binding_modification
+
+use std::{marker::PhantomData, mem, ops::Deref, ptr};
+
+use crate::*;
+
+/// Any binding modification, e.g. an [`InteractionProfileAnalogThresholdVALVE`]
+#[repr(transparent)]
+pub struct BindingModificationBase<'a> {
+    _inner: sys::BindingModificationBaseHeaderKHR,
+    _marker: PhantomData<&'a ()>,
+}
+
+/// Thresholds at which an analog input bound to a boolean action is considered pressed and
+/// released, optionally with haptic feedback on each transition
+///
+/// Requires [`XR_VALVE_analog_threshold`].
+///
+/// [`XR_VALVE_analog_threshold`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_VALVE_analog_threshold
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct InteractionProfileAnalogThresholdVALVE<'a> {
+    inner: sys::InteractionProfileAnalogThresholdVALVE,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a> InteractionProfileAnalogThresholdVALVE<'a> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: sys::InteractionProfileAnalogThresholdVALVE {
+                ty: sys::InteractionProfileAnalogThresholdVALVE::TYPE,
+                ..unsafe { mem::zeroed() }
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Initialize with the supplied raw values
+    ///
+    /// # Safety
+    ///
+    /// The guarantees normally enforced by this builder (e.g. lifetimes) must be preserved.
+    #[inline]
+    pub unsafe fn from_raw(inner: sys::InteractionProfileAnalogThresholdVALVE) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn into_raw(self) -> sys::InteractionProfileAnalogThresholdVALVE {
+        self.inner
+    }
+
+    #[inline]
+    pub fn as_raw(&self) -> &sys::InteractionProfileAnalogThresholdVALVE {
+        &self.inner
+    }
+
+    /// The action whose binding is modified, which may be a boolean or float action
+    #[inline]
+    pub fn action<T: ActionTy>(mut self, value: &Action<T>) -> Self {
+        self.inner.action = value.as_raw();
+        self
+    }
+
+    /// The binding to modify, which must be a float input, e.g. a trigger's `value`
+    #[inline]
+    pub fn binding(mut self, value: Path) -> Self {
+        self.inner.binding = value;
+        self
+    }
+
+    /// Value at or above which the input is considered pressed
+    #[inline]
+    pub fn on_threshold(mut self, value: f32) -> Self {
+        self.inner.on_threshold = value;
+        self
+    }
+
+    /// Value at or below which the input is considered released, no greater than the
+    /// `on_threshold`
+    #[inline]
+    pub fn off_threshold(mut self, value: f32) -> Self {
+        self.inner.off_threshold = value;
+        self
+    }
+
+    /// Feedback played when the input becomes pressed
+    #[inline]
+    pub fn on_haptic(mut self, value: &'a HapticBase<'a>) -> Self {
+        self.inner.on_haptic = value as *const _ as _;
+        self
+    }
+
+    /// Feedback played when the input becomes released
+    #[inline]
+    pub fn off_haptic(mut self, value: &'a HapticBase<'a>) -> Self {
+        self.inner.off_haptic = value as *const _ as _;
+        self
+    }
+}
+
+impl<'a> Deref for InteractionProfileAnalogThresholdVALVE<'a> {
+    type Target = BindingModificationBase<'a>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { mem::transmute(&self.inner) }
+    }
+}
+
+impl<'a> Default for InteractionProfileAnalogThresholdVALVE<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Instance {
+    /// Specify default bindings for a well-known input archetype, as
+    /// [`Instance::suggest_interaction_profile_bindings`], modified by `modifications`
+    ///
+    /// Requires [`XR_KHR_binding_modification`], as well as the extensions of each modification.
+    ///
+    /// [`XR_KHR_binding_modification`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_binding_modification
+    pub fn suggest_interaction_profile_bindings_with_modifications(
+        &self,
+        interaction_profile: Path,
+        bindings: &[Binding],
+        modifications: &[&BindingModificationBase<'_>],
+    ) -> Result<()> {
+        let modifications_info = sys::BindingModificationsKHR {
+            ty: sys::BindingModificationsKHR::TYPE,
+            next: ptr::null(),
+            binding_modification_count: modifications.len() as u32,
+            binding_modifications: modifications.as_ptr() as *const _,
+        };
+        let info = sys::InteractionProfileSuggestedBinding {
+            ty: sys::InteractionProfileSuggestedBinding::TYPE,
+            next: &modifications_info as *const _ as _,
+            interaction_profile,
+            count_suggested_bindings: bindings.len() as u32,
+            suggested_bindings: bindings.as_ptr() as *const _ as _,
+        };
+        unsafe {
+            cvt((self.fp().suggest_interaction_profile_bindings)(
+                self.as_raw(),
+                &info,
+            ))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use std::fmt;
+
+use crate::interaction_profiles::InteractionProfile;
+use crate::*;
+
+/// Collects suggested bindings for any number of interaction profiles and submits them together
+///
+/// Bindings are grouped under the most recently selected profile. In debug builds, binding a
+/// component that a profile from [`interaction_profiles`] doesn't have panics immediately, rather
+/// than failing the whole profile's suggestion at runtime.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn f(
+/// #     instance: &openxr::Instance,
+/// #     select: &openxr::Action<bool>,
+/// #     grip: &openxr::Action<openxr::Posef>,
+/// # ) -> openxr::Result<()> {
+/// use openxr::interaction_profiles::{KHR_SIMPLE_CONTROLLER, OCULUS_TOUCH};
+///
+/// openxr::BindingsBuilder::new()
+///     .profile(&KHR_SIMPLE_CONTROLLER)
+///     .bind(select, "/user/hand/right/input/select/click")
+///     .bind(grip, "/user/hand/right/input/grip/pose")
+///     .profile(&OCULUS_TOUCH)
+///     .bind(select, "/user/hand/right/input/a/click")
+///     .bind(grip, "/user/hand/right/input/grip/pose")
+///     .submit(instance)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct BindingsBuilder<'a> {
+    profiles: Vec<ProfileBindings<'a>>,
+    /// Index of the profile receiving bindings
+    current: Option<usize>,
+}
+
+struct ProfileBindings<'a> {
+    path: &'a str,
+    known: Option<InteractionProfile>,
+    bindings: Vec<(sys::Action, &'a str)>,
+    modifications: Vec<&'a BindingModificationBase<'a>>,
+}
+
+impl<'a> BindingsBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Group subsequent bindings under `profile`
+    pub fn profile(self, profile: &InteractionProfile) -> Self {
+        self.select(profile.path, Some(*profile))
+    }
+
+    /// Group subsequent bindings under the profile at `path`
+    ///
+    /// Bindings are checked only if `path` is one of the profiles in [`interaction_profiles`].
+    pub fn custom_profile(self, path: &'a str) -> Self {
+        let known = interaction_profiles::ALL
+            .iter()
+            .find(|x| x.path == path)
+            .copied();
+        self.select(path, known)
+    }
+
+    /// Suggest `binding`, a full path such as `/user/hand/left/input/trigger/value`, for `action`
+    ///
+    /// # Panics
+    ///
+    /// Panics if no profile was selected, or in debug builds if the profile is known not to
+    /// support `binding`.
+    pub fn bind<T: ActionTy>(mut self, action: &'a Action<T>, binding: &'a str) -> Self {
+        let profile = self.current();
+        if let Some(known) = profile.known {
+            debug_assert!(
+                known.supports_binding(binding),
+                "{} has no binding {}",
+                known.path,
+                binding
+            );
+        }
+        profile.bindings.push((action.as_raw(), binding));
+        self
+    }
+
+    /// Modify the current profile's bindings, e.g. with an
+    /// [`InteractionProfileAnalogThresholdVALVE`]
+    ///
+    /// Requires [`XR_KHR_binding_modification`] if used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no profile was selected.
+    ///
+    /// [`XR_KHR_binding_modification`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_binding_modification
+    pub fn modify(mut self, modification: &'a BindingModificationBase<'a>) -> Self {
+        self.current().modifications.push(modification);
+        self
+    }
+
+    /// Suggest the bindings of every profile, in the order profiles were first selected
+    ///
+    /// Stops at the first profile the runtime rejects.
+    pub fn submit(&self, instance: &Instance) -> std::result::Result<(), BindingsError> {
+        for profile in &self.profiles {
+            let error = |result| BindingsError {
+                profile: profile.path.into(),
+                result,
+            };
+            let bindings = profile
+                .bindings
+                .iter()
+                .map(|&(action, path)| {
+                    Ok(Binding::from_raw(action, instance.string_to_path(path)?))
+                })
+                .collect::<Result<Vec<_>>>()
+                .map_err(error)?;
+            let path = instance.string_to_path(profile.path).map_err(error)?;
+            if profile.modifications.is_empty() {
+                instance.suggest_interaction_profile_bindings(path, &bindings)
+            } else {
+                instance.suggest_interaction_profile_bindings_with_modifications(
+                    path,
+                    &bindings,
+                    &profile.modifications,
+                )
+            }
+            .map_err(error)?;
+        }
+        Ok(())
+    }
+
+    fn select(mut self, path: &'a str, known: Option<InteractionProfile>) -> Self {
+        let index = match self.profiles.iter().position(|x| x.path == path) {
+            Some(i) => i,
+            None => {
+                self.profiles.push(ProfileBindings {
+                    path,
+                    known,
+                    bindings: Vec::new(),
+                    modifications: Vec::new(),
+                });
+                self.profiles.len() - 1
+            }
+        };
+        self.current = Some(index);
+        self
+    }
+
+    fn current(&mut self) -> &mut ProfileBindings<'a> {
+        let index = self.current.expect("no interaction profile selected");
+        &mut self.profiles[index]
+    }
+}
+
+/// Failure of [`BindingsBuilder::submit`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingsError {
+    /// The profile whose bindings were rejected
+    pub profile: String,
+    pub result: sys::Result,
+}
+
+impl fmt::Display for BindingsError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "suggesting bindings for {}: {}",
+            self.profile, self.result
+        )
+    }
+}
+
+impl std::error::Error for BindingsError {}
+
+impl From<BindingsError> for sys::Result {
+    fn from(e: BindingsError) -> Self {
+        e.result
+    }
+}
@@ -1,4 +1,4 @@
-use std::{ptr, sync::Arc};
+use std::{mem, ptr, sync::Arc};
 
 use sys::{BodyJointFullBodyLocationMETA, BodyJointSetFullBodyMETA};
 
@@ -18,6 +18,19 @@ impl BodyTrackerFullBodyMETA {
         self.handle
     }
 
+    /// Give up ownership of the handle without destroying it
+    ///
+    /// The caller becomes responsible for destroying the returned handle.
+    #[inline]
+    pub fn into_raw(self) -> sys::BodyTrackerFullBodyMETA {
+        let mut this = mem::ManuallyDrop::new(self);
+        // Release everything but the handle, which `Drop` would destroy
+        unsafe {
+            ptr::drop_in_place(&mut this.session);
+        }
+        this.handle
+    }
+
     /// Take ownership of an existing body tracker
     ///
     /// # Safety
@@ -0,0 +1,219 @@
+//! A head-locked vignette that narrows the visible field of view during artificial motion.
+//!
+//! Darkening the periphery while the user is moved by the application, rather than by their own
+//! body, is a common way to reduce motion sickness. [`ComfortVignette`] eases a vignette radius
+//! towards one derived from an application-provided motion intensity, draws the vignette into an
+//! RGBA8 swapchain image when the radius changes, and builds a quad or cylinder layer that
+//! composites it over the scene using alpha blending.
+
+use std::time;
+
+use crate::*;
+
+/// A comfort vignette composited as an alpha-blended layer
+///
+/// The vignette is drawn into a square RGBA8 swapchain created by the application, e.g. with
+/// [`Session::create_swapchain`] and `SwapchainUsageFlags::TRANSFER_DST`. Each frame:
+///
+/// 1. call [`ComfortVignette::update`] with the current motion intensity,
+/// 2. if [`ComfortVignette::needs_redraw`], draw the pixels written by
+///    [`ComfortVignette::write_rgba8`] into the next swapchain image,
+/// 3. if [`ComfortVignette::is_visible`], submit [`ComfortVignette::quad_layer`] or
+///    [`ComfortVignette::cylinder_layer`] above the scene, with
+///    [`ComfortVignette::alpha_blend`] chained if XR_FB_composition_layer_alpha_blend is enabled.
+///
+/// Radii are fractions of the vignette layer's half extent, so a radius of √2 or more leaves the
+/// whole layer clear.
+#[derive(Debug, Clone)]
+pub struct ComfortVignette {
+    min_radius: f32,
+    max_radius: f32,
+    feather: f32,
+    half_angle: f32,
+    rate: f32,
+    color: [f32; 3],
+    radius: f32,
+    drawn_radius: Option<f32>,
+}
+
+impl ComfortVignette {
+    /// Create a vignette whose radius ranges from `max_radius` when there's no motion down to
+    /// `min_radius` at full intensity
+    pub fn new(min_radius: f32, max_radius: f32) -> Self {
+        assert!(
+            0.0 <= min_radius && min_radius <= max_radius,
+            "radii must be nonnegative and ordered"
+        );
+        Self {
+            min_radius,
+            max_radius,
+            feather: 0.15,
+            half_angle: 60f32.to_radians(),
+            rate: 8.0,
+            color: [0.0; 3],
+            radius: max_radius,
+            drawn_radius: None,
+        }
+    }
+
+    /// Width of the gradient between the clear center and the opaque edge, as a fraction of the
+    /// half extent
+    pub fn feather(mut self, value: f32) -> Self {
+        self.feather = value.max(0.0);
+        self
+    }
+
+    /// Half the angle the layer covers horizontally and vertically, in radians
+    ///
+    /// Should exceed the half angles of the views' fields of view, so the vignette's edge is never
+    /// seen. Must be less than a right angle.
+    pub fn half_angle(mut self, value: f32) -> Self {
+        assert!(
+            value > 0.0 && value < std::f32::consts::FRAC_PI_2,
+            "half angle out of range"
+        );
+        self.half_angle = value;
+        self
+    }
+
+    /// How quickly the radius follows the intensity, as the inverse of the easing time constant in
+    /// seconds
+    pub fn rate(mut self, value: f32) -> Self {
+        self.rate = value.max(0.0);
+        self
+    }
+
+    /// Color of the obstructed periphery
+    pub fn color(mut self, value: [f32; 3]) -> Self {
+        self.color = value;
+        self.drawn_radius = None;
+        self
+    }
+
+    /// Ease the radius towards that for `intensity`, between 0 for no motion and 1 for the most
+    /// intense, over `delta`, e.g. as returned by [`AnimationClock::update`]
+    pub fn update(&mut self, intensity: f32, delta: time::Duration) -> f32 {
+        let intensity = intensity.clamp(0.0, 1.0);
+        let target = self.max_radius + (self.min_radius - self.max_radius) * intensity;
+        let t = 1.0 - (-self.rate * delta.as_secs_f32()).exp();
+        self.radius += (target - self.radius) * t;
+        if (target - self.radius).abs() < 1e-3 {
+            self.radius = target;
+        }
+        self.radius
+    }
+
+    /// The current radius
+    #[inline]
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Whether any part of the layer is obstructed, i.e. whether it needs to be submitted
+    #[inline]
+    pub fn is_visible(&self) -> bool {
+        self.radius < std::f32::consts::SQRT_2
+    }
+
+    /// Whether the radius changed visibly since the vignette was last drawn
+    pub fn needs_redraw(&self) -> bool {
+        match self.drawn_radius {
+            Some(drawn) => (drawn - self.radius).abs() > 1e-3,
+            None => true,
+        }
+    }
+
+    /// Draw the vignette at the current radius into `pixels`, a `size` by `size` RGBA8 image with
+    /// premultiplied alpha
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pixels` is smaller than the image.
+    pub fn write_rgba8(&mut self, pixels: &mut [u8], size: u32) {
+        let size = size as usize;
+        assert!(pixels.len() >= size * size * 4, "pixel buffer too small");
+        let inner = self.radius;
+        let outer = self.radius + self.feather.max(1e-3);
+        let to_unit = |i: usize| (i as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+        for (i, pixel) in pixels.chunks_exact_mut(4).take(size * size).enumerate() {
+            let (x, y) = (to_unit(i % size), to_unit(i / size));
+            let r = (x * x + y * y).sqrt();
+            let t = ((r - inner) / (outer - inner)).clamp(0.0, 1.0);
+            let alpha = t * t * (3.0 - 2.0 * t);
+            let quantize = |x: f32| (x * 255.0 + 0.5) as u8;
+            pixel[0] = quantize(self.color[0] * alpha);
+            pixel[1] = quantize(self.color[1] * alpha);
+            pixel[2] = quantize(self.color[2] * alpha);
+            pixel[3] = quantize(alpha);
+        }
+        self.drawn_radius = Some(self.radius);
+    }
+
+    /// Blend factors compositing the premultiplied vignette over the layers beneath it
+    pub fn alpha_blend(&self) -> CompositionLayerAlphaBlendFB {
+        CompositionLayerAlphaBlendFB::new()
+            .src_factor_color(BlendFactorFB::ONE)
+            .dst_factor_color(BlendFactorFB::ONE_MINUS_SRC_ALPHA)
+            .src_factor_alpha(BlendFactorFB::ONE)
+            .dst_factor_alpha(BlendFactorFB::ONE_MINUS_SRC_ALPHA)
+    }
+
+    /// A quad layer showing the vignette drawn in `sub_image` in front of the viewer, where `view`
+    /// is a `VIEW` reference space
+    pub fn quad_layer<'a, G: Graphics>(
+        &self,
+        view: &'a Space,
+        sub_image: SwapchainSubImage<'a, G>,
+    ) -> CompositionLayerQuad<'a, G> {
+        let extent = 2.0 * self.half_angle.tan();
+        CompositionLayerQuad::new()
+            .layer_flags(CompositionLayerFlags::BLEND_TEXTURE_SOURCE_ALPHA)
+            .space(view)
+            .eye_visibility(EyeVisibility::BOTH)
+            .sub_image(sub_image)
+            .pose(Self::pose())
+            .size(Extent2Df {
+                width: extent,
+                height: extent,
+            })
+    }
+
+    /// A cylinder layer showing the vignette drawn in `sub_image` around the viewer, where `view`
+    /// is a `VIEW` reference space
+    ///
+    /// Requires XR_KHR_composition_layer_cylinder.
+    pub fn cylinder_layer<'a, G: Graphics>(
+        &self,
+        view: &'a Space,
+        sub_image: SwapchainSubImage<'a, G>,
+    ) -> CompositionLayerCylinderKHR<'a, G> {
+        let central_angle = 2.0 * self.half_angle;
+        CompositionLayerCylinderKHR::new()
+            .layer_flags(CompositionLayerFlags::BLEND_TEXTURE_SOURCE_ALPHA)
+            .space(view)
+            .eye_visibility(EyeVisibility::BOTH)
+            .sub_image(sub_image)
+            .pose(Posef::IDENTITY)
+            .radius(1.0)
+            .central_angle(central_angle)
+            .aspect_ratio(central_angle / (2.0 * self.half_angle.tan()))
+    }
+
+    fn pose() -> Posef {
+        Posef {
+            orientation: Quaternionf::IDENTITY,
+            position: Vector3f {
+                x: 0.0,
+                y: 0.0,
+                z: -1.0,
+            },
+        }
+    }
+}
+
+impl Default for ComfortVignette {
+    /// Clear without motion, narrowing down to half the layer's extent at full intensity
+    fn default() -> Self {
+        Self::new(0.5, std::f32::consts::SQRT_2)
+    }
+}
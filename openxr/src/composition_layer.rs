@@ -0,0 +1,87 @@
+//! Owned composition layers of any type, for layer stacks assembled at run time.
+//!
+//! [`FrameStream::end`] takes references to layers, which requires each layer to live in a
+//! separate binding for the duration of the call. [`CompositionLayer`] holds any layer builder by
+//! value, so a frame's layers can be collected into a single `Vec` and submitted with
+//! [`FrameStream::end_layers`].
+
+use std::ops::Deref;
+
+use crate::*;
+
+/// A composition layer of any type
+///
+/// Layer builders convert into this with `into()`:
+///
+/// ```no_run
+/// # fn f<G: openxr::Graphics>(
+/// #     frame_stream: &mut openxr::FrameStream<G>,
+/// #     state: openxr::FrameState,
+/// #     space: &openxr::Space,
+/// #     views: &[openxr::CompositionLayerProjectionView<'_, G>],
+/// #     passthrough: Option<&openxr::PassthroughLayer>,
+/// # ) {
+/// use openxr as xr;
+/// let mut layers: Vec<xr::CompositionLayer<G>> = Vec::new();
+/// if let Some(passthrough) = passthrough {
+///     layers.push(xr::CompositionLayerPassthroughFB::new().layer_handle(passthrough).into());
+/// }
+/// layers.push(xr::CompositionLayerProjection::new().space(space).views(views).into());
+/// frame_stream
+///     .end_layers(
+///         state.predicted_display_time,
+///         xr::EnvironmentBlendMode::OPAQUE,
+///         &layers,
+///     )
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Copy, Clone)]
+pub enum CompositionLayer<'a, G: Graphics> {
+    Projection(CompositionLayerProjection<'a, G>),
+    Quad(CompositionLayerQuad<'a, G>),
+    CylinderKHR(CompositionLayerCylinderKHR<'a, G>),
+    CubeKHR(CompositionLayerCubeKHR<'a, G>),
+    EquirectKHR(CompositionLayerEquirectKHR<'a, G>),
+    Equirect2KHR(CompositionLayerEquirect2KHR<'a, G>),
+    PassthroughFB(CompositionLayerPassthroughFB<'a, G>),
+}
+
+impl<'a, G: Graphics> Deref for CompositionLayer<'a, G> {
+    type Target = CompositionLayerBase<'a, G>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Projection(x) => x,
+            Self::Quad(x) => x,
+            Self::CylinderKHR(x) => x,
+            Self::CubeKHR(x) => x,
+            Self::EquirectKHR(x) => x,
+            Self::Equirect2KHR(x) => x,
+            Self::PassthroughFB(x) => x,
+        }
+    }
+}
+
+macro_rules! impl_from_layer {
+    ($($variant:ident($ty:ident)),*) => {
+        $(
+            impl<'a, G: Graphics> From<$ty<'a, G>> for CompositionLayer<'a, G> {
+                #[inline]
+                fn from(layer: $ty<'a, G>) -> Self {
+                    Self::$variant(layer)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_layer!(
+    Projection(CompositionLayerProjection),
+    Quad(CompositionLayerQuad),
+    CylinderKHR(CompositionLayerCylinderKHR),
+    CubeKHR(CompositionLayerCubeKHR),
+    EquirectKHR(CompositionLayerEquirectKHR),
+    Equirect2KHR(CompositionLayerEquirect2KHR),
+    PassthroughFB(CompositionLayerPassthroughFB)
+);
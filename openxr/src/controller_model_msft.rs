@@ -0,0 +1,243 @@
+//! Loading and animating the glTF controller models provided by [`XR_MSFT_controller_model`].
+//!
+//! The runtime reports one pose per animatable node, identified by a node name and optionally the
+//! name of one of its ancestors. Each pose replaces the local transform of the matching glTF node.
+//!
+//! [`XR_MSFT_controller_model`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_controller_model
+
+use std::ptr;
+
+pub use sys::ControllerModelKeyMSFT;
+
+use crate::*;
+
+impl<G> Session<G> {
+    /// Identify the controller model currently associated with a top level user path, e.g.
+    /// `/user/hand/left`
+    ///
+    /// Returns `None` if the runtime has no model for the path, for example because no controller
+    /// is connected. Keys may change over the course of a session and should be queried again
+    /// after `InteractionProfileChanged` events.
+    ///
+    /// Requires MSFT_controller_model.
+    pub fn get_controller_model_key_msft(
+        &self,
+        top_level_user_path: Path,
+    ) -> Result<Option<ControllerModelKeyMSFT>> {
+        let ext = self.controller_model_msft()?;
+        let mut state = sys::ControllerModelKeyStateMSFT::out(ptr::null_mut());
+        let state = unsafe {
+            cvt((ext.get_controller_model_key)(
+                self.as_raw(),
+                top_level_user_path,
+                state.as_mut_ptr(),
+            ))?;
+            state.assume_init()
+        };
+        Ok(Some(state.model_key).filter(|&x| x != ControllerModelKeyMSFT::NULL))
+    }
+
+    /// Load the glTF 2.0 binary (GLB) document describing a controller model
+    ///
+    /// Requires MSFT_controller_model.
+    pub fn load_controller_model_msft(&self, model_key: ControllerModelKeyMSFT) -> Result<Vec<u8>> {
+        let ext = self.controller_model_msft()?;
+        get_arr(|cap, count, buf| unsafe {
+            (ext.load_controller_model)(self.as_raw(), model_key, cap, count, buf)
+        })
+    }
+
+    /// Enumerate the animatable nodes of a controller model
+    ///
+    /// The order of the result matches the node poses returned by
+    /// [`Session::controller_model_state_msft`].
+    ///
+    /// Requires MSFT_controller_model.
+    pub fn controller_model_properties_msft(
+        &self,
+        model_key: ControllerModelKeyMSFT,
+    ) -> Result<Vec<ControllerModelNodeProperties>> {
+        let ext = self.controller_model_msft()?;
+        let init = sys::ControllerModelNodePropertiesMSFT::out(ptr::null_mut());
+        let nodes = get_arr_init(init, |cap, count, buf| unsafe {
+            let mut props = sys::ControllerModelPropertiesMSFT::out(ptr::null_mut());
+            (*props.as_mut_ptr()).node_capacity_input = cap;
+            (*props.as_mut_ptr()).node_count_output = 0;
+            (*props.as_mut_ptr()).node_properties = buf as _;
+            let r =
+                (ext.get_controller_model_properties)(self.as_raw(), model_key, props.as_mut_ptr());
+            *count = (*props.as_ptr()).node_count_output;
+            r
+        })?;
+        Ok(nodes
+            .iter()
+            .map(|x| unsafe {
+                let x = x.assume_init_ref();
+                ControllerModelNodeProperties {
+                    parent_node_name: fixed_str(&x.parent_node_name).into(),
+                    node_name: fixed_str(&x.node_name).into(),
+                }
+            })
+            .collect())
+    }
+
+    /// Current local poses of a controller model's animatable nodes, e.g. reflecting pressed
+    /// buttons
+    ///
+    /// Requires MSFT_controller_model.
+    pub fn controller_model_state_msft(
+        &self,
+        model_key: ControllerModelKeyMSFT,
+    ) -> Result<Vec<Posef>> {
+        let ext = self.controller_model_msft()?;
+        let init = sys::ControllerModelNodeStateMSFT::out(ptr::null_mut());
+        let nodes = get_arr_init(init, |cap, count, buf| unsafe {
+            let mut state = sys::ControllerModelStateMSFT::out(ptr::null_mut());
+            (*state.as_mut_ptr()).node_capacity_input = cap;
+            (*state.as_mut_ptr()).node_count_output = 0;
+            (*state.as_mut_ptr()).node_states = buf as _;
+            let r = (ext.get_controller_model_state)(self.as_raw(), model_key, state.as_mut_ptr());
+            *count = (*state.as_ptr()).node_count_output;
+            r
+        })?;
+        Ok(nodes
+            .iter()
+            .map(|x| unsafe { x.assume_init_ref().node_pose })
+            .collect())
+    }
+
+    fn controller_model_msft(&self) -> Result<&raw::ControllerModelMSFT> {
+        self.instance()
+            .exts()
+            .msft_controller_model
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)
+    }
+}
+
+/// Identifies an animatable node of a controller model
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ControllerModelNodeProperties {
+    /// Name of an ancestor of the node, or empty if `node_name` is unique within the model
+    pub parent_node_name: String,
+    pub node_name: String,
+}
+
+/// A node of the glTF document describing a controller model
+///
+/// Applications supply these from their glTF loader so that [`ControllerModelAnimator`] can
+/// resolve the runtime's node names without depending on a particular glTF implementation.
+#[derive(Debug, Copy, Clone)]
+pub struct GltfNode<'a> {
+    pub name: &'a str,
+    /// Index of the parent node in the same slice, or `None` for root nodes
+    pub parent: Option<usize>,
+}
+
+/// Maps runtime-provided controller model node states onto glTF node transforms
+///
+/// Construct once per loaded model, then call [`ControllerModelAnimator::apply`] each frame with
+/// the latest node states.
+#[derive(Debug, Clone)]
+pub struct ControllerModelAnimator {
+    /// For each runtime node, the index of the glTF node it drives
+    targets: Vec<Option<usize>>,
+}
+
+impl ControllerModelAnimator {
+    /// Resolve each of the runtime's animatable nodes to a glTF node
+    ///
+    /// `properties` must be in the order reported by the runtime, which is also the order of the
+    /// node states. Nodes that can't be found in `nodes` are ignored when animating.
+    pub fn new(properties: &[ControllerModelNodeProperties], nodes: &[GltfNode<'_>]) -> Self {
+        let targets = properties
+            .iter()
+            .map(|prop| {
+                nodes.iter().enumerate().position(|(index, node)| {
+                    node.name == prop.node_name
+                        && (prop.parent_node_name.is_empty()
+                            || has_ancestor(nodes, index, &prop.parent_node_name))
+                })
+            })
+            .collect();
+        Self { targets }
+    }
+
+    /// Index of the glTF node driven by the runtime node at `node_index`, if any
+    #[inline]
+    pub fn target(&self, node_index: usize) -> Option<usize> {
+        self.targets.get(node_index).copied().flatten()
+    }
+
+    /// Number of runtime nodes this animator was constructed for
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Overwrite the local transforms of animated nodes with the poses in `node_poses`
+    ///
+    /// `transforms` is indexed by glTF node and holds column-major local transform matrices, ready
+    /// to upload; entries for nodes not driven by the runtime are left untouched, so it should be
+    /// initialized with the model's rest transforms.
+    pub fn apply(&self, node_poses: &[Posef], transforms: &mut [[f32; 16]]) {
+        debug_assert_eq!(
+            node_poses.len(),
+            self.targets.len(),
+            "node state count doesn't match the model's node properties"
+        );
+        for (target, pose) in self.targets.iter().zip(node_poses) {
+            if let Some(transform) = target.and_then(|x| transforms.get_mut(x)) {
+                *transform = pose_to_matrix(pose);
+            }
+        }
+    }
+}
+
+fn has_ancestor(nodes: &[GltfNode<'_>], index: usize, name: &str) -> bool {
+    let mut current = nodes[index].parent;
+    // Bound the walk by the node count in case the supplied hierarchy contains a cycle
+    for _ in 0..nodes.len() {
+        let parent = match current {
+            Some(x) => x,
+            None => return false,
+        };
+        if nodes[parent].name == name {
+            return true;
+        }
+        current = nodes[parent].parent;
+    }
+    false
+}
+
+/// Convert a pose into a column-major rigid transformation matrix
+pub fn pose_to_matrix(pose: &Posef) -> [f32; 16] {
+    let Quaternionf { x, y, z, w } = pose.orientation;
+    let Vector3f {
+        x: tx,
+        y: ty,
+        z: tz,
+    } = pose.position;
+    let (x2, y2, z2) = (x + x, y + y, z + z);
+    let (xx, xy, xz) = (x * x2, x * y2, x * z2);
+    let (yy, yz, zz) = (y * y2, y * z2, z * z2);
+    let (wx, wy, wz) = (w * x2, w * y2, w * z2);
+    [
+        1.0 - (yy + zz),
+        xy + wz,
+        xz - wy,
+        0.0,
+        xy - wz,
+        1.0 - (xx + zz),
+        yz + wx,
+        0.0,
+        xz + wy,
+        yz - wx,
+        1.0 - (xx + yy),
+        0.0,
+        tx,
+        ty,
+        tz,
+        1.0,
+    ]
+}
@@ -0,0 +1,210 @@
+//! Simulated head and controllers for the null runtime, driven by mouse and keyboard.
+//!
+//! [`Entry::desktop_sim`] extends [`Entry::null`] with a head that can be turned and walked around
+//! and a pair of controllers implementing [`DesktopSim::INTERACTION_PROFILE`], so application logic
+//! can be iterated on without hardware. Applications feed window input to the returned
+//! [`DesktopSim`], e.g. mouse motion to [`DesktopSim::look`], WASD keys to [`DesktopSim::walk`], and
+//! mouse buttons to [`DesktopSim::set_select`].
+
+use std::f32::consts::FRAC_PI_2;
+
+use crate::null_runtime::{compose, quat_mul, with_sim, Input};
+use crate::*;
+
+impl Entry {
+    /// Access the null runtime with a simulated head and controllers, controlled through the
+    /// returned [`DesktopSim`]
+    ///
+    /// Available if the `desktop-sim` feature is enabled.
+    ///
+    /// Behaves as [`Entry::null`], except that the head follows the simulation and bindings
+    /// suggested for [`DesktopSim::INTERACTION_PROFILE`] drive action states and action spaces.
+    /// The simulation is shared by every null runtime instance in the process.
+    pub fn desktop_sim() -> (Self, DesktopSim) {
+        with_sim(|_| ());
+        (Self::null(), DesktopSim { _private: () })
+    }
+}
+
+/// A simulated controller
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SimHand {
+    Left,
+    Right,
+}
+
+impl SimHand {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Controls for the devices simulated by [`Entry::desktop_sim`]
+///
+/// Changes to buttons are observed by action states after the next [`Session::sync_actions`];
+/// the head and controllers move immediately.
+#[derive(Debug, Clone)]
+pub struct DesktopSim {
+    _private: (),
+}
+
+impl DesktopSim {
+    /// The interaction profile the simulated controllers implement
+    pub const INTERACTION_PROFILE: &'static str = "/interaction_profiles/khr/simple_controller";
+
+    /// Pose of the head in the stationary reference spaces
+    pub fn head_pose(&self) -> Posef {
+        with_sim(|sim| sim.head_pose())
+    }
+
+    /// Move the head to `position` in the stationary reference spaces
+    pub fn set_head_position(&self, position: Vector3f) {
+        with_sim(|sim| sim.position = position);
+    }
+
+    /// Turn the head by `yaw` radians to the left and tilt it by `pitch` radians upwards
+    ///
+    /// Pitch is limited to looking straight up or down.
+    pub fn look(&self, yaw: f32, pitch: f32) {
+        with_sim(|sim| {
+            sim.yaw += yaw;
+            sim.pitch = (sim.pitch + pitch).clamp(-FRAC_PI_2, FRAC_PI_2);
+        });
+    }
+
+    /// Move the head `forward`, `right`, and `up` meters relative to the direction it's facing,
+    /// ignoring its pitch
+    pub fn walk(&self, forward: f32, right: f32, up: f32) {
+        with_sim(|sim| {
+            let (sin, cos) = sim.yaw.sin_cos();
+            sim.position.x += -sin * forward + cos * right;
+            sim.position.y += up;
+            sim.position.z += -cos * forward - sin * right;
+        });
+    }
+
+    /// Hold the controller at `offset` from the head
+    ///
+    /// By default, the controllers are held 20cm to either side, 30cm below, and 40cm in front
+    /// of the head.
+    pub fn set_hand_offset(&self, hand: SimHand, offset: Posef) {
+        with_sim(|sim| sim.offsets[hand.index()] = offset);
+    }
+
+    /// Press or release the controller's select button
+    pub fn set_select(&self, hand: SimHand, pressed: bool) {
+        with_sim(|sim| sim.buttons[hand.index()].select = pressed);
+    }
+
+    /// Press or release the controller's menu button
+    pub fn set_menu(&self, hand: SimHand, pressed: bool) {
+        with_sim(|sim| sim.buttons[hand.index()].menu = pressed);
+    }
+
+    /// Return the head and controllers to their initial poses and release every button
+    pub fn reset(&self) {
+        with_sim(|sim| *sim = SimState::default());
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+struct Buttons {
+    select: bool,
+    menu: bool,
+}
+
+pub(crate) struct SimState {
+    position: Vector3f,
+    yaw: f32,
+    pitch: f32,
+    offsets: [Posef; 2],
+    buttons: [Buttons; 2],
+    /// Button states as of the latest sync
+    synced: [Buttons; 2],
+    /// Button states as of the sync before that
+    previous: [Buttons; 2],
+    synced_at: Time,
+}
+
+impl SimState {
+    pub(crate) fn head_pose(&self) -> Posef {
+        let (yaw_sin, yaw_cos) = (self.yaw / 2.0).sin_cos();
+        let (pitch_sin, pitch_cos) = (self.pitch / 2.0).sin_cos();
+        let yaw = Quaternionf {
+            x: 0.0,
+            y: yaw_sin,
+            z: 0.0,
+            w: yaw_cos,
+        };
+        let pitch = Quaternionf {
+            x: pitch_sin,
+            y: 0.0,
+            z: 0.0,
+            w: pitch_cos,
+        };
+        Posef {
+            orientation: quat_mul(&yaw, &pitch),
+            position: self.position,
+        }
+    }
+
+    /// State of the input at `binding`, if the simulated controllers have it
+    pub(crate) fn input(&self, binding: &str) -> Option<Input> {
+        let (hand, component) = if let Some(x) = binding.strip_prefix("/user/hand/left") {
+            (SimHand::Left.index(), x)
+        } else if let Some(x) = binding.strip_prefix("/user/hand/right") {
+            (SimHand::Right.index(), x)
+        } else {
+            return None;
+        };
+        let (synced, previous) = (self.synced[hand], self.previous[hand]);
+        Some(match component {
+            "/input/select/click" | "/input/select" => Input::Bool {
+                value: synced.select,
+                changed: synced.select != previous.select,
+            },
+            "/input/menu/click" | "/input/menu" => Input::Bool {
+                value: synced.menu,
+                changed: synced.menu != previous.menu,
+            },
+            "/input/grip/pose" | "/input/grip" | "/input/aim/pose" | "/input/aim" => {
+                Input::Pose(compose(&self.head_pose(), &self.offsets[hand]))
+            }
+            _ => return None,
+        })
+    }
+
+    /// Latch the current button states for action state queries
+    pub(crate) fn sync(&mut self, time: Time) {
+        self.previous = self.synced;
+        self.synced = self.buttons;
+        self.synced_at = time;
+    }
+
+    pub(crate) fn synced_at(&self) -> Time {
+        self.synced_at
+    }
+}
+
+impl Default for SimState {
+    fn default() -> Self {
+        let offset = |x| Posef {
+            orientation: Quaternionf::IDENTITY,
+            position: Vector3f {
+                x,
+                y: -0.3,
+                z: -0.4,
+            },
+        };
+        Self {
+            position: Vector3f::default(),
+            yaw: 0.0,
+            pitch: 0.0,
+            offsets: [offset(-0.2), offset(0.2)],
+            buttons: Default::default(),
+            synced: Default::default(),
+            previous: Default::default(),
+            synced_at: Time::from_nanos(0),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Polled connection and battery status of input devices.
+//!
+//! OpenXR has no standard battery input component, and none of the extensions in the registry
+//! these bindings are generated from report charge levels. [`DeviceStatusMonitor`] therefore
+//! derives connection status from the current interaction profile and, optionally, the activity of
+//! a pose action, while battery levels are read through an optional float action that applications
+//! bind to whichever vendor component exposes them, where a runtime offers one.
+
+use crate::*;
+
+/// Status of the device behind one top level user path, e.g. `/user/hand/left`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DeviceStatus {
+    /// Interaction profile currently in use for the path, if any
+    pub interaction_profile: Option<Path>,
+    /// Whether the monitor's pose action is active for the path
+    ///
+    /// `None` if the monitor has no pose action.
+    pub tracked: Option<bool>,
+    /// Charge level from 0 to 1 reported through the monitor's battery action
+    ///
+    /// `None` if the monitor has no battery action or the action isn't bound for the path.
+    pub battery: Option<f32>,
+}
+
+impl DeviceStatus {
+    /// Whether a device is present on the path
+    #[inline]
+    pub fn connected(&self) -> bool {
+        self.interaction_profile.is_some() && self.tracked != Some(false)
+    }
+}
+
+/// Polls [`DeviceStatus`] for a fixed set of top level user paths
+///
+/// Action state is only updated by [`Session::sync_actions`], which must be called with the
+/// action sets containing the monitor's actions before polling.
+pub struct DeviceStatusMonitor<G> {
+    session: Session<G>,
+    paths: Vec<Path>,
+    pose: Option<Action<Posef>>,
+    battery: Option<Action<f32>>,
+}
+
+impl<G> DeviceStatusMonitor<G> {
+    /// Monitor the devices on `paths`
+    pub fn new(session: &Session<G>, paths: &[Path]) -> Self {
+        Self {
+            session: session.clone(),
+            paths: paths.to_vec(),
+            pose: None,
+            battery: None,
+        }
+    }
+
+    /// Report whether `action` is active for each path as [`DeviceStatus::tracked`]
+    ///
+    /// `action` must have been created with the monitored paths as subaction paths.
+    pub fn with_pose_action(mut self, action: &Action<Posef>) -> Self {
+        self.pose = Some(action.clone());
+        self
+    }
+
+    /// Report the value of `action` for each path as [`DeviceStatus::battery`]
+    ///
+    /// `action` must have been created with the monitored paths as subaction paths, and bound
+    /// to a battery level component in each interaction profile that exposes one.
+    pub fn with_battery_action(mut self, action: &Action<f32>) -> Self {
+        self.battery = Some(action.clone());
+        self
+    }
+
+    #[inline]
+    pub fn paths(&self) -> &[Path] {
+        &self.paths
+    }
+
+    /// Query the status of the device on `path`, which needn't be one of the monitored paths
+    pub fn status(&self, path: Path) -> Result<DeviceStatus> {
+        let profile = self.session.current_interaction_profile(path)?;
+        let tracked = match self.pose {
+            Some(ref action) => Some(action.is_active(&self.session, path)?),
+            None => None,
+        };
+        let battery = match self.battery {
+            Some(ref action) => {
+                let state = action.state(&self.session, path)?;
+                if state.is_active {
+                    Some(state.current_state.clamp(0.0, 1.0))
+                } else {
+                    None
+                }
+            }
+            None => None,
+        };
+        Ok(DeviceStatus {
+            interaction_profile: if profile == Path::NULL {
+                None
+            } else {
+                Some(profile)
+            },
+            tracked,
+            battery,
+        })
+    }
+
+    /// Query the status of every monitored path
+    pub fn poll(&self) -> Result<Vec<(Path, DeviceStatus)>> {
+        self.paths
+            .iter()
+            .map(|&path| Ok((path, self.status(path)?)))
+            .collect()
+    }
+}
@@ -0,0 +1,219 @@
+//! Aggregation of frequent, individually uninteresting conditions into periodic warnings.
+//!
+//! Long sessions may lose events, have frames discarded, or time out waiting on swapchain images
+//! many times over. [`Diagnostics`] counts these and reports each kind at most once per interval,
+//! through the `log` crate if the `log` feature is enabled and through `tracing` if the `tracing`
+//! feature is enabled. Reports are also returned to the caller, so they can be surfaced without
+//! either.
+
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::*;
+
+/// A degraded condition counted by [`Diagnostics`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DiagnosticKind {
+    /// The runtime dropped events because they weren't polled quickly enough
+    EventsLost,
+    /// A frame was begun without the previous one being ended
+    FrameDiscarded,
+    /// Waiting on a swapchain image timed out
+    WaitTimeout,
+    /// A frame had more composition layers than the runtime supports
+    LayerLimitExceeded,
+}
+
+impl DiagnosticKind {
+    const ALL: [DiagnosticKind; 4] = [
+        DiagnosticKind::EventsLost,
+        DiagnosticKind::FrameDiscarded,
+        DiagnosticKind::WaitTimeout,
+        DiagnosticKind::LayerLimitExceeded,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match *self {
+            DiagnosticKind::EventsLost => "events lost",
+            DiagnosticKind::FrameDiscarded => "frames discarded",
+            DiagnosticKind::WaitTimeout => "swapchain wait timeouts",
+            DiagnosticKind::LayerLimitExceeded => "frames over the composition layer limit",
+        })
+    }
+}
+
+/// Occurrences of one [`DiagnosticKind`] since it was last reported
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DiagnosticReport {
+    pub kind: DiagnosticKind,
+    /// Occurrences since the previous report
+    pub count: u64,
+    /// Occurrences over the lifetime of the [`Diagnostics`]
+    pub total: u64,
+    /// Time covered by `count`
+    pub period: Duration,
+}
+
+impl fmt::Display for DiagnosticReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} in the last {:.1}s ({} total)",
+            self.count,
+            self.kind,
+            self.period.as_secs_f32(),
+            self.total
+        )
+    }
+}
+
+/// Rate-limited reporting of degraded conditions
+///
+/// Feed it events with [`Diagnostics::handle_event`] and call results with
+/// [`Diagnostics::record_result`]; anything not otherwise observable can be counted with
+/// [`Diagnostics::record`].
+pub struct Diagnostics {
+    interval: Duration,
+    counters: [Counter; 4],
+}
+
+#[derive(Copy, Clone)]
+struct Counter {
+    total: u64,
+    pending: u64,
+    since: Option<Instant>,
+}
+
+impl Diagnostics {
+    /// Report each kind of condition at most once per `interval`
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            counters: [Counter {
+                total: 0,
+                pending: 0,
+                since: None,
+            }; 4],
+        }
+    }
+
+    #[inline]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Count `count` occurrences of `kind`, reporting them if the interval has elapsed
+    pub fn record(&mut self, kind: DiagnosticKind, count: u64) -> Option<DiagnosticReport> {
+        if count == 0 {
+            return None;
+        }
+        let now = Instant::now();
+        let counter = &mut self.counters[kind.index()];
+        counter.total += count;
+        counter.pending += count;
+        match counter.since {
+            // Report the first occurrence immediately, so degradation is noticed promptly
+            None => {}
+            Some(since) if now.duration_since(since) >= self.interval => {}
+            Some(_) => return None,
+        }
+        Some(self.report(kind, now))
+    }
+
+    /// Count conditions signalled by `event`
+    pub fn handle_event(&mut self, event: &Event<'_>) -> Option<DiagnosticReport> {
+        match *event {
+            Event::EventsLost(e) => {
+                self.record(DiagnosticKind::EventsLost, e.lost_event_count().into())
+            }
+            _ => None,
+        }
+    }
+
+    /// Count conditions signalled by a success code, e.g. from [`FrameStream::begin`] or
+    /// [`Swapchain::wait_image`]
+    pub fn record_result(&mut self, result: sys::Result) -> Option<DiagnosticReport> {
+        match result {
+            sys::Result::FRAME_DISCARDED => self.record(DiagnosticKind::FrameDiscarded, 1),
+            sys::Result::TIMEOUT_EXPIRED => self.record(DiagnosticKind::WaitTimeout, 1),
+            _ => None,
+        }
+    }
+
+    /// Count a frame whose layers didn't fit within the runtime's limit
+    pub fn record_composition<G: Graphics>(
+        &mut self,
+        composition: &LayerComposition<'_, '_, G>,
+    ) -> Option<DiagnosticReport> {
+        if composition.exceeded_limit() {
+            self.record(DiagnosticKind::LayerLimitExceeded, 1)
+        } else {
+            None
+        }
+    }
+
+    /// Occurrences of `kind` over the lifetime of `self`
+    pub fn total(&self, kind: DiagnosticKind) -> u64 {
+        self.counters[kind.index()].total
+    }
+
+    /// Report every kind with occurrences not yet reported, regardless of the interval
+    ///
+    /// Useful when a session ends.
+    pub fn flush(&mut self) -> Vec<DiagnosticReport> {
+        let now = Instant::now();
+        let mut out = Vec::new();
+        for &kind in DiagnosticKind::ALL.iter() {
+            if self.counters[kind.index()].pending != 0 {
+                out.push(self.report(kind, now));
+            }
+        }
+        out
+    }
+
+    fn report(&mut self, kind: DiagnosticKind, now: Instant) -> DiagnosticReport {
+        let counter = &mut self.counters[kind.index()];
+        let report = DiagnosticReport {
+            kind,
+            count: counter.pending,
+            total: counter.total,
+            period: counter
+                .since
+                .map_or(Duration::from_secs(0), |since| now.duration_since(since)),
+        };
+        counter.pending = 0;
+        counter.since = Some(now);
+        emit(&report);
+        report
+    }
+}
+
+impl Default for Diagnostics {
+    /// Reports at most every five seconds
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5))
+    }
+}
+
+#[allow(unused_variables)]
+fn emit(report: &DiagnosticReport) {
+    #[cfg(feature = "log")]
+    log::warn!(target: "openxr", "{}", report);
+    #[cfg(feature = "tracing")]
+    tracing::warn!(
+        target: "openxr",
+        kind = ?report.kind,
+        count = report.count,
+        total = report.total,
+        "{}",
+        report
+    );
+}
@@ -38,7 +38,8 @@ impl Entry {
                     enumerate_instance_extension_properties:
                         sys::enumerate_instance_extension_properties,
                     enumerate_api_layer_properties: sys::enumerate_api_layer_properties,
-                },
+                }
+                .traced(),
                 #[cfg(feature = "loaded")]
                 _lib_guard: None,
             }),
@@ -89,7 +90,8 @@ impl Entry {
                     enumerate_api_layer_properties: *lib
                         .get(b"xrEnumerateApiLayerProperties\0")
                         .map_err(LoadError)?,
-                },
+                }
+                .traced(),
                 _lib_guard: Some(lib),
             }),
         })
@@ -127,7 +129,8 @@ impl Entry {
                         sys::Instance::NULL,
                         CStr::from_bytes_with_nul_unchecked(b"xrEnumerateApiLayerProperties\0"),
                     )?),
-                },
+                }
+                .traced(),
                 #[cfg(feature = "loaded")]
                 _lib_guard: None,
             }),
@@ -183,16 +186,6 @@ impl Entry {
         required_extensions: &ExtensionSet,
         layers: &[&str],
     ) -> Result<Instance> {
-        assert!(
-            app_info.application_name.len() < sys::MAX_APPLICATION_NAME_SIZE,
-            "application names are limited to {} bytes",
-            sys::MAX_APPLICATION_NAME_SIZE
-        );
-        assert!(
-            app_info.engine_name.len() < sys::MAX_ENGINE_NAME_SIZE,
-            "engine names are limited to {} bytes",
-            sys::MAX_ENGINE_NAME_SIZE
-        );
         let ext_names = required_extensions.names();
         let ext_ptrs = ext_names
             .iter()
@@ -243,11 +236,11 @@ impl Entry {
             enabled_extension_count: ext_ptrs.len() as _,
             enabled_extension_names: ext_ptrs.as_ptr(),
         };
-        place_cstr(
+        place_cstr_truncated(
             &mut info.application_info.application_name,
             app_info.application_name,
         );
-        place_cstr(&mut info.application_info.engine_name, app_info.engine_name);
+        place_cstr_truncated(&mut info.application_info.engine_name, app_info.engine_name);
         unsafe {
             let mut handle = sys::Instance::NULL;
             cvt((self.fp().create_instance)(&info, &mut handle))?;
@@ -259,6 +252,12 @@ impl Entry {
 
     /// Determine the set of extensions supported by this OpenXR implementation
     pub fn enumerate_extensions(&self) -> Result<ExtensionSet> {
+        Ok(ExtensionSet::from_properties(
+            &self.enumerate_extension_properties()?,
+        ))
+    }
+
+    pub(crate) fn enumerate_extension_properties(&self) -> Result<Vec<sys::ExtensionProperties>> {
         unsafe {
             let exts = get_arr_init(
                 sys::ExtensionProperties::out(ptr::null_mut()),
@@ -271,7 +270,7 @@ impl Entry {
                     )
                 },
             )?;
-            Ok(ExtensionSet::from_properties(mem::transmute(&exts[..])))
+            Ok(exts.into_iter().map(|x| x.assume_init()).collect())
         }
     }
 
@@ -305,6 +304,8 @@ unsafe fn get_instance_proc_addr_helper(
 ) -> Result<unsafe extern "system" fn()> {
     let mut f = None;
     cvt((get_instance_proc_addr)(instance, name.as_ptr(), &mut f))?;
+    #[cfg(feature = "ffi-trace")]
+    let f = f.map(|f| ffi_trace::wrap(name, f));
     Ok(f.unwrap())
 }
 
@@ -321,6 +322,42 @@ pub struct RawEntry {
     pub enumerate_api_layer_properties: sys::pfn::EnumerateApiLayerProperties,
 }
 
+impl RawEntry {
+    /// Log calls to the entry points if the `ffi-trace` feature is enabled
+    ///
+    /// Functions loaded through `get_instance_proc_addr` are wrapped as they are loaded.
+    #[inline]
+    fn traced(self) -> Self {
+        #[cfg(feature = "ffi-trace")]
+        {
+            fn wrap<T>(name: &[u8], f: T) -> T {
+                unsafe {
+                    let f = mem::transmute_copy::<T, sys::pfn::VoidFunction>(&f);
+                    let f = ffi_trace::wrap(CStr::from_bytes_with_nul_unchecked(name), f);
+                    mem::transmute_copy::<sys::pfn::VoidFunction, T>(&f)
+                }
+            }
+            return Self {
+                get_instance_proc_addr: wrap(
+                    b"xrGetInstanceProcAddr\0",
+                    self.get_instance_proc_addr,
+                ),
+                create_instance: wrap(b"xrCreateInstance\0", self.create_instance),
+                enumerate_instance_extension_properties: wrap(
+                    b"xrEnumerateInstanceExtensionProperties\0",
+                    self.enumerate_instance_extension_properties,
+                ),
+                enumerate_api_layer_properties: wrap(
+                    b"xrEnumerateApiLayerProperties\0",
+                    self.enumerate_api_layer_properties,
+                ),
+            };
+        }
+        #[allow(unreachable_code)]
+        self
+    }
+}
+
 /// An error encountered while loading entry points from a dynamic library at run time
 #[cfg(feature = "loaded")]
 pub struct LoadError(libloading::Error);
@@ -346,6 +383,11 @@ impl std::error::Error for LoadError {
     }
 }
 
+/// Identification of the application, for use by the runtime
+///
+/// Names may be localized. Names longer than `MAX_APPLICATION_NAME_SIZE - 1` or
+/// `MAX_ENGINE_NAME_SIZE - 1` bytes respectively are truncated to the last whole character that
+/// fits.
 #[derive(Debug, Copy, Clone, Default)]
 pub struct ApplicationInfo<'a> {
     pub application_name: &'a str,
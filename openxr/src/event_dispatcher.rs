@@ -0,0 +1,161 @@
+//! Routing of polled events to handlers registered by type.
+//!
+//! Rather than matching on every [`Event`] returned by [`Instance::poll_event`], applications can
+//! register a handler for each kind of event they care about with an [`EventDispatcher`], then
+//! call [`EventDispatcher::dispatch`] once per frame. Handlers are passed a mutable context of
+//! the application's choosing, so they can update application state without shared ownership.
+
+use crate::*;
+
+macro_rules! event_handlers {
+    ($($method:ident => $variant:ident,)*) => {
+        /// Calls handlers registered for each type of event polled from an instance
+        ///
+        /// `C` is the context passed to every handler by [`EventDispatcher::dispatch`].
+        ///
+        /// # Example
+        ///
+        /// ```no_run
+        /// # fn f(instance: &openxr::Instance, session: openxr::Session<openxr::Headless>) {
+        /// use openxr as xr;
+        /// struct App {
+        ///     session: xr::Session<xr::Headless>,
+        ///     running: bool,
+        /// }
+        /// let mut app = App { session, running: false };
+        /// let mut events = xr::EventDispatcher::new().on_session_state_changed(|app: &mut App, e| {
+        ///     match e.state() {
+        ///         xr::SessionState::READY => {
+        ///             app.session.begin(xr::ViewConfigurationType::PRIMARY_STEREO)?;
+        ///             app.running = true;
+        ///         }
+        ///         xr::SessionState::STOPPING => {
+        ///             app.session.end()?;
+        ///             app.running = false;
+        ///         }
+        ///         _ => {}
+        ///     }
+        ///     Ok(())
+        /// });
+        /// loop {
+        ///     events.dispatch(instance, &mut app).unwrap();
+        ///     // ...
+        /// }
+        /// # }
+        /// ```
+        pub struct EventDispatcher<C> {
+            buffer: EventDataBuffer,
+            any: Vec<Box<dyn FnMut(&mut C, &Event<'_>) -> Result<()>>>,
+            $($method: Vec<Box<dyn FnMut(&mut C, $variant<'_>) -> Result<()>>>,)*
+            raw: Vec<Box<dyn FnMut(&mut C, RawEvent<'_>) -> Result<()>>>,
+        }
+
+        impl<C> EventDispatcher<C> {
+            pub fn new() -> Self {
+                Self {
+                    buffer: EventDataBuffer::new(),
+                    any: Vec::new(),
+                    $($method: Vec::new(),)*
+                    raw: Vec::new(),
+                }
+            }
+
+            $(
+                #[doc = concat!("Call `handler` for each [`Event::", stringify!($variant), "`]")]
+                pub fn $method(
+                    mut self,
+                    handler: impl FnMut(&mut C, $variant<'_>) -> Result<()> + 'static,
+                ) -> Self {
+                    self.$method.push(Box::new(handler));
+                    self
+                }
+            )*
+
+            /// Call `handler` for each [`Event::Raw`], i.e. events of types these bindings don't
+            /// decode
+            pub fn on_raw(
+                mut self,
+                handler: impl FnMut(&mut C, RawEvent<'_>) -> Result<()> + 'static,
+            ) -> Self {
+                self.raw.push(Box::new(handler));
+                self
+            }
+
+            /// Call `handler` for every event, before any handlers registered for its type
+            pub fn on_any(
+                mut self,
+                handler: impl FnMut(&mut C, &Event<'_>) -> Result<()> + 'static,
+            ) -> Self {
+                self.any.push(Box::new(handler));
+                self
+            }
+
+            /// Poll every pending event from `instance` and pass each to its handlers, returning
+            /// the number of events dispatched
+            ///
+            /// Stops at the first error returned by a handler, leaving any later events queued for
+            /// the next call.
+            pub fn dispatch(&mut self, instance: &Instance, cx: &mut C) -> Result<usize> {
+                let mut count = 0;
+                while let Some(event) = instance.poll_event(&mut self.buffer)? {
+                    count += 1;
+                    for handler in &mut self.any {
+                        handler(cx, &event)?;
+                    }
+                    match event {
+                        $(
+                            Event::$variant(e) => {
+                                for handler in &mut self.$method {
+                                    handler(cx, e)?;
+                                }
+                            }
+                        )*
+                        Event::Raw(e) => {
+                            for handler in &mut self.raw {
+                                handler(cx, e)?;
+                            }
+                        }
+                    }
+                }
+                Ok(count)
+            }
+        }
+    };
+}
+
+event_handlers! {
+    on_events_lost => EventsLost,
+    on_instance_loss_pending => InstanceLossPending,
+    on_session_state_changed => SessionStateChanged,
+    on_reference_space_change_pending => ReferenceSpaceChangePending,
+    on_perf_settings_ext => PerfSettingsEXT,
+    on_visibility_mask_changed_khr => VisibilityMaskChangedKHR,
+    on_interaction_profile_changed => InteractionProfileChanged,
+    on_main_session_visibility_changed_extx => MainSessionVisibilityChangedEXTX,
+    on_display_refresh_rate_changed_fb => DisplayRefreshRateChangedFB,
+    on_spatial_anchor_create_complete_fb => SpatialAnchorCreateCompleteFB,
+    on_space_set_status_complete_fb => SpaceSetStatusCompleteFB,
+    on_space_query_results_available_fb => SpaceQueryResultsAvailableFB,
+    on_space_query_complete_fb => SpaceQueryCompleteFB,
+    on_space_save_complete_fb => SpaceSaveCompleteFB,
+    on_space_erase_complete_fb => SpaceEraseCompleteFB,
+    on_space_share_complete_fb => SpaceShareCompleteFB,
+    on_space_list_save_complete_fb => SpaceListSaveCompleteFB,
+    on_scene_capture_complete_fb => SceneCaptureCompleteFB,
+    on_passthrough_state_changed_fb => PassthroughStateChangedFB,
+    on_vive_tracker_connected_htcx => ViveTrackerConnectedHTCX,
+    on_marker_tracking_update_varjo => MarkerTrackingUpdateVARJO,
+    on_virtual_keyboard_commit_text_meta => VirtualKeyboardCommitTextMETA,
+    on_virtual_keyboard_backspace_meta => VirtualKeyboardBackspaceMETA,
+    on_virtual_keyboard_enter_meta => VirtualKeyboardEnterMETA,
+    on_virtual_keyboard_shown_meta => VirtualKeyboardShownMETA,
+    on_virtual_keyboard_hidden_meta => VirtualKeyboardHiddenMETA,
+    on_headset_fit_changed_ml => HeadsetFitChangedML,
+    on_eye_calibration_changed_ml => EyeCalibrationChangedML,
+}
+
+impl<C> Default for EventDispatcher<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,238 @@
+//! Formatting and serialization of the fields of [`Event`]s, used by the generated `Debug` and
+//! `Serialize` impls of the event types.
+//!
+//! Fields are represented as a reader would want them in a log: strings rather than `c_char`
+//! arrays, UUIDs in their usual hyphenated form, and, when serialized, enumerants by name and
+//! handles, paths and flags by their raw value.
+
+use std::{fmt, os::raw::c_char};
+
+#[cfg(feature = "serde")]
+use serde::ser::{SerializeStruct, Serializer};
+
+use crate::*;
+
+/// Adapts an event field to `Debug` and `Serialize`
+pub(crate) struct Field<'a, T: ?Sized>(pub &'a T);
+
+impl<T: EventField + ?Sized> fmt::Debug for Field<'_, T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_field(fmt)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: EventField + ?Sized> serde::Serialize for Field<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.0.serialize_field(serializer)
+    }
+}
+
+pub(crate) trait EventField {
+    fn fmt_field(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    #[cfg(feature = "serde")]
+    fn serialize_field<S: Serializer>(&self, serializer: S)
+        -> std::result::Result<S::Ok, S::Error>;
+}
+
+/// Types which are their own representation
+macro_rules! plain_fields {
+    ($($ty:ty),*) => {$(
+        impl EventField for $ty {
+            fn fmt_field(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(self, fmt)
+            }
+
+            #[cfg(feature = "serde")]
+            fn serialize_field<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(self, serializer)
+            }
+        }
+    )*};
+}
+
+plain_fields!(bool, f32, u32, u64);
+
+/// Enumerations, serialized by name
+macro_rules! enum_fields {
+    ($($ty:ty),*) => {$(
+        impl EventField for $ty {
+            fn fmt_field(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(self, fmt)
+            }
+
+            #[cfg(feature = "serde")]
+            fn serialize_field<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.collect_str(&format_args!("{:?}", self))
+            }
+        }
+    )*};
+}
+
+enum_fields!(
+    sys::Result,
+    SessionState,
+    ReferenceSpaceType,
+    ViewConfigurationType,
+    PerfSettingsDomainEXT,
+    PerfSettingsSubDomainEXT,
+    PerfSettingsNotificationLevelEXT,
+    SpaceComponentTypeFB,
+    SpaceStorageLocationFB,
+    EyeCalibrationStatusML,
+    HeadsetFitStatusML,
+    LocalizationMapConfidenceML,
+    LocalizationMapStateML,
+    sys::LocalizationMapTypeML
+);
+
+/// Handles, atoms and flags, serialized by raw value
+macro_rules! raw_fields {
+    ($($ty:ty),*) => {$(
+        impl EventField for $ty {
+            fn fmt_field(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(self, fmt)
+            }
+
+            #[cfg(feature = "serde")]
+            fn serialize_field<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_u64(self.into_raw())
+            }
+        }
+    )*};
+}
+
+raw_fields!(
+    sys::Session,
+    sys::Space,
+    sys::VirtualKeyboardMETA,
+    AsyncRequestIdFB,
+    Path,
+    OverlayMainSessionFlagsEXTX,
+    PassthroughStateChangedFlagsFB,
+    LocalizationMapErrorFlagsML
+);
+
+impl EventField for Time {
+    fn fmt_field(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, fmt)
+    }
+
+    /// Nanoseconds
+    #[cfg(feature = "serde")]
+    fn serialize_field<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.as_nanos())
+    }
+}
+
+impl EventField for Posef {
+    fn fmt_field(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, fmt)
+    }
+
+    #[cfg(feature = "serde")]
+    fn serialize_field<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let Quaternionf { x, y, z, w } = self.orientation;
+        let p = self.position;
+        let mut s = serializer.serialize_struct("Posef", 2)?;
+        s.serialize_field("orientation", &[x, y, z, w])?;
+        s.serialize_field("position", &[p.x, p.y, p.z])?;
+        s.end()
+    }
+}
+
+impl EventField for UuidEXT {
+    fn fmt_field(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, byte) in self.data.iter().enumerate() {
+            if let 4 | 6 | 8 | 10 = i {
+                fmt.write_str("-")?;
+            }
+            write!(fmt, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    fn serialize_field<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:?}", Field(self)))
+    }
+}
+
+impl EventField for ViveTrackerPathsHTCX {
+    fn fmt_field(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, fmt)
+    }
+
+    #[cfg(feature = "serde")]
+    fn serialize_field<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("ViveTrackerPathsHTCX", 2)?;
+        s.serialize_field("persistent", &Field(&self.persistent))?;
+        s.serialize_field("role", &self.role.map(Path::into_raw))?;
+        s.end()
+    }
+}
+
+impl EventField for &'_ sys::LocalizationMapML {
+    fn fmt_field(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("LocalizationMapML")
+            .field("name", &Field(&self.name))
+            .field("map_uuid", &Field(&self.map_uuid))
+            .field("map_type", &self.map_type)
+            .finish()
+    }
+
+    #[cfg(feature = "serde")]
+    fn serialize_field<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("LocalizationMapML", 3)?;
+        s.serialize_field("name", &Field(&self.name))?;
+        s.serialize_field("map_uuid", &Field(&self.map_uuid))?;
+        s.serialize_field("map_type", &Field(&self.map_type))?;
+        s.end()
+    }
+}
+
+/// Null-terminated strings, up to the first null
+impl<const N: usize> EventField for [c_char; N] {
+    fn fmt_field(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&fixed_str_lossy(self), fmt)
+    }
+
+    #[cfg(feature = "serde")]
+    fn serialize_field<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&fixed_str_lossy(self))
+    }
+}
+
+fn fixed_str_lossy(x: &[c_char]) -> String {
+    let end = x.iter().position(|&x| x == 0).unwrap_or(x.len());
+    let bytes = x[..end].iter().map(|&x| x as u8).collect::<Vec<_>>();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
@@ -0,0 +1,107 @@
+//! Instance events as an asynchronous [`Stream`].
+//!
+//! OpenXR has no way to wake a task when an event arrives, so an [`EventStream`] checks for events
+//! whenever a tick stream supplied by the application yields, e.g. an interval timer or a
+//! per-frame signal from the engine. Events already pending are yielded without waiting for a
+//! tick.
+
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+
+use crate::*;
+
+/// A [`Stream`] of the events of an instance, checked for each time `T` yields
+///
+/// Ends when `T` does. Yields an error and keeps going if polling the instance fails.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn f(instance: openxr::Instance, ticks: impl futures_core::Stream<Item = ()> + Unpin) {
+/// use futures_core::Stream;
+/// use std::{future, pin::Pin};
+///
+/// let mut events = openxr::EventStream::new(instance, ticks);
+/// // Or `futures::StreamExt::next`
+/// while let Some(event) = future::poll_fn(|cx| Pin::new(&mut events).poll_next(cx)).await {
+///     if let openxr::Event::SessionStateChanged(e) = event.unwrap().event() {
+///         println!("session state changed to {:?}", e.state());
+///     }
+/// }
+/// # }
+/// ```
+pub struct EventStream<T> {
+    instance: Instance,
+    ticks: T,
+    /// Storage for the next event, reused until an event is yielded in it
+    spare: Option<Box<EventDataBuffer>>,
+}
+
+impl<T: Stream + Unpin> EventStream<T> {
+    pub fn new(instance: Instance, ticks: T) -> Self {
+        Self {
+            instance,
+            ticks,
+            spare: None,
+        }
+    }
+
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.instance
+    }
+
+    pub fn into_inner(self) -> (Instance, T) {
+        (self.instance, self.ticks)
+    }
+}
+
+impl<T: Stream + Unpin> Stream for EventStream<T> {
+    type Item = Result<PolledEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            let buffer = this
+                .spare
+                .get_or_insert_with(|| Box::new(EventDataBuffer::new()));
+            match this.instance.poll_event(buffer) {
+                Ok(Some(_)) => {
+                    let buffer = this.spare.take().unwrap();
+                    return Poll::Ready(Some(Ok(PolledEvent { buffer })));
+                }
+                Ok(None) => {}
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+            match Pin::new(&mut this.ticks).poll_next(cx) {
+                Poll::Ready(Some(_)) => {}
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// An event yielded by [`EventStream`], owning the storage it was polled into
+pub struct PolledEvent {
+    buffer: Box<EventDataBuffer>,
+}
+
+impl PolledEvent {
+    #[inline]
+    pub fn event(&self) -> Event<'_> {
+        // Safety: only constructed after `xrPollEvent` populated the buffer
+        unsafe { Event::from_raw(&self.buffer.inner).unwrap() }
+    }
+}
+
+impl fmt::Debug for PolledEvent {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("PolledEvent").field(&self.event()).finish()
+    }
+}
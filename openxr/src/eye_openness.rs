@@ -0,0 +1,76 @@
+//! How far each eye is open, from whichever face tracking extension is available.
+//!
+//! Eyelid animation for avatars only needs a single value per eye, but vendors report it in
+//! different ways: `XR_FB_face_tracking2` as an eyes-closed blend shape, and
+//! `XR_HTC_facial_tracking` as a blink expression. [`EyeOpennessTracker`] reads either and
+//! normalizes them so that 0 is closed and 1 is fully open.
+
+use crate::*;
+
+/// Openness of each eye, from 0 for closed to 1 for fully open
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EyeOpenness {
+    pub left: f32,
+    pub right: f32,
+    pub time: Time,
+}
+
+/// A face tracker able to report [`EyeOpenness`]
+pub enum EyeOpennessTracker {
+    FaceTracker2FB(FaceTracker2FB),
+    /// Must have been created with `FacialTrackingTypeHTC::EYE_DEFAULT`
+    FacialTrackerHTC(FacialTrackerHTC),
+}
+
+impl EyeOpennessTracker {
+    /// Create a tracker using the first of `XR_FB_face_tracking2` and `XR_HTC_facial_tracking`
+    /// that is enabled
+    ///
+    /// Fails with `ERROR_EXTENSION_NOT_PRESENT` if neither is.
+    pub fn new<G>(session: &Session<G>) -> Result<Self> {
+        let exts = session.instance().exts();
+        if exts.fb_face_tracking2.is_some() {
+            Ok(EyeOpennessTracker::FaceTracker2FB(
+                session.create_face_tracker2_fb(true, false)?,
+            ))
+        } else if exts.htc_facial_tracking.is_some() {
+            Ok(EyeOpennessTracker::FacialTrackerHTC(
+                session.create_facial_tracker_htc(FacialTrackingTypeHTC::EYE_DEFAULT)?,
+            ))
+        } else {
+            Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT)
+        }
+    }
+
+    /// Openness of each eye at `time`, or `None` if the eyes aren't currently tracked
+    ///
+    /// `XR_HTC_facial_tracking` only reports the latest sample, so `time` is ignored for it.
+    pub fn get(&self, time: Time) -> Result<Option<EyeOpenness>> {
+        match self {
+            EyeOpennessTracker::FaceTracker2FB(tracker) => {
+                // The second expression set keeps the first set's indices for these blend shapes
+                let closed = |expression: FaceExpressionFB| expression.into_raw() as usize;
+                Ok(tracker
+                    .get_face_expression_weights(time)?
+                    .map(|x| EyeOpenness {
+                        left: openness(x.weights[closed(FaceExpressionFB::EYES_CLOSED_L)]),
+                        right: openness(x.weights[closed(FaceExpressionFB::EYES_CLOSED_R)]),
+                        time: x.time,
+                    }))
+            }
+            EyeOpennessTracker::FacialTrackerHTC(tracker) => {
+                let blink = |expression: EyeExpressionHTC| expression.into_raw() as usize;
+                Ok(tracker.get_facial_expressions()?.map(|x| EyeOpenness {
+                    left: openness(x.weights[blink(EyeExpressionHTC::LEFT_BLINK)]),
+                    right: openness(x.weights[blink(EyeExpressionHTC::RIGHT_BLINK)]),
+                    time: x.sample_time,
+                }))
+            }
+        }
+    }
+}
+
+/// Invert a closed or blink weight
+fn openness(closed: f32) -> f32 {
+    (1.0 - closed).clamp(0.0, 1.0)
+}
@@ -1,4 +1,8 @@
-use std::{mem::MaybeUninit, ptr, sync::Arc};
+use std::{
+    mem::{self, MaybeUninit},
+    ptr,
+    sync::Arc,
+};
 
 use crate::*;
 
@@ -13,6 +17,19 @@ impl FaceTracker2FB {
         self.handle
     }
 
+    /// Give up ownership of the handle without destroying it
+    ///
+    /// The caller becomes responsible for destroying the returned handle.
+    #[inline]
+    pub fn into_raw(self) -> sys::FaceTracker2FB {
+        let mut this = mem::ManuallyDrop::new(self);
+        // Release everything but the handle, which `Drop` would destroy
+        unsafe {
+            ptr::drop_in_place(&mut this.session);
+        }
+        this.handle
+    }
+
     /// Take ownership of an existing face tracker
     ///
     /// # Safety
@@ -50,25 +67,19 @@ impl FaceTracker2FB {
         let mut weights = MaybeUninit::<[f32; FACE_EXPRESSION2_COUNT]>::uninit();
         let mut confidences = [0.0; FACE_CONFIDENCE2_COUNT];
 
-        let mut expression_weights = sys::FaceExpressionWeights2FB {
-            ty: sys::FaceExpressionWeights2FB::TYPE,
-            next: ptr::null_mut(),
-            weight_count: FACE_EXPRESSION2_COUNT as u32,
-            weights: weights.as_mut_ptr() as _,
-            confidence_count: FACE_CONFIDENCE2_COUNT as u32,
-            confidences: confidences.as_mut_ptr() as _,
-            is_valid: sys::FALSE,
-            is_eye_following_blendshapes_valid: sys::FALSE,
-            data_source: sys::FaceTrackingDataSource2FB::from_raw(0),
-            time,
-        };
+        let mut expression_weights = sys::FaceExpressionWeights2FB::out(ptr::null_mut());
 
         unsafe {
+            (*expression_weights.as_mut_ptr()).weight_count = FACE_EXPRESSION2_COUNT as u32;
+            (*expression_weights.as_mut_ptr()).weights = weights.as_mut_ptr() as _;
+            (*expression_weights.as_mut_ptr()).confidence_count = FACE_CONFIDENCE2_COUNT as u32;
+            (*expression_weights.as_mut_ptr()).confidences = confidences.as_mut_ptr();
             cvt((self.fp().get_face_expression_weights2)(
                 self.handle,
                 &expression_info,
-                &mut expression_weights,
+                expression_weights.as_mut_ptr(),
             ))?;
+            let expression_weights = expression_weights.assume_init();
 
             if expression_weights.is_valid.into() {
                 Ok(Some(FaceExpressionWeights2FB {
@@ -0,0 +1,4717 @@
+//! Automatically generated code; do not edit!
+
+#![allow(unused, non_snake_case, clippy::too_many_arguments)]
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use libc::{timespec, wchar_t};
+use sys::platform::*;
+use sys::*;
+
+// Mirrors the private alias in openxr-sys
+type DevicePcmSampleRateGetInfoFB = DevicePcmSampleRateStateFB;
+
+/// Substitute a wrapper that logs each call to `f`, the implementation of the function `name`
+///
+/// Functions without a wrapper, and wrappers themselves, are returned unchanged. Each wrapper
+/// forwards to a single process-wide implementation: the first one it was substituted for.
+/// Any other implementation of the same function, e.g. one loaded from a second runtime, is
+/// returned unchanged and its calls aren't logged, with a warning the first time that happens.
+pub unsafe fn wrap(name: &CStr, f: pfn::VoidFunction) -> pfn::VoidFunction {
+    match name.to_bytes_with_nul() {
+        b"xrGetInstanceProcAddr\0" => substitute(
+            &GET_INSTANCE_PROC_ADDR,
+            f,
+            mem::transmute::<pfn::GetInstanceProcAddr, pfn::VoidFunction>(get_instance_proc_addr),
+        ),
+        b"xrEnumerateApiLayerProperties\0" => substitute(
+            &ENUMERATE_API_LAYER_PROPERTIES,
+            f,
+            mem::transmute::<pfn::EnumerateApiLayerProperties, pfn::VoidFunction>(
+                enumerate_api_layer_properties,
+            ),
+        ),
+        b"xrEnumerateInstanceExtensionProperties\0" => substitute(
+            &ENUMERATE_INSTANCE_EXTENSION_PROPERTIES,
+            f,
+            mem::transmute::<pfn::EnumerateInstanceExtensionProperties, pfn::VoidFunction>(
+                enumerate_instance_extension_properties,
+            ),
+        ),
+        b"xrCreateInstance\0" => substitute(
+            &CREATE_INSTANCE,
+            f,
+            mem::transmute::<pfn::CreateInstance, pfn::VoidFunction>(create_instance),
+        ),
+        b"xrDestroyInstance\0" => substitute(
+            &DESTROY_INSTANCE,
+            f,
+            mem::transmute::<pfn::DestroyInstance, pfn::VoidFunction>(destroy_instance),
+        ),
+        b"xrResultToString\0" => substitute(
+            &RESULT_TO_STRING,
+            f,
+            mem::transmute::<pfn::ResultToString, pfn::VoidFunction>(result_to_string),
+        ),
+        b"xrStructureTypeToString\0" => substitute(
+            &STRUCTURE_TYPE_TO_STRING,
+            f,
+            mem::transmute::<pfn::StructureTypeToString, pfn::VoidFunction>(
+                structure_type_to_string,
+            ),
+        ),
+        b"xrGetInstanceProperties\0" => substitute(
+            &GET_INSTANCE_PROPERTIES,
+            f,
+            mem::transmute::<pfn::GetInstanceProperties, pfn::VoidFunction>(
+                get_instance_properties,
+            ),
+        ),
+        b"xrGetSystem\0" => substitute(
+            &GET_SYSTEM,
+            f,
+            mem::transmute::<pfn::GetSystem, pfn::VoidFunction>(get_system),
+        ),
+        b"xrGetSystemProperties\0" => substitute(
+            &GET_SYSTEM_PROPERTIES,
+            f,
+            mem::transmute::<pfn::GetSystemProperties, pfn::VoidFunction>(get_system_properties),
+        ),
+        b"xrCreateSession\0" => substitute(
+            &CREATE_SESSION,
+            f,
+            mem::transmute::<pfn::CreateSession, pfn::VoidFunction>(create_session),
+        ),
+        b"xrDestroySession\0" => substitute(
+            &DESTROY_SESSION,
+            f,
+            mem::transmute::<pfn::DestroySession, pfn::VoidFunction>(destroy_session),
+        ),
+        b"xrDestroySpace\0" => substitute(
+            &DESTROY_SPACE,
+            f,
+            mem::transmute::<pfn::DestroySpace, pfn::VoidFunction>(destroy_space),
+        ),
+        b"xrEnumerateSwapchainFormats\0" => substitute(
+            &ENUMERATE_SWAPCHAIN_FORMATS,
+            f,
+            mem::transmute::<pfn::EnumerateSwapchainFormats, pfn::VoidFunction>(
+                enumerate_swapchain_formats,
+            ),
+        ),
+        b"xrCreateSwapchain\0" => substitute(
+            &CREATE_SWAPCHAIN,
+            f,
+            mem::transmute::<pfn::CreateSwapchain, pfn::VoidFunction>(create_swapchain),
+        ),
+        b"xrDestroySwapchain\0" => substitute(
+            &DESTROY_SWAPCHAIN,
+            f,
+            mem::transmute::<pfn::DestroySwapchain, pfn::VoidFunction>(destroy_swapchain),
+        ),
+        b"xrEnumerateSwapchainImages\0" => substitute(
+            &ENUMERATE_SWAPCHAIN_IMAGES,
+            f,
+            mem::transmute::<pfn::EnumerateSwapchainImages, pfn::VoidFunction>(
+                enumerate_swapchain_images,
+            ),
+        ),
+        b"xrAcquireSwapchainImage\0" => substitute(
+            &ACQUIRE_SWAPCHAIN_IMAGE,
+            f,
+            mem::transmute::<pfn::AcquireSwapchainImage, pfn::VoidFunction>(
+                acquire_swapchain_image,
+            ),
+        ),
+        b"xrWaitSwapchainImage\0" => substitute(
+            &WAIT_SWAPCHAIN_IMAGE,
+            f,
+            mem::transmute::<pfn::WaitSwapchainImage, pfn::VoidFunction>(wait_swapchain_image),
+        ),
+        b"xrReleaseSwapchainImage\0" => substitute(
+            &RELEASE_SWAPCHAIN_IMAGE,
+            f,
+            mem::transmute::<pfn::ReleaseSwapchainImage, pfn::VoidFunction>(
+                release_swapchain_image,
+            ),
+        ),
+        b"xrBeginSession\0" => substitute(
+            &BEGIN_SESSION,
+            f,
+            mem::transmute::<pfn::BeginSession, pfn::VoidFunction>(begin_session),
+        ),
+        b"xrEndSession\0" => substitute(
+            &END_SESSION,
+            f,
+            mem::transmute::<pfn::EndSession, pfn::VoidFunction>(end_session),
+        ),
+        b"xrRequestExitSession\0" => substitute(
+            &REQUEST_EXIT_SESSION,
+            f,
+            mem::transmute::<pfn::RequestExitSession, pfn::VoidFunction>(request_exit_session),
+        ),
+        b"xrEnumerateReferenceSpaces\0" => substitute(
+            &ENUMERATE_REFERENCE_SPACES,
+            f,
+            mem::transmute::<pfn::EnumerateReferenceSpaces, pfn::VoidFunction>(
+                enumerate_reference_spaces,
+            ),
+        ),
+        b"xrCreateReferenceSpace\0" => substitute(
+            &CREATE_REFERENCE_SPACE,
+            f,
+            mem::transmute::<pfn::CreateReferenceSpace, pfn::VoidFunction>(create_reference_space),
+        ),
+        b"xrCreateActionSpace\0" => substitute(
+            &CREATE_ACTION_SPACE,
+            f,
+            mem::transmute::<pfn::CreateActionSpace, pfn::VoidFunction>(create_action_space),
+        ),
+        b"xrLocateSpace\0" => substitute(
+            &LOCATE_SPACE,
+            f,
+            mem::transmute::<pfn::LocateSpace, pfn::VoidFunction>(locate_space),
+        ),
+        b"xrEnumerateViewConfigurations\0" => substitute(
+            &ENUMERATE_VIEW_CONFIGURATIONS,
+            f,
+            mem::transmute::<pfn::EnumerateViewConfigurations, pfn::VoidFunction>(
+                enumerate_view_configurations,
+            ),
+        ),
+        b"xrEnumerateEnvironmentBlendModes\0" => substitute(
+            &ENUMERATE_ENVIRONMENT_BLEND_MODES,
+            f,
+            mem::transmute::<pfn::EnumerateEnvironmentBlendModes, pfn::VoidFunction>(
+                enumerate_environment_blend_modes,
+            ),
+        ),
+        b"xrGetViewConfigurationProperties\0" => substitute(
+            &GET_VIEW_CONFIGURATION_PROPERTIES,
+            f,
+            mem::transmute::<pfn::GetViewConfigurationProperties, pfn::VoidFunction>(
+                get_view_configuration_properties,
+            ),
+        ),
+        b"xrEnumerateViewConfigurationViews\0" => substitute(
+            &ENUMERATE_VIEW_CONFIGURATION_VIEWS,
+            f,
+            mem::transmute::<pfn::EnumerateViewConfigurationViews, pfn::VoidFunction>(
+                enumerate_view_configuration_views,
+            ),
+        ),
+        b"xrBeginFrame\0" => substitute(
+            &BEGIN_FRAME,
+            f,
+            mem::transmute::<pfn::BeginFrame, pfn::VoidFunction>(begin_frame),
+        ),
+        b"xrLocateViews\0" => substitute(
+            &LOCATE_VIEWS,
+            f,
+            mem::transmute::<pfn::LocateViews, pfn::VoidFunction>(locate_views),
+        ),
+        b"xrEndFrame\0" => substitute(
+            &END_FRAME,
+            f,
+            mem::transmute::<pfn::EndFrame, pfn::VoidFunction>(end_frame),
+        ),
+        b"xrWaitFrame\0" => substitute(
+            &WAIT_FRAME,
+            f,
+            mem::transmute::<pfn::WaitFrame, pfn::VoidFunction>(wait_frame),
+        ),
+        b"xrApplyHapticFeedback\0" => substitute(
+            &APPLY_HAPTIC_FEEDBACK,
+            f,
+            mem::transmute::<pfn::ApplyHapticFeedback, pfn::VoidFunction>(apply_haptic_feedback),
+        ),
+        b"xrStopHapticFeedback\0" => substitute(
+            &STOP_HAPTIC_FEEDBACK,
+            f,
+            mem::transmute::<pfn::StopHapticFeedback, pfn::VoidFunction>(stop_haptic_feedback),
+        ),
+        b"xrPollEvent\0" => substitute(
+            &POLL_EVENT,
+            f,
+            mem::transmute::<pfn::PollEvent, pfn::VoidFunction>(poll_event),
+        ),
+        b"xrStringToPath\0" => substitute(
+            &STRING_TO_PATH,
+            f,
+            mem::transmute::<pfn::StringToPath, pfn::VoidFunction>(string_to_path),
+        ),
+        b"xrPathToString\0" => substitute(
+            &PATH_TO_STRING,
+            f,
+            mem::transmute::<pfn::PathToString, pfn::VoidFunction>(path_to_string),
+        ),
+        b"xrGetReferenceSpaceBoundsRect\0" => substitute(
+            &GET_REFERENCE_SPACE_BOUNDS_RECT,
+            f,
+            mem::transmute::<pfn::GetReferenceSpaceBoundsRect, pfn::VoidFunction>(
+                get_reference_space_bounds_rect,
+            ),
+        ),
+        #[cfg(target_os = "android")]
+        b"xrSetAndroidApplicationThreadKHR\0" => substitute(
+            &SET_ANDROID_APPLICATION_THREAD_KHR,
+            f,
+            mem::transmute::<pfn::SetAndroidApplicationThreadKHR, pfn::VoidFunction>(
+                set_android_application_thread_khr,
+            ),
+        ),
+        #[cfg(target_os = "android")]
+        b"xrCreateSwapchainAndroidSurfaceKHR\0" => substitute(
+            &CREATE_SWAPCHAIN_ANDROID_SURFACE_KHR,
+            f,
+            mem::transmute::<pfn::CreateSwapchainAndroidSurfaceKHR, pfn::VoidFunction>(
+                create_swapchain_android_surface_khr,
+            ),
+        ),
+        b"xrGetActionStateBoolean\0" => substitute(
+            &GET_ACTION_STATE_BOOLEAN,
+            f,
+            mem::transmute::<pfn::GetActionStateBoolean, pfn::VoidFunction>(
+                get_action_state_boolean,
+            ),
+        ),
+        b"xrGetActionStateFloat\0" => substitute(
+            &GET_ACTION_STATE_FLOAT,
+            f,
+            mem::transmute::<pfn::GetActionStateFloat, pfn::VoidFunction>(get_action_state_float),
+        ),
+        b"xrGetActionStateVector2f\0" => substitute(
+            &GET_ACTION_STATE_VECTOR2F,
+            f,
+            mem::transmute::<pfn::GetActionStateVector2f, pfn::VoidFunction>(
+                get_action_state_vector2f,
+            ),
+        ),
+        b"xrGetActionStatePose\0" => substitute(
+            &GET_ACTION_STATE_POSE,
+            f,
+            mem::transmute::<pfn::GetActionStatePose, pfn::VoidFunction>(get_action_state_pose),
+        ),
+        b"xrCreateActionSet\0" => substitute(
+            &CREATE_ACTION_SET,
+            f,
+            mem::transmute::<pfn::CreateActionSet, pfn::VoidFunction>(create_action_set),
+        ),
+        b"xrDestroyActionSet\0" => substitute(
+            &DESTROY_ACTION_SET,
+            f,
+            mem::transmute::<pfn::DestroyActionSet, pfn::VoidFunction>(destroy_action_set),
+        ),
+        b"xrCreateAction\0" => substitute(
+            &CREATE_ACTION,
+            f,
+            mem::transmute::<pfn::CreateAction, pfn::VoidFunction>(create_action),
+        ),
+        b"xrDestroyAction\0" => substitute(
+            &DESTROY_ACTION,
+            f,
+            mem::transmute::<pfn::DestroyAction, pfn::VoidFunction>(destroy_action),
+        ),
+        b"xrSuggestInteractionProfileBindings\0" => substitute(
+            &SUGGEST_INTERACTION_PROFILE_BINDINGS,
+            f,
+            mem::transmute::<pfn::SuggestInteractionProfileBindings, pfn::VoidFunction>(
+                suggest_interaction_profile_bindings,
+            ),
+        ),
+        b"xrAttachSessionActionSets\0" => substitute(
+            &ATTACH_SESSION_ACTION_SETS,
+            f,
+            mem::transmute::<pfn::AttachSessionActionSets, pfn::VoidFunction>(
+                attach_session_action_sets,
+            ),
+        ),
+        b"xrGetCurrentInteractionProfile\0" => substitute(
+            &GET_CURRENT_INTERACTION_PROFILE,
+            f,
+            mem::transmute::<pfn::GetCurrentInteractionProfile, pfn::VoidFunction>(
+                get_current_interaction_profile,
+            ),
+        ),
+        b"xrSyncActions\0" => substitute(
+            &SYNC_ACTIONS,
+            f,
+            mem::transmute::<pfn::SyncActions, pfn::VoidFunction>(sync_actions),
+        ),
+        b"xrEnumerateBoundSourcesForAction\0" => substitute(
+            &ENUMERATE_BOUND_SOURCES_FOR_ACTION,
+            f,
+            mem::transmute::<pfn::EnumerateBoundSourcesForAction, pfn::VoidFunction>(
+                enumerate_bound_sources_for_action,
+            ),
+        ),
+        b"xrGetInputSourceLocalizedName\0" => substitute(
+            &GET_INPUT_SOURCE_LOCALIZED_NAME,
+            f,
+            mem::transmute::<pfn::GetInputSourceLocalizedName, pfn::VoidFunction>(
+                get_input_source_localized_name,
+            ),
+        ),
+        b"xrGetVulkanInstanceExtensionsKHR\0" => substitute(
+            &GET_VULKAN_INSTANCE_EXTENSIONS_KHR,
+            f,
+            mem::transmute::<pfn::GetVulkanInstanceExtensionsKHR, pfn::VoidFunction>(
+                get_vulkan_instance_extensions_khr,
+            ),
+        ),
+        b"xrGetVulkanDeviceExtensionsKHR\0" => substitute(
+            &GET_VULKAN_DEVICE_EXTENSIONS_KHR,
+            f,
+            mem::transmute::<pfn::GetVulkanDeviceExtensionsKHR, pfn::VoidFunction>(
+                get_vulkan_device_extensions_khr,
+            ),
+        ),
+        b"xrGetVulkanGraphicsDeviceKHR\0" => substitute(
+            &GET_VULKAN_GRAPHICS_DEVICE_KHR,
+            f,
+            mem::transmute::<pfn::GetVulkanGraphicsDeviceKHR, pfn::VoidFunction>(
+                get_vulkan_graphics_device_khr,
+            ),
+        ),
+        b"xrGetOpenGLGraphicsRequirementsKHR\0" => substitute(
+            &GET_OPEN_GL_GRAPHICS_REQUIREMENTS_KHR,
+            f,
+            mem::transmute::<pfn::GetOpenGLGraphicsRequirementsKHR, pfn::VoidFunction>(
+                get_open_gl_graphics_requirements_khr,
+            ),
+        ),
+        b"xrGetOpenGLESGraphicsRequirementsKHR\0" => substitute(
+            &GET_OPEN_GLES_GRAPHICS_REQUIREMENTS_KHR,
+            f,
+            mem::transmute::<pfn::GetOpenGLESGraphicsRequirementsKHR, pfn::VoidFunction>(
+                get_open_gles_graphics_requirements_khr,
+            ),
+        ),
+        b"xrGetVulkanGraphicsRequirementsKHR\0" => substitute(
+            &GET_VULKAN_GRAPHICS_REQUIREMENTS_KHR,
+            f,
+            mem::transmute::<pfn::GetVulkanGraphicsRequirementsKHR, pfn::VoidFunction>(
+                get_vulkan_graphics_requirements_khr,
+            ),
+        ),
+        #[cfg(windows)]
+        b"xrGetD3D11GraphicsRequirementsKHR\0" => substitute(
+            &GET_D3_D11_GRAPHICS_REQUIREMENTS_KHR,
+            f,
+            mem::transmute::<pfn::GetD3D11GraphicsRequirementsKHR, pfn::VoidFunction>(
+                get_d3_d11_graphics_requirements_khr,
+            ),
+        ),
+        #[cfg(windows)]
+        b"xrGetD3D12GraphicsRequirementsKHR\0" => substitute(
+            &GET_D3_D12_GRAPHICS_REQUIREMENTS_KHR,
+            f,
+            mem::transmute::<pfn::GetD3D12GraphicsRequirementsKHR, pfn::VoidFunction>(
+                get_d3_d12_graphics_requirements_khr,
+            ),
+        ),
+        b"xrPerfSettingsSetPerformanceLevelEXT\0" => substitute(
+            &PERF_SETTINGS_SET_PERFORMANCE_LEVEL_EXT,
+            f,
+            mem::transmute::<pfn::PerfSettingsSetPerformanceLevelEXT, pfn::VoidFunction>(
+                perf_settings_set_performance_level_ext,
+            ),
+        ),
+        b"xrThermalGetTemperatureTrendEXT\0" => substitute(
+            &THERMAL_GET_TEMPERATURE_TREND_EXT,
+            f,
+            mem::transmute::<pfn::ThermalGetTemperatureTrendEXT, pfn::VoidFunction>(
+                thermal_get_temperature_trend_ext,
+            ),
+        ),
+        b"xrSetDebugUtilsObjectNameEXT\0" => substitute(
+            &SET_DEBUG_UTILS_OBJECT_NAME_EXT,
+            f,
+            mem::transmute::<pfn::SetDebugUtilsObjectNameEXT, pfn::VoidFunction>(
+                set_debug_utils_object_name_ext,
+            ),
+        ),
+        b"xrCreateDebugUtilsMessengerEXT\0" => substitute(
+            &CREATE_DEBUG_UTILS_MESSENGER_EXT,
+            f,
+            mem::transmute::<pfn::CreateDebugUtilsMessengerEXT, pfn::VoidFunction>(
+                create_debug_utils_messenger_ext,
+            ),
+        ),
+        b"xrDestroyDebugUtilsMessengerEXT\0" => substitute(
+            &DESTROY_DEBUG_UTILS_MESSENGER_EXT,
+            f,
+            mem::transmute::<pfn::DestroyDebugUtilsMessengerEXT, pfn::VoidFunction>(
+                destroy_debug_utils_messenger_ext,
+            ),
+        ),
+        b"xrSubmitDebugUtilsMessageEXT\0" => substitute(
+            &SUBMIT_DEBUG_UTILS_MESSAGE_EXT,
+            f,
+            mem::transmute::<pfn::SubmitDebugUtilsMessageEXT, pfn::VoidFunction>(
+                submit_debug_utils_message_ext,
+            ),
+        ),
+        b"xrSessionBeginDebugUtilsLabelRegionEXT\0" => substitute(
+            &SESSION_BEGIN_DEBUG_UTILS_LABEL_REGION_EXT,
+            f,
+            mem::transmute::<pfn::SessionBeginDebugUtilsLabelRegionEXT, pfn::VoidFunction>(
+                session_begin_debug_utils_label_region_ext,
+            ),
+        ),
+        b"xrSessionEndDebugUtilsLabelRegionEXT\0" => substitute(
+            &SESSION_END_DEBUG_UTILS_LABEL_REGION_EXT,
+            f,
+            mem::transmute::<pfn::SessionEndDebugUtilsLabelRegionEXT, pfn::VoidFunction>(
+                session_end_debug_utils_label_region_ext,
+            ),
+        ),
+        b"xrSessionInsertDebugUtilsLabelEXT\0" => substitute(
+            &SESSION_INSERT_DEBUG_UTILS_LABEL_EXT,
+            f,
+            mem::transmute::<pfn::SessionInsertDebugUtilsLabelEXT, pfn::VoidFunction>(
+                session_insert_debug_utils_label_ext,
+            ),
+        ),
+        #[cfg(windows)]
+        b"xrConvertTimeToWin32PerformanceCounterKHR\0" => substitute(
+            &CONVERT_TIME_TO_WIN32_PERFORMANCE_COUNTER_KHR,
+            f,
+            mem::transmute::<pfn::ConvertTimeToWin32PerformanceCounterKHR, pfn::VoidFunction>(
+                convert_time_to_win32_performance_counter_khr,
+            ),
+        ),
+        #[cfg(windows)]
+        b"xrConvertWin32PerformanceCounterToTimeKHR\0" => substitute(
+            &CONVERT_WIN32_PERFORMANCE_COUNTER_TO_TIME_KHR,
+            f,
+            mem::transmute::<pfn::ConvertWin32PerformanceCounterToTimeKHR, pfn::VoidFunction>(
+                convert_win32_performance_counter_to_time_khr,
+            ),
+        ),
+        b"xrCreateVulkanInstanceKHR\0" => substitute(
+            &CREATE_VULKAN_INSTANCE_KHR,
+            f,
+            mem::transmute::<pfn::CreateVulkanInstanceKHR, pfn::VoidFunction>(
+                create_vulkan_instance_khr,
+            ),
+        ),
+        b"xrCreateVulkanDeviceKHR\0" => substitute(
+            &CREATE_VULKAN_DEVICE_KHR,
+            f,
+            mem::transmute::<pfn::CreateVulkanDeviceKHR, pfn::VoidFunction>(
+                create_vulkan_device_khr,
+            ),
+        ),
+        b"xrGetVulkanGraphicsDevice2KHR\0" => substitute(
+            &GET_VULKAN_GRAPHICS_DEVICE2_KHR,
+            f,
+            mem::transmute::<pfn::GetVulkanGraphicsDevice2KHR, pfn::VoidFunction>(
+                get_vulkan_graphics_device2_khr,
+            ),
+        ),
+        b"xrConvertTimeToTimespecTimeKHR\0" => substitute(
+            &CONVERT_TIME_TO_TIMESPEC_TIME_KHR,
+            f,
+            mem::transmute::<pfn::ConvertTimeToTimespecTimeKHR, pfn::VoidFunction>(
+                convert_time_to_timespec_time_khr,
+            ),
+        ),
+        b"xrConvertTimespecTimeToTimeKHR\0" => substitute(
+            &CONVERT_TIMESPEC_TIME_TO_TIME_KHR,
+            f,
+            mem::transmute::<pfn::ConvertTimespecTimeToTimeKHR, pfn::VoidFunction>(
+                convert_timespec_time_to_time_khr,
+            ),
+        ),
+        b"xrGetVisibilityMaskKHR\0" => substitute(
+            &GET_VISIBILITY_MASK_KHR,
+            f,
+            mem::transmute::<pfn::GetVisibilityMaskKHR, pfn::VoidFunction>(get_visibility_mask_khr),
+        ),
+        b"xrCreateSpatialAnchorMSFT\0" => substitute(
+            &CREATE_SPATIAL_ANCHOR_MSFT,
+            f,
+            mem::transmute::<pfn::CreateSpatialAnchorMSFT, pfn::VoidFunction>(
+                create_spatial_anchor_msft,
+            ),
+        ),
+        b"xrCreateSpatialAnchorSpaceMSFT\0" => substitute(
+            &CREATE_SPATIAL_ANCHOR_SPACE_MSFT,
+            f,
+            mem::transmute::<pfn::CreateSpatialAnchorSpaceMSFT, pfn::VoidFunction>(
+                create_spatial_anchor_space_msft,
+            ),
+        ),
+        b"xrDestroySpatialAnchorMSFT\0" => substitute(
+            &DESTROY_SPATIAL_ANCHOR_MSFT,
+            f,
+            mem::transmute::<pfn::DestroySpatialAnchorMSFT, pfn::VoidFunction>(
+                destroy_spatial_anchor_msft,
+            ),
+        ),
+        b"xrSetInputDeviceActiveEXT\0" => substitute(
+            &SET_INPUT_DEVICE_ACTIVE_EXT,
+            f,
+            mem::transmute::<pfn::SetInputDeviceActiveEXT, pfn::VoidFunction>(
+                set_input_device_active_ext,
+            ),
+        ),
+        b"xrSetInputDeviceStateBoolEXT\0" => substitute(
+            &SET_INPUT_DEVICE_STATE_BOOL_EXT,
+            f,
+            mem::transmute::<pfn::SetInputDeviceStateBoolEXT, pfn::VoidFunction>(
+                set_input_device_state_bool_ext,
+            ),
+        ),
+        b"xrSetInputDeviceStateFloatEXT\0" => substitute(
+            &SET_INPUT_DEVICE_STATE_FLOAT_EXT,
+            f,
+            mem::transmute::<pfn::SetInputDeviceStateFloatEXT, pfn::VoidFunction>(
+                set_input_device_state_float_ext,
+            ),
+        ),
+        b"xrSetInputDeviceStateVector2fEXT\0" => substitute(
+            &SET_INPUT_DEVICE_STATE_VECTOR2F_EXT,
+            f,
+            mem::transmute::<pfn::SetInputDeviceStateVector2fEXT, pfn::VoidFunction>(
+                set_input_device_state_vector2f_ext,
+            ),
+        ),
+        b"xrSetInputDeviceLocationEXT\0" => substitute(
+            &SET_INPUT_DEVICE_LOCATION_EXT,
+            f,
+            mem::transmute::<pfn::SetInputDeviceLocationEXT, pfn::VoidFunction>(
+                set_input_device_location_ext,
+            ),
+        ),
+        b"xrInitializeLoaderKHR\0" => substitute(
+            &INITIALIZE_LOADER_KHR,
+            f,
+            mem::transmute::<pfn::InitializeLoaderKHR, pfn::VoidFunction>(initialize_loader_khr),
+        ),
+        b"xrCreateSpatialGraphNodeSpaceMSFT\0" => substitute(
+            &CREATE_SPATIAL_GRAPH_NODE_SPACE_MSFT,
+            f,
+            mem::transmute::<pfn::CreateSpatialGraphNodeSpaceMSFT, pfn::VoidFunction>(
+                create_spatial_graph_node_space_msft,
+            ),
+        ),
+        b"xrTryCreateSpatialGraphStaticNodeBindingMSFT\0" => substitute(
+            &TRY_CREATE_SPATIAL_GRAPH_STATIC_NODE_BINDING_MSFT,
+            f,
+            mem::transmute::<pfn::TryCreateSpatialGraphStaticNodeBindingMSFT, pfn::VoidFunction>(
+                try_create_spatial_graph_static_node_binding_msft,
+            ),
+        ),
+        b"xrDestroySpatialGraphNodeBindingMSFT\0" => substitute(
+            &DESTROY_SPATIAL_GRAPH_NODE_BINDING_MSFT,
+            f,
+            mem::transmute::<pfn::DestroySpatialGraphNodeBindingMSFT, pfn::VoidFunction>(
+                destroy_spatial_graph_node_binding_msft,
+            ),
+        ),
+        b"xrGetSpatialGraphNodeBindingPropertiesMSFT\0" => substitute(
+            &GET_SPATIAL_GRAPH_NODE_BINDING_PROPERTIES_MSFT,
+            f,
+            mem::transmute::<pfn::GetSpatialGraphNodeBindingPropertiesMSFT, pfn::VoidFunction>(
+                get_spatial_graph_node_binding_properties_msft,
+            ),
+        ),
+        b"xrCreateHandTrackerEXT\0" => substitute(
+            &CREATE_HAND_TRACKER_EXT,
+            f,
+            mem::transmute::<pfn::CreateHandTrackerEXT, pfn::VoidFunction>(create_hand_tracker_ext),
+        ),
+        b"xrDestroyHandTrackerEXT\0" => substitute(
+            &DESTROY_HAND_TRACKER_EXT,
+            f,
+            mem::transmute::<pfn::DestroyHandTrackerEXT, pfn::VoidFunction>(
+                destroy_hand_tracker_ext,
+            ),
+        ),
+        b"xrLocateHandJointsEXT\0" => substitute(
+            &LOCATE_HAND_JOINTS_EXT,
+            f,
+            mem::transmute::<pfn::LocateHandJointsEXT, pfn::VoidFunction>(locate_hand_joints_ext),
+        ),
+        b"xrCreateFaceTrackerFB\0" => substitute(
+            &CREATE_FACE_TRACKER2_FB,
+            f,
+            mem::transmute::<pfn::CreateFaceTracker2FB, pfn::VoidFunction>(create_face_tracker2_fb),
+        ),
+        b"xrDestroyFaceTrackerFB\0" => substitute(
+            &DESTROY_FACE_TRACKER2_FB,
+            f,
+            mem::transmute::<pfn::DestroyFaceTracker2FB, pfn::VoidFunction>(
+                destroy_face_tracker2_fb,
+            ),
+        ),
+        b"xrGetFaceExpressionWeightsFB\0" => substitute(
+            &GET_FACE_EXPRESSION_WEIGHTS2_FB,
+            f,
+            mem::transmute::<pfn::GetFaceExpressionWeights2FB, pfn::VoidFunction>(
+                get_face_expression_weights2_fb,
+            ),
+        ),
+        b"xrCreateBodyTrackerFB\0" => substitute(
+            &CREATE_BODY_TRACKER_FB,
+            f,
+            mem::transmute::<pfn::CreateBodyTrackerFB, pfn::VoidFunction>(create_body_tracker_fb),
+        ),
+        b"xrDestroyBodyTrackerFB\0" => substitute(
+            &DESTROY_BODY_TRACKER_FB,
+            f,
+            mem::transmute::<pfn::DestroyBodyTrackerFB, pfn::VoidFunction>(destroy_body_tracker_fb),
+        ),
+        b"xrLocateBodyJointsFB\0" => substitute(
+            &LOCATE_BODY_JOINTS_FB,
+            f,
+            mem::transmute::<pfn::LocateBodyJointsFB, pfn::VoidFunction>(locate_body_joints_fb),
+        ),
+        b"xrGetBodySkeletonFB\0" => substitute(
+            &GET_BODY_SKELETON_FB,
+            f,
+            mem::transmute::<pfn::GetBodySkeletonFB, pfn::VoidFunction>(get_body_skeleton_fb),
+        ),
+        b"xrCreateEyeTrackerFB\0" => substitute(
+            &CREATE_EYE_TRACKER_FB,
+            f,
+            mem::transmute::<pfn::CreateEyeTrackerFB, pfn::VoidFunction>(create_eye_tracker_fb),
+        ),
+        b"xrDestroyEyeTrackerFB\0" => substitute(
+            &DESTROY_EYE_TRACKER_FB,
+            f,
+            mem::transmute::<pfn::DestroyEyeTrackerFB, pfn::VoidFunction>(destroy_eye_tracker_fb),
+        ),
+        b"xrGetEyeGazesFB\0" => substitute(
+            &GET_EYE_GAZES_FB,
+            f,
+            mem::transmute::<pfn::GetEyeGazesFB, pfn::VoidFunction>(get_eye_gazes_fb),
+        ),
+        b"xrCreateHandMeshSpaceMSFT\0" => substitute(
+            &CREATE_HAND_MESH_SPACE_MSFT,
+            f,
+            mem::transmute::<pfn::CreateHandMeshSpaceMSFT, pfn::VoidFunction>(
+                create_hand_mesh_space_msft,
+            ),
+        ),
+        b"xrUpdateHandMeshMSFT\0" => substitute(
+            &UPDATE_HAND_MESH_MSFT,
+            f,
+            mem::transmute::<pfn::UpdateHandMeshMSFT, pfn::VoidFunction>(update_hand_mesh_msft),
+        ),
+        b"xrGetControllerModelKeyMSFT\0" => substitute(
+            &GET_CONTROLLER_MODEL_KEY_MSFT,
+            f,
+            mem::transmute::<pfn::GetControllerModelKeyMSFT, pfn::VoidFunction>(
+                get_controller_model_key_msft,
+            ),
+        ),
+        b"xrLoadControllerModelMSFT\0" => substitute(
+            &LOAD_CONTROLLER_MODEL_MSFT,
+            f,
+            mem::transmute::<pfn::LoadControllerModelMSFT, pfn::VoidFunction>(
+                load_controller_model_msft,
+            ),
+        ),
+        b"xrGetControllerModelPropertiesMSFT\0" => substitute(
+            &GET_CONTROLLER_MODEL_PROPERTIES_MSFT,
+            f,
+            mem::transmute::<pfn::GetControllerModelPropertiesMSFT, pfn::VoidFunction>(
+                get_controller_model_properties_msft,
+            ),
+        ),
+        b"xrGetControllerModelStateMSFT\0" => substitute(
+            &GET_CONTROLLER_MODEL_STATE_MSFT,
+            f,
+            mem::transmute::<pfn::GetControllerModelStateMSFT, pfn::VoidFunction>(
+                get_controller_model_state_msft,
+            ),
+        ),
+        b"xrEnumerateDisplayRefreshRatesFB\0" => substitute(
+            &ENUMERATE_DISPLAY_REFRESH_RATES_FB,
+            f,
+            mem::transmute::<pfn::EnumerateDisplayRefreshRatesFB, pfn::VoidFunction>(
+                enumerate_display_refresh_rates_fb,
+            ),
+        ),
+        b"xrGetDisplayRefreshRateFB\0" => substitute(
+            &GET_DISPLAY_REFRESH_RATE_FB,
+            f,
+            mem::transmute::<pfn::GetDisplayRefreshRateFB, pfn::VoidFunction>(
+                get_display_refresh_rate_fb,
+            ),
+        ),
+        b"xrRequestDisplayRefreshRateFB\0" => substitute(
+            &REQUEST_DISPLAY_REFRESH_RATE_FB,
+            f,
+            mem::transmute::<pfn::RequestDisplayRefreshRateFB, pfn::VoidFunction>(
+                request_display_refresh_rate_fb,
+            ),
+        ),
+        #[cfg(windows)]
+        b"xrCreateSpatialAnchorFromPerceptionAnchorMSFT\0" => substitute(
+            &CREATE_SPATIAL_ANCHOR_FROM_PERCEPTION_ANCHOR_MSFT,
+            f,
+            mem::transmute::<pfn::CreateSpatialAnchorFromPerceptionAnchorMSFT, pfn::VoidFunction>(
+                create_spatial_anchor_from_perception_anchor_msft,
+            ),
+        ),
+        #[cfg(windows)]
+        b"xrTryGetPerceptionAnchorFromSpatialAnchorMSFT\0" => substitute(
+            &TRY_GET_PERCEPTION_ANCHOR_FROM_SPATIAL_ANCHOR_MSFT,
+            f,
+            mem::transmute::<pfn::TryGetPerceptionAnchorFromSpatialAnchorMSFT, pfn::VoidFunction>(
+                try_get_perception_anchor_from_spatial_anchor_msft,
+            ),
+        ),
+        b"xrUpdateSwapchainFB\0" => substitute(
+            &UPDATE_SWAPCHAIN_FB,
+            f,
+            mem::transmute::<pfn::UpdateSwapchainFB, pfn::VoidFunction>(update_swapchain_fb),
+        ),
+        b"xrGetSwapchainStateFB\0" => substitute(
+            &GET_SWAPCHAIN_STATE_FB,
+            f,
+            mem::transmute::<pfn::GetSwapchainStateFB, pfn::VoidFunction>(get_swapchain_state_fb),
+        ),
+        b"xrEnumerateColorSpacesFB\0" => substitute(
+            &ENUMERATE_COLOR_SPACES_FB,
+            f,
+            mem::transmute::<pfn::EnumerateColorSpacesFB, pfn::VoidFunction>(
+                enumerate_color_spaces_fb,
+            ),
+        ),
+        b"xrSetColorSpaceFB\0" => substitute(
+            &SET_COLOR_SPACE_FB,
+            f,
+            mem::transmute::<pfn::SetColorSpaceFB, pfn::VoidFunction>(set_color_space_fb),
+        ),
+        b"xrCreateFoveationProfileFB\0" => substitute(
+            &CREATE_FOVEATION_PROFILE_FB,
+            f,
+            mem::transmute::<pfn::CreateFoveationProfileFB, pfn::VoidFunction>(
+                create_foveation_profile_fb,
+            ),
+        ),
+        b"xrDestroyFoveationProfileFB\0" => substitute(
+            &DESTROY_FOVEATION_PROFILE_FB,
+            f,
+            mem::transmute::<pfn::DestroyFoveationProfileFB, pfn::VoidFunction>(
+                destroy_foveation_profile_fb,
+            ),
+        ),
+        b"xrGetFoveationEyeTrackedStateMETA\0" => substitute(
+            &GET_FOVEATION_EYE_TRACKED_STATE_META,
+            f,
+            mem::transmute::<pfn::GetFoveationEyeTrackedStateMETA, pfn::VoidFunction>(
+                get_foveation_eye_tracked_state_meta,
+            ),
+        ),
+        b"xrGetHandMeshFB\0" => substitute(
+            &GET_HAND_MESH_FB,
+            f,
+            mem::transmute::<pfn::GetHandMeshFB, pfn::VoidFunction>(get_hand_mesh_fb),
+        ),
+        b"xrEnumerateRenderModelPathsFB\0" => substitute(
+            &ENUMERATE_RENDER_MODEL_PATHS_FB,
+            f,
+            mem::transmute::<pfn::EnumerateRenderModelPathsFB, pfn::VoidFunction>(
+                enumerate_render_model_paths_fb,
+            ),
+        ),
+        b"xrGetRenderModelPropertiesFB\0" => substitute(
+            &GET_RENDER_MODEL_PROPERTIES_FB,
+            f,
+            mem::transmute::<pfn::GetRenderModelPropertiesFB, pfn::VoidFunction>(
+                get_render_model_properties_fb,
+            ),
+        ),
+        b"xrLoadRenderModelFB\0" => substitute(
+            &LOAD_RENDER_MODEL_FB,
+            f,
+            mem::transmute::<pfn::LoadRenderModelFB, pfn::VoidFunction>(load_render_model_fb),
+        ),
+        b"xrQuerySystemTrackedKeyboardFB\0" => substitute(
+            &QUERY_SYSTEM_TRACKED_KEYBOARD_FB,
+            f,
+            mem::transmute::<pfn::QuerySystemTrackedKeyboardFB, pfn::VoidFunction>(
+                query_system_tracked_keyboard_fb,
+            ),
+        ),
+        b"xrCreateKeyboardSpaceFB\0" => substitute(
+            &CREATE_KEYBOARD_SPACE_FB,
+            f,
+            mem::transmute::<pfn::CreateKeyboardSpaceFB, pfn::VoidFunction>(
+                create_keyboard_space_fb,
+            ),
+        ),
+        b"xrSetEnvironmentDepthEstimationVARJO\0" => substitute(
+            &SET_ENVIRONMENT_DEPTH_ESTIMATION_VARJO,
+            f,
+            mem::transmute::<pfn::SetEnvironmentDepthEstimationVARJO, pfn::VoidFunction>(
+                set_environment_depth_estimation_varjo,
+            ),
+        ),
+        b"xrEnumerateReprojectionModesMSFT\0" => substitute(
+            &ENUMERATE_REPROJECTION_MODES_MSFT,
+            f,
+            mem::transmute::<pfn::EnumerateReprojectionModesMSFT, pfn::VoidFunction>(
+                enumerate_reprojection_modes_msft,
+            ),
+        ),
+        b"xrGetAudioOutputDeviceGuidOculus\0" => substitute(
+            &GET_AUDIO_OUTPUT_DEVICE_GUID_OCULUS,
+            f,
+            mem::transmute::<pfn::GetAudioOutputDeviceGuidOculus, pfn::VoidFunction>(
+                get_audio_output_device_guid_oculus,
+            ),
+        ),
+        b"xrGetAudioInputDeviceGuidOculus\0" => substitute(
+            &GET_AUDIO_INPUT_DEVICE_GUID_OCULUS,
+            f,
+            mem::transmute::<pfn::GetAudioInputDeviceGuidOculus, pfn::VoidFunction>(
+                get_audio_input_device_guid_oculus,
+            ),
+        ),
+        b"xrCreateSpatialAnchorFB\0" => substitute(
+            &CREATE_SPATIAL_ANCHOR_FB,
+            f,
+            mem::transmute::<pfn::CreateSpatialAnchorFB, pfn::VoidFunction>(
+                create_spatial_anchor_fb,
+            ),
+        ),
+        b"xrGetSpaceUuidFB\0" => substitute(
+            &GET_SPACE_UUID_FB,
+            f,
+            mem::transmute::<pfn::GetSpaceUuidFB, pfn::VoidFunction>(get_space_uuid_fb),
+        ),
+        b"xrEnumerateSpaceSupportedComponentsFB\0" => substitute(
+            &ENUMERATE_SPACE_SUPPORTED_COMPONENTS_FB,
+            f,
+            mem::transmute::<pfn::EnumerateSpaceSupportedComponentsFB, pfn::VoidFunction>(
+                enumerate_space_supported_components_fb,
+            ),
+        ),
+        b"xrSetSpaceComponentStatusFB\0" => substitute(
+            &SET_SPACE_COMPONENT_STATUS_FB,
+            f,
+            mem::transmute::<pfn::SetSpaceComponentStatusFB, pfn::VoidFunction>(
+                set_space_component_status_fb,
+            ),
+        ),
+        b"xrGetSpaceComponentStatusFB\0" => substitute(
+            &GET_SPACE_COMPONENT_STATUS_FB,
+            f,
+            mem::transmute::<pfn::GetSpaceComponentStatusFB, pfn::VoidFunction>(
+                get_space_component_status_fb,
+            ),
+        ),
+        b"xrCreateTriangleMeshFB\0" => substitute(
+            &CREATE_TRIANGLE_MESH_FB,
+            f,
+            mem::transmute::<pfn::CreateTriangleMeshFB, pfn::VoidFunction>(create_triangle_mesh_fb),
+        ),
+        b"xrDestroyTriangleMeshFB\0" => substitute(
+            &DESTROY_TRIANGLE_MESH_FB,
+            f,
+            mem::transmute::<pfn::DestroyTriangleMeshFB, pfn::VoidFunction>(
+                destroy_triangle_mesh_fb,
+            ),
+        ),
+        b"xrTriangleMeshGetVertexBufferFB\0" => substitute(
+            &TRIANGLE_MESH_GET_VERTEX_BUFFER_FB,
+            f,
+            mem::transmute::<pfn::TriangleMeshGetVertexBufferFB, pfn::VoidFunction>(
+                triangle_mesh_get_vertex_buffer_fb,
+            ),
+        ),
+        b"xrTriangleMeshGetIndexBufferFB\0" => substitute(
+            &TRIANGLE_MESH_GET_INDEX_BUFFER_FB,
+            f,
+            mem::transmute::<pfn::TriangleMeshGetIndexBufferFB, pfn::VoidFunction>(
+                triangle_mesh_get_index_buffer_fb,
+            ),
+        ),
+        b"xrTriangleMeshBeginUpdateFB\0" => substitute(
+            &TRIANGLE_MESH_BEGIN_UPDATE_FB,
+            f,
+            mem::transmute::<pfn::TriangleMeshBeginUpdateFB, pfn::VoidFunction>(
+                triangle_mesh_begin_update_fb,
+            ),
+        ),
+        b"xrTriangleMeshEndUpdateFB\0" => substitute(
+            &TRIANGLE_MESH_END_UPDATE_FB,
+            f,
+            mem::transmute::<pfn::TriangleMeshEndUpdateFB, pfn::VoidFunction>(
+                triangle_mesh_end_update_fb,
+            ),
+        ),
+        b"xrTriangleMeshBeginVertexBufferUpdateFB\0" => substitute(
+            &TRIANGLE_MESH_BEGIN_VERTEX_BUFFER_UPDATE_FB,
+            f,
+            mem::transmute::<pfn::TriangleMeshBeginVertexBufferUpdateFB, pfn::VoidFunction>(
+                triangle_mesh_begin_vertex_buffer_update_fb,
+            ),
+        ),
+        b"xrTriangleMeshEndVertexBufferUpdateFB\0" => substitute(
+            &TRIANGLE_MESH_END_VERTEX_BUFFER_UPDATE_FB,
+            f,
+            mem::transmute::<pfn::TriangleMeshEndVertexBufferUpdateFB, pfn::VoidFunction>(
+                triangle_mesh_end_vertex_buffer_update_fb,
+            ),
+        ),
+        b"xrCreatePassthroughFB\0" => substitute(
+            &CREATE_PASSTHROUGH_FB,
+            f,
+            mem::transmute::<pfn::CreatePassthroughFB, pfn::VoidFunction>(create_passthrough_fb),
+        ),
+        b"xrDestroyPassthroughFB\0" => substitute(
+            &DESTROY_PASSTHROUGH_FB,
+            f,
+            mem::transmute::<pfn::DestroyPassthroughFB, pfn::VoidFunction>(destroy_passthrough_fb),
+        ),
+        b"xrPassthroughStartFB\0" => substitute(
+            &PASSTHROUGH_START_FB,
+            f,
+            mem::transmute::<pfn::PassthroughStartFB, pfn::VoidFunction>(passthrough_start_fb),
+        ),
+        b"xrPassthroughPauseFB\0" => substitute(
+            &PASSTHROUGH_PAUSE_FB,
+            f,
+            mem::transmute::<pfn::PassthroughPauseFB, pfn::VoidFunction>(passthrough_pause_fb),
+        ),
+        b"xrCreatePassthroughLayerFB\0" => substitute(
+            &CREATE_PASSTHROUGH_LAYER_FB,
+            f,
+            mem::transmute::<pfn::CreatePassthroughLayerFB, pfn::VoidFunction>(
+                create_passthrough_layer_fb,
+            ),
+        ),
+        b"xrDestroyPassthroughLayerFB\0" => substitute(
+            &DESTROY_PASSTHROUGH_LAYER_FB,
+            f,
+            mem::transmute::<pfn::DestroyPassthroughLayerFB, pfn::VoidFunction>(
+                destroy_passthrough_layer_fb,
+            ),
+        ),
+        b"xrPassthroughLayerPauseFB\0" => substitute(
+            &PASSTHROUGH_LAYER_PAUSE_FB,
+            f,
+            mem::transmute::<pfn::PassthroughLayerPauseFB, pfn::VoidFunction>(
+                passthrough_layer_pause_fb,
+            ),
+        ),
+        b"xrPassthroughLayerResumeFB\0" => substitute(
+            &PASSTHROUGH_LAYER_RESUME_FB,
+            f,
+            mem::transmute::<pfn::PassthroughLayerResumeFB, pfn::VoidFunction>(
+                passthrough_layer_resume_fb,
+            ),
+        ),
+        b"xrPassthroughLayerSetStyleFB\0" => substitute(
+            &PASSTHROUGH_LAYER_SET_STYLE_FB,
+            f,
+            mem::transmute::<pfn::PassthroughLayerSetStyleFB, pfn::VoidFunction>(
+                passthrough_layer_set_style_fb,
+            ),
+        ),
+        b"xrCreateGeometryInstanceFB\0" => substitute(
+            &CREATE_GEOMETRY_INSTANCE_FB,
+            f,
+            mem::transmute::<pfn::CreateGeometryInstanceFB, pfn::VoidFunction>(
+                create_geometry_instance_fb,
+            ),
+        ),
+        b"xrDestroyGeometryInstanceFB\0" => substitute(
+            &DESTROY_GEOMETRY_INSTANCE_FB,
+            f,
+            mem::transmute::<pfn::DestroyGeometryInstanceFB, pfn::VoidFunction>(
+                destroy_geometry_instance_fb,
+            ),
+        ),
+        b"xrGeometryInstanceSetTransformFB\0" => substitute(
+            &GEOMETRY_INSTANCE_SET_TRANSFORM_FB,
+            f,
+            mem::transmute::<pfn::GeometryInstanceSetTransformFB, pfn::VoidFunction>(
+                geometry_instance_set_transform_fb,
+            ),
+        ),
+        b"xrQuerySpacesFB\0" => substitute(
+            &QUERY_SPACES_FB,
+            f,
+            mem::transmute::<pfn::QuerySpacesFB, pfn::VoidFunction>(query_spaces_fb),
+        ),
+        b"xrRetrieveSpaceQueryResultsFB\0" => substitute(
+            &RETRIEVE_SPACE_QUERY_RESULTS_FB,
+            f,
+            mem::transmute::<pfn::RetrieveSpaceQueryResultsFB, pfn::VoidFunction>(
+                retrieve_space_query_results_fb,
+            ),
+        ),
+        b"xrSaveSpaceFB\0" => substitute(
+            &SAVE_SPACE_FB,
+            f,
+            mem::transmute::<pfn::SaveSpaceFB, pfn::VoidFunction>(save_space_fb),
+        ),
+        b"xrEraseSpaceFB\0" => substitute(
+            &ERASE_SPACE_FB,
+            f,
+            mem::transmute::<pfn::EraseSpaceFB, pfn::VoidFunction>(erase_space_fb),
+        ),
+        b"xrSaveSpaceListFB\0" => substitute(
+            &SAVE_SPACE_LIST_FB,
+            f,
+            mem::transmute::<pfn::SaveSpaceListFB, pfn::VoidFunction>(save_space_list_fb),
+        ),
+        b"xrShareSpacesFB\0" => substitute(
+            &SHARE_SPACES_FB,
+            f,
+            mem::transmute::<pfn::ShareSpacesFB, pfn::VoidFunction>(share_spaces_fb),
+        ),
+        b"xrGetSpaceContainerFB\0" => substitute(
+            &GET_SPACE_CONTAINER_FB,
+            f,
+            mem::transmute::<pfn::GetSpaceContainerFB, pfn::VoidFunction>(get_space_container_fb),
+        ),
+        b"xrGetSpaceBoundingBox2DFB\0" => substitute(
+            &GET_SPACE_BOUNDING_BOX2_DFB,
+            f,
+            mem::transmute::<pfn::GetSpaceBoundingBox2DFB, pfn::VoidFunction>(
+                get_space_bounding_box2_dfb,
+            ),
+        ),
+        b"xrGetSpaceBoundingBox3DFB\0" => substitute(
+            &GET_SPACE_BOUNDING_BOX3_DFB,
+            f,
+            mem::transmute::<pfn::GetSpaceBoundingBox3DFB, pfn::VoidFunction>(
+                get_space_bounding_box3_dfb,
+            ),
+        ),
+        b"xrGetSpaceSemanticLabelsFB\0" => substitute(
+            &GET_SPACE_SEMANTIC_LABELS_FB,
+            f,
+            mem::transmute::<pfn::GetSpaceSemanticLabelsFB, pfn::VoidFunction>(
+                get_space_semantic_labels_fb,
+            ),
+        ),
+        b"xrGetSpaceBoundary2DFB\0" => substitute(
+            &GET_SPACE_BOUNDARY2_DFB,
+            f,
+            mem::transmute::<pfn::GetSpaceBoundary2DFB, pfn::VoidFunction>(get_space_boundary2_dfb),
+        ),
+        b"xrGetSpaceRoomLayoutFB\0" => substitute(
+            &GET_SPACE_ROOM_LAYOUT_FB,
+            f,
+            mem::transmute::<pfn::GetSpaceRoomLayoutFB, pfn::VoidFunction>(
+                get_space_room_layout_fb,
+            ),
+        ),
+        b"xrRequestSceneCaptureFB\0" => substitute(
+            &REQUEST_SCENE_CAPTURE_FB,
+            f,
+            mem::transmute::<pfn::RequestSceneCaptureFB, pfn::VoidFunction>(
+                request_scene_capture_fb,
+            ),
+        ),
+        b"xrPassthroughLayerSetKeyboardHandsIntensityFB\0" => substitute(
+            &PASSTHROUGH_LAYER_SET_KEYBOARD_HANDS_INTENSITY_FB,
+            f,
+            mem::transmute::<pfn::PassthroughLayerSetKeyboardHandsIntensityFB, pfn::VoidFunction>(
+                passthrough_layer_set_keyboard_hands_intensity_fb,
+            ),
+        ),
+        b"xrCreateSpatialAnchorStoreConnectionMSFT\0" => substitute(
+            &CREATE_SPATIAL_ANCHOR_STORE_CONNECTION_MSFT,
+            f,
+            mem::transmute::<pfn::CreateSpatialAnchorStoreConnectionMSFT, pfn::VoidFunction>(
+                create_spatial_anchor_store_connection_msft,
+            ),
+        ),
+        b"xrDestroySpatialAnchorStoreConnectionMSFT\0" => substitute(
+            &DESTROY_SPATIAL_ANCHOR_STORE_CONNECTION_MSFT,
+            f,
+            mem::transmute::<pfn::DestroySpatialAnchorStoreConnectionMSFT, pfn::VoidFunction>(
+                destroy_spatial_anchor_store_connection_msft,
+            ),
+        ),
+        b"xrPersistSpatialAnchorMSFT\0" => substitute(
+            &PERSIST_SPATIAL_ANCHOR_MSFT,
+            f,
+            mem::transmute::<pfn::PersistSpatialAnchorMSFT, pfn::VoidFunction>(
+                persist_spatial_anchor_msft,
+            ),
+        ),
+        b"xrEnumeratePersistedSpatialAnchorNamesMSFT\0" => substitute(
+            &ENUMERATE_PERSISTED_SPATIAL_ANCHOR_NAMES_MSFT,
+            f,
+            mem::transmute::<pfn::EnumeratePersistedSpatialAnchorNamesMSFT, pfn::VoidFunction>(
+                enumerate_persisted_spatial_anchor_names_msft,
+            ),
+        ),
+        b"xrCreateSpatialAnchorFromPersistedNameMSFT\0" => substitute(
+            &CREATE_SPATIAL_ANCHOR_FROM_PERSISTED_NAME_MSFT,
+            f,
+            mem::transmute::<pfn::CreateSpatialAnchorFromPersistedNameMSFT, pfn::VoidFunction>(
+                create_spatial_anchor_from_persisted_name_msft,
+            ),
+        ),
+        b"xrUnpersistSpatialAnchorMSFT\0" => substitute(
+            &UNPERSIST_SPATIAL_ANCHOR_MSFT,
+            f,
+            mem::transmute::<pfn::UnpersistSpatialAnchorMSFT, pfn::VoidFunction>(
+                unpersist_spatial_anchor_msft,
+            ),
+        ),
+        b"xrClearSpatialAnchorStoreMSFT\0" => substitute(
+            &CLEAR_SPATIAL_ANCHOR_STORE_MSFT,
+            f,
+            mem::transmute::<pfn::ClearSpatialAnchorStoreMSFT, pfn::VoidFunction>(
+                clear_spatial_anchor_store_msft,
+            ),
+        ),
+        b"xrCreateFacialTrackerHTC\0" => substitute(
+            &CREATE_FACIAL_TRACKER_HTC,
+            f,
+            mem::transmute::<pfn::CreateFacialTrackerHTC, pfn::VoidFunction>(
+                create_facial_tracker_htc,
+            ),
+        ),
+        b"xrDestroyFacialTrackerHTC\0" => substitute(
+            &DESTROY_FACIAL_TRACKER_HTC,
+            f,
+            mem::transmute::<pfn::DestroyFacialTrackerHTC, pfn::VoidFunction>(
+                destroy_facial_tracker_htc,
+            ),
+        ),
+        b"xrGetFacialExpressionsHTC\0" => substitute(
+            &GET_FACIAL_EXPRESSIONS_HTC,
+            f,
+            mem::transmute::<pfn::GetFacialExpressionsHTC, pfn::VoidFunction>(
+                get_facial_expressions_htc,
+            ),
+        ),
+        b"xrCreatePassthroughHTC\0" => substitute(
+            &CREATE_PASSTHROUGH_HTC,
+            f,
+            mem::transmute::<pfn::CreatePassthroughHTC, pfn::VoidFunction>(create_passthrough_htc),
+        ),
+        b"xrDestroyPassthroughHTC\0" => substitute(
+            &DESTROY_PASSTHROUGH_HTC,
+            f,
+            mem::transmute::<pfn::DestroyPassthroughHTC, pfn::VoidFunction>(
+                destroy_passthrough_htc,
+            ),
+        ),
+        b"xrEnumerateViveTrackerPathsHTCX\0" => substitute(
+            &ENUMERATE_VIVE_TRACKER_PATHS_HTCX,
+            f,
+            mem::transmute::<pfn::EnumerateViveTrackerPathsHTCX, pfn::VoidFunction>(
+                enumerate_vive_tracker_paths_htcx,
+            ),
+        ),
+        b"xrSetMarkerTrackingVARJO\0" => substitute(
+            &SET_MARKER_TRACKING_VARJO,
+            f,
+            mem::transmute::<pfn::SetMarkerTrackingVARJO, pfn::VoidFunction>(
+                set_marker_tracking_varjo,
+            ),
+        ),
+        b"xrSetMarkerTrackingTimeoutVARJO\0" => substitute(
+            &SET_MARKER_TRACKING_TIMEOUT_VARJO,
+            f,
+            mem::transmute::<pfn::SetMarkerTrackingTimeoutVARJO, pfn::VoidFunction>(
+                set_marker_tracking_timeout_varjo,
+            ),
+        ),
+        b"xrSetMarkerTrackingPredictionVARJO\0" => substitute(
+            &SET_MARKER_TRACKING_PREDICTION_VARJO,
+            f,
+            mem::transmute::<pfn::SetMarkerTrackingPredictionVARJO, pfn::VoidFunction>(
+                set_marker_tracking_prediction_varjo,
+            ),
+        ),
+        b"xrGetMarkerSizeVARJO\0" => substitute(
+            &GET_MARKER_SIZE_VARJO,
+            f,
+            mem::transmute::<pfn::GetMarkerSizeVARJO, pfn::VoidFunction>(get_marker_size_varjo),
+        ),
+        b"xrCreateMarkerSpaceVARJO\0" => substitute(
+            &CREATE_MARKER_SPACE_VARJO,
+            f,
+            mem::transmute::<pfn::CreateMarkerSpaceVARJO, pfn::VoidFunction>(
+                create_marker_space_varjo,
+            ),
+        ),
+        b"xrSetDigitalLensControlALMALENCE\0" => substitute(
+            &SET_DIGITAL_LENS_CONTROL_ALMALENCE,
+            f,
+            mem::transmute::<pfn::SetDigitalLensControlALMALENCE, pfn::VoidFunction>(
+                set_digital_lens_control_almalence,
+            ),
+        ),
+        b"xrSetViewOffsetVARJO\0" => substitute(
+            &SET_VIEW_OFFSET_VARJO,
+            f,
+            mem::transmute::<pfn::SetViewOffsetVARJO, pfn::VoidFunction>(set_view_offset_varjo),
+        ),
+        b"xrEnumerateExternalCamerasOCULUS\0" => substitute(
+            &ENUMERATE_EXTERNAL_CAMERAS_OCULUS,
+            f,
+            mem::transmute::<pfn::EnumerateExternalCamerasOCULUS, pfn::VoidFunction>(
+                enumerate_external_cameras_oculus,
+            ),
+        ),
+        b"xrCreatePassthroughColorLutMETA\0" => substitute(
+            &CREATE_PASSTHROUGH_COLOR_LUT_META,
+            f,
+            mem::transmute::<pfn::CreatePassthroughColorLutMETA, pfn::VoidFunction>(
+                create_passthrough_color_lut_meta,
+            ),
+        ),
+        b"xrDestroyPassthroughColorLutMETA\0" => substitute(
+            &DESTROY_PASSTHROUGH_COLOR_LUT_META,
+            f,
+            mem::transmute::<pfn::DestroyPassthroughColorLutMETA, pfn::VoidFunction>(
+                destroy_passthrough_color_lut_meta,
+            ),
+        ),
+        b"xrUpdatePassthroughColorLutMETA\0" => substitute(
+            &UPDATE_PASSTHROUGH_COLOR_LUT_META,
+            f,
+            mem::transmute::<pfn::UpdatePassthroughColorLutMETA, pfn::VoidFunction>(
+                update_passthrough_color_lut_meta,
+            ),
+        ),
+        b"xrEnumeratePerformanceMetricsCounterPathsMETA\0" => substitute(
+            &ENUMERATE_PERFORMANCE_METRICS_COUNTER_PATHS_META,
+            f,
+            mem::transmute::<pfn::EnumeratePerformanceMetricsCounterPathsMETA, pfn::VoidFunction>(
+                enumerate_performance_metrics_counter_paths_meta,
+            ),
+        ),
+        b"xrSetPerformanceMetricsStateMETA\0" => substitute(
+            &SET_PERFORMANCE_METRICS_STATE_META,
+            f,
+            mem::transmute::<pfn::SetPerformanceMetricsStateMETA, pfn::VoidFunction>(
+                set_performance_metrics_state_meta,
+            ),
+        ),
+        b"xrGetPerformanceMetricsStateMETA\0" => substitute(
+            &GET_PERFORMANCE_METRICS_STATE_META,
+            f,
+            mem::transmute::<pfn::GetPerformanceMetricsStateMETA, pfn::VoidFunction>(
+                get_performance_metrics_state_meta,
+            ),
+        ),
+        b"xrQueryPerformanceMetricsCounterMETA\0" => substitute(
+            &QUERY_PERFORMANCE_METRICS_COUNTER_META,
+            f,
+            mem::transmute::<pfn::QueryPerformanceMetricsCounterMETA, pfn::VoidFunction>(
+                query_performance_metrics_counter_meta,
+            ),
+        ),
+        b"xrGetPassthroughPreferencesMETA\0" => substitute(
+            &GET_PASSTHROUGH_PREFERENCES_META,
+            f,
+            mem::transmute::<pfn::GetPassthroughPreferencesMETA, pfn::VoidFunction>(
+                get_passthrough_preferences_meta,
+            ),
+        ),
+        b"xrApplyFoveationHTC\0" => substitute(
+            &APPLY_FOVEATION_HTC,
+            f,
+            mem::transmute::<pfn::ApplyFoveationHTC, pfn::VoidFunction>(apply_foveation_htc),
+        ),
+        b"xrCreateSpaceFromCoordinateFrameUIDML\0" => substitute(
+            &CREATE_SPACE_FROM_COORDINATE_FRAME_UIDML,
+            f,
+            mem::transmute::<pfn::CreateSpaceFromCoordinateFrameUIDML, pfn::VoidFunction>(
+                create_space_from_coordinate_frame_uidml,
+            ),
+        ),
+        b"xrGetDeviceSampleRateFB\0" => substitute(
+            &GET_DEVICE_SAMPLE_RATE_FB,
+            f,
+            mem::transmute::<pfn::GetDeviceSampleRateFB, pfn::VoidFunction>(
+                get_device_sample_rate_fb,
+            ),
+        ),
+        b"xrSetTrackingOptimizationSettingsHintQCOM\0" => substitute(
+            &SET_TRACKING_OPTIMIZATION_SETTINGS_HINT_QCOM,
+            f,
+            mem::transmute::<pfn::SetTrackingOptimizationSettingsHintQCOM, pfn::VoidFunction>(
+                set_tracking_optimization_settings_hint_qcom,
+            ),
+        ),
+        b"xrCreateSpaceUserFB\0" => substitute(
+            &CREATE_SPACE_USER_FB,
+            f,
+            mem::transmute::<pfn::CreateSpaceUserFB, pfn::VoidFunction>(create_space_user_fb),
+        ),
+        b"xrGetSpaceUserIdFB\0" => substitute(
+            &GET_SPACE_USER_ID_FB,
+            f,
+            mem::transmute::<pfn::GetSpaceUserIdFB, pfn::VoidFunction>(get_space_user_id_fb),
+        ),
+        b"xrDestroySpaceUserFB\0" => substitute(
+            &DESTROY_SPACE_USER_FB,
+            f,
+            mem::transmute::<pfn::DestroySpaceUserFB, pfn::VoidFunction>(destroy_space_user_fb),
+        ),
+        b"xrApplyForceFeedbackCurlMNDX\0" => substitute(
+            &APPLY_FORCE_FEEDBACK_CURL_MNDX,
+            f,
+            mem::transmute::<pfn::ApplyForceFeedbackCurlMNDX, pfn::VoidFunction>(
+                apply_force_feedback_curl_mndx,
+            ),
+        ),
+        b"xrCreatePlaneDetectorEXT\0" => substitute(
+            &CREATE_PLANE_DETECTOR_EXT,
+            f,
+            mem::transmute::<pfn::CreatePlaneDetectorEXT, pfn::VoidFunction>(
+                create_plane_detector_ext,
+            ),
+        ),
+        b"xrDestroyPlaneDetectorEXT\0" => substitute(
+            &DESTROY_PLANE_DETECTOR_EXT,
+            f,
+            mem::transmute::<pfn::DestroyPlaneDetectorEXT, pfn::VoidFunction>(
+                destroy_plane_detector_ext,
+            ),
+        ),
+        b"xrBeginPlaneDetectionEXT\0" => substitute(
+            &BEGIN_PLANE_DETECTION_EXT,
+            f,
+            mem::transmute::<pfn::BeginPlaneDetectionEXT, pfn::VoidFunction>(
+                begin_plane_detection_ext,
+            ),
+        ),
+        b"xrGetPlaneDetectionStateEXT\0" => substitute(
+            &GET_PLANE_DETECTION_STATE_EXT,
+            f,
+            mem::transmute::<pfn::GetPlaneDetectionStateEXT, pfn::VoidFunction>(
+                get_plane_detection_state_ext,
+            ),
+        ),
+        b"xrGetPlaneDetectionsEXT\0" => substitute(
+            &GET_PLANE_DETECTIONS_EXT,
+            f,
+            mem::transmute::<pfn::GetPlaneDetectionsEXT, pfn::VoidFunction>(
+                get_plane_detections_ext,
+            ),
+        ),
+        b"xrGetPlanePolygonBufferEXT\0" => substitute(
+            &GET_PLANE_POLYGON_BUFFER_EXT,
+            f,
+            mem::transmute::<pfn::GetPlanePolygonBufferEXT, pfn::VoidFunction>(
+                get_plane_polygon_buffer_ext,
+            ),
+        ),
+        b"xrCreateVirtualKeyboardMETA\0" => substitute(
+            &CREATE_VIRTUAL_KEYBOARD_META,
+            f,
+            mem::transmute::<pfn::CreateVirtualKeyboardMETA, pfn::VoidFunction>(
+                create_virtual_keyboard_meta,
+            ),
+        ),
+        b"xrDestroyVirtualKeyboardMETA\0" => substitute(
+            &DESTROY_VIRTUAL_KEYBOARD_META,
+            f,
+            mem::transmute::<pfn::DestroyVirtualKeyboardMETA, pfn::VoidFunction>(
+                destroy_virtual_keyboard_meta,
+            ),
+        ),
+        b"xrCreateVirtualKeyboardSpaceMETA\0" => substitute(
+            &CREATE_VIRTUAL_KEYBOARD_SPACE_META,
+            f,
+            mem::transmute::<pfn::CreateVirtualKeyboardSpaceMETA, pfn::VoidFunction>(
+                create_virtual_keyboard_space_meta,
+            ),
+        ),
+        b"xrSuggestVirtualKeyboardLocationMETA\0" => substitute(
+            &SUGGEST_VIRTUAL_KEYBOARD_LOCATION_META,
+            f,
+            mem::transmute::<pfn::SuggestVirtualKeyboardLocationMETA, pfn::VoidFunction>(
+                suggest_virtual_keyboard_location_meta,
+            ),
+        ),
+        b"xrGetVirtualKeyboardScaleMETA\0" => substitute(
+            &GET_VIRTUAL_KEYBOARD_SCALE_META,
+            f,
+            mem::transmute::<pfn::GetVirtualKeyboardScaleMETA, pfn::VoidFunction>(
+                get_virtual_keyboard_scale_meta,
+            ),
+        ),
+        b"xrSetVirtualKeyboardModelVisibilityMETA\0" => substitute(
+            &SET_VIRTUAL_KEYBOARD_MODEL_VISIBILITY_META,
+            f,
+            mem::transmute::<pfn::SetVirtualKeyboardModelVisibilityMETA, pfn::VoidFunction>(
+                set_virtual_keyboard_model_visibility_meta,
+            ),
+        ),
+        b"xrGetVirtualKeyboardModelAnimationStatesMETA\0" => substitute(
+            &GET_VIRTUAL_KEYBOARD_MODEL_ANIMATION_STATES_META,
+            f,
+            mem::transmute::<pfn::GetVirtualKeyboardModelAnimationStatesMETA, pfn::VoidFunction>(
+                get_virtual_keyboard_model_animation_states_meta,
+            ),
+        ),
+        b"xrGetVirtualKeyboardDirtyTexturesMETA\0" => substitute(
+            &GET_VIRTUAL_KEYBOARD_DIRTY_TEXTURES_META,
+            f,
+            mem::transmute::<pfn::GetVirtualKeyboardDirtyTexturesMETA, pfn::VoidFunction>(
+                get_virtual_keyboard_dirty_textures_meta,
+            ),
+        ),
+        b"xrGetVirtualKeyboardTextureDataMETA\0" => substitute(
+            &GET_VIRTUAL_KEYBOARD_TEXTURE_DATA_META,
+            f,
+            mem::transmute::<pfn::GetVirtualKeyboardTextureDataMETA, pfn::VoidFunction>(
+                get_virtual_keyboard_texture_data_meta,
+            ),
+        ),
+        b"xrSendVirtualKeyboardInputMETA\0" => substitute(
+            &SEND_VIRTUAL_KEYBOARD_INPUT_META,
+            f,
+            mem::transmute::<pfn::SendVirtualKeyboardInputMETA, pfn::VoidFunction>(
+                send_virtual_keyboard_input_meta,
+            ),
+        ),
+        b"xrChangeVirtualKeyboardTextContextMETA\0" => substitute(
+            &CHANGE_VIRTUAL_KEYBOARD_TEXT_CONTEXT_META,
+            f,
+            mem::transmute::<pfn::ChangeVirtualKeyboardTextContextMETA, pfn::VoidFunction>(
+                change_virtual_keyboard_text_context_meta,
+            ),
+        ),
+        b"xrEnableUserCalibrationEventsML\0" => substitute(
+            &ENABLE_USER_CALIBRATION_EVENTS_ML,
+            f,
+            mem::transmute::<pfn::EnableUserCalibrationEventsML, pfn::VoidFunction>(
+                enable_user_calibration_events_ml,
+            ),
+        ),
+        b"xrGetVulkanGraphicsRequirements2KHR\0" => substitute(
+            &GET_VULKAN_GRAPHICS_REQUIREMENTS2_KHR,
+            f,
+            mem::transmute::<pfn::GetVulkanGraphicsRequirements2KHR, pfn::VoidFunction>(
+                get_vulkan_graphics_requirements2_khr,
+            ),
+        ),
+        _ => f,
+    }
+}
+
+/// Bind `wrapper` to `f` through `real`, unless it's already bound to something else
+fn substitute(
+    real: &AtomicUsize,
+    f: pfn::VoidFunction,
+    wrapper: pfn::VoidFunction,
+) -> pfn::VoidFunction {
+    if f as usize == wrapper as usize {
+        return wrapper;
+    }
+    match real.compare_exchange(0, f as usize, Ordering::Relaxed, Ordering::Relaxed) {
+        Ok(_) => wrapper,
+        Err(x) if x == f as usize => wrapper,
+        Err(_) => {
+            static WARNED: AtomicBool = AtomicBool::new(false);
+            if !WARNED.swap(true, Ordering::Relaxed) {
+                tracing::warn!(target: "openxr::ffi", "a second OpenXR runtime was loaded; its calls will not be traced");
+            }
+            f
+        }
+    }
+}
+
+static GET_INSTANCE_PROC_ADDR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_instance_proc_addr(
+    instance: Instance,
+    name: *const c_char,
+    function: *mut Option<pfn::VoidFunction>,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetInstanceProcAddr>(
+        GET_INSTANCE_PROC_ADDR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, name, function);
+    tracing::trace!(target: "openxr::ffi", "xrGetInstanceProcAddr(instance: {:?}, name: {:?}, function: {:?}) -> {:?}", instance, name, function, result);
+    result
+}
+
+static ENUMERATE_API_LAYER_PROPERTIES: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_api_layer_properties(
+    property_capacity_input: u32,
+    property_count_output: *mut u32,
+    properties: *mut ApiLayerProperties,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateApiLayerProperties>(
+        ENUMERATE_API_LAYER_PROPERTIES.load(Ordering::Relaxed),
+    );
+    let result = real(property_capacity_input, property_count_output, properties);
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateApiLayerProperties(property_capacity_input: {:?}, property_count_output: {:?}, properties: {:?}) -> {:?}", property_capacity_input, property_count_output, properties, result);
+    result
+}
+
+static ENUMERATE_INSTANCE_EXTENSION_PROPERTIES: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    layer_name: *const c_char,
+    property_capacity_input: u32,
+    property_count_output: *mut u32,
+    properties: *mut ExtensionProperties,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateInstanceExtensionProperties>(
+        ENUMERATE_INSTANCE_EXTENSION_PROPERTIES.load(Ordering::Relaxed),
+    );
+    let result = real(
+        layer_name,
+        property_capacity_input,
+        property_count_output,
+        properties,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateInstanceExtensionProperties(layer_name: {:?}, property_capacity_input: {:?}, property_count_output: {:?}, properties: {:?}) -> {:?}", layer_name, property_capacity_input, property_count_output, properties, result);
+    result
+}
+
+static CREATE_INSTANCE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_instance(
+    create_info: *const InstanceCreateInfo,
+    instance: *mut Instance,
+) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::CreateInstance>(CREATE_INSTANCE.load(Ordering::Relaxed));
+    let result = real(create_info, instance);
+    tracing::trace!(target: "openxr::ffi", "xrCreateInstance(create_info: {:?}, instance: {:?}) -> {:?}", create_info, instance, result);
+    result
+}
+
+static DESTROY_INSTANCE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_instance(instance: Instance) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::DestroyInstance>(DESTROY_INSTANCE.load(Ordering::Relaxed));
+    let result = real(instance);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyInstance(instance: {:?}) -> {:?}", instance, result);
+    result
+}
+
+static RESULT_TO_STRING: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn result_to_string(
+    instance: Instance,
+    value: Result,
+    buffer: *mut c_char,
+) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::ResultToString>(RESULT_TO_STRING.load(Ordering::Relaxed));
+    let result = real(instance, value, buffer);
+    tracing::trace!(target: "openxr::ffi", "xrResultToString(instance: {:?}, value: {:?}, buffer: {:?}) -> {:?}", instance, value, buffer, result);
+    result
+}
+
+static STRUCTURE_TYPE_TO_STRING: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn structure_type_to_string(
+    instance: Instance,
+    value: StructureType,
+    buffer: *mut c_char,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::StructureTypeToString>(
+        STRUCTURE_TYPE_TO_STRING.load(Ordering::Relaxed),
+    );
+    let result = real(instance, value, buffer);
+    tracing::trace!(target: "openxr::ffi", "xrStructureTypeToString(instance: {:?}, value: {:?}, buffer: {:?}) -> {:?}", instance, value, buffer, result);
+    result
+}
+
+static GET_INSTANCE_PROPERTIES: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_instance_properties(
+    instance: Instance,
+    instance_properties: *mut InstanceProperties,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetInstanceProperties>(
+        GET_INSTANCE_PROPERTIES.load(Ordering::Relaxed),
+    );
+    let result = real(instance, instance_properties);
+    tracing::trace!(target: "openxr::ffi", "xrGetInstanceProperties(instance: {:?}, instance_properties: {:?}) -> {:?}", instance, instance_properties, result);
+    result
+}
+
+static GET_SYSTEM: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_system(
+    instance: Instance,
+    get_info: *const SystemGetInfo,
+    system_id: *mut SystemId,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSystem>(GET_SYSTEM.load(Ordering::Relaxed));
+    let result = real(instance, get_info, system_id);
+    tracing::trace!(target: "openxr::ffi", "xrGetSystem(instance: {:?}, get_info: {:?}, system_id: {:?}) -> {:?}", instance, get_info, system_id, result);
+    result
+}
+
+static GET_SYSTEM_PROPERTIES: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_system_properties(
+    instance: Instance,
+    system_id: SystemId,
+    properties: *mut SystemProperties,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSystemProperties>(
+        GET_SYSTEM_PROPERTIES.load(Ordering::Relaxed),
+    );
+    let result = real(instance, system_id, properties);
+    tracing::trace!(target: "openxr::ffi", "xrGetSystemProperties(instance: {:?}, system_id: {:?}, properties: {:?}) -> {:?}", instance, system_id, properties, result);
+    result
+}
+
+static CREATE_SESSION: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_session(
+    instance: Instance,
+    create_info: *const SessionCreateInfo,
+    session: *mut Session,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateSession>(CREATE_SESSION.load(Ordering::Relaxed));
+    let result = real(instance, create_info, session);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSession(instance: {:?}, create_info: {:?}, session: {:?}) -> {:?}", instance, create_info, session, result);
+    result
+}
+
+static DESTROY_SESSION: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_session(session: Session) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::DestroySession>(DESTROY_SESSION.load(Ordering::Relaxed));
+    let result = real(session);
+    tracing::trace!(target: "openxr::ffi", "xrDestroySession(session: {:?}) -> {:?}", session, result);
+    result
+}
+
+static DESTROY_SPACE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_space(space: Space) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroySpace>(DESTROY_SPACE.load(Ordering::Relaxed));
+    let result = real(space);
+    tracing::trace!(target: "openxr::ffi", "xrDestroySpace(space: {:?}) -> {:?}", space, result);
+    result
+}
+
+static ENUMERATE_SWAPCHAIN_FORMATS: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_swapchain_formats(
+    session: Session,
+    format_capacity_input: u32,
+    format_count_output: *mut u32,
+    formats: *mut i64,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateSwapchainFormats>(
+        ENUMERATE_SWAPCHAIN_FORMATS.load(Ordering::Relaxed),
+    );
+    let result = real(session, format_capacity_input, format_count_output, formats);
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateSwapchainFormats(session: {:?}, format_capacity_input: {:?}, format_count_output: {:?}, formats: {:?}) -> {:?}", session, format_capacity_input, format_count_output, formats, result);
+    result
+}
+
+static CREATE_SWAPCHAIN: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_swapchain(
+    session: Session,
+    create_info: *const SwapchainCreateInfo,
+    swapchain: *mut Swapchain,
+) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::CreateSwapchain>(CREATE_SWAPCHAIN.load(Ordering::Relaxed));
+    let result = real(session, create_info, swapchain);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSwapchain(session: {:?}, create_info: {:?}, swapchain: {:?}) -> {:?}", session, create_info, swapchain, result);
+    result
+}
+
+static DESTROY_SWAPCHAIN: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_swapchain(swapchain: Swapchain) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::DestroySwapchain>(DESTROY_SWAPCHAIN.load(Ordering::Relaxed));
+    let result = real(swapchain);
+    tracing::trace!(target: "openxr::ffi", "xrDestroySwapchain(swapchain: {:?}) -> {:?}", swapchain, result);
+    result
+}
+
+static ENUMERATE_SWAPCHAIN_IMAGES: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_swapchain_images(
+    swapchain: Swapchain,
+    image_capacity_input: u32,
+    image_count_output: *mut u32,
+    images: *mut SwapchainImageBaseHeader,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateSwapchainImages>(
+        ENUMERATE_SWAPCHAIN_IMAGES.load(Ordering::Relaxed),
+    );
+    let result = real(swapchain, image_capacity_input, image_count_output, images);
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateSwapchainImages(swapchain: {:?}, image_capacity_input: {:?}, image_count_output: {:?}, images: {:?}) -> {:?}", swapchain, image_capacity_input, image_count_output, images, result);
+    result
+}
+
+static ACQUIRE_SWAPCHAIN_IMAGE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn acquire_swapchain_image(
+    swapchain: Swapchain,
+    acquire_info: *const SwapchainImageAcquireInfo,
+    index: *mut u32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::AcquireSwapchainImage>(
+        ACQUIRE_SWAPCHAIN_IMAGE.load(Ordering::Relaxed),
+    );
+    let result = real(swapchain, acquire_info, index);
+    tracing::trace!(target: "openxr::ffi", "xrAcquireSwapchainImage(swapchain: {:?}, acquire_info: {:?}, index: {:?}) -> {:?}", swapchain, acquire_info, index, result);
+    result
+}
+
+static WAIT_SWAPCHAIN_IMAGE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn wait_swapchain_image(
+    swapchain: Swapchain,
+    wait_info: *const SwapchainImageWaitInfo,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::WaitSwapchainImage>(
+        WAIT_SWAPCHAIN_IMAGE.load(Ordering::Relaxed),
+    );
+    let result = real(swapchain, wait_info);
+    tracing::trace!(target: "openxr::ffi", "xrWaitSwapchainImage(swapchain: {:?}, wait_info: {:?}) -> {:?}", swapchain, wait_info, result);
+    result
+}
+
+static RELEASE_SWAPCHAIN_IMAGE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn release_swapchain_image(
+    swapchain: Swapchain,
+    release_info: *const SwapchainImageReleaseInfo,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ReleaseSwapchainImage>(
+        RELEASE_SWAPCHAIN_IMAGE.load(Ordering::Relaxed),
+    );
+    let result = real(swapchain, release_info);
+    tracing::trace!(target: "openxr::ffi", "xrReleaseSwapchainImage(swapchain: {:?}, release_info: {:?}) -> {:?}", swapchain, release_info, result);
+    result
+}
+
+static BEGIN_SESSION: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn begin_session(
+    session: Session,
+    begin_info: *const SessionBeginInfo,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::BeginSession>(BEGIN_SESSION.load(Ordering::Relaxed));
+    let result = real(session, begin_info);
+    tracing::trace!(target: "openxr::ffi", "xrBeginSession(session: {:?}, begin_info: {:?}) -> {:?}", session, begin_info, result);
+    result
+}
+
+static END_SESSION: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn end_session(session: Session) -> Result {
+    let real = mem::transmute::<usize, pfn::EndSession>(END_SESSION.load(Ordering::Relaxed));
+    let result = real(session);
+    tracing::trace!(target: "openxr::ffi", "xrEndSession(session: {:?}) -> {:?}", session, result);
+    result
+}
+
+static REQUEST_EXIT_SESSION: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn request_exit_session(session: Session) -> Result {
+    let real = mem::transmute::<usize, pfn::RequestExitSession>(
+        REQUEST_EXIT_SESSION.load(Ordering::Relaxed),
+    );
+    let result = real(session);
+    tracing::trace!(target: "openxr::ffi", "xrRequestExitSession(session: {:?}) -> {:?}", session, result);
+    result
+}
+
+static ENUMERATE_REFERENCE_SPACES: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_reference_spaces(
+    session: Session,
+    space_capacity_input: u32,
+    space_count_output: *mut u32,
+    spaces: *mut ReferenceSpaceType,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateReferenceSpaces>(
+        ENUMERATE_REFERENCE_SPACES.load(Ordering::Relaxed),
+    );
+    let result = real(session, space_capacity_input, space_count_output, spaces);
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateReferenceSpaces(session: {:?}, space_capacity_input: {:?}, space_count_output: {:?}, spaces: {:?}) -> {:?}", session, space_capacity_input, space_count_output, spaces, result);
+    result
+}
+
+static CREATE_REFERENCE_SPACE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_reference_space(
+    session: Session,
+    create_info: *const ReferenceSpaceCreateInfo,
+    space: *mut Space,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateReferenceSpace>(
+        CREATE_REFERENCE_SPACE.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, space);
+    tracing::trace!(target: "openxr::ffi", "xrCreateReferenceSpace(session: {:?}, create_info: {:?}, space: {:?}) -> {:?}", session, create_info, space, result);
+    result
+}
+
+static CREATE_ACTION_SPACE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_action_space(
+    session: Session,
+    create_info: *const ActionSpaceCreateInfo,
+    space: *mut Space,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateActionSpace>(
+        CREATE_ACTION_SPACE.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, space);
+    tracing::trace!(target: "openxr::ffi", "xrCreateActionSpace(session: {:?}, create_info: {:?}, space: {:?}) -> {:?}", session, create_info, space, result);
+    result
+}
+
+static LOCATE_SPACE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn locate_space(
+    space: Space,
+    base_space: Space,
+    time: Time,
+    location: *mut SpaceLocation,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::LocateSpace>(LOCATE_SPACE.load(Ordering::Relaxed));
+    let result = real(space, base_space, time, location);
+    tracing::trace!(target: "openxr::ffi", "xrLocateSpace(space: {:?}, base_space: {:?}, time: {:?}, location: {:?}) -> {:?}", space, base_space, time, location, result);
+    result
+}
+
+static ENUMERATE_VIEW_CONFIGURATIONS: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_view_configurations(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type_capacity_input: u32,
+    view_configuration_type_count_output: *mut u32,
+    view_configuration_types: *mut ViewConfigurationType,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateViewConfigurations>(
+        ENUMERATE_VIEW_CONFIGURATIONS.load(Ordering::Relaxed),
+    );
+    let result = real(
+        instance,
+        system_id,
+        view_configuration_type_capacity_input,
+        view_configuration_type_count_output,
+        view_configuration_types,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateViewConfigurations(instance: {:?}, system_id: {:?}, view_configuration_type_capacity_input: {:?}, view_configuration_type_count_output: {:?}, view_configuration_types: {:?}) -> {:?}", instance, system_id, view_configuration_type_capacity_input, view_configuration_type_count_output, view_configuration_types, result);
+    result
+}
+
+static ENUMERATE_ENVIRONMENT_BLEND_MODES: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_environment_blend_modes(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    environment_blend_mode_capacity_input: u32,
+    environment_blend_mode_count_output: *mut u32,
+    environment_blend_modes: *mut EnvironmentBlendMode,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateEnvironmentBlendModes>(
+        ENUMERATE_ENVIRONMENT_BLEND_MODES.load(Ordering::Relaxed),
+    );
+    let result = real(
+        instance,
+        system_id,
+        view_configuration_type,
+        environment_blend_mode_capacity_input,
+        environment_blend_mode_count_output,
+        environment_blend_modes,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateEnvironmentBlendModes(instance: {:?}, system_id: {:?}, view_configuration_type: {:?}, environment_blend_mode_capacity_input: {:?}, environment_blend_mode_count_output: {:?}, environment_blend_modes: {:?}) -> {:?}", instance, system_id, view_configuration_type, environment_blend_mode_capacity_input, environment_blend_mode_count_output, environment_blend_modes, result);
+    result
+}
+
+static GET_VIEW_CONFIGURATION_PROPERTIES: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_view_configuration_properties(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    configuration_properties: *mut ViewConfigurationProperties,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetViewConfigurationProperties>(
+        GET_VIEW_CONFIGURATION_PROPERTIES.load(Ordering::Relaxed),
+    );
+    let result = real(
+        instance,
+        system_id,
+        view_configuration_type,
+        configuration_properties,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrGetViewConfigurationProperties(instance: {:?}, system_id: {:?}, view_configuration_type: {:?}, configuration_properties: {:?}) -> {:?}", instance, system_id, view_configuration_type, configuration_properties, result);
+    result
+}
+
+static ENUMERATE_VIEW_CONFIGURATION_VIEWS: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_view_configuration_views(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    view_capacity_input: u32,
+    view_count_output: *mut u32,
+    views: *mut ViewConfigurationView,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateViewConfigurationViews>(
+        ENUMERATE_VIEW_CONFIGURATION_VIEWS.load(Ordering::Relaxed),
+    );
+    let result = real(
+        instance,
+        system_id,
+        view_configuration_type,
+        view_capacity_input,
+        view_count_output,
+        views,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateViewConfigurationViews(instance: {:?}, system_id: {:?}, view_configuration_type: {:?}, view_capacity_input: {:?}, view_count_output: {:?}, views: {:?}) -> {:?}", instance, system_id, view_configuration_type, view_capacity_input, view_count_output, views, result);
+    result
+}
+
+static BEGIN_FRAME: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn begin_frame(
+    session: Session,
+    frame_begin_info: *const FrameBeginInfo,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::BeginFrame>(BEGIN_FRAME.load(Ordering::Relaxed));
+    let result = real(session, frame_begin_info);
+    tracing::trace!(target: "openxr::ffi", "xrBeginFrame(session: {:?}, frame_begin_info: {:?}) -> {:?}", session, frame_begin_info, result);
+    result
+}
+
+static LOCATE_VIEWS: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn locate_views(
+    session: Session,
+    view_locate_info: *const ViewLocateInfo,
+    view_state: *mut ViewState,
+    view_capacity_input: u32,
+    view_count_output: *mut u32,
+    views: *mut View,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::LocateViews>(LOCATE_VIEWS.load(Ordering::Relaxed));
+    let result = real(
+        session,
+        view_locate_info,
+        view_state,
+        view_capacity_input,
+        view_count_output,
+        views,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrLocateViews(session: {:?}, view_locate_info: {:?}, view_state: {:?}, view_capacity_input: {:?}, view_count_output: {:?}, views: {:?}) -> {:?}", session, view_locate_info, view_state, view_capacity_input, view_count_output, views, result);
+    result
+}
+
+static END_FRAME: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn end_frame(
+    session: Session,
+    frame_end_info: *const FrameEndInfo,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EndFrame>(END_FRAME.load(Ordering::Relaxed));
+    let result = real(session, frame_end_info);
+    tracing::trace!(target: "openxr::ffi", "xrEndFrame(session: {:?}, frame_end_info: {:?}) -> {:?}", session, frame_end_info, result);
+    result
+}
+
+static WAIT_FRAME: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn wait_frame(
+    session: Session,
+    frame_wait_info: *const FrameWaitInfo,
+    frame_state: *mut FrameState,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::WaitFrame>(WAIT_FRAME.load(Ordering::Relaxed));
+    let result = real(session, frame_wait_info, frame_state);
+    tracing::trace!(target: "openxr::ffi", "xrWaitFrame(session: {:?}, frame_wait_info: {:?}, frame_state: {:?}) -> {:?}", session, frame_wait_info, frame_state, result);
+    result
+}
+
+static APPLY_HAPTIC_FEEDBACK: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn apply_haptic_feedback(
+    session: Session,
+    haptic_action_info: *const HapticActionInfo,
+    haptic_feedback: *const HapticBaseHeader,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ApplyHapticFeedback>(
+        APPLY_HAPTIC_FEEDBACK.load(Ordering::Relaxed),
+    );
+    let result = real(session, haptic_action_info, haptic_feedback);
+    tracing::trace!(target: "openxr::ffi", "xrApplyHapticFeedback(session: {:?}, haptic_action_info: {:?}, haptic_feedback: {:?}) -> {:?}", session, haptic_action_info, haptic_feedback, result);
+    result
+}
+
+static STOP_HAPTIC_FEEDBACK: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn stop_haptic_feedback(
+    session: Session,
+    haptic_action_info: *const HapticActionInfo,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::StopHapticFeedback>(
+        STOP_HAPTIC_FEEDBACK.load(Ordering::Relaxed),
+    );
+    let result = real(session, haptic_action_info);
+    tracing::trace!(target: "openxr::ffi", "xrStopHapticFeedback(session: {:?}, haptic_action_info: {:?}) -> {:?}", session, haptic_action_info, result);
+    result
+}
+
+static POLL_EVENT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn poll_event(
+    instance: Instance,
+    event_data: *mut EventDataBuffer,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::PollEvent>(POLL_EVENT.load(Ordering::Relaxed));
+    let result = real(instance, event_data);
+    tracing::trace!(target: "openxr::ffi", "xrPollEvent(instance: {:?}, event_data: {:?}) -> {:?}", instance, event_data, result);
+    result
+}
+
+static STRING_TO_PATH: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn string_to_path(
+    instance: Instance,
+    path_string: *const c_char,
+    path: *mut Path,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::StringToPath>(STRING_TO_PATH.load(Ordering::Relaxed));
+    let result = real(instance, path_string, path);
+    tracing::trace!(target: "openxr::ffi", "xrStringToPath(instance: {:?}, path_string: {:?}, path: {:?}) -> {:?}", instance, path_string, path, result);
+    result
+}
+
+static PATH_TO_STRING: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn path_to_string(
+    instance: Instance,
+    path: Path,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::PathToString>(PATH_TO_STRING.load(Ordering::Relaxed));
+    let result = real(
+        instance,
+        path,
+        buffer_capacity_input,
+        buffer_count_output,
+        buffer,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrPathToString(instance: {:?}, path: {:?}, buffer_capacity_input: {:?}, buffer_count_output: {:?}, buffer: {:?}) -> {:?}", instance, path, buffer_capacity_input, buffer_count_output, buffer, result);
+    result
+}
+
+static GET_REFERENCE_SPACE_BOUNDS_RECT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_reference_space_bounds_rect(
+    session: Session,
+    reference_space_type: ReferenceSpaceType,
+    bounds: *mut Extent2Df,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetReferenceSpaceBoundsRect>(
+        GET_REFERENCE_SPACE_BOUNDS_RECT.load(Ordering::Relaxed),
+    );
+    let result = real(session, reference_space_type, bounds);
+    tracing::trace!(target: "openxr::ffi", "xrGetReferenceSpaceBoundsRect(session: {:?}, reference_space_type: {:?}, bounds: {:?}) -> {:?}", session, reference_space_type, bounds, result);
+    result
+}
+
+#[cfg(target_os = "android")]
+static SET_ANDROID_APPLICATION_THREAD_KHR: AtomicUsize = AtomicUsize::new(0);
+#[cfg(target_os = "android")]
+unsafe extern "system" fn set_android_application_thread_khr(
+    session: Session,
+    thread_type: AndroidThreadTypeKHR,
+    thread_id: u32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetAndroidApplicationThreadKHR>(
+        SET_ANDROID_APPLICATION_THREAD_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(session, thread_type, thread_id);
+    tracing::trace!(target: "openxr::ffi", "xrSetAndroidApplicationThreadKHR(session: {:?}, thread_type: {:?}, thread_id: {:?}) -> {:?}", session, thread_type, thread_id, result);
+    result
+}
+
+#[cfg(target_os = "android")]
+static CREATE_SWAPCHAIN_ANDROID_SURFACE_KHR: AtomicUsize = AtomicUsize::new(0);
+#[cfg(target_os = "android")]
+unsafe extern "system" fn create_swapchain_android_surface_khr(
+    session: Session,
+    info: *const SwapchainCreateInfo,
+    swapchain: *mut Swapchain,
+    surface: *mut jobject,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateSwapchainAndroidSurfaceKHR>(
+        CREATE_SWAPCHAIN_ANDROID_SURFACE_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(session, info, swapchain, surface);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSwapchainAndroidSurfaceKHR(session: {:?}, info: {:?}, swapchain: {:?}, surface: {:?}) -> {:?}", session, info, swapchain, surface, result);
+    result
+}
+
+static GET_ACTION_STATE_BOOLEAN: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_action_state_boolean(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateBoolean,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetActionStateBoolean>(
+        GET_ACTION_STATE_BOOLEAN.load(Ordering::Relaxed),
+    );
+    let result = real(session, get_info, state);
+    tracing::trace!(target: "openxr::ffi", "xrGetActionStateBoolean(session: {:?}, get_info: {:?}, state: {:?}) -> {:?}", session, get_info, state, result);
+    result
+}
+
+static GET_ACTION_STATE_FLOAT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_action_state_float(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateFloat,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetActionStateFloat>(
+        GET_ACTION_STATE_FLOAT.load(Ordering::Relaxed),
+    );
+    let result = real(session, get_info, state);
+    tracing::trace!(target: "openxr::ffi", "xrGetActionStateFloat(session: {:?}, get_info: {:?}, state: {:?}) -> {:?}", session, get_info, state, result);
+    result
+}
+
+static GET_ACTION_STATE_VECTOR2F: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_action_state_vector2f(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateVector2f,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetActionStateVector2f>(
+        GET_ACTION_STATE_VECTOR2F.load(Ordering::Relaxed),
+    );
+    let result = real(session, get_info, state);
+    tracing::trace!(target: "openxr::ffi", "xrGetActionStateVector2f(session: {:?}, get_info: {:?}, state: {:?}) -> {:?}", session, get_info, state, result);
+    result
+}
+
+static GET_ACTION_STATE_POSE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_action_state_pose(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStatePose,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetActionStatePose>(
+        GET_ACTION_STATE_POSE.load(Ordering::Relaxed),
+    );
+    let result = real(session, get_info, state);
+    tracing::trace!(target: "openxr::ffi", "xrGetActionStatePose(session: {:?}, get_info: {:?}, state: {:?}) -> {:?}", session, get_info, state, result);
+    result
+}
+
+static CREATE_ACTION_SET: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_action_set(
+    instance: Instance,
+    create_info: *const ActionSetCreateInfo,
+    action_set: *mut ActionSet,
+) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::CreateActionSet>(CREATE_ACTION_SET.load(Ordering::Relaxed));
+    let result = real(instance, create_info, action_set);
+    tracing::trace!(target: "openxr::ffi", "xrCreateActionSet(instance: {:?}, create_info: {:?}, action_set: {:?}) -> {:?}", instance, create_info, action_set, result);
+    result
+}
+
+static DESTROY_ACTION_SET: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_action_set(action_set: ActionSet) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::DestroyActionSet>(DESTROY_ACTION_SET.load(Ordering::Relaxed));
+    let result = real(action_set);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyActionSet(action_set: {:?}) -> {:?}", action_set, result);
+    result
+}
+
+static CREATE_ACTION: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_action(
+    action_set: ActionSet,
+    create_info: *const ActionCreateInfo,
+    action: *mut Action,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateAction>(CREATE_ACTION.load(Ordering::Relaxed));
+    let result = real(action_set, create_info, action);
+    tracing::trace!(target: "openxr::ffi", "xrCreateAction(action_set: {:?}, create_info: {:?}, action: {:?}) -> {:?}", action_set, create_info, action, result);
+    result
+}
+
+static DESTROY_ACTION: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_action(action: Action) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyAction>(DESTROY_ACTION.load(Ordering::Relaxed));
+    let result = real(action);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyAction(action: {:?}) -> {:?}", action, result);
+    result
+}
+
+static SUGGEST_INTERACTION_PROFILE_BINDINGS: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn suggest_interaction_profile_bindings(
+    instance: Instance,
+    suggested_bindings: *const InteractionProfileSuggestedBinding,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SuggestInteractionProfileBindings>(
+        SUGGEST_INTERACTION_PROFILE_BINDINGS.load(Ordering::Relaxed),
+    );
+    let result = real(instance, suggested_bindings);
+    tracing::trace!(target: "openxr::ffi", "xrSuggestInteractionProfileBindings(instance: {:?}, suggested_bindings: {:?}) -> {:?}", instance, suggested_bindings, result);
+    result
+}
+
+static ATTACH_SESSION_ACTION_SETS: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn attach_session_action_sets(
+    session: Session,
+    attach_info: *const SessionActionSetsAttachInfo,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::AttachSessionActionSets>(
+        ATTACH_SESSION_ACTION_SETS.load(Ordering::Relaxed),
+    );
+    let result = real(session, attach_info);
+    tracing::trace!(target: "openxr::ffi", "xrAttachSessionActionSets(session: {:?}, attach_info: {:?}) -> {:?}", session, attach_info, result);
+    result
+}
+
+static GET_CURRENT_INTERACTION_PROFILE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_current_interaction_profile(
+    session: Session,
+    top_level_user_path: Path,
+    interaction_profile: *mut InteractionProfileState,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetCurrentInteractionProfile>(
+        GET_CURRENT_INTERACTION_PROFILE.load(Ordering::Relaxed),
+    );
+    let result = real(session, top_level_user_path, interaction_profile);
+    tracing::trace!(target: "openxr::ffi", "xrGetCurrentInteractionProfile(session: {:?}, top_level_user_path: {:?}, interaction_profile: {:?}) -> {:?}", session, top_level_user_path, interaction_profile, result);
+    result
+}
+
+static SYNC_ACTIONS: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn sync_actions(
+    session: Session,
+    sync_info: *const ActionsSyncInfo,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SyncActions>(SYNC_ACTIONS.load(Ordering::Relaxed));
+    let result = real(session, sync_info);
+    tracing::trace!(target: "openxr::ffi", "xrSyncActions(session: {:?}, sync_info: {:?}) -> {:?}", session, sync_info, result);
+    result
+}
+
+static ENUMERATE_BOUND_SOURCES_FOR_ACTION: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_bound_sources_for_action(
+    session: Session,
+    enumerate_info: *const BoundSourcesForActionEnumerateInfo,
+    source_capacity_input: u32,
+    source_count_output: *mut u32,
+    sources: *mut Path,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateBoundSourcesForAction>(
+        ENUMERATE_BOUND_SOURCES_FOR_ACTION.load(Ordering::Relaxed),
+    );
+    let result = real(
+        session,
+        enumerate_info,
+        source_capacity_input,
+        source_count_output,
+        sources,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateBoundSourcesForAction(session: {:?}, enumerate_info: {:?}, source_capacity_input: {:?}, source_count_output: {:?}, sources: {:?}) -> {:?}", session, enumerate_info, source_capacity_input, source_count_output, sources, result);
+    result
+}
+
+static GET_INPUT_SOURCE_LOCALIZED_NAME: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_input_source_localized_name(
+    session: Session,
+    get_info: *const InputSourceLocalizedNameGetInfo,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetInputSourceLocalizedName>(
+        GET_INPUT_SOURCE_LOCALIZED_NAME.load(Ordering::Relaxed),
+    );
+    let result = real(
+        session,
+        get_info,
+        buffer_capacity_input,
+        buffer_count_output,
+        buffer,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrGetInputSourceLocalizedName(session: {:?}, get_info: {:?}, buffer_capacity_input: {:?}, buffer_count_output: {:?}, buffer: {:?}) -> {:?}", session, get_info, buffer_capacity_input, buffer_count_output, buffer, result);
+    result
+}
+
+static GET_VULKAN_INSTANCE_EXTENSIONS_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_vulkan_instance_extensions_khr(
+    instance: Instance,
+    system_id: SystemId,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetVulkanInstanceExtensionsKHR>(
+        GET_VULKAN_INSTANCE_EXTENSIONS_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(
+        instance,
+        system_id,
+        buffer_capacity_input,
+        buffer_count_output,
+        buffer,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrGetVulkanInstanceExtensionsKHR(instance: {:?}, system_id: {:?}, buffer_capacity_input: {:?}, buffer_count_output: {:?}, buffer: {:?}) -> {:?}", instance, system_id, buffer_capacity_input, buffer_count_output, buffer, result);
+    result
+}
+
+static GET_VULKAN_DEVICE_EXTENSIONS_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_vulkan_device_extensions_khr(
+    instance: Instance,
+    system_id: SystemId,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetVulkanDeviceExtensionsKHR>(
+        GET_VULKAN_DEVICE_EXTENSIONS_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(
+        instance,
+        system_id,
+        buffer_capacity_input,
+        buffer_count_output,
+        buffer,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrGetVulkanDeviceExtensionsKHR(instance: {:?}, system_id: {:?}, buffer_capacity_input: {:?}, buffer_count_output: {:?}, buffer: {:?}) -> {:?}", instance, system_id, buffer_capacity_input, buffer_count_output, buffer, result);
+    result
+}
+
+static GET_VULKAN_GRAPHICS_DEVICE_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_vulkan_graphics_device_khr(
+    instance: Instance,
+    system_id: SystemId,
+    vk_instance: VkInstance,
+    vk_physical_device: *mut VkPhysicalDevice,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetVulkanGraphicsDeviceKHR>(
+        GET_VULKAN_GRAPHICS_DEVICE_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, system_id, vk_instance, vk_physical_device);
+    tracing::trace!(target: "openxr::ffi", "xrGetVulkanGraphicsDeviceKHR(instance: {:?}, system_id: {:?}, vk_instance: {:?}, vk_physical_device: {:?}) -> {:?}", instance, system_id, vk_instance, vk_physical_device, result);
+    result
+}
+
+static GET_OPEN_GL_GRAPHICS_REQUIREMENTS_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_open_gl_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsOpenGLKHR,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetOpenGLGraphicsRequirementsKHR>(
+        GET_OPEN_GL_GRAPHICS_REQUIREMENTS_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, system_id, graphics_requirements);
+    tracing::trace!(target: "openxr::ffi", "xrGetOpenGLGraphicsRequirementsKHR(instance: {:?}, system_id: {:?}, graphics_requirements: {:?}) -> {:?}", instance, system_id, graphics_requirements, result);
+    result
+}
+
+static GET_OPEN_GLES_GRAPHICS_REQUIREMENTS_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_open_gles_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsOpenGLESKHR,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetOpenGLESGraphicsRequirementsKHR>(
+        GET_OPEN_GLES_GRAPHICS_REQUIREMENTS_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, system_id, graphics_requirements);
+    tracing::trace!(target: "openxr::ffi", "xrGetOpenGLESGraphicsRequirementsKHR(instance: {:?}, system_id: {:?}, graphics_requirements: {:?}) -> {:?}", instance, system_id, graphics_requirements, result);
+    result
+}
+
+static GET_VULKAN_GRAPHICS_REQUIREMENTS_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_vulkan_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsVulkanKHR,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetVulkanGraphicsRequirementsKHR>(
+        GET_VULKAN_GRAPHICS_REQUIREMENTS_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, system_id, graphics_requirements);
+    tracing::trace!(target: "openxr::ffi", "xrGetVulkanGraphicsRequirementsKHR(instance: {:?}, system_id: {:?}, graphics_requirements: {:?}) -> {:?}", instance, system_id, graphics_requirements, result);
+    result
+}
+
+#[cfg(windows)]
+static GET_D3_D11_GRAPHICS_REQUIREMENTS_KHR: AtomicUsize = AtomicUsize::new(0);
+#[cfg(windows)]
+unsafe extern "system" fn get_d3_d11_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsD3D11KHR,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetD3D11GraphicsRequirementsKHR>(
+        GET_D3_D11_GRAPHICS_REQUIREMENTS_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, system_id, graphics_requirements);
+    tracing::trace!(target: "openxr::ffi", "xrGetD3D11GraphicsRequirementsKHR(instance: {:?}, system_id: {:?}, graphics_requirements: {:?}) -> {:?}", instance, system_id, graphics_requirements, result);
+    result
+}
+
+#[cfg(windows)]
+static GET_D3_D12_GRAPHICS_REQUIREMENTS_KHR: AtomicUsize = AtomicUsize::new(0);
+#[cfg(windows)]
+unsafe extern "system" fn get_d3_d12_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsD3D12KHR,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetD3D12GraphicsRequirementsKHR>(
+        GET_D3_D12_GRAPHICS_REQUIREMENTS_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, system_id, graphics_requirements);
+    tracing::trace!(target: "openxr::ffi", "xrGetD3D12GraphicsRequirementsKHR(instance: {:?}, system_id: {:?}, graphics_requirements: {:?}) -> {:?}", instance, system_id, graphics_requirements, result);
+    result
+}
+
+static PERF_SETTINGS_SET_PERFORMANCE_LEVEL_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn perf_settings_set_performance_level_ext(
+    session: Session,
+    domain: PerfSettingsDomainEXT,
+    level: PerfSettingsLevelEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::PerfSettingsSetPerformanceLevelEXT>(
+        PERF_SETTINGS_SET_PERFORMANCE_LEVEL_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(session, domain, level);
+    tracing::trace!(target: "openxr::ffi", "xrPerfSettingsSetPerformanceLevelEXT(session: {:?}, domain: {:?}, level: {:?}) -> {:?}", session, domain, level, result);
+    result
+}
+
+static THERMAL_GET_TEMPERATURE_TREND_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn thermal_get_temperature_trend_ext(
+    session: Session,
+    domain: PerfSettingsDomainEXT,
+    notification_level: *mut PerfSettingsNotificationLevelEXT,
+    temp_headroom: *mut f32,
+    temp_slope: *mut f32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ThermalGetTemperatureTrendEXT>(
+        THERMAL_GET_TEMPERATURE_TREND_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(
+        session,
+        domain,
+        notification_level,
+        temp_headroom,
+        temp_slope,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrThermalGetTemperatureTrendEXT(session: {:?}, domain: {:?}, notification_level: {:?}, temp_headroom: {:?}, temp_slope: {:?}) -> {:?}", session, domain, notification_level, temp_headroom, temp_slope, result);
+    result
+}
+
+static SET_DEBUG_UTILS_OBJECT_NAME_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_debug_utils_object_name_ext(
+    instance: Instance,
+    name_info: *const DebugUtilsObjectNameInfoEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetDebugUtilsObjectNameEXT>(
+        SET_DEBUG_UTILS_OBJECT_NAME_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(instance, name_info);
+    tracing::trace!(target: "openxr::ffi", "xrSetDebugUtilsObjectNameEXT(instance: {:?}, name_info: {:?}) -> {:?}", instance, name_info, result);
+    result
+}
+
+static CREATE_DEBUG_UTILS_MESSENGER_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_debug_utils_messenger_ext(
+    instance: Instance,
+    create_info: *const DebugUtilsMessengerCreateInfoEXT,
+    messenger: *mut DebugUtilsMessengerEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateDebugUtilsMessengerEXT>(
+        CREATE_DEBUG_UTILS_MESSENGER_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(instance, create_info, messenger);
+    tracing::trace!(target: "openxr::ffi", "xrCreateDebugUtilsMessengerEXT(instance: {:?}, create_info: {:?}, messenger: {:?}) -> {:?}", instance, create_info, messenger, result);
+    result
+}
+
+static DESTROY_DEBUG_UTILS_MESSENGER_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_debug_utils_messenger_ext(
+    messenger: DebugUtilsMessengerEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyDebugUtilsMessengerEXT>(
+        DESTROY_DEBUG_UTILS_MESSENGER_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(messenger);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyDebugUtilsMessengerEXT(messenger: {:?}) -> {:?}", messenger, result);
+    result
+}
+
+static SUBMIT_DEBUG_UTILS_MESSAGE_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn submit_debug_utils_message_ext(
+    instance: Instance,
+    message_severity: DebugUtilsMessageSeverityFlagsEXT,
+    message_types: DebugUtilsMessageTypeFlagsEXT,
+    callback_data: *const DebugUtilsMessengerCallbackDataEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SubmitDebugUtilsMessageEXT>(
+        SUBMIT_DEBUG_UTILS_MESSAGE_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(instance, message_severity, message_types, callback_data);
+    tracing::trace!(target: "openxr::ffi", "xrSubmitDebugUtilsMessageEXT(instance: {:?}, message_severity: {:?}, message_types: {:?}, callback_data: {:?}) -> {:?}", instance, message_severity, message_types, callback_data, result);
+    result
+}
+
+static SESSION_BEGIN_DEBUG_UTILS_LABEL_REGION_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn session_begin_debug_utils_label_region_ext(
+    session: Session,
+    label_info: *const DebugUtilsLabelEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SessionBeginDebugUtilsLabelRegionEXT>(
+        SESSION_BEGIN_DEBUG_UTILS_LABEL_REGION_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(session, label_info);
+    tracing::trace!(target: "openxr::ffi", "xrSessionBeginDebugUtilsLabelRegionEXT(session: {:?}, label_info: {:?}) -> {:?}", session, label_info, result);
+    result
+}
+
+static SESSION_END_DEBUG_UTILS_LABEL_REGION_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn session_end_debug_utils_label_region_ext(session: Session) -> Result {
+    let real = mem::transmute::<usize, pfn::SessionEndDebugUtilsLabelRegionEXT>(
+        SESSION_END_DEBUG_UTILS_LABEL_REGION_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(session);
+    tracing::trace!(target: "openxr::ffi", "xrSessionEndDebugUtilsLabelRegionEXT(session: {:?}) -> {:?}", session, result);
+    result
+}
+
+static SESSION_INSERT_DEBUG_UTILS_LABEL_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn session_insert_debug_utils_label_ext(
+    session: Session,
+    label_info: *const DebugUtilsLabelEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SessionInsertDebugUtilsLabelEXT>(
+        SESSION_INSERT_DEBUG_UTILS_LABEL_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(session, label_info);
+    tracing::trace!(target: "openxr::ffi", "xrSessionInsertDebugUtilsLabelEXT(session: {:?}, label_info: {:?}) -> {:?}", session, label_info, result);
+    result
+}
+
+#[cfg(windows)]
+static CONVERT_TIME_TO_WIN32_PERFORMANCE_COUNTER_KHR: AtomicUsize = AtomicUsize::new(0);
+#[cfg(windows)]
+unsafe extern "system" fn convert_time_to_win32_performance_counter_khr(
+    instance: Instance,
+    time: Time,
+    performance_counter: *mut LARGE_INTEGER,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ConvertTimeToWin32PerformanceCounterKHR>(
+        CONVERT_TIME_TO_WIN32_PERFORMANCE_COUNTER_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, time, performance_counter);
+    tracing::trace!(target: "openxr::ffi", "xrConvertTimeToWin32PerformanceCounterKHR(instance: {:?}, time: {:?}, performance_counter: {:?}) -> {:?}", instance, time, performance_counter, result);
+    result
+}
+
+#[cfg(windows)]
+static CONVERT_WIN32_PERFORMANCE_COUNTER_TO_TIME_KHR: AtomicUsize = AtomicUsize::new(0);
+#[cfg(windows)]
+unsafe extern "system" fn convert_win32_performance_counter_to_time_khr(
+    instance: Instance,
+    performance_counter: *const LARGE_INTEGER,
+    time: *mut Time,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ConvertWin32PerformanceCounterToTimeKHR>(
+        CONVERT_WIN32_PERFORMANCE_COUNTER_TO_TIME_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, performance_counter, time);
+    tracing::trace!(target: "openxr::ffi", "xrConvertWin32PerformanceCounterToTimeKHR(instance: {:?}, performance_counter: {:?}, time: {:?}) -> {:?}", instance, performance_counter, time, result);
+    result
+}
+
+static CREATE_VULKAN_INSTANCE_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_vulkan_instance_khr(
+    instance: Instance,
+    create_info: *const VulkanInstanceCreateInfoKHR,
+    vulkan_instance: *mut VkInstance,
+    vulkan_result: *mut VkResult,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateVulkanInstanceKHR>(
+        CREATE_VULKAN_INSTANCE_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, create_info, vulkan_instance, vulkan_result);
+    tracing::trace!(target: "openxr::ffi", "xrCreateVulkanInstanceKHR(instance: {:?}, create_info: {:?}, vulkan_instance: {:?}, vulkan_result: {:?}) -> {:?}", instance, create_info, vulkan_instance, vulkan_result, result);
+    result
+}
+
+static CREATE_VULKAN_DEVICE_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_vulkan_device_khr(
+    instance: Instance,
+    create_info: *const VulkanDeviceCreateInfoKHR,
+    vulkan_device: *mut VkDevice,
+    vulkan_result: *mut VkResult,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateVulkanDeviceKHR>(
+        CREATE_VULKAN_DEVICE_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, create_info, vulkan_device, vulkan_result);
+    tracing::trace!(target: "openxr::ffi", "xrCreateVulkanDeviceKHR(instance: {:?}, create_info: {:?}, vulkan_device: {:?}, vulkan_result: {:?}) -> {:?}", instance, create_info, vulkan_device, vulkan_result, result);
+    result
+}
+
+static GET_VULKAN_GRAPHICS_DEVICE2_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_vulkan_graphics_device2_khr(
+    instance: Instance,
+    get_info: *const VulkanGraphicsDeviceGetInfoKHR,
+    vulkan_physical_device: *mut VkPhysicalDevice,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetVulkanGraphicsDevice2KHR>(
+        GET_VULKAN_GRAPHICS_DEVICE2_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, get_info, vulkan_physical_device);
+    tracing::trace!(target: "openxr::ffi", "xrGetVulkanGraphicsDevice2KHR(instance: {:?}, get_info: {:?}, vulkan_physical_device: {:?}) -> {:?}", instance, get_info, vulkan_physical_device, result);
+    result
+}
+
+static CONVERT_TIME_TO_TIMESPEC_TIME_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn convert_time_to_timespec_time_khr(
+    instance: Instance,
+    time: Time,
+    timespec_time: *mut timespec,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ConvertTimeToTimespecTimeKHR>(
+        CONVERT_TIME_TO_TIMESPEC_TIME_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, time, timespec_time);
+    tracing::trace!(target: "openxr::ffi", "xrConvertTimeToTimespecTimeKHR(instance: {:?}, time: {:?}, timespec_time: {:?}) -> {:?}", instance, time, timespec_time, result);
+    result
+}
+
+static CONVERT_TIMESPEC_TIME_TO_TIME_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn convert_timespec_time_to_time_khr(
+    instance: Instance,
+    timespec_time: *const timespec,
+    time: *mut Time,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ConvertTimespecTimeToTimeKHR>(
+        CONVERT_TIMESPEC_TIME_TO_TIME_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, timespec_time, time);
+    tracing::trace!(target: "openxr::ffi", "xrConvertTimespecTimeToTimeKHR(instance: {:?}, timespec_time: {:?}, time: {:?}) -> {:?}", instance, timespec_time, time, result);
+    result
+}
+
+static GET_VISIBILITY_MASK_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_visibility_mask_khr(
+    session: Session,
+    view_configuration_type: ViewConfigurationType,
+    view_index: u32,
+    visibility_mask_type: VisibilityMaskTypeKHR,
+    visibility_mask: *mut VisibilityMaskKHR,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetVisibilityMaskKHR>(
+        GET_VISIBILITY_MASK_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(
+        session,
+        view_configuration_type,
+        view_index,
+        visibility_mask_type,
+        visibility_mask,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrGetVisibilityMaskKHR(session: {:?}, view_configuration_type: {:?}, view_index: {:?}, visibility_mask_type: {:?}, visibility_mask: {:?}) -> {:?}", session, view_configuration_type, view_index, visibility_mask_type, visibility_mask, result);
+    result
+}
+
+static CREATE_SPATIAL_ANCHOR_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_spatial_anchor_msft(
+    session: Session,
+    create_info: *const SpatialAnchorCreateInfoMSFT,
+    anchor: *mut SpatialAnchorMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateSpatialAnchorMSFT>(
+        CREATE_SPATIAL_ANCHOR_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, anchor);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSpatialAnchorMSFT(session: {:?}, create_info: {:?}, anchor: {:?}) -> {:?}", session, create_info, anchor, result);
+    result
+}
+
+static CREATE_SPATIAL_ANCHOR_SPACE_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_spatial_anchor_space_msft(
+    session: Session,
+    create_info: *const SpatialAnchorSpaceCreateInfoMSFT,
+    space: *mut Space,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateSpatialAnchorSpaceMSFT>(
+        CREATE_SPATIAL_ANCHOR_SPACE_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, space);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSpatialAnchorSpaceMSFT(session: {:?}, create_info: {:?}, space: {:?}) -> {:?}", session, create_info, space, result);
+    result
+}
+
+static DESTROY_SPATIAL_ANCHOR_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_spatial_anchor_msft(anchor: SpatialAnchorMSFT) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroySpatialAnchorMSFT>(
+        DESTROY_SPATIAL_ANCHOR_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(anchor);
+    tracing::trace!(target: "openxr::ffi", "xrDestroySpatialAnchorMSFT(anchor: {:?}) -> {:?}", anchor, result);
+    result
+}
+
+static SET_INPUT_DEVICE_ACTIVE_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_input_device_active_ext(
+    session: Session,
+    interaction_profile: Path,
+    top_level_path: Path,
+    is_active: Bool32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetInputDeviceActiveEXT>(
+        SET_INPUT_DEVICE_ACTIVE_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(session, interaction_profile, top_level_path, is_active);
+    tracing::trace!(target: "openxr::ffi", "xrSetInputDeviceActiveEXT(session: {:?}, interaction_profile: {:?}, top_level_path: {:?}, is_active: {:?}) -> {:?}", session, interaction_profile, top_level_path, is_active, result);
+    result
+}
+
+static SET_INPUT_DEVICE_STATE_BOOL_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_input_device_state_bool_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    state: Bool32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetInputDeviceStateBoolEXT>(
+        SET_INPUT_DEVICE_STATE_BOOL_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(session, top_level_path, input_source_path, state);
+    tracing::trace!(target: "openxr::ffi", "xrSetInputDeviceStateBoolEXT(session: {:?}, top_level_path: {:?}, input_source_path: {:?}, state: {:?}) -> {:?}", session, top_level_path, input_source_path, state, result);
+    result
+}
+
+static SET_INPUT_DEVICE_STATE_FLOAT_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_input_device_state_float_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    state: f32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetInputDeviceStateFloatEXT>(
+        SET_INPUT_DEVICE_STATE_FLOAT_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(session, top_level_path, input_source_path, state);
+    tracing::trace!(target: "openxr::ffi", "xrSetInputDeviceStateFloatEXT(session: {:?}, top_level_path: {:?}, input_source_path: {:?}, state: {:?}) -> {:?}", session, top_level_path, input_source_path, state, result);
+    result
+}
+
+static SET_INPUT_DEVICE_STATE_VECTOR2F_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_input_device_state_vector2f_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    state: Vector2f,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetInputDeviceStateVector2fEXT>(
+        SET_INPUT_DEVICE_STATE_VECTOR2F_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(session, top_level_path, input_source_path, state);
+    tracing::trace!(target: "openxr::ffi", "xrSetInputDeviceStateVector2fEXT(session: {:?}, top_level_path: {:?}, input_source_path: {:?}, state: {:?}) -> {:?}", session, top_level_path, input_source_path, state, result);
+    result
+}
+
+static SET_INPUT_DEVICE_LOCATION_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_input_device_location_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    space: Space,
+    pose: Posef,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetInputDeviceLocationEXT>(
+        SET_INPUT_DEVICE_LOCATION_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(session, top_level_path, input_source_path, space, pose);
+    tracing::trace!(target: "openxr::ffi", "xrSetInputDeviceLocationEXT(session: {:?}, top_level_path: {:?}, input_source_path: {:?}, space: {:?}, pose: {:?}) -> {:?}", session, top_level_path, input_source_path, space, pose, result);
+    result
+}
+
+static INITIALIZE_LOADER_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn initialize_loader_khr(
+    loader_init_info: *const LoaderInitInfoBaseHeaderKHR,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::InitializeLoaderKHR>(
+        INITIALIZE_LOADER_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(loader_init_info);
+    tracing::trace!(target: "openxr::ffi", "xrInitializeLoaderKHR(loader_init_info: {:?}) -> {:?}", loader_init_info, result);
+    result
+}
+
+static CREATE_SPATIAL_GRAPH_NODE_SPACE_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_spatial_graph_node_space_msft(
+    session: Session,
+    create_info: *const SpatialGraphNodeSpaceCreateInfoMSFT,
+    space: *mut Space,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateSpatialGraphNodeSpaceMSFT>(
+        CREATE_SPATIAL_GRAPH_NODE_SPACE_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, space);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSpatialGraphNodeSpaceMSFT(session: {:?}, create_info: {:?}, space: {:?}) -> {:?}", session, create_info, space, result);
+    result
+}
+
+static TRY_CREATE_SPATIAL_GRAPH_STATIC_NODE_BINDING_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn try_create_spatial_graph_static_node_binding_msft(
+    session: Session,
+    create_info: *const SpatialGraphStaticNodeBindingCreateInfoMSFT,
+    node_binding: *mut SpatialGraphNodeBindingMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::TryCreateSpatialGraphStaticNodeBindingMSFT>(
+        TRY_CREATE_SPATIAL_GRAPH_STATIC_NODE_BINDING_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, node_binding);
+    tracing::trace!(target: "openxr::ffi", "xrTryCreateSpatialGraphStaticNodeBindingMSFT(session: {:?}, create_info: {:?}, node_binding: {:?}) -> {:?}", session, create_info, node_binding, result);
+    result
+}
+
+static DESTROY_SPATIAL_GRAPH_NODE_BINDING_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_spatial_graph_node_binding_msft(
+    node_binding: SpatialGraphNodeBindingMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroySpatialGraphNodeBindingMSFT>(
+        DESTROY_SPATIAL_GRAPH_NODE_BINDING_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(node_binding);
+    tracing::trace!(target: "openxr::ffi", "xrDestroySpatialGraphNodeBindingMSFT(node_binding: {:?}) -> {:?}", node_binding, result);
+    result
+}
+
+static GET_SPATIAL_GRAPH_NODE_BINDING_PROPERTIES_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_spatial_graph_node_binding_properties_msft(
+    node_binding: SpatialGraphNodeBindingMSFT,
+    get_info: *const SpatialGraphNodeBindingPropertiesGetInfoMSFT,
+    properties: *mut SpatialGraphNodeBindingPropertiesMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSpatialGraphNodeBindingPropertiesMSFT>(
+        GET_SPATIAL_GRAPH_NODE_BINDING_PROPERTIES_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(node_binding, get_info, properties);
+    tracing::trace!(target: "openxr::ffi", "xrGetSpatialGraphNodeBindingPropertiesMSFT(node_binding: {:?}, get_info: {:?}, properties: {:?}) -> {:?}", node_binding, get_info, properties, result);
+    result
+}
+
+static CREATE_HAND_TRACKER_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_hand_tracker_ext(
+    session: Session,
+    create_info: *const HandTrackerCreateInfoEXT,
+    hand_tracker: *mut HandTrackerEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateHandTrackerEXT>(
+        CREATE_HAND_TRACKER_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, hand_tracker);
+    tracing::trace!(target: "openxr::ffi", "xrCreateHandTrackerEXT(session: {:?}, create_info: {:?}, hand_tracker: {:?}) -> {:?}", session, create_info, hand_tracker, result);
+    result
+}
+
+static DESTROY_HAND_TRACKER_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_hand_tracker_ext(hand_tracker: HandTrackerEXT) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyHandTrackerEXT>(
+        DESTROY_HAND_TRACKER_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(hand_tracker);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyHandTrackerEXT(hand_tracker: {:?}) -> {:?}", hand_tracker, result);
+    result
+}
+
+static LOCATE_HAND_JOINTS_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn locate_hand_joints_ext(
+    hand_tracker: HandTrackerEXT,
+    locate_info: *const HandJointsLocateInfoEXT,
+    locations: *mut HandJointLocationsEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::LocateHandJointsEXT>(
+        LOCATE_HAND_JOINTS_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(hand_tracker, locate_info, locations);
+    tracing::trace!(target: "openxr::ffi", "xrLocateHandJointsEXT(hand_tracker: {:?}, locate_info: {:?}, locations: {:?}) -> {:?}", hand_tracker, locate_info, locations, result);
+    result
+}
+
+static CREATE_FACE_TRACKER2_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_face_tracker2_fb(
+    session: Session,
+    create_info: *const FaceTrackerCreateInfo2FB,
+    face_tracker: *mut FaceTracker2FB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateFaceTracker2FB>(
+        CREATE_FACE_TRACKER2_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, face_tracker);
+    tracing::trace!(target: "openxr::ffi", "xrCreateFaceTrackerFB(session: {:?}, create_info: {:?}, face_tracker: {:?}) -> {:?}", session, create_info, face_tracker, result);
+    result
+}
+
+static DESTROY_FACE_TRACKER2_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_face_tracker2_fb(face_tracker: FaceTracker2FB) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyFaceTracker2FB>(
+        DESTROY_FACE_TRACKER2_FB.load(Ordering::Relaxed),
+    );
+    let result = real(face_tracker);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyFaceTrackerFB(face_tracker: {:?}) -> {:?}", face_tracker, result);
+    result
+}
+
+static GET_FACE_EXPRESSION_WEIGHTS2_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_face_expression_weights2_fb(
+    face_tracker: FaceTracker2FB,
+    expression_info: *const FaceExpressionInfo2FB,
+    expression_weights: *mut FaceExpressionWeights2FB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetFaceExpressionWeights2FB>(
+        GET_FACE_EXPRESSION_WEIGHTS2_FB.load(Ordering::Relaxed),
+    );
+    let result = real(face_tracker, expression_info, expression_weights);
+    tracing::trace!(target: "openxr::ffi", "xrGetFaceExpressionWeightsFB(face_tracker: {:?}, expression_info: {:?}, expression_weights: {:?}) -> {:?}", face_tracker, expression_info, expression_weights, result);
+    result
+}
+
+static CREATE_BODY_TRACKER_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_body_tracker_fb(
+    session: Session,
+    create_info: *const BodyTrackerCreateInfoFB,
+    body_tracker: *mut BodyTrackerFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateBodyTrackerFB>(
+        CREATE_BODY_TRACKER_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, body_tracker);
+    tracing::trace!(target: "openxr::ffi", "xrCreateBodyTrackerFB(session: {:?}, create_info: {:?}, body_tracker: {:?}) -> {:?}", session, create_info, body_tracker, result);
+    result
+}
+
+static DESTROY_BODY_TRACKER_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_body_tracker_fb(body_tracker: BodyTrackerFB) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyBodyTrackerFB>(
+        DESTROY_BODY_TRACKER_FB.load(Ordering::Relaxed),
+    );
+    let result = real(body_tracker);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyBodyTrackerFB(body_tracker: {:?}) -> {:?}", body_tracker, result);
+    result
+}
+
+static LOCATE_BODY_JOINTS_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn locate_body_joints_fb(
+    body_tracker: BodyTrackerFB,
+    locate_info: *const BodyJointsLocateInfoFB,
+    locations: *mut BodyJointLocationsFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::LocateBodyJointsFB>(
+        LOCATE_BODY_JOINTS_FB.load(Ordering::Relaxed),
+    );
+    let result = real(body_tracker, locate_info, locations);
+    tracing::trace!(target: "openxr::ffi", "xrLocateBodyJointsFB(body_tracker: {:?}, locate_info: {:?}, locations: {:?}) -> {:?}", body_tracker, locate_info, locations, result);
+    result
+}
+
+static GET_BODY_SKELETON_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_body_skeleton_fb(
+    body_tracker: BodyTrackerFB,
+    skeleton: *mut BodySkeletonFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetBodySkeletonFB>(
+        GET_BODY_SKELETON_FB.load(Ordering::Relaxed),
+    );
+    let result = real(body_tracker, skeleton);
+    tracing::trace!(target: "openxr::ffi", "xrGetBodySkeletonFB(body_tracker: {:?}, skeleton: {:?}) -> {:?}", body_tracker, skeleton, result);
+    result
+}
+
+static CREATE_EYE_TRACKER_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_eye_tracker_fb(
+    session: Session,
+    create_info: *const EyeTrackerCreateInfoFB,
+    eye_tracker: *mut EyeTrackerFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateEyeTrackerFB>(
+        CREATE_EYE_TRACKER_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, eye_tracker);
+    tracing::trace!(target: "openxr::ffi", "xrCreateEyeTrackerFB(session: {:?}, create_info: {:?}, eye_tracker: {:?}) -> {:?}", session, create_info, eye_tracker, result);
+    result
+}
+
+static DESTROY_EYE_TRACKER_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_eye_tracker_fb(eye_tracker: EyeTrackerFB) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyEyeTrackerFB>(
+        DESTROY_EYE_TRACKER_FB.load(Ordering::Relaxed),
+    );
+    let result = real(eye_tracker);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyEyeTrackerFB(eye_tracker: {:?}) -> {:?}", eye_tracker, result);
+    result
+}
+
+static GET_EYE_GAZES_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_eye_gazes_fb(
+    eye_tracker: EyeTrackerFB,
+    gaze_info: *const EyeGazesInfoFB,
+    eye_gazes: *mut EyeGazesFB,
+) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::GetEyeGazesFB>(GET_EYE_GAZES_FB.load(Ordering::Relaxed));
+    let result = real(eye_tracker, gaze_info, eye_gazes);
+    tracing::trace!(target: "openxr::ffi", "xrGetEyeGazesFB(eye_tracker: {:?}, gaze_info: {:?}, eye_gazes: {:?}) -> {:?}", eye_tracker, gaze_info, eye_gazes, result);
+    result
+}
+
+static CREATE_HAND_MESH_SPACE_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_hand_mesh_space_msft(
+    hand_tracker: HandTrackerEXT,
+    create_info: *const HandMeshSpaceCreateInfoMSFT,
+    space: *mut Space,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateHandMeshSpaceMSFT>(
+        CREATE_HAND_MESH_SPACE_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(hand_tracker, create_info, space);
+    tracing::trace!(target: "openxr::ffi", "xrCreateHandMeshSpaceMSFT(hand_tracker: {:?}, create_info: {:?}, space: {:?}) -> {:?}", hand_tracker, create_info, space, result);
+    result
+}
+
+static UPDATE_HAND_MESH_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn update_hand_mesh_msft(
+    hand_tracker: HandTrackerEXT,
+    update_info: *const HandMeshUpdateInfoMSFT,
+    hand_mesh: *mut HandMeshMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::UpdateHandMeshMSFT>(
+        UPDATE_HAND_MESH_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(hand_tracker, update_info, hand_mesh);
+    tracing::trace!(target: "openxr::ffi", "xrUpdateHandMeshMSFT(hand_tracker: {:?}, update_info: {:?}, hand_mesh: {:?}) -> {:?}", hand_tracker, update_info, hand_mesh, result);
+    result
+}
+
+static GET_CONTROLLER_MODEL_KEY_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_controller_model_key_msft(
+    session: Session,
+    top_level_user_path: Path,
+    controller_model_key_state: *mut ControllerModelKeyStateMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetControllerModelKeyMSFT>(
+        GET_CONTROLLER_MODEL_KEY_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(session, top_level_user_path, controller_model_key_state);
+    tracing::trace!(target: "openxr::ffi", "xrGetControllerModelKeyMSFT(session: {:?}, top_level_user_path: {:?}, controller_model_key_state: {:?}) -> {:?}", session, top_level_user_path, controller_model_key_state, result);
+    result
+}
+
+static LOAD_CONTROLLER_MODEL_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn load_controller_model_msft(
+    session: Session,
+    model_key: ControllerModelKeyMSFT,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut u8,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::LoadControllerModelMSFT>(
+        LOAD_CONTROLLER_MODEL_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(
+        session,
+        model_key,
+        buffer_capacity_input,
+        buffer_count_output,
+        buffer,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrLoadControllerModelMSFT(session: {:?}, model_key: {:?}, buffer_capacity_input: {:?}, buffer_count_output: {:?}, buffer: {:?}) -> {:?}", session, model_key, buffer_capacity_input, buffer_count_output, buffer, result);
+    result
+}
+
+static GET_CONTROLLER_MODEL_PROPERTIES_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_controller_model_properties_msft(
+    session: Session,
+    model_key: ControllerModelKeyMSFT,
+    properties: *mut ControllerModelPropertiesMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetControllerModelPropertiesMSFT>(
+        GET_CONTROLLER_MODEL_PROPERTIES_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(session, model_key, properties);
+    tracing::trace!(target: "openxr::ffi", "xrGetControllerModelPropertiesMSFT(session: {:?}, model_key: {:?}, properties: {:?}) -> {:?}", session, model_key, properties, result);
+    result
+}
+
+static GET_CONTROLLER_MODEL_STATE_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_controller_model_state_msft(
+    session: Session,
+    model_key: ControllerModelKeyMSFT,
+    state: *mut ControllerModelStateMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetControllerModelStateMSFT>(
+        GET_CONTROLLER_MODEL_STATE_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(session, model_key, state);
+    tracing::trace!(target: "openxr::ffi", "xrGetControllerModelStateMSFT(session: {:?}, model_key: {:?}, state: {:?}) -> {:?}", session, model_key, state, result);
+    result
+}
+
+static ENUMERATE_DISPLAY_REFRESH_RATES_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_display_refresh_rates_fb(
+    session: Session,
+    display_refresh_rate_capacity_input: u32,
+    display_refresh_rate_count_output: *mut u32,
+    display_refresh_rates: *mut f32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateDisplayRefreshRatesFB>(
+        ENUMERATE_DISPLAY_REFRESH_RATES_FB.load(Ordering::Relaxed),
+    );
+    let result = real(
+        session,
+        display_refresh_rate_capacity_input,
+        display_refresh_rate_count_output,
+        display_refresh_rates,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateDisplayRefreshRatesFB(session: {:?}, display_refresh_rate_capacity_input: {:?}, display_refresh_rate_count_output: {:?}, display_refresh_rates: {:?}) -> {:?}", session, display_refresh_rate_capacity_input, display_refresh_rate_count_output, display_refresh_rates, result);
+    result
+}
+
+static GET_DISPLAY_REFRESH_RATE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_display_refresh_rate_fb(
+    session: Session,
+    display_refresh_rate: *mut f32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetDisplayRefreshRateFB>(
+        GET_DISPLAY_REFRESH_RATE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, display_refresh_rate);
+    tracing::trace!(target: "openxr::ffi", "xrGetDisplayRefreshRateFB(session: {:?}, display_refresh_rate: {:?}) -> {:?}", session, display_refresh_rate, result);
+    result
+}
+
+static REQUEST_DISPLAY_REFRESH_RATE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn request_display_refresh_rate_fb(
+    session: Session,
+    display_refresh_rate: f32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::RequestDisplayRefreshRateFB>(
+        REQUEST_DISPLAY_REFRESH_RATE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, display_refresh_rate);
+    tracing::trace!(target: "openxr::ffi", "xrRequestDisplayRefreshRateFB(session: {:?}, display_refresh_rate: {:?}) -> {:?}", session, display_refresh_rate, result);
+    result
+}
+
+#[cfg(windows)]
+static CREATE_SPATIAL_ANCHOR_FROM_PERCEPTION_ANCHOR_MSFT: AtomicUsize = AtomicUsize::new(0);
+#[cfg(windows)]
+unsafe extern "system" fn create_spatial_anchor_from_perception_anchor_msft(
+    session: Session,
+    perception_anchor: *mut IUnknown,
+    anchor: *mut SpatialAnchorMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateSpatialAnchorFromPerceptionAnchorMSFT>(
+        CREATE_SPATIAL_ANCHOR_FROM_PERCEPTION_ANCHOR_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(session, perception_anchor, anchor);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSpatialAnchorFromPerceptionAnchorMSFT(session: {:?}, perception_anchor: {:?}, anchor: {:?}) -> {:?}", session, perception_anchor, anchor, result);
+    result
+}
+
+#[cfg(windows)]
+static TRY_GET_PERCEPTION_ANCHOR_FROM_SPATIAL_ANCHOR_MSFT: AtomicUsize = AtomicUsize::new(0);
+#[cfg(windows)]
+unsafe extern "system" fn try_get_perception_anchor_from_spatial_anchor_msft(
+    session: Session,
+    anchor: SpatialAnchorMSFT,
+    perception_anchor: *mut *mut IUnknown,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::TryGetPerceptionAnchorFromSpatialAnchorMSFT>(
+        TRY_GET_PERCEPTION_ANCHOR_FROM_SPATIAL_ANCHOR_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(session, anchor, perception_anchor);
+    tracing::trace!(target: "openxr::ffi", "xrTryGetPerceptionAnchorFromSpatialAnchorMSFT(session: {:?}, anchor: {:?}, perception_anchor: {:?}) -> {:?}", session, anchor, perception_anchor, result);
+    result
+}
+
+static UPDATE_SWAPCHAIN_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn update_swapchain_fb(
+    swapchain: Swapchain,
+    state: *const SwapchainStateBaseHeaderFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::UpdateSwapchainFB>(
+        UPDATE_SWAPCHAIN_FB.load(Ordering::Relaxed),
+    );
+    let result = real(swapchain, state);
+    tracing::trace!(target: "openxr::ffi", "xrUpdateSwapchainFB(swapchain: {:?}, state: {:?}) -> {:?}", swapchain, state, result);
+    result
+}
+
+static GET_SWAPCHAIN_STATE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_swapchain_state_fb(
+    swapchain: Swapchain,
+    state: *mut SwapchainStateBaseHeaderFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSwapchainStateFB>(
+        GET_SWAPCHAIN_STATE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(swapchain, state);
+    tracing::trace!(target: "openxr::ffi", "xrGetSwapchainStateFB(swapchain: {:?}, state: {:?}) -> {:?}", swapchain, state, result);
+    result
+}
+
+static ENUMERATE_COLOR_SPACES_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_color_spaces_fb(
+    session: Session,
+    color_space_capacity_input: u32,
+    color_space_count_output: *mut u32,
+    color_spaces: *mut ColorSpaceFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateColorSpacesFB>(
+        ENUMERATE_COLOR_SPACES_FB.load(Ordering::Relaxed),
+    );
+    let result = real(
+        session,
+        color_space_capacity_input,
+        color_space_count_output,
+        color_spaces,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateColorSpacesFB(session: {:?}, color_space_capacity_input: {:?}, color_space_count_output: {:?}, color_spaces: {:?}) -> {:?}", session, color_space_capacity_input, color_space_count_output, color_spaces, result);
+    result
+}
+
+static SET_COLOR_SPACE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_color_space_fb(
+    session: Session,
+    color_space: ColorSpaceFB,
+) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::SetColorSpaceFB>(SET_COLOR_SPACE_FB.load(Ordering::Relaxed));
+    let result = real(session, color_space);
+    tracing::trace!(target: "openxr::ffi", "xrSetColorSpaceFB(session: {:?}, color_space: {:?}) -> {:?}", session, color_space, result);
+    result
+}
+
+static CREATE_FOVEATION_PROFILE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_foveation_profile_fb(
+    session: Session,
+    create_info: *const FoveationProfileCreateInfoFB,
+    profile: *mut FoveationProfileFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateFoveationProfileFB>(
+        CREATE_FOVEATION_PROFILE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, profile);
+    tracing::trace!(target: "openxr::ffi", "xrCreateFoveationProfileFB(session: {:?}, create_info: {:?}, profile: {:?}) -> {:?}", session, create_info, profile, result);
+    result
+}
+
+static DESTROY_FOVEATION_PROFILE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_foveation_profile_fb(profile: FoveationProfileFB) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyFoveationProfileFB>(
+        DESTROY_FOVEATION_PROFILE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(profile);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyFoveationProfileFB(profile: {:?}) -> {:?}", profile, result);
+    result
+}
+
+static GET_FOVEATION_EYE_TRACKED_STATE_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_foveation_eye_tracked_state_meta(
+    session: Session,
+    foveation_state: *mut FoveationEyeTrackedStateMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetFoveationEyeTrackedStateMETA>(
+        GET_FOVEATION_EYE_TRACKED_STATE_META.load(Ordering::Relaxed),
+    );
+    let result = real(session, foveation_state);
+    tracing::trace!(target: "openxr::ffi", "xrGetFoveationEyeTrackedStateMETA(session: {:?}, foveation_state: {:?}) -> {:?}", session, foveation_state, result);
+    result
+}
+
+static GET_HAND_MESH_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_hand_mesh_fb(
+    hand_tracker: HandTrackerEXT,
+    mesh: *mut HandTrackingMeshFB,
+) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::GetHandMeshFB>(GET_HAND_MESH_FB.load(Ordering::Relaxed));
+    let result = real(hand_tracker, mesh);
+    tracing::trace!(target: "openxr::ffi", "xrGetHandMeshFB(hand_tracker: {:?}, mesh: {:?}) -> {:?}", hand_tracker, mesh, result);
+    result
+}
+
+static ENUMERATE_RENDER_MODEL_PATHS_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_render_model_paths_fb(
+    session: Session,
+    path_capacity_input: u32,
+    path_count_output: *mut u32,
+    paths: *mut RenderModelPathInfoFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateRenderModelPathsFB>(
+        ENUMERATE_RENDER_MODEL_PATHS_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, path_capacity_input, path_count_output, paths);
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateRenderModelPathsFB(session: {:?}, path_capacity_input: {:?}, path_count_output: {:?}, paths: {:?}) -> {:?}", session, path_capacity_input, path_count_output, paths, result);
+    result
+}
+
+static GET_RENDER_MODEL_PROPERTIES_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_render_model_properties_fb(
+    session: Session,
+    path: Path,
+    properties: *mut RenderModelPropertiesFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetRenderModelPropertiesFB>(
+        GET_RENDER_MODEL_PROPERTIES_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, path, properties);
+    tracing::trace!(target: "openxr::ffi", "xrGetRenderModelPropertiesFB(session: {:?}, path: {:?}, properties: {:?}) -> {:?}", session, path, properties, result);
+    result
+}
+
+static LOAD_RENDER_MODEL_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn load_render_model_fb(
+    session: Session,
+    info: *const RenderModelLoadInfoFB,
+    buffer: *mut RenderModelBufferFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::LoadRenderModelFB>(
+        LOAD_RENDER_MODEL_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, info, buffer);
+    tracing::trace!(target: "openxr::ffi", "xrLoadRenderModelFB(session: {:?}, info: {:?}, buffer: {:?}) -> {:?}", session, info, buffer, result);
+    result
+}
+
+static QUERY_SYSTEM_TRACKED_KEYBOARD_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn query_system_tracked_keyboard_fb(
+    session: Session,
+    query_info: *const KeyboardTrackingQueryFB,
+    keyboard: *mut KeyboardTrackingDescriptionFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::QuerySystemTrackedKeyboardFB>(
+        QUERY_SYSTEM_TRACKED_KEYBOARD_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, query_info, keyboard);
+    tracing::trace!(target: "openxr::ffi", "xrQuerySystemTrackedKeyboardFB(session: {:?}, query_info: {:?}, keyboard: {:?}) -> {:?}", session, query_info, keyboard, result);
+    result
+}
+
+static CREATE_KEYBOARD_SPACE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_keyboard_space_fb(
+    session: Session,
+    create_info: *const KeyboardSpaceCreateInfoFB,
+    keyboard_space: *mut Space,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateKeyboardSpaceFB>(
+        CREATE_KEYBOARD_SPACE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, keyboard_space);
+    tracing::trace!(target: "openxr::ffi", "xrCreateKeyboardSpaceFB(session: {:?}, create_info: {:?}, keyboard_space: {:?}) -> {:?}", session, create_info, keyboard_space, result);
+    result
+}
+
+static SET_ENVIRONMENT_DEPTH_ESTIMATION_VARJO: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_environment_depth_estimation_varjo(
+    session: Session,
+    enabled: Bool32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetEnvironmentDepthEstimationVARJO>(
+        SET_ENVIRONMENT_DEPTH_ESTIMATION_VARJO.load(Ordering::Relaxed),
+    );
+    let result = real(session, enabled);
+    tracing::trace!(target: "openxr::ffi", "xrSetEnvironmentDepthEstimationVARJO(session: {:?}, enabled: {:?}) -> {:?}", session, enabled, result);
+    result
+}
+
+static ENUMERATE_REPROJECTION_MODES_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_reprojection_modes_msft(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    mode_capacity_input: u32,
+    mode_count_output: *mut u32,
+    modes: *mut ReprojectionModeMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateReprojectionModesMSFT>(
+        ENUMERATE_REPROJECTION_MODES_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(
+        instance,
+        system_id,
+        view_configuration_type,
+        mode_capacity_input,
+        mode_count_output,
+        modes,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateReprojectionModesMSFT(instance: {:?}, system_id: {:?}, view_configuration_type: {:?}, mode_capacity_input: {:?}, mode_count_output: {:?}, modes: {:?}) -> {:?}", instance, system_id, view_configuration_type, mode_capacity_input, mode_count_output, modes, result);
+    result
+}
+
+static GET_AUDIO_OUTPUT_DEVICE_GUID_OCULUS: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_audio_output_device_guid_oculus(
+    instance: Instance,
+    buffer: *mut wchar_t,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetAudioOutputDeviceGuidOculus>(
+        GET_AUDIO_OUTPUT_DEVICE_GUID_OCULUS.load(Ordering::Relaxed),
+    );
+    let result = real(instance, buffer);
+    tracing::trace!(target: "openxr::ffi", "xrGetAudioOutputDeviceGuidOculus(instance: {:?}, buffer: {:?}) -> {:?}", instance, buffer, result);
+    result
+}
+
+static GET_AUDIO_INPUT_DEVICE_GUID_OCULUS: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_audio_input_device_guid_oculus(
+    instance: Instance,
+    buffer: *mut wchar_t,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetAudioInputDeviceGuidOculus>(
+        GET_AUDIO_INPUT_DEVICE_GUID_OCULUS.load(Ordering::Relaxed),
+    );
+    let result = real(instance, buffer);
+    tracing::trace!(target: "openxr::ffi", "xrGetAudioInputDeviceGuidOculus(instance: {:?}, buffer: {:?}) -> {:?}", instance, buffer, result);
+    result
+}
+
+static CREATE_SPATIAL_ANCHOR_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_spatial_anchor_fb(
+    session: Session,
+    info: *const SpatialAnchorCreateInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateSpatialAnchorFB>(
+        CREATE_SPATIAL_ANCHOR_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, info, request_id);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSpatialAnchorFB(session: {:?}, info: {:?}, request_id: {:?}) -> {:?}", session, info, request_id, result);
+    result
+}
+
+static GET_SPACE_UUID_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_space_uuid_fb(space: Space, uuid: *mut UuidEXT) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::GetSpaceUuidFB>(GET_SPACE_UUID_FB.load(Ordering::Relaxed));
+    let result = real(space, uuid);
+    tracing::trace!(target: "openxr::ffi", "xrGetSpaceUuidFB(space: {:?}, uuid: {:?}) -> {:?}", space, uuid, result);
+    result
+}
+
+static ENUMERATE_SPACE_SUPPORTED_COMPONENTS_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_space_supported_components_fb(
+    space: Space,
+    component_type_capacity_input: u32,
+    component_type_count_output: *mut u32,
+    component_types: *mut SpaceComponentTypeFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateSpaceSupportedComponentsFB>(
+        ENUMERATE_SPACE_SUPPORTED_COMPONENTS_FB.load(Ordering::Relaxed),
+    );
+    let result = real(
+        space,
+        component_type_capacity_input,
+        component_type_count_output,
+        component_types,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateSpaceSupportedComponentsFB(space: {:?}, component_type_capacity_input: {:?}, component_type_count_output: {:?}, component_types: {:?}) -> {:?}", space, component_type_capacity_input, component_type_count_output, component_types, result);
+    result
+}
+
+static SET_SPACE_COMPONENT_STATUS_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_space_component_status_fb(
+    space: Space,
+    info: *const SpaceComponentStatusSetInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetSpaceComponentStatusFB>(
+        SET_SPACE_COMPONENT_STATUS_FB.load(Ordering::Relaxed),
+    );
+    let result = real(space, info, request_id);
+    tracing::trace!(target: "openxr::ffi", "xrSetSpaceComponentStatusFB(space: {:?}, info: {:?}, request_id: {:?}) -> {:?}", space, info, request_id, result);
+    result
+}
+
+static GET_SPACE_COMPONENT_STATUS_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_space_component_status_fb(
+    space: Space,
+    component_type: SpaceComponentTypeFB,
+    status: *mut SpaceComponentStatusFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSpaceComponentStatusFB>(
+        GET_SPACE_COMPONENT_STATUS_FB.load(Ordering::Relaxed),
+    );
+    let result = real(space, component_type, status);
+    tracing::trace!(target: "openxr::ffi", "xrGetSpaceComponentStatusFB(space: {:?}, component_type: {:?}, status: {:?}) -> {:?}", space, component_type, status, result);
+    result
+}
+
+static CREATE_TRIANGLE_MESH_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_triangle_mesh_fb(
+    session: Session,
+    create_info: *const TriangleMeshCreateInfoFB,
+    out_triangle_mesh: *mut TriangleMeshFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateTriangleMeshFB>(
+        CREATE_TRIANGLE_MESH_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, out_triangle_mesh);
+    tracing::trace!(target: "openxr::ffi", "xrCreateTriangleMeshFB(session: {:?}, create_info: {:?}, out_triangle_mesh: {:?}) -> {:?}", session, create_info, out_triangle_mesh, result);
+    result
+}
+
+static DESTROY_TRIANGLE_MESH_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_triangle_mesh_fb(mesh: TriangleMeshFB) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyTriangleMeshFB>(
+        DESTROY_TRIANGLE_MESH_FB.load(Ordering::Relaxed),
+    );
+    let result = real(mesh);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyTriangleMeshFB(mesh: {:?}) -> {:?}", mesh, result);
+    result
+}
+
+static TRIANGLE_MESH_GET_VERTEX_BUFFER_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn triangle_mesh_get_vertex_buffer_fb(
+    mesh: TriangleMeshFB,
+    out_vertex_buffer: *mut *mut Vector3f,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::TriangleMeshGetVertexBufferFB>(
+        TRIANGLE_MESH_GET_VERTEX_BUFFER_FB.load(Ordering::Relaxed),
+    );
+    let result = real(mesh, out_vertex_buffer);
+    tracing::trace!(target: "openxr::ffi", "xrTriangleMeshGetVertexBufferFB(mesh: {:?}, out_vertex_buffer: {:?}) -> {:?}", mesh, out_vertex_buffer, result);
+    result
+}
+
+static TRIANGLE_MESH_GET_INDEX_BUFFER_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn triangle_mesh_get_index_buffer_fb(
+    mesh: TriangleMeshFB,
+    out_index_buffer: *mut *mut u32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::TriangleMeshGetIndexBufferFB>(
+        TRIANGLE_MESH_GET_INDEX_BUFFER_FB.load(Ordering::Relaxed),
+    );
+    let result = real(mesh, out_index_buffer);
+    tracing::trace!(target: "openxr::ffi", "xrTriangleMeshGetIndexBufferFB(mesh: {:?}, out_index_buffer: {:?}) -> {:?}", mesh, out_index_buffer, result);
+    result
+}
+
+static TRIANGLE_MESH_BEGIN_UPDATE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn triangle_mesh_begin_update_fb(mesh: TriangleMeshFB) -> Result {
+    let real = mem::transmute::<usize, pfn::TriangleMeshBeginUpdateFB>(
+        TRIANGLE_MESH_BEGIN_UPDATE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(mesh);
+    tracing::trace!(target: "openxr::ffi", "xrTriangleMeshBeginUpdateFB(mesh: {:?}) -> {:?}", mesh, result);
+    result
+}
+
+static TRIANGLE_MESH_END_UPDATE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn triangle_mesh_end_update_fb(
+    mesh: TriangleMeshFB,
+    vertex_count: u32,
+    triangle_count: u32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::TriangleMeshEndUpdateFB>(
+        TRIANGLE_MESH_END_UPDATE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(mesh, vertex_count, triangle_count);
+    tracing::trace!(target: "openxr::ffi", "xrTriangleMeshEndUpdateFB(mesh: {:?}, vertex_count: {:?}, triangle_count: {:?}) -> {:?}", mesh, vertex_count, triangle_count, result);
+    result
+}
+
+static TRIANGLE_MESH_BEGIN_VERTEX_BUFFER_UPDATE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn triangle_mesh_begin_vertex_buffer_update_fb(
+    mesh: TriangleMeshFB,
+    out_vertex_count: *mut u32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::TriangleMeshBeginVertexBufferUpdateFB>(
+        TRIANGLE_MESH_BEGIN_VERTEX_BUFFER_UPDATE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(mesh, out_vertex_count);
+    tracing::trace!(target: "openxr::ffi", "xrTriangleMeshBeginVertexBufferUpdateFB(mesh: {:?}, out_vertex_count: {:?}) -> {:?}", mesh, out_vertex_count, result);
+    result
+}
+
+static TRIANGLE_MESH_END_VERTEX_BUFFER_UPDATE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn triangle_mesh_end_vertex_buffer_update_fb(
+    mesh: TriangleMeshFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::TriangleMeshEndVertexBufferUpdateFB>(
+        TRIANGLE_MESH_END_VERTEX_BUFFER_UPDATE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(mesh);
+    tracing::trace!(target: "openxr::ffi", "xrTriangleMeshEndVertexBufferUpdateFB(mesh: {:?}) -> {:?}", mesh, result);
+    result
+}
+
+static CREATE_PASSTHROUGH_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_passthrough_fb(
+    session: Session,
+    create_info: *const PassthroughCreateInfoFB,
+    out_passthrough: *mut PassthroughFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreatePassthroughFB>(
+        CREATE_PASSTHROUGH_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, out_passthrough);
+    tracing::trace!(target: "openxr::ffi", "xrCreatePassthroughFB(session: {:?}, create_info: {:?}, out_passthrough: {:?}) -> {:?}", session, create_info, out_passthrough, result);
+    result
+}
+
+static DESTROY_PASSTHROUGH_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_passthrough_fb(passthrough: PassthroughFB) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyPassthroughFB>(
+        DESTROY_PASSTHROUGH_FB.load(Ordering::Relaxed),
+    );
+    let result = real(passthrough);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyPassthroughFB(passthrough: {:?}) -> {:?}", passthrough, result);
+    result
+}
+
+static PASSTHROUGH_START_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn passthrough_start_fb(passthrough: PassthroughFB) -> Result {
+    let real = mem::transmute::<usize, pfn::PassthroughStartFB>(
+        PASSTHROUGH_START_FB.load(Ordering::Relaxed),
+    );
+    let result = real(passthrough);
+    tracing::trace!(target: "openxr::ffi", "xrPassthroughStartFB(passthrough: {:?}) -> {:?}", passthrough, result);
+    result
+}
+
+static PASSTHROUGH_PAUSE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn passthrough_pause_fb(passthrough: PassthroughFB) -> Result {
+    let real = mem::transmute::<usize, pfn::PassthroughPauseFB>(
+        PASSTHROUGH_PAUSE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(passthrough);
+    tracing::trace!(target: "openxr::ffi", "xrPassthroughPauseFB(passthrough: {:?}) -> {:?}", passthrough, result);
+    result
+}
+
+static CREATE_PASSTHROUGH_LAYER_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_passthrough_layer_fb(
+    session: Session,
+    create_info: *const PassthroughLayerCreateInfoFB,
+    out_layer: *mut PassthroughLayerFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreatePassthroughLayerFB>(
+        CREATE_PASSTHROUGH_LAYER_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, out_layer);
+    tracing::trace!(target: "openxr::ffi", "xrCreatePassthroughLayerFB(session: {:?}, create_info: {:?}, out_layer: {:?}) -> {:?}", session, create_info, out_layer, result);
+    result
+}
+
+static DESTROY_PASSTHROUGH_LAYER_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_passthrough_layer_fb(layer: PassthroughLayerFB) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyPassthroughLayerFB>(
+        DESTROY_PASSTHROUGH_LAYER_FB.load(Ordering::Relaxed),
+    );
+    let result = real(layer);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyPassthroughLayerFB(layer: {:?}) -> {:?}", layer, result);
+    result
+}
+
+static PASSTHROUGH_LAYER_PAUSE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn passthrough_layer_pause_fb(layer: PassthroughLayerFB) -> Result {
+    let real = mem::transmute::<usize, pfn::PassthroughLayerPauseFB>(
+        PASSTHROUGH_LAYER_PAUSE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(layer);
+    tracing::trace!(target: "openxr::ffi", "xrPassthroughLayerPauseFB(layer: {:?}) -> {:?}", layer, result);
+    result
+}
+
+static PASSTHROUGH_LAYER_RESUME_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn passthrough_layer_resume_fb(layer: PassthroughLayerFB) -> Result {
+    let real = mem::transmute::<usize, pfn::PassthroughLayerResumeFB>(
+        PASSTHROUGH_LAYER_RESUME_FB.load(Ordering::Relaxed),
+    );
+    let result = real(layer);
+    tracing::trace!(target: "openxr::ffi", "xrPassthroughLayerResumeFB(layer: {:?}) -> {:?}", layer, result);
+    result
+}
+
+static PASSTHROUGH_LAYER_SET_STYLE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn passthrough_layer_set_style_fb(
+    layer: PassthroughLayerFB,
+    style: *const PassthroughStyleFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::PassthroughLayerSetStyleFB>(
+        PASSTHROUGH_LAYER_SET_STYLE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(layer, style);
+    tracing::trace!(target: "openxr::ffi", "xrPassthroughLayerSetStyleFB(layer: {:?}, style: {:?}) -> {:?}", layer, style, result);
+    result
+}
+
+static CREATE_GEOMETRY_INSTANCE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_geometry_instance_fb(
+    session: Session,
+    create_info: *const GeometryInstanceCreateInfoFB,
+    out_geometry_instance: *mut GeometryInstanceFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateGeometryInstanceFB>(
+        CREATE_GEOMETRY_INSTANCE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, out_geometry_instance);
+    tracing::trace!(target: "openxr::ffi", "xrCreateGeometryInstanceFB(session: {:?}, create_info: {:?}, out_geometry_instance: {:?}) -> {:?}", session, create_info, out_geometry_instance, result);
+    result
+}
+
+static DESTROY_GEOMETRY_INSTANCE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_geometry_instance_fb(instance: GeometryInstanceFB) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyGeometryInstanceFB>(
+        DESTROY_GEOMETRY_INSTANCE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(instance);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyGeometryInstanceFB(instance: {:?}) -> {:?}", instance, result);
+    result
+}
+
+static GEOMETRY_INSTANCE_SET_TRANSFORM_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn geometry_instance_set_transform_fb(
+    instance: GeometryInstanceFB,
+    transformation: *const GeometryInstanceTransformFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GeometryInstanceSetTransformFB>(
+        GEOMETRY_INSTANCE_SET_TRANSFORM_FB.load(Ordering::Relaxed),
+    );
+    let result = real(instance, transformation);
+    tracing::trace!(target: "openxr::ffi", "xrGeometryInstanceSetTransformFB(instance: {:?}, transformation: {:?}) -> {:?}", instance, transformation, result);
+    result
+}
+
+static QUERY_SPACES_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn query_spaces_fb(
+    session: Session,
+    info: *const SpaceQueryInfoBaseHeaderFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::QuerySpacesFB>(QUERY_SPACES_FB.load(Ordering::Relaxed));
+    let result = real(session, info, request_id);
+    tracing::trace!(target: "openxr::ffi", "xrQuerySpacesFB(session: {:?}, info: {:?}, request_id: {:?}) -> {:?}", session, info, request_id, result);
+    result
+}
+
+static RETRIEVE_SPACE_QUERY_RESULTS_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn retrieve_space_query_results_fb(
+    session: Session,
+    request_id: AsyncRequestIdFB,
+    results: *mut SpaceQueryResultsFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::RetrieveSpaceQueryResultsFB>(
+        RETRIEVE_SPACE_QUERY_RESULTS_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, request_id, results);
+    tracing::trace!(target: "openxr::ffi", "xrRetrieveSpaceQueryResultsFB(session: {:?}, request_id: {:?}, results: {:?}) -> {:?}", session, request_id, results, result);
+    result
+}
+
+static SAVE_SPACE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn save_space_fb(
+    session: Session,
+    info: *const SpaceSaveInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SaveSpaceFB>(SAVE_SPACE_FB.load(Ordering::Relaxed));
+    let result = real(session, info, request_id);
+    tracing::trace!(target: "openxr::ffi", "xrSaveSpaceFB(session: {:?}, info: {:?}, request_id: {:?}) -> {:?}", session, info, request_id, result);
+    result
+}
+
+static ERASE_SPACE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn erase_space_fb(
+    session: Session,
+    info: *const SpaceEraseInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EraseSpaceFB>(ERASE_SPACE_FB.load(Ordering::Relaxed));
+    let result = real(session, info, request_id);
+    tracing::trace!(target: "openxr::ffi", "xrEraseSpaceFB(session: {:?}, info: {:?}, request_id: {:?}) -> {:?}", session, info, request_id, result);
+    result
+}
+
+static SAVE_SPACE_LIST_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn save_space_list_fb(
+    session: Session,
+    info: *const SpaceListSaveInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    let real =
+        mem::transmute::<usize, pfn::SaveSpaceListFB>(SAVE_SPACE_LIST_FB.load(Ordering::Relaxed));
+    let result = real(session, info, request_id);
+    tracing::trace!(target: "openxr::ffi", "xrSaveSpaceListFB(session: {:?}, info: {:?}, request_id: {:?}) -> {:?}", session, info, request_id, result);
+    result
+}
+
+static SHARE_SPACES_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn share_spaces_fb(
+    session: Session,
+    info: *const SpaceShareInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ShareSpacesFB>(SHARE_SPACES_FB.load(Ordering::Relaxed));
+    let result = real(session, info, request_id);
+    tracing::trace!(target: "openxr::ffi", "xrShareSpacesFB(session: {:?}, info: {:?}, request_id: {:?}) -> {:?}", session, info, request_id, result);
+    result
+}
+
+static GET_SPACE_CONTAINER_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_space_container_fb(
+    session: Session,
+    space: Space,
+    space_container_output: *mut SpaceContainerFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSpaceContainerFB>(
+        GET_SPACE_CONTAINER_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, space, space_container_output);
+    tracing::trace!(target: "openxr::ffi", "xrGetSpaceContainerFB(session: {:?}, space: {:?}, space_container_output: {:?}) -> {:?}", session, space, space_container_output, result);
+    result
+}
+
+static GET_SPACE_BOUNDING_BOX2_DFB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_space_bounding_box2_dfb(
+    session: Session,
+    space: Space,
+    bounding_box2_d_output: *mut Rect2Df,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSpaceBoundingBox2DFB>(
+        GET_SPACE_BOUNDING_BOX2_DFB.load(Ordering::Relaxed),
+    );
+    let result = real(session, space, bounding_box2_d_output);
+    tracing::trace!(target: "openxr::ffi", "xrGetSpaceBoundingBox2DFB(session: {:?}, space: {:?}, bounding_box2_d_output: {:?}) -> {:?}", session, space, bounding_box2_d_output, result);
+    result
+}
+
+static GET_SPACE_BOUNDING_BOX3_DFB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_space_bounding_box3_dfb(
+    session: Session,
+    space: Space,
+    bounding_box3_d_output: *mut Rect3DfFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSpaceBoundingBox3DFB>(
+        GET_SPACE_BOUNDING_BOX3_DFB.load(Ordering::Relaxed),
+    );
+    let result = real(session, space, bounding_box3_d_output);
+    tracing::trace!(target: "openxr::ffi", "xrGetSpaceBoundingBox3DFB(session: {:?}, space: {:?}, bounding_box3_d_output: {:?}) -> {:?}", session, space, bounding_box3_d_output, result);
+    result
+}
+
+static GET_SPACE_SEMANTIC_LABELS_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_space_semantic_labels_fb(
+    session: Session,
+    space: Space,
+    semantic_labels_output: *mut SemanticLabelsFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSpaceSemanticLabelsFB>(
+        GET_SPACE_SEMANTIC_LABELS_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, space, semantic_labels_output);
+    tracing::trace!(target: "openxr::ffi", "xrGetSpaceSemanticLabelsFB(session: {:?}, space: {:?}, semantic_labels_output: {:?}) -> {:?}", session, space, semantic_labels_output, result);
+    result
+}
+
+static GET_SPACE_BOUNDARY2_DFB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_space_boundary2_dfb(
+    session: Session,
+    space: Space,
+    boundary2_d_output: *mut Boundary2DFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSpaceBoundary2DFB>(
+        GET_SPACE_BOUNDARY2_DFB.load(Ordering::Relaxed),
+    );
+    let result = real(session, space, boundary2_d_output);
+    tracing::trace!(target: "openxr::ffi", "xrGetSpaceBoundary2DFB(session: {:?}, space: {:?}, boundary2_d_output: {:?}) -> {:?}", session, space, boundary2_d_output, result);
+    result
+}
+
+static GET_SPACE_ROOM_LAYOUT_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_space_room_layout_fb(
+    session: Session,
+    space: Space,
+    room_layout_output: *mut RoomLayoutFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSpaceRoomLayoutFB>(
+        GET_SPACE_ROOM_LAYOUT_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, space, room_layout_output);
+    tracing::trace!(target: "openxr::ffi", "xrGetSpaceRoomLayoutFB(session: {:?}, space: {:?}, room_layout_output: {:?}) -> {:?}", session, space, room_layout_output, result);
+    result
+}
+
+static REQUEST_SCENE_CAPTURE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn request_scene_capture_fb(
+    session: Session,
+    info: *const SceneCaptureRequestInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::RequestSceneCaptureFB>(
+        REQUEST_SCENE_CAPTURE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, info, request_id);
+    tracing::trace!(target: "openxr::ffi", "xrRequestSceneCaptureFB(session: {:?}, info: {:?}, request_id: {:?}) -> {:?}", session, info, request_id, result);
+    result
+}
+
+static PASSTHROUGH_LAYER_SET_KEYBOARD_HANDS_INTENSITY_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn passthrough_layer_set_keyboard_hands_intensity_fb(
+    layer: PassthroughLayerFB,
+    intensity: *const PassthroughKeyboardHandsIntensityFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::PassthroughLayerSetKeyboardHandsIntensityFB>(
+        PASSTHROUGH_LAYER_SET_KEYBOARD_HANDS_INTENSITY_FB.load(Ordering::Relaxed),
+    );
+    let result = real(layer, intensity);
+    tracing::trace!(target: "openxr::ffi", "xrPassthroughLayerSetKeyboardHandsIntensityFB(layer: {:?}, intensity: {:?}) -> {:?}", layer, intensity, result);
+    result
+}
+
+static CREATE_SPATIAL_ANCHOR_STORE_CONNECTION_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_spatial_anchor_store_connection_msft(
+    session: Session,
+    spatial_anchor_store: *mut SpatialAnchorStoreConnectionMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateSpatialAnchorStoreConnectionMSFT>(
+        CREATE_SPATIAL_ANCHOR_STORE_CONNECTION_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(session, spatial_anchor_store);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSpatialAnchorStoreConnectionMSFT(session: {:?}, spatial_anchor_store: {:?}) -> {:?}", session, spatial_anchor_store, result);
+    result
+}
+
+static DESTROY_SPATIAL_ANCHOR_STORE_CONNECTION_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_spatial_anchor_store_connection_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroySpatialAnchorStoreConnectionMSFT>(
+        DESTROY_SPATIAL_ANCHOR_STORE_CONNECTION_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(spatial_anchor_store);
+    tracing::trace!(target: "openxr::ffi", "xrDestroySpatialAnchorStoreConnectionMSFT(spatial_anchor_store: {:?}) -> {:?}", spatial_anchor_store, result);
+    result
+}
+
+static PERSIST_SPATIAL_ANCHOR_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn persist_spatial_anchor_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+    spatial_anchor_persistence_info: *const SpatialAnchorPersistenceInfoMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::PersistSpatialAnchorMSFT>(
+        PERSIST_SPATIAL_ANCHOR_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(spatial_anchor_store, spatial_anchor_persistence_info);
+    tracing::trace!(target: "openxr::ffi", "xrPersistSpatialAnchorMSFT(spatial_anchor_store: {:?}, spatial_anchor_persistence_info: {:?}) -> {:?}", spatial_anchor_store, spatial_anchor_persistence_info, result);
+    result
+}
+
+static ENUMERATE_PERSISTED_SPATIAL_ANCHOR_NAMES_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_persisted_spatial_anchor_names_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+    spatial_anchor_name_capacity_input: u32,
+    spatial_anchor_name_count_output: *mut u32,
+    spatial_anchor_names: *mut SpatialAnchorPersistenceNameMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumeratePersistedSpatialAnchorNamesMSFT>(
+        ENUMERATE_PERSISTED_SPATIAL_ANCHOR_NAMES_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(
+        spatial_anchor_store,
+        spatial_anchor_name_capacity_input,
+        spatial_anchor_name_count_output,
+        spatial_anchor_names,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrEnumeratePersistedSpatialAnchorNamesMSFT(spatial_anchor_store: {:?}, spatial_anchor_name_capacity_input: {:?}, spatial_anchor_name_count_output: {:?}, spatial_anchor_names: {:?}) -> {:?}", spatial_anchor_store, spatial_anchor_name_capacity_input, spatial_anchor_name_count_output, spatial_anchor_names, result);
+    result
+}
+
+static CREATE_SPATIAL_ANCHOR_FROM_PERSISTED_NAME_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_spatial_anchor_from_persisted_name_msft(
+    session: Session,
+    spatial_anchor_create_info: *const SpatialAnchorFromPersistedAnchorCreateInfoMSFT,
+    spatial_anchor: *mut SpatialAnchorMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateSpatialAnchorFromPersistedNameMSFT>(
+        CREATE_SPATIAL_ANCHOR_FROM_PERSISTED_NAME_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(session, spatial_anchor_create_info, spatial_anchor);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSpatialAnchorFromPersistedNameMSFT(session: {:?}, spatial_anchor_create_info: {:?}, spatial_anchor: {:?}) -> {:?}", session, spatial_anchor_create_info, spatial_anchor, result);
+    result
+}
+
+static UNPERSIST_SPATIAL_ANCHOR_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn unpersist_spatial_anchor_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+    spatial_anchor_persistence_name: *const SpatialAnchorPersistenceNameMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::UnpersistSpatialAnchorMSFT>(
+        UNPERSIST_SPATIAL_ANCHOR_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(spatial_anchor_store, spatial_anchor_persistence_name);
+    tracing::trace!(target: "openxr::ffi", "xrUnpersistSpatialAnchorMSFT(spatial_anchor_store: {:?}, spatial_anchor_persistence_name: {:?}) -> {:?}", spatial_anchor_store, spatial_anchor_persistence_name, result);
+    result
+}
+
+static CLEAR_SPATIAL_ANCHOR_STORE_MSFT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn clear_spatial_anchor_store_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ClearSpatialAnchorStoreMSFT>(
+        CLEAR_SPATIAL_ANCHOR_STORE_MSFT.load(Ordering::Relaxed),
+    );
+    let result = real(spatial_anchor_store);
+    tracing::trace!(target: "openxr::ffi", "xrClearSpatialAnchorStoreMSFT(spatial_anchor_store: {:?}) -> {:?}", spatial_anchor_store, result);
+    result
+}
+
+static CREATE_FACIAL_TRACKER_HTC: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_facial_tracker_htc(
+    session: Session,
+    create_info: *const FacialTrackerCreateInfoHTC,
+    facial_tracker: *mut FacialTrackerHTC,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateFacialTrackerHTC>(
+        CREATE_FACIAL_TRACKER_HTC.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, facial_tracker);
+    tracing::trace!(target: "openxr::ffi", "xrCreateFacialTrackerHTC(session: {:?}, create_info: {:?}, facial_tracker: {:?}) -> {:?}", session, create_info, facial_tracker, result);
+    result
+}
+
+static DESTROY_FACIAL_TRACKER_HTC: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_facial_tracker_htc(facial_tracker: FacialTrackerHTC) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyFacialTrackerHTC>(
+        DESTROY_FACIAL_TRACKER_HTC.load(Ordering::Relaxed),
+    );
+    let result = real(facial_tracker);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyFacialTrackerHTC(facial_tracker: {:?}) -> {:?}", facial_tracker, result);
+    result
+}
+
+static GET_FACIAL_EXPRESSIONS_HTC: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_facial_expressions_htc(
+    facial_tracker: FacialTrackerHTC,
+    facial_expressions: *mut FacialExpressionsHTC,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetFacialExpressionsHTC>(
+        GET_FACIAL_EXPRESSIONS_HTC.load(Ordering::Relaxed),
+    );
+    let result = real(facial_tracker, facial_expressions);
+    tracing::trace!(target: "openxr::ffi", "xrGetFacialExpressionsHTC(facial_tracker: {:?}, facial_expressions: {:?}) -> {:?}", facial_tracker, facial_expressions, result);
+    result
+}
+
+static CREATE_PASSTHROUGH_HTC: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_passthrough_htc(
+    session: Session,
+    create_info: *const PassthroughCreateInfoHTC,
+    passthrough: *mut PassthroughHTC,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreatePassthroughHTC>(
+        CREATE_PASSTHROUGH_HTC.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, passthrough);
+    tracing::trace!(target: "openxr::ffi", "xrCreatePassthroughHTC(session: {:?}, create_info: {:?}, passthrough: {:?}) -> {:?}", session, create_info, passthrough, result);
+    result
+}
+
+static DESTROY_PASSTHROUGH_HTC: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_passthrough_htc(passthrough: PassthroughHTC) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyPassthroughHTC>(
+        DESTROY_PASSTHROUGH_HTC.load(Ordering::Relaxed),
+    );
+    let result = real(passthrough);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyPassthroughHTC(passthrough: {:?}) -> {:?}", passthrough, result);
+    result
+}
+
+static ENUMERATE_VIVE_TRACKER_PATHS_HTCX: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_vive_tracker_paths_htcx(
+    instance: Instance,
+    path_capacity_input: u32,
+    path_count_output: *mut u32,
+    paths: *mut ViveTrackerPathsHTCX,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateViveTrackerPathsHTCX>(
+        ENUMERATE_VIVE_TRACKER_PATHS_HTCX.load(Ordering::Relaxed),
+    );
+    let result = real(instance, path_capacity_input, path_count_output, paths);
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateViveTrackerPathsHTCX(instance: {:?}, path_capacity_input: {:?}, path_count_output: {:?}, paths: {:?}) -> {:?}", instance, path_capacity_input, path_count_output, paths, result);
+    result
+}
+
+static SET_MARKER_TRACKING_VARJO: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_marker_tracking_varjo(session: Session, enabled: Bool32) -> Result {
+    let real = mem::transmute::<usize, pfn::SetMarkerTrackingVARJO>(
+        SET_MARKER_TRACKING_VARJO.load(Ordering::Relaxed),
+    );
+    let result = real(session, enabled);
+    tracing::trace!(target: "openxr::ffi", "xrSetMarkerTrackingVARJO(session: {:?}, enabled: {:?}) -> {:?}", session, enabled, result);
+    result
+}
+
+static SET_MARKER_TRACKING_TIMEOUT_VARJO: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_marker_tracking_timeout_varjo(
+    session: Session,
+    marker_id: u64,
+    timeout: Duration,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetMarkerTrackingTimeoutVARJO>(
+        SET_MARKER_TRACKING_TIMEOUT_VARJO.load(Ordering::Relaxed),
+    );
+    let result = real(session, marker_id, timeout);
+    tracing::trace!(target: "openxr::ffi", "xrSetMarkerTrackingTimeoutVARJO(session: {:?}, marker_id: {:?}, timeout: {:?}) -> {:?}", session, marker_id, timeout, result);
+    result
+}
+
+static SET_MARKER_TRACKING_PREDICTION_VARJO: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_marker_tracking_prediction_varjo(
+    session: Session,
+    marker_id: u64,
+    enable: Bool32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetMarkerTrackingPredictionVARJO>(
+        SET_MARKER_TRACKING_PREDICTION_VARJO.load(Ordering::Relaxed),
+    );
+    let result = real(session, marker_id, enable);
+    tracing::trace!(target: "openxr::ffi", "xrSetMarkerTrackingPredictionVARJO(session: {:?}, marker_id: {:?}, enable: {:?}) -> {:?}", session, marker_id, enable, result);
+    result
+}
+
+static GET_MARKER_SIZE_VARJO: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_marker_size_varjo(
+    session: Session,
+    marker_id: u64,
+    size: *mut Extent2Df,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetMarkerSizeVARJO>(
+        GET_MARKER_SIZE_VARJO.load(Ordering::Relaxed),
+    );
+    let result = real(session, marker_id, size);
+    tracing::trace!(target: "openxr::ffi", "xrGetMarkerSizeVARJO(session: {:?}, marker_id: {:?}, size: {:?}) -> {:?}", session, marker_id, size, result);
+    result
+}
+
+static CREATE_MARKER_SPACE_VARJO: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_marker_space_varjo(
+    session: Session,
+    create_info: *const MarkerSpaceCreateInfoVARJO,
+    space: *mut Space,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateMarkerSpaceVARJO>(
+        CREATE_MARKER_SPACE_VARJO.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, space);
+    tracing::trace!(target: "openxr::ffi", "xrCreateMarkerSpaceVARJO(session: {:?}, create_info: {:?}, space: {:?}) -> {:?}", session, create_info, space, result);
+    result
+}
+
+static SET_DIGITAL_LENS_CONTROL_ALMALENCE: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_digital_lens_control_almalence(
+    session: Session,
+    digital_lens_control: *const DigitalLensControlALMALENCE,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetDigitalLensControlALMALENCE>(
+        SET_DIGITAL_LENS_CONTROL_ALMALENCE.load(Ordering::Relaxed),
+    );
+    let result = real(session, digital_lens_control);
+    tracing::trace!(target: "openxr::ffi", "xrSetDigitalLensControlALMALENCE(session: {:?}, digital_lens_control: {:?}) -> {:?}", session, digital_lens_control, result);
+    result
+}
+
+static SET_VIEW_OFFSET_VARJO: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_view_offset_varjo(session: Session, offset: f32) -> Result {
+    let real = mem::transmute::<usize, pfn::SetViewOffsetVARJO>(
+        SET_VIEW_OFFSET_VARJO.load(Ordering::Relaxed),
+    );
+    let result = real(session, offset);
+    tracing::trace!(target: "openxr::ffi", "xrSetViewOffsetVARJO(session: {:?}, offset: {:?}) -> {:?}", session, offset, result);
+    result
+}
+
+static ENUMERATE_EXTERNAL_CAMERAS_OCULUS: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_external_cameras_oculus(
+    session: Session,
+    camera_capacity_input: u32,
+    camera_count_output: *mut u32,
+    cameras: *mut ExternalCameraOCULUS,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumerateExternalCamerasOCULUS>(
+        ENUMERATE_EXTERNAL_CAMERAS_OCULUS.load(Ordering::Relaxed),
+    );
+    let result = real(session, camera_capacity_input, camera_count_output, cameras);
+    tracing::trace!(target: "openxr::ffi", "xrEnumerateExternalCamerasOCULUS(session: {:?}, camera_capacity_input: {:?}, camera_count_output: {:?}, cameras: {:?}) -> {:?}", session, camera_capacity_input, camera_count_output, cameras, result);
+    result
+}
+
+static CREATE_PASSTHROUGH_COLOR_LUT_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_passthrough_color_lut_meta(
+    passthrough: PassthroughFB,
+    create_info: *const PassthroughColorLutCreateInfoMETA,
+    color_lut: *mut PassthroughColorLutMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreatePassthroughColorLutMETA>(
+        CREATE_PASSTHROUGH_COLOR_LUT_META.load(Ordering::Relaxed),
+    );
+    let result = real(passthrough, create_info, color_lut);
+    tracing::trace!(target: "openxr::ffi", "xrCreatePassthroughColorLutMETA(passthrough: {:?}, create_info: {:?}, color_lut: {:?}) -> {:?}", passthrough, create_info, color_lut, result);
+    result
+}
+
+static DESTROY_PASSTHROUGH_COLOR_LUT_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_passthrough_color_lut_meta(
+    color_lut: PassthroughColorLutMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyPassthroughColorLutMETA>(
+        DESTROY_PASSTHROUGH_COLOR_LUT_META.load(Ordering::Relaxed),
+    );
+    let result = real(color_lut);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyPassthroughColorLutMETA(color_lut: {:?}) -> {:?}", color_lut, result);
+    result
+}
+
+static UPDATE_PASSTHROUGH_COLOR_LUT_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn update_passthrough_color_lut_meta(
+    color_lut: PassthroughColorLutMETA,
+    update_info: *const PassthroughColorLutUpdateInfoMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::UpdatePassthroughColorLutMETA>(
+        UPDATE_PASSTHROUGH_COLOR_LUT_META.load(Ordering::Relaxed),
+    );
+    let result = real(color_lut, update_info);
+    tracing::trace!(target: "openxr::ffi", "xrUpdatePassthroughColorLutMETA(color_lut: {:?}, update_info: {:?}) -> {:?}", color_lut, update_info, result);
+    result
+}
+
+static ENUMERATE_PERFORMANCE_METRICS_COUNTER_PATHS_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enumerate_performance_metrics_counter_paths_meta(
+    instance: Instance,
+    counter_path_capacity_input: u32,
+    counter_path_count_output: *mut u32,
+    counter_paths: *mut Path,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnumeratePerformanceMetricsCounterPathsMETA>(
+        ENUMERATE_PERFORMANCE_METRICS_COUNTER_PATHS_META.load(Ordering::Relaxed),
+    );
+    let result = real(
+        instance,
+        counter_path_capacity_input,
+        counter_path_count_output,
+        counter_paths,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrEnumeratePerformanceMetricsCounterPathsMETA(instance: {:?}, counter_path_capacity_input: {:?}, counter_path_count_output: {:?}, counter_paths: {:?}) -> {:?}", instance, counter_path_capacity_input, counter_path_count_output, counter_paths, result);
+    result
+}
+
+static SET_PERFORMANCE_METRICS_STATE_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_performance_metrics_state_meta(
+    session: Session,
+    state: *const PerformanceMetricsStateMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetPerformanceMetricsStateMETA>(
+        SET_PERFORMANCE_METRICS_STATE_META.load(Ordering::Relaxed),
+    );
+    let result = real(session, state);
+    tracing::trace!(target: "openxr::ffi", "xrSetPerformanceMetricsStateMETA(session: {:?}, state: {:?}) -> {:?}", session, state, result);
+    result
+}
+
+static GET_PERFORMANCE_METRICS_STATE_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_performance_metrics_state_meta(
+    session: Session,
+    state: *mut PerformanceMetricsStateMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetPerformanceMetricsStateMETA>(
+        GET_PERFORMANCE_METRICS_STATE_META.load(Ordering::Relaxed),
+    );
+    let result = real(session, state);
+    tracing::trace!(target: "openxr::ffi", "xrGetPerformanceMetricsStateMETA(session: {:?}, state: {:?}) -> {:?}", session, state, result);
+    result
+}
+
+static QUERY_PERFORMANCE_METRICS_COUNTER_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn query_performance_metrics_counter_meta(
+    session: Session,
+    counter_path: Path,
+    counter: *mut PerformanceMetricsCounterMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::QueryPerformanceMetricsCounterMETA>(
+        QUERY_PERFORMANCE_METRICS_COUNTER_META.load(Ordering::Relaxed),
+    );
+    let result = real(session, counter_path, counter);
+    tracing::trace!(target: "openxr::ffi", "xrQueryPerformanceMetricsCounterMETA(session: {:?}, counter_path: {:?}, counter: {:?}) -> {:?}", session, counter_path, counter, result);
+    result
+}
+
+static GET_PASSTHROUGH_PREFERENCES_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_passthrough_preferences_meta(
+    session: Session,
+    preferences: *mut PassthroughPreferencesMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetPassthroughPreferencesMETA>(
+        GET_PASSTHROUGH_PREFERENCES_META.load(Ordering::Relaxed),
+    );
+    let result = real(session, preferences);
+    tracing::trace!(target: "openxr::ffi", "xrGetPassthroughPreferencesMETA(session: {:?}, preferences: {:?}) -> {:?}", session, preferences, result);
+    result
+}
+
+static APPLY_FOVEATION_HTC: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn apply_foveation_htc(
+    session: Session,
+    apply_info: *const FoveationApplyInfoHTC,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ApplyFoveationHTC>(
+        APPLY_FOVEATION_HTC.load(Ordering::Relaxed),
+    );
+    let result = real(session, apply_info);
+    tracing::trace!(target: "openxr::ffi", "xrApplyFoveationHTC(session: {:?}, apply_info: {:?}) -> {:?}", session, apply_info, result);
+    result
+}
+
+static CREATE_SPACE_FROM_COORDINATE_FRAME_UIDML: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_space_from_coordinate_frame_uidml(
+    session: Session,
+    create_info: *const CoordinateSpaceCreateInfoML,
+    space: *mut Space,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateSpaceFromCoordinateFrameUIDML>(
+        CREATE_SPACE_FROM_COORDINATE_FRAME_UIDML.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, space);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSpaceFromCoordinateFrameUIDML(session: {:?}, create_info: {:?}, space: {:?}) -> {:?}", session, create_info, space, result);
+    result
+}
+
+static GET_DEVICE_SAMPLE_RATE_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_device_sample_rate_fb(
+    session: Session,
+    haptic_action_info: *const HapticActionInfo,
+    device_sample_rate: *mut DevicePcmSampleRateGetInfoFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetDeviceSampleRateFB>(
+        GET_DEVICE_SAMPLE_RATE_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, haptic_action_info, device_sample_rate);
+    tracing::trace!(target: "openxr::ffi", "xrGetDeviceSampleRateFB(session: {:?}, haptic_action_info: {:?}, device_sample_rate: {:?}) -> {:?}", session, haptic_action_info, device_sample_rate, result);
+    result
+}
+
+static SET_TRACKING_OPTIMIZATION_SETTINGS_HINT_QCOM: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_tracking_optimization_settings_hint_qcom(
+    session: Session,
+    domain: TrackingOptimizationSettingsDomainQCOM,
+    hint: TrackingOptimizationSettingsHintQCOM,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetTrackingOptimizationSettingsHintQCOM>(
+        SET_TRACKING_OPTIMIZATION_SETTINGS_HINT_QCOM.load(Ordering::Relaxed),
+    );
+    let result = real(session, domain, hint);
+    tracing::trace!(target: "openxr::ffi", "xrSetTrackingOptimizationSettingsHintQCOM(session: {:?}, domain: {:?}, hint: {:?}) -> {:?}", session, domain, hint, result);
+    result
+}
+
+static CREATE_SPACE_USER_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_space_user_fb(
+    session: Session,
+    info: *const SpaceUserCreateInfoFB,
+    user: *mut SpaceUserFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateSpaceUserFB>(
+        CREATE_SPACE_USER_FB.load(Ordering::Relaxed),
+    );
+    let result = real(session, info, user);
+    tracing::trace!(target: "openxr::ffi", "xrCreateSpaceUserFB(session: {:?}, info: {:?}, user: {:?}) -> {:?}", session, info, user, result);
+    result
+}
+
+static GET_SPACE_USER_ID_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_space_user_id_fb(
+    user: SpaceUserFB,
+    user_id: *mut SpaceUserIdFB,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetSpaceUserIdFB>(
+        GET_SPACE_USER_ID_FB.load(Ordering::Relaxed),
+    );
+    let result = real(user, user_id);
+    tracing::trace!(target: "openxr::ffi", "xrGetSpaceUserIdFB(user: {:?}, user_id: {:?}) -> {:?}", user, user_id, result);
+    result
+}
+
+static DESTROY_SPACE_USER_FB: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_space_user_fb(user: SpaceUserFB) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroySpaceUserFB>(
+        DESTROY_SPACE_USER_FB.load(Ordering::Relaxed),
+    );
+    let result = real(user);
+    tracing::trace!(target: "openxr::ffi", "xrDestroySpaceUserFB(user: {:?}) -> {:?}", user, result);
+    result
+}
+
+static APPLY_FORCE_FEEDBACK_CURL_MNDX: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn apply_force_feedback_curl_mndx(
+    hand_tracker: HandTrackerEXT,
+    locations: *const ForceFeedbackCurlApplyLocationsMNDX,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ApplyForceFeedbackCurlMNDX>(
+        APPLY_FORCE_FEEDBACK_CURL_MNDX.load(Ordering::Relaxed),
+    );
+    let result = real(hand_tracker, locations);
+    tracing::trace!(target: "openxr::ffi", "xrApplyForceFeedbackCurlMNDX(hand_tracker: {:?}, locations: {:?}) -> {:?}", hand_tracker, locations, result);
+    result
+}
+
+static CREATE_PLANE_DETECTOR_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_plane_detector_ext(
+    session: Session,
+    create_info: *const PlaneDetectorCreateInfoEXT,
+    plane_detector: *mut PlaneDetectorEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreatePlaneDetectorEXT>(
+        CREATE_PLANE_DETECTOR_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, plane_detector);
+    tracing::trace!(target: "openxr::ffi", "xrCreatePlaneDetectorEXT(session: {:?}, create_info: {:?}, plane_detector: {:?}) -> {:?}", session, create_info, plane_detector, result);
+    result
+}
+
+static DESTROY_PLANE_DETECTOR_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_plane_detector_ext(plane_detector: PlaneDetectorEXT) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyPlaneDetectorEXT>(
+        DESTROY_PLANE_DETECTOR_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(plane_detector);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyPlaneDetectorEXT(plane_detector: {:?}) -> {:?}", plane_detector, result);
+    result
+}
+
+static BEGIN_PLANE_DETECTION_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn begin_plane_detection_ext(
+    plane_detector: PlaneDetectorEXT,
+    begin_info: *const PlaneDetectorBeginInfoEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::BeginPlaneDetectionEXT>(
+        BEGIN_PLANE_DETECTION_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(plane_detector, begin_info);
+    tracing::trace!(target: "openxr::ffi", "xrBeginPlaneDetectionEXT(plane_detector: {:?}, begin_info: {:?}) -> {:?}", plane_detector, begin_info, result);
+    result
+}
+
+static GET_PLANE_DETECTION_STATE_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_plane_detection_state_ext(
+    plane_detector: PlaneDetectorEXT,
+    state: *mut PlaneDetectionStateEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetPlaneDetectionStateEXT>(
+        GET_PLANE_DETECTION_STATE_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(plane_detector, state);
+    tracing::trace!(target: "openxr::ffi", "xrGetPlaneDetectionStateEXT(plane_detector: {:?}, state: {:?}) -> {:?}", plane_detector, state, result);
+    result
+}
+
+static GET_PLANE_DETECTIONS_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_plane_detections_ext(
+    plane_detector: PlaneDetectorEXT,
+    info: *const PlaneDetectorGetInfoEXT,
+    locations: *mut PlaneDetectorLocationsEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetPlaneDetectionsEXT>(
+        GET_PLANE_DETECTIONS_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(plane_detector, info, locations);
+    tracing::trace!(target: "openxr::ffi", "xrGetPlaneDetectionsEXT(plane_detector: {:?}, info: {:?}, locations: {:?}) -> {:?}", plane_detector, info, locations, result);
+    result
+}
+
+static GET_PLANE_POLYGON_BUFFER_EXT: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_plane_polygon_buffer_ext(
+    plane_detector: PlaneDetectorEXT,
+    plane_id: u64,
+    polygon_buffer_index: u32,
+    polygon_buffer: *mut PlaneDetectorPolygonBufferEXT,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetPlanePolygonBufferEXT>(
+        GET_PLANE_POLYGON_BUFFER_EXT.load(Ordering::Relaxed),
+    );
+    let result = real(
+        plane_detector,
+        plane_id,
+        polygon_buffer_index,
+        polygon_buffer,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrGetPlanePolygonBufferEXT(plane_detector: {:?}, plane_id: {:?}, polygon_buffer_index: {:?}, polygon_buffer: {:?}) -> {:?}", plane_detector, plane_id, polygon_buffer_index, polygon_buffer, result);
+    result
+}
+
+static CREATE_VIRTUAL_KEYBOARD_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_virtual_keyboard_meta(
+    session: Session,
+    create_info: *const VirtualKeyboardCreateInfoMETA,
+    keyboard: *mut VirtualKeyboardMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateVirtualKeyboardMETA>(
+        CREATE_VIRTUAL_KEYBOARD_META.load(Ordering::Relaxed),
+    );
+    let result = real(session, create_info, keyboard);
+    tracing::trace!(target: "openxr::ffi", "xrCreateVirtualKeyboardMETA(session: {:?}, create_info: {:?}, keyboard: {:?}) -> {:?}", session, create_info, keyboard, result);
+    result
+}
+
+static DESTROY_VIRTUAL_KEYBOARD_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn destroy_virtual_keyboard_meta(keyboard: VirtualKeyboardMETA) -> Result {
+    let real = mem::transmute::<usize, pfn::DestroyVirtualKeyboardMETA>(
+        DESTROY_VIRTUAL_KEYBOARD_META.load(Ordering::Relaxed),
+    );
+    let result = real(keyboard);
+    tracing::trace!(target: "openxr::ffi", "xrDestroyVirtualKeyboardMETA(keyboard: {:?}) -> {:?}", keyboard, result);
+    result
+}
+
+static CREATE_VIRTUAL_KEYBOARD_SPACE_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn create_virtual_keyboard_space_meta(
+    session: Session,
+    keyboard: VirtualKeyboardMETA,
+    create_info: *const VirtualKeyboardSpaceCreateInfoMETA,
+    keyboard_space: *mut Space,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::CreateVirtualKeyboardSpaceMETA>(
+        CREATE_VIRTUAL_KEYBOARD_SPACE_META.load(Ordering::Relaxed),
+    );
+    let result = real(session, keyboard, create_info, keyboard_space);
+    tracing::trace!(target: "openxr::ffi", "xrCreateVirtualKeyboardSpaceMETA(session: {:?}, keyboard: {:?}, create_info: {:?}, keyboard_space: {:?}) -> {:?}", session, keyboard, create_info, keyboard_space, result);
+    result
+}
+
+static SUGGEST_VIRTUAL_KEYBOARD_LOCATION_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn suggest_virtual_keyboard_location_meta(
+    keyboard: VirtualKeyboardMETA,
+    location_info: *const VirtualKeyboardLocationInfoMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SuggestVirtualKeyboardLocationMETA>(
+        SUGGEST_VIRTUAL_KEYBOARD_LOCATION_META.load(Ordering::Relaxed),
+    );
+    let result = real(keyboard, location_info);
+    tracing::trace!(target: "openxr::ffi", "xrSuggestVirtualKeyboardLocationMETA(keyboard: {:?}, location_info: {:?}) -> {:?}", keyboard, location_info, result);
+    result
+}
+
+static GET_VIRTUAL_KEYBOARD_SCALE_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_virtual_keyboard_scale_meta(
+    keyboard: VirtualKeyboardMETA,
+    scale: *mut f32,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetVirtualKeyboardScaleMETA>(
+        GET_VIRTUAL_KEYBOARD_SCALE_META.load(Ordering::Relaxed),
+    );
+    let result = real(keyboard, scale);
+    tracing::trace!(target: "openxr::ffi", "xrGetVirtualKeyboardScaleMETA(keyboard: {:?}, scale: {:?}) -> {:?}", keyboard, scale, result);
+    result
+}
+
+static SET_VIRTUAL_KEYBOARD_MODEL_VISIBILITY_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn set_virtual_keyboard_model_visibility_meta(
+    keyboard: VirtualKeyboardMETA,
+    model_visibility: *const VirtualKeyboardModelVisibilitySetInfoMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SetVirtualKeyboardModelVisibilityMETA>(
+        SET_VIRTUAL_KEYBOARD_MODEL_VISIBILITY_META.load(Ordering::Relaxed),
+    );
+    let result = real(keyboard, model_visibility);
+    tracing::trace!(target: "openxr::ffi", "xrSetVirtualKeyboardModelVisibilityMETA(keyboard: {:?}, model_visibility: {:?}) -> {:?}", keyboard, model_visibility, result);
+    result
+}
+
+static GET_VIRTUAL_KEYBOARD_MODEL_ANIMATION_STATES_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_virtual_keyboard_model_animation_states_meta(
+    keyboard: VirtualKeyboardMETA,
+    animation_states: *mut VirtualKeyboardModelAnimationStatesMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetVirtualKeyboardModelAnimationStatesMETA>(
+        GET_VIRTUAL_KEYBOARD_MODEL_ANIMATION_STATES_META.load(Ordering::Relaxed),
+    );
+    let result = real(keyboard, animation_states);
+    tracing::trace!(target: "openxr::ffi", "xrGetVirtualKeyboardModelAnimationStatesMETA(keyboard: {:?}, animation_states: {:?}) -> {:?}", keyboard, animation_states, result);
+    result
+}
+
+static GET_VIRTUAL_KEYBOARD_DIRTY_TEXTURES_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_virtual_keyboard_dirty_textures_meta(
+    keyboard: VirtualKeyboardMETA,
+    texture_id_capacity_input: u32,
+    texture_id_count_output: *mut u32,
+    texture_ids: *mut u64,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetVirtualKeyboardDirtyTexturesMETA>(
+        GET_VIRTUAL_KEYBOARD_DIRTY_TEXTURES_META.load(Ordering::Relaxed),
+    );
+    let result = real(
+        keyboard,
+        texture_id_capacity_input,
+        texture_id_count_output,
+        texture_ids,
+    );
+    tracing::trace!(target: "openxr::ffi", "xrGetVirtualKeyboardDirtyTexturesMETA(keyboard: {:?}, texture_id_capacity_input: {:?}, texture_id_count_output: {:?}, texture_ids: {:?}) -> {:?}", keyboard, texture_id_capacity_input, texture_id_count_output, texture_ids, result);
+    result
+}
+
+static GET_VIRTUAL_KEYBOARD_TEXTURE_DATA_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_virtual_keyboard_texture_data_meta(
+    keyboard: VirtualKeyboardMETA,
+    texture_id: u64,
+    texture_data: *mut VirtualKeyboardTextureDataMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetVirtualKeyboardTextureDataMETA>(
+        GET_VIRTUAL_KEYBOARD_TEXTURE_DATA_META.load(Ordering::Relaxed),
+    );
+    let result = real(keyboard, texture_id, texture_data);
+    tracing::trace!(target: "openxr::ffi", "xrGetVirtualKeyboardTextureDataMETA(keyboard: {:?}, texture_id: {:?}, texture_data: {:?}) -> {:?}", keyboard, texture_id, texture_data, result);
+    result
+}
+
+static SEND_VIRTUAL_KEYBOARD_INPUT_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn send_virtual_keyboard_input_meta(
+    keyboard: VirtualKeyboardMETA,
+    info: *const VirtualKeyboardInputInfoMETA,
+    interactor_root_pose: *mut Posef,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::SendVirtualKeyboardInputMETA>(
+        SEND_VIRTUAL_KEYBOARD_INPUT_META.load(Ordering::Relaxed),
+    );
+    let result = real(keyboard, info, interactor_root_pose);
+    tracing::trace!(target: "openxr::ffi", "xrSendVirtualKeyboardInputMETA(keyboard: {:?}, info: {:?}, interactor_root_pose: {:?}) -> {:?}", keyboard, info, interactor_root_pose, result);
+    result
+}
+
+static CHANGE_VIRTUAL_KEYBOARD_TEXT_CONTEXT_META: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn change_virtual_keyboard_text_context_meta(
+    keyboard: VirtualKeyboardMETA,
+    change_info: *const VirtualKeyboardTextContextChangeInfoMETA,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::ChangeVirtualKeyboardTextContextMETA>(
+        CHANGE_VIRTUAL_KEYBOARD_TEXT_CONTEXT_META.load(Ordering::Relaxed),
+    );
+    let result = real(keyboard, change_info);
+    tracing::trace!(target: "openxr::ffi", "xrChangeVirtualKeyboardTextContextMETA(keyboard: {:?}, change_info: {:?}) -> {:?}", keyboard, change_info, result);
+    result
+}
+
+static ENABLE_USER_CALIBRATION_EVENTS_ML: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn enable_user_calibration_events_ml(
+    instance: Instance,
+    enable_info: *const UserCalibrationEnableEventsInfoML,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::EnableUserCalibrationEventsML>(
+        ENABLE_USER_CALIBRATION_EVENTS_ML.load(Ordering::Relaxed),
+    );
+    let result = real(instance, enable_info);
+    tracing::trace!(target: "openxr::ffi", "xrEnableUserCalibrationEventsML(instance: {:?}, enable_info: {:?}) -> {:?}", instance, enable_info, result);
+    result
+}
+
+static GET_VULKAN_GRAPHICS_REQUIREMENTS2_KHR: AtomicUsize = AtomicUsize::new(0);
+unsafe extern "system" fn get_vulkan_graphics_requirements2_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsVulkanKHR,
+) -> Result {
+    let real = mem::transmute::<usize, pfn::GetVulkanGraphicsRequirements2KHR>(
+        GET_VULKAN_GRAPHICS_REQUIREMENTS2_KHR.load(Ordering::Relaxed),
+    );
+    let result = real(instance, system_id, graphics_requirements);
+    tracing::trace!(target: "openxr::ffi", "xrGetVulkanGraphicsRequirements2KHR(instance: {:?}, system_id: {:?}, graphics_requirements: {:?}) -> {:?}", instance, system_id, graphics_requirements, result);
+    result
+}
@@ -0,0 +1,62 @@
+//! Foveated rendering on Varjo headsets with [`XR_VARJO_foveated_rendering`].
+//!
+//! When foveation is active, the focus views of `PRIMARY_QUAD_VARJO` follow the user's gaze, and
+//! the runtime recommends smaller focus view sizes. The same choice must be made both when sizing
+//! swapchains with [`Instance::enumerate_view_configuration_views_foveated_varjo`] and when
+//! locating views with [`Session::locate_views_foveated_varjo`]. Whether the system supports
+//! foveation at all is reported by [`Instance::supports_varjo_eye_tracked_foveation`].
+//!
+//! [`XR_VARJO_foveated_rendering`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_VARJO_foveated_rendering
+
+use crate::*;
+
+impl Instance {
+    /// Like [`Instance::enumerate_view_configuration_views`], but with recommendations for
+    /// foveated rendering if `foveated` is set
+    ///
+    /// Requires XR_VARJO_foveated_rendering.
+    pub fn enumerate_view_configuration_views_foveated_varjo(
+        &self,
+        system: SystemId,
+        ty: ViewConfigurationType,
+        foveated: bool,
+    ) -> Result<Vec<ViewConfigurationView>> {
+        let views = self.enumerate_view_configuration_views_with(
+            system,
+            ty,
+            &ViewConfigurationViewsQuery::new().foveated_varjo(foveated),
+        )?;
+        Ok(views.into_iter().map(|x| x.view).collect())
+    }
+}
+
+impl<G> Session<G> {
+    /// Like [`Session::locate_views`], but with foveated focus views if `foveated` is set
+    ///
+    /// Requires XR_VARJO_foveated_rendering.
+    pub fn locate_views_foveated_varjo(
+        &self,
+        view_configuration_type: ViewConfigurationType,
+        display_time: Time,
+        space: &Space,
+        foveated: bool,
+    ) -> Result<(ViewStateFlags, Vec<View>)> {
+        let located = self.locate_views_with(
+            view_configuration_type,
+            display_time,
+            space,
+            &ViewLocateQuery::new().foveated_varjo(foveated),
+        )?;
+        Ok((located.state.flags(), located.views))
+    }
+
+    /// Create a space tracking the user's combined gaze, as used to place foveated focus views
+    ///
+    /// Requires XR_VARJO_foveated_rendering.
+    pub fn create_rendering_gaze_space_varjo(&self) -> Result<Space> {
+        if self.instance().exts().varjo_foveated_rendering.is_none() {
+            return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+        }
+        self.create_reference_space(ReferenceSpaceType::COMBINED_EYE_VARJO, Posef::IDENTITY)
+    }
+}
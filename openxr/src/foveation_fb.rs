@@ -1,5 +1,7 @@
 use std::{ptr, sync::Arc};
 
+use sys::platform::VkImage;
+
 use crate::*;
 
 #[derive(Clone)]
@@ -84,17 +86,8 @@ impl<G: Graphics> Session<G> {
 
         let mut out = sys::Swapchain::NULL;
         let info = sys::SwapchainCreateInfo {
-            ty: sys::SwapchainCreateInfo::TYPE,
             next: &foveation_info as *const _ as _,
-            create_flags: info.create_flags,
-            usage_flags: info.usage_flags,
-            format: G::lower_format(info.format),
-            sample_count: info.sample_count,
-            width: info.width,
-            height: info.height,
-            face_count: info.face_count,
-            array_size: info.array_size,
-            mip_count: info.mip_count,
+            ..info.to_raw()
         };
         unsafe {
             cvt((self.instance().fp().create_swapchain)(
@@ -129,6 +122,75 @@ impl<G: Graphics> Swapchain<G> {
     }
 }
 
+/// A Vulkan swapchain image along with the fragment density map the runtime provides for it
+///
+/// Requires [`XR_FB_foveation_vulkan`](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_FB_foveation_vulkan)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FoveatedImageVulkanFB {
+    pub image: VkImage,
+    /// Fragment density map to attach when rendering to `image`, or `None` if the runtime didn't
+    /// provide one
+    pub foveation_image: Option<VkImage>,
+    pub foveation_width: u32,
+    pub foveation_height: u32,
+}
+
+impl Swapchain<Vulkan> {
+    /// Like [`Swapchain::enumerate_images`], but also retrieve each image's fragment density map
+    ///
+    /// The swapchain must have been created with
+    /// [`Session::create_swapchain_with_foveation`] and
+    /// `SwapchainCreateFoveationFlagsFB::FRAGMENT_DENSITY_MAP`.
+    pub fn enumerate_images_with_foveation_fb(&self) -> Result<Vec<FoveatedImageVulkanFB>> {
+        self.instance()
+            .exts()
+            .fb_foveation_vulkan
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let enumerate = self.instance().fp().enumerate_swapchain_images;
+
+        let mut count = 0;
+        unsafe {
+            cvt(enumerate(self.as_raw(), 0, &mut count, ptr::null_mut()))?;
+        }
+        // The chained structures must stay put while the runtime writes to them
+        let mut foveation = (0..count)
+            .map(|_| sys::SwapchainImageFoveationVulkanFB::out(ptr::null_mut()))
+            .collect::<Vec<_>>();
+        let mut images = foveation
+            .iter_mut()
+            .map(|x| sys::SwapchainImageVulkanKHR::out(x.as_mut_ptr() as _))
+            .collect::<Vec<_>>();
+        unsafe {
+            cvt(enumerate(
+                self.as_raw(),
+                count,
+                &mut count,
+                images.as_mut_ptr() as *mut _,
+            ))?;
+        }
+        Ok(images
+            .iter()
+            .zip(&foveation)
+            .take(count as usize)
+            .map(|(image, foveation)| {
+                let (image, foveation) =
+                    unsafe { (image.assume_init_ref(), foveation.assume_init_ref()) };
+                FoveatedImageVulkanFB {
+                    image: image.image as _,
+                    foveation_image: if foveation.image == 0 {
+                        None
+                    } else {
+                        Some(foveation.image as _)
+                    },
+                    foveation_width: foveation.width,
+                    foveation_height: foveation.height,
+                }
+            })
+            .collect())
+    }
+}
+
 struct FoveationProfileFBInner {
     instance: Instance,
     handle: sys::FoveationProfileFB,
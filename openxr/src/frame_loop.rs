@@ -0,0 +1,121 @@
+use crate::*;
+
+/// Drives the wait/begin/end frame cycle, making the runtime's rendering decisions explicit
+///
+/// Combines a [`FrameWaiter`] and [`FrameStream`] for applications that wait for and present
+/// frames on the same thread. Each iteration calls [`FrameLoop::begin`], acts on the returned
+/// [`FrameDecision`], then finishes the frame with either [`FrameLoop::end`] or
+/// [`FrameLoop::skip`].
+///
+/// # Example
+///
+/// ```no_run
+/// # fn dummy<G: openxr::Graphics>(
+/// #     frame_loop: &mut openxr::FrameLoop<G>,
+/// #     layers: &[&openxr::CompositionLayerBase<'_, G>],
+/// # ) {
+/// match frame_loop.begin().unwrap() {
+///     openxr::FrameDecision::Render(state) | openxr::FrameDecision::RenderAfterDiscard(state) => {
+///         // draw scene...
+///         frame_loop
+///             .end(&state, openxr::EnvironmentBlendMode::OPAQUE, layers)
+///             .unwrap();
+///     }
+///     openxr::FrameDecision::Skip(state) => {
+///         frame_loop
+///             .skip(&state, openxr::EnvironmentBlendMode::OPAQUE)
+///             .unwrap();
+///     }
+/// }
+/// # }
+/// ```
+pub struct FrameLoop<G: Graphics> {
+    waiter: FrameWaiter,
+    stream: FrameStream<G>,
+}
+
+impl<G: Graphics> FrameLoop<G> {
+    pub fn new(waiter: FrameWaiter, stream: FrameStream<G>) -> Self {
+        Self { waiter, stream }
+    }
+
+    /// Wait until the next frame should begin, then begin it
+    ///
+    /// Every successful call must be followed by exactly one call to [`FrameLoop::end`] or
+    /// [`FrameLoop::skip`], whatever the decision; a frame that's begun but never ended is
+    /// discarded by the runtime when the next frame begins.
+    pub fn begin(&mut self) -> Result<FrameDecision> {
+        let state = self.waiter.wait()?;
+        let status = self.stream.begin()?;
+        Ok(if !state.should_render {
+            FrameDecision::Skip(state)
+        } else if status == sys::Result::FRAME_DISCARDED {
+            FrameDecision::RenderAfterDiscard(state)
+        } else {
+            FrameDecision::Render(state)
+        })
+    }
+
+    /// Submit rendered layers for the frame begun by the preceding [`FrameLoop::begin`]
+    pub fn end(
+        &mut self,
+        state: &FrameState,
+        environment_blend_mode: EnvironmentBlendMode,
+        layers: &[&CompositionLayerBase<'_, G>],
+    ) -> Result<sys::Result> {
+        self.stream
+            .end(state.predicted_display_time, environment_blend_mode, layers)
+    }
+
+    /// End the frame begun by the preceding [`FrameLoop::begin`] without submitting any layers
+    ///
+    /// This is the required way to finish a [`FrameDecision::Skip`] frame: skipping the call
+    /// entirely leaves the frame open, which stalls subsequent frames.
+    pub fn skip(
+        &mut self,
+        state: &FrameState,
+        environment_blend_mode: EnvironmentBlendMode,
+    ) -> Result<sys::Result> {
+        self.stream
+            .end(state.predicted_display_time, environment_blend_mode, &[])
+    }
+
+    /// Access the underlying handles, e.g. to use secondary view configurations
+    pub fn parts_mut(&mut self) -> (&mut FrameWaiter, &mut FrameStream<G>) {
+        (&mut self.waiter, &mut self.stream)
+    }
+
+    pub fn into_parts(self) -> (FrameWaiter, FrameStream<G>) {
+        (self.waiter, self.stream)
+    }
+}
+
+/// What the application should do with a frame begun by [`FrameLoop::begin`]
+#[derive(Debug, Copy, Clone)]
+pub enum FrameDecision {
+    /// Render the frame and submit it with [`FrameLoop::end`]
+    Render(FrameState),
+    /// The runtime discarded the previous frame because it was never ended
+    ///
+    /// This frame should be rendered and submitted with [`FrameLoop::end`] as usual, but the
+    /// discard usually indicates an application bug, such as not ending skipped frames.
+    RenderAfterDiscard(FrameState),
+    /// The runtime doesn't need this frame rendered, e.g. because the application isn't visible
+    ///
+    /// Skip rendering, but end the frame with [`FrameLoop::skip`].
+    Skip(FrameState),
+}
+
+impl FrameDecision {
+    #[inline]
+    pub fn state(&self) -> &FrameState {
+        match self {
+            Self::Render(x) | Self::RenderAfterDiscard(x) | Self::Skip(x) => x,
+        }
+    }
+
+    #[inline]
+    pub fn should_render(&self) -> bool {
+        !matches!(self, Self::Skip(_))
+    }
+}
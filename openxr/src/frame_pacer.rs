@@ -0,0 +1,236 @@
+use std::{
+    collections::VecDeque,
+    time::{self, Instant},
+};
+
+use crate::*;
+
+/// Suggests when to start CPU work for each frame, to finish it shortly before the compositor
+/// needs it
+///
+/// [`FrameWaiter::wait`] returns as soon as the runtime is ready for a new frame, which is often
+/// well before the work must start. Work sampled early, such as input and poses, is then staler
+/// than necessary when displayed, while starting too late misses the compositor's deadline.
+/// `FramePacer` records how long recent waits blocked, i.e. how much time each frame had to
+/// spare, and recommends delaying the next frame's work by the smallest of those minus a safety
+/// margin.
+///
+/// Call [`FramePacer::wait`] in place of [`FrameWaiter::wait`], then
+/// [`FramePacer::sleep_until_start`] before sampling input and rendering.
+#[derive(Debug)]
+pub struct FramePacer {
+    capacity: usize,
+    margin: time::Duration,
+    /// Time to spare in recent frames, had they started as soon as their wait returned
+    slack: VecDeque<time::Duration>,
+    period: time::Duration,
+    woke: Option<Instant>,
+    /// Delay actually applied to the current frame by `sleep_until_start`
+    applied: time::Duration,
+    timer: Option<Timer>,
+}
+
+impl FramePacer {
+    /// Create a pacer considering the last `capacity` frames and leaving `margin` to spare
+    pub fn new(capacity: usize, margin: time::Duration) -> Self {
+        assert!(capacity > 0, "capacity must be nonzero");
+        Self {
+            capacity,
+            margin,
+            slack: VecDeque::with_capacity(capacity),
+            period: time::Duration::ZERO,
+            woke: None,
+            applied: time::Duration::ZERO,
+            timer: None,
+        }
+    }
+
+    /// Use a high-resolution OS timer in [`FramePacer::sleep_until_start`] where the default
+    /// sleep is too coarse for frame pacing
+    ///
+    /// Windows' default timer resolution is around 15ms. Other platforms already sleep with
+    /// high resolution, so this has no effect there.
+    pub fn with_high_resolution_timer(mut self) -> Self {
+        self.timer = Timer::new();
+        self
+    }
+
+    /// Wait for the next frame with `waiter`, recording how long that took
+    pub fn wait(&mut self, waiter: &mut FrameWaiter) -> Result<FrameState> {
+        let start = Instant::now();
+        let state = waiter.wait()?;
+        self.record_wait(start.elapsed(), state.predicted_display_period);
+        Ok(state)
+    }
+
+    /// Record a wait performed by other means, e.g. by [`FrameLoop::begin`]
+    ///
+    /// Must be called as soon as the wait returns.
+    pub fn record_wait(&mut self, waited: time::Duration, predicted_display_period: Duration) {
+        if self.slack.len() == self.capacity {
+            self.slack.pop_front();
+        }
+        // Delaying the previous frame's work shortened this wait by as much
+        self.slack.push_back(waited + self.applied);
+        self.period = predicted_display_period.into();
+        self.woke = Some(Instant::now());
+        self.applied = time::Duration::ZERO;
+    }
+
+    /// How long to wait after the latest wait returned before starting the frame's CPU work
+    ///
+    /// Zero until a wait has been recorded, and never more than the display period minus the
+    /// margin.
+    pub fn start_delay(&self) -> time::Duration {
+        let slack = match self.slack.iter().min() {
+            Some(&x) => x,
+            None => return time::Duration::ZERO,
+        };
+        slack
+            .saturating_sub(self.margin)
+            .min(self.period.saturating_sub(self.margin))
+    }
+
+    /// When to start the CPU work of the frame the latest wait returned, or `None` if no wait
+    /// has been recorded
+    pub fn target_start(&self) -> Option<Instant> {
+        Some(self.woke? + self.start_delay())
+    }
+
+    /// Block until [`FramePacer::target_start`]
+    pub fn sleep_until_start(&mut self) {
+        let (woke, target) = match (self.woke, self.target_start()) {
+            (Some(woke), Some(target)) => (woke, target),
+            _ => return,
+        };
+        let now = Instant::now();
+        if target > now {
+            match self.timer {
+                Some(ref timer) => timer.sleep(target - now),
+                None => std::thread::sleep(target - now),
+            }
+        }
+        self.applied = Instant::now().saturating_duration_since(woke);
+    }
+
+    /// Forget recorded waits, e.g. after the application's workload changed abruptly
+    pub fn reset(&mut self) {
+        self.slack.clear();
+        self.woke = None;
+        self.applied = time::Duration::ZERO;
+    }
+}
+
+impl Default for FramePacer {
+    /// Consider the last 30 frames and leave 2ms to spare
+    fn default() -> Self {
+        Self::new(30, time::Duration::from_millis(2))
+    }
+}
+
+/// A high-resolution waitable timer
+#[cfg(windows)]
+#[derive(Debug)]
+struct Timer(win32::Handle);
+
+#[cfg(windows)]
+unsafe impl Send for Timer {}
+#[cfg(windows)]
+unsafe impl Sync for Timer {}
+
+#[cfg(windows)]
+impl Timer {
+    fn new() -> Option<Self> {
+        let handle = unsafe {
+            win32::CreateWaitableTimerExW(
+                std::ptr::null_mut(),
+                std::ptr::null(),
+                win32::CREATE_WAITABLE_TIMER_HIGH_RESOLUTION,
+                win32::TIMER_ALL_ACCESS,
+            )
+        };
+        // High resolution timers require Windows 10 1803 or later
+        if handle.is_null() {
+            None
+        } else {
+            Some(Self(handle))
+        }
+    }
+
+    fn sleep(&self, duration: time::Duration) {
+        // Negative due times are relative, in 100ns units
+        let due = -((duration.as_nanos() / 100) as i64);
+        unsafe {
+            if win32::SetWaitableTimer(
+                self.0,
+                &due,
+                0,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                0,
+            ) == 0
+            {
+                std::thread::sleep(duration);
+                return;
+            }
+            win32::WaitForSingleObject(self.0, win32::INFINITE);
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Drop for Timer {
+    fn drop(&mut self) {
+        unsafe {
+            win32::CloseHandle(self.0);
+        }
+    }
+}
+
+#[cfg(windows)]
+#[allow(non_snake_case)]
+mod win32 {
+    use std::os::raw::c_void;
+
+    pub type Handle = *mut c_void;
+
+    pub const CREATE_WAITABLE_TIMER_HIGH_RESOLUTION: u32 = 0x0000_0002;
+    pub const TIMER_ALL_ACCESS: u32 = 0x001F_0003;
+    pub const INFINITE: u32 = 0xFFFF_FFFF;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn CreateWaitableTimerExW(
+            timer_attributes: *mut c_void,
+            timer_name: *const u16,
+            flags: u32,
+            desired_access: u32,
+        ) -> Handle;
+        pub fn SetWaitableTimer(
+            timer: Handle,
+            due_time: *const i64,
+            period: i32,
+            completion_routine: *mut c_void,
+            arg_to_completion_routine: *mut c_void,
+            resume: i32,
+        ) -> i32;
+        pub fn WaitForSingleObject(handle: Handle, milliseconds: u32) -> u32;
+        pub fn CloseHandle(object: Handle) -> i32;
+    }
+}
+
+/// Other platforms' sleep is already high-resolution
+#[cfg(not(windows))]
+#[derive(Debug)]
+struct Timer;
+
+#[cfg(not(windows))]
+impl Timer {
+    fn new() -> Option<Self> {
+        None
+    }
+
+    fn sleep(&self, duration: time::Duration) {
+        std::thread::sleep(duration);
+    }
+}
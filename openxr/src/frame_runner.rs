@@ -0,0 +1,239 @@
+use std::{thread, time};
+
+use crate::*;
+
+/// Runs a session's event handling and frame cycle, calling into the application only to render
+///
+/// Wraps a [`FrameLoop`] and a [`SessionLifecycle`] together with the steps nearly every
+/// application takes around them: beginning and ending the session as the runtime asks, locating
+/// the views, acquiring, waiting on and releasing a swapchain image, and submitting a projection
+/// layer that shows each view's region of that image. Applications needing more control, e.g.
+/// over the submitted layers, can use those pieces directly instead.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn dummy<G: openxr::Graphics>(
+/// #     mut runner: openxr::FrameRunner<G>,
+/// #     images: &mut openxr::SwapchainImages<G, ()>,
+/// #     layout: &openxr::SwapchainLayout,
+/// #     stage: &openxr::Space,
+/// # ) {
+/// while runner.poll_events(|_| {}).unwrap() {
+///     let status = runner
+///         .frame(images, layout, stage, |cx| {
+///             // draw cx.views into cx.regions of cx.image...
+///             Ok(())
+///         })
+///         .unwrap();
+///     if status == openxr::FrameStatus::Idle {
+///         runner.idle();
+///     }
+/// }
+/// # }
+/// ```
+pub struct FrameRunner<G: Graphics> {
+    session: Session<G>,
+    frame_loop: FrameLoop<G>,
+    lifecycle: SessionLifecycle,
+    view_configuration_type: ViewConfigurationType,
+    environment_blend_mode: EnvironmentBlendMode,
+    wait_policy: SwapchainWaitPolicy,
+    events: EventDataBuffer,
+    running: bool,
+    exiting: bool,
+}
+
+impl<G: Graphics> FrameRunner<G> {
+    /// Take over the values returned by [`Instance::create_session`]
+    pub fn new(
+        (session, waiter, stream): (Session<G>, FrameWaiter, FrameStream<G>),
+        view_configuration_type: ViewConfigurationType,
+        environment_blend_mode: EnvironmentBlendMode,
+    ) -> Self {
+        Self {
+            session,
+            frame_loop: FrameLoop::new(waiter, stream),
+            lifecycle: SessionLifecycle::default(),
+            view_configuration_type,
+            environment_blend_mode,
+            wait_policy: SwapchainWaitPolicy::default(),
+            events: EventDataBuffer::new(),
+            running: false,
+            exiting: false,
+        }
+    }
+
+    /// Throttle rendering and haptics while unfocused according to `policy`
+    pub fn background_policy(mut self, policy: BackgroundPolicy) -> Self {
+        self.lifecycle.set_policy(policy);
+        self
+    }
+
+    /// Wait for swapchain images according to `policy`
+    pub fn wait_policy(mut self, policy: SwapchainWaitPolicy) -> Self {
+        self.wait_policy = policy;
+        self
+    }
+
+    #[inline]
+    pub fn session(&self) -> &Session<G> {
+        &self.session
+    }
+
+    #[inline]
+    pub fn lifecycle(&self) -> &SessionLifecycle {
+        &self.lifecycle
+    }
+
+    /// Whether the session has begun and not yet ended, i.e. whether frames are being run
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Handle every pending event, passing each to `on_event` afterwards
+    ///
+    /// Begins the session when it becomes `READY` and ends it when `STOPPING`. Returns `false`
+    /// once the application should exit, i.e. after the session enters `EXITING` or
+    /// `LOSS_PENDING`, or the instance is about to be lost.
+    pub fn poll_events(&mut self, mut on_event: impl FnMut(&Event<'_>)) -> Result<bool> {
+        let instance = self.session.instance();
+        while let Some(event) = instance.poll_event(&mut self.events)? {
+            match event {
+                Event::SessionStateChanged(e) if e.session() == self.session.as_raw() => {
+                    self.lifecycle.handle_event(&event);
+                    match e.state() {
+                        SessionState::READY => {
+                            self.session.begin(self.view_configuration_type)?;
+                            self.running = true;
+                        }
+                        SessionState::STOPPING => {
+                            self.session.end()?;
+                            self.running = false;
+                        }
+                        SessionState::EXITING | SessionState::LOSS_PENDING => {
+                            self.exiting = true;
+                        }
+                        _ => {}
+                    }
+                }
+                Event::InstanceLossPending(_) => {
+                    self.exiting = true;
+                }
+                _ => {}
+            }
+            on_event(&event);
+        }
+        Ok(!self.exiting)
+    }
+
+    /// Run one frame, calling `render` to draw it into the next image of `images` unless the
+    /// runtime or the background policy skips it
+    ///
+    /// `layout` describes where each view is drawn in the swapchain, and the submitted projection
+    /// layer's views are located in `space`. The image is released after `render` returns, so
+    /// `render` must have submitted its work for the image to the GPU by then.
+    ///
+    /// If an error is returned after the frame began, the frame is left open; the runtime
+    /// discards it when the next frame begins.
+    pub fn frame<T, F>(
+        &mut self,
+        images: &mut SwapchainImages<G, T>,
+        layout: &SwapchainLayout,
+        space: &Space,
+        render: F,
+    ) -> Result<FrameStatus>
+    where
+        F: FnOnce(FrameContext<'_, G, T>) -> Result<()>,
+    {
+        if !self.running {
+            return Ok(FrameStatus::Idle);
+        }
+        let decision = self.lifecycle.throttle(self.frame_loop.begin()?);
+        let state = match decision {
+            FrameDecision::Skip(state) => {
+                self.frame_loop.skip(&state, self.environment_blend_mode)?;
+                return Ok(FrameStatus::Skipped);
+            }
+            FrameDecision::Render(state) | FrameDecision::RenderAfterDiscard(state) => state,
+        };
+
+        let (view_state_flags, views) = self.session.locate_views(
+            self.view_configuration_type,
+            state.predicted_display_time,
+            space,
+        )?;
+        let index = images.acquire()?;
+        images.wait_with_policy(&self.wait_policy)?;
+        let (image, data) = images.get_mut(index);
+        render(FrameContext {
+            state: &state,
+            view_state_flags,
+            views: &views,
+            regions: layout.views(),
+            image_index: index,
+            image,
+            data,
+        })?;
+        images.release()?;
+
+        let projection_views = views
+            .iter()
+            .zip(layout.views())
+            .map(|(view, region)| {
+                CompositionLayerProjectionView::new()
+                    .pose(view.pose)
+                    .fov(view.fov)
+                    .sub_image(region.sub_image(images.swapchain()))
+            })
+            .collect::<Vec<_>>();
+        let layer = CompositionLayerProjection::new()
+            .space(space)
+            .views(&projection_views);
+        self.frame_loop
+            .end(&state, self.environment_blend_mode, &[&layer])?;
+        Ok(FrameStatus::Rendered)
+    }
+
+    /// Sleep briefly, for calling between [`FrameStatus::Idle`] frames instead of spinning
+    pub fn idle(&self) {
+        thread::sleep(time::Duration::from_millis(10));
+    }
+
+    /// Ask the runtime to stop the session, after which [`FrameRunner::poll_events`] eventually
+    /// returns `false`
+    pub fn request_exit(&self) -> Result<()> {
+        self.session.request_exit()?;
+        Ok(())
+    }
+
+    pub fn into_parts(self) -> (Session<G>, FrameLoop<G>) {
+        (self.session, self.frame_loop)
+    }
+}
+
+/// What a frame rendered by [`FrameRunner::frame`] should show
+pub struct FrameContext<'a, G: Graphics, T> {
+    pub state: &'a FrameState,
+    pub view_state_flags: ViewStateFlags,
+    /// Where each view is located at the frame's predicted display time
+    pub views: &'a [View],
+    /// Where each view is to be drawn in the image
+    pub regions: &'a [ViewRegion],
+    pub image_index: u32,
+    pub image: &'a G::SwapchainImage,
+    /// The application's data for this image, from [`SwapchainImages`]
+    pub data: &'a mut T,
+}
+
+/// Outcome of [`FrameRunner::frame`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FrameStatus {
+    /// The session isn't running, so no frame was begun
+    Idle,
+    /// The frame was ended without being rendered
+    Skipped,
+    /// The frame was rendered and submitted
+    Rendered,
+}
@@ -1,4 +1,4 @@
-use std::ptr;
+use std::{os::raw::c_void, ptr};
 
 use crate::*;
 
@@ -8,6 +8,10 @@ use crate::*;
 /// operations. These are separated so that `&mut self` receivers can be used to statically
 /// guarantee that calls are synchronized as required by OpenXR, enabling a safe interface.
 ///
+/// `FrameStream` is `Send` and `Sync` for every `G`, so frames may be begun and ended on a
+/// render thread while the [`FrameWaiter`] is used on another, as OpenXR permits, without
+/// further locking.
+///
 /// # Example
 ///
 /// A typical presentation loop body should look roughly as follows:
@@ -23,7 +27,9 @@ use crate::*;
 /// # ) {
 /// let state = frame_waiter.wait().unwrap();
 /// let image = swapchain.acquire_image().unwrap();
-/// swapchain.wait_image(openxr::Duration::INFINITE).unwrap();
+/// swapchain
+///     .wait_image_with_policy(&openxr::SwapchainWaitPolicy::default())
+///     .unwrap();
 ///
 /// frame_stream.begin().unwrap();
 ///
@@ -88,25 +94,31 @@ impl<G: Graphics> FrameStream<G> {
     }
 
     /// Indicate that graphics device work is beginning
+    ///
+    /// Returns `FRAME_DISCARDED` if the previous frame was never ended, or `SESSION_LOSS_PENDING`
+    /// if the session is about to be lost.
     #[inline]
-    pub fn begin(&mut self) -> Result<()> {
-        unsafe {
-            cvt((self.fp().begin_frame)(self.session.as_raw(), ptr::null()))?;
-        }
-        Ok(())
+    pub fn begin(&mut self) -> Result<sys::Result> {
+        let status = unsafe { cvt((self.fp().begin_frame)(self.session.as_raw(), ptr::null()))? };
+        frame_timing::record_begin(&self.session.inner.frame_timings, status);
+        Ok(status)
     }
 
     /// Indicate that all graphics work for the frame has been submitted
     ///
     /// `layers` is an array of references to any type of composition layer,
-    /// e.g. `CompositionLayerProjection`.
+    /// e.g. `CompositionLayerProjection`. Layers are composited in order, so backgrounds such as
+    /// a `CompositionLayerCubeKHR` skybox, whose swapchain must be created with a `face_count` of
+    /// 6, or a `CompositionLayerEquirect2KHR` 360 degree image come first.
+    ///
+    /// Returns `SESSION_LOSS_PENDING` if the session is about to be lost.
     #[inline]
     pub fn end(
         &mut self,
         display_time: Time,
         environment_blend_mode: EnvironmentBlendMode,
         layers: &[&CompositionLayerBase<'_, G>],
-    ) -> Result<()> {
+    ) -> Result<sys::Result> {
         assert!(layers.len() <= u32::max_value() as usize);
         let info = sys::FrameEndInfo {
             ty: sys::FrameEndInfo::TYPE,
@@ -116,10 +128,23 @@ impl<G: Graphics> FrameStream<G> {
             layer_count: layers.len() as u32,
             layers: layers.as_ptr() as _,
         };
-        unsafe {
-            cvt((self.fp().end_frame)(self.session.as_raw(), &info))?;
-        }
-        Ok(())
+        let status = unsafe { cvt((self.fp().end_frame)(self.session.as_raw(), &info))? };
+        frame_timing::record_end(&self.session.inner.frame_timings, layers.len());
+        Ok(status)
+    }
+
+    /// Indicate that all graphics work for the frame has been submitted, with owned layers
+    ///
+    /// Like [`FrameStream::end`], for layer stacks collected at run time as [`CompositionLayer`]s.
+    #[inline]
+    pub fn end_layers(
+        &mut self,
+        display_time: Time,
+        environment_blend_mode: EnvironmentBlendMode,
+        layers: &[CompositionLayer<'_, G>],
+    ) -> Result<sys::Result> {
+        let layers = layers.iter().map(|x| &**x).collect::<Vec<_>>();
+        self.end(display_time, environment_blend_mode, &layers)
     }
 
     /// Indicate that all graphics work for the frame has been submitted
@@ -138,35 +163,72 @@ impl<G: Graphics> FrameStream<G> {
         environment_blend_mode: EnvironmentBlendMode,
         layers: &[&CompositionLayerBase<'_, G>],
         secondary_info: SecondaryEndInfo<'_, '_, '_, G>,
-    ) -> Result<()> {
+    ) -> Result<sys::Result> {
+        self.end_with(
+            display_time,
+            environment_blend_mode,
+            layers,
+            FrameEndOptions::new().secondary(secondary_info),
+        )
+    }
+
+    /// Indicate that all graphics work for the frame has been submitted, with extension
+    /// structures chained onto `XrFrameEndInfo`
+    ///
+    /// `options` selects the frame-end extensions to use. Per-layer settings belong in the layers'
+    /// own chains instead, through e.g. [`CompositionLayerProjection::push_next`].
+    pub fn end_with(
+        &mut self,
+        display_time: Time,
+        environment_blend_mode: EnvironmentBlendMode,
+        layers: &[&CompositionLayerBase<'_, G>],
+        options: FrameEndOptions<'_, '_, '_, G>,
+    ) -> Result<sys::Result> {
         assert!(layers.len() <= u32::max_value() as usize);
-        assert!(secondary_info.layers.len() <= u32::max_value() as usize);
-        let single_secondary_info = [sys::SecondaryViewConfigurationLayerInfoMSFT {
-            ty: sys::SecondaryViewConfigurationLayerInfoMSFT::TYPE,
-            next: ptr::null(),
-            view_configuration_type: secondary_info.ty,
-            environment_blend_mode: secondary_info.environment_blend_mode,
-            layer_count: secondary_info.layers.len() as u32,
-            layers: secondary_info.layers.as_ptr() as *const _,
-        }];
-        let secondary_info = sys::SecondaryViewConfigurationFrameEndInfoMSFT {
-            ty: sys::SecondaryViewConfigurationFrameEndInfoMSFT::TYPE,
-            next: ptr::null(),
-            view_configuration_count: 1,
-            view_configuration_layers_info: single_secondary_info.as_ptr() as *const _,
-        };
+        let mut next = options.next;
+
+        let local_dimming;
+        if let Some(mode) = options.local_dimming {
+            local_dimming = sys::LocalDimmingFrameEndInfoMETA {
+                ty: sys::LocalDimmingFrameEndInfoMETA::TYPE,
+                next,
+                local_dimming_mode: mode,
+            };
+            next = &local_dimming as *const _ as *const _;
+        }
+
+        let single_secondary_info;
+        let secondary_info;
+        if let Some(info) = options.secondary {
+            assert!(info.layers.len() <= u32::max_value() as usize);
+            single_secondary_info = [sys::SecondaryViewConfigurationLayerInfoMSFT {
+                ty: sys::SecondaryViewConfigurationLayerInfoMSFT::TYPE,
+                next: ptr::null(),
+                view_configuration_type: info.ty,
+                environment_blend_mode: info.environment_blend_mode,
+                layer_count: info.layers.len() as u32,
+                layers: info.layers.as_ptr() as *const _,
+            }];
+            secondary_info = sys::SecondaryViewConfigurationFrameEndInfoMSFT {
+                ty: sys::SecondaryViewConfigurationFrameEndInfoMSFT::TYPE,
+                next,
+                view_configuration_count: 1,
+                view_configuration_layers_info: single_secondary_info.as_ptr() as *const _,
+            };
+            next = &secondary_info as *const _ as *const _;
+        }
+
         let info = sys::FrameEndInfo {
             ty: sys::FrameEndInfo::TYPE,
-            next: &secondary_info as *const _ as *const _,
+            next,
             display_time,
             environment_blend_mode,
             layer_count: layers.len() as u32,
             layers: layers.as_ptr() as _,
         };
-        unsafe {
-            cvt((self.fp().end_frame)(self.session.as_raw(), &info))?;
-        }
-        Ok(())
+        let status = unsafe { cvt((self.fp().end_frame)(self.session.as_raw(), &info))? };
+        frame_timing::record_end(&self.session.inner.frame_timings, layers.len());
+        Ok(status)
     }
 
     // Private helper
@@ -175,3 +237,58 @@ impl<G: Graphics> FrameStream<G> {
         self.session.instance().fp()
     }
 }
+
+/// Optional extension structures for [`FrameStream::end_with`]
+pub struct FrameEndOptions<'a, 'b, 'c, G: Graphics> {
+    secondary: Option<SecondaryEndInfo<'a, 'b, 'c, G>>,
+    local_dimming: Option<LocalDimmingModeMETA>,
+    next: *const c_void,
+}
+
+impl<'a, 'b, 'c, G: Graphics> FrameEndOptions<'a, 'b, 'c, G> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            secondary: None,
+            local_dimming: None,
+            next: ptr::null(),
+        }
+    }
+
+    /// Submit layers for a secondary view configuration
+    ///
+    /// `XR_MSFT_secondary_view_configuration` must be loaded and the session must have been
+    /// started with `begin_secondary`.
+    #[inline]
+    pub fn secondary(mut self, info: SecondaryEndInfo<'a, 'b, 'c, G>) -> Self {
+        self.secondary = Some(info);
+        self
+    }
+
+    /// Request local dimming for this frame
+    ///
+    /// Requires `XR_META_local_dimming`.
+    #[inline]
+    pub fn local_dimming_meta(mut self, mode: LocalDimmingModeMETA) -> Self {
+        self.local_dimming = Some(mode);
+        self
+    }
+
+    /// Chain raw extension structures for which no dedicated option exists yet
+    ///
+    /// # Safety
+    ///
+    /// `next` must be null or point to a valid chain of OpenXR structures accepted in the chain
+    /// of `XrFrameEndInfo`, which remains valid until the frame is ended.
+    #[inline]
+    pub unsafe fn next(mut self, next: *const c_void) -> Self {
+        self.next = next;
+        self
+    }
+}
+
+impl<'a, 'b, 'c, G: Graphics> Default for FrameEndOptions<'a, 'b, 'c, G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,232 @@
+//! Frame timing statistics, e.g. for performance overlays.
+//!
+//! A [`FrameTiming`] is recorded into by the session's [`FrameWaiter`] and [`FrameStream`] for as
+//! long as it's alive, so timing can be collected without changing the frame loop. OpenXR doesn't
+//! report when frames were actually displayed; missed display periods are instead inferred from
+//! gaps between consecutive predicted display times, which advance by more than one period when
+//! the runtime had to skip a refresh.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard, Weak},
+    time::{self, Instant},
+};
+
+use crate::*;
+
+/// Timing of recent frames of a session
+///
+/// Clones share the same record. Recording stops once every clone has been dropped.
+#[derive(Clone)]
+pub struct FrameTiming {
+    inner: Arc<Mutex<FrameTimingRecorder>>,
+}
+
+impl FrameTiming {
+    /// Record the timing of each frame of `session`, keeping the latest `capacity` frames
+    pub fn new<G>(session: &Session<G>, capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "frame timing needs room for at least one frame"
+        );
+        let inner = Arc::new(Mutex::new(FrameTimingRecorder {
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+            pending: None,
+            last_predicted_display_time: None,
+            totals: FrameTotals::default(),
+        }));
+        session
+            .inner
+            .frame_timings
+            .lock()
+            .unwrap()
+            .push(Arc::downgrade(&inner));
+        Self { inner }
+    }
+
+    /// Recorded frames, from oldest to newest
+    pub fn samples(&self) -> Vec<FrameTimingSample> {
+        self.lock().samples.iter().copied().collect()
+    }
+
+    /// The most recently ended frame
+    pub fn latest(&self) -> Option<FrameTimingSample> {
+        self.lock().samples.back().copied()
+    }
+
+    /// Summarize the recorded frames
+    pub fn stats(&self) -> FrameTimingStats {
+        let recorder = self.lock();
+        let samples = &recorder.samples;
+        let mean = |f: fn(&FrameTimingSample) -> time::Duration| {
+            if samples.is_empty() {
+                time::Duration::ZERO
+            } else {
+                samples.iter().map(f).sum::<time::Duration>() / samples.len() as u32
+            }
+        };
+        let max = |f: fn(&FrameTimingSample) -> time::Duration| {
+            samples.iter().map(f).max().unwrap_or_default()
+        };
+        let interval = match (samples.front(), samples.back()) {
+            (Some(first), Some(last)) if samples.len() > 1 => {
+                let nanos = (last.predicted_display_time - first.predicted_display_time).as_nanos();
+                time::Duration::from_nanos(nanos.max(0) as u64) / (samples.len() - 1) as u32
+            }
+            _ => time::Duration::ZERO,
+        };
+        FrameTimingStats {
+            frames: recorder.totals.frames,
+            dropped_frames: recorder.totals.dropped_frames,
+            discarded_frames: recorder.totals.discarded_frames,
+            mean_wait: mean(|x| x.wait),
+            max_wait: max(|x| x.wait),
+            mean_cpu: mean(|x| x.cpu),
+            max_cpu: max(|x| x.cpu),
+            mean_display_interval: interval,
+            predicted_display_period: samples.back().map(|x| x.predicted_display_period),
+        }
+    }
+
+    /// Forget all recorded frames and reset the totals
+    pub fn reset(&self) {
+        let mut recorder = self.lock();
+        recorder.samples.clear();
+        recorder.pending = None;
+        recorder.last_predicted_display_time = None;
+        recorder.totals = FrameTotals::default();
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity
+    }
+
+    fn lock(&self) -> MutexGuard<'_, FrameTimingRecorder> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Timing of a single frame, recorded by [`FrameTiming`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FrameTimingSample {
+    pub predicted_display_time: Time,
+    pub predicted_display_period: Duration,
+    /// Time spent blocked waiting for the frame
+    pub wait: time::Duration,
+    /// Time from the end of the wait until the frame was ended
+    pub cpu: time::Duration,
+    /// Display periods skipped between the previous frame and this one
+    pub missed_periods: u32,
+    /// Whether any layers were submitted
+    pub rendered: bool,
+}
+
+/// A summary of the frames recorded by a [`FrameTiming`]
+///
+/// Counts cover every frame since the `FrameTiming` was created or reset; durations cover the
+/// frames still recorded.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FrameTimingStats {
+    /// Number of frames ended
+    pub frames: u64,
+    /// Number of display periods for which no new frame was ready
+    pub dropped_frames: u64,
+    /// Number of frames begun without the previous frame being ended
+    pub discarded_frames: u64,
+    pub mean_wait: time::Duration,
+    pub max_wait: time::Duration,
+    pub mean_cpu: time::Duration,
+    pub max_cpu: time::Duration,
+    /// Mean interval between the predicted display times of consecutive frames
+    pub mean_display_interval: time::Duration,
+    /// The predicted display period of the latest frame
+    pub predicted_display_period: Option<Duration>,
+}
+
+pub(crate) struct FrameTimingRecorder {
+    capacity: usize,
+    samples: VecDeque<FrameTimingSample>,
+    /// The frame waited for but not yet ended, and when its wait returned
+    pending: Option<(FrameTimingSample, Instant)>,
+    last_predicted_display_time: Option<Time>,
+    totals: FrameTotals,
+}
+
+#[derive(Default)]
+struct FrameTotals {
+    frames: u64,
+    dropped_frames: u64,
+    discarded_frames: u64,
+}
+
+impl FrameTimingRecorder {
+    fn wait(&mut self, wait: time::Duration, state: &FrameState) {
+        let period = state.predicted_display_period.as_nanos().max(1);
+        let missed_periods = match self.last_predicted_display_time {
+            Some(last) => {
+                let periods =
+                    ((state.predicted_display_time - last).as_nanos() + period / 2) / period;
+                (periods - 1).clamp(0, i64::from(u32::MAX)) as u32
+            }
+            None => 0,
+        };
+        self.last_predicted_display_time = Some(state.predicted_display_time);
+        let sample = FrameTimingSample {
+            predicted_display_time: state.predicted_display_time,
+            predicted_display_period: state.predicted_display_period,
+            wait,
+            cpu: time::Duration::ZERO,
+            missed_periods,
+            rendered: false,
+        };
+        self.pending = Some((sample, Instant::now()));
+    }
+
+    fn begin(&mut self, status: sys::Result) {
+        if status == sys::Result::FRAME_DISCARDED {
+            self.totals.discarded_frames += 1;
+        }
+    }
+
+    fn end(&mut self, layer_count: usize) {
+        let (mut sample, waited) = match self.pending.take() {
+            Some(x) => x,
+            None => return,
+        };
+        sample.cpu = waited.elapsed();
+        sample.rendered = layer_count > 0;
+        self.totals.frames += 1;
+        self.totals.dropped_frames += u64::from(sample.missed_periods);
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+}
+
+pub(crate) type FrameTimings = Mutex<Vec<Weak<Mutex<FrameTimingRecorder>>>>;
+
+fn record(timings: &FrameTimings, mut f: impl FnMut(&mut FrameTimingRecorder)) {
+    let mut timings = timings.lock().unwrap();
+    if timings.is_empty() {
+        return;
+    }
+    timings.retain(|x| x.strong_count() != 0);
+    for timing in timings.iter().filter_map(Weak::upgrade) {
+        f(&mut timing.lock().unwrap_or_else(|e| e.into_inner()));
+    }
+}
+
+pub(crate) fn record_wait(timings: &FrameTimings, wait: time::Duration, state: &FrameState) {
+    record(timings, |x| x.wait(wait, state));
+}
+
+pub(crate) fn record_begin(timings: &FrameTimings, status: sys::Result) {
+    record(timings, |x| x.begin(status));
+}
+
+pub(crate) fn record_end(timings: &FrameTimings, layer_count: usize) {
+    record(timings, |x| x.end(layer_count));
+}
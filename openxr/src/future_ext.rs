@@ -0,0 +1,145 @@
+//! Asynchronous operations via [`XR_EXT_future`]
+//!
+//! The extension must be [enabled by name](crate#extensions-without-extensionset-fields).
+//!
+//! Extensions built on it, such as `XR_ML_spatial_anchors`, wrap their futures in types that
+//! decode the completion result.
+//!
+//! [`XR_EXT_future`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_EXT_future
+
+use std::ptr;
+
+pub use sys::FutureStateEXT;
+
+use crate::*;
+
+/// An operation running in the background
+///
+/// Cancelled on drop unless it has been completed.
+///
+/// Requires [`XR_EXT_future`].
+///
+/// [`XR_EXT_future`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_EXT_future
+pub struct FutureEXT {
+    instance: Instance,
+    fp: FutureFnEXT,
+    handle: sys::FutureEXT,
+    completed: bool,
+}
+
+impl FutureEXT {
+    /// Take ownership of a future returned by an extension function
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid, incomplete future created from `instance`.
+    pub unsafe fn from_raw(instance: &Instance, handle: sys::FutureEXT) -> Result<Self> {
+        Ok(Self {
+            instance: instance.clone(),
+            fp: FutureFnEXT::load(instance)?,
+            handle,
+            completed: false,
+        })
+    }
+
+    #[inline]
+    pub fn as_raw(&self) -> sys::FutureEXT {
+        self.handle
+    }
+
+    /// Give up ownership of the handle without cancelling it
+    #[inline]
+    pub fn into_raw(mut self) -> sys::FutureEXT {
+        self.completed = true;
+        self.handle
+    }
+
+    /// Whether the result is available
+    pub fn poll(&self) -> Result<FutureStateEXT> {
+        let info = sys::FuturePollInfoEXT {
+            ty: sys::FuturePollInfoEXT::TYPE,
+            next: ptr::null(),
+            future: self.handle,
+        };
+        let mut out = sys::FuturePollResultEXT::out(ptr::null_mut());
+        unsafe {
+            cvt((self.fp.poll_future)(
+                self.instance.as_raw(),
+                &info,
+                out.as_mut_ptr(),
+            ))?;
+            Ok(out.assume_init().state)
+        }
+    }
+
+    /// Abandon the operation
+    pub fn cancel(mut self) -> Result<()> {
+        self.completed = true;
+        self.cancel_raw()
+    }
+
+    /// Pass the future to its completion function `complete` if it's ready, returning `None`
+    /// otherwise
+    ///
+    /// Must not be called again after returning a result.
+    pub(crate) fn complete_with<T>(
+        &mut self,
+        complete: impl FnOnce(sys::FutureEXT) -> Result<T>,
+    ) -> Result<Option<T>> {
+        assert!(!self.completed, "future already completed");
+        if self.poll()? != FutureStateEXT::READY {
+            return Ok(None);
+        }
+        let result = complete(self.handle)?;
+        self.completed = true;
+        Ok(Some(result))
+    }
+
+    fn cancel_raw(&self) -> Result<()> {
+        let info = sys::FutureCancelInfoEXT {
+            ty: sys::FutureCancelInfoEXT::TYPE,
+            next: ptr::null(),
+            future: self.handle,
+        };
+        unsafe {
+            cvt((self.fp.cancel_future)(self.instance.as_raw(), &info))?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for FutureEXT {
+    fn drop(&mut self) {
+        if !self.completed {
+            let _ = self.cancel_raw();
+        }
+    }
+}
+
+/// Function pointers for `XR_EXT_future`, which the generated bindings omit
+#[derive(Copy, Clone)]
+struct FutureFnEXT {
+    poll_future: pfn::PollFutureEXT,
+    cancel_future: pfn::CancelFutureEXT,
+}
+
+impl FutureFnEXT {
+    unsafe fn load(instance: &Instance) -> Result<Self> {
+        Ok(Self {
+            poll_future: load_ext_fn(instance, b"xrPollFutureEXT\0")?,
+            cancel_future: load_ext_fn(instance, b"xrCancelFutureEXT\0")?,
+        })
+    }
+}
+
+mod pfn {
+    use crate::sys;
+
+    pub type PollFutureEXT = unsafe extern "system" fn(
+        sys::Instance,
+        *const sys::FuturePollInfoEXT,
+        *mut sys::FuturePollResultEXT,
+    ) -> sys::Result;
+    pub type CancelFutureEXT =
+        unsafe extern "system" fn(sys::Instance, *const sys::FutureCancelInfoEXT) -> sys::Result;
+}
@@ -0,0 +1,125 @@
+//! Rumble for gamepads, through the same haptic actions used for tracked controllers.
+//!
+//! Gamepad profiles expose each motor as a separate haptic output on `/user/gamepad`, so unlike
+//! controllers, whose hands are selected by subaction path, every motor needs an action of its
+//! own. [`GamepadRumble`] creates and binds one per [`RumbleMotor`] and lets them be driven
+//! together.
+
+use crate::*;
+
+/// A rumble motor of a gamepad
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum RumbleMotor {
+    /// The low frequency motor in the left grip
+    Left,
+    /// The high frequency motor in the right grip
+    Right,
+    /// The motor behind the left trigger
+    LeftTrigger,
+    /// The motor behind the right trigger
+    RightTrigger,
+}
+
+impl RumbleMotor {
+    pub const ALL: [RumbleMotor; 4] = [
+        RumbleMotor::Left,
+        RumbleMotor::Right,
+        RumbleMotor::LeftTrigger,
+        RumbleMotor::RightTrigger,
+    ];
+
+    /// The motor's output in gamepad interaction profiles
+    pub fn binding_path(self) -> &'static str {
+        match self {
+            RumbleMotor::Left => "/user/gamepad/output/haptic_left",
+            RumbleMotor::Right => "/user/gamepad/output/haptic_right",
+            RumbleMotor::LeftTrigger => "/user/gamepad/output/haptic_left_trigger",
+            RumbleMotor::RightTrigger => "/user/gamepad/output/haptic_right_trigger",
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            RumbleMotor::Left => "left",
+            RumbleMotor::Right => "right",
+            RumbleMotor::LeftTrigger => "left_trigger",
+            RumbleMotor::RightTrigger => "right_trigger",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Haptic actions for each [`RumbleMotor`] of a gamepad
+pub struct GamepadRumble {
+    actions: [Action<Haptic>; 4],
+}
+
+impl GamepadRumble {
+    /// The gamepad interaction profile defined by the core specification
+    pub const INTERACTION_PROFILE: &'static str = "/interaction_profiles/microsoft/xbox_controller";
+
+    /// Create an action for each motor in `action_set`, named `name` followed by the motor, e.g.
+    /// `rumble_left`
+    pub fn new(action_set: &ActionSet, name: &str, localized_name: &str) -> Result<Self> {
+        let create = |motor: RumbleMotor| {
+            action_set.create_action::<Haptic>(
+                &format!("{}_{}", name, motor.name()),
+                &format!("{} ({})", localized_name, motor.name().replace('_', " ")),
+                &[],
+            )
+        };
+        Ok(Self {
+            actions: [
+                create(RumbleMotor::Left)?,
+                create(RumbleMotor::Right)?,
+                create(RumbleMotor::LeftTrigger)?,
+                create(RumbleMotor::RightTrigger)?,
+            ],
+        })
+    }
+
+    /// The action driving `motor`
+    #[inline]
+    pub fn action(&self, motor: RumbleMotor) -> &Action<Haptic> {
+        &self.actions[motor.index()]
+    }
+
+    /// Bindings of each action to its motor, to be suggested for
+    /// [`GamepadRumble::INTERACTION_PROFILE`] along with the application's other gamepad bindings
+    pub fn bindings(&self, instance: &Instance) -> Result<Vec<Binding<'_>>> {
+        RumbleMotor::ALL
+            .iter()
+            .map(|&motor| {
+                Ok(Binding::new(
+                    self.action(motor),
+                    instance.string_to_path(motor.binding_path())?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Play `event`, e.g. a [`HapticVibration`], on each of `motors`
+    pub fn apply<G>(
+        &self,
+        session: &Session<G>,
+        motors: &[RumbleMotor],
+        event: &HapticBase,
+    ) -> Result<()> {
+        for &motor in motors {
+            self.action(motor)
+                .apply_feedback(session, Path::NULL, event)?;
+        }
+        Ok(())
+    }
+
+    /// Stop any vibration on each of `motors`
+    pub fn stop<G>(&self, session: &Session<G>, motors: &[RumbleMotor]) -> Result<()> {
+        for &motor in motors {
+            self.action(motor).stop_feedback(session, Path::NULL)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+//! A single gaze ray for pointing, from whichever source is available.
+//!
+//! Eye gaze is exposed through `XR_EXT_eye_gaze_interaction` as a pose action, by
+//! `XR_FB_eye_tracking_social` as per-eye poses, or not at all, in which case head gaze is the
+//! usual substitute. [`GazeInput`] tries each in that order every time it's queried, so pointing
+//! keeps working as eye tracking gains or loses tracking, or is disabled by the user.
+
+use crate::*;
+
+/// Locates the user's gaze, preferring eye gaze interaction, then social eye tracking, then head
+/// gaze
+pub struct GazeInput {
+    eye_gaze: Option<Space>,
+    social: Option<EyeTrackerSocial>,
+    head: Space,
+}
+
+impl GazeInput {
+    /// Prepare to locate gaze within `session`
+    ///
+    /// `eye_gaze_action` should be an action bound to `/user/eyes_ext/input/gaze_ext/pose` in the
+    /// `/interaction_profiles/ext/eye_gaze_interaction` profile, if `XR_EXT_eye_gaze_interaction`
+    /// is enabled. A social eye tracker is created if `XR_FB_eye_tracking_social` is enabled and
+    /// [`AndroidPermission::EyeTracking`] has been granted.
+    pub fn new<G>(session: &Session<G>, eye_gaze_action: Option<&Action<Posef>>) -> Result<Self> {
+        let eye_gaze = match eye_gaze_action {
+            Some(action) => {
+                Some(action.create_space(session.clone(), Path::NULL, Posef::IDENTITY)?)
+            }
+            None => None,
+        };
+        let social = if session.instance().exts().fb_eye_tracking_social.is_some()
+            && AndroidPermission::EyeTracking.is_granted().unwrap_or(false)
+        {
+            Some(session.create_eye_tracker_social()?)
+        } else {
+            None
+        };
+        let head = session.create_reference_space(ReferenceSpaceType::VIEW, Posef::IDENTITY)?;
+        Ok(Self {
+            eye_gaze,
+            social,
+            head,
+        })
+    }
+
+    /// Locate the gaze ray in `base` at `time`
+    ///
+    /// Returns `None` if not even the head could be located, e.g. while tracking is lost.
+    pub fn gaze_ray(&self, base: &Space, time: Time) -> Result<Option<GazeRay>> {
+        if let Some(ref space) = self.eye_gaze {
+            // Locations of inactive actions, e.g. unbound ones, are never valid
+            if let Some(pose) = located_pose(&space.locate(base, time)?) {
+                return Ok(Some(GazeRay::from_pose(&pose, GazeSource::EyeGaze)));
+            }
+        }
+        if let Some(ref tracker) = self.social {
+            let gazes = tracker.get_eye_gazes(base, time)?;
+            match gazes.gaze {
+                [Some(ref left), Some(ref right)] => {
+                    let left = GazeRay::from_pose(&left.pose, GazeSource::EyeTrackingSocial);
+                    let right = GazeRay::from_pose(&right.pose, GazeSource::EyeTrackingSocial);
+                    return Ok(Some(GazeRay {
+                        origin: scale(&add(&left.origin, &right.origin), 0.5),
+                        direction: normalize(&add(&left.direction, &right.direction)),
+                        source: GazeSource::EyeTrackingSocial,
+                    }));
+                }
+                [Some(ref gaze), None] | [None, Some(ref gaze)] => {
+                    return Ok(Some(GazeRay::from_pose(
+                        &gaze.pose,
+                        GazeSource::EyeTrackingSocial,
+                    )));
+                }
+                [None, None] => {}
+            }
+        }
+        Ok(located_pose(&self.head.locate(base, time)?)
+            .map(|pose| GazeRay::from_pose(&pose, GazeSource::Head)))
+    }
+}
+
+/// Where a [`GazeRay`] came from
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GazeSource {
+    /// `XR_EXT_eye_gaze_interaction`
+    EyeGaze,
+    /// `XR_FB_eye_tracking_social`, combining both eyes when both are tracked
+    EyeTrackingSocial,
+    /// The forward direction of the `VIEW` reference space
+    Head,
+}
+
+/// A ray along the user's gaze
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GazeRay {
+    pub origin: Vector3f,
+    /// Unit length
+    pub direction: Vector3f,
+    pub source: GazeSource,
+}
+
+impl GazeRay {
+    /// The ray along the -Z axis of `pose`
+    fn from_pose(pose: &Posef, source: GazeSource) -> Self {
+        let q = &pose.orientation;
+        Self {
+            origin: pose.position,
+            direction: Vector3f {
+                x: -2.0 * (q.x * q.z + q.w * q.y),
+                y: -2.0 * (q.y * q.z - q.w * q.x),
+                z: -(1.0 - 2.0 * (q.x * q.x + q.y * q.y)),
+            },
+            source,
+        }
+    }
+}
+
+fn located_pose(location: &SpaceLocation) -> Option<Posef> {
+    let valid = SpaceLocationFlags::ORIENTATION_VALID | SpaceLocationFlags::POSITION_VALID;
+    if location.location_flags.contains(valid) {
+        Some(location.pose)
+    } else {
+        None
+    }
+}
+
+fn add(a: &Vector3f, b: &Vector3f) -> Vector3f {
+    Vector3f {
+        x: a.x + b.x,
+        y: a.y + b.y,
+        z: a.z + b.z,
+    }
+}
+
+fn scale(v: &Vector3f, s: f32) -> Vector3f {
+    Vector3f {
+        x: v.x * s,
+        y: v.y * s,
+        z: v.z * s,
+    }
+}
+
+fn normalize(v: &Vector3f) -> Vector3f {
+    let len = (v.x * v.x + v.y * v.y + v.z * v.z).sqrt();
+    if len > 0.0 {
+        scale(v, 1.0 / len)
+    } else {
+        *v
+    }
+}
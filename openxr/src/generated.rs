@@ -2,6 +2,7 @@
 #![allow(clippy::wrong_self_convention, clippy::transmute_ptr_to_ptr)]
 use crate::*;
 use std::borrow::Cow;
+use std::fmt;
 use std::mem::MaybeUninit;
 pub use sys::platform::{
     EGLenum, VkComponentSwizzle, VkFilter, VkSamplerAddressMode, VkSamplerMipmapMode,
@@ -16,9 +17,9 @@ pub use sys::{
     Extent2Df, Extent2Di, Extent3DfEXT, Extent3DfFB, ExternalCameraAttachedToDeviceOCULUS,
     ExternalCameraExtrinsicsOCULUS, ExternalCameraIntrinsicsOCULUS,
     ExternalCameraStatusFlagsOCULUS, EyeCalibrationStatusML, EyeExpressionHTC, EyePositionFB,
-    EyeVisibility, FaceConfidence2FB, FaceExpressionFB, FaceExpressionSet2FB, FacialTrackingTypeHTC,
-    ForceFeedbackCurlApplyLocationMNDX, ForceFeedbackCurlLocationMNDX, FormFactor,
-    FoveationConfigurationHTC, FoveationDynamicFB, FoveationDynamicFlagsHTC,
+    EyeVisibility, FaceConfidence2FB, FaceExpressionFB, FaceExpressionSet2FB,
+    FacialTrackingTypeHTC, ForceFeedbackCurlApplyLocationMNDX, ForceFeedbackCurlLocationMNDX,
+    FormFactor, FoveationConfigurationHTC, FoveationDynamicFB, FoveationDynamicFlagsHTC,
     FoveationEyeTrackedProfileCreateFlagsMETA, FoveationEyeTrackedStateFlagsMETA, FoveationLevelFB,
     FoveationLevelHTC, FoveationModeHTC, Fovf, FrameEndInfoFlagsML,
     GlobalDimmerFrameEndInfoFlagsML, HandEXT, HandForearmJointULTRALEAP, HandJointEXT,
@@ -1487,6 +1488,8 @@ pub struct InstanceExtensions {
     pub varjo_marker_tracking: Option<raw::MarkerTrackingVARJO>,
     pub varjo_view_offset: Option<raw::ViewOffsetVARJO>,
     pub yvr_controller_interaction: Option<raw::ControllerInteractionYVR>,
+    #[doc = r" Revisions of the enabled extensions"]
+    pub versions: ExtensionVersions,
 }
 impl InstanceExtensions {
     #[doc = r" Load extension function pointer tables"]
@@ -2208,10 +2211,681 @@ impl InstanceExtensions {
             } else {
                 None
             },
+            versions: entry
+                .enumerate_extension_properties()
+                .map(|x| ExtensionVersions::from_properties(&x, required))
+                .unwrap_or_default(),
         })
     }
 }
-#[derive(Copy, Clone)]
+#[doc = r" Spec versions reported by the runtime for enabled extensions"]
+#[doc = r""]
+#[doc = r" Fields are `None` for extensions that weren't enabled, or that the runtime itself doesn't"]
+#[doc = r" advertise (e.g. because they're provided by an API layer). All fields are `None` if the"]
+#[doc = r" runtime's extensions couldn't be enumerated."]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ExtensionVersions {
+    pub almalence_digital_lens_control: Option<u32>,
+    pub bd_controller_interaction: Option<u32>,
+    pub epic_view_configuration_fov: Option<u32>,
+    pub ext_performance_settings: Option<u32>,
+    pub ext_thermal_query: Option<u32>,
+    pub ext_debug_utils: Option<u32>,
+    pub ext_eye_gaze_interaction: Option<u32>,
+    pub ext_view_configuration_depth_range: Option<u32>,
+    pub ext_conformance_automation: Option<u32>,
+    pub ext_hand_tracking: Option<u32>,
+    #[cfg(windows)]
+    pub ext_win32_appcontainer_compatible: Option<u32>,
+    pub ext_dpad_binding: Option<u32>,
+    pub ext_hand_joints_motion_range: Option<u32>,
+    pub ext_samsung_odyssey_controller: Option<u32>,
+    pub ext_hp_mixed_reality_controller: Option<u32>,
+    pub ext_palm_pose: Option<u32>,
+    pub ext_uuid: Option<u32>,
+    pub ext_hand_interaction: Option<u32>,
+    pub ext_active_action_set_priority: Option<u32>,
+    pub ext_local_floor: Option<u32>,
+    pub ext_hand_tracking_data_source: Option<u32>,
+    pub ext_plane_detection: Option<u32>,
+    pub fb_composition_layer_image_layout: Option<u32>,
+    pub fb_composition_layer_alpha_blend: Option<u32>,
+    #[cfg(target_os = "android")]
+    pub fb_android_surface_swapchain_create: Option<u32>,
+    pub fb_swapchain_update_state: Option<u32>,
+    pub fb_composition_layer_secure_content: Option<u32>,
+    pub fb_body_tracking: Option<u32>,
+    pub meta_body_tracking_full_body: Option<u32>,
+    pub fb_display_refresh_rate: Option<u32>,
+    pub fb_color_space: Option<u32>,
+    pub fb_hand_tracking_mesh: Option<u32>,
+    pub fb_hand_tracking_aim: Option<u32>,
+    pub fb_hand_tracking_capsules: Option<u32>,
+    pub fb_spatial_entity: Option<u32>,
+    pub fb_foveation: Option<u32>,
+    pub fb_foveation_configuration: Option<u32>,
+    pub fb_keyboard_tracking: Option<u32>,
+    pub fb_triangle_mesh: Option<u32>,
+    pub fb_passthrough: Option<u32>,
+    pub fb_render_model: Option<u32>,
+    pub fb_spatial_entity_query: Option<u32>,
+    pub fb_spatial_entity_storage: Option<u32>,
+    pub fb_foveation_vulkan: Option<u32>,
+    #[cfg(target_os = "android")]
+    pub fb_swapchain_update_state_android_surface: Option<u32>,
+    pub fb_swapchain_update_state_opengl_es: Option<u32>,
+    pub fb_swapchain_update_state_vulkan: Option<u32>,
+    pub fb_touch_controller_pro: Option<u32>,
+    pub fb_spatial_entity_sharing: Option<u32>,
+    pub fb_space_warp: Option<u32>,
+    pub fb_haptic_amplitude_envelope: Option<u32>,
+    pub fb_scene: Option<u32>,
+    pub fb_scene_capture: Option<u32>,
+    pub fb_spatial_entity_container: Option<u32>,
+    pub fb_face_tracking2: Option<u32>,
+    pub fb_eye_tracking_social: Option<u32>,
+    pub fb_passthrough_keyboard_hands: Option<u32>,
+    pub fb_composition_layer_settings: Option<u32>,
+    pub fb_touch_controller_proximity: Option<u32>,
+    pub fb_haptic_pcm: Option<u32>,
+    pub fb_composition_layer_depth_test: Option<u32>,
+    pub fb_spatial_entity_storage_batch: Option<u32>,
+    pub fb_spatial_entity_user: Option<u32>,
+    pub htc_vive_cosmos_controller_interaction: Option<u32>,
+    pub htc_facial_tracking: Option<u32>,
+    pub htc_vive_focus3_controller_interaction: Option<u32>,
+    pub htc_hand_interaction: Option<u32>,
+    pub htc_vive_wrist_tracker_interaction: Option<u32>,
+    pub htc_passthrough: Option<u32>,
+    pub htc_foveation: Option<u32>,
+    pub huawei_controller_interaction: Option<u32>,
+    #[cfg(target_os = "android")]
+    pub khr_android_thread_settings: Option<u32>,
+    #[cfg(target_os = "android")]
+    pub khr_android_surface_swapchain: Option<u32>,
+    pub khr_composition_layer_cube: Option<u32>,
+    #[cfg(target_os = "android")]
+    pub khr_android_create_instance: Option<u32>,
+    pub khr_composition_layer_depth: Option<u32>,
+    pub khr_vulkan_swapchain_format_list: Option<u32>,
+    pub khr_composition_layer_cylinder: Option<u32>,
+    pub khr_composition_layer_equirect: Option<u32>,
+    pub khr_opengl_enable: Option<u32>,
+    pub khr_opengl_es_enable: Option<u32>,
+    pub khr_vulkan_enable: Option<u32>,
+    #[cfg(windows)]
+    pub khr_d3d11_enable: Option<u32>,
+    #[cfg(windows)]
+    pub khr_d3d12_enable: Option<u32>,
+    pub khr_visibility_mask: Option<u32>,
+    pub khr_composition_layer_color_scale_bias: Option<u32>,
+    #[cfg(windows)]
+    pub khr_win32_convert_performance_counter_time: Option<u32>,
+    pub khr_convert_timespec_time: Option<u32>,
+    pub khr_loader_init: Option<u32>,
+    #[cfg(target_os = "android")]
+    pub khr_loader_init_android: Option<u32>,
+    pub khr_vulkan_enable2: Option<u32>,
+    pub khr_composition_layer_equirect2: Option<u32>,
+    pub khr_binding_modification: Option<u32>,
+    pub khr_swapchain_usage_input_attachment_bit: Option<u32>,
+    pub meta_foveation_eye_tracked: Option<u32>,
+    pub meta_local_dimming: Option<u32>,
+    pub meta_passthrough_preferences: Option<u32>,
+    pub meta_virtual_keyboard: Option<u32>,
+    pub meta_vulkan_swapchain_create_info: Option<u32>,
+    pub meta_performance_metrics: Option<u32>,
+    pub meta_headset_id: Option<u32>,
+    pub meta_passthrough_color_lut: Option<u32>,
+    pub ml_ml2_controller_interaction: Option<u32>,
+    pub ml_frame_end_info: Option<u32>,
+    pub ml_global_dimmer: Option<u32>,
+    pub ml_compat: Option<u32>,
+    pub ml_user_calibration: Option<u32>,
+    pub mnd_headless: Option<u32>,
+    pub mnd_swapchain_usage_input_attachment_bit: Option<u32>,
+    pub msft_unbounded_reference_space: Option<u32>,
+    pub msft_spatial_anchor: Option<u32>,
+    pub msft_spatial_graph_bridge: Option<u32>,
+    pub msft_hand_interaction: Option<u32>,
+    pub msft_hand_tracking_mesh: Option<u32>,
+    pub msft_secondary_view_configuration: Option<u32>,
+    pub msft_first_person_observer: Option<u32>,
+    pub msft_controller_model: Option<u32>,
+    #[cfg(windows)]
+    pub msft_perception_anchor_interop: Option<u32>,
+    #[cfg(windows)]
+    pub msft_holographic_window_attachment: Option<u32>,
+    pub msft_composition_layer_reprojection: Option<u32>,
+    pub msft_spatial_anchor_persistence: Option<u32>,
+    #[cfg(target_os = "android")]
+    pub oculus_android_session_state_enable: Option<u32>,
+    pub oculus_audio_device_guid: Option<u32>,
+    pub oculus_external_camera: Option<u32>,
+    pub oppo_controller_interaction: Option<u32>,
+    pub qcom_tracking_optimization_settings: Option<u32>,
+    pub ultraleap_hand_tracking_forearm: Option<u32>,
+    pub valve_analog_threshold: Option<u32>,
+    pub varjo_quad_views: Option<u32>,
+    pub varjo_foveated_rendering: Option<u32>,
+    pub varjo_composition_layer_depth_test: Option<u32>,
+    pub varjo_environment_depth_estimation: Option<u32>,
+    pub varjo_marker_tracking: Option<u32>,
+    pub varjo_view_offset: Option<u32>,
+    pub yvr_controller_interaction: Option<u32>,
+}
+impl ExtensionVersions {
+    pub(crate) fn from_properties(
+        properties: &[sys::ExtensionProperties],
+        required: &ExtensionSet,
+    ) -> Self {
+        let mut out = Self::default();
+        for ext in properties {
+            match crate::fixed_str_bytes(&ext.extension_name) {
+                raw::DigitalLensControlALMALENCE::NAME
+                    if required.almalence_digital_lens_control =>
+                {
+                    out.almalence_digital_lens_control = Some(ext.extension_version);
+                }
+                raw::ControllerInteractionBD::NAME if required.bd_controller_interaction => {
+                    out.bd_controller_interaction = Some(ext.extension_version);
+                }
+                raw::ViewConfigurationFovEPIC::NAME if required.epic_view_configuration_fov => {
+                    out.epic_view_configuration_fov = Some(ext.extension_version);
+                }
+                raw::PerformanceSettingsEXT::NAME if required.ext_performance_settings => {
+                    out.ext_performance_settings = Some(ext.extension_version);
+                }
+                raw::ThermalQueryEXT::NAME if required.ext_thermal_query => {
+                    out.ext_thermal_query = Some(ext.extension_version);
+                }
+                raw::DebugUtilsEXT::NAME if required.ext_debug_utils => {
+                    out.ext_debug_utils = Some(ext.extension_version);
+                }
+                raw::EyeGazeInteractionEXT::NAME if required.ext_eye_gaze_interaction => {
+                    out.ext_eye_gaze_interaction = Some(ext.extension_version);
+                }
+                raw::ViewConfigurationDepthRangeEXT::NAME
+                    if required.ext_view_configuration_depth_range =>
+                {
+                    out.ext_view_configuration_depth_range = Some(ext.extension_version);
+                }
+                raw::ConformanceAutomationEXT::NAME if required.ext_conformance_automation => {
+                    out.ext_conformance_automation = Some(ext.extension_version);
+                }
+                raw::HandTrackingEXT::NAME if required.ext_hand_tracking => {
+                    out.ext_hand_tracking = Some(ext.extension_version);
+                }
+                #[cfg(windows)]
+                raw::Win32AppcontainerCompatibleEXT::NAME
+                    if required.ext_win32_appcontainer_compatible =>
+                {
+                    out.ext_win32_appcontainer_compatible = Some(ext.extension_version);
+                }
+                raw::DpadBindingEXT::NAME if required.ext_dpad_binding => {
+                    out.ext_dpad_binding = Some(ext.extension_version);
+                }
+                raw::HandJointsMotionRangeEXT::NAME if required.ext_hand_joints_motion_range => {
+                    out.ext_hand_joints_motion_range = Some(ext.extension_version);
+                }
+                raw::SamsungOdysseyControllerEXT::NAME
+                    if required.ext_samsung_odyssey_controller =>
+                {
+                    out.ext_samsung_odyssey_controller = Some(ext.extension_version);
+                }
+                raw::HpMixedRealityControllerEXT::NAME
+                    if required.ext_hp_mixed_reality_controller =>
+                {
+                    out.ext_hp_mixed_reality_controller = Some(ext.extension_version);
+                }
+                raw::PalmPoseEXT::NAME if required.ext_palm_pose => {
+                    out.ext_palm_pose = Some(ext.extension_version);
+                }
+                raw::UuidEXT::NAME if required.ext_uuid => {
+                    out.ext_uuid = Some(ext.extension_version);
+                }
+                raw::HandInteractionEXT::NAME if required.ext_hand_interaction => {
+                    out.ext_hand_interaction = Some(ext.extension_version);
+                }
+                raw::ActiveActionSetPriorityEXT::NAME
+                    if required.ext_active_action_set_priority =>
+                {
+                    out.ext_active_action_set_priority = Some(ext.extension_version);
+                }
+                raw::LocalFloorEXT::NAME if required.ext_local_floor => {
+                    out.ext_local_floor = Some(ext.extension_version);
+                }
+                raw::HandTrackingDataSourceEXT::NAME if required.ext_hand_tracking_data_source => {
+                    out.ext_hand_tracking_data_source = Some(ext.extension_version);
+                }
+                raw::PlaneDetectionEXT::NAME if required.ext_plane_detection => {
+                    out.ext_plane_detection = Some(ext.extension_version);
+                }
+                raw::CompositionLayerImageLayoutFB::NAME
+                    if required.fb_composition_layer_image_layout =>
+                {
+                    out.fb_composition_layer_image_layout = Some(ext.extension_version);
+                }
+                raw::CompositionLayerAlphaBlendFB::NAME
+                    if required.fb_composition_layer_alpha_blend =>
+                {
+                    out.fb_composition_layer_alpha_blend = Some(ext.extension_version);
+                }
+                #[cfg(target_os = "android")]
+                raw::AndroidSurfaceSwapchainCreateFB::NAME
+                    if required.fb_android_surface_swapchain_create =>
+                {
+                    out.fb_android_surface_swapchain_create = Some(ext.extension_version);
+                }
+                raw::SwapchainUpdateStateFB::NAME if required.fb_swapchain_update_state => {
+                    out.fb_swapchain_update_state = Some(ext.extension_version);
+                }
+                raw::CompositionLayerSecureContentFB::NAME
+                    if required.fb_composition_layer_secure_content =>
+                {
+                    out.fb_composition_layer_secure_content = Some(ext.extension_version);
+                }
+                raw::BodyTrackingFB::NAME if required.fb_body_tracking => {
+                    out.fb_body_tracking = Some(ext.extension_version);
+                }
+                raw::BodyTrackingFullBodyMETA::NAME if required.meta_body_tracking_full_body => {
+                    out.meta_body_tracking_full_body = Some(ext.extension_version);
+                }
+                raw::DisplayRefreshRateFB::NAME if required.fb_display_refresh_rate => {
+                    out.fb_display_refresh_rate = Some(ext.extension_version);
+                }
+                raw::ColorSpaceFB::NAME if required.fb_color_space => {
+                    out.fb_color_space = Some(ext.extension_version);
+                }
+                raw::HandTrackingMeshFB::NAME if required.fb_hand_tracking_mesh => {
+                    out.fb_hand_tracking_mesh = Some(ext.extension_version);
+                }
+                raw::HandTrackingAimFB::NAME if required.fb_hand_tracking_aim => {
+                    out.fb_hand_tracking_aim = Some(ext.extension_version);
+                }
+                raw::HandTrackingCapsulesFB::NAME if required.fb_hand_tracking_capsules => {
+                    out.fb_hand_tracking_capsules = Some(ext.extension_version);
+                }
+                raw::SpatialEntityFB::NAME if required.fb_spatial_entity => {
+                    out.fb_spatial_entity = Some(ext.extension_version);
+                }
+                raw::FoveationFB::NAME if required.fb_foveation => {
+                    out.fb_foveation = Some(ext.extension_version);
+                }
+                raw::FoveationConfigurationFB::NAME if required.fb_foveation_configuration => {
+                    out.fb_foveation_configuration = Some(ext.extension_version);
+                }
+                raw::KeyboardTrackingFB::NAME if required.fb_keyboard_tracking => {
+                    out.fb_keyboard_tracking = Some(ext.extension_version);
+                }
+                raw::TriangleMeshFB::NAME if required.fb_triangle_mesh => {
+                    out.fb_triangle_mesh = Some(ext.extension_version);
+                }
+                raw::PassthroughFB::NAME if required.fb_passthrough => {
+                    out.fb_passthrough = Some(ext.extension_version);
+                }
+                raw::RenderModelFB::NAME if required.fb_render_model => {
+                    out.fb_render_model = Some(ext.extension_version);
+                }
+                raw::SpatialEntityQueryFB::NAME if required.fb_spatial_entity_query => {
+                    out.fb_spatial_entity_query = Some(ext.extension_version);
+                }
+                raw::SpatialEntityStorageFB::NAME if required.fb_spatial_entity_storage => {
+                    out.fb_spatial_entity_storage = Some(ext.extension_version);
+                }
+                raw::FoveationVulkanFB::NAME if required.fb_foveation_vulkan => {
+                    out.fb_foveation_vulkan = Some(ext.extension_version);
+                }
+                #[cfg(target_os = "android")]
+                raw::SwapchainUpdateStateAndroidSurfaceFB::NAME
+                    if required.fb_swapchain_update_state_android_surface =>
+                {
+                    out.fb_swapchain_update_state_android_surface = Some(ext.extension_version);
+                }
+                raw::SwapchainUpdateStateOpenglEsFB::NAME
+                    if required.fb_swapchain_update_state_opengl_es =>
+                {
+                    out.fb_swapchain_update_state_opengl_es = Some(ext.extension_version);
+                }
+                raw::SwapchainUpdateStateVulkanFB::NAME
+                    if required.fb_swapchain_update_state_vulkan =>
+                {
+                    out.fb_swapchain_update_state_vulkan = Some(ext.extension_version);
+                }
+                raw::TouchControllerProFB::NAME if required.fb_touch_controller_pro => {
+                    out.fb_touch_controller_pro = Some(ext.extension_version);
+                }
+                raw::SpatialEntitySharingFB::NAME if required.fb_spatial_entity_sharing => {
+                    out.fb_spatial_entity_sharing = Some(ext.extension_version);
+                }
+                raw::SpaceWarpFB::NAME if required.fb_space_warp => {
+                    out.fb_space_warp = Some(ext.extension_version);
+                }
+                raw::HapticAmplitudeEnvelopeFB::NAME if required.fb_haptic_amplitude_envelope => {
+                    out.fb_haptic_amplitude_envelope = Some(ext.extension_version);
+                }
+                raw::SceneFB::NAME if required.fb_scene => {
+                    out.fb_scene = Some(ext.extension_version);
+                }
+                raw::SceneCaptureFB::NAME if required.fb_scene_capture => {
+                    out.fb_scene_capture = Some(ext.extension_version);
+                }
+                raw::SpatialEntityContainerFB::NAME if required.fb_spatial_entity_container => {
+                    out.fb_spatial_entity_container = Some(ext.extension_version);
+                }
+                raw::FaceTracking2FB::NAME if required.fb_face_tracking2 => {
+                    out.fb_face_tracking2 = Some(ext.extension_version);
+                }
+                raw::EyeTrackingSocialFB::NAME if required.fb_eye_tracking_social => {
+                    out.fb_eye_tracking_social = Some(ext.extension_version);
+                }
+                raw::PassthroughKeyboardHandsFB::NAME if required.fb_passthrough_keyboard_hands => {
+                    out.fb_passthrough_keyboard_hands = Some(ext.extension_version);
+                }
+                raw::CompositionLayerSettingsFB::NAME if required.fb_composition_layer_settings => {
+                    out.fb_composition_layer_settings = Some(ext.extension_version);
+                }
+                raw::TouchControllerProximityFB::NAME if required.fb_touch_controller_proximity => {
+                    out.fb_touch_controller_proximity = Some(ext.extension_version);
+                }
+                raw::HapticPcmFB::NAME if required.fb_haptic_pcm => {
+                    out.fb_haptic_pcm = Some(ext.extension_version);
+                }
+                raw::CompositionLayerDepthTestFB::NAME
+                    if required.fb_composition_layer_depth_test =>
+                {
+                    out.fb_composition_layer_depth_test = Some(ext.extension_version);
+                }
+                raw::SpatialEntityStorageBatchFB::NAME
+                    if required.fb_spatial_entity_storage_batch =>
+                {
+                    out.fb_spatial_entity_storage_batch = Some(ext.extension_version);
+                }
+                raw::SpatialEntityUserFB::NAME if required.fb_spatial_entity_user => {
+                    out.fb_spatial_entity_user = Some(ext.extension_version);
+                }
+                raw::ViveCosmosControllerInteractionHTC::NAME
+                    if required.htc_vive_cosmos_controller_interaction =>
+                {
+                    out.htc_vive_cosmos_controller_interaction = Some(ext.extension_version);
+                }
+                raw::FacialTrackingHTC::NAME if required.htc_facial_tracking => {
+                    out.htc_facial_tracking = Some(ext.extension_version);
+                }
+                raw::ViveFocus3ControllerInteractionHTC::NAME
+                    if required.htc_vive_focus3_controller_interaction =>
+                {
+                    out.htc_vive_focus3_controller_interaction = Some(ext.extension_version);
+                }
+                raw::HandInteractionHTC::NAME if required.htc_hand_interaction => {
+                    out.htc_hand_interaction = Some(ext.extension_version);
+                }
+                raw::ViveWristTrackerInteractionHTC::NAME
+                    if required.htc_vive_wrist_tracker_interaction =>
+                {
+                    out.htc_vive_wrist_tracker_interaction = Some(ext.extension_version);
+                }
+                raw::PassthroughHTC::NAME if required.htc_passthrough => {
+                    out.htc_passthrough = Some(ext.extension_version);
+                }
+                raw::FoveationHTC::NAME if required.htc_foveation => {
+                    out.htc_foveation = Some(ext.extension_version);
+                }
+                raw::ControllerInteractionHUAWEI::NAME
+                    if required.huawei_controller_interaction =>
+                {
+                    out.huawei_controller_interaction = Some(ext.extension_version);
+                }
+                #[cfg(target_os = "android")]
+                raw::AndroidThreadSettingsKHR::NAME if required.khr_android_thread_settings => {
+                    out.khr_android_thread_settings = Some(ext.extension_version);
+                }
+                #[cfg(target_os = "android")]
+                raw::AndroidSurfaceSwapchainKHR::NAME if required.khr_android_surface_swapchain => {
+                    out.khr_android_surface_swapchain = Some(ext.extension_version);
+                }
+                raw::CompositionLayerCubeKHR::NAME if required.khr_composition_layer_cube => {
+                    out.khr_composition_layer_cube = Some(ext.extension_version);
+                }
+                #[cfg(target_os = "android")]
+                raw::AndroidCreateInstanceKHR::NAME if required.khr_android_create_instance => {
+                    out.khr_android_create_instance = Some(ext.extension_version);
+                }
+                raw::CompositionLayerDepthKHR::NAME if required.khr_composition_layer_depth => {
+                    out.khr_composition_layer_depth = Some(ext.extension_version);
+                }
+                raw::VulkanSwapchainFormatListKHR::NAME
+                    if required.khr_vulkan_swapchain_format_list =>
+                {
+                    out.khr_vulkan_swapchain_format_list = Some(ext.extension_version);
+                }
+                raw::CompositionLayerCylinderKHR::NAME
+                    if required.khr_composition_layer_cylinder =>
+                {
+                    out.khr_composition_layer_cylinder = Some(ext.extension_version);
+                }
+                raw::CompositionLayerEquirectKHR::NAME
+                    if required.khr_composition_layer_equirect =>
+                {
+                    out.khr_composition_layer_equirect = Some(ext.extension_version);
+                }
+                raw::OpenglEnableKHR::NAME if required.khr_opengl_enable => {
+                    out.khr_opengl_enable = Some(ext.extension_version);
+                }
+                raw::OpenglEsEnableKHR::NAME if required.khr_opengl_es_enable => {
+                    out.khr_opengl_es_enable = Some(ext.extension_version);
+                }
+                raw::VulkanEnableKHR::NAME if required.khr_vulkan_enable => {
+                    out.khr_vulkan_enable = Some(ext.extension_version);
+                }
+                #[cfg(windows)]
+                raw::D3d11EnableKHR::NAME if required.khr_d3d11_enable => {
+                    out.khr_d3d11_enable = Some(ext.extension_version);
+                }
+                #[cfg(windows)]
+                raw::D3d12EnableKHR::NAME if required.khr_d3d12_enable => {
+                    out.khr_d3d12_enable = Some(ext.extension_version);
+                }
+                raw::VisibilityMaskKHR::NAME if required.khr_visibility_mask => {
+                    out.khr_visibility_mask = Some(ext.extension_version);
+                }
+                raw::CompositionLayerColorScaleBiasKHR::NAME
+                    if required.khr_composition_layer_color_scale_bias =>
+                {
+                    out.khr_composition_layer_color_scale_bias = Some(ext.extension_version);
+                }
+                #[cfg(windows)]
+                raw::Win32ConvertPerformanceCounterTimeKHR::NAME
+                    if required.khr_win32_convert_performance_counter_time =>
+                {
+                    out.khr_win32_convert_performance_counter_time = Some(ext.extension_version);
+                }
+                raw::ConvertTimespecTimeKHR::NAME if required.khr_convert_timespec_time => {
+                    out.khr_convert_timespec_time = Some(ext.extension_version);
+                }
+                raw::LoaderInitKHR::NAME if required.khr_loader_init => {
+                    out.khr_loader_init = Some(ext.extension_version);
+                }
+                #[cfg(target_os = "android")]
+                raw::LoaderInitAndroidKHR::NAME if required.khr_loader_init_android => {
+                    out.khr_loader_init_android = Some(ext.extension_version);
+                }
+                raw::VulkanEnable2KHR::NAME if required.khr_vulkan_enable2 => {
+                    out.khr_vulkan_enable2 = Some(ext.extension_version);
+                }
+                raw::CompositionLayerEquirect2KHR::NAME
+                    if required.khr_composition_layer_equirect2 =>
+                {
+                    out.khr_composition_layer_equirect2 = Some(ext.extension_version);
+                }
+                raw::BindingModificationKHR::NAME if required.khr_binding_modification => {
+                    out.khr_binding_modification = Some(ext.extension_version);
+                }
+                raw::SwapchainUsageInputAttachmentBitKHR::NAME
+                    if required.khr_swapchain_usage_input_attachment_bit =>
+                {
+                    out.khr_swapchain_usage_input_attachment_bit = Some(ext.extension_version);
+                }
+                raw::FoveationEyeTrackedMETA::NAME if required.meta_foveation_eye_tracked => {
+                    out.meta_foveation_eye_tracked = Some(ext.extension_version);
+                }
+                raw::LocalDimmingMETA::NAME if required.meta_local_dimming => {
+                    out.meta_local_dimming = Some(ext.extension_version);
+                }
+                raw::PassthroughPreferencesMETA::NAME if required.meta_passthrough_preferences => {
+                    out.meta_passthrough_preferences = Some(ext.extension_version);
+                }
+                raw::VirtualKeyboardMETA::NAME if required.meta_virtual_keyboard => {
+                    out.meta_virtual_keyboard = Some(ext.extension_version);
+                }
+                raw::VulkanSwapchainCreateInfoMETA::NAME
+                    if required.meta_vulkan_swapchain_create_info =>
+                {
+                    out.meta_vulkan_swapchain_create_info = Some(ext.extension_version);
+                }
+                raw::PerformanceMetricsMETA::NAME if required.meta_performance_metrics => {
+                    out.meta_performance_metrics = Some(ext.extension_version);
+                }
+                raw::HeadsetIdMETA::NAME if required.meta_headset_id => {
+                    out.meta_headset_id = Some(ext.extension_version);
+                }
+                raw::PassthroughColorLutMETA::NAME if required.meta_passthrough_color_lut => {
+                    out.meta_passthrough_color_lut = Some(ext.extension_version);
+                }
+                raw::Ml2ControllerInteractionML::NAME if required.ml_ml2_controller_interaction => {
+                    out.ml_ml2_controller_interaction = Some(ext.extension_version);
+                }
+                raw::FrameEndInfoML::NAME if required.ml_frame_end_info => {
+                    out.ml_frame_end_info = Some(ext.extension_version);
+                }
+                raw::GlobalDimmerML::NAME if required.ml_global_dimmer => {
+                    out.ml_global_dimmer = Some(ext.extension_version);
+                }
+                raw::CompatML::NAME if required.ml_compat => {
+                    out.ml_compat = Some(ext.extension_version);
+                }
+                raw::UserCalibrationML::NAME if required.ml_user_calibration => {
+                    out.ml_user_calibration = Some(ext.extension_version);
+                }
+                raw::HeadlessMND::NAME if required.mnd_headless => {
+                    out.mnd_headless = Some(ext.extension_version);
+                }
+                raw::SwapchainUsageInputAttachmentBitMND::NAME
+                    if required.mnd_swapchain_usage_input_attachment_bit =>
+                {
+                    out.mnd_swapchain_usage_input_attachment_bit = Some(ext.extension_version);
+                }
+                raw::UnboundedReferenceSpaceMSFT::NAME
+                    if required.msft_unbounded_reference_space =>
+                {
+                    out.msft_unbounded_reference_space = Some(ext.extension_version);
+                }
+                raw::SpatialAnchorMSFT::NAME if required.msft_spatial_anchor => {
+                    out.msft_spatial_anchor = Some(ext.extension_version);
+                }
+                raw::SpatialGraphBridgeMSFT::NAME if required.msft_spatial_graph_bridge => {
+                    out.msft_spatial_graph_bridge = Some(ext.extension_version);
+                }
+                raw::HandInteractionMSFT::NAME if required.msft_hand_interaction => {
+                    out.msft_hand_interaction = Some(ext.extension_version);
+                }
+                raw::HandTrackingMeshMSFT::NAME if required.msft_hand_tracking_mesh => {
+                    out.msft_hand_tracking_mesh = Some(ext.extension_version);
+                }
+                raw::SecondaryViewConfigurationMSFT::NAME
+                    if required.msft_secondary_view_configuration =>
+                {
+                    out.msft_secondary_view_configuration = Some(ext.extension_version);
+                }
+                raw::FirstPersonObserverMSFT::NAME if required.msft_first_person_observer => {
+                    out.msft_first_person_observer = Some(ext.extension_version);
+                }
+                raw::ControllerModelMSFT::NAME if required.msft_controller_model => {
+                    out.msft_controller_model = Some(ext.extension_version);
+                }
+                #[cfg(windows)]
+                raw::PerceptionAnchorInteropMSFT::NAME
+                    if required.msft_perception_anchor_interop =>
+                {
+                    out.msft_perception_anchor_interop = Some(ext.extension_version);
+                }
+                #[cfg(windows)]
+                raw::HolographicWindowAttachmentMSFT::NAME
+                    if required.msft_holographic_window_attachment =>
+                {
+                    out.msft_holographic_window_attachment = Some(ext.extension_version);
+                }
+                raw::CompositionLayerReprojectionMSFT::NAME
+                    if required.msft_composition_layer_reprojection =>
+                {
+                    out.msft_composition_layer_reprojection = Some(ext.extension_version);
+                }
+                raw::SpatialAnchorPersistenceMSFT::NAME
+                    if required.msft_spatial_anchor_persistence =>
+                {
+                    out.msft_spatial_anchor_persistence = Some(ext.extension_version);
+                }
+                #[cfg(target_os = "android")]
+                raw::AndroidSessionStateEnableOCULUS::NAME
+                    if required.oculus_android_session_state_enable =>
+                {
+                    out.oculus_android_session_state_enable = Some(ext.extension_version);
+                }
+                raw::AudioDeviceGuidOCULUS::NAME if required.oculus_audio_device_guid => {
+                    out.oculus_audio_device_guid = Some(ext.extension_version);
+                }
+                raw::ExternalCameraOCULUS::NAME if required.oculus_external_camera => {
+                    out.oculus_external_camera = Some(ext.extension_version);
+                }
+                raw::ControllerInteractionOPPO::NAME if required.oppo_controller_interaction => {
+                    out.oppo_controller_interaction = Some(ext.extension_version);
+                }
+                raw::TrackingOptimizationSettingsQCOM::NAME
+                    if required.qcom_tracking_optimization_settings =>
+                {
+                    out.qcom_tracking_optimization_settings = Some(ext.extension_version);
+                }
+                raw::HandTrackingForearmULTRALEAP::NAME
+                    if required.ultraleap_hand_tracking_forearm =>
+                {
+                    out.ultraleap_hand_tracking_forearm = Some(ext.extension_version);
+                }
+                raw::AnalogThresholdVALVE::NAME if required.valve_analog_threshold => {
+                    out.valve_analog_threshold = Some(ext.extension_version);
+                }
+                raw::QuadViewsVARJO::NAME if required.varjo_quad_views => {
+                    out.varjo_quad_views = Some(ext.extension_version);
+                }
+                raw::FoveatedRenderingVARJO::NAME if required.varjo_foveated_rendering => {
+                    out.varjo_foveated_rendering = Some(ext.extension_version);
+                }
+                raw::CompositionLayerDepthTestVARJO::NAME
+                    if required.varjo_composition_layer_depth_test =>
+                {
+                    out.varjo_composition_layer_depth_test = Some(ext.extension_version);
+                }
+                raw::EnvironmentDepthEstimationVARJO::NAME
+                    if required.varjo_environment_depth_estimation =>
+                {
+                    out.varjo_environment_depth_estimation = Some(ext.extension_version);
+                }
+                raw::MarkerTrackingVARJO::NAME if required.varjo_marker_tracking => {
+                    out.varjo_marker_tracking = Some(ext.extension_version);
+                }
+                raw::ViewOffsetVARJO::NAME if required.varjo_view_offset => {
+                    out.varjo_view_offset = Some(ext.extension_version);
+                }
+                raw::ControllerInteractionYVR::NAME if required.yvr_controller_interaction => {
+                    out.yvr_controller_interaction = Some(ext.extension_version);
+                }
+                _ => {}
+            }
+        }
+        out
+    }
+}
+#[derive(Copy, Clone, Debug)]
 #[non_exhaustive]
 pub enum Event<'a> {
     EventsLost(EventsLost<'a>),
@@ -2242,11 +2916,14 @@ pub enum Event<'a> {
     VirtualKeyboardHiddenMETA(VirtualKeyboardHiddenMETA<'a>),
     HeadsetFitChangedML(HeadsetFitChangedML<'a>),
     EyeCalibrationChangedML(EyeCalibrationChangedML<'a>),
+    #[doc = r" An event of a type these bindings don't decode, e.g. from an unknown extension"]
+    Raw(RawEvent<'a>),
 }
 impl<'a> Event<'a> {
     #[doc = r" Decode an event"]
     #[doc = r""]
-    #[doc = r" Returns `None` if the event type is not recognized, e.g. if it's from an unknown extension"]
+    #[doc = r" Events of unrecognized types, e.g. from an unknown extension, decode as `Event::Raw`,"]
+    #[doc = r" so this currently never returns `None`."]
     #[doc = r""]
     #[doc = r" # Safety"]
     #[doc = r""]
@@ -2369,14 +3046,154 @@ impl<'a> Event<'a> {
                 let typed = &*(raw as *const sys::EventDataEyeCalibrationChangedML);
                 Event::EyeCalibrationChangedML(EyeCalibrationChangedML::new(typed))
             }
-            _ => {
-                return None;
-            }
+            _ => Event::Raw(RawEvent::new(&*raw)),
         })
     }
 }
+#[doc = r#" Serialized as externally tagged, e.g. `{"SessionStateChanged": {"session": 1, ...}}`"#]
+#[cfg(feature = "serde")]
+impl serde::Serialize for Event<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match *self {
+            Event::EventsLost(ref e) => {
+                serializer.serialize_newtype_variant("Event", 0u32, "EventsLost", e)
+            }
+            Event::InstanceLossPending(ref e) => {
+                serializer.serialize_newtype_variant("Event", 1u32, "InstanceLossPending", e)
+            }
+            Event::SessionStateChanged(ref e) => {
+                serializer.serialize_newtype_variant("Event", 2u32, "SessionStateChanged", e)
+            }
+            Event::ReferenceSpaceChangePending(ref e) => serializer.serialize_newtype_variant(
+                "Event",
+                3u32,
+                "ReferenceSpaceChangePending",
+                e,
+            ),
+            Event::PerfSettingsEXT(ref e) => {
+                serializer.serialize_newtype_variant("Event", 4u32, "PerfSettingsEXT", e)
+            }
+            Event::VisibilityMaskChangedKHR(ref e) => {
+                serializer.serialize_newtype_variant("Event", 5u32, "VisibilityMaskChangedKHR", e)
+            }
+            Event::InteractionProfileChanged(ref e) => {
+                serializer.serialize_newtype_variant("Event", 6u32, "InteractionProfileChanged", e)
+            }
+            Event::MainSessionVisibilityChangedEXTX(ref e) => serializer.serialize_newtype_variant(
+                "Event",
+                7u32,
+                "MainSessionVisibilityChangedEXTX",
+                e,
+            ),
+            Event::DisplayRefreshRateChangedFB(ref e) => serializer.serialize_newtype_variant(
+                "Event",
+                8u32,
+                "DisplayRefreshRateChangedFB",
+                e,
+            ),
+            Event::SpatialAnchorCreateCompleteFB(ref e) => serializer.serialize_newtype_variant(
+                "Event",
+                9u32,
+                "SpatialAnchorCreateCompleteFB",
+                e,
+            ),
+            Event::SpaceSetStatusCompleteFB(ref e) => {
+                serializer.serialize_newtype_variant("Event", 10u32, "SpaceSetStatusCompleteFB", e)
+            }
+            Event::SpaceQueryResultsAvailableFB(ref e) => serializer.serialize_newtype_variant(
+                "Event",
+                11u32,
+                "SpaceQueryResultsAvailableFB",
+                e,
+            ),
+            Event::SpaceQueryCompleteFB(ref e) => {
+                serializer.serialize_newtype_variant("Event", 12u32, "SpaceQueryCompleteFB", e)
+            }
+            Event::SpaceSaveCompleteFB(ref e) => {
+                serializer.serialize_newtype_variant("Event", 13u32, "SpaceSaveCompleteFB", e)
+            }
+            Event::SpaceEraseCompleteFB(ref e) => {
+                serializer.serialize_newtype_variant("Event", 14u32, "SpaceEraseCompleteFB", e)
+            }
+            Event::SpaceShareCompleteFB(ref e) => {
+                serializer.serialize_newtype_variant("Event", 15u32, "SpaceShareCompleteFB", e)
+            }
+            Event::SpaceListSaveCompleteFB(ref e) => {
+                serializer.serialize_newtype_variant("Event", 16u32, "SpaceListSaveCompleteFB", e)
+            }
+            Event::SceneCaptureCompleteFB(ref e) => {
+                serializer.serialize_newtype_variant("Event", 17u32, "SceneCaptureCompleteFB", e)
+            }
+            Event::PassthroughStateChangedFB(ref e) => {
+                serializer.serialize_newtype_variant("Event", 18u32, "PassthroughStateChangedFB", e)
+            }
+            Event::ViveTrackerConnectedHTCX(ref e) => {
+                serializer.serialize_newtype_variant("Event", 19u32, "ViveTrackerConnectedHTCX", e)
+            }
+            Event::MarkerTrackingUpdateVARJO(ref e) => {
+                serializer.serialize_newtype_variant("Event", 20u32, "MarkerTrackingUpdateVARJO", e)
+            }
+            Event::VirtualKeyboardCommitTextMETA(ref e) => serializer.serialize_newtype_variant(
+                "Event",
+                21u32,
+                "VirtualKeyboardCommitTextMETA",
+                e,
+            ),
+            Event::VirtualKeyboardBackspaceMETA(ref e) => serializer.serialize_newtype_variant(
+                "Event",
+                22u32,
+                "VirtualKeyboardBackspaceMETA",
+                e,
+            ),
+            Event::VirtualKeyboardEnterMETA(ref e) => {
+                serializer.serialize_newtype_variant("Event", 23u32, "VirtualKeyboardEnterMETA", e)
+            }
+            Event::VirtualKeyboardShownMETA(ref e) => {
+                serializer.serialize_newtype_variant("Event", 24u32, "VirtualKeyboardShownMETA", e)
+            }
+            Event::VirtualKeyboardHiddenMETA(ref e) => {
+                serializer.serialize_newtype_variant("Event", 25u32, "VirtualKeyboardHiddenMETA", e)
+            }
+            Event::HeadsetFitChangedML(ref e) => {
+                serializer.serialize_newtype_variant("Event", 26u32, "HeadsetFitChangedML", e)
+            }
+            Event::EyeCalibrationChangedML(ref e) => {
+                serializer.serialize_newtype_variant("Event", 27u32, "EyeCalibrationChangedML", e)
+            }
+            Event::Raw(ref e) => serializer.serialize_newtype_variant("Event", 28u32, "Raw", e),
+        }
+    }
+}
 #[derive(Copy, Clone)]
 pub struct EventsLost<'a>(&'a sys::EventDataEventsLost);
+impl fmt::Debug for EventsLost<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("EventsLost")
+            .field(
+                "lost_event_count",
+                &event_fields::Field(&self.lost_event_count()),
+            )
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for EventsLost<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("EventsLost", 1usize)?;
+        s.serialize_field(
+            "lost_event_count",
+            &event_fields::Field(&self.lost_event_count()),
+        )?;
+        s.end()
+    }
+}
 impl<'a> EventsLost<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2393,6 +3210,25 @@ impl<'a> EventsLost<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct InstanceLossPending<'a>(&'a sys::EventDataInstanceLossPending);
+impl fmt::Debug for InstanceLossPending<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("InstanceLossPending")
+            .field("loss_time", &event_fields::Field(&self.loss_time()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for InstanceLossPending<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("InstanceLossPending", 1usize)?;
+        s.serialize_field("loss_time", &event_fields::Field(&self.loss_time()))?;
+        s.end()
+    }
+}
 impl<'a> InstanceLossPending<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2409,6 +3245,29 @@ impl<'a> InstanceLossPending<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct SessionStateChanged<'a>(&'a sys::EventDataSessionStateChanged);
+impl fmt::Debug for SessionStateChanged<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SessionStateChanged")
+            .field("session", &event_fields::Field(&self.session()))
+            .field("state", &event_fields::Field(&self.state()))
+            .field("time", &event_fields::Field(&self.time()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SessionStateChanged<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("SessionStateChanged", 3usize)?;
+        s.serialize_field("session", &event_fields::Field(&self.session()))?;
+        s.serialize_field("state", &event_fields::Field(&self.state()))?;
+        s.serialize_field("time", &event_fields::Field(&self.time()))?;
+        s.end()
+    }
+}
 impl<'a> SessionStateChanged<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2433,6 +3292,45 @@ impl<'a> SessionStateChanged<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct ReferenceSpaceChangePending<'a>(&'a sys::EventDataReferenceSpaceChangePending);
+impl fmt::Debug for ReferenceSpaceChangePending<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("ReferenceSpaceChangePending")
+            .field("session", &event_fields::Field(&self.session()))
+            .field(
+                "reference_space_type",
+                &event_fields::Field(&self.reference_space_type()),
+            )
+            .field("change_time", &event_fields::Field(&self.change_time()))
+            .field("pose_valid", &event_fields::Field(&self.pose_valid()))
+            .field(
+                "pose_in_previous_space",
+                &event_fields::Field(&self.pose_in_previous_space()),
+            )
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ReferenceSpaceChangePending<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("ReferenceSpaceChangePending", 5usize)?;
+        s.serialize_field("session", &event_fields::Field(&self.session()))?;
+        s.serialize_field(
+            "reference_space_type",
+            &event_fields::Field(&self.reference_space_type()),
+        )?;
+        s.serialize_field("change_time", &event_fields::Field(&self.change_time()))?;
+        s.serialize_field("pose_valid", &event_fields::Field(&self.pose_valid()))?;
+        s.serialize_field(
+            "pose_in_previous_space",
+            &event_fields::Field(&self.pose_in_previous_space()),
+        )?;
+        s.end()
+    }
+}
 impl<'a> ReferenceSpaceChangePending<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2465,6 +3363,31 @@ impl<'a> ReferenceSpaceChangePending<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct PerfSettingsEXT<'a>(&'a sys::EventDataPerfSettingsEXT);
+impl fmt::Debug for PerfSettingsEXT<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("PerfSettingsEXT")
+            .field("domain", &event_fields::Field(&self.domain()))
+            .field("sub_domain", &event_fields::Field(&self.sub_domain()))
+            .field("from_level", &event_fields::Field(&self.from_level()))
+            .field("to_level", &event_fields::Field(&self.to_level()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for PerfSettingsEXT<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("PerfSettingsEXT", 4usize)?;
+        s.serialize_field("domain", &event_fields::Field(&self.domain()))?;
+        s.serialize_field("sub_domain", &event_fields::Field(&self.sub_domain()))?;
+        s.serialize_field("from_level", &event_fields::Field(&self.from_level()))?;
+        s.serialize_field("to_level", &event_fields::Field(&self.to_level()))?;
+        s.end()
+    }
+}
 impl<'a> PerfSettingsEXT<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2493,6 +3416,35 @@ impl<'a> PerfSettingsEXT<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct VisibilityMaskChangedKHR<'a>(&'a sys::EventDataVisibilityMaskChangedKHR);
+impl fmt::Debug for VisibilityMaskChangedKHR<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("VisibilityMaskChangedKHR")
+            .field("session", &event_fields::Field(&self.session()))
+            .field(
+                "view_configuration_type",
+                &event_fields::Field(&self.view_configuration_type()),
+            )
+            .field("view_index", &event_fields::Field(&self.view_index()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VisibilityMaskChangedKHR<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("VisibilityMaskChangedKHR", 3usize)?;
+        s.serialize_field("session", &event_fields::Field(&self.session()))?;
+        s.serialize_field(
+            "view_configuration_type",
+            &event_fields::Field(&self.view_configuration_type()),
+        )?;
+        s.serialize_field("view_index", &event_fields::Field(&self.view_index()))?;
+        s.end()
+    }
+}
 impl<'a> VisibilityMaskChangedKHR<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2517,6 +3469,25 @@ impl<'a> VisibilityMaskChangedKHR<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct InteractionProfileChanged<'a>(&'a sys::EventDataInteractionProfileChanged);
+impl fmt::Debug for InteractionProfileChanged<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("InteractionProfileChanged")
+            .field("session", &event_fields::Field(&self.session()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for InteractionProfileChanged<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("InteractionProfileChanged", 1usize)?;
+        s.serialize_field("session", &event_fields::Field(&self.session()))?;
+        s.end()
+    }
+}
 impl<'a> InteractionProfileChanged<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2533,6 +3504,27 @@ impl<'a> InteractionProfileChanged<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct MainSessionVisibilityChangedEXTX<'a>(&'a sys::EventDataMainSessionVisibilityChangedEXTX);
+impl fmt::Debug for MainSessionVisibilityChangedEXTX<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("MainSessionVisibilityChangedEXTX")
+            .field("visible", &event_fields::Field(&self.visible()))
+            .field("flags", &event_fields::Field(&self.flags()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for MainSessionVisibilityChangedEXTX<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("MainSessionVisibilityChangedEXTX", 2usize)?;
+        s.serialize_field("visible", &event_fields::Field(&self.visible()))?;
+        s.serialize_field("flags", &event_fields::Field(&self.flags()))?;
+        s.end()
+    }
+}
 impl<'a> MainSessionVisibilityChangedEXTX<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2553,6 +3545,39 @@ impl<'a> MainSessionVisibilityChangedEXTX<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct DisplayRefreshRateChangedFB<'a>(&'a sys::EventDataDisplayRefreshRateChangedFB);
+impl fmt::Debug for DisplayRefreshRateChangedFB<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("DisplayRefreshRateChangedFB")
+            .field(
+                "from_display_refresh_rate",
+                &event_fields::Field(&self.from_display_refresh_rate()),
+            )
+            .field(
+                "to_display_refresh_rate",
+                &event_fields::Field(&self.to_display_refresh_rate()),
+            )
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for DisplayRefreshRateChangedFB<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("DisplayRefreshRateChangedFB", 2usize)?;
+        s.serialize_field(
+            "from_display_refresh_rate",
+            &event_fields::Field(&self.from_display_refresh_rate()),
+        )?;
+        s.serialize_field(
+            "to_display_refresh_rate",
+            &event_fields::Field(&self.to_display_refresh_rate()),
+        )?;
+        s.end()
+    }
+}
 impl<'a> DisplayRefreshRateChangedFB<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2573,6 +3598,31 @@ impl<'a> DisplayRefreshRateChangedFB<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct SpatialAnchorCreateCompleteFB<'a>(&'a sys::EventDataSpatialAnchorCreateCompleteFB);
+impl fmt::Debug for SpatialAnchorCreateCompleteFB<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SpatialAnchorCreateCompleteFB")
+            .field("request_id", &event_fields::Field(&self.request_id()))
+            .field("result", &event_fields::Field(&self.result()))
+            .field("space", &event_fields::Field(&self.space()))
+            .field("uuid", &event_fields::Field(&self.uuid()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SpatialAnchorCreateCompleteFB<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("SpatialAnchorCreateCompleteFB", 4usize)?;
+        s.serialize_field("request_id", &event_fields::Field(&self.request_id()))?;
+        s.serialize_field("result", &event_fields::Field(&self.result()))?;
+        s.serialize_field("space", &event_fields::Field(&self.space()))?;
+        s.serialize_field("uuid", &event_fields::Field(&self.uuid()))?;
+        s.end()
+    }
+}
 impl<'a> SpatialAnchorCreateCompleteFB<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2601,6 +3651,41 @@ impl<'a> SpatialAnchorCreateCompleteFB<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct SpaceSetStatusCompleteFB<'a>(&'a sys::EventDataSpaceSetStatusCompleteFB);
+impl fmt::Debug for SpaceSetStatusCompleteFB<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SpaceSetStatusCompleteFB")
+            .field("request_id", &event_fields::Field(&self.request_id()))
+            .field("result", &event_fields::Field(&self.result()))
+            .field("space", &event_fields::Field(&self.space()))
+            .field("uuid", &event_fields::Field(&self.uuid()))
+            .field(
+                "component_type",
+                &event_fields::Field(&self.component_type()),
+            )
+            .field("enabled", &event_fields::Field(&self.enabled()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SpaceSetStatusCompleteFB<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("SpaceSetStatusCompleteFB", 6usize)?;
+        s.serialize_field("request_id", &event_fields::Field(&self.request_id()))?;
+        s.serialize_field("result", &event_fields::Field(&self.result()))?;
+        s.serialize_field("space", &event_fields::Field(&self.space()))?;
+        s.serialize_field("uuid", &event_fields::Field(&self.uuid()))?;
+        s.serialize_field(
+            "component_type",
+            &event_fields::Field(&self.component_type()),
+        )?;
+        s.serialize_field("enabled", &event_fields::Field(&self.enabled()))?;
+        s.end()
+    }
+}
 impl<'a> SpaceSetStatusCompleteFB<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2637,6 +3722,25 @@ impl<'a> SpaceSetStatusCompleteFB<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct SpaceQueryResultsAvailableFB<'a>(&'a sys::EventDataSpaceQueryResultsAvailableFB);
+impl fmt::Debug for SpaceQueryResultsAvailableFB<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SpaceQueryResultsAvailableFB")
+            .field("request_id", &event_fields::Field(&self.request_id()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SpaceQueryResultsAvailableFB<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("SpaceQueryResultsAvailableFB", 1usize)?;
+        s.serialize_field("request_id", &event_fields::Field(&self.request_id()))?;
+        s.end()
+    }
+}
 impl<'a> SpaceQueryResultsAvailableFB<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2653,6 +3757,27 @@ impl<'a> SpaceQueryResultsAvailableFB<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct SpaceQueryCompleteFB<'a>(&'a sys::EventDataSpaceQueryCompleteFB);
+impl fmt::Debug for SpaceQueryCompleteFB<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SpaceQueryCompleteFB")
+            .field("request_id", &event_fields::Field(&self.request_id()))
+            .field("result", &event_fields::Field(&self.result()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SpaceQueryCompleteFB<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("SpaceQueryCompleteFB", 2usize)?;
+        s.serialize_field("request_id", &event_fields::Field(&self.request_id()))?;
+        s.serialize_field("result", &event_fields::Field(&self.result()))?;
+        s.end()
+    }
+}
 impl<'a> SpaceQueryCompleteFB<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2673,6 +3798,33 @@ impl<'a> SpaceQueryCompleteFB<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct SpaceSaveCompleteFB<'a>(&'a sys::EventDataSpaceSaveCompleteFB);
+impl fmt::Debug for SpaceSaveCompleteFB<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SpaceSaveCompleteFB")
+            .field("request_id", &event_fields::Field(&self.request_id()))
+            .field("result", &event_fields::Field(&self.result()))
+            .field("space", &event_fields::Field(&self.space()))
+            .field("uuid", &event_fields::Field(&self.uuid()))
+            .field("location", &event_fields::Field(&self.location()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SpaceSaveCompleteFB<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("SpaceSaveCompleteFB", 5usize)?;
+        s.serialize_field("request_id", &event_fields::Field(&self.request_id()))?;
+        s.serialize_field("result", &event_fields::Field(&self.result()))?;
+        s.serialize_field("space", &event_fields::Field(&self.space()))?;
+        s.serialize_field("uuid", &event_fields::Field(&self.uuid()))?;
+        s.serialize_field("location", &event_fields::Field(&self.location()))?;
+        s.end()
+    }
+}
 impl<'a> SpaceSaveCompleteFB<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2705,6 +3857,33 @@ impl<'a> SpaceSaveCompleteFB<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct SpaceEraseCompleteFB<'a>(&'a sys::EventDataSpaceEraseCompleteFB);
+impl fmt::Debug for SpaceEraseCompleteFB<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SpaceEraseCompleteFB")
+            .field("request_id", &event_fields::Field(&self.request_id()))
+            .field("result", &event_fields::Field(&self.result()))
+            .field("space", &event_fields::Field(&self.space()))
+            .field("uuid", &event_fields::Field(&self.uuid()))
+            .field("location", &event_fields::Field(&self.location()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SpaceEraseCompleteFB<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("SpaceEraseCompleteFB", 5usize)?;
+        s.serialize_field("request_id", &event_fields::Field(&self.request_id()))?;
+        s.serialize_field("result", &event_fields::Field(&self.result()))?;
+        s.serialize_field("space", &event_fields::Field(&self.space()))?;
+        s.serialize_field("uuid", &event_fields::Field(&self.uuid()))?;
+        s.serialize_field("location", &event_fields::Field(&self.location()))?;
+        s.end()
+    }
+}
 impl<'a> SpaceEraseCompleteFB<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2737,6 +3916,27 @@ impl<'a> SpaceEraseCompleteFB<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct SpaceShareCompleteFB<'a>(&'a sys::EventDataSpaceShareCompleteFB);
+impl fmt::Debug for SpaceShareCompleteFB<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SpaceShareCompleteFB")
+            .field("request_id", &event_fields::Field(&self.request_id()))
+            .field("result", &event_fields::Field(&self.result()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SpaceShareCompleteFB<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("SpaceShareCompleteFB", 2usize)?;
+        s.serialize_field("request_id", &event_fields::Field(&self.request_id()))?;
+        s.serialize_field("result", &event_fields::Field(&self.result()))?;
+        s.end()
+    }
+}
 impl<'a> SpaceShareCompleteFB<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2757,6 +3957,27 @@ impl<'a> SpaceShareCompleteFB<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct SpaceListSaveCompleteFB<'a>(&'a sys::EventDataSpaceListSaveCompleteFB);
+impl fmt::Debug for SpaceListSaveCompleteFB<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SpaceListSaveCompleteFB")
+            .field("request_id", &event_fields::Field(&self.request_id()))
+            .field("result", &event_fields::Field(&self.result()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SpaceListSaveCompleteFB<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("SpaceListSaveCompleteFB", 2usize)?;
+        s.serialize_field("request_id", &event_fields::Field(&self.request_id()))?;
+        s.serialize_field("result", &event_fields::Field(&self.result()))?;
+        s.end()
+    }
+}
 impl<'a> SpaceListSaveCompleteFB<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2777,6 +3998,27 @@ impl<'a> SpaceListSaveCompleteFB<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct SceneCaptureCompleteFB<'a>(&'a sys::EventDataSceneCaptureCompleteFB);
+impl fmt::Debug for SceneCaptureCompleteFB<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SceneCaptureCompleteFB")
+            .field("request_id", &event_fields::Field(&self.request_id()))
+            .field("result", &event_fields::Field(&self.result()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SceneCaptureCompleteFB<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("SceneCaptureCompleteFB", 2usize)?;
+        s.serialize_field("request_id", &event_fields::Field(&self.request_id()))?;
+        s.serialize_field("result", &event_fields::Field(&self.result()))?;
+        s.end()
+    }
+}
 impl<'a> SceneCaptureCompleteFB<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2797,6 +4039,25 @@ impl<'a> SceneCaptureCompleteFB<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct PassthroughStateChangedFB<'a>(&'a sys::EventDataPassthroughStateChangedFB);
+impl fmt::Debug for PassthroughStateChangedFB<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("PassthroughStateChangedFB")
+            .field("flags", &event_fields::Field(&self.flags()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for PassthroughStateChangedFB<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("PassthroughStateChangedFB", 1usize)?;
+        s.serialize_field("flags", &event_fields::Field(&self.flags()))?;
+        s.end()
+    }
+}
 impl<'a> PassthroughStateChangedFB<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2813,6 +4074,25 @@ impl<'a> PassthroughStateChangedFB<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct ViveTrackerConnectedHTCX<'a>(&'a sys::EventDataViveTrackerConnectedHTCX);
+impl fmt::Debug for ViveTrackerConnectedHTCX<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("ViveTrackerConnectedHTCX")
+            .field("paths", &event_fields::Field(&self.paths()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ViveTrackerConnectedHTCX<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("ViveTrackerConnectedHTCX", 1usize)?;
+        s.serialize_field("paths", &event_fields::Field(&self.paths()))?;
+        s.end()
+    }
+}
 impl<'a> ViveTrackerConnectedHTCX<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2829,6 +4109,31 @@ impl<'a> ViveTrackerConnectedHTCX<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct MarkerTrackingUpdateVARJO<'a>(&'a sys::EventDataMarkerTrackingUpdateVARJO);
+impl fmt::Debug for MarkerTrackingUpdateVARJO<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("MarkerTrackingUpdateVARJO")
+            .field("marker_id", &event_fields::Field(&self.marker_id()))
+            .field("is_active", &event_fields::Field(&self.is_active()))
+            .field("is_predicted", &event_fields::Field(&self.is_predicted()))
+            .field("time", &event_fields::Field(&self.time()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for MarkerTrackingUpdateVARJO<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("MarkerTrackingUpdateVARJO", 4usize)?;
+        s.serialize_field("marker_id", &event_fields::Field(&self.marker_id()))?;
+        s.serialize_field("is_active", &event_fields::Field(&self.is_active()))?;
+        s.serialize_field("is_predicted", &event_fields::Field(&self.is_predicted()))?;
+        s.serialize_field("time", &event_fields::Field(&self.time()))?;
+        s.end()
+    }
+}
 impl<'a> MarkerTrackingUpdateVARJO<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2857,6 +4162,27 @@ impl<'a> MarkerTrackingUpdateVARJO<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct VirtualKeyboardCommitTextMETA<'a>(&'a sys::EventDataVirtualKeyboardCommitTextMETA);
+impl fmt::Debug for VirtualKeyboardCommitTextMETA<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("VirtualKeyboardCommitTextMETA")
+            .field("keyboard", &event_fields::Field(&self.keyboard()))
+            .field("text", &event_fields::Field(&self.text()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VirtualKeyboardCommitTextMETA<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("VirtualKeyboardCommitTextMETA", 2usize)?;
+        s.serialize_field("keyboard", &event_fields::Field(&self.keyboard()))?;
+        s.serialize_field("text", &event_fields::Field(&self.text()))?;
+        s.end()
+    }
+}
 impl<'a> VirtualKeyboardCommitTextMETA<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2877,6 +4203,25 @@ impl<'a> VirtualKeyboardCommitTextMETA<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct VirtualKeyboardBackspaceMETA<'a>(&'a sys::EventDataVirtualKeyboardBackspaceMETA);
+impl fmt::Debug for VirtualKeyboardBackspaceMETA<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("VirtualKeyboardBackspaceMETA")
+            .field("keyboard", &event_fields::Field(&self.keyboard()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VirtualKeyboardBackspaceMETA<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("VirtualKeyboardBackspaceMETA", 1usize)?;
+        s.serialize_field("keyboard", &event_fields::Field(&self.keyboard()))?;
+        s.end()
+    }
+}
 impl<'a> VirtualKeyboardBackspaceMETA<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2893,6 +4238,25 @@ impl<'a> VirtualKeyboardBackspaceMETA<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct VirtualKeyboardEnterMETA<'a>(&'a sys::EventDataVirtualKeyboardEnterMETA);
+impl fmt::Debug for VirtualKeyboardEnterMETA<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("VirtualKeyboardEnterMETA")
+            .field("keyboard", &event_fields::Field(&self.keyboard()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VirtualKeyboardEnterMETA<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("VirtualKeyboardEnterMETA", 1usize)?;
+        s.serialize_field("keyboard", &event_fields::Field(&self.keyboard()))?;
+        s.end()
+    }
+}
 impl<'a> VirtualKeyboardEnterMETA<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2909,6 +4273,25 @@ impl<'a> VirtualKeyboardEnterMETA<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct VirtualKeyboardShownMETA<'a>(&'a sys::EventDataVirtualKeyboardShownMETA);
+impl fmt::Debug for VirtualKeyboardShownMETA<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("VirtualKeyboardShownMETA")
+            .field("keyboard", &event_fields::Field(&self.keyboard()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VirtualKeyboardShownMETA<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("VirtualKeyboardShownMETA", 1usize)?;
+        s.serialize_field("keyboard", &event_fields::Field(&self.keyboard()))?;
+        s.end()
+    }
+}
 impl<'a> VirtualKeyboardShownMETA<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2925,6 +4308,25 @@ impl<'a> VirtualKeyboardShownMETA<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct VirtualKeyboardHiddenMETA<'a>(&'a sys::EventDataVirtualKeyboardHiddenMETA);
+impl fmt::Debug for VirtualKeyboardHiddenMETA<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("VirtualKeyboardHiddenMETA")
+            .field("keyboard", &event_fields::Field(&self.keyboard()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VirtualKeyboardHiddenMETA<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("VirtualKeyboardHiddenMETA", 1usize)?;
+        s.serialize_field("keyboard", &event_fields::Field(&self.keyboard()))?;
+        s.end()
+    }
+}
 impl<'a> VirtualKeyboardHiddenMETA<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2941,6 +4343,27 @@ impl<'a> VirtualKeyboardHiddenMETA<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct HeadsetFitChangedML<'a>(&'a sys::EventDataHeadsetFitChangedML);
+impl fmt::Debug for HeadsetFitChangedML<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("HeadsetFitChangedML")
+            .field("status", &event_fields::Field(&self.status()))
+            .field("time", &event_fields::Field(&self.time()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for HeadsetFitChangedML<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("HeadsetFitChangedML", 2usize)?;
+        s.serialize_field("status", &event_fields::Field(&self.status()))?;
+        s.serialize_field("time", &event_fields::Field(&self.time()))?;
+        s.end()
+    }
+}
 impl<'a> HeadsetFitChangedML<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -2961,6 +4384,25 @@ impl<'a> HeadsetFitChangedML<'a> {
 }
 #[derive(Copy, Clone)]
 pub struct EyeCalibrationChangedML<'a>(&'a sys::EventDataEyeCalibrationChangedML);
+impl fmt::Debug for EyeCalibrationChangedML<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("EyeCalibrationChangedML")
+            .field("status", &event_fields::Field(&self.status()))
+            .finish()
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for EyeCalibrationChangedML<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("EyeCalibrationChangedML", 1usize)?;
+        s.serialize_field("status", &event_fields::Field(&self.status()))?;
+        s.end()
+    }
+}
 impl<'a> EyeCalibrationChangedML<'a> {
     #[inline]
     #[doc = r" # Safety"]
@@ -5929,7 +7371,7 @@ pub(crate) mod builder {
         }
         #[inline]
         pub fn localized_action_set_name(mut self, value: &str) -> Self {
-            place_cstr(&mut self.inner.localized_action_set_name, value);
+            place_cstr_truncated(&mut self.inner.localized_action_set_name, value);
             self
         }
         #[inline]
@@ -5999,7 +7441,7 @@ pub(crate) mod builder {
         }
         #[inline]
         pub fn localized_action_name(mut self, value: &str) -> Self {
-            place_cstr(&mut self.inner.localized_action_name, value);
+            place_cstr_truncated(&mut self.inner.localized_action_name, value);
             self
         }
     }
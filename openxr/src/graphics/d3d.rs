@@ -69,11 +69,7 @@ impl Graphics for D3D11 {
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
         let images = get_arr_init(
-            sys::SwapchainImageD3D11KHR {
-                ty: sys::SwapchainImageD3D11KHR::TYPE,
-                next: ptr::null_mut(),
-                texture: ptr::null_mut(),
-            },
+            sys::SwapchainImageD3D11KHR::out(ptr::null_mut()),
             |capacity, count, buf| unsafe {
                 (swapchain.instance().fp().enumerate_swapchain_images)(
                     swapchain.as_raw(),
@@ -83,7 +79,10 @@ impl Graphics for D3D11 {
                 )
             },
         )?;
-        Ok(images.into_iter().map(|x| x.texture).collect())
+        Ok(images
+            .into_iter()
+            .map(|x| unsafe { x.assume_init().texture })
+            .collect())
     }
 }
 
@@ -154,11 +153,7 @@ impl Graphics for D3D12 {
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
         let images = get_arr_init(
-            sys::SwapchainImageD3D12KHR {
-                ty: sys::SwapchainImageD3D12KHR::TYPE,
-                next: ptr::null_mut(),
-                texture: ptr::null_mut(),
-            },
+            sys::SwapchainImageD3D12KHR::out(ptr::null_mut()),
             |capacity, count, buf| unsafe {
                 (swapchain.instance().fp().enumerate_swapchain_images)(
                     swapchain.as_raw(),
@@ -168,7 +163,10 @@ impl Graphics for D3D12 {
                 )
             },
         )?;
-        Ok(images.into_iter().map(|x| x.texture).collect())
+        Ok(images
+            .into_iter()
+            .map(|x| unsafe { x.assume_init().texture })
+            .collect())
     }
 }
 
@@ -188,3 +186,119 @@ pub struct SessionCreateInfoD3D12 {
     pub device: *mut ID3D12Device,
     pub queue: *mut ID3D12CommandQueue,
 }
+
+impl D3D11 {
+    /// The D3D11 texture parameters the runtime uses for swapchain images created with `usage`
+    ///
+    /// Textures imported into other code must not be bound in ways these flags don't permit.
+    pub fn texture_usage(usage: SwapchainUsageFlags) -> TextureUsageD3D11 {
+        TextureUsageD3D11 {
+            bind_flags: super::translate_usage(
+                usage,
+                &[
+                    (SwapchainUsageFlags::SAMPLED, 0x8),
+                    (SwapchainUsageFlags::COLOR_ATTACHMENT, 0x20),
+                    (SwapchainUsageFlags::DEPTH_STENCIL_ATTACHMENT, 0x40),
+                    (SwapchainUsageFlags::UNORDERED_ACCESS, 0x80),
+                ],
+            ) as u32,
+            typeless: usage.contains(SwapchainUsageFlags::MUTABLE_FORMAT),
+        }
+    }
+}
+
+impl D3D11 {
+    /// Check a device against the requirements of `system`, describing problems that
+    /// [`Instance::create_session`] would only report as `ERROR_GRAPHICS_DEVICE_INVALID`
+    ///
+    /// `adapter_luid` identifies the adapter the device was created on, from
+    /// `IDXGIAdapter::GetDesc`, and `feature_level` is `ID3D11Device::GetFeatureLevel`.
+    pub fn validate_device(
+        instance: &Instance,
+        system: SystemId,
+        adapter_luid: LUID,
+        feature_level: D3D_FEATURE_LEVEL,
+    ) -> Result<(), GraphicsBindingError> {
+        let requirements = instance.graphics_requirements::<D3D11>(system)?;
+        validate_device(&requirements, adapter_luid, feature_level)
+    }
+}
+
+impl D3D12 {
+    /// Check a device against the requirements of `system`, describing problems that
+    /// [`Instance::create_session`] would only report as `ERROR_GRAPHICS_DEVICE_INVALID`
+    ///
+    /// `adapter_luid` is `ID3D12Device::GetAdapterLuid`, and `feature_level` is the highest level
+    /// reported by `ID3D12Device::CheckFeatureSupport` for `D3D12_FEATURE_FEATURE_LEVELS`.
+    pub fn validate_device(
+        instance: &Instance,
+        system: SystemId,
+        adapter_luid: LUID,
+        feature_level: D3D_FEATURE_LEVEL,
+    ) -> Result<(), GraphicsBindingError> {
+        let requirements = instance.graphics_requirements::<D3D12>(system)?;
+        validate_device(&requirements, adapter_luid, feature_level)
+    }
+}
+
+fn validate_device(
+    requirements: &Requirements,
+    adapter_luid: LUID,
+    feature_level: D3D_FEATURE_LEVEL,
+) -> Result<(), GraphicsBindingError> {
+    let required = requirements.adapter_luid;
+    if (adapter_luid.LowPart, adapter_luid.HighPart) != (required.LowPart, required.HighPart) {
+        return Err(GraphicsBindingError::Adapter);
+    }
+    if feature_level < requirements.min_feature_level {
+        return Err(GraphicsBindingError::FeatureLevel {
+            level: feature_level,
+            min: requirements.min_feature_level,
+        });
+    }
+    Ok(())
+}
+
+impl D3D12 {
+    /// The D3D12 resource parameters the runtime uses for swapchain images created with `usage`
+    ///
+    /// Resources imported into other code must not be used in ways these flags don't permit.
+    pub fn resource_usage(usage: SwapchainUsageFlags) -> ResourceUsageD3D12 {
+        let mut flags = super::translate_usage(
+            usage,
+            &[
+                (SwapchainUsageFlags::COLOR_ATTACHMENT, 0x1),
+                (SwapchainUsageFlags::DEPTH_STENCIL_ATTACHMENT, 0x2),
+                (SwapchainUsageFlags::UNORDERED_ACCESS, 0x4),
+            ],
+        ) as u32;
+        // D3D12 only allows denying shader access to depth-stencil resources
+        if usage.contains(SwapchainUsageFlags::DEPTH_STENCIL_ATTACHMENT)
+            && !usage.contains(SwapchainUsageFlags::SAMPLED)
+        {
+            flags |= 0x8;
+        }
+        ResourceUsageD3D12 {
+            flags,
+            typeless: usage.contains(SwapchainUsageFlags::MUTABLE_FORMAT),
+        }
+    }
+}
+
+/// Native parameters of a swapchain image, as returned by [`D3D11::texture_usage`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TextureUsageD3D11 {
+    /// `D3D11_TEXTURE2D_DESC::BindFlags`
+    pub bind_flags: u32,
+    /// Whether the texture has the typeless variant of the requested format
+    pub typeless: bool,
+}
+
+/// Native parameters of a swapchain image, as returned by [`D3D12::resource_usage`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ResourceUsageD3D12 {
+    /// `D3D12_RESOURCE_DESC::Flags`
+    pub flags: u32,
+    /// Whether the resource has the typeless variant of the requested format
+    pub typeless: bool,
+}
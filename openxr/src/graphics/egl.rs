@@ -0,0 +1,104 @@
+use std::ptr;
+
+use sys::platform::*;
+
+use crate::*;
+
+pub use super::opengl::{Requirements, TextureUsage};
+
+/// The OpenGL graphics API, with contexts created through EGL
+///
+/// See [`XR_MNDX_egl_enable`] for safety details. Useful on Linux runtimes such as Monado when the
+/// application renders through EGL rather than GLX, e.g. under Wayland or without a display
+/// server.
+///
+/// `XR_KHR_opengl_enable` must be enabled too, as it provides the graphics requirements and
+/// swapchain images. `XR_MNDX_egl_enable` itself must be
+/// [enabled by name](crate#extensions-without-extensionset-fields).
+///
+/// [`XR_MNDX_egl_enable`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MNDX_egl_enable
+pub enum OpenGlEgl {}
+
+impl Graphics for OpenGlEgl {
+    type Requirements = Requirements;
+    type SessionCreateInfo = SessionCreateInfo;
+    type Format = u32;
+    type SwapchainImage = u32;
+
+    fn raise_format(x: i64) -> u32 {
+        x as _
+    }
+    fn lower_format(x: u32) -> i64 {
+        x.into()
+    }
+
+    fn requirements(inst: &Instance, system: SystemId) -> Result<Requirements> {
+        OpenGL::requirements(inst, system)
+    }
+
+    unsafe fn create_session(
+        instance: &Instance,
+        system: SystemId,
+        info: &Self::SessionCreateInfo,
+    ) -> Result<sys::Session> {
+        let binding = sys::GraphicsBindingEGLMNDX {
+            ty: sys::GraphicsBindingEGLMNDX::TYPE,
+            next: ptr::null(),
+            get_proc_address: Some(info.get_proc_address),
+            display: info.display,
+            config: info.config,
+            context: info.context,
+        };
+        let info = sys::SessionCreateInfo {
+            ty: sys::SessionCreateInfo::TYPE,
+            next: &binding as *const _ as *const _,
+            create_flags: Default::default(),
+            system_id: system,
+        };
+        let mut out = sys::Session::NULL;
+        cvt((instance.fp().create_session)(
+            instance.as_raw(),
+            &info,
+            &mut out,
+        ))?;
+        Ok(out)
+    }
+
+    fn enumerate_swapchain_images(
+        swapchain: &Swapchain<Self>,
+    ) -> Result<Vec<Self::SwapchainImage>> {
+        let images = get_arr_init(
+            sys::SwapchainImageOpenGLKHR::out(ptr::null_mut()),
+            |capacity, count, buf| unsafe {
+                (swapchain.instance().fp().enumerate_swapchain_images)(
+                    swapchain.as_raw(),
+                    capacity,
+                    count,
+                    buf as *mut _,
+                )
+            },
+        )?;
+        Ok(images
+            .into_iter()
+            .map(|x| unsafe { x.assume_init().image })
+            .collect())
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct SessionCreateInfo {
+    /// `eglGetProcAddress`, used by the runtime to load the EGL functions it needs
+    pub get_proc_address: EglGetProcAddressMNDX,
+    pub display: EGLDisplay,
+    pub config: EGLConfig,
+    pub context: EGLContext,
+}
+
+impl OpenGlEgl {
+    /// The operations swapchain images created with `usage` may be used for
+    ///
+    /// See [`OpenGL::texture_usage`].
+    pub fn texture_usage(usage: SwapchainUsageFlags) -> TextureUsage {
+        TextureUsage::new(usage)
+    }
+}
@@ -0,0 +1,63 @@
+use std::ptr;
+
+use crate::*;
+
+/// Sessions without graphics, for applications that only use tracking and input
+///
+/// Such sessions never render, and may not create swapchains. Requires [`XR_MND_headless`].
+///
+/// [`XR_MND_headless`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MND_headless
+pub enum Headless {}
+
+impl Graphics for Headless {
+    type Requirements = ();
+    type SessionCreateInfo = ();
+    type Format = i64;
+    type SwapchainImage = ();
+
+    fn raise_format(x: i64) -> i64 {
+        x
+    }
+    fn lower_format(x: i64) -> i64 {
+        x
+    }
+
+    fn requirements(_: &Instance, _: SystemId) -> Result<()> {
+        Ok(())
+    }
+
+    unsafe fn create_session(
+        instance: &Instance,
+        system: SystemId,
+        (): &(),
+    ) -> Result<sys::Session> {
+        let info = sys::SessionCreateInfo {
+            ty: sys::SessionCreateInfo::TYPE,
+            next: ptr::null(),
+            create_flags: Default::default(),
+            system_id: system,
+        };
+        let mut out = sys::Session::NULL;
+        cvt((instance.fp().create_session)(
+            instance.as_raw(),
+            &info,
+            &mut out,
+        ))?;
+        Ok(out)
+    }
+
+    fn enumerate_swapchain_images(
+        swapchain: &Swapchain<Self>,
+    ) -> Result<Vec<Self::SwapchainImage>> {
+        let mut count = 0;
+        unsafe {
+            cvt((swapchain.instance().fp().enumerate_swapchain_images)(
+                swapchain.as_raw(),
+                0,
+                &mut count,
+                ptr::null_mut(),
+            ))?;
+        }
+        Ok(vec![(); count as usize])
+    }
+}
@@ -0,0 +1,116 @@
+use std::{os::raw::c_void, ptr};
+
+use crate::*;
+
+/// The Metal graphics API
+///
+/// See [`XR_KHR_metal_enable`] for safety details.
+///
+/// The extension must be [enabled by name](crate#extensions-without-extensionset-fields).
+///
+/// [`XR_KHR_metal_enable`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_metal_enable
+pub enum Metal {}
+
+impl Graphics for Metal {
+    type Requirements = Requirements;
+    type SessionCreateInfo = SessionCreateInfo;
+    /// `MTLPixelFormat`
+    type Format = u64;
+    /// `id<MTLTexture>`
+    type SwapchainImage = *mut c_void;
+
+    fn raise_format(x: i64) -> u64 {
+        x as _
+    }
+    fn lower_format(x: u64) -> i64 {
+        x as _
+    }
+
+    fn requirements(inst: &Instance, system: SystemId) -> Result<Requirements> {
+        let out = unsafe {
+            let get_metal_graphics_requirements: pfn::GetMetalGraphicsRequirementsKHR =
+                load_ext_fn(inst, b"xrGetMetalGraphicsRequirementsKHR\0")?;
+            let mut x = sys::GraphicsRequirementsMetalKHR::out(ptr::null_mut());
+            cvt(get_metal_graphics_requirements(
+                inst.as_raw(),
+                system,
+                x.as_mut_ptr(),
+            ))?;
+            x.assume_init()
+        };
+        Ok(Requirements {
+            metal_device: out.metal_device,
+        })
+    }
+
+    unsafe fn create_session(
+        instance: &Instance,
+        system: SystemId,
+        info: &Self::SessionCreateInfo,
+    ) -> Result<sys::Session> {
+        let binding = sys::GraphicsBindingMetalKHR {
+            ty: sys::GraphicsBindingMetalKHR::TYPE,
+            next: ptr::null(),
+            command_queue: info.command_queue,
+        };
+        let info = sys::SessionCreateInfo {
+            ty: sys::SessionCreateInfo::TYPE,
+            next: &binding as *const _ as *const _,
+            create_flags: Default::default(),
+            system_id: system,
+        };
+        let mut out = sys::Session::NULL;
+        cvt((instance.fp().create_session)(
+            instance.as_raw(),
+            &info,
+            &mut out,
+        ))?;
+        Ok(out)
+    }
+
+    fn enumerate_swapchain_images(
+        swapchain: &Swapchain<Self>,
+    ) -> Result<Vec<Self::SwapchainImage>> {
+        let images = get_arr_init(
+            sys::SwapchainImageMetalKHR {
+                ty: sys::SwapchainImageMetalKHR::TYPE,
+                next: ptr::null(),
+                texture: ptr::null_mut(),
+            },
+            |capacity, count, buf| unsafe {
+                (swapchain.instance().fp().enumerate_swapchain_images)(
+                    swapchain.as_raw(),
+                    capacity,
+                    count,
+                    buf as *mut _,
+                )
+            },
+        )?;
+        Ok(images
+            .into_iter()
+            .map(|x| unsafe { x.assume_init().texture })
+            .collect())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Requirements {
+    /// The `id<MTLDevice>` the session's command queue must be created from
+    pub metal_device: *mut c_void,
+}
+
+#[derive(Copy, Clone)]
+pub struct SessionCreateInfo {
+    /// An `id<MTLCommandQueue>` created from [`Requirements::metal_device`]
+    pub command_queue: *mut c_void,
+}
+
+mod pfn {
+    use crate::sys;
+
+    pub type GetMetalGraphicsRequirementsKHR = unsafe extern "system" fn(
+        sys::Instance,
+        sys::SystemId,
+        *mut sys::GraphicsRequirementsMetalKHR,
+    ) -> sys::Result;
+}
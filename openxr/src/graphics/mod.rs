@@ -1,9 +1,81 @@
+use std::{fmt, os::raw::c_void};
+
 use crate::*;
 
 /// Static dispatch for OpenXR graphics bindings
 ///
-/// The types and functions defined by this trait are an implementation detail, and should not be
-/// referenced externally.
+/// Implemented for each graphics API the runtime may bind a session to. Applications may implement
+/// it for APIs this crate doesn't cover, e.g. those of vendor extensions, with the help of
+/// [`create_session_with_binding`] and [`enumerate_swapchain_images_as`]:
+///
+/// ```no_run
+/// use openxr as xr;
+/// use std::os::raw::c_void;
+///
+/// /// A graphics API introduced by a hypothetical vendor extension
+/// enum Custom {}
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone)]
+/// struct GraphicsBindingCustom {
+///     ty: xr::sys::StructureType,
+///     next: *const c_void,
+///     device: *mut c_void,
+/// }
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone)]
+/// struct SwapchainImageCustom {
+///     ty: xr::sys::StructureType,
+///     next: *mut c_void,
+///     image: u64,
+/// }
+///
+/// impl xr::Graphics for Custom {
+///     type Requirements = ();
+///     type SessionCreateInfo = *mut c_void;
+///     type Format = u32;
+///     type SwapchainImage = u64;
+///
+///     fn raise_format(x: i64) -> u32 {
+///         x as _
+///     }
+///     fn lower_format(x: u32) -> i64 {
+///         x.into()
+///     }
+///
+///     fn requirements(_: &xr::Instance, _: xr::SystemId) -> xr::Result<()> {
+///         Ok(())
+///     }
+///
+///     unsafe fn create_session(
+///         instance: &xr::Instance,
+///         system: xr::SystemId,
+///         device: &*mut c_void,
+///     ) -> xr::Result<xr::sys::Session> {
+///         let binding = GraphicsBindingCustom {
+///             ty: xr::sys::StructureType::from_raw(1_999_000_000),
+///             next: std::ptr::null(),
+///             device: *device,
+///         };
+///         xr::create_session_with_binding(instance, system, &binding as *const _ as *const _)
+///     }
+///
+///     fn enumerate_swapchain_images(swapchain: &xr::Swapchain<Self>) -> xr::Result<Vec<u64>> {
+///         let images = unsafe {
+///             xr::enumerate_swapchain_images_as(
+///                 swapchain,
+///                 SwapchainImageCustom {
+///                     ty: xr::sys::StructureType::from_raw(1_999_000_001),
+///                     next: std::ptr::null_mut(),
+///                     image: 0,
+///                 },
+///             )?
+///         };
+///         Ok(images.into_iter().map(|x| x.image).collect())
+///     }
+/// }
+/// ```
 pub trait Graphics: Sized {
     /// Compatibility details within this graphics API
     type Requirements;
@@ -14,26 +86,211 @@ pub trait Graphics: Sized {
     /// Identifiers for images to render to
     type SwapchainImage;
 
-    #[doc(hidden)]
+    /// Convert a format as enumerated by the runtime
     fn raise_format(x: i64) -> Self::Format;
-    #[doc(hidden)]
+    /// Convert a format to pass to the runtime
     fn lower_format(x: Self::Format) -> i64;
 
-    #[doc(hidden)]
+    /// Query the runtime's requirements for this graphics API on `system`
+    ///
+    /// Backs [`Instance::graphics_requirements`].
     fn requirements(instance: &Instance, system: SystemId) -> Result<Self::Requirements>;
 
-    #[doc(hidden)]
+    /// Create a session bound to the graphics device described by `info`
+    ///
+    /// Backs [`Instance::create_session`].
+    ///
+    /// # Safety
+    ///
+    /// `info` must describe valid graphics objects meeting the requirements of the API's extension.
     unsafe fn create_session(
         instance: &Instance,
         system: SystemId,
         info: &Self::SessionCreateInfo,
     ) -> Result<sys::Session>;
 
-    #[doc(hidden)]
+    /// Retrieve the images of `swapchain`
+    ///
+    /// Backs [`Swapchain::enumerate_images`].
     fn enumerate_swapchain_images(swapchain: &Swapchain<Self>)
         -> Result<Vec<Self::SwapchainImage>>;
 }
 
+/// Create a raw session with `binding` chained onto its `XrSessionCreateInfo`
+///
+/// Helper for implementing [`Graphics::create_session`].
+///
+/// # Safety
+///
+/// `binding` must point to a valid graphics binding structure, such as
+/// `XrGraphicsBindingVulkanKHR`, or be null for extensions that allow sessions without one.
+pub unsafe fn create_session_with_binding(
+    instance: &Instance,
+    system: SystemId,
+    binding: *const c_void,
+) -> Result<sys::Session> {
+    let info = sys::SessionCreateInfo {
+        ty: sys::SessionCreateInfo::TYPE,
+        next: binding,
+        create_flags: Default::default(),
+        system_id: system,
+    };
+    let mut out = sys::Session::NULL;
+    cvt((instance.fp().create_session)(
+        instance.as_raw(),
+        &info,
+        &mut out,
+    ))?;
+    Ok(out)
+}
+
+/// Retrieve the images of `swapchain` as copies of `init` filled in by the runtime
+///
+/// Helper for implementing [`Graphics::enumerate_swapchain_images`].
+///
+/// # Safety
+///
+/// `T` must be the `XrSwapchainImage*` structure matching the swapchain's graphics API, and `init`
+/// must have its `type` field set accordingly.
+pub unsafe fn enumerate_swapchain_images_as<G: Graphics, T: Copy>(
+    swapchain: &Swapchain<G>,
+    init: T,
+) -> Result<Vec<T>> {
+    get_arr_init(init, |capacity, count, buf| {
+        (swapchain.instance().fp().enumerate_swapchain_images)(
+            swapchain.as_raw(),
+            capacity,
+            count,
+            buf as *mut _,
+        )
+    })
+}
+
+/// Combine the native flags corresponding to each swapchain usage flag set in `usage`
+fn translate_usage(usage: SwapchainUsageFlags, table: &[(SwapchainUsageFlags, u64)]) -> u64 {
+    table
+        .iter()
+        .filter(|&&(flag, _)| usage.contains(flag))
+        .fold(0, |acc, &(_, native)| acc | native)
+}
+
+/// A graphics binding that the runtime would reject, found before creating a session
+///
+/// Produced by [`Vulkan::validate_session_create_info`], [`OpenGlEs::validate_context`] and the D3D
+/// backends' `validate_device`.
+/// Runtimes report all of these as `ERROR_GRAPHICS_DEVICE_INVALID`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphicsBindingError {
+    /// The runtime reported an error while querying its requirements
+    Runtime(sys::Result),
+    /// The device supports an older graphics API version than the runtime requires
+    ApiVersion { version: Version, min: Version },
+    /// The Vulkan physical device isn't the one the runtime selected for the system
+    PhysicalDevice,
+    /// The Vulkan queue family index is out of range
+    QueueFamily { index: u32, count: u32 },
+    /// The Vulkan queue family doesn't support graphics operations
+    QueueFamilyNotGraphics { index: u32 },
+    /// The Vulkan queue index is out of range for its family
+    QueueIndex { index: u32, count: u32 },
+    /// The Vulkan device wasn't created with an extension the runtime requires
+    MissingDeviceExtension(String),
+    /// The D3D device wasn't created on the adapter the runtime uses for the system
+    Adapter,
+    /// The D3D device's `D3D_FEATURE_LEVEL` is lower than the runtime requires
+    FeatureLevel { level: i32, min: i32 },
+    /// A required graphics handle is null
+    NullHandle(&'static str),
+    /// The EGL context's `EGL_CONTEXT_CLIENT_TYPE` isn't `EGL_OPENGL_ES_API`
+    ContextClientType { client_type: u32 },
+    /// The EGL config's `EGL_RENDERABLE_TYPE` lacks the bit for the context's OpenGL ES version
+    ConfigRenderableType { renderable_type: u32, required: u32 },
+}
+
+impl fmt::Display for GraphicsBindingError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Runtime(e) => e.fmt(fmt),
+            Self::ApiVersion { version, min } => write!(
+                fmt,
+                "graphics API version {} is older than the minimum {} required by the runtime",
+                version, min
+            ),
+            Self::PhysicalDevice => fmt.write_str(
+                "the physical device differs from the one returned by vulkan_graphics_device",
+            ),
+            Self::QueueFamily { index, count } => write!(
+                fmt,
+                "queue family index {} is out of range for a device with {} queue families",
+                index, count
+            ),
+            Self::QueueFamilyNotGraphics { index } => write!(
+                fmt,
+                "queue family {} does not support graphics operations",
+                index
+            ),
+            Self::QueueIndex { index, count } => write!(
+                fmt,
+                "queue index {} is out of range for a queue family with {} queues",
+                index, count
+            ),
+            Self::MissingDeviceExtension(name) => write!(
+                fmt,
+                "the device was not created with the {} extension required by the runtime",
+                name
+            ),
+            Self::Adapter => fmt.write_str(
+                "the device was not created on the adapter identified by the runtime's requirements",
+            ),
+            Self::FeatureLevel { level, min } => write!(
+                fmt,
+                "feature level {:#x} is lower than the minimum {:#x} required by the runtime",
+                level, min
+            ),
+            Self::NullHandle(name) => write!(fmt, "the {} is null", name),
+            Self::ContextClientType { client_type } => write!(
+                fmt,
+                "the EGL context's client API {:#x} is not OpenGL ES",
+                client_type
+            ),
+            Self::ConfigRenderableType {
+                renderable_type,
+                required,
+            } => write!(
+                fmt,
+                "the EGL config's renderable type {:#x} lacks the required bit {:#x}",
+                renderable_type, required
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GraphicsBindingError {}
+
+impl From<sys::Result> for GraphicsBindingError {
+    fn from(e: sys::Result) -> Self {
+        Self::Runtime(e)
+    }
+}
+
+impl From<GraphicsBindingError> for sys::Result {
+    /// Collapse into the OpenXR result session creation would have failed with
+    fn from(e: GraphicsBindingError) -> Self {
+        match e {
+            GraphicsBindingError::Runtime(e) => e,
+            _ => sys::Result::ERROR_GRAPHICS_DEVICE_INVALID,
+        }
+    }
+}
+
+/// Check a graphics API version against a runtime's minimum, ignoring patch versions
+fn check_api_version(version: Version, min: Version) -> Result<(), GraphicsBindingError> {
+    if (version.major(), version.minor()) < (min.major(), min.minor()) {
+        return Err(GraphicsBindingError::ApiVersion { version, min });
+    }
+    Ok(())
+}
+
 #[cfg(windows)]
 pub mod d3d;
 #[cfg(windows)]
@@ -41,6 +298,11 @@ pub use d3d::D3D11;
 #[cfg(windows)]
 pub use d3d::D3D12;
 
+#[cfg(target_vendor = "apple")]
+pub mod metal;
+#[cfg(target_vendor = "apple")]
+pub use metal::Metal;
+
 pub mod vulkan;
 pub use vulkan::Vulkan;
 
@@ -49,3 +311,9 @@ pub use opengl::OpenGL;
 
 pub mod opengles;
 pub use opengles::OpenGlEs;
+
+pub mod egl;
+pub use egl::OpenGlEgl;
+
+pub mod headless;
+pub use headless::Headless;
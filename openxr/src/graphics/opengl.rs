@@ -105,11 +105,7 @@ impl Graphics for OpenGL {
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
         let images = get_arr_init(
-            sys::SwapchainImageOpenGLKHR {
-                ty: sys::SwapchainImageOpenGLKHR::TYPE,
-                next: ptr::null_mut(),
-                image: 0,
-            },
+            sys::SwapchainImageOpenGLKHR::out(ptr::null_mut()),
             |capacity, count, buf| unsafe {
                 (swapchain.instance().fp().enumerate_swapchain_images)(
                     swapchain.as_raw(),
@@ -119,7 +115,10 @@ impl Graphics for OpenGL {
                 )
             },
         )?;
-        Ok(images.into_iter().map(|x| x.image).collect())
+        Ok(images
+            .into_iter()
+            .map(|x| unsafe { x.assume_init().image })
+            .collect())
     }
 }
 
@@ -140,3 +139,46 @@ pub enum SessionCreateInfo {
     #[cfg(windows)]
     Windows { h_dc: HDC, h_glrc: HGLRC },
 }
+
+impl OpenGL {
+    /// The operations swapchain images created with `usage` may be used for
+    ///
+    /// OpenGL textures carry no usage flags, so nothing prevents other uses, but their behavior is
+    /// undefined.
+    pub fn texture_usage(usage: SwapchainUsageFlags) -> TextureUsage {
+        TextureUsage::new(usage)
+    }
+}
+
+/// Operations permitted on a swapchain texture, as returned by [`OpenGL::texture_usage`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TextureUsage {
+    /// May be attached to a framebuffer's color attachment points
+    pub color_attachment: bool,
+    /// May be attached to a framebuffer's depth and/or stencil attachment points
+    pub depth_stencil_attachment: bool,
+    /// May be sampled by shaders
+    pub sampled: bool,
+    /// May be bound with `glBindImageTexture`
+    pub image_load_store: bool,
+    /// May be read by copies and blits
+    pub copy_src: bool,
+    /// May be written by copies and blits
+    pub copy_dst: bool,
+    /// May be reinterpreted with `glTextureView`
+    pub texture_view: bool,
+}
+
+impl TextureUsage {
+    pub(crate) fn new(usage: SwapchainUsageFlags) -> Self {
+        Self {
+            color_attachment: usage.contains(SwapchainUsageFlags::COLOR_ATTACHMENT),
+            depth_stencil_attachment: usage.contains(SwapchainUsageFlags::DEPTH_STENCIL_ATTACHMENT),
+            sampled: usage.contains(SwapchainUsageFlags::SAMPLED),
+            image_load_store: usage.contains(SwapchainUsageFlags::UNORDERED_ACCESS),
+            copy_src: usage.contains(SwapchainUsageFlags::TRANSFER_SRC),
+            copy_dst: usage.contains(SwapchainUsageFlags::TRANSFER_DST),
+            texture_view: usage.contains(SwapchainUsageFlags::MUTABLE_FORMAT),
+        }
+    }
+}
@@ -5,6 +5,8 @@ use sys::platform::*;
 
 use crate::*;
 
+pub use super::opengl::TextureUsage;
+
 /// The OpenGL ES graphics API
 ///
 /// See [`XR_KHR_opengl_es_enable`] for safety details.
@@ -82,11 +84,7 @@ impl Graphics for OpenGlEs {
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
         let images = get_arr_init(
-            sys::SwapchainImageOpenGLESKHR {
-                ty: sys::SwapchainImageOpenGLESKHR::TYPE,
-                next: ptr::null_mut(),
-                image: 0,
-            },
+            sys::SwapchainImageOpenGLESKHR::out(ptr::null_mut()),
             |capacity, count, buf| unsafe {
                 (swapchain.instance().fp().enumerate_swapchain_images)(
                     swapchain.as_raw(),
@@ -96,7 +94,10 @@ impl Graphics for OpenGlEs {
                 )
             },
         )?;
-        Ok(images.into_iter().map(|x| x.image).collect())
+        Ok(images
+            .into_iter()
+            .map(|x| unsafe { x.assume_init().image })
+            .collect())
     }
 }
 
@@ -114,3 +115,157 @@ pub enum SessionCreateInfo {
         context: EGLContext,
     },
 }
+
+impl OpenGlEs {
+    /// The operations swapchain images created with `usage` may be used for
+    ///
+    /// See [`OpenGL::texture_usage`].
+    pub fn texture_usage(usage: SwapchainUsageFlags) -> TextureUsage {
+        TextureUsage::new(usage)
+    }
+
+    /// Check an EGL context against the requirements of `system`, describing problems that
+    /// [`Instance::create_session`] would only report as `ERROR_GRAPHICS_DEVICE_INVALID`
+    ///
+    /// Also satisfies the requirement to query [`Instance::graphics_requirements`] before
+    /// creating a session.
+    pub fn validate_context(
+        instance: &Instance,
+        system: SystemId,
+        context: &ContextDescription,
+    ) -> Result<(), GraphicsBindingError> {
+        let requirements = instance.graphics_requirements::<OpenGlEs>(system)?;
+        if context.client_type != egl::OPENGL_ES_API {
+            return Err(GraphicsBindingError::ContextClientType {
+                client_type: context.client_type,
+            });
+        }
+        super::check_api_version(context.api_version, requirements.min_api_version_supported)?;
+        let required_bit = renderable_bit(context.api_version);
+        if context.config_renderable_type & required_bit == 0 {
+            return Err(GraphicsBindingError::ConfigRenderableType {
+                renderable_type: context.config_renderable_type,
+                required: required_bit,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the handles of `info` and the context they refer to
+    ///
+    /// See [`OpenGlEs::validate_context`].
+    #[cfg(target_os = "android")]
+    pub fn validate_session_create_info(
+        instance: &Instance,
+        system: SystemId,
+        info: &SessionCreateInfo,
+        context: &ContextDescription,
+    ) -> Result<(), GraphicsBindingError> {
+        match *info {
+            SessionCreateInfo::Android {
+                display,
+                config,
+                context: egl_context,
+            } => {
+                if display.is_null() {
+                    return Err(GraphicsBindingError::NullHandle("EGLDisplay"));
+                }
+                if config.is_null() {
+                    return Err(GraphicsBindingError::NullHandle("EGLConfig"));
+                }
+                if egl_context.is_null() {
+                    return Err(GraphicsBindingError::NullHandle("EGLContext"));
+                }
+            }
+        }
+        Self::validate_context(instance, system, context)
+    }
+
+    /// Attributes for `eglChooseConfig` selecting configs that can render the OpenGL ES version
+    /// `requirements` calls for
+    ///
+    /// Selects 8-bit RGBA configs without depth, stencil or multisampling, since rendering targets
+    /// swapchain images rather than the config's surface, and with pbuffer support for the small
+    /// surface some drivers need to make a context current.
+    pub fn config_attributes(requirements: &Requirements) -> [i32; 19] {
+        [
+            egl::RED_SIZE,
+            8,
+            egl::GREEN_SIZE,
+            8,
+            egl::BLUE_SIZE,
+            8,
+            egl::ALPHA_SIZE,
+            8,
+            egl::DEPTH_SIZE,
+            0,
+            egl::STENCIL_SIZE,
+            0,
+            egl::SAMPLES,
+            0,
+            egl::RENDERABLE_TYPE,
+            renderable_bit(requirements.min_api_version_supported) as i32,
+            egl::SURFACE_TYPE,
+            egl::PBUFFER_BIT,
+            egl::NONE,
+        ]
+    }
+
+    /// Attributes for `eglCreateContext` requesting the minimum OpenGL ES version `requirements`
+    /// allow
+    pub fn context_attributes(requirements: &Requirements) -> [i32; 3] {
+        [
+            egl::CONTEXT_CLIENT_VERSION,
+            i32::from(requirements.min_api_version_supported.major()),
+            egl::NONE,
+        ]
+    }
+
+    /// Attributes for `eglCreatePbufferSurface` creating a minimal surface to make a context
+    /// current with
+    pub fn pbuffer_attributes() -> [i32; 5] {
+        [egl::WIDTH, 16, egl::HEIGHT, 16, egl::NONE]
+    }
+}
+
+/// Properties of the EGL context bound to a session, for [`OpenGlEs::validate_context`]
+#[derive(Debug, Copy, Clone)]
+pub struct ContextDescription {
+    /// Version of the context, as reported by `glGetIntegerv` with `GL_MAJOR_VERSION` and
+    /// `GL_MINOR_VERSION` while it is current
+    pub api_version: Version,
+    /// `EGL_CONTEXT_CLIENT_TYPE`, as reported by `eglQueryContext`
+    pub client_type: u32,
+    /// `EGL_RENDERABLE_TYPE` of the context's config, as reported by `eglGetConfigAttrib`
+    pub config_renderable_type: u32,
+}
+
+/// The `EGL_RENDERABLE_TYPE` bit a config needs to support contexts of `version`
+fn renderable_bit(version: Version) -> u32 {
+    if version.major() >= 3 {
+        egl::OPENGL_ES3_BIT
+    } else {
+        egl::OPENGL_ES2_BIT
+    }
+}
+
+mod egl {
+    pub const OPENGL_ES_API: u32 = 0x30A0;
+    pub const OPENGL_ES2_BIT: u32 = 0x0004;
+    pub const OPENGL_ES3_BIT: u32 = 0x0040;
+
+    pub const ALPHA_SIZE: i32 = 0x3021;
+    pub const BLUE_SIZE: i32 = 0x3022;
+    pub const GREEN_SIZE: i32 = 0x3023;
+    pub const RED_SIZE: i32 = 0x3024;
+    pub const DEPTH_SIZE: i32 = 0x3025;
+    pub const STENCIL_SIZE: i32 = 0x3026;
+    pub const SURFACE_TYPE: i32 = 0x3033;
+    pub const NONE: i32 = 0x3038;
+    pub const RENDERABLE_TYPE: i32 = 0x3040;
+    pub const SAMPLES: i32 = 0x3031;
+    pub const HEIGHT: i32 = 0x3056;
+    pub const WIDTH: i32 = 0x3057;
+    pub const CONTEXT_CLIENT_VERSION: i32 = 0x3098;
+    pub const PBUFFER_BIT: i32 = 0x0001;
+}
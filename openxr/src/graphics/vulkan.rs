@@ -8,7 +8,16 @@ use crate::*;
 ///
 /// See [`XR_KHR_vulkan_enable2`] for safety details.
 ///
+/// Runtimes that only offer the original [`XR_KHR_vulkan_enable`] are supported too, as both
+/// extensions bind sessions the same way. If `khr_vulkan_enable2` isn't enabled, requirements and
+/// [`Instance::vulkan_graphics_device`] go through `khr_vulkan_enable` instead; the application
+/// then creates its Vulkan instance and device itself, enabling the extensions listed by
+/// [`Instance::vulkan_legacy_instance_extensions`] and
+/// [`Instance::vulkan_legacy_device_extensions`], rather than calling
+/// [`Instance::create_vulkan_instance`] and [`Instance::create_vulkan_device`].
+///
 /// [`XR_KHR_vulkan_enable2`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_vulkan_enable2
+/// [`XR_KHR_vulkan_enable`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_vulkan_enable
 pub enum Vulkan {}
 
 impl Graphics for Vulkan {
@@ -74,11 +83,7 @@ impl Graphics for Vulkan {
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
         let images = get_arr_init(
-            sys::SwapchainImageVulkanKHR {
-                ty: sys::SwapchainImageVulkanKHR::TYPE,
-                next: ptr::null_mut(),
-                image: 0,
-            },
+            sys::SwapchainImageVulkanKHR::out(ptr::null_mut()),
             |capacity, count, buf| unsafe {
                 (swapchain.instance().fp().enumerate_swapchain_images)(
                     swapchain.as_raw(),
@@ -88,7 +93,10 @@ impl Graphics for Vulkan {
                 )
             },
         )?;
-        Ok(images.into_iter().map(|x| x.image as _).collect())
+        Ok(images
+            .into_iter()
+            .map(|x| unsafe { x.assume_init().image as _ })
+            .collect())
     }
 }
 
@@ -106,3 +114,124 @@ pub struct SessionCreateInfo {
     pub queue_family_index: u32,
     pub queue_index: u32,
 }
+
+impl Vulkan {
+    /// The Vulkan image parameters the runtime uses for swapchain images created with `usage`
+    ///
+    /// Images imported into other code must not be used in ways these flags don't permit.
+    pub fn image_usage(usage: SwapchainUsageFlags) -> ImageUsage {
+        ImageUsage {
+            usage: super::translate_usage(
+                usage,
+                &[
+                    (SwapchainUsageFlags::TRANSFER_SRC, 0x0000_0001),
+                    (SwapchainUsageFlags::TRANSFER_DST, 0x0000_0002),
+                    (SwapchainUsageFlags::SAMPLED, 0x0000_0004),
+                    (SwapchainUsageFlags::UNORDERED_ACCESS, 0x0000_0008),
+                    (SwapchainUsageFlags::COLOR_ATTACHMENT, 0x0000_0010),
+                    (SwapchainUsageFlags::DEPTH_STENCIL_ATTACHMENT, 0x0000_0020),
+                    (SwapchainUsageFlags::INPUT_ATTACHMENT, 0x0000_0080),
+                ],
+            ),
+            flags: super::translate_usage(
+                usage,
+                &[(SwapchainUsageFlags::MUTABLE_FORMAT, 0x0000_0008)],
+            ),
+        }
+    }
+
+    /// Check `info` against the requirements of `system`, describing problems that
+    /// [`Instance::create_session`] would only report as `ERROR_GRAPHICS_DEVICE_INVALID`
+    ///
+    /// Also satisfies the requirement to query [`Instance::graphics_requirements`] before
+    /// creating a session. When only `XR_KHR_vulkan_enable` is in use, the device must have been
+    /// created with every extension listed by [`Instance::vulkan_legacy_device_extensions`];
+    /// `XR_KHR_vulkan_enable2` adds them itself through [`Instance::create_vulkan_device`].
+    ///
+    /// # Safety
+    ///
+    /// `info.instance` must be a valid Vulkan instance, as for
+    /// [`Instance::vulkan_graphics_device`].
+    pub unsafe fn validate_session_create_info(
+        instance: &Instance,
+        system: SystemId,
+        info: &SessionCreateInfo,
+        device: &DeviceDescription<'_>,
+    ) -> Result<(), GraphicsBindingError> {
+        let requirements = instance.graphics_requirements::<Vulkan>(system)?;
+        super::check_api_version(
+            version_from_vk(device.api_version),
+            requirements.min_api_version_supported,
+        )?;
+        if instance.vulkan_graphics_device(system, info.instance)? != info.physical_device {
+            return Err(GraphicsBindingError::PhysicalDevice);
+        }
+        let family = device
+            .queue_families
+            .get(info.queue_family_index as usize)
+            .ok_or(GraphicsBindingError::QueueFamily {
+                index: info.queue_family_index,
+                count: device.queue_families.len() as u32,
+            })?;
+        const VK_QUEUE_GRAPHICS_BIT: u32 = 0x0000_0001;
+        if family.queue_flags & VK_QUEUE_GRAPHICS_BIT == 0 {
+            return Err(GraphicsBindingError::QueueFamilyNotGraphics {
+                index: info.queue_family_index,
+            });
+        }
+        if info.queue_index >= family.queue_count {
+            return Err(GraphicsBindingError::QueueIndex {
+                index: info.queue_index,
+                count: family.queue_count,
+            });
+        }
+        if instance.exts().khr_vulkan_enable2.is_none() {
+            let required = instance.vulkan_legacy_device_extensions(system)?;
+            if let Some(missing) = required
+                .split_whitespace()
+                .find(|name| !device.enabled_extensions.contains(name))
+            {
+                return Err(GraphicsBindingError::MissingDeviceExtension(missing.into()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decode a version number in Vulkan's `VK_MAKE_API_VERSION` encoding
+fn version_from_vk(version: u32) -> Version {
+    Version::new(
+        ((version >> 22) & 0x7f) as u16,
+        ((version >> 12) & 0x3ff) as u16,
+        version & 0xfff,
+    )
+}
+
+/// Properties of the Vulkan device described by a [`SessionCreateInfo`], for
+/// [`Vulkan::validate_session_create_info`]
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceDescription<'a> {
+    /// `VkPhysicalDeviceProperties::apiVersion`
+    pub api_version: u32,
+    /// The physical device's queue families, as returned by
+    /// `vkGetPhysicalDeviceQueueFamilyProperties`
+    pub queue_families: &'a [QueueFamily],
+    /// `VkDeviceCreateInfo::ppEnabledExtensionNames`
+    pub enabled_extensions: &'a [&'a str],
+}
+
+/// The parts of `VkQueueFamilyProperties` relevant to session creation
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct QueueFamily {
+    pub queue_flags: u32,
+    pub queue_count: u32,
+}
+
+/// Native parameters of a swapchain image, as returned by [`Vulkan::image_usage`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ImageUsage {
+    /// `VkImageCreateInfo::usage`
+    pub usage: VkImageUsageFlags,
+    /// `VkImageCreateInfo::flags`
+    pub flags: VkImageCreateFlags,
+}
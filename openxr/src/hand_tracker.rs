@@ -1,4 +1,4 @@
-use std::{ptr, sync::Arc};
+use std::{mem, ptr, sync::Arc};
 
 use crate::*;
 
@@ -20,6 +20,19 @@ impl HandTracker {
         self.handle
     }
 
+    /// Give up ownership of the handle without destroying it
+    ///
+    /// The caller becomes responsible for destroying the returned handle.
+    #[inline]
+    pub fn into_raw(self) -> sys::HandTrackerEXT {
+        let mut this = mem::ManuallyDrop::new(self);
+        // Release everything but the handle, which `Drop` would destroy
+        unsafe {
+            ptr::drop_in_place(&mut this.session);
+        }
+        this.handle
+    }
+
     /// Take ownership of an existing hand tracker
     ///
     /// # Safety
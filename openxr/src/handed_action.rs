@@ -0,0 +1,116 @@
+use crate::interaction_profiles::{LEFT_HAND, RIGHT_HAND};
+use crate::*;
+
+/// An action with a subaction path for each hand, so that one action can be bound to both
+/// controllers and queried for either
+///
+/// # Example
+///
+/// ```no_run
+/// # fn f(
+/// #     session: &openxr::Session<openxr::Headless>,
+/// #     set: &openxr::ActionSet,
+/// #     stage: &openxr::Space,
+/// #     time: openxr::Time,
+/// # ) -> openxr::Result<()> {
+/// use openxr::Hand;
+///
+/// let grip = openxr::HandedAction::<openxr::Posef>::new(set, "grip", "Grip")?;
+/// // Suggest bindings for `grip.action()`, then attach `set`
+/// let left = grip.create_space(session, Hand::LEFT, openxr::Posef::IDENTITY)?;
+/// // Each frame
+/// if grip.is_active(session, Hand::LEFT)? {
+///     println!("left hand at {:?}", left.locate(stage, time)?.pose);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct HandedAction<T: ActionTy> {
+    action: Action<T>,
+    subaction_paths: [Path; 2],
+}
+
+impl<T: ActionTy> HandedAction<T> {
+    /// Create an action in `set` with subaction paths `/user/hand/left` and `/user/hand/right`
+    pub fn new(set: &ActionSet, name: &str, localized_name: &str) -> Result<Self> {
+        let instance = set.instance();
+        let subaction_paths = [
+            instance.string_to_path(LEFT_HAND)?,
+            instance.string_to_path(RIGHT_HAND)?,
+        ];
+        Ok(Self {
+            action: set.create_action(name, localized_name, &subaction_paths)?,
+            subaction_paths,
+        })
+    }
+
+    /// The underlying action, e.g. for suggesting bindings
+    #[inline]
+    pub fn action(&self) -> &Action<T> {
+        &self.action
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `hand` is neither `Hand::LEFT` nor `Hand::RIGHT`.
+    #[inline]
+    pub fn subaction_path(&self, hand: Hand) -> Path {
+        match hand {
+            Hand::LEFT => self.subaction_paths[0],
+            Hand::RIGHT => self.subaction_paths[1],
+            _ => panic!("unknown hand {:?}", hand),
+        }
+    }
+
+    pub fn into_inner(self) -> Action<T> {
+        self.action
+    }
+}
+
+impl<T: ActionInput> HandedAction<T> {
+    #[inline]
+    pub fn state<G>(&self, session: &Session<G>, hand: Hand) -> Result<ActionState<T>> {
+        self.action.state(session, self.subaction_path(hand))
+    }
+}
+
+impl HandedAction<Posef> {
+    /// Create a space tracking `hand`'s pose
+    #[inline]
+    pub fn create_space<G>(
+        &self,
+        session: &Session<G>,
+        hand: Hand,
+        pose_in_action_space: Posef,
+    ) -> Result<Space> {
+        self.action.create_space(
+            session.clone(),
+            self.subaction_path(hand),
+            pose_in_action_space,
+        )
+    }
+
+    #[inline]
+    pub fn is_active<G>(&self, session: &Session<G>, hand: Hand) -> Result<bool> {
+        self.action.is_active(session, self.subaction_path(hand))
+    }
+}
+
+impl HandedAction<Haptic> {
+    #[inline]
+    pub fn apply_feedback<G>(
+        &self,
+        session: &Session<G>,
+        hand: Hand,
+        event: &HapticBase,
+    ) -> Result<()> {
+        self.action
+            .apply_feedback(session, self.subaction_path(hand), event)
+    }
+
+    #[inline]
+    pub fn stop_feedback<G>(&self, session: &Session<G>, hand: Hand) -> Result<()> {
+        self.action
+            .stop_feedback(session, self.subaction_path(hand))
+    }
+}
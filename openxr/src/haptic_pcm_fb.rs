@@ -0,0 +1,165 @@
+//! Streaming audio-derived vibrations with [`XR_FB_haptic_pcm`].
+//!
+//! Runtimes consume PCM haptic buffers at a device-specific sample rate, accept at most
+//! [`sys::MAX_HAPTIC_PCM_BUFFER_SIZE_FB`] samples per submission, and may accept fewer samples than
+//! offered. [`HapticPcmQueueFB`] takes care of resampling and of resubmitting whatever wasn't
+//! consumed.
+//!
+//! [`XR_FB_haptic_pcm`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_FB_haptic_pcm
+
+use std::ptr;
+
+use crate::*;
+
+impl Action<Haptic> {
+    /// Sample rate at which the device bound to this action plays PCM haptics
+    ///
+    /// Requires XR_FB_haptic_pcm.
+    pub fn device_sample_rate_fb<G>(
+        &self,
+        session: &Session<G>,
+        subaction_path: Path,
+    ) -> Result<f32> {
+        let ext = self
+            .instance()
+            .exts()
+            .fb_haptic_pcm
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::HapticActionInfo {
+            ty: sys::HapticActionInfo::TYPE,
+            next: ptr::null(),
+            action: self.as_raw(),
+            subaction_path,
+        };
+        let mut out = sys::DevicePcmSampleRateStateFB::out(ptr::null_mut());
+        unsafe {
+            cvt((ext.get_device_sample_rate)(
+                session.as_raw(),
+                &info,
+                out.as_mut_ptr(),
+            ))?;
+            Ok(out.assume_init().sample_rate)
+        }
+    }
+}
+
+/// Converts a stream of samples between sample rates by linear interpolation
+///
+/// State is carried across calls to [`PcmResampler::process`], so a stream may be fed in
+/// arbitrarily sized pieces.
+#[derive(Debug, Copy, Clone)]
+pub struct PcmResampler {
+    /// Input samples advanced per output sample
+    step: f64,
+    /// Position of the next output sample between `prev` and the next input sample
+    phase: f64,
+    prev: Option<f32>,
+}
+
+impl PcmResampler {
+    pub fn new(source_rate: f32, target_rate: f32) -> Self {
+        assert!(
+            source_rate > 0.0 && target_rate > 0.0,
+            "sample rates must be positive"
+        );
+        Self {
+            step: f64::from(source_rate) / f64::from(target_rate),
+            phase: 0.0,
+            prev: None,
+        }
+    }
+
+    /// Resample `input`, appending the result to `output`
+    pub fn process(&mut self, input: &[f32], output: &mut Vec<f32>) {
+        output.reserve((input.len() as f64 / self.step).ceil() as usize);
+        for &sample in input {
+            if let Some(prev) = self.prev {
+                while self.phase < 1.0 {
+                    output.push(prev + (sample - prev) * self.phase as f32);
+                    self.phase += self.step;
+                }
+                self.phase -= 1.0;
+            }
+            self.prev = Some(sample);
+        }
+    }
+
+    /// Forget buffered state, e.g. before starting an unrelated stream
+    pub fn reset(&mut self) {
+        self.phase = 0.0;
+        self.prev = None;
+    }
+}
+
+/// Buffers PCM haptic samples and submits them to the runtime in acceptable chunks
+///
+/// Push audio at its native sample rate with [`HapticPcmQueueFB::push`], then call
+/// [`HapticPcmQueueFB::submit`] regularly (e.g. once per frame) until
+/// [`HapticPcmQueueFB::pending`] drops to zero.
+#[derive(Debug, Clone)]
+pub struct HapticPcmQueueFB {
+    resampler: PcmResampler,
+    sample_rate: f32,
+    pending: Vec<f32>,
+}
+
+impl HapticPcmQueueFB {
+    /// Create a queue converting from `source_rate` to `device_rate`
+    ///
+    /// `device_rate` should come from [`Action::device_sample_rate_fb`].
+    pub fn new(source_rate: f32, device_rate: f32) -> Self {
+        Self {
+            resampler: PcmResampler::new(source_rate, device_rate),
+            sample_rate: device_rate,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Queue samples for playback
+    pub fn push(&mut self, samples: &[f32]) {
+        self.resampler.process(samples, &mut self.pending);
+    }
+
+    /// Number of resampled samples not yet accepted by the runtime
+    #[inline]
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Drop all queued samples
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.resampler.reset();
+    }
+
+    /// Offer queued samples to the runtime, appending them to any vibration already playing
+    ///
+    /// Returns the number of samples the runtime accepted, which are removed from the queue.
+    ///
+    /// Requires XR_FB_haptic_pcm.
+    pub fn submit<G>(
+        &mut self,
+        action: &Action<Haptic>,
+        session: &Session<G>,
+        subaction_path: Path,
+    ) -> Result<usize> {
+        if self.pending.is_empty() {
+            return Ok(0);
+        }
+        let len = self.pending.len().min(sys::MAX_HAPTIC_PCM_BUFFER_SIZE_FB);
+        let mut consumed = 0;
+        action.apply_feedback(
+            session,
+            subaction_path,
+            &HapticPcmVibrationFB::new()
+                .buffer(&self.pending[..len])
+                .sample_rate(self.sample_rate)
+                .append(true)
+                .samples_consumed(&mut consumed),
+        )?;
+        let consumed = (consumed as usize).min(len);
+        self.pending.drain(..consumed);
+        Ok(consumed)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::*;
+
+impl Action<Haptic> {
+    /// Describe feedback for the device bound to this action for `subaction_path`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn f(
+    /// #     session: &openxr::Session<openxr::Headless>,
+    /// #     rumble: &openxr::Action<openxr::Haptic>,
+    /// #     left_hand: openxr::Path,
+    /// # ) -> openxr::Result<()> {
+    /// rumble
+    ///     .haptics(session, left_hand)
+    ///     .duration(openxr::Duration::from_nanos(100_000_000))
+    ///     .amplitude(0.5)
+    ///     .vibrate()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn haptics<'a, G>(
+        &'a self,
+        session: &'a Session<G>,
+        subaction_path: Path,
+    ) -> Haptics<'a, G> {
+        Haptics {
+            action: self,
+            session,
+            subaction_path,
+            duration: Duration::MIN_HAPTIC,
+            frequency: FREQUENCY_UNSPECIFIED,
+            amplitude: 1.0,
+        }
+    }
+}
+
+/// Haptic feedback on one subaction path of an [`Action<Haptic>`], from [`Action::haptics`]
+///
+/// By default, the shortest pulse the device supports at full amplitude and a frequency of the
+/// runtime's choosing. The duration also applies to amplitude envelopes.
+pub struct Haptics<'a, G> {
+    action: &'a Action<Haptic>,
+    session: &'a Session<G>,
+    subaction_path: Path,
+    duration: Duration,
+    frequency: f32,
+    amplitude: f32,
+}
+
+impl<'a, G> Haptics<'a, G> {
+    /// `Duration::MIN_HAPTIC` for the shortest pulse the device supports
+    #[inline]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// In Hz, or `FREQUENCY_UNSPECIFIED` to let the runtime choose
+    #[inline]
+    pub fn frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Between 0 and 1
+    #[inline]
+    pub fn amplitude(mut self, amplitude: f32) -> Self {
+        debug_assert!(
+            (0.0..=1.0).contains(&amplitude),
+            "haptic amplitude {} is outside [0, 1]",
+            amplitude
+        );
+        self.amplitude = amplitude;
+        self
+    }
+
+    /// Play a vibration of the configured duration, frequency and amplitude, replacing any
+    /// feedback in progress
+    pub fn vibrate(&self) -> Result<()> {
+        let event = HapticVibration::new()
+            .duration(self.duration)
+            .frequency(self.frequency)
+            .amplitude(self.amplitude);
+        self.apply(&event)
+    }
+
+    /// Play `amplitudes`, spread evenly over the configured duration
+    ///
+    /// Requires XR_FB_haptic_amplitude_envelope.
+    pub fn amplitude_envelope_fb(&self, amplitudes: &[f32]) -> Result<()> {
+        if self
+            .action
+            .instance()
+            .exts()
+            .fb_haptic_amplitude_envelope
+            .is_none()
+        {
+            return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+        }
+        let event = builder::HapticAmplitudeEnvelopeVibrationFB::new()
+            .duration(self.duration)
+            .amplitudes(amplitudes);
+        self.apply(&event)
+    }
+
+    /// Play `buffer`, sampled at `sample_rate`, after any PCM feedback in progress if `append`
+    ///
+    /// Returns the number of samples the runtime accepted, which may be fewer than offered; see
+    /// [`HapticPcmQueueFB`] for streaming longer buffers. Requires XR_FB_haptic_pcm.
+    pub fn pcm_fb(&self, buffer: &[f32], sample_rate: f32, append: bool) -> Result<u32> {
+        if self.action.instance().exts().fb_haptic_pcm.is_none() {
+            return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+        }
+        let mut samples_consumed = 0;
+        let event = HapticPcmVibrationFB::new()
+            .buffer(buffer)
+            .sample_rate(sample_rate)
+            .append(append)
+            .samples_consumed(&mut samples_consumed);
+        self.apply(&event)?;
+        Ok(samples_consumed)
+    }
+
+    /// Stop any feedback in progress, e.g. a long or infinite vibration
+    #[inline]
+    pub fn stop(&self) -> Result<()> {
+        self.action.stop_feedback(self.session, self.subaction_path)
+    }
+
+    fn apply(&self, event: &HapticBase) -> Result<()> {
+        self.action
+            .apply_feedback(self.session, self.subaction_path, event)
+    }
+}
@@ -1,4 +1,4 @@
-use std::{ptr, sync::Arc};
+use std::{mem, ptr, sync::Arc};
 
 use crate::*;
 
@@ -14,6 +14,19 @@ impl FacialTrackerHTC {
         self.handle
     }
 
+    /// Give up ownership of the handle without destroying it
+    ///
+    /// The caller becomes responsible for destroying the returned handle.
+    #[inline]
+    pub fn into_raw(self) -> sys::FacialTrackerHTC {
+        let mut this = mem::ManuallyDrop::new(self);
+        // Release everything but the handle, which `Drop` would destroy
+        unsafe {
+            ptr::drop_in_place(&mut this.session);
+        }
+        this.handle
+    }
+
     /// Take ownership of an existing facial tracker
     ///
     /// # Safety
@@ -46,22 +59,19 @@ impl FacialTrackerHTC {
     pub fn get_facial_expressions(&self) -> Result<Option<FaceExpressionWeightsHTC>> {
         let mut weights = Vec::with_capacity(self.expression_count);
 
-        let mut facial_expressions = sys::FacialExpressionsHTC {
-            ty: sys::FacialExpressionsHTC::TYPE,
-            next: ptr::null_mut(),
-            is_active: sys::FALSE,
-            sample_time: Time::from_nanos(0),
-            expression_count: self.expression_count as u32,
-            expression_weightings: weights.as_mut_ptr(),
-        };
+        let mut facial_expressions = sys::FacialExpressionsHTC::out(ptr::null_mut());
 
         unsafe {
+            (*facial_expressions.as_mut_ptr()).expression_count = self.expression_count as u32;
+            (*facial_expressions.as_mut_ptr()).expression_weightings = weights.as_mut_ptr();
             cvt((self.fp().get_facial_expressions)(
                 self.handle,
-                &mut facial_expressions,
+                facial_expressions.as_mut_ptr(),
             ))?;
+            let facial_expressions = facial_expressions.assume_init();
 
             if facial_expressions.is_active.into() {
+                weights.set_len(self.expression_count);
                 Ok(Some(FaceExpressionWeightsHTC {
                     weights,
                     sample_time: facial_expressions.sample_time,
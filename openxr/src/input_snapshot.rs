@@ -0,0 +1,158 @@
+//! Action states gathered into plain data once per frame.
+//!
+//! Querying actions needs the [`Session`] and the actions themselves, which is awkward for engines
+//! that hand input to systems on other threads or store it as a resource. An [`InputSnapshotter`]
+//! holds a list of actions and reads all of them right after syncing into an [`InputSnapshot`],
+//! which is plain data that can be stored or sent to other threads freely.
+
+use crate::*;
+
+/// The actions to read into each [`InputSnapshot`]
+///
+/// Each `add_*` method returns the index of the action's state in the matching field of the
+/// snapshot.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn f(
+/// #     session: &openxr::Session<openxr::Headless>,
+/// #     set: &openxr::ActionSet,
+/// #     fire: &openxr::Action<bool>,
+/// #     grip: &openxr::Action<openxr::Posef>,
+/// #     stage: &openxr::Space,
+/// #     time: openxr::Time,
+/// # ) -> openxr::Result<()> {
+/// let mut snapshotter = openxr::InputSnapshotter::new();
+/// let fire = snapshotter.add_bool(fire, openxr::Path::NULL);
+/// let grip = snapshotter.add_pose(session, grip, openxr::Path::NULL)?;
+/// // Each frame
+/// let input = snapshotter.sync_and_snapshot(
+///     session,
+///     &[openxr::ActiveActionSet::new(set)],
+///     stage,
+///     time,
+/// )?;
+/// if input.bools[fire].current_state {
+///     println!("fired from {:?}", input.poses[grip].pose);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct InputSnapshotter {
+    bools: Vec<(Action<bool>, Path)>,
+    floats: Vec<(Action<f32>, Path)>,
+    vec2s: Vec<(Action<Vector2f>, Path)>,
+    poses: Vec<(Action<Posef>, Path, Space)>,
+}
+
+impl InputSnapshotter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index into [`InputSnapshot::bools`]
+    pub fn add_bool(&mut self, action: &Action<bool>, subaction_path: Path) -> usize {
+        self.bools.push((action.clone(), subaction_path));
+        self.bools.len() - 1
+    }
+
+    /// Index into [`InputSnapshot::floats`]
+    pub fn add_float(&mut self, action: &Action<f32>, subaction_path: Path) -> usize {
+        self.floats.push((action.clone(), subaction_path));
+        self.floats.len() - 1
+    }
+
+    /// Index into [`InputSnapshot::vec2s`]
+    pub fn add_vec2(&mut self, action: &Action<Vector2f>, subaction_path: Path) -> usize {
+        self.vec2s.push((action.clone(), subaction_path));
+        self.vec2s.len() - 1
+    }
+
+    /// Index into [`InputSnapshot::poses`]
+    ///
+    /// Creates an action space in `session`, which is located in each snapshot.
+    pub fn add_pose<G>(
+        &mut self,
+        session: &Session<G>,
+        action: &Action<Posef>,
+        subaction_path: Path,
+    ) -> Result<usize> {
+        let space = action.create_space(session.clone(), subaction_path, Posef::IDENTITY)?;
+        self.poses.push((action.clone(), subaction_path, space));
+        Ok(self.poses.len() - 1)
+    }
+
+    /// Sync `action_sets`, then read every action, locating poses in `base` at `time`
+    ///
+    /// `time` is typically the predicted display time of the frame being rendered.
+    pub fn sync_and_snapshot<G>(
+        &self,
+        session: &Session<G>,
+        action_sets: &[ActiveActionSet<'_>],
+        base: &Space,
+        time: Time,
+    ) -> Result<InputSnapshot> {
+        session.sync_actions(action_sets)?;
+        self.snapshot(session, base, time)
+    }
+
+    /// Read every action as of the latest sync, locating poses in `base` at `time`
+    pub fn snapshot<G>(
+        &self,
+        session: &Session<G>,
+        base: &Space,
+        time: Time,
+    ) -> Result<InputSnapshot> {
+        Ok(InputSnapshot {
+            time,
+            bools: states(session, &self.bools)?,
+            floats: states(session, &self.floats)?,
+            vec2s: states(session, &self.vec2s)?,
+            poses: self
+                .poses
+                .iter()
+                .map(|(action, subaction_path, space)| {
+                    let location = space.locate(base, time)?;
+                    Ok(PoseSnapshot {
+                        is_active: action.is_active(session, *subaction_path)?,
+                        location_flags: location.location_flags,
+                        pose: location.pose,
+                    })
+                })
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+fn states<T: ActionInput, G>(
+    session: &Session<G>,
+    actions: &[(Action<T>, Path)],
+) -> Result<Vec<ActionState<T>>> {
+    actions
+        .iter()
+        .map(|(action, subaction_path)| action.state(session, *subaction_path))
+        .collect()
+}
+
+/// The states of the actions of an [`InputSnapshotter`] at one point in time, in the order they
+/// were added
+#[derive(Debug, Clone)]
+pub struct InputSnapshot {
+    /// The time poses were located at
+    pub time: Time,
+    pub bools: Vec<ActionState<bool>>,
+    pub floats: Vec<ActionState<f32>>,
+    pub vec2s: Vec<ActionState<Vector2f>>,
+    pub poses: Vec<PoseSnapshot>,
+}
+
+/// The location of a pose action's space
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PoseSnapshot {
+    pub is_active: bool,
+    pub location_flags: SpaceLocationFlags,
+    /// Only the parts indicated valid by `location_flags` are meaningful
+    pub pose: Posef,
+}
@@ -1,9 +1,12 @@
+#[cfg(not(windows))]
+use std::time::{SystemTime, UNIX_EPOCH};
 use std::{
     ffi::CString,
+    fmt,
     marker::PhantomData,
     mem::{self, MaybeUninit},
     ptr,
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, Weak},
 };
 
 use sys::platform::*;
@@ -13,6 +16,12 @@ use crate::*;
 /// Root object mediating an application's interaction with OpenXR
 ///
 /// Constructed from an `Entry`.
+///
+/// Instances are independent of one another: each loads its own function pointers and the crate
+/// keeps no global state for them, so an application and a library it embeds may each create one
+/// in the same process. Two features are exceptions: the `null-runtime` feature's runtime is a
+/// single process-wide implementation, and the `ffi-trace` feature only traces calls into the
+/// first runtime loaded.
 #[derive(Clone)]
 pub struct Instance {
     inner: Arc<InstanceInner>,
@@ -63,6 +72,18 @@ impl Instance {
         &self.inner.exts
     }
 
+    /// Create a reference to the instance that doesn't keep it alive
+    ///
+    /// Useful for long-lived callbacks, e.g. debug messengers or event subscribers, which would
+    /// otherwise delay the instance's destruction until they're dropped. Sessions keep their
+    /// instance alive regardless.
+    #[inline]
+    pub fn downgrade(&self) -> WeakInstance {
+        WeakInstance {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
     /// Set the debug name of this `Instance`, if `XR_EXT_debug_utils` is loaded
     #[inline]
     pub fn set_name(&mut self, name: &str) -> Result<()> {
@@ -84,6 +105,10 @@ impl Instance {
         }
     }
 
+    /// The runtime's name for `result`, e.g. `XR_ERROR_LIMIT_REACHED`
+    ///
+    /// Unlike the `Debug` and `Display` impls of [`sys::Result`], this also names values from
+    /// extensions newer than these bindings.
     #[inline]
     pub fn result_to_string(&self, result: sys::Result) -> Result<String> {
         unsafe {
@@ -97,6 +122,10 @@ impl Instance {
         }
     }
 
+    /// The runtime's name for `ty`, e.g. `XR_TYPE_INSTANCE_CREATE_INFO`
+    ///
+    /// Like [`Instance::result_to_string`], this also names structures from extensions newer than
+    /// these bindings.
     #[inline]
     pub fn structure_type_to_string(&self, ty: StructureType) -> Result<String> {
         unsafe {
@@ -136,16 +165,7 @@ impl Instance {
                 system,
                 &mut p,
             ))?;
-            Ok(SystemProperties {
-                system_id: p.system_id,
-                vendor_id: p.vendor_id,
-                system_name: fixed_str(&p.system_name).into(),
-                graphics_properties: p.graphics_properties,
-                tracking_properties: SystemTrackingProperties {
-                    orientation_tracking: p.tracking_properties.orientation_tracking.into(),
-                    position_tracking: p.tracking_properties.position_tracking.into(),
-                },
-            })
+            Ok(SystemProperties::from_raw(&p))
         }
     }
 
@@ -274,7 +294,7 @@ impl Instance {
         system: SystemId,
     ) -> Result<PassthroughCapabilityFlagsFB> {
         let mut props = sys::SystemPassthroughProperties2FB {
-            ty: sys::SystemPassthroughPropertiesFB::TYPE,
+            ty: sys::SystemPassthroughProperties2FB::TYPE,
             next: ptr::null_mut(),
             capabilities: PassthroughCapabilityFlagsFB::EMPTY,
         };
@@ -604,17 +624,7 @@ impl Instance {
         )?;
         Ok(views
             .into_iter()
-            .map(|x| {
-                let x = unsafe { x.assume_init() };
-                ViewConfigurationView {
-                    recommended_image_rect_width: x.recommended_image_rect_width,
-                    max_image_rect_width: x.max_image_rect_width,
-                    recommended_image_rect_height: x.recommended_image_rect_height,
-                    max_image_rect_height: x.max_image_rect_height,
-                    recommended_swapchain_sample_count: x.recommended_swapchain_sample_count,
-                    max_swapchain_sample_count: x.max_swapchain_sample_count,
-                }
-            })
+            .map(|x| ViewConfigurationView::from_raw(unsafe { &x.assume_init() }))
             .collect())
     }
 
@@ -636,6 +646,65 @@ impl Instance {
         })
     }
 
+    /// Reprojection modes the runtime supports for a view configuration
+    ///
+    /// Requires MSFT_composition_layer_reprojection.
+    #[inline]
+    pub fn enumerate_reprojection_modes_msft(
+        &self,
+        system: SystemId,
+        view_configuration_type: ViewConfigurationType,
+    ) -> Result<Vec<ReprojectionModeMSFT>> {
+        let ext = self
+            .exts()
+            .msft_composition_layer_reprojection
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        get_arr(|cap, count, buf| unsafe {
+            (ext.enumerate_reprojection_modes)(
+                self.as_raw(),
+                system,
+                view_configuration_type,
+                cap,
+                count,
+                buf,
+            )
+        })
+    }
+
+    /// Choose the most suitable supported reprojection mode for a view configuration
+    ///
+    /// Modes that rely on the depth buffer are only considered if `has_depth` is set, i.e. if the
+    /// application submits `CompositionLayerDepthInfoKHR` with its projection views. Otherwise
+    /// `PLANAR_MANUAL` is preferred, falling back to `ORIENTATION_ONLY`. Returns `None` if no
+    /// suitable mode is supported, in which case the runtime's default reprojection should be
+    /// used.
+    ///
+    /// Requires MSFT_composition_layer_reprojection.
+    pub fn preferred_reprojection_mode_msft(
+        &self,
+        system: SystemId,
+        view_configuration_type: ViewConfigurationType,
+        has_depth: bool,
+    ) -> Result<Option<ReprojectionModeMSFT>> {
+        const WITH_DEPTH: &[ReprojectionModeMSFT] = &[
+            ReprojectionModeMSFT::DEPTH,
+            ReprojectionModeMSFT::PLANAR_FROM_DEPTH,
+            ReprojectionModeMSFT::PLANAR_MANUAL,
+            ReprojectionModeMSFT::ORIENTATION_ONLY,
+        ];
+        const WITHOUT_DEPTH: &[ReprojectionModeMSFT] = &[
+            ReprojectionModeMSFT::PLANAR_MANUAL,
+            ReprojectionModeMSFT::ORIENTATION_ONLY,
+        ];
+        let supported = self.enumerate_reprojection_modes_msft(system, view_configuration_type)?;
+        let preference = if has_depth { WITH_DEPTH } else { WITHOUT_DEPTH };
+        Ok(preference
+            .iter()
+            .copied()
+            .find(|mode| supported.contains(mode)))
+    }
+
     /// Obtain the current `Time`
     ///
     /// Requires KHR_convert_timespec_time. Most applications should use times from
@@ -662,6 +731,76 @@ impl Instance {
         }
     }
 
+    /// Convert a `CLOCK_MONOTONIC` reading to a `Time`
+    ///
+    /// Requires KHR_convert_timespec_time.
+    #[cfg(not(windows))]
+    pub fn time_from_timespec(&self, timespec: &libc::timespec) -> Result<Time> {
+        let fp = self.convert_timespec_time()?;
+        let mut out = MaybeUninit::uninit();
+        unsafe {
+            cvt((fp.convert_timespec_time_to_time)(
+                self.as_raw(),
+                timespec,
+                out.as_mut_ptr(),
+            ))?;
+            Ok(out.assume_init())
+        }
+    }
+
+    /// Convert a `Time` to the corresponding `CLOCK_MONOTONIC` reading
+    ///
+    /// Requires KHR_convert_timespec_time.
+    #[cfg(not(windows))]
+    pub fn timespec_from_time(&self, time: Time) -> Result<libc::timespec> {
+        let fp = self.convert_timespec_time()?;
+        let mut out = MaybeUninit::uninit();
+        unsafe {
+            cvt((fp.convert_time_to_timespec_time)(
+                self.as_raw(),
+                time,
+                out.as_mut_ptr(),
+            ))?;
+            Ok(out.assume_init())
+        }
+    }
+
+    /// Convert a `Time` to wall-clock time
+    ///
+    /// Uses the current offset between the monotonic and wall clocks, so times far from the
+    /// present are affected by any adjustments made to the wall clock in between. See
+    /// [`UnixTimeSync`] for a drift-aware alternative.
+    ///
+    /// Requires KHR_convert_timespec_time.
+    #[cfg(not(windows))]
+    pub fn system_time_from_time(&self, time: Time) -> Result<SystemTime> {
+        let monotonic = timespec_nanos(&self.timespec_from_time(time)?);
+        let nanos = monotonic + wall_clock_offset();
+        Ok(if nanos >= 0 {
+            UNIX_EPOCH + std::time::Duration::from_nanos(nanos as u64)
+        } else {
+            UNIX_EPOCH - std::time::Duration::from_nanos(nanos.unsigned_abs())
+        })
+    }
+
+    /// Convert wall-clock time to a `Time`
+    ///
+    /// The inverse of [`Instance::system_time_from_time`], with the same caveats.
+    ///
+    /// Requires KHR_convert_timespec_time.
+    #[cfg(not(windows))]
+    pub fn time_from_system_time(&self, time: SystemTime) -> Result<Time> {
+        let unix = match time.duration_since(UNIX_EPOCH) {
+            Ok(x) => x.as_nanos() as i64,
+            Err(e) => -(e.duration().as_nanos() as i64),
+        };
+        let monotonic = unix - wall_clock_offset();
+        self.time_from_timespec(&libc::timespec {
+            tv_sec: monotonic.div_euclid(1_000_000_000) as _,
+            tv_nsec: monotonic.rem_euclid(1_000_000_000) as _,
+        })
+    }
+
     /// Obtain the current `Time`
     ///
     /// Requires KHR_win32_convert_performance_counter_time. Most applications should use
@@ -692,6 +831,40 @@ impl Instance {
         }
     }
 
+    /// Convert a `QueryPerformanceCounter` reading to a `Time`
+    ///
+    /// Requires KHR_win32_convert_performance_counter_time.
+    #[cfg(windows)]
+    pub fn time_from_performance_counter(&self, performance_counter: i64) -> Result<Time> {
+        let fp = self.convert_performance_counter_time()?;
+        let mut out = MaybeUninit::uninit();
+        unsafe {
+            cvt((fp.convert_win32_performance_counter_to_time)(
+                self.as_raw(),
+                &performance_counter,
+                out.as_mut_ptr(),
+            ))?;
+            Ok(out.assume_init())
+        }
+    }
+
+    /// Convert a `Time` to the corresponding `QueryPerformanceCounter` reading
+    ///
+    /// Requires KHR_win32_convert_performance_counter_time.
+    #[cfg(windows)]
+    pub fn performance_counter_from_time(&self, time: Time) -> Result<i64> {
+        let fp = self.convert_performance_counter_time()?;
+        let mut out = MaybeUninit::uninit();
+        unsafe {
+            cvt((fp.convert_time_to_win32_performance_counter)(
+                self.as_raw(),
+                time,
+                out.as_mut_ptr(),
+            ))?;
+            Ok(out.assume_init())
+        }
+    }
+
     /// Specify default bindings for a well-known input archetype
     #[inline]
     pub fn suggest_interaction_profile_bindings(
@@ -717,6 +890,9 @@ impl Instance {
 
     /// Allocate a new [`ActionSet`]
     ///
+    /// `localized_name` is truncated to the last whole character that fits in
+    /// `MAX_LOCALIZED_ACTION_SET_NAME_SIZE - 1` bytes.
+    ///
     /// [`ActionSet`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#input-action-creation
     #[inline]
     pub fn create_action_set(
@@ -802,6 +978,24 @@ impl Instance {
             .as_ref()
             .expect("KHR_d3d12_enable not loaded")
     }
+    #[cfg(not(windows))]
+    fn convert_timespec_time(&self) -> Result<&raw::ConvertTimespecTimeKHR> {
+        self.exts()
+            .khr_convert_timespec_time
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)
+    }
+
+    #[cfg(windows)]
+    fn convert_performance_counter_time(
+        &self,
+    ) -> Result<&raw::Win32ConvertPerformanceCounterTimeKHR> {
+        self.exts()
+            .khr_win32_convert_performance_counter_time
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)
+    }
+
     pub(crate) fn visibility_mask(&self) -> &raw::VisibilityMaskKHR {
         self.exts()
             .khr_visibility_mask
@@ -810,6 +1004,44 @@ impl Instance {
     }
 }
 
+#[cfg(not(windows))]
+#[allow(clippy::unnecessary_cast)] // Fields are narrower on some 32-bit targets
+fn timespec_nanos(timespec: &libc::timespec) -> i64 {
+    timespec.tv_sec as i64 * 1_000_000_000 + timespec.tv_nsec as i64
+}
+
+/// `CLOCK_REALTIME` minus `CLOCK_MONOTONIC`, in nanoseconds
+#[cfg(not(windows))]
+fn wall_clock_offset() -> i64 {
+    let read = |clock| unsafe {
+        let mut out = MaybeUninit::uninit();
+        libc::clock_gettime(clock, out.as_mut_ptr());
+        timespec_nanos(&out.assume_init())
+    };
+    // Bracket the wall clock reading to halve the error introduced by the calls themselves
+    let before = read(libc::CLOCK_MONOTONIC);
+    let realtime = read(libc::CLOCK_REALTIME);
+    let after = read(libc::CLOCK_MONOTONIC);
+    realtime - (before + (after - before) / 2)
+}
+
+/// A reference to an [`Instance`] that doesn't keep it alive, obtained from
+/// [`Instance::downgrade`]
+#[derive(Clone)]
+pub struct WeakInstance {
+    inner: Weak<InstanceInner>,
+}
+
+impl WeakInstance {
+    /// Access the instance, unless every `Instance` referring to it has been dropped
+    #[inline]
+    pub fn upgrade(&self) -> Option<Instance> {
+        Some(Instance {
+            inner: self.inner.upgrade()?,
+        })
+    }
+}
+
 struct InstanceInner {
     entry: Entry,
     handle: sys::Instance,
@@ -841,6 +1073,22 @@ pub struct SystemProperties {
     pub tracking_properties: SystemTrackingProperties,
 }
 
+impl SystemProperties {
+    /// `x` must have been filled in by the runtime
+    pub(crate) unsafe fn from_raw(x: &sys::SystemProperties) -> Self {
+        Self {
+            system_id: x.system_id,
+            vendor_id: x.vendor_id,
+            system_name: fixed_str(&x.system_name).into(),
+            graphics_properties: x.graphics_properties,
+            tracking_properties: SystemTrackingProperties {
+                orientation_tracking: x.tracking_properties.orientation_tracking.into(),
+                position_tracking: x.tracking_properties.position_tracking.into(),
+            },
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct SystemTrackingProperties {
     pub orientation_tracking: bool,
@@ -850,10 +1098,13 @@ pub struct SystemTrackingProperties {
 #[derive(Debug, Copy, Clone)]
 pub struct ViewConfigurationProperties {
     pub view_configuration_type: ViewConfigurationType,
+    /// Whether projection views may be submitted with a different field of view than was located
+    /// for them; see [`ViewConfigurationProperties::resolve_fov`]
     pub fov_mutable: bool,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ViewConfigurationView {
     pub recommended_image_rect_width: u32,
     pub max_image_rect_width: u32,
@@ -863,8 +1114,21 @@ pub struct ViewConfigurationView {
     pub max_swapchain_sample_count: u32,
 }
 
+impl ViewConfigurationView {
+    pub(crate) fn from_raw(x: &sys::ViewConfigurationView) -> Self {
+        Self {
+            recommended_image_rect_width: x.recommended_image_rect_width,
+            max_image_rect_width: x.max_image_rect_width,
+            recommended_image_rect_height: x.recommended_image_rect_height,
+            max_image_rect_height: x.max_image_rect_height,
+            recommended_swapchain_sample_count: x.recommended_swapchain_sample_count,
+            max_swapchain_sample_count: x.max_swapchain_sample_count,
+        }
+    }
+}
+
 pub struct EventDataBuffer {
-    inner: MaybeUninit<sys::EventDataBuffer>,
+    pub(crate) inner: MaybeUninit<sys::EventDataBuffer>,
 }
 
 impl EventDataBuffer {
@@ -881,6 +1145,55 @@ impl Default for EventDataBuffer {
     }
 }
 
+/// An event of a type not decoded by these bindings, see [`Event::Raw`]
+#[derive(Copy, Clone)]
+pub struct RawEvent<'a>(&'a sys::EventDataBuffer);
+
+impl<'a> RawEvent<'a> {
+    /// # Safety
+    ///
+    /// `inner` must have been populated by a successful call to `xrPollEvent`.
+    #[inline]
+    pub unsafe fn new(inner: &'a sys::EventDataBuffer) -> Self {
+        Self(inner)
+    }
+
+    /// The type of the event's structure
+    #[inline]
+    pub fn ty(self) -> StructureType {
+        self.0.ty
+    }
+
+    /// Access the raw event data, e.g. to cast it to the structure identified by
+    /// [`RawEvent::ty`]
+    #[inline]
+    pub fn as_raw(self) -> &'a sys::EventDataBuffer {
+        self.0
+    }
+}
+
+impl fmt::Debug for RawEvent<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("RawEvent")
+            .field("ty", &self.ty())
+            .finish()
+    }
+}
+
+/// Serialized as the name of its structure type
+#[cfg(feature = "serde")]
+impl serde::Serialize for RawEvent<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("RawEvent", 1)?;
+        s.serialize_field("ty", &format_args!("{:?}", self.ty()))?;
+        s.end()
+    }
+}
+
 #[repr(transparent)]
 #[derive(Copy, Clone)]
 pub struct Binding<'a> {
@@ -891,11 +1204,14 @@ pub struct Binding<'a> {
 impl<'a> Binding<'a> {
     #[inline]
     pub fn new<T: ActionTy>(action: &'a Action<T>, binding: Path) -> Self {
+        Self::from_raw(action.as_raw(), binding)
+    }
+
+    /// `action` must be kept alive for `'a` by the caller
+    #[inline]
+    pub(crate) fn from_raw(action: sys::Action, binding: Path) -> Self {
         Self {
-            _inner: sys::ActionSuggestedBinding {
-                action: action.as_raw(),
-                binding,
-            },
+            _inner: sys::ActionSuggestedBinding { action, binding },
             _marker: PhantomData,
         }
     }
@@ -0,0 +1,606 @@
+//! Interaction profiles defined by the specification and its extensions, with the components
+//! each one supports.
+//!
+//! Suggesting a binding to a component a profile doesn't have fails the whole
+//! [`Instance::suggest_interaction_profile_bindings`] call with `ERROR_PATH_UNSUPPORTED`, so it
+//! pays to check paths before they reach the runtime:
+//!
+//! ```no_run
+//! # fn f(instance: &openxr::Instance, trigger: &openxr::Action<f32>) -> openxr::Result<()> {
+//! use openxr::interaction_profiles::{LEFT_HAND, OCULUS_TOUCH};
+//!
+//! instance.suggest_interaction_profile_bindings(
+//!     OCULUS_TOUCH.path(instance)?,
+//!     &[openxr::Binding::new(
+//!         trigger,
+//!         OCULUS_TOUCH.binding(instance, LEFT_HAND, "/input/trigger/value")?,
+//!     )],
+//! )?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::*;
+
+pub const LEFT_HAND: &str = "/user/hand/left";
+pub const RIGHT_HAND: &str = "/user/hand/right";
+pub const HEAD: &str = "/user/head";
+pub const GAMEPAD: &str = "/user/gamepad";
+pub const EYES: &str = "/user/eyes_ext";
+
+const HANDS: &[&str] = &[LEFT_HAND, RIGHT_HAND];
+
+/// An interaction profile and the components it supports
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InteractionProfile {
+    /// e.g. `/interaction_profiles/khr/simple_controller`
+    pub path: &'static str,
+    /// The extension which must be enabled to suggest bindings for this profile, if any
+    pub extension: Option<&'static str>,
+    /// Top level user paths the profile applies to
+    pub user_paths: &'static [&'static str],
+    /// Components available on every user path, e.g. `/input/trigger/value`
+    ///
+    /// Only leaf paths are listed; their parents, e.g. `/input/thumbstick` for a `Vector2f`
+    /// action, are supported implicitly.
+    pub components: &'static [&'static str],
+    /// Components available only on specific user paths, as `(user path, component)`
+    pub exclusive_components: &'static [(&'static str, &'static str)],
+}
+
+impl InteractionProfile {
+    #[inline]
+    pub fn path(&self, instance: &Instance) -> Result<Path> {
+        instance.string_to_path(self.path)
+    }
+
+    /// Whether `component` is available on `user_path`
+    ///
+    /// An identifier path such as `/input/thumbstick` is available if any of its components are.
+    pub fn supports(&self, user_path: &str, component: &str) -> bool {
+        (self.user_paths.contains(&user_path)
+            && self.components.iter().any(|x| provides(x, component)))
+            || self
+                .exclusive_components
+                .iter()
+                .any(|&(user, x)| user == user_path && provides(x, component))
+    }
+
+    /// Whether the profile supports `binding`, a full path, e.g. `/user/hand/left/input/x/click`
+    pub fn supports_binding(&self, binding: &str) -> bool {
+        self.user_paths
+            .iter()
+            .chain(self.exclusive_components.iter().map(|(user, _)| user))
+            .filter_map(|user| Some((*user, binding.strip_prefix(user)?)))
+            .any(|(user, component)| self.supports(user, component))
+    }
+
+    /// The path of `component` on `user_path`, for use in a [`Binding`]
+    ///
+    /// Returns `ERROR_PATH_UNSUPPORTED` if the profile has no such component, without consulting
+    /// the runtime.
+    pub fn binding(&self, instance: &Instance, user_path: &str, component: &str) -> Result<Path> {
+        if !self.supports(user_path, component) {
+            return Err(sys::Result::ERROR_PATH_UNSUPPORTED);
+        }
+        instance.string_to_path(&[user_path, component].concat())
+    }
+
+    /// Every binding path the profile supports, e.g. `/user/hand/left/input/trigger/value`
+    ///
+    /// Identifier paths such as `/user/hand/left/input/thumbstick` aren't listed separately.
+    pub fn bindings(&self) -> impl Iterator<Item = String> + '_ {
+        self.user_paths
+            .iter()
+            .flat_map(move |user| self.components.iter().map(move |x| [user, *x].concat()))
+            .chain(
+                self.exclusive_components
+                    .iter()
+                    .map(|&(user, x)| [user, x].concat()),
+            )
+    }
+}
+
+/// Whether binding `component` is satisfied by the listed leaf `leaf`, either exactly or as its
+/// identifier path, e.g. `/input/thumbstick` for `/input/thumbstick/x`
+fn provides(leaf: &str, component: &str) -> bool {
+    // Identifier paths have exactly two levels, e.g. `/input/thumbstick`
+    leaf == component
+        || (component.matches('/').count() == 2
+            && leaf.rfind('/').map(|i| &leaf[..i]) == Some(component))
+}
+
+/// Every profile in this module
+pub const ALL: &[InteractionProfile] = &[
+    KHR_SIMPLE_CONTROLLER,
+    GOOGLE_DAYDREAM_CONTROLLER,
+    HTC_VIVE_CONTROLLER,
+    HTC_VIVE_PRO,
+    MICROSOFT_MOTION_CONTROLLER,
+    MICROSOFT_XBOX_CONTROLLER,
+    OCULUS_GO_CONTROLLER,
+    OCULUS_TOUCH,
+    VALVE_INDEX_CONTROLLER,
+    EXT_EYE_GAZE,
+    EXT_HAND_INTERACTION,
+    FB_TOUCH_CONTROLLER_PRO,
+    HP_MIXED_REALITY_CONTROLLER,
+    HTC_HAND_INTERACTION,
+    HTC_VIVE_COSMOS_CONTROLLER,
+    HTC_VIVE_FOCUS3_CONTROLLER,
+    HTC_VIVE_TRACKER,
+    HUAWEI_CONTROLLER,
+    META_TOUCH_CONTROLLER_PLUS,
+    MICROSOFT_HAND_INTERACTION,
+    ML2_CONTROLLER,
+    SAMSUNG_ODYSSEY_CONTROLLER,
+];
+
+pub const KHR_SIMPLE_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/khr/simple_controller",
+    extension: None,
+    user_paths: HANDS,
+    components: &[
+        "/input/select/click",
+        "/input/menu/click",
+        "/input/grip/pose",
+        "/input/aim/pose",
+        "/output/haptic",
+    ],
+    exclusive_components: &[],
+};
+
+pub const GOOGLE_DAYDREAM_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/google/daydream_controller",
+    extension: None,
+    user_paths: HANDS,
+    components: &[
+        "/input/select/click",
+        "/input/trackpad/x",
+        "/input/trackpad/y",
+        "/input/trackpad/click",
+        "/input/trackpad/touch",
+        "/input/grip/pose",
+        "/input/aim/pose",
+    ],
+    exclusive_components: &[],
+};
+
+pub const HTC_VIVE_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/htc/vive_controller",
+    extension: None,
+    user_paths: HANDS,
+    components: &[
+        "/input/system/click",
+        "/input/squeeze/click",
+        "/input/menu/click",
+        "/input/trigger/click",
+        "/input/trigger/value",
+        "/input/trackpad/x",
+        "/input/trackpad/y",
+        "/input/trackpad/click",
+        "/input/trackpad/touch",
+        "/input/grip/pose",
+        "/input/aim/pose",
+        "/output/haptic",
+    ],
+    exclusive_components: &[],
+};
+
+pub const HTC_VIVE_PRO: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/htc/vive_pro",
+    extension: None,
+    user_paths: &[HEAD],
+    components: &[
+        "/input/system/click",
+        "/input/volume_up/click",
+        "/input/volume_down/click",
+        "/input/mute_mic/click",
+    ],
+    exclusive_components: &[],
+};
+
+const MOTION_CONTROLLER_COMPONENTS: &[&str] = &[
+    "/input/menu/click",
+    "/input/squeeze/click",
+    "/input/trigger/value",
+    "/input/thumbstick/x",
+    "/input/thumbstick/y",
+    "/input/thumbstick/click",
+    "/input/trackpad/x",
+    "/input/trackpad/y",
+    "/input/trackpad/click",
+    "/input/trackpad/touch",
+    "/input/grip/pose",
+    "/input/aim/pose",
+    "/output/haptic",
+];
+
+pub const MICROSOFT_MOTION_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/microsoft/motion_controller",
+    extension: None,
+    user_paths: HANDS,
+    components: MOTION_CONTROLLER_COMPONENTS,
+    exclusive_components: &[],
+};
+
+pub const MICROSOFT_XBOX_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/microsoft/xbox_controller",
+    extension: None,
+    user_paths: &[GAMEPAD],
+    components: &[
+        "/input/menu/click",
+        "/input/view/click",
+        "/input/a/click",
+        "/input/b/click",
+        "/input/x/click",
+        "/input/y/click",
+        "/input/dpad_down/click",
+        "/input/dpad_right/click",
+        "/input/dpad_up/click",
+        "/input/dpad_left/click",
+        "/input/shoulder_left/click",
+        "/input/shoulder_right/click",
+        "/input/thumbstick_left/click",
+        "/input/thumbstick_right/click",
+        "/input/trigger_left/value",
+        "/input/trigger_right/value",
+        "/input/thumbstick_left/x",
+        "/input/thumbstick_left/y",
+        "/input/thumbstick_right/x",
+        "/input/thumbstick_right/y",
+        "/output/haptic_left",
+        "/output/haptic_right",
+        "/output/haptic_left_trigger",
+        "/output/haptic_right_trigger",
+    ],
+    exclusive_components: &[],
+};
+
+pub const OCULUS_GO_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/oculus/go_controller",
+    extension: None,
+    user_paths: HANDS,
+    components: &[
+        "/input/system/click",
+        "/input/trigger/click",
+        "/input/back/click",
+        "/input/trackpad/x",
+        "/input/trackpad/y",
+        "/input/trackpad/click",
+        "/input/trackpad/touch",
+        "/input/grip/pose",
+        "/input/aim/pose",
+    ],
+    exclusive_components: &[],
+};
+
+const TOUCH_COMPONENTS: &[&str] = &[
+    "/input/squeeze/value",
+    "/input/trigger/value",
+    "/input/trigger/touch",
+    "/input/thumbstick/x",
+    "/input/thumbstick/y",
+    "/input/thumbstick/click",
+    "/input/thumbstick/touch",
+    "/input/thumbrest/touch",
+    "/input/grip/pose",
+    "/input/aim/pose",
+    "/output/haptic",
+];
+
+const TOUCH_EXCLUSIVE_COMPONENTS: &[(&str, &str)] = &[
+    (LEFT_HAND, "/input/x/click"),
+    (LEFT_HAND, "/input/x/touch"),
+    (LEFT_HAND, "/input/y/click"),
+    (LEFT_HAND, "/input/y/touch"),
+    (LEFT_HAND, "/input/menu/click"),
+    (RIGHT_HAND, "/input/a/click"),
+    (RIGHT_HAND, "/input/a/touch"),
+    (RIGHT_HAND, "/input/b/click"),
+    (RIGHT_HAND, "/input/b/touch"),
+    (RIGHT_HAND, "/input/system/click"),
+];
+
+pub const OCULUS_TOUCH: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/oculus/touch_controller",
+    extension: None,
+    user_paths: HANDS,
+    components: TOUCH_COMPONENTS,
+    exclusive_components: TOUCH_EXCLUSIVE_COMPONENTS,
+};
+
+pub const VALVE_INDEX_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/valve/index_controller",
+    extension: None,
+    user_paths: HANDS,
+    components: &[
+        "/input/system/click",
+        "/input/system/touch",
+        "/input/a/click",
+        "/input/a/touch",
+        "/input/b/click",
+        "/input/b/touch",
+        "/input/squeeze/value",
+        "/input/squeeze/force",
+        "/input/trigger/click",
+        "/input/trigger/value",
+        "/input/trigger/touch",
+        "/input/thumbstick/x",
+        "/input/thumbstick/y",
+        "/input/thumbstick/click",
+        "/input/thumbstick/touch",
+        "/input/trackpad/x",
+        "/input/trackpad/y",
+        "/input/trackpad/force",
+        "/input/trackpad/touch",
+        "/input/grip/pose",
+        "/input/aim/pose",
+        "/output/haptic",
+    ],
+    exclusive_components: &[],
+};
+
+pub const EXT_EYE_GAZE: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/ext/eye_gaze_interaction",
+    extension: Some("XR_EXT_eye_gaze_interaction"),
+    user_paths: &[EYES],
+    components: &["/input/gaze_ext/pose"],
+    exclusive_components: &[],
+};
+
+pub const EXT_HAND_INTERACTION: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/ext/hand_interaction_ext",
+    extension: Some("XR_EXT_hand_interaction"),
+    user_paths: HANDS,
+    components: &[
+        "/input/aim/pose",
+        "/input/aim_activate_ext/value",
+        "/input/aim_activate_ext/ready_ext",
+        "/input/grip/pose",
+        "/input/grasp_ext/value",
+        "/input/grasp_ext/ready_ext",
+        "/input/pinch_ext/pose",
+        "/input/pinch_ext/value",
+        "/input/pinch_ext/ready_ext",
+        "/input/poke_ext/pose",
+    ],
+    exclusive_components: &[],
+};
+
+pub const FB_TOUCH_CONTROLLER_PRO: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/facebook/touch_controller_pro",
+    extension: Some("XR_FB_touch_controller_pro"),
+    user_paths: HANDS,
+    components: &[
+        "/input/squeeze/value",
+        "/input/trigger/value",
+        "/input/trigger/touch",
+        "/input/thumbstick/x",
+        "/input/thumbstick/y",
+        "/input/thumbstick/click",
+        "/input/thumbstick/touch",
+        "/input/thumbrest/touch",
+        "/input/thumbrest/force",
+        "/input/stylus_fb/force",
+        "/input/trigger/curl_fb",
+        "/input/trigger/slide_fb",
+        "/input/trigger/proximity_fb",
+        "/input/thumb_fb/proximity_fb",
+        "/input/grip/pose",
+        "/input/aim/pose",
+        "/output/haptic",
+        "/output/haptic_trigger_fb",
+        "/output/haptic_thumb_fb",
+    ],
+    exclusive_components: TOUCH_EXCLUSIVE_COMPONENTS,
+};
+
+pub const HP_MIXED_REALITY_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/hp/mixed_reality_controller",
+    extension: Some("XR_EXT_hp_mixed_reality_controller"),
+    user_paths: HANDS,
+    components: &[
+        "/input/menu/click",
+        "/input/squeeze/value",
+        "/input/trigger/value",
+        "/input/thumbstick/x",
+        "/input/thumbstick/y",
+        "/input/thumbstick/click",
+        "/input/grip/pose",
+        "/input/aim/pose",
+        "/output/haptic",
+    ],
+    exclusive_components: &[
+        (LEFT_HAND, "/input/x/click"),
+        (LEFT_HAND, "/input/y/click"),
+        (RIGHT_HAND, "/input/a/click"),
+        (RIGHT_HAND, "/input/b/click"),
+    ],
+};
+
+const HAND_INTERACTION_COMPONENTS: &[&str] = &[
+    "/input/select/value",
+    "/input/squeeze/value",
+    "/input/aim/pose",
+    "/input/grip/pose",
+];
+
+pub const HTC_HAND_INTERACTION: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/htc/hand_interaction",
+    extension: Some("XR_HTC_hand_interaction"),
+    user_paths: HANDS,
+    components: HAND_INTERACTION_COMPONENTS,
+    exclusive_components: &[],
+};
+
+const VIVE_EXCLUSIVE_COMPONENTS: &[(&str, &str)] = &[
+    (LEFT_HAND, "/input/x/click"),
+    (LEFT_HAND, "/input/y/click"),
+    (LEFT_HAND, "/input/menu/click"),
+    (RIGHT_HAND, "/input/a/click"),
+    (RIGHT_HAND, "/input/b/click"),
+    (RIGHT_HAND, "/input/system/click"),
+];
+
+pub const HTC_VIVE_COSMOS_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/htc/vive_cosmos_controller",
+    extension: Some("XR_HTC_vive_cosmos_controller_interaction"),
+    user_paths: HANDS,
+    components: &[
+        "/input/shoulder/click",
+        "/input/squeeze/click",
+        "/input/trigger/click",
+        "/input/trigger/value",
+        "/input/thumbstick/x",
+        "/input/thumbstick/y",
+        "/input/thumbstick/click",
+        "/input/thumbstick/touch",
+        "/input/grip/pose",
+        "/input/aim/pose",
+        "/output/haptic",
+    ],
+    exclusive_components: VIVE_EXCLUSIVE_COMPONENTS,
+};
+
+pub const HTC_VIVE_FOCUS3_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/htc/vive_focus3_controller",
+    extension: Some("XR_HTC_vive_focus3_controller_interaction"),
+    user_paths: HANDS,
+    components: &[
+        "/input/squeeze/click",
+        "/input/squeeze/touch",
+        "/input/squeeze/value",
+        "/input/trigger/click",
+        "/input/trigger/touch",
+        "/input/trigger/value",
+        "/input/thumbstick/x",
+        "/input/thumbstick/y",
+        "/input/thumbstick/click",
+        "/input/thumbstick/touch",
+        "/input/thumbrest/touch",
+        "/input/grip/pose",
+        "/input/aim/pose",
+        "/output/haptic",
+    ],
+    exclusive_components: VIVE_EXCLUSIVE_COMPONENTS,
+};
+
+pub const HTC_VIVE_TRACKER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/htc/vive_tracker_htcx",
+    extension: Some("XR_HTCX_vive_tracker_interaction"),
+    user_paths: &[
+        "/user/vive_tracker_htcx/role/handheld_object",
+        "/user/vive_tracker_htcx/role/left_foot",
+        "/user/vive_tracker_htcx/role/right_foot",
+        "/user/vive_tracker_htcx/role/left_shoulder",
+        "/user/vive_tracker_htcx/role/right_shoulder",
+        "/user/vive_tracker_htcx/role/left_elbow",
+        "/user/vive_tracker_htcx/role/right_elbow",
+        "/user/vive_tracker_htcx/role/left_knee",
+        "/user/vive_tracker_htcx/role/right_knee",
+        "/user/vive_tracker_htcx/role/waist",
+        "/user/vive_tracker_htcx/role/chest",
+        "/user/vive_tracker_htcx/role/camera",
+        "/user/vive_tracker_htcx/role/keyboard",
+    ],
+    components: &[
+        "/input/system/click",
+        "/input/squeeze/click",
+        "/input/menu/click",
+        "/input/trigger/click",
+        "/input/trigger/value",
+        "/input/trackpad/x",
+        "/input/trackpad/y",
+        "/input/trackpad/click",
+        "/input/trackpad/touch",
+        "/input/grip/pose",
+        "/output/haptic",
+    ],
+    exclusive_components: &[],
+};
+
+pub const HUAWEI_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/huawei/controller",
+    extension: Some("XR_HUAWEI_controller_interaction"),
+    user_paths: HANDS,
+    components: &[
+        "/input/home/click",
+        "/input/back/click",
+        "/input/volume_up/click",
+        "/input/volume_down/click",
+        "/input/trigger/value",
+        "/input/trigger/click",
+        "/input/trackpad/x",
+        "/input/trackpad/y",
+        "/input/trackpad/click",
+        "/input/trackpad/touch",
+        "/input/aim/pose",
+        "/input/grip/pose",
+        "/output/haptic",
+    ],
+    exclusive_components: &[],
+};
+
+pub const META_TOUCH_CONTROLLER_PLUS: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/meta/touch_controller_plus",
+    extension: Some("XR_META_touch_controller_plus"),
+    user_paths: HANDS,
+    components: &[
+        "/input/squeeze/value",
+        "/input/trigger/value",
+        "/input/trigger/touch",
+        "/input/trigger/force",
+        "/input/trigger/proximity_meta",
+        "/input/trigger/curl_meta",
+        "/input/trigger/slide_meta",
+        "/input/thumb_meta/proximity_meta",
+        "/input/thumbstick/x",
+        "/input/thumbstick/y",
+        "/input/thumbstick/click",
+        "/input/thumbstick/touch",
+        "/input/thumbrest/touch",
+        "/input/grip/pose",
+        "/input/aim/pose",
+        "/output/haptic",
+    ],
+    exclusive_components: TOUCH_EXCLUSIVE_COMPONENTS,
+};
+
+pub const MICROSOFT_HAND_INTERACTION: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/microsoft/hand_interaction",
+    extension: Some("XR_MSFT_hand_interaction"),
+    user_paths: HANDS,
+    components: HAND_INTERACTION_COMPONENTS,
+    exclusive_components: &[],
+};
+
+pub const ML2_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/ml/ml2_controller",
+    extension: Some("XR_ML_ml2_controller_interaction"),
+    user_paths: HANDS,
+    components: &[
+        "/input/menu/click",
+        "/input/home/click",
+        "/input/trigger/click",
+        "/input/trigger/value",
+        "/input/trackpad/y",
+        "/input/trackpad/x",
+        "/input/trackpad/click",
+        "/input/trackpad/force",
+        "/input/trackpad/touch",
+        "/input/aim/pose",
+        "/input/grip/pose",
+        "/input/shoulder/click",
+        "/output/haptic",
+    ],
+    exclusive_components: &[],
+};
+
+pub const SAMSUNG_ODYSSEY_CONTROLLER: InteractionProfile = InteractionProfile {
+    path: "/interaction_profiles/samsung/odyssey_controller",
+    extension: Some("XR_EXT_samsung_odyssey_controller"),
+    user_paths: HANDS,
+    components: MOTION_CONTROLLER_COMPONENTS,
+    exclusive_components: &[],
+};
@@ -0,0 +1,276 @@
+//! Extension structures for composition layers and projection views.
+//!
+//! Layer builders accept these through `push_next`, which links them into the builder's `next`
+//! chain for as long as the layer is borrowed.
+
+use std::{marker::PhantomData, mem};
+
+use crate::*;
+
+/// An extension structure that may be chained onto composition layers or projection views
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(C)]` or `#[repr(transparent)]` OpenXR input structures, beginning
+/// with `ty` and `next` fields like `sys::BaseInStructure`.
+pub unsafe trait LayerChainItem {}
+
+macro_rules! impl_push_next {
+    ($($ty:ident),*) => {
+        $(
+            impl<'a, G: Graphics> $ty<'a, G> {
+                /// Chain `item` onto this structure
+                ///
+                /// The runtime must support the item's extension, and accept it in the chain of
+                /// this kind of structure.
+                #[inline]
+                pub fn push_next<T: LayerChainItem>(self, item: &'a mut T) -> Self {
+                    let mut inner = self.into_raw();
+                    let item = item as *mut T as *mut sys::BaseInStructure;
+                    unsafe {
+                        (*item).next = inner.next as _;
+                        inner.next = item as _;
+                        Self::from_raw(inner)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_push_next!(
+    CompositionLayerProjectionView,
+    CompositionLayerProjection,
+    CompositionLayerQuad,
+    CompositionLayerCylinderKHR,
+    CompositionLayerCubeKHR,
+    CompositionLayerEquirectKHR,
+    CompositionLayerEquirect2KHR
+);
+
+/// Depth buffer for a projection view, chained with
+/// [`CompositionLayerProjectionView::push_next`]
+///
+/// Requires XR_KHR_composition_layer_depth.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct CompositionLayerDepthInfoKHR<'a, G: Graphics> {
+    inner: sys::CompositionLayerDepthInfoKHR,
+    _marker: PhantomData<&'a G>,
+}
+
+impl<'a, G: Graphics> CompositionLayerDepthInfoKHR<'a, G> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: sys::CompositionLayerDepthInfoKHR {
+                ty: sys::CompositionLayerDepthInfoKHR::TYPE,
+                ..unsafe { mem::zeroed() }
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Initialize with the supplied raw values
+    ///
+    /// # Safety
+    ///
+    /// The guarantees normally enforced by this builder (e.g. lifetimes) must be preserved.
+    #[inline]
+    pub unsafe fn from_raw(inner: sys::CompositionLayerDepthInfoKHR) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn into_raw(self) -> sys::CompositionLayerDepthInfoKHR {
+        self.inner
+    }
+
+    #[inline]
+    pub fn as_raw(&self) -> &sys::CompositionLayerDepthInfoKHR {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn sub_image(mut self, value: SwapchainSubImage<'a, G>) -> Self {
+        self.inner.sub_image = value.into_raw();
+        self
+    }
+
+    #[inline]
+    pub fn min_depth(mut self, value: f32) -> Self {
+        self.inner.min_depth = value;
+        self
+    }
+
+    #[inline]
+    pub fn max_depth(mut self, value: f32) -> Self {
+        self.inner.max_depth = value;
+        self
+    }
+
+    #[inline]
+    pub fn near_z(mut self, value: f32) -> Self {
+        self.inner.near_z = value;
+        self
+    }
+
+    #[inline]
+    pub fn far_z(mut self, value: f32) -> Self {
+        self.inner.far_z = value;
+        self
+    }
+}
+
+impl<'a, G: Graphics> Default for CompositionLayerDepthInfoKHR<'a, G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<'a, G: Graphics> LayerChainItem for CompositionLayerDepthInfoKHR<'a, G> {}
+
+/// Depth test a projection layer against previously composited layers, chained with
+/// [`CompositionLayerProjection::push_next`]
+///
+/// Only pixels whose depth lies within the given range are tested. The layer's views must have
+/// [`CompositionLayerDepthInfoKHR`] chained.
+///
+/// Requires XR_VARJO_composition_layer_depth_test.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct CompositionLayerDepthTestVARJO {
+    inner: sys::CompositionLayerDepthTestVARJO,
+}
+
+impl CompositionLayerDepthTestVARJO {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: sys::CompositionLayerDepthTestVARJO {
+                ty: sys::CompositionLayerDepthTestVARJO::TYPE,
+                ..unsafe { mem::zeroed() }
+            },
+        }
+    }
+
+    /// Initialize with the supplied raw values
+    ///
+    /// # Safety
+    ///
+    /// The guarantees normally enforced by this builder must be preserved.
+    #[inline]
+    pub unsafe fn from_raw(inner: sys::CompositionLayerDepthTestVARJO) -> Self {
+        Self { inner }
+    }
+
+    #[inline]
+    pub fn into_raw(self) -> sys::CompositionLayerDepthTestVARJO {
+        self.inner
+    }
+
+    #[inline]
+    pub fn as_raw(&self) -> &sys::CompositionLayerDepthTestVARJO {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn depth_test_range_near_z(mut self, value: f32) -> Self {
+        self.inner.depth_test_range_near_z = value;
+        self
+    }
+
+    #[inline]
+    pub fn depth_test_range_far_z(mut self, value: f32) -> Self {
+        self.inner.depth_test_range_far_z = value;
+        self
+    }
+}
+
+impl Default for CompositionLayerDepthTestVARJO {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl LayerChainItem for CompositionLayerDepthTestVARJO {}
+
+/// Custom blend factors for a composition layer, chained with e.g.
+/// [`CompositionLayerQuad::push_next`]
+///
+/// Without this, layers with [`CompositionLayerFlags::BLEND_TEXTURE_SOURCE_ALPHA`] are blended
+/// as `ONE`, `ONE_MINUS_SRC_ALPHA` for premultiplied alpha.
+///
+/// Requires XR_FB_composition_layer_alpha_blend.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct CompositionLayerAlphaBlendFB {
+    inner: sys::CompositionLayerAlphaBlendFB,
+}
+
+impl CompositionLayerAlphaBlendFB {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: sys::CompositionLayerAlphaBlendFB {
+                ty: sys::CompositionLayerAlphaBlendFB::TYPE,
+                ..unsafe { mem::zeroed() }
+            },
+        }
+    }
+
+    /// Initialize with the supplied raw values
+    ///
+    /// # Safety
+    ///
+    /// The guarantees normally enforced by this builder must be preserved.
+    #[inline]
+    pub unsafe fn from_raw(inner: sys::CompositionLayerAlphaBlendFB) -> Self {
+        Self { inner }
+    }
+
+    #[inline]
+    pub fn into_raw(self) -> sys::CompositionLayerAlphaBlendFB {
+        self.inner
+    }
+
+    #[inline]
+    pub fn as_raw(&self) -> &sys::CompositionLayerAlphaBlendFB {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn src_factor_color(mut self, value: BlendFactorFB) -> Self {
+        self.inner.src_factor_color = value;
+        self
+    }
+
+    #[inline]
+    pub fn dst_factor_color(mut self, value: BlendFactorFB) -> Self {
+        self.inner.dst_factor_color = value;
+        self
+    }
+
+    #[inline]
+    pub fn src_factor_alpha(mut self, value: BlendFactorFB) -> Self {
+        self.inner.src_factor_alpha = value;
+        self
+    }
+
+    #[inline]
+    pub fn dst_factor_alpha(mut self, value: BlendFactorFB) -> Self {
+        self.inner.dst_factor_alpha = value;
+        self
+    }
+}
+
+impl Default for CompositionLayerAlphaBlendFB {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl LayerChainItem for CompositionLayerAlphaBlendFB {}
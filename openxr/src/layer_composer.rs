@@ -0,0 +1,121 @@
+//! Keeping a frame's composition layers within the runtime's limit.
+//!
+//! Runtimes only guarantee [`sys::MIN_COMPOSITION_LAYERS_SUPPORTED`] layers per frame, reporting
+//! their actual limit as `SystemGraphicsProperties::max_layer_count`, and fail
+//! [`FrameStream::end`] with `ERROR_LAYER_LIMIT_EXCEEDED` beyond it. [`LayerComposer`] collects a
+//! frame's layers and, when there are too many, demotes quads the application is able to draw
+//! itself so that they can be rendered into a projection layer instead.
+
+use crate::*;
+
+/// Collects the composition layers for a frame, fitting them within the runtime's limit
+pub struct LayerComposer<'a, 'b, G: Graphics> {
+    max_layer_count: usize,
+    layers: Vec<Slot<'a, 'b, G>>,
+}
+
+enum Slot<'a, 'b, G: Graphics> {
+    Fixed(&'b CompositionLayerBase<'a, G>),
+    Mergeable(&'b CompositionLayerQuad<'a, G>),
+}
+
+impl<'a, 'b, G: Graphics> LayerComposer<'a, 'b, G> {
+    /// Compose within the limit reported in `properties`, e.g. from
+    /// [`Instance::system_properties`]
+    pub fn new(properties: &SystemGraphicsProperties) -> Self {
+        Self::with_max_layer_count(properties.max_layer_count as usize)
+    }
+
+    /// Compose within an explicit limit
+    pub fn with_max_layer_count(max_layer_count: usize) -> Self {
+        Self {
+            max_layer_count,
+            layers: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn max_layer_count(&self) -> usize {
+        self.max_layer_count
+    }
+
+    /// Add a layer that must be submitted to the runtime, above those already added
+    pub fn push(&mut self, layer: &'b CompositionLayerBase<'a, G>) {
+        self.layers.push(Slot::Fixed(layer));
+    }
+
+    /// Add a quad above those already added, which the application can render into one of its
+    /// projection layers if the limit would otherwise be exceeded
+    pub fn push_mergeable(&mut self, layer: &'b CompositionLayerQuad<'a, G>) {
+        self.layers.push(Slot::Mergeable(layer));
+    }
+
+    /// Remove all layers, e.g. to start the next frame
+    pub fn clear(&mut self) {
+        self.layers.clear();
+    }
+
+    /// Decide which layers to submit
+    ///
+    /// Mergeable quads are demoted starting with the bottommost, as those are the most likely to
+    /// be covered anyway. If fixed layers alone exceed the limit, the topmost are dropped.
+    pub fn compose(&self) -> LayerComposition<'a, 'b, G> {
+        let mut excess = self.layers.len().saturating_sub(self.max_layer_count);
+        let mut out = LayerComposition {
+            layers: Vec::with_capacity(self.layers.len().min(self.max_layer_count)),
+            merged: Vec::new(),
+            dropped: 0,
+        };
+        for entry in &self.layers {
+            match *entry {
+                Slot::Mergeable(quad) if excess > 0 => {
+                    out.merged.push(quad);
+                    excess -= 1;
+                }
+                Slot::Mergeable(quad) => out.layers.push(&**quad),
+                Slot::Fixed(layer) => out.layers.push(layer),
+            }
+        }
+        if out.layers.len() > self.max_layer_count {
+            out.dropped = out.layers.len() - self.max_layer_count;
+            out.layers.truncate(self.max_layer_count);
+        }
+        out
+    }
+}
+
+/// The result of [`LayerComposer::compose`]
+///
+/// Layers left out can be reported with [`Diagnostics::record_composition`].
+pub struct LayerComposition<'a, 'b, G: Graphics> {
+    layers: Vec<&'b CompositionLayerBase<'a, G>>,
+    merged: Vec<&'b CompositionLayerQuad<'a, G>>,
+    dropped: usize,
+}
+
+impl<'a, 'b, G: Graphics> LayerComposition<'a, 'b, G> {
+    /// Layers to pass to [`FrameStream::end`]
+    #[inline]
+    pub fn layers(&self) -> &[&'b CompositionLayerBase<'a, G>] {
+        &self.layers
+    }
+
+    /// Quads that were left out, and which the application should render into its projection
+    /// layers itself, bottommost first
+    #[inline]
+    pub fn merged(&self) -> &[&'b CompositionLayerQuad<'a, G>] {
+        &self.merged
+    }
+
+    /// Number of layers that were left out and couldn't be merged
+    #[inline]
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Whether any layers were left out
+    #[inline]
+    pub fn exceeded_limit(&self) -> bool {
+        !self.merged.is_empty() || self.dropped != 0
+    }
+}
@@ -1,7 +1,16 @@
 //! To get started, construct an `Entry` object.
+//!
+//! # Extensions without `ExtensionSet` fields
+//!
+//! Some extensions wrapped by this crate are missing from the registry that [`ExtensionSet`] and
+//! [`InstanceExtensions`] are generated from. Enable one by adding its name, e.g.
+//! `"XR_KHR_locate_spaces"` for [`sys::KHR_LOCATE_SPACES_EXTENSION_NAME`], to
+//! [`ExtensionSet::other`] when creating the instance. Its functions are loaded when needed, which
+//! fails, typically with `ERROR_FUNCTION_UNSUPPORTED`, if the extension wasn't enabled.
 
 // deref_addrof false positive: https://github.com/rust-lang/rust-clippy/issues/8247
 #![allow(clippy::transmute_ptr_to_ptr, clippy::deref_addrof)]
+use std::borrow::Cow;
 use std::os::raw::c_char;
 
 pub use sys::{
@@ -15,20 +24,100 @@ mod entry;
 pub use entry::*;
 mod instance;
 pub use instance::*;
+mod system_capabilities;
+pub use system_capabilities::*;
 mod session;
 pub use session::*;
 mod frame_stream;
 pub use frame_stream::*;
+mod composition_layer;
+pub use composition_layer::*;
+mod owned_layer;
+pub use owned_layer::*;
+mod frame_loop;
+pub use frame_loop::*;
+mod frame_runner;
+pub use frame_runner::*;
+mod frame_pacer;
+pub use frame_pacer::*;
+mod frame_timing;
+pub use frame_timing::*;
+mod animation_clock;
+pub use animation_clock::*;
+mod session_lifecycle;
+pub use session_lifecycle::*;
+mod event_dispatcher;
+mod event_fields;
+pub use event_dispatcher::*;
+mod reference_space;
+pub use reference_space::*;
+mod shutdown;
+pub use shutdown::*;
+#[cfg(feature = "futures")]
+mod event_stream;
+#[cfg(feature = "futures")]
+pub use event_stream::*;
+mod diagnostics;
+pub use diagnostics::*;
+mod android_permission;
+pub use android_permission::*;
+mod layer_chain;
+pub use layer_chain::*;
+mod layer_composer;
+pub use layer_composer::*;
+mod time_sync;
+pub use time_sync::*;
+mod api_dump;
+pub use api_dump::*;
 mod graphics;
 pub use graphics::*;
 mod swapchain;
 pub use swapchain::*;
+mod swapchain_waiter;
+pub use swapchain_waiter::*;
+mod swapchain_images;
+pub use swapchain_images::*;
+mod view_layout;
+pub use view_layout::*;
+mod view_fov;
+pub use view_fov::*;
+mod view_configuration_views;
+pub use view_configuration_views::*;
+mod view_locate;
+pub use view_locate::*;
+mod comfort_vignette;
+pub use comfort_vignette::*;
 mod space;
 pub use space::*;
+mod locate_spaces;
+mod stage_boundary;
+pub use stage_boundary::*;
 mod action_set;
 pub use action_set::*;
 mod action;
 pub use action::*;
+mod action_builder;
+pub use action_builder::*;
+mod action_history;
+pub use action_history::*;
+mod action_edges;
+pub use action_edges::*;
+mod handed_action;
+pub use handed_action::*;
+mod haptics;
+pub use haptics::*;
+mod input_snapshot;
+pub use input_snapshot::*;
+mod throw_assist;
+pub use throw_assist::*;
+mod gamepad_rumble;
+pub use gamepad_rumble::*;
+mod binding_modification;
+pub use binding_modification::*;
+mod bindings_builder;
+pub use bindings_builder::*;
+pub mod interaction_profiles;
+
 mod hand_tracker;
 pub use hand_tracker::*;
 mod secondary_view;
@@ -37,23 +126,63 @@ mod foveation_fb;
 pub use foveation_fb::*;
 mod vive_tracker_paths;
 pub use vive_tracker_paths::*;
+mod tracker;
+pub use tracker::*;
+mod device_status;
+pub use device_status::*;
 mod display_refresh_rate;
 pub use display_refresh_rate::*;
 mod passthrough;
 pub use passthrough::*;
 mod eye_tracking_social;
 pub use eye_tracking_social::*;
+mod gaze_input;
+pub use gaze_input::*;
 mod face_tracking_fb;
 pub use face_tracking_fb::*;
 mod htc_facial_tracking;
 pub use htc_facial_tracking::*;
+mod eye_openness;
+pub use eye_openness::*;
 mod body_tracking_full_body_meta;
 pub use body_tracking_full_body_meta::*;
+mod controller_model_msft;
+pub use controller_model_msft::*;
+mod scene_understanding_msft;
+pub use scene_understanding_msft::*;
+mod localization_map_ml;
+pub use localization_map_ml::*;
+mod future_ext;
+pub use future_ext::*;
+mod spatial_anchors_ml;
+pub use spatial_anchors_ml::*;
+mod haptic_pcm_fb;
+pub use haptic_pcm_fb::*;
+mod quad_views_varjo;
+pub use quad_views_varjo::*;
+mod foveated_rendering_varjo;
+mod spatial_entity_sharing_fb;
+pub use spatial_entity_sharing_fb::*;
+mod wrapper_modules;
+pub use wrapper_modules::*;
+#[cfg(all(feature = "wgpu", not(target_vendor = "apple")))]
+mod wgpu_integration;
+#[cfg(all(feature = "wgpu", not(target_vendor = "apple")))]
+pub use wgpu_integration::*;
+#[cfg(feature = "desktop-sim")]
+mod desktop_sim;
+#[cfg(feature = "ffi-trace")]
+mod ffi_trace;
+#[cfg(feature = "null-runtime")]
+mod null_runtime;
+#[cfg(feature = "desktop-sim")]
+pub use desktop_sim::*;
 
 pub use builder::{
     CompositionLayerBase, CompositionLayerCubeKHR, CompositionLayerCylinderKHR,
-    CompositionLayerEquirectKHR, CompositionLayerProjection, CompositionLayerProjectionView,
-    CompositionLayerQuad, HapticBase, HapticPcmVibrationFB, HapticVibration, SwapchainSubImage,
+    CompositionLayerEquirect2KHR, CompositionLayerEquirectKHR, CompositionLayerProjection,
+    CompositionLayerProjectionView, CompositionLayerQuad, HapticBase, HapticPcmVibrationFB,
+    HapticVibration, SwapchainSubImage,
 };
 
 pub type Result<T, E = sys::Result> = std::result::Result<T, E>;
@@ -88,8 +217,22 @@ fn place_cstr(out: &mut [c_char], s: &str) {
     out[s.len()] = 0;
 }
 
-unsafe fn fixed_str(x: &[c_char]) -> &str {
-    std::str::from_utf8_unchecked(std::ffi::CStr::from_ptr(x.as_ptr()).to_bytes())
+/// Like `place_cstr`, but truncates `s` at a character boundary to fit, and at its first null
+fn place_cstr_truncated(out: &mut [c_char], s: &str) {
+    let s = s.split('\0').next().unwrap();
+    let mut len = s.len().min(out.len() - 1);
+    while !s.is_char_boundary(len) {
+        len -= 1;
+    }
+    for (i, o) in s[..len].bytes().zip(out.iter_mut()) {
+        *o = i as c_char;
+    }
+    out[len] = 0;
+}
+
+/// Invalid UTF-8 is replaced rather than trusted, since runtimes may truncate multi-byte text
+unsafe fn fixed_str(x: &[c_char]) -> Cow<'_, str> {
+    String::from_utf8_lossy(std::ffi::CStr::from_ptr(x.as_ptr()).to_bytes())
 }
 
 /// Includes null for convenience of comparison with C string constants
@@ -98,6 +241,29 @@ fn fixed_str_bytes(x: &[c_char]) -> &[u8] {
     unsafe { std::mem::transmute(&x[..=end]) }
 }
 
+/// Load `name`, a nul-terminated function of an extension the generated bindings omit
+///
+/// `F` must be the function pointer type of `name`.
+unsafe fn load_ext_fn<F: Copy>(instance: &Instance, name: &[u8]) -> Result<F> {
+    let f = instance.entry().get_instance_proc_addr(
+        instance.as_raw(),
+        std::ffi::CStr::from_bytes_with_nul_unchecked(name),
+    )?;
+    debug_assert_eq!(std::mem::size_of::<F>(), std::mem::size_of_val(&f));
+    Ok(std::mem::transmute_copy(&f))
+}
+
+/// Push `x` onto the front of the chain starting at `next` if `enabled`
+///
+/// `T` must be a structure beginning with `ty` and `next`.
+unsafe fn push_next<T>(next: &mut *mut sys::BaseOutStructure, enabled: bool, x: &mut T) {
+    if enabled {
+        let x = x as *mut T as *mut sys::BaseOutStructure;
+        (*x).next = *next;
+        *next = x;
+    }
+}
+
 fn get_str(mut getter: impl FnMut(u32, &mut u32, *mut c_char) -> sys::Result) -> Result<String> {
     let mut bytes = get_arr(|x, y, z| getter(x, y, z as _))?;
     // Truncate at first null byte
@@ -108,7 +274,8 @@ fn get_str(mut getter: impl FnMut(u32, &mut u32, *mut c_char) -> sys::Result) ->
         .unwrap_or(0);
     bytes.truncate(first_nt);
 
-    unsafe { Ok(String::from_utf8_unchecked(bytes)) }
+    Ok(String::from_utf8(bytes)
+        .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned()))
 }
 
 fn get_arr<T: Copy>(
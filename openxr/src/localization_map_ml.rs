@@ -0,0 +1,376 @@
+//! Shared spaces via [`XR_ML_localization_map`]
+//!
+//! The extension must be [enabled by name](crate#extensions-without-extensionset-fields).
+//!
+//! Once events are enabled with [`Session::enable_localization_events_ml`], the runtime reports
+//! progress through events which [`Instance::poll_event`] returns as [`Event::Raw`]; decode them
+//! with [`LocalizationChangedML::from_event`]. Maps exported from one device with
+//! [`Session::export_localization_map_ml`] can be imported on another with
+//! [`Session::import_localization_map_ml`] and then localized into with
+//! [`Session::request_map_localization_ml`].
+//!
+//! [`XR_ML_localization_map`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map
+
+use std::{
+    fmt, ptr,
+    sync::{Arc, Mutex},
+};
+
+pub use sys::{
+    LocalizationMapConfidenceML, LocalizationMapErrorFlagsML, LocalizationMapStateML,
+    LocalizationMapTypeML,
+};
+
+use crate::*;
+
+/// A localization map known to the runtime
+#[derive(Debug, Clone)]
+pub struct LocalizationMapML {
+    pub name: String,
+    pub map_uuid: UuidEXT,
+    pub map_type: LocalizationMapTypeML,
+}
+
+impl LocalizationMapML {
+    /// Copy a map description reported by the runtime, e.g. by
+    /// [`LocalizationChangedML::map`]
+    pub fn from_raw(raw: &sys::LocalizationMapML) -> Self {
+        Self {
+            name: unsafe { fixed_str(&raw.name) }.into_owned(),
+            map_uuid: raw.map_uuid,
+            map_type: raw.map_type,
+        }
+    }
+}
+
+impl<G> Session<G> {
+    /// Enable or disable delivery of [`LocalizationChangedML`] events
+    ///
+    /// Requires [`XR_ML_localization_map`].
+    ///
+    /// [`XR_ML_localization_map`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map
+    pub fn enable_localization_events_ml(&self, enabled: bool) -> Result<()> {
+        let fp = self.localization_map_fp()?;
+        let info = sys::LocalizationEnableEventsInfoML {
+            ty: sys::LocalizationEnableEventsInfoML::TYPE,
+            next: ptr::null(),
+            enabled: enabled.into(),
+        };
+        unsafe {
+            cvt((fp.enable_localization_events)(self.as_raw(), &info))?;
+        }
+        Ok(())
+    }
+
+    /// Enumerate the localization maps available on this device
+    ///
+    /// Requires [`XR_ML_localization_map`].
+    ///
+    /// [`XR_ML_localization_map`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map
+    pub fn query_localization_maps_ml(&self) -> Result<Vec<LocalizationMapML>> {
+        let fp = self.localization_map_fp()?;
+        let maps = get_arr_init(
+            sys::LocalizationMapML::out(ptr::null_mut()),
+            |cap, count, buf| unsafe {
+                (fp.query_localization_maps)(self.as_raw(), ptr::null(), cap, count, buf as _)
+            },
+        )?;
+        Ok(maps
+            .iter()
+            .map(|x| LocalizationMapML::from_raw(unsafe { x.assume_init_ref() }))
+            .collect())
+    }
+
+    /// Begin localizing into the map identified by `map_uuid`
+    ///
+    /// The outcome is reported through [`LocalizationChangedML`] events, which must have been
+    /// enabled with [`Session::enable_localization_events_ml`].
+    ///
+    /// Requires [`XR_ML_localization_map`].
+    ///
+    /// [`XR_ML_localization_map`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map
+    pub fn request_map_localization_ml(&self, map_uuid: UuidEXT) -> Result<()> {
+        let fp = self.localization_map_fp()?;
+        let info = sys::MapLocalizationRequestInfoML {
+            ty: sys::MapLocalizationRequestInfoML::TYPE,
+            next: ptr::null(),
+            map_uuid,
+        };
+        unsafe {
+            cvt((fp.request_map_localization)(self.as_raw(), &info))?;
+        }
+        Ok(())
+    }
+
+    /// Import a map previously obtained from [`ExportedLocalizationMapML::data`], returning the
+    /// UUID it is known by on this device
+    ///
+    /// Requires [`XR_ML_localization_map`].
+    ///
+    /// [`XR_ML_localization_map`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map
+    pub fn import_localization_map_ml(&self, data: &[u8]) -> Result<UuidEXT> {
+        let fp = self.localization_map_fp()?;
+        let info = sys::LocalizationMapImportInfoML {
+            ty: sys::LocalizationMapImportInfoML::TYPE,
+            next: ptr::null(),
+            size: data.len() as u32,
+            data: data.as_ptr() as _,
+        };
+        let mut out = UuidEXT {
+            data: [0; sys::UUID_SIZE_EXT],
+        };
+        unsafe {
+            cvt((fp.import_localization_map)(self.as_raw(), &info, &mut out))?;
+        }
+        Ok(out)
+    }
+
+    /// Prepare the map identified by `map_uuid` for export
+    ///
+    /// Cloud maps can't be exported.
+    ///
+    /// Requires [`XR_ML_localization_map`].
+    ///
+    /// [`XR_ML_localization_map`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map
+    pub fn export_localization_map_ml(
+        &self,
+        map_uuid: UuidEXT,
+    ) -> Result<ExportedLocalizationMapML> {
+        let fp = self.localization_map_fp()?;
+        let mut handle = sys::ExportedLocalizationMapML::NULL;
+        unsafe {
+            cvt((fp.create_exported_localization_map)(
+                self.as_raw(),
+                &map_uuid,
+                &mut handle,
+            ))?;
+        }
+        Ok(ExportedLocalizationMapML {
+            _session: self.inner.clone(),
+            fp,
+            handle,
+        })
+    }
+
+    fn localization_map_fp(&self) -> Result<LocalizationMapFnML> {
+        let mut cached = self.inner.localization_map.lock().unwrap();
+        if let Some(fp) = *cached {
+            return Ok(fp);
+        }
+        let fp = unsafe { LocalizationMapFnML::load(self.instance())? };
+        *cached = Some(fp);
+        Ok(fp)
+    }
+}
+
+/// The extension's function pointers, once loaded
+pub(crate) type LocalizationMapCache = Mutex<Option<LocalizationMapFnML>>;
+
+/// A change in localization, reported once enabled by [`Session::enable_localization_events_ml`]
+///
+/// The generated [`Event`] doesn't cover this extension, so these arrive as [`Event::Raw`].
+#[derive(Copy, Clone)]
+pub struct LocalizationChangedML<'a>(&'a sys::EventDataLocalizationChangedML);
+
+impl<'a> LocalizationChangedML<'a> {
+    /// # Safety
+    ///
+    /// `inner` must be valid event data according to the OpenXR spec. Refer to
+    /// [sys::EventDataLocalizationChangedML] for more information.
+    #[inline]
+    pub unsafe fn new(inner: &'a sys::EventDataLocalizationChangedML) -> Self {
+        Self(inner)
+    }
+
+    /// Decode `event`, if it is a change in localization
+    pub fn from_event(event: RawEvent<'a>) -> Option<Self> {
+        if event.ty() != sys::EventDataLocalizationChangedML::TYPE {
+            return None;
+        }
+        // Safety: the runtime populated the buffer with a structure of this type
+        unsafe {
+            let raw = event.as_raw() as *const sys::EventDataBuffer;
+            Some(Self::new(
+                &*(raw as *const sys::EventDataLocalizationChangedML),
+            ))
+        }
+    }
+
+    #[inline]
+    pub fn session(self) -> sys::Session {
+        self.0.session
+    }
+
+    #[inline]
+    pub fn state(self) -> LocalizationMapStateML {
+        self.0.state
+    }
+
+    #[inline]
+    pub fn map(self) -> &'a sys::LocalizationMapML {
+        &self.0.map
+    }
+
+    #[inline]
+    pub fn confidence(self) -> LocalizationMapConfidenceML {
+        self.0.confidence
+    }
+
+    #[inline]
+    pub fn error_flags(self) -> LocalizationMapErrorFlagsML {
+        self.0.error_flags
+    }
+}
+
+impl fmt::Debug for LocalizationChangedML<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("LocalizationChangedML")
+            .field("session", &event_fields::Field(&self.session()))
+            .field("state", &event_fields::Field(&self.state()))
+            .field("map", &event_fields::Field(&self.map()))
+            .field("confidence", &event_fields::Field(&self.confidence()))
+            .field("error_flags", &event_fields::Field(&self.error_flags()))
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LocalizationChangedML<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("LocalizationChangedML", 5)?;
+        s.serialize_field("session", &event_fields::Field(&self.session()))?;
+        s.serialize_field("state", &event_fields::Field(&self.state()))?;
+        s.serialize_field("map", &event_fields::Field(&self.map()))?;
+        s.serialize_field("confidence", &event_fields::Field(&self.confidence()))?;
+        s.serialize_field("error_flags", &event_fields::Field(&self.error_flags()))?;
+        s.end()
+    }
+}
+
+impl<C> EventDispatcher<C> {
+    /// Call `handler` for each [`LocalizationChangedML`] event
+    ///
+    /// These are [`Event::Raw`] events, so handlers registered with [`EventDispatcher::on_raw`]
+    /// see them too.
+    pub fn on_localization_changed_ml(
+        self,
+        mut handler: impl FnMut(&mut C, LocalizationChangedML<'_>) -> Result<()> + 'static,
+    ) -> Self {
+        self.on_raw(move |cx, e| match LocalizationChangedML::from_event(e) {
+            Some(e) => handler(cx, e),
+            None => Ok(()),
+        })
+    }
+}
+
+/// A localization map captured for transfer to another device
+///
+/// Requires [`XR_ML_localization_map`].
+///
+/// [`XR_ML_localization_map`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map
+pub struct ExportedLocalizationMapML {
+    _session: Arc<session::SessionInner>,
+    fp: LocalizationMapFnML,
+    handle: sys::ExportedLocalizationMapML,
+}
+
+impl ExportedLocalizationMapML {
+    #[inline]
+    pub fn as_raw(&self) -> sys::ExportedLocalizationMapML {
+        self.handle
+    }
+
+    /// The opaque serialized map, suitable for [`Session::import_localization_map_ml`]
+    pub fn data(&self) -> Result<Vec<u8>> {
+        get_arr(|cap, count, buf| unsafe {
+            (self.fp.get_exported_localization_map_data)(self.handle, cap, count, buf as _)
+        })
+    }
+}
+
+impl Drop for ExportedLocalizationMapML {
+    fn drop(&mut self) {
+        unsafe {
+            (self.fp.destroy_exported_localization_map)(self.handle);
+        }
+    }
+}
+
+/// Function pointers for `XR_ML_localization_map`, which the generated bindings omit
+#[derive(Copy, Clone)]
+pub(crate) struct LocalizationMapFnML {
+    enable_localization_events: pfn::EnableLocalizationEventsML,
+    query_localization_maps: pfn::QueryLocalizationMapsML,
+    request_map_localization: pfn::RequestMapLocalizationML,
+    import_localization_map: pfn::ImportLocalizationMapML,
+    create_exported_localization_map: pfn::CreateExportedLocalizationMapML,
+    destroy_exported_localization_map: pfn::DestroyExportedLocalizationMapML,
+    get_exported_localization_map_data: pfn::GetExportedLocalizationMapDataML,
+}
+
+impl LocalizationMapFnML {
+    unsafe fn load(instance: &Instance) -> Result<Self> {
+        Ok(Self {
+            enable_localization_events: load_ext_fn(instance, b"xrEnableLocalizationEventsML\0")?,
+            query_localization_maps: load_ext_fn(instance, b"xrQueryLocalizationMapsML\0")?,
+            request_map_localization: load_ext_fn(instance, b"xrRequestMapLocalizationML\0")?,
+            import_localization_map: load_ext_fn(instance, b"xrImportLocalizationMapML\0")?,
+            create_exported_localization_map: load_ext_fn(
+                instance,
+                b"xrCreateExportedLocalizationMapML\0",
+            )?,
+            destroy_exported_localization_map: load_ext_fn(
+                instance,
+                b"xrDestroyExportedLocalizationMapML\0",
+            )?,
+            get_exported_localization_map_data: load_ext_fn(
+                instance,
+                b"xrGetExportedLocalizationMapDataML\0",
+            )?,
+        })
+    }
+}
+
+mod pfn {
+    use std::os::raw::c_char;
+
+    use crate::sys;
+
+    pub type EnableLocalizationEventsML = unsafe extern "system" fn(
+        sys::Session,
+        *const sys::LocalizationEnableEventsInfoML,
+    ) -> sys::Result;
+    pub type QueryLocalizationMapsML = unsafe extern "system" fn(
+        sys::Session,
+        *const sys::BaseInStructure,
+        u32,
+        *mut u32,
+        *mut sys::LocalizationMapML,
+    ) -> sys::Result;
+    pub type RequestMapLocalizationML = unsafe extern "system" fn(
+        sys::Session,
+        *const sys::MapLocalizationRequestInfoML,
+    ) -> sys::Result;
+    pub type ImportLocalizationMapML = unsafe extern "system" fn(
+        sys::Session,
+        *const sys::LocalizationMapImportInfoML,
+        *mut sys::UuidEXT,
+    ) -> sys::Result;
+    pub type CreateExportedLocalizationMapML = unsafe extern "system" fn(
+        sys::Session,
+        *const sys::UuidEXT,
+        *mut sys::ExportedLocalizationMapML,
+    ) -> sys::Result;
+    pub type DestroyExportedLocalizationMapML =
+        unsafe extern "system" fn(sys::ExportedLocalizationMapML) -> sys::Result;
+    pub type GetExportedLocalizationMapDataML = unsafe extern "system" fn(
+        sys::ExportedLocalizationMapML,
+        u32,
+        *mut u32,
+        *mut c_char,
+    ) -> sys::Result;
+}
@@ -0,0 +1,170 @@
+//! Locating many spaces in one call with [`XR_KHR_locate_spaces`].
+//!
+//! The extension must be [enabled by name](crate#extensions-without-extensionset-fields). The
+//! equivalent OpenXR 1.1 function is used instead where the runtime only exposes that.
+//!
+//! [`XR_KHR_locate_spaces`]: https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_locate_spaces
+
+use std::{
+    ptr,
+    sync::{Arc, Mutex},
+};
+
+use crate::*;
+
+impl<G> Session<G> {
+    /// Locate each of `spaces` relative to `base` at `time`, in one call to the runtime
+    ///
+    /// Much cheaper than calling [`Space::locate`] for each space when there are many, e.g. body
+    /// joints or trackers. Requires [`XR_KHR_locate_spaces`] or OpenXR 1.1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the spaces belongs to another session.
+    ///
+    /// [`XR_KHR_locate_spaces`]: https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_locate_spaces
+    pub fn locate_spaces(
+        &self,
+        spaces: &[&Space],
+        base: &Space,
+        time: Time,
+    ) -> Result<Vec<SpaceLocation>> {
+        let mut locations = vec![sys::SpaceLocationDataKHR::default(); spaces.len()];
+        self.locate_spaces_raw(spaces, base, time, &mut locations, ptr::null_mut())?;
+        Ok(locations.iter().map(location).collect())
+    }
+
+    /// Locate each of `spaces` relative to `base` at `time`, with velocities, in one call to the
+    /// runtime
+    ///
+    /// Like [`Session::locate_spaces`], but as [`Space::relate`].
+    pub fn relate_spaces(
+        &self,
+        spaces: &[&Space],
+        base: &Space,
+        time: Time,
+    ) -> Result<Vec<(SpaceLocation, SpaceVelocity)>> {
+        let mut locations = vec![sys::SpaceLocationDataKHR::default(); spaces.len()];
+        let mut velocities = vec![sys::SpaceVelocityDataKHR::default(); spaces.len()];
+        let mut velocities_out = sys::SpaceVelocitiesKHR::out(ptr::null_mut());
+        unsafe {
+            (*velocities_out.as_mut_ptr()).velocity_count = spaces.len() as u32;
+            (*velocities_out.as_mut_ptr()).velocities = velocities.as_mut_ptr();
+        }
+        self.locate_spaces_raw(
+            spaces,
+            base,
+            time,
+            &mut locations,
+            velocities_out.as_mut_ptr() as _,
+        )?;
+        Ok(locations
+            .iter()
+            .zip(&velocities)
+            .map(|(x, v)| (location(x), velocity(v)))
+            .collect())
+    }
+
+    fn locate_spaces_raw(
+        &self,
+        spaces: &[&Space],
+        base: &Space,
+        time: Time,
+        locations: &mut [sys::SpaceLocationDataKHR],
+        next: *mut std::os::raw::c_void,
+    ) -> Result<()> {
+        // These asserts allow this function to be safe.
+        for space in spaces.iter().copied().chain(Some(base)) {
+            assert!(
+                Arc::ptr_eq(&space.session, &self.inner),
+                "spaces must have been created, allocated, or retrieved from this `Session`"
+            );
+        }
+        let fp = self.locate_spaces_fn()?;
+        let handles = spaces.iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+        let info = sys::SpacesLocateInfoKHR {
+            ty: sys::SpacesLocateInfoKHR::TYPE,
+            next: ptr::null(),
+            base_space: base.as_raw(),
+            time,
+            space_count: handles.len() as u32,
+            spaces: handles.as_ptr(),
+        };
+        let mut out = sys::SpaceLocationsKHR::out(next as _);
+        unsafe {
+            (*out.as_mut_ptr()).location_count = locations.len() as u32;
+            (*out.as_mut_ptr()).locations = locations.as_mut_ptr();
+            cvt((fp)(self.as_raw(), &info, out.as_mut_ptr()))?;
+        }
+        Ok(())
+    }
+
+    fn locate_spaces_fn(&self) -> Result<pfn::LocateSpacesKHR> {
+        let mut cached = self.inner.locate_spaces.lock().unwrap();
+        if let Some(fp) = *cached {
+            return Ok(fp);
+        }
+        let instance = self.instance();
+        // Safety: both names refer to functions of this signature
+        let fp = unsafe {
+            load_ext_fn(instance, b"xrLocateSpacesKHR\0")
+                .or_else(|_| load_ext_fn(instance, b"xrLocateSpaces\0"))?
+        };
+        *cached = Some(fp);
+        Ok(fp)
+    }
+}
+
+/// The bulk locate function, once loaded
+pub(crate) type LocateSpacesCache = Mutex<Option<pfn::LocateSpacesKHR>>;
+
+fn location(x: &sys::SpaceLocationDataKHR) -> SpaceLocation {
+    let flags = x.location_flags;
+    SpaceLocation {
+        location_flags: flags,
+        pose: Posef {
+            orientation: valid_or_default(
+                flags.contains(SpaceLocationFlags::ORIENTATION_VALID),
+                x.pose.orientation,
+            ),
+            position: valid_or_default(
+                flags.contains(SpaceLocationFlags::POSITION_VALID),
+                x.pose.position,
+            ),
+        },
+    }
+}
+
+fn velocity(x: &sys::SpaceVelocityDataKHR) -> SpaceVelocity {
+    let flags = x.velocity_flags;
+    SpaceVelocity {
+        velocity_flags: flags,
+        linear_velocity: valid_or_default(
+            flags.contains(SpaceVelocityFlags::LINEAR_VALID),
+            x.linear_velocity,
+        ),
+        angular_velocity: valid_or_default(
+            flags.contains(SpaceVelocityFlags::ANGULAR_VALID),
+            x.angular_velocity,
+        ),
+    }
+}
+
+/// Runtimes may leave the parts of a location not flagged as valid as they were
+fn valid_or_default<T: Default>(valid: bool, x: T) -> T {
+    if valid {
+        x
+    } else {
+        T::default()
+    }
+}
+
+pub(crate) mod pfn {
+    use crate::sys;
+
+    pub type LocateSpacesKHR = unsafe extern "system" fn(
+        sys::Session,
+        *const sys::SpacesLocateInfoKHR,
+        *mut sys::SpaceLocationsKHR,
+    ) -> sys::Result;
+}
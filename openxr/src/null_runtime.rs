@@ -0,0 +1,1253 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    ffi::CStr,
+    mem,
+    os::raw::c_char,
+    ptr,
+    sync::{Mutex, MutexGuard},
+};
+
+use crate::*;
+
+impl Entry {
+    /// Access a built-in OpenXR implementation that displays nothing and has no input devices
+    ///
+    /// Available if the `null-runtime` feature is enabled.
+    ///
+    /// Every call returns immediately: frames are paced only by the application, swapchains have
+    /// no images, the head and eyes are fixed at the origin of every reference space, and all
+    /// actions are inactive. Sessions advance to the `FOCUSED` state as soon as they're begun, and
+    /// shut down normally after `Session::request_exit`. This allows application logic and
+    /// examples to be exercised in environments without a runtime or GPU, such as CI containers.
+    /// See `Entry::desktop_sim` for simulated input devices.
+    ///
+    /// Only core functions and [`XR_MND_headless`] are supported, so sessions must be created
+    /// with [`Headless`] graphics.
+    ///
+    /// [`XR_MND_headless`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MND_headless
+    pub fn null() -> Self {
+        unsafe {
+            Self::from_get_instance_proc_addr(get_instance_proc_addr)
+                .expect("null runtime provides all entry points")
+        }
+    }
+}
+
+const VIEW_CONFIGURATION: ViewConfigurationType = ViewConfigurationType::PRIMARY_STEREO;
+const VIEW_COUNT: usize = 2;
+const FRAME_PERIOD: i64 = 11_111_111;
+const IPD: f32 = 0.063;
+
+fn null_system() -> SystemId {
+    SystemId::from_raw(1)
+}
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+
+#[derive(Default)]
+struct State {
+    next_handle: u64,
+    /// Path strings, indexed by path value minus one
+    paths: Vec<String>,
+    /// Pending session state changes, by instance
+    events: HashMap<u64, VecDeque<(sys::Session, SessionState, Time)>>,
+    sessions: HashMap<u64, NullSession>,
+    spaces: HashMap<u64, NullSpace>,
+    bindings: Vec<SuggestedBinding>,
+    #[cfg(feature = "desktop-sim")]
+    sim: Option<crate::desktop_sim::SimState>,
+}
+
+struct NullSession {
+    instance: u64,
+    state: SessionState,
+    running: bool,
+    frame: i64,
+    frame_open: bool,
+}
+
+struct SuggestedBinding {
+    instance: u64,
+    interaction_profile: Path,
+    action: sys::Action,
+    binding: Path,
+}
+
+enum NullSpace {
+    Reference {
+        ty: ReferenceSpaceType,
+        pose: Posef,
+    },
+    Action {
+        action: sys::Action,
+        subaction_path: Path,
+        pose: Posef,
+    },
+}
+
+/// The state of an input source an action is bound to
+#[cfg_attr(not(feature = "desktop-sim"), allow(dead_code))]
+#[derive(Copy, Clone)]
+pub(crate) enum Input {
+    Bool { value: bool, changed: bool },
+    Pose(Posef),
+}
+
+impl State {
+    fn handle(&mut self) -> u64 {
+        self.next_handle += 1;
+        self.next_handle
+    }
+
+    /// Move `session` through `states`, queueing an event for each
+    fn transition(&mut self, session: sys::Session, states: &[SessionState]) {
+        let s = self.sessions.get_mut(&session.into_raw()).unwrap();
+        let time = Time::from_nanos(s.frame * FRAME_PERIOD);
+        let queue = self.events.entry(s.instance).or_default();
+        for &state in states {
+            s.state = state;
+            queue.push_back((session, state, time));
+        }
+    }
+
+    fn path_str(&self, path: Path) -> Option<&str> {
+        (path.into_raw() as usize)
+            .checked_sub(1)
+            .and_then(|i| self.paths.get(i))
+            .map(|x| &x[..])
+    }
+
+    fn find_path(&self, string: &str) -> Option<Path> {
+        self.paths
+            .iter()
+            .position(|x| x == string)
+            .map(|i| Path::from_raw(i as u64 + 1))
+    }
+
+    /// Interaction profile used for every hand, if any
+    fn interaction_profile(&self) -> Option<Path> {
+        #[cfg(feature = "desktop-sim")]
+        {
+            if self.sim.is_some() {
+                return self.find_path(crate::desktop_sim::DesktopSim::INTERACTION_PROFILE);
+            }
+        }
+        None
+    }
+
+    fn head_pose(&self) -> Posef {
+        #[cfg(feature = "desktop-sim")]
+        {
+            if let Some(ref sim) = self.sim {
+                return sim.head_pose();
+            }
+        }
+        Posef::IDENTITY
+    }
+
+    fn read_input(&self, _binding: &str) -> Option<Input> {
+        #[cfg(feature = "desktop-sim")]
+        {
+            if let Some(ref sim) = self.sim {
+                return sim.input(_binding);
+            }
+        }
+        None
+    }
+
+    /// Inputs bound to `action` in the current interaction profile, limited to those under
+    /// `subaction_path` unless it's NULL
+    fn inputs(&self, action: sys::Action, subaction_path: Path) -> Vec<Input> {
+        let profile = match self.interaction_profile() {
+            Some(x) => x,
+            None => return Vec::new(),
+        };
+        let prefix = self.path_str(subaction_path);
+        self.bindings
+            .iter()
+            .filter(|x| x.interaction_profile == profile && x.action == action)
+            .filter_map(|x| {
+                let binding = self.path_str(x.binding)?;
+                if let Some(prefix) = prefix {
+                    if !binding.starts_with(prefix) || !binding[prefix.len()..].starts_with('/') {
+                        return None;
+                    }
+                }
+                self.read_input(binding)
+            })
+            .collect()
+    }
+
+    /// Pose of `space` relative to the origin shared by the stationary reference spaces
+    fn space_pose(&self, space: sys::Space) -> Option<Posef> {
+        match *self.spaces.get(&space.into_raw())? {
+            NullSpace::Reference { ty, pose } => Some(if ty == ReferenceSpaceType::VIEW {
+                compose(&self.head_pose(), &pose)
+            } else {
+                pose
+            }),
+            NullSpace::Action {
+                action,
+                subaction_path,
+                pose,
+            } => self
+                .inputs(action, subaction_path)
+                .into_iter()
+                .find_map(|x| match x {
+                    Input::Pose(origin) => Some(compose(&origin, &pose)),
+                    _ => None,
+                }),
+        }
+    }
+}
+
+fn state() -> MutexGuard<'static, Option<State>> {
+    let mut guard = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    guard.get_or_insert_with(State::default);
+    guard
+}
+
+/// Run `f` on the simulated devices, enabling the simulation if it isn't already
+#[cfg(feature = "desktop-sim")]
+pub(crate) fn with_sim<R>(f: impl FnOnce(&mut crate::desktop_sim::SimState) -> R) -> R {
+    let mut guard = state();
+    f(guard
+        .as_mut()
+        .unwrap()
+        .sim
+        .get_or_insert_with(Default::default))
+}
+
+macro_rules! with_session {
+    ($session:expr, |$state:ident, $s:ident| $body:expr) => {{
+        let mut guard = state();
+        let $state = guard.as_mut().unwrap();
+        match $state.sessions.get_mut(&$session.into_raw()) {
+            Some($s) => $body,
+            None => sys::Result::ERROR_HANDLE_INVALID,
+        }
+    }};
+}
+
+/// Implement the two-call idiom for `len` elements, initializing each with `write`
+unsafe fn enumerate(
+    len: usize,
+    capacity: u32,
+    count: *mut u32,
+    mut write: impl FnMut(usize),
+) -> sys::Result {
+    *count = len as u32;
+    if capacity == 0 {
+        return sys::Result::SUCCESS;
+    }
+    if (capacity as usize) < len {
+        return sys::Result::ERROR_SIZE_INSUFFICIENT;
+    }
+    (0..len).for_each(&mut write);
+    sys::Result::SUCCESS
+}
+
+unsafe fn enumerate_copy<T: Copy>(
+    items: &[T],
+    capacity: u32,
+    count: *mut u32,
+    out: *mut T,
+) -> sys::Result {
+    enumerate(items.len(), capacity, count, |i| *out.add(i) = items[i])
+}
+
+unsafe fn enumerate_str(s: &str, capacity: u32, count: *mut u32, out: *mut c_char) -> sys::Result {
+    let bytes = s.as_bytes();
+    enumerate(bytes.len() + 1, capacity, count, |i| {
+        *out.add(i) = bytes.get(i).copied().unwrap_or(0) as c_char
+    })
+}
+
+unsafe fn write_str(out: *mut c_char, capacity: usize, s: &str) {
+    let out = std::slice::from_raw_parts_mut(out, capacity);
+    let len = s.len().min(capacity - 1);
+    for (o, &b) in out.iter_mut().zip(&s.as_bytes()[..len]) {
+        *o = b as c_char;
+    }
+    out[len] = 0;
+}
+
+macro_rules! lookup {
+    ($name:expr, $($xr:literal => $f:ident: $ty:ident,)*) => {
+        match $name {
+            $($xr => Some(mem::transmute::<sys::pfn::$ty, sys::pfn::VoidFunction>(
+                $f as sys::pfn::$ty,
+            )),)*
+            _ => None,
+        }
+    };
+}
+
+unsafe extern "system" fn get_instance_proc_addr(
+    _instance: sys::Instance,
+    name: *const c_char,
+    function: *mut Option<sys::pfn::VoidFunction>,
+) -> sys::Result {
+    *function = lookup!(CStr::from_ptr(name).to_bytes(),
+        b"xrGetInstanceProcAddr" => get_instance_proc_addr: GetInstanceProcAddr,
+        b"xrEnumerateApiLayerProperties" => enumerate_api_layer_properties: EnumerateApiLayerProperties,
+        b"xrEnumerateInstanceExtensionProperties" => enumerate_instance_extension_properties: EnumerateInstanceExtensionProperties,
+        b"xrCreateInstance" => create_instance: CreateInstance,
+        b"xrDestroyInstance" => destroy_instance: DestroyInstance,
+        b"xrResultToString" => result_to_string: ResultToString,
+        b"xrStructureTypeToString" => structure_type_to_string: StructureTypeToString,
+        b"xrGetInstanceProperties" => get_instance_properties: GetInstanceProperties,
+        b"xrGetSystem" => get_system: GetSystem,
+        b"xrGetSystemProperties" => get_system_properties: GetSystemProperties,
+        b"xrCreateSession" => create_session: CreateSession,
+        b"xrDestroySession" => destroy_session: DestroySession,
+        b"xrDestroySpace" => destroy_space: DestroySpace,
+        b"xrEnumerateSwapchainFormats" => enumerate_swapchain_formats: EnumerateSwapchainFormats,
+        b"xrCreateSwapchain" => create_swapchain: CreateSwapchain,
+        b"xrDestroySwapchain" => destroy_swapchain: DestroySwapchain,
+        b"xrEnumerateSwapchainImages" => enumerate_swapchain_images: EnumerateSwapchainImages,
+        b"xrAcquireSwapchainImage" => acquire_swapchain_image: AcquireSwapchainImage,
+        b"xrWaitSwapchainImage" => wait_swapchain_image: WaitSwapchainImage,
+        b"xrReleaseSwapchainImage" => release_swapchain_image: ReleaseSwapchainImage,
+        b"xrBeginSession" => begin_session: BeginSession,
+        b"xrEndSession" => end_session: EndSession,
+        b"xrRequestExitSession" => request_exit_session: RequestExitSession,
+        b"xrEnumerateReferenceSpaces" => enumerate_reference_spaces: EnumerateReferenceSpaces,
+        b"xrCreateReferenceSpace" => create_reference_space: CreateReferenceSpace,
+        b"xrCreateActionSpace" => create_action_space: CreateActionSpace,
+        b"xrLocateSpace" => locate_space: LocateSpace,
+        b"xrEnumerateViewConfigurations" => enumerate_view_configurations: EnumerateViewConfigurations,
+        b"xrEnumerateEnvironmentBlendModes" => enumerate_environment_blend_modes: EnumerateEnvironmentBlendModes,
+        b"xrGetViewConfigurationProperties" => get_view_configuration_properties: GetViewConfigurationProperties,
+        b"xrEnumerateViewConfigurationViews" => enumerate_view_configuration_views: EnumerateViewConfigurationViews,
+        b"xrBeginFrame" => begin_frame: BeginFrame,
+        b"xrLocateViews" => locate_views: LocateViews,
+        b"xrEndFrame" => end_frame: EndFrame,
+        b"xrWaitFrame" => wait_frame: WaitFrame,
+        b"xrApplyHapticFeedback" => apply_haptic_feedback: ApplyHapticFeedback,
+        b"xrStopHapticFeedback" => stop_haptic_feedback: StopHapticFeedback,
+        b"xrPollEvent" => poll_event: PollEvent,
+        b"xrStringToPath" => string_to_path: StringToPath,
+        b"xrPathToString" => path_to_string: PathToString,
+        b"xrGetReferenceSpaceBoundsRect" => get_reference_space_bounds_rect: GetReferenceSpaceBoundsRect,
+        b"xrGetActionStateBoolean" => get_action_state_boolean: GetActionStateBoolean,
+        b"xrGetActionStateFloat" => get_action_state_float: GetActionStateFloat,
+        b"xrGetActionStateVector2f" => get_action_state_vector2f: GetActionStateVector2f,
+        b"xrGetActionStatePose" => get_action_state_pose: GetActionStatePose,
+        b"xrCreateActionSet" => create_action_set: CreateActionSet,
+        b"xrDestroyActionSet" => destroy_handle: DestroyActionSet,
+        b"xrCreateAction" => create_action: CreateAction,
+        b"xrDestroyAction" => destroy_handle: DestroyAction,
+        b"xrSuggestInteractionProfileBindings" => suggest_interaction_profile_bindings: SuggestInteractionProfileBindings,
+        b"xrAttachSessionActionSets" => attach_session_action_sets: AttachSessionActionSets,
+        b"xrGetCurrentInteractionProfile" => get_current_interaction_profile: GetCurrentInteractionProfile,
+        b"xrSyncActions" => sync_actions: SyncActions,
+        b"xrEnumerateBoundSourcesForAction" => enumerate_bound_sources_for_action: EnumerateBoundSourcesForAction,
+        b"xrGetInputSourceLocalizedName" => get_input_source_localized_name: GetInputSourceLocalizedName,
+    );
+    if (*function).is_some() {
+        sys::Result::SUCCESS
+    } else {
+        sys::Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+}
+
+unsafe extern "system" fn enumerate_api_layer_properties(
+    _capacity: u32,
+    count: *mut u32,
+    _properties: *mut sys::ApiLayerProperties,
+) -> sys::Result {
+    *count = 0;
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    layer_name: *const c_char,
+    capacity: u32,
+    count: *mut u32,
+    properties: *mut sys::ExtensionProperties,
+) -> sys::Result {
+    if !layer_name.is_null() {
+        return sys::Result::ERROR_API_LAYER_NOT_PRESENT;
+    }
+    enumerate(1, capacity, count, |i| {
+        let out = &mut *properties.add(i);
+        let name = CStr::from_bytes_with_nul_unchecked(sys::MND_HEADLESS_EXTENSION_NAME);
+        write_str(
+            out.extension_name.as_mut_ptr(),
+            out.extension_name.len(),
+            name.to_str().unwrap(),
+        );
+        out.extension_version = sys::MND_headless_SPEC_VERSION;
+    })
+}
+
+unsafe extern "system" fn create_instance(
+    info: *const sys::InstanceCreateInfo,
+    instance: *mut sys::Instance,
+) -> sys::Result {
+    let info = &*info;
+    if info.enabled_api_layer_count != 0 {
+        return sys::Result::ERROR_API_LAYER_NOT_PRESENT;
+    }
+    for i in 0..info.enabled_extension_count as usize {
+        let name = CStr::from_ptr(*info.enabled_extension_names.add(i));
+        if name.to_bytes_with_nul() != sys::MND_HEADLESS_EXTENSION_NAME {
+            return sys::Result::ERROR_EXTENSION_NOT_PRESENT;
+        }
+    }
+    let mut guard = state();
+    let state = guard.as_mut().unwrap();
+    let handle = state.handle();
+    state.events.insert(handle, VecDeque::new());
+    *instance = sys::Instance::from_raw(handle);
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn destroy_instance(instance: sys::Instance) -> sys::Result {
+    let mut guard = state();
+    let state = guard.as_mut().unwrap();
+    state.events.remove(&instance.into_raw());
+    state
+        .sessions
+        .retain(|_, s| s.instance != instance.into_raw());
+    state.bindings.retain(|x| x.instance != instance.into_raw());
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn result_to_string(
+    _instance: sys::Instance,
+    value: sys::Result,
+    buffer: *mut c_char,
+) -> sys::Result {
+    write_str(
+        buffer,
+        sys::MAX_RESULT_STRING_SIZE,
+        &format!("XR_{:?}", value),
+    );
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn structure_type_to_string(
+    _instance: sys::Instance,
+    value: StructureType,
+    buffer: *mut c_char,
+) -> sys::Result {
+    write_str(
+        buffer,
+        sys::MAX_STRUCTURE_NAME_SIZE,
+        &format!("XR_TYPE_{:?}", value),
+    );
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_instance_properties(
+    _instance: sys::Instance,
+    properties: *mut sys::InstanceProperties,
+) -> sys::Result {
+    let properties = &mut *properties;
+    properties.runtime_version = Version::new(0, 0, 0);
+    write_str(
+        properties.runtime_name.as_mut_ptr(),
+        properties.runtime_name.len(),
+        "openxrs null runtime",
+    );
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_system(
+    _instance: sys::Instance,
+    info: *const sys::SystemGetInfo,
+    system: *mut SystemId,
+) -> sys::Result {
+    if (*info).form_factor != FormFactor::HEAD_MOUNTED_DISPLAY {
+        return sys::Result::ERROR_FORM_FACTOR_UNSUPPORTED;
+    }
+    *system = null_system();
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_system_properties(
+    _instance: sys::Instance,
+    system: SystemId,
+    properties: *mut sys::SystemProperties,
+) -> sys::Result {
+    if system != null_system() {
+        return sys::Result::ERROR_SYSTEM_INVALID;
+    }
+    let properties = &mut *properties;
+    properties.system_id = system;
+    properties.vendor_id = 0;
+    write_str(
+        properties.system_name.as_mut_ptr(),
+        properties.system_name.len(),
+        "Null HMD",
+    );
+    properties.graphics_properties = sys::SystemGraphicsProperties {
+        max_swapchain_image_height: 4096,
+        max_swapchain_image_width: 4096,
+        max_layer_count: sys::MIN_COMPOSITION_LAYERS_SUPPORTED as u32,
+    };
+    properties.tracking_properties = sys::SystemTrackingProperties {
+        orientation_tracking: true.into(),
+        position_tracking: true.into(),
+    };
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn create_session(
+    instance: sys::Instance,
+    info: *const sys::SessionCreateInfo,
+    session: *mut sys::Session,
+) -> sys::Result {
+    if (*info).system_id != null_system() {
+        return sys::Result::ERROR_SYSTEM_INVALID;
+    }
+    let mut guard = state();
+    let state = guard.as_mut().unwrap();
+    let handle = state.handle();
+    state.sessions.insert(
+        handle,
+        NullSession {
+            instance: instance.into_raw(),
+            state: SessionState::UNKNOWN,
+            running: false,
+            frame: 0,
+            frame_open: false,
+        },
+    );
+    *session = sys::Session::from_raw(handle);
+    state.transition(*session, &[SessionState::IDLE, SessionState::READY]);
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn destroy_session(session: sys::Session) -> sys::Result {
+    let mut guard = state();
+    guard.as_mut().unwrap().sessions.remove(&session.into_raw());
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn destroy_space(space: sys::Space) -> sys::Result {
+    let mut guard = state();
+    guard.as_mut().unwrap().spaces.remove(&space.into_raw());
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn destroy_handle<T>(_handle: T) -> sys::Result {
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn enumerate_swapchain_formats(
+    _session: sys::Session,
+    _capacity: u32,
+    count: *mut u32,
+    _formats: *mut i64,
+) -> sys::Result {
+    *count = 0;
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn create_swapchain(
+    _session: sys::Session,
+    _info: *const sys::SwapchainCreateInfo,
+    swapchain: *mut sys::Swapchain,
+) -> sys::Result {
+    let mut guard = state();
+    *swapchain = sys::Swapchain::from_raw(guard.as_mut().unwrap().handle());
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn destroy_swapchain(_swapchain: sys::Swapchain) -> sys::Result {
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn enumerate_swapchain_images(
+    _swapchain: sys::Swapchain,
+    _capacity: u32,
+    count: *mut u32,
+    _images: *mut sys::SwapchainImageBaseHeader,
+) -> sys::Result {
+    *count = 0;
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn acquire_swapchain_image(
+    _swapchain: sys::Swapchain,
+    _info: *const sys::SwapchainImageAcquireInfo,
+    index: *mut u32,
+) -> sys::Result {
+    *index = 0;
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn wait_swapchain_image(
+    _swapchain: sys::Swapchain,
+    _info: *const sys::SwapchainImageWaitInfo,
+) -> sys::Result {
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn release_swapchain_image(
+    _swapchain: sys::Swapchain,
+    _info: *const sys::SwapchainImageReleaseInfo,
+) -> sys::Result {
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn begin_session(
+    session: sys::Session,
+    info: *const sys::SessionBeginInfo,
+) -> sys::Result {
+    if (*info).primary_view_configuration_type != VIEW_CONFIGURATION {
+        return sys::Result::ERROR_VIEW_CONFIGURATION_TYPE_UNSUPPORTED;
+    }
+    with_session!(session, |state, s| {
+        if s.running {
+            return sys::Result::ERROR_SESSION_RUNNING;
+        }
+        if s.state != SessionState::READY {
+            return sys::Result::ERROR_SESSION_NOT_READY;
+        }
+        s.running = true;
+        state.transition(
+            session,
+            &[
+                SessionState::SYNCHRONIZED,
+                SessionState::VISIBLE,
+                SessionState::FOCUSED,
+            ],
+        );
+        sys::Result::SUCCESS
+    })
+}
+
+unsafe extern "system" fn end_session(session: sys::Session) -> sys::Result {
+    with_session!(session, |state, s| {
+        if !s.running {
+            return sys::Result::ERROR_SESSION_NOT_RUNNING;
+        }
+        if s.state != SessionState::STOPPING {
+            return sys::Result::ERROR_SESSION_NOT_STOPPING;
+        }
+        s.running = false;
+        state.transition(session, &[SessionState::IDLE, SessionState::EXITING]);
+        sys::Result::SUCCESS
+    })
+}
+
+unsafe extern "system" fn request_exit_session(session: sys::Session) -> sys::Result {
+    with_session!(session, |state, s| {
+        if !s.running {
+            return sys::Result::ERROR_SESSION_NOT_RUNNING;
+        }
+        state.transition(
+            session,
+            &[
+                SessionState::VISIBLE,
+                SessionState::SYNCHRONIZED,
+                SessionState::STOPPING,
+            ],
+        );
+        sys::Result::SUCCESS
+    })
+}
+
+unsafe extern "system" fn enumerate_reference_spaces(
+    _session: sys::Session,
+    capacity: u32,
+    count: *mut u32,
+    spaces: *mut ReferenceSpaceType,
+) -> sys::Result {
+    enumerate_copy(
+        &[
+            ReferenceSpaceType::VIEW,
+            ReferenceSpaceType::LOCAL,
+            ReferenceSpaceType::STAGE,
+        ],
+        capacity,
+        count,
+        spaces,
+    )
+}
+
+unsafe extern "system" fn create_reference_space(
+    _session: sys::Session,
+    info: *const sys::ReferenceSpaceCreateInfo,
+    space: *mut sys::Space,
+) -> sys::Result {
+    let mut guard = state();
+    let state = guard.as_mut().unwrap();
+    let handle = state.handle();
+    state.spaces.insert(
+        handle,
+        NullSpace::Reference {
+            ty: (*info).reference_space_type,
+            pose: (*info).pose_in_reference_space,
+        },
+    );
+    *space = sys::Space::from_raw(handle);
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn create_action_space(
+    _session: sys::Session,
+    info: *const sys::ActionSpaceCreateInfo,
+    space: *mut sys::Space,
+) -> sys::Result {
+    let mut guard = state();
+    let state = guard.as_mut().unwrap();
+    let handle = state.handle();
+    state.spaces.insert(
+        handle,
+        NullSpace::Action {
+            action: (*info).action,
+            subaction_path: (*info).subaction_path,
+            pose: (*info).pose_in_action_space,
+        },
+    );
+    *space = sys::Space::from_raw(handle);
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn locate_space(
+    space: sys::Space,
+    base_space: sys::Space,
+    _time: Time,
+    location: *mut sys::SpaceLocation,
+) -> sys::Result {
+    let guard = state();
+    let state = guard.as_ref().unwrap();
+    let location = &mut *location;
+    let valid = match (state.space_pose(space), state.space_pose(base_space)) {
+        (Some(pose), Some(base)) => {
+            location.location_flags = SpaceLocationFlags::ORIENTATION_VALID
+                | SpaceLocationFlags::POSITION_VALID
+                | SpaceLocationFlags::ORIENTATION_TRACKED
+                | SpaceLocationFlags::POSITION_TRACKED;
+            location.pose = compose(&invert(&base), &pose);
+            true
+        }
+        _ => {
+            location.location_flags = SpaceLocationFlags::EMPTY;
+            location.pose = Posef::IDENTITY;
+            false
+        }
+    };
+    let mut next = location.next as *mut sys::BaseOutStructure;
+    while !next.is_null() {
+        if (*next).ty == sys::SpaceVelocity::TYPE {
+            let velocity = &mut *(next as *mut sys::SpaceVelocity);
+            velocity.velocity_flags = if valid {
+                SpaceVelocityFlags::LINEAR_VALID | SpaceVelocityFlags::ANGULAR_VALID
+            } else {
+                SpaceVelocityFlags::EMPTY
+            };
+            velocity.linear_velocity = Vector3f::default();
+            velocity.angular_velocity = Vector3f::default();
+        }
+        next = (*next).next;
+    }
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn enumerate_view_configurations(
+    _instance: sys::Instance,
+    _system: SystemId,
+    capacity: u32,
+    count: *mut u32,
+    types: *mut ViewConfigurationType,
+) -> sys::Result {
+    enumerate_copy(&[VIEW_CONFIGURATION], capacity, count, types)
+}
+
+unsafe extern "system" fn enumerate_environment_blend_modes(
+    _instance: sys::Instance,
+    _system: SystemId,
+    ty: ViewConfigurationType,
+    capacity: u32,
+    count: *mut u32,
+    modes: *mut EnvironmentBlendMode,
+) -> sys::Result {
+    if ty != VIEW_CONFIGURATION {
+        return sys::Result::ERROR_VIEW_CONFIGURATION_TYPE_UNSUPPORTED;
+    }
+    enumerate_copy(&[EnvironmentBlendMode::OPAQUE], capacity, count, modes)
+}
+
+unsafe extern "system" fn get_view_configuration_properties(
+    _instance: sys::Instance,
+    _system: SystemId,
+    ty: ViewConfigurationType,
+    properties: *mut sys::ViewConfigurationProperties,
+) -> sys::Result {
+    if ty != VIEW_CONFIGURATION {
+        return sys::Result::ERROR_VIEW_CONFIGURATION_TYPE_UNSUPPORTED;
+    }
+    (*properties).view_configuration_type = ty;
+    (*properties).fov_mutable = false.into();
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn enumerate_view_configuration_views(
+    _instance: sys::Instance,
+    _system: SystemId,
+    ty: ViewConfigurationType,
+    capacity: u32,
+    count: *mut u32,
+    views: *mut sys::ViewConfigurationView,
+) -> sys::Result {
+    if ty != VIEW_CONFIGURATION {
+        return sys::Result::ERROR_VIEW_CONFIGURATION_TYPE_UNSUPPORTED;
+    }
+    enumerate(VIEW_COUNT, capacity, count, |i| {
+        let view = &mut *views.add(i);
+        view.recommended_image_rect_width = 1024;
+        view.max_image_rect_width = 4096;
+        view.recommended_image_rect_height = 1024;
+        view.max_image_rect_height = 4096;
+        view.recommended_swapchain_sample_count = 1;
+        view.max_swapchain_sample_count = 1;
+    })
+}
+
+unsafe extern "system" fn begin_frame(
+    session: sys::Session,
+    _info: *const sys::FrameBeginInfo,
+) -> sys::Result {
+    with_session!(session, |_state, s| {
+        if !s.running {
+            sys::Result::ERROR_SESSION_NOT_RUNNING
+        } else if s.frame_open {
+            sys::Result::FRAME_DISCARDED
+        } else {
+            s.frame_open = true;
+            sys::Result::SUCCESS
+        }
+    })
+}
+
+unsafe extern "system" fn locate_views(
+    _session: sys::Session,
+    info: *const sys::ViewLocateInfo,
+    view_state: *mut sys::ViewState,
+    capacity: u32,
+    count: *mut u32,
+    views: *mut sys::View,
+) -> sys::Result {
+    if (*info).view_configuration_type != VIEW_CONFIGURATION {
+        return sys::Result::ERROR_VIEW_CONFIGURATION_TYPE_UNSUPPORTED;
+    }
+    let guard = state();
+    let state = guard.as_ref().unwrap();
+    let head = match state.space_pose((*info).space) {
+        Some(base) => {
+            (*view_state).view_state_flags = ViewStateFlags::ORIENTATION_VALID
+                | ViewStateFlags::POSITION_VALID
+                | ViewStateFlags::ORIENTATION_TRACKED
+                | ViewStateFlags::POSITION_TRACKED;
+            compose(&invert(&base), &state.head_pose())
+        }
+        None => {
+            (*view_state).view_state_flags = ViewStateFlags::EMPTY;
+            Posef::IDENTITY
+        }
+    };
+    enumerate(VIEW_COUNT, capacity, count, |i| {
+        let view = &mut *views.add(i);
+        let side = if i == 0 { -1.0 } else { 1.0 };
+        view.pose = compose(
+            &head,
+            &Posef {
+                orientation: Quaternionf::IDENTITY,
+                position: Vector3f {
+                    x: side * IPD / 2.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+            },
+        );
+        view.fov = Fovf {
+            angle_left: -0.8,
+            angle_right: 0.8,
+            angle_up: 0.8,
+            angle_down: -0.8,
+        };
+    })
+}
+
+unsafe extern "system" fn end_frame(
+    session: sys::Session,
+    _info: *const sys::FrameEndInfo,
+) -> sys::Result {
+    with_session!(session, |_state, s| {
+        if !s.running {
+            sys::Result::ERROR_SESSION_NOT_RUNNING
+        } else if !s.frame_open {
+            sys::Result::ERROR_CALL_ORDER_INVALID
+        } else {
+            s.frame_open = false;
+            sys::Result::SUCCESS
+        }
+    })
+}
+
+unsafe extern "system" fn wait_frame(
+    session: sys::Session,
+    _info: *const sys::FrameWaitInfo,
+    frame_state: *mut sys::FrameState,
+) -> sys::Result {
+    with_session!(session, |_state, s| {
+        if !s.running {
+            return sys::Result::ERROR_SESSION_NOT_RUNNING;
+        }
+        s.frame += 1;
+        let frame_state = &mut *frame_state;
+        frame_state.predicted_display_time = Time::from_nanos(s.frame * FRAME_PERIOD);
+        frame_state.predicted_display_period = Duration::from_nanos(FRAME_PERIOD);
+        frame_state.should_render =
+            (s.state == SessionState::VISIBLE || s.state == SessionState::FOCUSED).into();
+        sys::Result::SUCCESS
+    })
+}
+
+unsafe extern "system" fn apply_haptic_feedback(
+    _session: sys::Session,
+    _info: *const sys::HapticActionInfo,
+    _feedback: *const sys::HapticBaseHeader,
+) -> sys::Result {
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn stop_haptic_feedback(
+    _session: sys::Session,
+    _info: *const sys::HapticActionInfo,
+) -> sys::Result {
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn poll_event(
+    instance: sys::Instance,
+    event: *mut sys::EventDataBuffer,
+) -> sys::Result {
+    let mut guard = state();
+    let state = guard.as_mut().unwrap();
+    let queue = match state.events.get_mut(&instance.into_raw()) {
+        Some(x) => x,
+        None => return sys::Result::ERROR_HANDLE_INVALID,
+    };
+    match queue.pop_front() {
+        Some((session, state, time)) => {
+            (event as *mut sys::EventDataSessionStateChanged).write(
+                sys::EventDataSessionStateChanged {
+                    ty: sys::EventDataSessionStateChanged::TYPE,
+                    next: ptr::null(),
+                    session,
+                    state,
+                    time,
+                },
+            );
+            sys::Result::SUCCESS
+        }
+        None => sys::Result::EVENT_UNAVAILABLE,
+    }
+}
+
+unsafe extern "system" fn string_to_path(
+    _instance: sys::Instance,
+    string: *const c_char,
+    path: *mut Path,
+) -> sys::Result {
+    let string = match CStr::from_ptr(string).to_str() {
+        Ok(x) if x.starts_with('/') => x,
+        _ => return sys::Result::ERROR_PATH_FORMAT_INVALID,
+    };
+    let mut guard = state();
+    let state = guard.as_mut().unwrap();
+    *path = match state.find_path(string) {
+        Some(x) => x,
+        None => {
+            state.paths.push(string.to_owned());
+            Path::from_raw(state.paths.len() as u64)
+        }
+    };
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn path_to_string(
+    _instance: sys::Instance,
+    path: Path,
+    capacity: u32,
+    count: *mut u32,
+    buffer: *mut c_char,
+) -> sys::Result {
+    let guard = state();
+    match guard.as_ref().unwrap().path_str(path) {
+        Some(x) => enumerate_str(x, capacity, count, buffer),
+        None => sys::Result::ERROR_PATH_INVALID,
+    }
+}
+
+unsafe extern "system" fn get_reference_space_bounds_rect(
+    _session: sys::Session,
+    _ty: ReferenceSpaceType,
+    bounds: *mut Extent2Df,
+) -> sys::Result {
+    *bounds = Extent2Df {
+        width: 0.0,
+        height: 0.0,
+    };
+    sys::Result::SPACE_BOUNDS_UNAVAILABLE
+}
+
+/// Combine the boolean inputs bound to an action, as (value, changed, active)
+unsafe fn bool_inputs(info: *const sys::ActionStateGetInfo) -> (bool, bool, bool) {
+    let guard = state();
+    let state = guard.as_ref().unwrap();
+    let mut out = (false, false, false);
+    for input in state.inputs((*info).action, (*info).subaction_path) {
+        if let Input::Bool { value, changed } = input {
+            out.0 |= value;
+            out.1 |= changed;
+            out.2 = true;
+        }
+    }
+    out
+}
+
+unsafe extern "system" fn get_action_state_boolean(
+    session: sys::Session,
+    info: *const sys::ActionStateGetInfo,
+    state: *mut sys::ActionStateBoolean,
+) -> sys::Result {
+    let (value, changed, active) = bool_inputs(info);
+    let state = &mut *state;
+    state.current_state = value.into();
+    state.changed_since_last_sync = changed.into();
+    state.last_change_time = if changed {
+        last_sync_time(session)
+    } else {
+        Time::from_nanos(0)
+    };
+    state.is_active = active.into();
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_action_state_float(
+    session: sys::Session,
+    info: *const sys::ActionStateGetInfo,
+    state: *mut sys::ActionStateFloat,
+) -> sys::Result {
+    let (value, changed, active) = bool_inputs(info);
+    let state = &mut *state;
+    state.current_state = if value { 1.0 } else { 0.0 };
+    state.changed_since_last_sync = changed.into();
+    state.last_change_time = if changed {
+        last_sync_time(session)
+    } else {
+        Time::from_nanos(0)
+    };
+    state.is_active = active.into();
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_action_state_vector2f(
+    _session: sys::Session,
+    _info: *const sys::ActionStateGetInfo,
+    state: *mut sys::ActionStateVector2f,
+) -> sys::Result {
+    let state = &mut *state;
+    state.current_state = Vector2f::default();
+    state.changed_since_last_sync = false.into();
+    state.last_change_time = Time::from_nanos(0);
+    state.is_active = false.into();
+    sys::Result::SUCCESS
+}
+
+/// Whether a pose input is bound to an action
+unsafe fn pose_input_active(info: *const sys::ActionStateGetInfo) -> bool {
+    let guard = state();
+    guard
+        .as_ref()
+        .unwrap()
+        .inputs((*info).action, (*info).subaction_path)
+        .iter()
+        .any(|x| matches!(x, Input::Pose(_)))
+}
+
+unsafe extern "system" fn get_action_state_pose(
+    _session: sys::Session,
+    info: *const sys::ActionStateGetInfo,
+    state: *mut sys::ActionStatePose,
+) -> sys::Result {
+    (*state).is_active = pose_input_active(info).into();
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn create_action_set(
+    _instance: sys::Instance,
+    _info: *const sys::ActionSetCreateInfo,
+    action_set: *mut sys::ActionSet,
+) -> sys::Result {
+    let mut guard = state();
+    *action_set = sys::ActionSet::from_raw(guard.as_mut().unwrap().handle());
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn create_action(
+    _action_set: sys::ActionSet,
+    _info: *const sys::ActionCreateInfo,
+    action: *mut sys::Action,
+) -> sys::Result {
+    let mut guard = state();
+    *action = sys::Action::from_raw(guard.as_mut().unwrap().handle());
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn suggest_interaction_profile_bindings(
+    instance: sys::Instance,
+    suggested: *const sys::InteractionProfileSuggestedBinding,
+) -> sys::Result {
+    let suggested = &*suggested;
+    let instance = instance.into_raw();
+    let interaction_profile = suggested.interaction_profile;
+    let mut guard = state();
+    let state = guard.as_mut().unwrap();
+    // Later suggestions for a profile replace earlier ones from the same instance
+    state
+        .bindings
+        .retain(|x| x.instance != instance || x.interaction_profile != interaction_profile);
+    for i in 0..suggested.count_suggested_bindings as usize {
+        let binding = &*suggested.suggested_bindings.add(i);
+        state.bindings.push(SuggestedBinding {
+            instance,
+            interaction_profile,
+            action: binding.action,
+            binding: binding.binding,
+        });
+    }
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn attach_session_action_sets(
+    _session: sys::Session,
+    _info: *const sys::SessionActionSetsAttachInfo,
+) -> sys::Result {
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_current_interaction_profile(
+    session: sys::Session,
+    top_level_user_path: Path,
+    profile: *mut sys::InteractionProfileState,
+) -> sys::Result {
+    let guard = state();
+    let state = guard.as_ref().unwrap();
+    let instance = match state.sessions.get(&session.into_raw()) {
+        Some(s) => s.instance,
+        None => return sys::Result::ERROR_HANDLE_INVALID,
+    };
+    let is_hand = matches!(
+        state.path_str(top_level_user_path),
+        Some("/user/hand/left") | Some("/user/hand/right")
+    );
+    (*profile).interaction_profile = match state.interaction_profile() {
+        Some(x)
+            if is_hand
+                && state
+                    .bindings
+                    .iter()
+                    .any(|b| b.instance == instance && b.interaction_profile == x) =>
+        {
+            x
+        }
+        _ => Path::NULL,
+    };
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn sync_actions(
+    session: sys::Session,
+    _info: *const sys::ActionsSyncInfo,
+) -> sys::Result {
+    with_session!(session, |_state, s| {
+        if s.state == SessionState::FOCUSED {
+            #[cfg(feature = "desktop-sim")]
+            {
+                let time = Time::from_nanos(s.frame * FRAME_PERIOD);
+                if let Some(ref mut sim) = _state.sim {
+                    sim.sync(time);
+                }
+            }
+            sys::Result::SUCCESS
+        } else {
+            sys::Result::SESSION_NOT_FOCUSED
+        }
+    })
+}
+
+/// Time at which inputs were last synchronized for `session`
+fn last_sync_time(_session: sys::Session) -> Time {
+    #[cfg(feature = "desktop-sim")]
+    {
+        if let Some(ref sim) = state().as_ref().unwrap().sim {
+            return sim.synced_at();
+        }
+    }
+    Time::from_nanos(0)
+}
+
+unsafe extern "system" fn enumerate_bound_sources_for_action(
+    _session: sys::Session,
+    _info: *const sys::BoundSourcesForActionEnumerateInfo,
+    _capacity: u32,
+    count: *mut u32,
+    _sources: *mut Path,
+) -> sys::Result {
+    *count = 0;
+    sys::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_input_source_localized_name(
+    _session: sys::Session,
+    _info: *const sys::InputSourceLocalizedNameGetInfo,
+    capacity: u32,
+    count: *mut u32,
+    buffer: *mut c_char,
+) -> sys::Result {
+    enumerate_str("", capacity, count, buffer)
+}
+
+pub(crate) fn quat_mul(a: &Quaternionf, b: &Quaternionf) -> Quaternionf {
+    Quaternionf {
+        x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+        y: a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+        z: a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+    }
+}
+
+/// The pose `b`, given relative to `a`, relative to `a`'s parent
+pub(crate) fn compose(a: &Posef, b: &Posef) -> Posef {
+    let offset = a.orientation.rotate_vector(b.position);
+    Posef {
+        orientation: quat_mul(&a.orientation, &b.orientation),
+        position: Vector3f {
+            x: a.position.x + offset.x,
+            y: a.position.y + offset.y,
+            z: a.position.z + offset.z,
+        },
+    }
+}
+
+fn invert(p: &Posef) -> Posef {
+    let orientation = p.orientation.conjugate();
+    let position = orientation.rotate_vector(p.position);
+    Posef {
+        orientation,
+        position: Vector3f {
+            x: -position.x,
+            y: -position.y,
+            z: -position.z,
+        },
+    }
+}
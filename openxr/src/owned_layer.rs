@@ -0,0 +1,242 @@
+//! Composition layer builders that own what they refer to.
+//!
+//! The builders generated for each layer type borrow their spaces and swapchains, so a layer can't
+//! outlive the frame it's built in or be sent to another thread. The owned builders here hold
+//! those through `Arc`s instead, and lend out an ordinary borrowed layer for submission through
+//! e.g. [`OwnedCompositionLayerProjection::as_layer`].
+
+use std::{
+    any::Any,
+    marker::PhantomData,
+    mem, ptr,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use crate::*;
+
+/// Shared ownership of a swapchain, as accepted by [`OwnedSwapchainSubImage::swapchain`]
+pub trait SharedSwapchain<G: Graphics>: Send + Sync + 'static {
+    /// The raw swapchain handle
+    fn swapchain_handle(&self) -> sys::Swapchain;
+}
+
+impl<G: Graphics + 'static> SharedSwapchain<G> for Swapchain<G> {
+    #[inline]
+    fn swapchain_handle(&self) -> sys::Swapchain {
+        self.as_raw()
+    }
+}
+
+impl<G: Graphics + 'static> SharedSwapchain<G> for Mutex<Swapchain<G>> {
+    fn swapchain_handle(&self) -> sys::Swapchain {
+        match self.lock() {
+            Ok(x) => x.as_raw(),
+            Err(e) => e.into_inner().as_raw(),
+        }
+    }
+}
+
+impl<G: Graphics + 'static> SharedSwapchain<G> for RwLock<Swapchain<G>> {
+    fn swapchain_handle(&self) -> sys::Swapchain {
+        match self.read() {
+            Ok(x) => x.as_raw(),
+            Err(e) => e.into_inner().as_raw(),
+        }
+    }
+}
+
+type KeepAlive = Arc<dyn Any + Send + Sync>;
+
+/// A [`SwapchainSubImage`] keeping its swapchain alive
+#[derive(Clone)]
+pub struct OwnedSwapchainSubImage<G: Graphics> {
+    inner: sys::SwapchainSubImage,
+    swapchain: Option<KeepAlive>,
+    _marker: PhantomData<fn(G)>,
+}
+
+impl<G: Graphics> OwnedSwapchainSubImage<G> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: unsafe { mem::zeroed() },
+            swapchain: None,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn swapchain<S: SharedSwapchain<G>>(mut self, value: Arc<S>) -> Self {
+        self.inner.swapchain = value.swapchain_handle();
+        self.swapchain = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn image_rect(mut self, value: Rect2Di) -> Self {
+        self.inner.image_rect = value;
+        self
+    }
+
+    #[inline]
+    pub fn image_array_index(mut self, value: u32) -> Self {
+        self.inner.image_array_index = value;
+        self
+    }
+
+    /// Borrow as an ordinary sub-image
+    #[inline]
+    pub fn as_sub_image(&self) -> SwapchainSubImage<'_, G> {
+        unsafe { SwapchainSubImage::from_raw(self.inner) }
+    }
+}
+
+impl<G: Graphics> Default for OwnedSwapchainSubImage<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`CompositionLayerProjectionView`] keeping its swapchain alive
+#[derive(Clone)]
+pub struct OwnedCompositionLayerProjectionView<G: Graphics> {
+    inner: sys::CompositionLayerProjectionView,
+    swapchain: Option<KeepAlive>,
+    _marker: PhantomData<fn(G)>,
+}
+
+impl<G: Graphics> OwnedCompositionLayerProjectionView<G> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: sys::CompositionLayerProjectionView {
+                ty: sys::CompositionLayerProjectionView::TYPE,
+                ..unsafe { mem::zeroed() }
+            },
+            swapchain: None,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn pose(mut self, value: Posef) -> Self {
+        self.inner.pose = value;
+        self
+    }
+
+    #[inline]
+    pub fn fov(mut self, value: Fovf) -> Self {
+        self.inner.fov = value;
+        self
+    }
+
+    #[inline]
+    pub fn sub_image(mut self, value: OwnedSwapchainSubImage<G>) -> Self {
+        self.inner.sub_image = value.inner;
+        self.swapchain = value.swapchain;
+        self
+    }
+}
+
+impl<G: Graphics> Default for OwnedCompositionLayerProjectionView<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`CompositionLayerProjection`] keeping its space and swapchains alive
+///
+/// May be built on one thread and submitted from another, or kept across frames and updated in
+/// place.
+#[derive(Clone)]
+pub struct OwnedCompositionLayerProjection<G: Graphics> {
+    inner: sys::CompositionLayerProjection,
+    space: Option<Arc<Space>>,
+    views: Vec<sys::CompositionLayerProjectionView>,
+    swapchains: Vec<Option<KeepAlive>>,
+    _marker: PhantomData<fn(G)>,
+}
+
+impl<G: Graphics> OwnedCompositionLayerProjection<G> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: sys::CompositionLayerProjection {
+                ty: sys::CompositionLayerProjection::TYPE,
+                ..unsafe { mem::zeroed() }
+            },
+            space: None,
+            views: Vec::new(),
+            swapchains: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn layer_flags(mut self, value: CompositionLayerFlags) -> Self {
+        self.inner.layer_flags = value;
+        self
+    }
+
+    #[inline]
+    pub fn space(mut self, value: Arc<Space>) -> Self {
+        self.inner.space = value.as_raw();
+        self.space = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn views(mut self, value: Vec<OwnedCompositionLayerProjectionView<G>>) -> Self {
+        self.set_views(value);
+        self
+    }
+
+    /// Replace the views in place, e.g. with freshly located poses for the next frame
+    pub fn set_views(&mut self, value: Vec<OwnedCompositionLayerProjectionView<G>>) {
+        let (views, swapchains) = value
+            .into_iter()
+            .map(|view| (view.inner, view.swapchain))
+            .unzip();
+        self.views = views;
+        self.swapchains = swapchains;
+    }
+
+    /// Update the pose and field of view of the view at `index`, keeping its sub-image
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    #[inline]
+    pub fn set_view_pose(&mut self, index: usize, pose: Posef, fov: Fovf) {
+        let view = &mut self.views[index];
+        view.pose = pose;
+        view.fov = fov;
+    }
+
+    /// Borrow as an ordinary layer, e.g. to pass to [`FrameStream::end`]
+    pub fn as_layer(&self) -> CompositionLayerProjection<'_, G> {
+        assert!(self.views.len() <= u32::MAX as usize);
+        unsafe {
+            CompositionLayerProjection::from_raw(sys::CompositionLayerProjection {
+                next: ptr::null(),
+                view_count: self.views.len() as u32,
+                views: self.views.as_ptr(),
+                ..self.inner
+            })
+        }
+    }
+}
+
+impl<G: Graphics> Default for OwnedCompositionLayerProjection<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The raw structures' pointers are either null or rebuilt from owned data on each borrow
+unsafe impl<G: Graphics> Send for OwnedSwapchainSubImage<G> {}
+unsafe impl<G: Graphics> Sync for OwnedSwapchainSubImage<G> {}
+unsafe impl<G: Graphics> Send for OwnedCompositionLayerProjectionView<G> {}
+unsafe impl<G: Graphics> Sync for OwnedCompositionLayerProjectionView<G> {}
+unsafe impl<G: Graphics> Send for OwnedCompositionLayerProjection<G> {}
+unsafe impl<G: Graphics> Sync for OwnedCompositionLayerProjection<G> {}
@@ -8,11 +8,13 @@
 //!
 //! Not all features are currently implemented. Execution control features are implemented, style-oriented features are not.
 use crate::{
-    cvt, raw, session, sys, PassthroughFlagsFB, PassthroughLayerPurposeFB, Result, Session,
-    SessionInner,
+    cvt, raw, session, sys, CompositionLayerBase, CompositionLayerFlags, EnvironmentBlendMode,
+    Graphics, PassthroughFlagsFB, PassthroughLayerPurposeFB, Result, Session, SessionInner, Space,
 };
-use std::ptr;
+use std::marker::PhantomData;
+use std::ops::Deref;
 use std::sync::Arc;
+use std::{fmt, mem, ptr};
 use sys::PassthroughLayerFB;
 
 /// A [passthrough feature].
@@ -30,6 +32,37 @@ pub struct Passthrough {
 }
 
 impl Passthrough {
+    /// Take ownership of an existing passthrough feature handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid passthrough feature handle associated with `session`.
+    #[inline]
+    pub unsafe fn from_raw<G>(session: &Session<G>, handle: sys::PassthroughFB) -> Self {
+        Self {
+            session: session.inner.clone(),
+            handle,
+        }
+    }
+
+    #[inline]
+    pub fn as_raw(&self) -> sys::PassthroughFB {
+        self.handle
+    }
+
+    /// Give up ownership of the handle without destroying it
+    ///
+    /// The caller becomes responsible for destroying the returned handle.
+    #[inline]
+    pub fn into_raw(self) -> sys::PassthroughFB {
+        let mut this = mem::ManuallyDrop::new(self);
+        // Release everything but the handle, which `Drop` would destroy
+        unsafe {
+            ptr::drop_in_place(&mut this.session);
+        }
+        this.handle
+    }
+
     /// [Create](https://www.khronos.org/registry/OpenXR/specs/1.0/man/html/openxr.html#xrCreatePassthroughFB)
     /// a passthrough feature.
     pub(crate) fn create<G>(session: &Session<G>, flags: PassthroughFlagsFB) -> Result<Self> {
@@ -94,6 +127,37 @@ pub struct PassthroughLayer {
 }
 
 impl PassthroughLayer {
+    /// Take ownership of an existing passthrough layer handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid passthrough layer handle associated with `session`.
+    #[inline]
+    pub unsafe fn from_raw<G>(session: &Session<G>, handle: sys::PassthroughLayerFB) -> Self {
+        Self {
+            session: session.inner.clone(),
+            handle,
+        }
+    }
+
+    #[inline]
+    pub fn as_raw(&self) -> sys::PassthroughLayerFB {
+        self.handle
+    }
+
+    /// Give up ownership of the handle without destroying it
+    ///
+    /// The caller becomes responsible for destroying the returned handle.
+    #[inline]
+    pub fn into_raw(self) -> sys::PassthroughLayerFB {
+        let mut this = mem::ManuallyDrop::new(self);
+        // Release everything but the handle, which `Drop` would destroy
+        unsafe {
+            ptr::drop_in_place(&mut this.session);
+        }
+        this.handle
+    }
+
     /// [Create](https://www.khronos.org/registry/OpenXR/specs/1.0/man/html/openxr.html#xrCreatePassthroughLayerFB)
     /// a passthrough layer.
     pub(crate) fn create<G>(
@@ -157,6 +221,186 @@ impl Drop for PassthroughLayer {
     }
 }
 
+/// A composition layer displaying a [`PassthroughLayer`]
+///
+/// Requires [`XR_FB_passthrough`].
+///
+/// [`XR_FB_passthrough`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_FB_passthrough
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct CompositionLayerPassthroughFB<'a, G: Graphics> {
+    inner: sys::CompositionLayerPassthroughFB,
+    _marker: PhantomData<&'a G>,
+}
+
+impl<'a, G: Graphics> CompositionLayerPassthroughFB<'a, G> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: sys::CompositionLayerPassthroughFB {
+                ty: sys::CompositionLayerPassthroughFB::TYPE,
+                ..unsafe { mem::zeroed() }
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Initialize with the supplied raw values
+    ///
+    /// # Safety
+    ///
+    /// The guarantees normally enforced by this builder (e.g. lifetimes) must be preserved.
+    #[inline]
+    pub unsafe fn from_raw(inner: sys::CompositionLayerPassthroughFB) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn into_raw(self) -> sys::CompositionLayerPassthroughFB {
+        self.inner
+    }
+
+    #[inline]
+    pub fn as_raw(&self) -> &sys::CompositionLayerPassthroughFB {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn layer_flags(mut self, value: CompositionLayerFlags) -> Self {
+        self.inner.flags = value;
+        self
+    }
+
+    #[inline]
+    pub fn space(mut self, value: &'a Space) -> Self {
+        self.inner.space = value.as_raw();
+        self
+    }
+
+    #[inline]
+    pub fn layer_handle(mut self, value: &'a PassthroughLayer) -> Self {
+        self.inner.layer_handle = value.handle;
+        self
+    }
+}
+
+impl<'a, G: Graphics> Deref for CompositionLayerPassthroughFB<'a, G> {
+    type Target = CompositionLayerBase<'a, G>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(&self.inner as *const _ as *const CompositionLayerBase<'a, G>) }
+    }
+}
+
+impl<'a, G: Graphics> Default for CompositionLayerPassthroughFB<'a, G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Where passthrough is composited relative to the application's own layers
+///
+/// Layers are composited in submission order, so the placement determines both where the
+/// passthrough layer goes in the list passed to [`FrameStream::end`] and which layers must blend
+/// with texture alpha for the others to show through. Getting either wrong doesn't produce an
+/// error from the runtime, just a black or passthrough-free image.
+///
+/// [`FrameStream::end`]: crate::FrameStream::end
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PassthroughPlacementFB {
+    /// Passthrough is drawn first and shows wherever the application's layers are transparent
+    Underlay,
+    /// Passthrough is drawn last, over the application's layers
+    Overlay,
+}
+
+impl PassthroughPlacementFB {
+    /// Flags the passthrough layer should be submitted with
+    pub fn passthrough_layer_flags(self) -> CompositionLayerFlags {
+        match self {
+            Self::Underlay => CompositionLayerFlags::EMPTY,
+            Self::Overlay => CompositionLayerFlags::BLEND_TEXTURE_SOURCE_ALPHA,
+        }
+    }
+
+    /// Flags the application's projection layers must include
+    ///
+    /// Underlaid passthrough is only visible where projection layers are blended by alpha, and
+    /// so where the application clears to or renders alpha below 1.
+    pub fn projection_layer_flags(self) -> CompositionLayerFlags {
+        match self {
+            Self::Underlay => CompositionLayerFlags::BLEND_TEXTURE_SOURCE_ALPHA,
+            Self::Overlay => CompositionLayerFlags::EMPTY,
+        }
+    }
+
+    /// Check that passthrough with this placement will be visible given the session's
+    /// environment blend mode and the flags of its projection layers
+    pub fn validate(
+        self,
+        blend_mode: EnvironmentBlendMode,
+        projection_layer_flags: CompositionLayerFlags,
+    ) -> std::result::Result<(), PassthroughPlacementError> {
+        if blend_mode != EnvironmentBlendMode::OPAQUE {
+            return Err(PassthroughPlacementError::BlendMode(blend_mode));
+        }
+        let required = self.projection_layer_flags();
+        if !projection_layer_flags.contains(required) {
+            return Err(PassthroughPlacementError::MissingFlags(required));
+        }
+        Ok(())
+    }
+
+    /// Order `passthrough` relative to the application's `layers`, ready for submission
+    pub fn order<'b, 'a, G: Graphics>(
+        self,
+        passthrough: &'b CompositionLayerBase<'a, G>,
+        layers: &[&'b CompositionLayerBase<'a, G>],
+    ) -> Vec<&'b CompositionLayerBase<'a, G>> {
+        let mut out = Vec::with_capacity(layers.len() + 1);
+        if self == Self::Underlay {
+            out.push(passthrough);
+        }
+        out.extend_from_slice(layers);
+        if self == Self::Overlay {
+            out.push(passthrough);
+        }
+        out
+    }
+}
+
+/// Failure of [`PassthroughPlacementFB::validate`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PassthroughPlacementError {
+    /// Passthrough layers are composited as part of the application's image, which requires the
+    /// `OPAQUE` environment blend mode
+    BlendMode(EnvironmentBlendMode),
+    /// Projection layers lack flags the placement requires
+    MissingFlags(CompositionLayerFlags),
+}
+
+impl fmt::Display for PassthroughPlacementError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::BlendMode(mode) => write!(
+                fmt,
+                "passthrough layers require the OPAQUE environment blend mode, not {:?}",
+                mode
+            ),
+            Self::MissingFlags(flags) => write!(
+                fmt,
+                "projection layers must be submitted with {:?} for passthrough to show through",
+                flags
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PassthroughPlacementError {}
+
 #[inline]
 pub(crate) fn fp(session: &SessionInner) -> &raw::PassthroughFB {
     session
@@ -0,0 +1,143 @@
+//! Rendering with the [`XR_VARJO_quad_views`] view configuration.
+//!
+//! `PRIMARY_QUAD_VARJO` has four views: a wide, low density "context" view per eye at indices 0
+//! and 1, and a narrow, high density "focus" view per eye at indices 2 and 3. Because the two
+//! pairs have very different resolutions, each pair is best rendered into its own two-layer array
+//! swapchain.
+//!
+//! [`XR_VARJO_quad_views`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_VARJO_quad_views
+
+use crate::*;
+
+/// Number of views in the `PRIMARY_QUAD_VARJO` view configuration
+pub const QUAD_VIEW_COUNT_VARJO: usize = 4;
+
+/// Which part of the display a quad view covers
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum QuadViewRegionVARJO {
+    /// The full field of view of an eye, at low pixel density
+    Context,
+    /// The center of an eye's field of view, at high pixel density
+    Focus,
+}
+
+impl QuadViewRegionVARJO {
+    /// Region covered by the view at `view_index`
+    #[inline]
+    pub fn of_view(view_index: usize) -> Self {
+        if view_index < 2 {
+            Self::Context
+        } else {
+            Self::Focus
+        }
+    }
+}
+
+/// Rendering guidance for the views of `PRIMARY_QUAD_VARJO`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct QuadViewLayoutVARJO {
+    /// Left and right context views
+    pub context: [ViewConfigurationView; 2],
+    /// Left and right focus views
+    pub focus: [ViewConfigurationView; 2],
+}
+
+impl QuadViewLayoutVARJO {
+    /// Recommended size of each layer of a region's two-layer array swapchain
+    pub fn recommended_extent(&self, region: QuadViewRegionVARJO) -> Extent2Di {
+        let views = match region {
+            QuadViewRegionVARJO::Context => &self.context,
+            QuadViewRegionVARJO::Focus => &self.focus,
+        };
+        Extent2Di {
+            width: views[0]
+                .recommended_image_rect_width
+                .max(views[1].recommended_image_rect_width) as i32,
+            height: views[0]
+                .recommended_image_rect_height
+                .max(views[1].recommended_image_rect_height) as i32,
+        }
+    }
+
+    /// Recommended sample count for a region's swapchain
+    pub fn recommended_sample_count(&self, region: QuadViewRegionVARJO) -> u32 {
+        let views = match region {
+            QuadViewRegionVARJO::Context => &self.context,
+            QuadViewRegionVARJO::Focus => &self.focus,
+        };
+        views[0]
+            .recommended_swapchain_sample_count
+            .max(views[1].recommended_swapchain_sample_count)
+    }
+
+    /// Build the four projection views for a `PRIMARY_QUAD_VARJO` projection layer
+    ///
+    /// `views` must come from `Session::locate_views` with `PRIMARY_QUAD_VARJO`, and each
+    /// swapchain must have two array layers sized according to
+    /// [`QuadViewLayoutVARJO::recommended_extent`], with the left eye in layer 0.
+    pub fn projection_views<'a, G: Graphics>(
+        &self,
+        views: &[View],
+        context: &'a Swapchain<G>,
+        focus: &'a Swapchain<G>,
+    ) -> [CompositionLayerProjectionView<'a, G>; QUAD_VIEW_COUNT_VARJO] {
+        assert_eq!(
+            views.len(),
+            QUAD_VIEW_COUNT_VARJO,
+            "PRIMARY_QUAD_VARJO has exactly four views"
+        );
+        let view = |index: usize| {
+            let swapchain = match QuadViewRegionVARJO::of_view(index) {
+                QuadViewRegionVARJO::Context => context,
+                QuadViewRegionVARJO::Focus => focus,
+            };
+            let config = self.view(index);
+            let extent = Extent2Di {
+                width: config.recommended_image_rect_width as i32,
+                height: config.recommended_image_rect_height as i32,
+            };
+            CompositionLayerProjectionView::new()
+                .pose(views[index].pose)
+                .fov(views[index].fov)
+                .sub_image(
+                    SwapchainSubImage::new()
+                        .swapchain(swapchain)
+                        .image_array_index(index as u32 % 2)
+                        .image_rect(Rect2Di {
+                            offset: Offset2Di { x: 0, y: 0 },
+                            extent,
+                        }),
+                )
+        };
+        [view(0), view(1), view(2), view(3)]
+    }
+
+    fn view(&self, index: usize) -> &ViewConfigurationView {
+        match QuadViewRegionVARJO::of_view(index) {
+            QuadViewRegionVARJO::Context => &self.context[index],
+            QuadViewRegionVARJO::Focus => &self.focus[index - 2],
+        }
+    }
+}
+
+impl Instance {
+    /// Query rendering guidance for the `PRIMARY_QUAD_VARJO` view configuration
+    ///
+    /// Requires XR_VARJO_quad_views.
+    pub fn quad_view_layout_varjo(&self, system: SystemId) -> Result<QuadViewLayoutVARJO> {
+        if self.exts().varjo_quad_views.is_none() {
+            return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+        }
+        let views = self.enumerate_view_configuration_views(
+            system,
+            ViewConfigurationType::PRIMARY_QUAD_VARJO,
+        )?;
+        if views.len() != QUAD_VIEW_COUNT_VARJO {
+            return Err(sys::Result::ERROR_VIEW_CONFIGURATION_TYPE_UNSUPPORTED);
+        }
+        Ok(QuadViewLayoutVARJO {
+            context: [views[0], views[1]],
+            focus: [views[2], views[3]],
+        })
+    }
+}
@@ -0,0 +1,158 @@
+use crate::*;
+
+/// A reference space which follows the runtime's changes to its origin, e.g. when the user
+/// recenters or redefines their play area
+///
+/// The runtime announces such changes ahead of time with a `ReferenceSpaceChangePending` event
+/// and applies them at the event's `change_time`. Locations in the space jump at that moment, so
+/// content anchored to the old origin needs to be moved in the same frame, not when the event is
+/// polled. Pass every event to [`ReferenceSpaceTracker::handle_event`], then call
+/// [`ReferenceSpaceTracker::update`] with each frame's predicted display time; it returns the
+/// change in the first frame it applies to.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn f(
+/// #     session: &openxr::Session<openxr::Headless>,
+/// #     events: &[openxr::Event<'_>],
+/// #     frame_time: openxr::Time,
+/// # ) -> openxr::Result<()> {
+/// let mut stage = openxr::ReferenceSpaceTracker::new(
+///     session,
+///     openxr::ReferenceSpaceType::STAGE,
+///     openxr::Posef::IDENTITY,
+/// )?;
+/// for event in events {
+///     stage.handle_event(event);
+/// }
+/// if let Some(change) = stage.update(frame_time)? {
+///     // Move world-locked content using `change.pose_in_previous_space`
+/// }
+/// // Locate views etc. in `stage.space()`
+/// # Ok(())
+/// # }
+/// ```
+pub struct ReferenceSpaceTracker {
+    session: Session<AnyGraphics>,
+    reference_space_type: ReferenceSpaceType,
+    pose_in_reference_space: Posef,
+    space: Space,
+    pending: Option<ReferenceSpaceChange>,
+    recreate: bool,
+}
+
+impl ReferenceSpaceTracker {
+    /// Create a reference space of type `reference_space_type` in `session`
+    pub fn new<G>(
+        session: &Session<G>,
+        reference_space_type: ReferenceSpaceType,
+        pose_in_reference_space: Posef,
+    ) -> Result<Self> {
+        let space =
+            session.create_reference_space(reference_space_type, pose_in_reference_space)?;
+        Ok(Self {
+            session: session.clone().into_any_graphics(),
+            reference_space_type,
+            pose_in_reference_space,
+            space,
+            pending: None,
+            recreate: true,
+        })
+    }
+
+    /// Whether to replace the space with a newly created one when a change takes effect
+    ///
+    /// Enabled by default, so that runtimes which only apply the change to spaces created after
+    /// it behave like the rest. Disable it if other objects hold on to the space's handle.
+    pub fn recreate(mut self, recreate: bool) -> Self {
+        self.recreate = recreate;
+        self
+    }
+
+    #[inline]
+    pub fn space(&self) -> &Space {
+        &self.space
+    }
+
+    #[inline]
+    pub fn reference_space_type(&self) -> ReferenceSpaceType {
+        self.reference_space_type
+    }
+
+    #[inline]
+    pub fn pose_in_reference_space(&self) -> Posef {
+        self.pose_in_reference_space
+    }
+
+    /// The announced change which hasn't taken effect yet, if any
+    #[inline]
+    pub fn pending(&self) -> Option<&ReferenceSpaceChange> {
+        self.pending.as_ref()
+    }
+
+    /// Note a change announced by `event`
+    ///
+    /// Returns the change if `event` announced one to this space. A change announced before the
+    /// previous one took effect supersedes it.
+    pub fn handle_event(&mut self, event: &Event<'_>) -> Option<ReferenceSpaceChange> {
+        match event {
+            Event::ReferenceSpaceChangePending(e)
+                if e.session() == self.session.as_raw()
+                    && e.reference_space_type() == self.reference_space_type =>
+            {
+                let change = ReferenceSpaceChange {
+                    reference_space_type: e.reference_space_type(),
+                    change_time: e.change_time(),
+                    pose_in_previous_space: if e.pose_valid() {
+                        Some(e.pose_in_previous_space())
+                    } else {
+                        None
+                    },
+                };
+                self.pending = Some(change);
+                Some(change)
+            }
+            _ => None,
+        }
+    }
+
+    /// Apply the pending change if it takes effect by `time`, e.g. a frame's predicted display
+    /// time
+    ///
+    /// Returns the change when it's applied, after which [`ReferenceSpaceTracker::space`] refers
+    /// to the new origin.
+    pub fn update(&mut self, time: Time) -> Result<Option<ReferenceSpaceChange>> {
+        let change = match self.pending {
+            Some(x) if x.change_time.as_nanos() <= time.as_nanos() => x,
+            _ => return Ok(None),
+        };
+        if self.recreate {
+            self.space = self
+                .session
+                .create_reference_space(self.reference_space_type, self.pose_in_reference_space)?;
+        }
+        self.pending = None;
+        Ok(Some(change))
+    }
+
+    /// Replace the space with one offset from the reference space's origin by
+    /// `pose_in_reference_space`, e.g. to recenter on the user
+    pub fn set_pose_in_reference_space(&mut self, pose_in_reference_space: Posef) -> Result<()> {
+        self.space = self
+            .session
+            .create_reference_space(self.reference_space_type, pose_in_reference_space)?;
+        self.pose_in_reference_space = pose_in_reference_space;
+        Ok(())
+    }
+}
+
+/// A change to the origin of a reference space, from a `ReferenceSpaceChangePending` event
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ReferenceSpaceChange {
+    pub reference_space_type: ReferenceSpaceType,
+    /// When the new origin takes effect
+    pub change_time: Time,
+    /// The new origin's pose in the space before the change, if the runtime knows it
+    pub pose_in_previous_space: Option<Posef>,
+}
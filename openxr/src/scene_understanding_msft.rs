@@ -0,0 +1,376 @@
+//! Scene understanding via [`XR_MSFT_scene_understanding`], including capture and replay of scenes
+//! through [`XR_MSFT_scene_understanding_serialization`].
+//!
+//! Both extensions must be [enabled by name](crate#extensions-without-extensionset-fields).
+//!
+//! A serialized scene is a list of opaque byte buffers, one per fragment. Fragments captured with
+//! [`SceneMSFT::serialize`] can be stored and later fed to
+//! [`SceneObserverMSFT::deserialize_scene`] to reconstruct the same scene, e.g. in tests.
+//!
+//! [`XR_MSFT_scene_understanding`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding
+//! [`XR_MSFT_scene_understanding_serialization`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding_serialization
+
+use std::{ptr, sync::Arc};
+
+pub use sys::{
+    SceneComponentMSFT, SceneFrustumBoundMSFT, SceneOrientedBoxBoundMSFT, SceneSphereBoundMSFT,
+    UuidMSFT,
+};
+
+use crate::*;
+
+/// Observes the user's surroundings to compute scenes
+///
+/// Requires [`XR_MSFT_scene_understanding`].
+///
+/// [`XR_MSFT_scene_understanding`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding
+pub struct SceneObserverMSFT {
+    inner: Arc<SceneObserverInner>,
+}
+
+impl SceneObserverMSFT {
+    #[inline]
+    pub fn as_raw(&self) -> sys::SceneObserverMSFT {
+        self.inner.handle
+    }
+
+    /// Begin computing a new scene within `bounds`
+    ///
+    /// Poll [`SceneObserverMSFT::compute_state`] to find out when the scene is ready. Include
+    /// [`SceneComputeFeatureMSFT::SERIALIZE_SCENE`] in `features` to be able to
+    /// [`SceneMSFT::serialize`] the result.
+    pub fn compute_new_scene(
+        &self,
+        features: &[SceneComputeFeatureMSFT],
+        consistency: SceneComputeConsistencyMSFT,
+        bounds: &SceneBounds<'_>,
+    ) -> Result<()> {
+        assert_eq!(&*self.inner.session as *const session::SessionInner, &*bounds.space.session as *const session::SessionInner,
+                   "`self` and `bounds.space` must have been created, allocated, or retrieved from the same `Session`");
+        let info = sys::NewSceneComputeInfoMSFT {
+            ty: sys::NewSceneComputeInfoMSFT::TYPE,
+            next: ptr::null(),
+            requested_feature_count: features.len() as u32,
+            requested_features: features.as_ptr(),
+            consistency,
+            bounds: sys::SceneBoundsMSFT {
+                space: bounds.space.as_raw(),
+                time: bounds.time,
+                sphere_count: bounds.spheres.len() as u32,
+                spheres: bounds.spheres.as_ptr(),
+                box_count: bounds.boxes.len() as u32,
+                boxes: bounds.boxes.as_ptr(),
+                frustum_count: bounds.frustums.len() as u32,
+                frustums: bounds.frustums.as_ptr(),
+            },
+        };
+        unsafe {
+            cvt((self.inner.fp.compute_new_scene)(self.as_raw(), &info))?;
+        }
+        Ok(())
+    }
+
+    /// Progress of the most recent scene computation or deserialization
+    pub fn compute_state(&self) -> Result<SceneComputeStateMSFT> {
+        let mut out = SceneComputeStateMSFT::NONE;
+        unsafe {
+            cvt((self.inner.fp.get_scene_compute_state)(
+                self.as_raw(),
+                &mut out,
+            ))?;
+        }
+        Ok(out)
+    }
+
+    /// Obtain the most recently computed or deserialized scene
+    ///
+    /// [`SceneObserverMSFT::compute_state`] must have reported
+    /// [`SceneComputeStateMSFT::COMPLETED`].
+    pub fn create_scene(&self) -> Result<SceneMSFT> {
+        let info = sys::SceneCreateInfoMSFT {
+            ty: sys::SceneCreateInfoMSFT::TYPE,
+            next: ptr::null(),
+        };
+        let mut handle = sys::SceneMSFT::NULL;
+        unsafe {
+            cvt((self.inner.fp.create_scene)(
+                self.as_raw(),
+                &info,
+                &mut handle,
+            ))?;
+        }
+        Ok(SceneMSFT {
+            observer: self.inner.clone(),
+            handle,
+        })
+    }
+
+    /// Begin reconstructing a scene from fragments previously obtained from
+    /// [`SceneMSFT::serialize`]
+    ///
+    /// As with [`SceneObserverMSFT::compute_new_scene`], poll
+    /// [`SceneObserverMSFT::compute_state`] and then call [`SceneObserverMSFT::create_scene`].
+    ///
+    /// Requires [`XR_MSFT_scene_understanding_serialization`].
+    ///
+    /// [`XR_MSFT_scene_understanding_serialization`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding_serialization
+    pub fn deserialize_scene(&self, fragments: &[&[u8]]) -> Result<()> {
+        let deserialize_scene = self
+            .inner
+            .fp
+            .deserialize_scene
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let fragments = fragments
+            .iter()
+            .map(|x| sys::DeserializeSceneFragmentMSFT {
+                buffer_size: x.len() as u32,
+                buffer: x.as_ptr(),
+            })
+            .collect::<Vec<_>>();
+        let info = sys::SceneDeserializeInfoMSFT {
+            ty: sys::SceneDeserializeInfoMSFT::TYPE,
+            next: ptr::null(),
+            fragment_count: fragments.len() as u32,
+            fragments: fragments.as_ptr(),
+        };
+        unsafe {
+            cvt(deserialize_scene(self.as_raw(), &info))?;
+        }
+        Ok(())
+    }
+}
+
+/// Volumes of interest for [`SceneObserverMSFT::compute_new_scene`]
+#[derive(Copy, Clone)]
+pub struct SceneBounds<'a> {
+    /// Space in which the bounds are expressed
+    pub space: &'a Space,
+    /// Time at which `space` is located
+    pub time: Time,
+    pub spheres: &'a [SceneSphereBoundMSFT],
+    pub boxes: &'a [SceneOrientedBoxBoundMSFT],
+    pub frustums: &'a [SceneFrustumBoundMSFT],
+}
+
+/// A snapshot of the user's surroundings computed by a [`SceneObserverMSFT`]
+pub struct SceneMSFT {
+    observer: Arc<SceneObserverInner>,
+    handle: sys::SceneMSFT,
+}
+
+impl SceneMSFT {
+    #[inline]
+    pub fn as_raw(&self) -> sys::SceneMSFT {
+        self.handle
+    }
+
+    /// Enumerate the components of a particular type making up this scene
+    pub fn components(&self, ty: SceneComponentTypeMSFT) -> Result<Vec<SceneComponentMSFT>> {
+        let info = sys::SceneComponentsGetInfoMSFT {
+            ty: sys::SceneComponentsGetInfoMSFT::TYPE,
+            next: ptr::null(),
+            component_type: ty,
+        };
+        let mut out = Vec::<SceneComponentMSFT>::new();
+        let mut components = sys::SceneComponentsMSFT::out(ptr::null_mut());
+        unsafe {
+            (*components.as_mut_ptr()).component_capacity_input = 0;
+            (*components.as_mut_ptr()).components = ptr::null_mut();
+            cvt((self.observer.fp.get_scene_components)(
+                self.handle,
+                &info,
+                components.as_mut_ptr(),
+            ))?;
+            let mut components = components.assume_init();
+            loop {
+                out.reserve(components.component_count_output as usize);
+                components.component_capacity_input = out.capacity() as u32;
+                components.components = out.as_mut_ptr();
+                match cvt((self.observer.fp.get_scene_components)(
+                    self.handle,
+                    &info,
+                    &mut components,
+                )) {
+                    Ok(_) => {
+                        out.set_len(components.component_count_output as usize);
+                        return Ok(out);
+                    }
+                    Err(sys::Result::ERROR_SIZE_INSUFFICIENT) => {}
+                    Err(e) => {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Retrieve the serialized data of a single scene fragment
+    ///
+    /// Fragment IDs are the IDs of [`SceneComponentTypeMSFT::SERIALIZED_SCENE_FRAGMENT`]
+    /// components.
+    ///
+    /// Requires [`XR_MSFT_scene_understanding_serialization`].
+    ///
+    /// [`XR_MSFT_scene_understanding_serialization`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding_serialization
+    pub fn serialized_fragment_data(&self, fragment_id: UuidMSFT) -> Result<Vec<u8>> {
+        let get_serialized_scene_fragment_data = self
+            .observer
+            .fp
+            .get_serialized_scene_fragment_data
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::SerializedSceneFragmentDataGetInfoMSFT {
+            ty: sys::SerializedSceneFragmentDataGetInfoMSFT::TYPE,
+            next: ptr::null(),
+            scene_fragment_id: fragment_id,
+        };
+        get_arr(|cap, count, buf| unsafe {
+            get_serialized_scene_fragment_data(self.handle, &info, cap, count, buf)
+        })
+    }
+
+    /// Capture this scene as a list of fragments suitable for
+    /// [`SceneObserverMSFT::deserialize_scene`]
+    ///
+    /// The scene must have been computed with [`SceneComputeFeatureMSFT::SERIALIZE_SCENE`].
+    ///
+    /// Requires [`XR_MSFT_scene_understanding_serialization`].
+    ///
+    /// [`XR_MSFT_scene_understanding_serialization`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding_serialization
+    pub fn serialize(&self) -> Result<Vec<Vec<u8>>> {
+        self.components(SceneComponentTypeMSFT::SERIALIZED_SCENE_FRAGMENT)?
+            .iter()
+            .map(|fragment| self.serialized_fragment_data(fragment.id))
+            .collect()
+    }
+}
+
+impl Drop for SceneMSFT {
+    fn drop(&mut self) {
+        unsafe {
+            (self.observer.fp.destroy_scene)(self.handle);
+        }
+    }
+}
+
+impl<G> Session<G> {
+    /// Create a [`SceneObserverMSFT`]
+    ///
+    /// Requires [`XR_MSFT_scene_understanding`].
+    ///
+    /// [`XR_MSFT_scene_understanding`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding
+    pub fn create_scene_observer_msft(&self) -> Result<SceneObserverMSFT> {
+        let instance = self.instance();
+        let fp = unsafe { SceneUnderstandingMSFT::load(instance)? };
+        let info = sys::SceneObserverCreateInfoMSFT {
+            ty: sys::SceneObserverCreateInfoMSFT::TYPE,
+            next: ptr::null(),
+        };
+        let mut handle = sys::SceneObserverMSFT::NULL;
+        unsafe {
+            cvt((fp.create_scene_observer)(
+                self.as_raw(),
+                &info,
+                &mut handle,
+            ))?;
+        }
+        Ok(SceneObserverMSFT {
+            inner: Arc::new(SceneObserverInner {
+                session: self.inner.clone(),
+                fp,
+                handle,
+            }),
+        })
+    }
+}
+
+struct SceneObserverInner {
+    session: Arc<session::SessionInner>,
+    fp: SceneUnderstandingMSFT,
+    handle: sys::SceneObserverMSFT,
+}
+
+impl Drop for SceneObserverInner {
+    fn drop(&mut self) {
+        unsafe {
+            (self.fp.destroy_scene_observer)(self.handle);
+        }
+    }
+}
+
+/// Function pointers for the scene understanding extensions, which the generated bindings omit
+#[derive(Copy, Clone)]
+struct SceneUnderstandingMSFT {
+    create_scene_observer: pfn::CreateSceneObserverMSFT,
+    destroy_scene_observer: pfn::DestroySceneObserverMSFT,
+    create_scene: pfn::CreateSceneMSFT,
+    destroy_scene: pfn::DestroySceneMSFT,
+    compute_new_scene: pfn::ComputeNewSceneMSFT,
+    get_scene_compute_state: pfn::GetSceneComputeStateMSFT,
+    get_scene_components: pfn::GetSceneComponentsMSFT,
+    /// Present if `XR_MSFT_scene_understanding_serialization` is enabled
+    deserialize_scene: Option<pfn::DeserializeSceneMSFT>,
+    /// Present if `XR_MSFT_scene_understanding_serialization` is enabled
+    get_serialized_scene_fragment_data: Option<pfn::GetSerializedSceneFragmentDataMSFT>,
+}
+
+impl SceneUnderstandingMSFT {
+    unsafe fn load(instance: &Instance) -> Result<Self> {
+        Ok(Self {
+            create_scene_observer: load_ext_fn(instance, b"xrCreateSceneObserverMSFT\0")?,
+            destroy_scene_observer: load_ext_fn(instance, b"xrDestroySceneObserverMSFT\0")?,
+            create_scene: load_ext_fn(instance, b"xrCreateSceneMSFT\0")?,
+            destroy_scene: load_ext_fn(instance, b"xrDestroySceneMSFT\0")?,
+            compute_new_scene: load_ext_fn(instance, b"xrComputeNewSceneMSFT\0")?,
+            get_scene_compute_state: load_ext_fn(instance, b"xrGetSceneComputeStateMSFT\0")?,
+            get_scene_components: load_ext_fn(instance, b"xrGetSceneComponentsMSFT\0")?,
+            deserialize_scene: load_ext_fn(instance, b"xrDeserializeSceneMSFT\0").ok(),
+            get_serialized_scene_fragment_data: load_ext_fn(
+                instance,
+                b"xrGetSerializedSceneFragmentDataMSFT\0",
+            )
+            .ok(),
+        })
+    }
+}
+
+mod pfn {
+    use crate::sys;
+
+    pub type CreateSceneObserverMSFT = unsafe extern "system" fn(
+        sys::Session,
+        *const sys::SceneObserverCreateInfoMSFT,
+        *mut sys::SceneObserverMSFT,
+    ) -> sys::Result;
+    pub type DestroySceneObserverMSFT =
+        unsafe extern "system" fn(sys::SceneObserverMSFT) -> sys::Result;
+    pub type CreateSceneMSFT = unsafe extern "system" fn(
+        sys::SceneObserverMSFT,
+        *const sys::SceneCreateInfoMSFT,
+        *mut sys::SceneMSFT,
+    ) -> sys::Result;
+    pub type DestroySceneMSFT = unsafe extern "system" fn(sys::SceneMSFT) -> sys::Result;
+    pub type ComputeNewSceneMSFT = unsafe extern "system" fn(
+        sys::SceneObserverMSFT,
+        *const sys::NewSceneComputeInfoMSFT,
+    ) -> sys::Result;
+    pub type GetSceneComputeStateMSFT = unsafe extern "system" fn(
+        sys::SceneObserverMSFT,
+        *mut sys::SceneComputeStateMSFT,
+    ) -> sys::Result;
+    pub type GetSceneComponentsMSFT = unsafe extern "system" fn(
+        sys::SceneMSFT,
+        *const sys::SceneComponentsGetInfoMSFT,
+        *mut sys::SceneComponentsMSFT,
+    ) -> sys::Result;
+    pub type DeserializeSceneMSFT = unsafe extern "system" fn(
+        sys::SceneObserverMSFT,
+        *const sys::SceneDeserializeInfoMSFT,
+    ) -> sys::Result;
+    pub type GetSerializedSceneFragmentDataMSFT = unsafe extern "system" fn(
+        sys::SceneMSFT,
+        *const sys::SerializedSceneFragmentDataGetInfoMSFT,
+        u32,
+        *mut u32,
+        *mut u8,
+    ) -> sys::Result;
+}
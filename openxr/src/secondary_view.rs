@@ -10,3 +10,57 @@ pub struct SecondaryEndInfo<'a, 'b, 'c, G: Graphics> {
     pub environment_blend_mode: EnvironmentBlendMode,
     pub layers: &'a [&'b CompositionLayerBase<'c, G>],
 }
+
+impl ExtensionSet {
+    /// Whether both `XR_MSFT_secondary_view_configuration` and `XR_MSFT_first_person_observer`
+    /// are present
+    #[inline]
+    pub fn supports_first_person_observer_msft(&self) -> bool {
+        self.msft_secondary_view_configuration && self.msft_first_person_observer
+    }
+
+    /// Enable the extensions required to render the first-person observer view for mixed reality
+    /// capture
+    #[inline]
+    pub fn enable_first_person_observer_msft(&mut self) {
+        self.msft_secondary_view_configuration = true;
+        self.msft_first_person_observer = true;
+    }
+}
+
+impl Instance {
+    /// Whether the system can render the first-person observer secondary view
+    ///
+    /// Returns `false` unless both `XR_MSFT_secondary_view_configuration` and
+    /// `XR_MSFT_first_person_observer` are loaded.
+    pub fn supports_first_person_observer_msft(&self, system: SystemId) -> Result<bool> {
+        if self.exts().msft_secondary_view_configuration.is_none()
+            || self.exts().msft_first_person_observer.is_none()
+        {
+            return Ok(false);
+        }
+        Ok(self
+            .enumerate_view_configurations(system)?
+            .contains(&ViewConfigurationType::SECONDARY_MONO_FIRST_PERSON_OBSERVER_MSFT))
+    }
+}
+
+impl<G> Session<G> {
+    /// Begin the session with the first-person observer enabled as its only secondary view
+    /// configuration
+    ///
+    /// Check [`Instance::supports_first_person_observer_msft`] first. Frames should then be waited
+    /// on with `FrameWaiter::wait_secondary` and submitted with `FrameStream::end_secondary`, using
+    /// the observer's views from `Session::locate_views` with
+    /// `SECONDARY_MONO_FIRST_PERSON_OBSERVER_MSFT`.
+    #[inline]
+    pub fn begin_with_first_person_observer_msft(
+        &self,
+        ty: ViewConfigurationType,
+    ) -> Result<sys::Result> {
+        self.begin_with_secondary(
+            ty,
+            &[ViewConfigurationType::SECONDARY_MONO_FIRST_PERSON_OBSERVER_MSFT],
+        )
+    }
+}
@@ -1,5 +1,12 @@
 use std::mem::MaybeUninit;
-use std::{marker::PhantomData, ptr, sync::Arc};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+use std::{
+    ffi::c_void,
+    marker::PhantomData,
+    ptr,
+    sync::{Arc, Mutex, Weak},
+};
 
 use crate::*;
 
@@ -10,7 +17,8 @@ pub(crate) type DropGuard = Box<dyn std::any::Any + Send + Sync>;
 /// Convertible into an API-agnostic session using [`Session::into_any_graphics`].
 pub struct Session<G> {
     pub(crate) inner: Arc<SessionInner>,
-    _marker: PhantomData<G>,
+    // `G` is only a tag, and mustn't affect whether the session can be sent between threads
+    _marker: PhantomData<fn() -> G>,
 }
 
 impl<G> Session<G> {
@@ -67,11 +75,8 @@ impl<G> Session<G> {
 
     /// Request a transition to `SessionState::STOPPING` so that `end` may be called.
     #[inline]
-    pub fn request_exit(&self) -> Result<()> {
-        unsafe {
-            cvt((self.fp().request_exit_session)(self.as_raw()))?;
-        }
-        Ok(())
+    pub fn request_exit(&self) -> Result<sys::Result> {
+        unsafe { cvt((self.fp().request_exit_session)(self.as_raw())) }
     }
 
     /// Terminate a session in the `SessionState::STOPPING` state
@@ -132,6 +137,38 @@ impl<G> Session<G> {
         }
     }
 
+    /// Creates a `Space` tracking a coordinate frame obtained from the Magic Leap C API
+    ///
+    /// Requires [`XR_ML_compat`](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_compat)
+    pub fn create_space_from_coordinate_frame_uid_ml(
+        &self,
+        cfuid: sys::MLCoordinateFrameUID,
+        pose_in_coordinate_space: Posef,
+    ) -> Result<Space> {
+        let fp = self
+            .inner
+            .instance
+            .exts()
+            .ml_compat
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::CoordinateSpaceCreateInfoML {
+            ty: sys::CoordinateSpaceCreateInfoML::TYPE,
+            next: ptr::null(),
+            cfuid,
+            pose_in_coordinate_space,
+        };
+        let mut out = sys::Space::NULL;
+        unsafe {
+            cvt((fp.create_space_from_coordinate_frame_uid)(
+                self.as_raw(),
+                &info,
+                &mut out,
+            ))?;
+            Ok(Space::from_session_raw(self.inner.clone(), out))
+        }
+    }
+
     /// Get the view and projection info for a particular display time
     ///
     /// When rendering, this should be called as late as possible before the GPU accesses it to
@@ -142,10 +179,20 @@ impl<G> Session<G> {
         view_configuration_type: ViewConfigurationType,
         display_time: Time,
         space: &Space,
+    ) -> Result<(ViewStateFlags, Vec<View>)> {
+        self.locate_views_chained(ptr::null(), view_configuration_type, display_time, space)
+    }
+
+    pub(crate) fn locate_views_chained(
+        &self,
+        next: *const c_void,
+        view_configuration_type: ViewConfigurationType,
+        display_time: Time,
+        space: &Space,
     ) -> Result<(ViewStateFlags, Vec<View>)> {
         let info = sys::ViewLocateInfo {
             ty: sys::ViewLocateInfo::TYPE,
-            next: ptr::null(),
+            next,
             view_configuration_type,
             display_time,
             space: space.as_raw(),
@@ -190,35 +237,57 @@ impl<G> Session<G> {
 
     /// Enable use of action sets with a session
     ///
-    /// Once attached, action sets become immutable.
+    /// Once attached, action sets become immutable: creating actions in them fails with
+    /// `ERROR_ACTIONSETS_ALREADY_ATTACHED`, as does attaching further sets to the same session.
+    /// Applications that need to add actions later should create them in new action sets and
+    /// attach those to a new session, such as one created when recreating the session after
+    /// `SessionState::EXITING`. Previously attached sets may be attached to the new session too.
     #[inline]
     pub fn attach_action_sets(&self, sets: &[&ActionSet]) -> Result<()> {
-        let sets = sets.iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+        if self.action_sets_attached() {
+            return Err(sys::Result::ERROR_ACTIONSETS_ALREADY_ATTACHED);
+        }
+        let raw = sets.iter().map(|x| x.as_raw()).collect::<Vec<_>>();
         let info = sys::SessionActionSetsAttachInfo {
             ty: sys::SessionActionSetsAttachInfo::TYPE,
             next: ptr::null(),
-            count_action_sets: sets.len() as u32,
-            action_sets: sets.as_ptr(),
+            count_action_sets: raw.len() as u32,
+            action_sets: raw.as_ptr(),
         };
         unsafe {
             cvt((self.fp().attach_session_action_sets)(self.as_raw(), &info))?;
         }
+        self.inner
+            .action_sets_attached
+            .store(true, Ordering::Relaxed);
+        for set in sets {
+            set.mark_attached();
+        }
         Ok(())
     }
 
+    /// Whether [`Session::attach_action_sets`] has succeeded on this session
+    #[inline]
+    pub fn action_sets_attached(&self) -> bool {
+        self.inner.action_sets_attached.load(Ordering::Relaxed)
+    }
+
     /// Designate active input actions and update their states
+    ///
+    /// Returns `SESSION_NOT_FOCUSED` if the session isn't focused, in which case all action
+    /// states are inactive. The new states are then recorded into each live [`ActionHistory`];
+    /// a history whose state can't be queried misses that sync, without failing it.
     #[inline]
-    pub fn sync_actions(&self, action_sets: &[ActiveActionSet<'_>]) -> Result<()> {
+    pub fn sync_actions(&self, action_sets: &[ActiveActionSet<'_>]) -> Result<sys::Result> {
         let info = sys::ActionsSyncInfo {
             ty: sys::ActionsSyncInfo::TYPE,
             next: ptr::null(),
             count_active_action_sets: action_sets.len() as u32,
             active_action_sets: action_sets.as_ptr() as _,
         };
-        unsafe {
-            cvt((self.fp().sync_actions)(self.as_raw(), &info))?;
-        }
-        Ok(())
+        let result = unsafe { cvt((self.fp().sync_actions)(self.as_raw(), &info))? };
+        self.record_action_histories();
+        Ok(result)
     }
 
     /// Get a name for the input source in the current system locale
@@ -251,24 +320,20 @@ impl<G> Session<G> {
         view_index: u32,
         visibility_mask_type: VisibilityMaskTypeKHR,
     ) -> Result<VisibilityMask> {
-        let mut info = sys::VisibilityMaskKHR {
-            ty: sys::VisibilityMaskKHR::TYPE,
-            next: ptr::null_mut(),
-            vertex_capacity_input: 0,
-            vertex_count_output: 0,
-            vertices: ptr::null_mut(),
-            index_capacity_input: 0,
-            index_count_output: 0,
-            indices: ptr::null_mut(),
-        };
+        let mut info = sys::VisibilityMaskKHR::out(ptr::null_mut());
         unsafe {
+            (*info.as_mut_ptr()).vertex_capacity_input = 0;
+            (*info.as_mut_ptr()).vertices = ptr::null_mut();
+            (*info.as_mut_ptr()).index_capacity_input = 0;
+            (*info.as_mut_ptr()).indices = ptr::null_mut();
             cvt((self.instance().visibility_mask().get_visibility_mask)(
                 self.as_raw(),
                 view_configuration_type,
                 view_index,
                 visibility_mask_type,
-                &mut info,
+                info.as_mut_ptr(),
             ))?;
+            let mut info = info.assume_init();
             let mut out = VisibilityMask {
                 vertices: Vec::with_capacity(info.vertex_count_output as usize),
                 indices: Vec::with_capacity(info.index_count_output as usize),
@@ -349,12 +414,80 @@ impl<G> Session<G> {
         Ok(())
     }
 
+    /// Enable or disable depth estimation of the video see-through environment, which allows
+    /// composition layers with depth to be occluded by real-world objects
+    ///
+    /// Requires [`XR_VARJO_environment_depth_estimation`](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_VARJO_environment_depth_estimation)
+    pub fn set_environment_depth_estimation_varjo(&self, enabled: bool) -> Result<()> {
+        let ext = self
+            .inner
+            .instance
+            .exts()
+            .varjo_environment_depth_estimation
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        unsafe {
+            cvt((ext.set_environment_depth_estimation)(
+                self.as_raw(),
+                enabled.into(),
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Inform the runtime that the thread with ID `thread_id`, as returned by `gettid`, performs
+    /// work of type `thread_type`, so that it can be scheduled accordingly
+    ///
+    /// Requires [`XR_KHR_android_thread_settings`](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_android_thread_settings)
+    #[cfg(target_os = "android")]
+    pub fn set_android_application_thread(
+        &self,
+        thread_type: AndroidThreadTypeKHR,
+        thread_id: u32,
+    ) -> Result<()> {
+        let ext = self
+            .inner
+            .instance
+            .exts()
+            .khr_android_thread_settings
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        unsafe {
+            cvt((ext.set_android_application_thread)(
+                self.as_raw(),
+                thread_type,
+                thread_id,
+            ))?;
+        }
+        Ok(())
+    }
+
     // Private helper
     #[inline]
     fn fp(&self) -> &raw::Instance {
         self.inner.instance.fp()
     }
 
+    fn record_action_histories(&self) {
+        let live = {
+            let mut histories = self.inner.action_histories.lock().unwrap();
+            histories.retain(|x| x.strong_count() != 0);
+            histories
+                .iter()
+                .filter_map(Weak::upgrade)
+                .collect::<Vec<_>>()
+        };
+        if live.is_empty() {
+            return;
+        }
+        // Recording runs without the lock held, so histories can be created meanwhile
+        let session = self.clone().into_any_graphics();
+        for history in live {
+            // The sync itself succeeded; a history that can't be updated just misses this one
+            let _ = history.record(&session);
+        }
+    }
+
     /// Convert into a graphics API agnostic session
     ///
     /// Useful for applications which support multiple graphics APIs, but don't want `G` parameters
@@ -365,6 +498,18 @@ impl<G> Session<G> {
             _marker: PhantomData,
         }
     }
+
+    /// Create a reference to the session that doesn't keep it alive
+    ///
+    /// Useful for long-lived callbacks, which would otherwise delay the session's destruction
+    /// until they're dropped.
+    #[inline]
+    pub fn downgrade(&self) -> WeakSession<G> {
+        WeakSession {
+            inner: Arc::downgrade(&self.inner),
+            _marker: PhantomData,
+        }
+    }
 }
 
 impl<G: Graphics> Session<G> {
@@ -384,6 +529,13 @@ impl<G: Graphics> Session<G> {
             inner: Arc::new(SessionInner {
                 instance,
                 handle,
+                action_sets_attached: AtomicBool::new(false),
+                action_histories: Mutex::new(Vec::new()),
+                frame_timings: Mutex::new(Vec::new()),
+                locate_spaces: Mutex::new(None),
+                localization_map: Mutex::new(None),
+                spatial_anchors: Mutex::new(None),
+                share_spaces_meta: Mutex::new(None),
                 _drop_guard: drop_guard,
             }),
             _marker: PhantomData,
@@ -410,19 +562,7 @@ impl<G: Graphics> Session<G> {
     #[inline]
     pub fn create_swapchain(&self, info: &SwapchainCreateInfo<G>) -> Result<Swapchain<G>> {
         let mut out = sys::Swapchain::NULL;
-        let info = sys::SwapchainCreateInfo {
-            ty: sys::SwapchainCreateInfo::TYPE,
-            next: ptr::null(),
-            create_flags: info.create_flags,
-            usage_flags: info.usage_flags,
-            format: G::lower_format(info.format),
-            sample_count: info.sample_count,
-            width: info.width,
-            height: info.height,
-            face_count: info.face_count,
-            array_size: info.array_size,
-            mip_count: info.mip_count,
-        };
+        let info = info.to_raw();
         unsafe {
             cvt((self.fp().create_swapchain)(self.as_raw(), &info, &mut out))?;
             Ok(Swapchain::from_raw(self.clone(), out))
@@ -457,8 +597,11 @@ impl<G: Graphics> Session<G> {
     }
 }
 
-/// Mesh obtained from `Session::get_visibility_mask`
-#[derive(Clone, Default, PartialEq)]
+/// Mesh obtained from [`Session::get_visibility_mask_khr`]
+///
+/// Vertices are in view space on the plane at unit distance, so the view's projection maps them
+/// to clip space. Indices form a triangle list, except for `VisibilityMaskTypeKHR::LINE_LOOP`.
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct VisibilityMask {
     pub vertices: Vec<Vector2f>,
     pub indices: Vec<u32>,
@@ -473,9 +616,42 @@ impl<G> Clone for Session<G> {
     }
 }
 
+/// A reference to a [`Session`] that doesn't keep it alive, obtained from [`Session::downgrade`]
+pub struct WeakSession<G> {
+    pub(crate) inner: Weak<SessionInner>,
+    pub(crate) _marker: PhantomData<fn() -> G>,
+}
+
+impl<G> WeakSession<G> {
+    /// Access the session, unless every `Session` referring to it has been dropped
+    #[inline]
+    pub fn upgrade(&self) -> Option<Session<G>> {
+        Some(Session {
+            inner: self.inner.upgrade()?,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<G> Clone for WeakSession<G> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
 pub(crate) struct SessionInner {
     pub(crate) instance: Instance,
     pub(crate) handle: sys::Session,
+    pub(crate) action_sets_attached: AtomicBool,
+    pub(crate) action_histories: Mutex<Vec<Weak<dyn RecordActionHistory>>>,
+    pub(crate) frame_timings: frame_timing::FrameTimings,
+    pub(crate) locate_spaces: locate_spaces::LocateSpacesCache,
+    pub(crate) localization_map: localization_map_ml::LocalizationMapCache,
+    pub(crate) spatial_anchors: spatial_anchors_ml::SpatialAnchorsCache,
+    pub(crate) share_spaces_meta: spatial_entity_sharing_fb::ShareSpacesCache,
     pub(crate) _drop_guard: DropGuard,
 }
 
@@ -503,7 +679,44 @@ pub struct SwapchainCreateInfo<G: Graphics> {
     pub mip_count: u32,
 }
 
+impl<G: Graphics> SwapchainCreateInfo<G> {
+    /// Convert from the raw structure, ignoring its `next` chain
+    #[inline]
+    pub fn from_raw(raw: &sys::SwapchainCreateInfo) -> Self {
+        Self {
+            create_flags: raw.create_flags,
+            usage_flags: raw.usage_flags,
+            format: G::raise_format(raw.format),
+            sample_count: raw.sample_count,
+            width: raw.width,
+            height: raw.height,
+            face_count: raw.face_count,
+            array_size: raw.array_size,
+            mip_count: raw.mip_count,
+        }
+    }
+
+    /// Convert to the raw structure, with an empty `next` chain
+    #[inline]
+    pub fn to_raw(&self) -> sys::SwapchainCreateInfo {
+        sys::SwapchainCreateInfo {
+            ty: sys::SwapchainCreateInfo::TYPE,
+            next: ptr::null(),
+            create_flags: self.create_flags,
+            usage_flags: self.usage_flags,
+            format: G::lower_format(self.format),
+            sample_count: self.sample_count,
+            width: self.width,
+            height: self.height,
+            face_count: self.face_count,
+            array_size: self.array_size,
+            mip_count: self.mip_count,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct View {
     pub pose: Posef,
     pub fov: Fovf,
@@ -575,20 +788,24 @@ impl FrameWaiter {
     /// Block until rendering should begin, and return details to guide rendering
     #[inline]
     pub fn wait(&mut self) -> Result<FrameState> {
-        let out = unsafe {
+        let start = Instant::now();
+        let (out, status) = unsafe {
             let mut x = sys::FrameState::out(ptr::null_mut());
-            cvt((self.session.instance.fp().wait_frame)(
+            let status = cvt((self.session.instance.fp().wait_frame)(
                 self.session.handle,
                 ptr::null(),
                 x.as_mut_ptr(),
             ))?;
-            x.assume_init()
+            (x.assume_init(), status)
         };
-        Ok(FrameState {
+        let frame_state = FrameState {
             predicted_display_time: out.predicted_display_time,
             predicted_display_period: out.predicted_display_period,
             should_render: out.should_render.into(),
-        })
+            status,
+        };
+        frame_timing::record_wait(&self.session.frame_timings, start.elapsed(), &frame_state);
+        Ok(frame_state)
     }
 
     /// Same as .wait() but also returns whether each secondary view is active
@@ -605,18 +822,26 @@ impl FrameWaiter {
             count as usize,
             sys::SecondaryViewConfigurationStateMSFT::out(ptr::null_mut()),
         );
-        let out = unsafe {
+        let start = Instant::now();
+        let (out, status) = unsafe {
             let mut secondary = sys::SecondaryViewConfigurationFrameStateMSFT::out(ptr::null_mut());
             (*secondary.as_mut_ptr()).view_configuration_count = count;
             (*secondary.as_mut_ptr()).view_configuration_states = vec.as_mut_ptr() as *mut _;
             let mut x = sys::FrameState::out(&mut secondary as *mut _ as *mut _);
-            cvt((self.session.instance.fp().wait_frame)(
+            let status = cvt((self.session.instance.fp().wait_frame)(
                 self.session.handle,
                 ptr::null(),
                 x.as_mut_ptr(),
             ))?;
-            x.assume_init()
+            (x.assume_init(), status)
+        };
+        let frame_state = FrameState {
+            predicted_display_time: out.predicted_display_time,
+            predicted_display_period: out.predicted_display_period,
+            should_render: out.should_render.into(),
+            status,
         };
+        frame_timing::record_wait(&self.session.frame_timings, start.elapsed(), &frame_state);
         let secondary = vec
             .into_iter()
             .map(|x| {
@@ -627,14 +852,7 @@ impl FrameWaiter {
                 }
             })
             .collect();
-        Ok((
-            FrameState {
-                predicted_display_time: out.predicted_display_time,
-                predicted_display_period: out.predicted_display_period,
-                should_render: out.should_render.into(),
-            },
-            secondary,
-        ))
+        Ok((frame_state, secondary))
     }
 
     /// Same as .wait() but also returns whether the secondary view is active,
@@ -647,31 +865,32 @@ impl FrameWaiter {
         let mut state = [sys::SecondaryViewConfigurationStateMSFT::out(
             ptr::null_mut(),
         )];
-        let out = unsafe {
+        let start = Instant::now();
+        let (out, status) = unsafe {
             let mut secondary = sys::SecondaryViewConfigurationFrameStateMSFT::out(ptr::null_mut());
             (*secondary.as_mut_ptr()).view_configuration_count = 1;
             (*secondary.as_mut_ptr()).view_configuration_states = state.as_mut_ptr() as *mut _;
             let mut x = sys::FrameState::out(&mut secondary as *mut _ as *mut _);
-            cvt((self.session.instance.fp().wait_frame)(
+            let status = cvt((self.session.instance.fp().wait_frame)(
                 self.session.handle,
                 ptr::null(),
                 x.as_mut_ptr(),
             ))?;
-            x.assume_init()
+            (x.assume_init(), status)
         };
+        let frame_state = FrameState {
+            predicted_display_time: out.predicted_display_time,
+            predicted_display_period: out.predicted_display_period,
+            should_render: out.should_render.into(),
+            status,
+        };
+        frame_timing::record_wait(&self.session.frame_timings, start.elapsed(), &frame_state);
         let state = unsafe { state[0].assume_init() };
         let state = SecondaryViewState {
             ty: state.view_configuration_type,
             active: state.active.into(),
         };
-        Ok((
-            FrameState {
-                predicted_display_time: out.predicted_display_time,
-                predicted_display_period: out.predicted_display_period,
-                should_render: out.should_render.into(),
-            },
-            state,
-        ))
+        Ok((frame_state, state))
     }
 }
 
@@ -681,4 +900,6 @@ pub struct FrameState {
     pub predicted_display_time: Time,
     pub predicted_display_period: Duration,
     pub should_render: bool,
+    /// Success code reported by the runtime, e.g. `SESSION_LOSS_PENDING`
+    pub status: sys::Result,
 }
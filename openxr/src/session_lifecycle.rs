@@ -0,0 +1,138 @@
+use crate::*;
+
+/// How an application behaves while its session runs without input focus
+///
+/// While `SYNCHRONIZED`, the application's output isn't displayed at all; while `VISIBLE`, it's
+/// displayed but another application (e.g. a system menu) has input focus. In both cases the
+/// specification recommends reducing resource usage, and haptic feedback isn't delivered.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BackgroundPolicy {
+    /// Render only one in this many frames while `VISIBLE` but not `FOCUSED`
+    ///
+    /// `1` renders every frame the runtime asks for.
+    pub unfocused_render_interval: u32,
+    /// Skip haptic feedback requests while the session isn't `FOCUSED`
+    pub suppress_unfocused_haptics: bool,
+}
+
+impl Default for BackgroundPolicy {
+    fn default() -> Self {
+        Self {
+            unfocused_render_interval: 1,
+            suppress_unfocused_haptics: true,
+        }
+    }
+}
+
+/// Tracks a session's state and applies a [`BackgroundPolicy`] to rendering and haptics
+///
+/// Pass every event from `Instance::poll_event` to [`SessionLifecycle::handle_event`], and each
+/// frame's [`FrameDecision`] through [`SessionLifecycle::throttle`].
+#[derive(Debug, Clone)]
+pub struct SessionLifecycle {
+    policy: BackgroundPolicy,
+    state: SessionState,
+    /// Frames left to skip before the next unfocused frame is rendered
+    unfocused_frames: u32,
+}
+
+impl SessionLifecycle {
+    pub fn new(policy: BackgroundPolicy) -> Self {
+        Self {
+            policy,
+            state: SessionState::UNKNOWN,
+            unfocused_frames: 0,
+        }
+    }
+
+    /// Update the tracked state from `event`
+    ///
+    /// Returns the new state if `event` was a session state change.
+    pub fn handle_event(&mut self, event: &Event<'_>) -> Option<SessionState> {
+        match event {
+            Event::SessionStateChanged(e) => {
+                self.state = e.state();
+                self.unfocused_frames = 0;
+                Some(self.state)
+            }
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn state(&self) -> SessionState {
+        self.state
+    }
+
+    #[inline]
+    pub fn policy(&self) -> &BackgroundPolicy {
+        &self.policy
+    }
+
+    #[inline]
+    pub fn set_policy(&mut self, policy: BackgroundPolicy) {
+        self.policy = policy;
+    }
+
+    /// Whether the application's output is currently displayed
+    #[inline]
+    pub fn is_visible(&self) -> bool {
+        self.state == SessionState::VISIBLE || self.state == SessionState::FOCUSED
+    }
+
+    /// Whether the application currently receives input
+    #[inline]
+    pub fn is_focused(&self) -> bool {
+        self.state == SessionState::FOCUSED
+    }
+
+    /// Turn rendering decisions into skips according to the policy
+    ///
+    /// Frames are never rendered while `SYNCHRONIZED`. The returned decision must still be acted
+    /// on as usual; in particular, skipped frames must be ended with [`FrameLoop::skip`].
+    pub fn throttle(&mut self, decision: FrameDecision) -> FrameDecision {
+        if !decision.should_render() || self.is_focused() {
+            return decision;
+        }
+        let state = *decision.state();
+        if !self.is_visible() {
+            return FrameDecision::Skip(state);
+        }
+        if self.unfocused_frames == 0 {
+            self.unfocused_frames = self.policy.unfocused_render_interval.max(1) - 1;
+            decision
+        } else {
+            self.unfocused_frames -= 1;
+            FrameDecision::Skip(state)
+        }
+    }
+
+    /// Whether haptic feedback should currently be sent
+    #[inline]
+    pub fn haptics_allowed(&self) -> bool {
+        self.is_focused() || !self.policy.suppress_unfocused_haptics
+    }
+
+    /// Apply haptic feedback unless suppressed by the policy
+    ///
+    /// Returns whether the feedback was passed on to the runtime.
+    pub fn apply_feedback<G>(
+        &self,
+        action: &Action<Haptic>,
+        session: &Session<G>,
+        subaction_path: Path,
+        event: &HapticBase,
+    ) -> Result<bool> {
+        if !self.haptics_allowed() {
+            return Ok(false);
+        }
+        action.apply_feedback(session, subaction_path, event)?;
+        Ok(true)
+    }
+}
+
+impl Default for SessionLifecycle {
+    fn default() -> Self {
+        Self::new(BackgroundPolicy::default())
+    }
+}
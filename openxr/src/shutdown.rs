@@ -0,0 +1,247 @@
+//! Destruction of handles in dependency order.
+//!
+//! Each handle keeps its parents alive, so dropping a session while one of its swapchains is still
+//! held elsewhere doesn't destroy the session. That's safe, but applications that must destroy
+//! their graphics device after OpenXR is done with it need every handle gone first, and missing
+//! one is easy. A [`Shutdown`] takes ownership of the handles, destroys children before their
+//! parents regardless of the order they were registered in, and reports any session or instance
+//! that's still alive afterwards.
+
+use std::{error::Error, fmt, marker::PhantomData, sync::Arc};
+
+use crate::*;
+
+/// Handles which can be registered with a [`Shutdown`]
+pub trait Teardown: 'static {
+    /// Distance from the instance in the handle hierarchy, e.g. 0 for an instance, 1 for a session
+    /// and 2 for a space
+    fn depth(&self) -> u32;
+
+    /// If this is a session, a reference through which [`Shutdown::finish`] can check that it was
+    /// destroyed
+    fn as_session(&self) -> Option<WeakSession<AnyGraphics>> {
+        None
+    }
+
+    /// If this is an instance, a reference through which [`Shutdown::finish`] can check that it
+    /// was destroyed
+    fn as_instance(&self) -> Option<WeakInstance> {
+        None
+    }
+}
+
+/// Owns handles so they can be destroyed in child-before-parent order
+///
+/// Handles are destroyed by [`Shutdown::finish`], or when the `Shutdown` is dropped. The deepest
+/// handles go first; handles at the same depth go in the reverse of the order they were
+/// registered.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn f(
+/// #     session: openxr::Session<openxr::Vulkan>,
+/// #     frame_waiter: openxr::FrameWaiter,
+/// #     frame_stream: openxr::FrameStream<openxr::Vulkan>,
+/// #     swapchain: openxr::Swapchain<openxr::Vulkan>,
+/// #     stage: openxr::Space,
+/// # ) {
+/// let mut shutdown = openxr::Shutdown::new();
+/// shutdown
+///     .push(session)
+///     .push(frame_waiter)
+///     .push(frame_stream)
+///     .push(swapchain)
+///     .push(stage);
+/// shutdown
+///     .finish()
+///     .expect("OpenXR handles outlived shutdown");
+/// // Safe to destroy the graphics device now
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Shutdown {
+    handles: Vec<(u32, Box<dyn Teardown>)>,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take ownership of `handle`, to be destroyed by the shutdown
+    pub fn push(&mut self, handle: impl Teardown) -> &mut Self {
+        self.handles.push((handle.depth(), Box::new(handle)));
+        self
+    }
+
+    /// Number of handles registered
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Destroy every registered handle
+    ///
+    /// Fails if any registered session or instance is still kept alive by a handle that wasn't
+    /// registered, in which case the underlying OpenXR object hasn't been destroyed.
+    pub fn finish(mut self) -> std::result::Result<(), ShutdownError> {
+        let sessions = self
+            .handles
+            .iter()
+            .filter_map(|(_, x)| x.as_session())
+            .collect::<Vec<_>>();
+        let instances = self
+            .handles
+            .iter()
+            .filter_map(|(_, x)| x.as_instance())
+            .collect::<Vec<_>>();
+        self.destroy();
+        let error = ShutdownError {
+            sessions: sessions.iter().filter(|x| x.upgrade().is_some()).count(),
+            instances: instances.iter().filter(|x| x.upgrade().is_some()).count(),
+        };
+        if error.sessions == 0 && error.instances == 0 {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+
+    fn destroy(&mut self) {
+        // Stable, so handles at the same depth keep their registration order before reversal
+        self.handles.sort_by_key(|&(depth, _)| depth);
+        while let Some(handle) = self.handles.pop() {
+            drop(handle);
+        }
+    }
+}
+
+impl Drop for Shutdown {
+    fn drop(&mut self) {
+        self.destroy();
+    }
+}
+
+/// Error returned by [`Shutdown::finish`] when registered handles outlived it
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ShutdownError {
+    /// Number of registered sessions still alive
+    pub sessions: usize,
+    /// Number of registered instances still alive
+    pub instances: usize,
+}
+
+impl fmt::Display for ShutdownError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "{} session(s) and {} instance(s) are still referenced by unregistered handles",
+            self.sessions, self.instances
+        )
+    }
+}
+
+impl Error for ShutdownError {}
+
+impl Teardown for Instance {
+    fn depth(&self) -> u32 {
+        0
+    }
+
+    fn as_instance(&self) -> Option<WeakInstance> {
+        Some(self.downgrade())
+    }
+}
+
+impl<G: 'static> Teardown for Session<G> {
+    fn depth(&self) -> u32 {
+        1
+    }
+
+    fn as_session(&self) -> Option<WeakSession<AnyGraphics>> {
+        Some(WeakSession {
+            inner: Arc::downgrade(&self.inner),
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl Teardown for ActionSet {
+    fn depth(&self) -> u32 {
+        1
+    }
+}
+
+impl<T: ActionTy + 'static> Teardown for Action<T> {
+    fn depth(&self) -> u32 {
+        2
+    }
+}
+
+impl<T: ActionTy + 'static> Teardown for HandedAction<T> {
+    fn depth(&self) -> u32 {
+        2
+    }
+}
+
+impl Teardown for FrameWaiter {
+    fn depth(&self) -> u32 {
+        2
+    }
+}
+
+impl<G: Graphics + 'static> Teardown for FrameStream<G> {
+    fn depth(&self) -> u32 {
+        2
+    }
+}
+
+impl<G: Graphics + 'static> Teardown for Swapchain<G> {
+    fn depth(&self) -> u32 {
+        2
+    }
+}
+
+impl Teardown for Space {
+    fn depth(&self) -> u32 {
+        2
+    }
+}
+
+impl Teardown for HandTracker {
+    fn depth(&self) -> u32 {
+        2
+    }
+}
+
+impl Teardown for Passthrough {
+    fn depth(&self) -> u32 {
+        2
+    }
+}
+
+impl Teardown for PassthroughLayer {
+    fn depth(&self) -> u32 {
+        3
+    }
+}
+
+impl<T: Teardown> Teardown for Option<T> {
+    fn depth(&self) -> u32 {
+        self.as_ref().map_or(0, T::depth)
+    }
+
+    fn as_session(&self) -> Option<WeakSession<AnyGraphics>> {
+        self.as_ref()?.as_session()
+    }
+
+    fn as_instance(&self) -> Option<WeakInstance> {
+        self.as_ref()?.as_instance()
+    }
+}
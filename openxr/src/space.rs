@@ -1,4 +1,9 @@
-use std::{ffi::CString, mem::MaybeUninit, ptr, sync::Arc};
+use std::{
+    ffi::CString,
+    mem::{self, MaybeUninit},
+    ptr,
+    sync::Arc,
+};
 
 use sys::BodyJointFullBodyLocationMETA;
 
@@ -43,12 +48,39 @@ impl Space {
         }
     }
 
+    /// Take ownership of a space handle created by an extension, e.g. a spatial anchor
+    #[inline]
+    pub(crate) unsafe fn from_session_raw(
+        session: Arc<session::SessionInner>,
+        handle: sys::Space,
+    ) -> Self {
+        Self {
+            session,
+            _action_guard: None,
+            handle,
+        }
+    }
+
     /// Access the raw swapchain handle
     #[inline]
     pub fn as_raw(&self) -> sys::Space {
         self.handle
     }
 
+    /// Give up ownership of the handle without destroying it
+    ///
+    /// The caller becomes responsible for destroying the returned handle.
+    #[inline]
+    pub fn into_raw(self) -> sys::Space {
+        let mut this = mem::ManuallyDrop::new(self);
+        // Release everything but the handle, which `Drop` would destroy
+        unsafe {
+            ptr::drop_in_place(&mut this.session);
+            ptr::drop_in_place(&mut this._action_guard);
+        }
+        this.handle
+    }
+
     /// Access the `Instance` self is descended from
     #[inline]
     pub fn instance(&self) -> &Instance {
@@ -117,6 +149,20 @@ impl Space {
         }
     }
 
+    /// Determine the location and velocity of a space relative to a base space at a specified time,
+    /// if currently known by the runtime.
+    ///
+    /// Equivalent to [`Space::relate`]. Use [`SpaceVelocity::linear`] and
+    /// [`SpaceVelocity::angular`] to read only the velocities the runtime knows.
+    #[inline]
+    pub fn locate_with_velocity(
+        &self,
+        base: &Space,
+        time: Time,
+    ) -> Result<(SpaceLocation, SpaceVelocity)> {
+        self.relate(base, time)
+    }
+
     /// Determine the locations of the joints of a hand tracker relative to this space at a
     /// specified time, if currently known by the runtime.
     ///
@@ -138,19 +184,15 @@ impl Space {
                 time,
             };
             let mut locations = MaybeUninit::<[HandJointLocation; HAND_JOINT_COUNT]>::uninit();
-            let mut location_info = sys::HandJointLocationsEXT {
-                ty: sys::HandJointLocationsEXT::TYPE,
-                next: ptr::null_mut(),
-                is_active: false.into(),
-                joint_count: HAND_JOINT_COUNT as u32,
-                joint_locations: locations.as_mut_ptr() as _,
-            };
+            let mut location_info = sys::HandJointLocationsEXT::out(ptr::null_mut());
+            (*location_info.as_mut_ptr()).joint_count = HAND_JOINT_COUNT as u32;
+            (*location_info.as_mut_ptr()).joint_locations = locations.as_mut_ptr() as _;
             cvt((tracker.fp().locate_hand_joints)(
                 tracker.as_raw(),
                 &locate_info,
-                &mut location_info,
+                location_info.as_mut_ptr(),
             ))?;
-            Ok(if location_info.is_active.into() {
+            Ok(if location_info.assume_init().is_active.into() {
                 Some(locations.assume_init())
             } else {
                 None
@@ -179,26 +221,20 @@ impl Space {
                 time,
             };
             let mut velocities = MaybeUninit::<[HandJointVelocity; HAND_JOINT_COUNT]>::uninit();
-            let mut velocity_info = sys::HandJointVelocitiesEXT {
-                ty: sys::HandJointVelocitiesEXT::TYPE,
-                next: ptr::null_mut(),
-                joint_count: HAND_JOINT_COUNT as u32,
-                joint_velocities: velocities.as_mut_ptr() as _,
-            };
+            let mut velocity_info = sys::HandJointVelocitiesEXT::out(ptr::null_mut());
+            (*velocity_info.as_mut_ptr()).joint_count = HAND_JOINT_COUNT as u32;
+            (*velocity_info.as_mut_ptr()).joint_velocities = velocities.as_mut_ptr() as _;
             let mut locations = MaybeUninit::<[HandJointLocation; HAND_JOINT_COUNT]>::uninit();
-            let mut location_info = sys::HandJointLocationsEXT {
-                ty: sys::HandJointLocationsEXT::TYPE,
-                next: &mut velocity_info as *mut _ as _,
-                is_active: false.into(),
-                joint_count: HAND_JOINT_COUNT as u32,
-                joint_locations: locations.as_mut_ptr() as _,
-            };
+            let mut location_info =
+                sys::HandJointLocationsEXT::out(velocity_info.as_mut_ptr() as _);
+            (*location_info.as_mut_ptr()).joint_count = HAND_JOINT_COUNT as u32;
+            (*location_info.as_mut_ptr()).joint_locations = locations.as_mut_ptr() as _;
             cvt((tracker.fp().locate_hand_joints)(
                 tracker.as_raw(),
                 &locate_info,
-                &mut location_info,
+                location_info.as_mut_ptr(),
             ))?;
-            Ok(if location_info.is_active.into() {
+            Ok(if location_info.assume_init().is_active.into() {
                 Some((locations.assume_init(), velocities.assume_init()))
             } else {
                 None
@@ -228,22 +264,15 @@ impl Space {
                 time,
             };
             let mut locations = MaybeUninit::<[BodyJointFullBodyLocationMETA; BODY_JOINT_FULL_BODY_COUNT_META]>::uninit();
-            let mut location_info = sys::BodyJointFullBodyLocationsMETA {
-                ty: sys::BodyJointFullBodyLocationsMETA::TYPE,
-                next: ptr::null_mut(),
-                is_active: false.into(),
-                confidence: 0.0,
-                joint_count: if full_body { BODY_JOINT_FULL_BODY_COUNT_META } else { BODY_JOINT_COUNT_META } as u32,
-                joint_locations: locations.as_mut_ptr() as _,
-                skeleton_changed_count: 0,
-                time: time,
-            };
+            let mut location_info = sys::BodyJointFullBodyLocationsMETA::out(ptr::null_mut());
+            (*location_info.as_mut_ptr()).joint_count = if full_body { BODY_JOINT_FULL_BODY_COUNT_META } else { BODY_JOINT_COUNT_META } as u32;
+            (*location_info.as_mut_ptr()).joint_locations = locations.as_mut_ptr() as _;
             cvt((tracker.fp().locate_body_joints)(
                 tracker.as_raw(),
                 &locate_info,
-                &mut location_info,
+                location_info.as_mut_ptr(),
             ))?;
-            Ok(if location_info.is_active.into() {
+            Ok(if location_info.assume_init().is_active.into() {
                 Some(locations.assume_init())
             } else {
                 None
@@ -267,12 +296,57 @@ impl Drop for Space {
 }
 
 #[derive(Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpaceLocation {
     pub location_flags: SpaceLocationFlags,
     pub pose: Posef,
 }
 
 impl SpaceLocation {
+    #[inline]
+    pub fn is_position_valid(&self) -> bool {
+        self.location_flags
+            .contains(SpaceLocationFlags::POSITION_VALID)
+    }
+
+    #[inline]
+    pub fn is_orientation_valid(&self) -> bool {
+        self.location_flags
+            .contains(SpaceLocationFlags::ORIENTATION_VALID)
+    }
+
+    /// Whether the position is actively tracked, rather than e.g. inferred or last known
+    #[inline]
+    pub fn is_position_tracked(&self) -> bool {
+        self.location_flags
+            .contains(SpaceLocationFlags::POSITION_TRACKED)
+    }
+
+    /// Whether the orientation is actively tracked, rather than e.g. inferred or last known
+    #[inline]
+    pub fn is_orientation_tracked(&self) -> bool {
+        self.location_flags
+            .contains(SpaceLocationFlags::ORIENTATION_TRACKED)
+    }
+
+    /// The pose, if both its position and orientation are valid
+    #[inline]
+    pub fn valid_pose(&self) -> Option<Posef> {
+        (self.is_position_valid() && self.is_orientation_valid()).then_some(self.pose)
+    }
+
+    /// The position, if valid
+    #[inline]
+    pub fn position(&self) -> Option<Vector3f> {
+        self.is_position_valid().then_some(self.pose.position)
+    }
+
+    /// The orientation, if valid
+    #[inline]
+    pub fn orientation(&self) -> Option<Quaternionf> {
+        self.is_orientation_valid().then_some(self.pose.orientation)
+    }
+
     unsafe fn new(raw: &MaybeUninit<sys::SpaceLocation>) -> Self {
         // Applications *must* not read invalid parts of a pose, i.e. they may be uninitialized
         let ptr = raw.as_ptr();
@@ -294,6 +368,7 @@ impl SpaceLocation {
 }
 
 #[derive(Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpaceVelocity {
     pub velocity_flags: SpaceVelocityFlags,
     pub linear_velocity: Vector3f,
@@ -301,6 +376,22 @@ pub struct SpaceVelocity {
 }
 
 impl SpaceVelocity {
+    /// The linear velocity, if valid
+    #[inline]
+    pub fn linear(&self) -> Option<Vector3f> {
+        self.velocity_flags
+            .contains(SpaceVelocityFlags::LINEAR_VALID)
+            .then_some(self.linear_velocity)
+    }
+
+    /// The angular velocity, if valid
+    #[inline]
+    pub fn angular(&self) -> Option<Vector3f> {
+        self.velocity_flags
+            .contains(SpaceVelocityFlags::ANGULAR_VALID)
+            .then_some(self.angular_velocity)
+    }
+
     unsafe fn new(raw: &MaybeUninit<sys::SpaceVelocity>) -> Self {
         // Applications *must* not read invalid velocities, i.e. they may be uninitialized
         let ptr = raw.as_ptr();
@@ -0,0 +1,596 @@
+//! Persistent spatial anchors via [`XR_ML_spatial_anchors`] and
+//! [`XR_ML_spatial_anchors_storage`]
+//!
+//! Both extensions, along with `XR_EXT_future`, must be
+//! [enabled by name](crate#extensions-without-extensionset-fields).
+//!
+//! Every operation runs in the background. Each returns a future whose `complete` method yields
+//! `None` until the result is available, so they can be checked once per frame.
+//!
+//! [`XR_ML_spatial_anchors`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors
+//! [`XR_ML_spatial_anchors_storage`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage
+
+use std::{
+    fmt, ptr,
+    sync::{Arc, Mutex},
+};
+
+pub use sys::SpatialAnchorConfidenceML;
+
+use crate::*;
+
+/// Identifies a spatial anchor published to storage
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct SpatialAnchorUuidML(pub [u8; sys::UUID_SIZE_EXT]);
+
+impl SpatialAnchorUuidML {
+    #[inline]
+    pub fn from_raw(x: UuidEXT) -> Self {
+        Self(x.data)
+    }
+
+    #[inline]
+    pub fn into_raw(self) -> UuidEXT {
+        UuidEXT { data: self.0 }
+    }
+}
+
+impl fmt::Debug for SpatialAnchorUuidML {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for SpatialAnchorUuidML {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if i == 4 || i == 6 || i == 8 || i == 10 {
+                f.write_str("-")?;
+            }
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<G> Session<G> {
+    /// Begin creating a spatial anchor at `pose` in `base`, as located at `time`
+    ///
+    /// Requires [`XR_ML_spatial_anchors`].
+    ///
+    /// [`XR_ML_spatial_anchors`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors
+    pub fn create_spatial_anchor_ml(
+        &self,
+        base: &Space,
+        pose: Posef,
+        time: Time,
+    ) -> Result<CreateSpatialAnchorsFutureML> {
+        let fp = SpatialAnchorsFnML::cached(&self.inner)?;
+        let info = sys::SpatialAnchorsCreateInfoFromPoseML {
+            ty: sys::SpatialAnchorsCreateInfoFromPoseML::TYPE,
+            next: ptr::null(),
+            base_space: base.as_raw(),
+            pose_in_base_space: pose,
+            time,
+        };
+        unsafe {
+            CreateSpatialAnchorsFutureML::new(
+                self.inner.clone(),
+                fp,
+                &info as *const _ as *const sys::SpatialAnchorsCreateInfoBaseHeaderML,
+                1,
+            )
+        }
+    }
+
+    /// Open the device's spatial anchor storage
+    ///
+    /// Requires [`XR_ML_spatial_anchors_storage`].
+    ///
+    /// [`XR_ML_spatial_anchors_storage`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage
+    pub fn create_spatial_anchors_storage_ml(&self) -> Result<SpatialAnchorsStorageML> {
+        let fp = SpatialAnchorsFnML::cached(&self.inner)?;
+        let storage_fp = unsafe { SpatialAnchorsStorageFnML::load(self.instance())? };
+        let info = sys::SpatialAnchorsCreateStorageInfoML {
+            ty: sys::SpatialAnchorsCreateStorageInfoML::TYPE,
+            next: ptr::null(),
+        };
+        let mut handle = sys::SpatialAnchorsStorageML::NULL;
+        unsafe {
+            cvt((storage_fp.create_spatial_anchors_storage)(
+                self.as_raw(),
+                &info,
+                &mut handle,
+            ))?;
+        }
+        Ok(SpatialAnchorsStorageML {
+            inner: Arc::new(StorageInner {
+                session: self.inner.clone(),
+                fp,
+                storage_fp,
+                handle,
+            }),
+        })
+    }
+}
+
+impl Space {
+    /// How confident the runtime is in this spatial anchor's pose
+    ///
+    /// Requires [`XR_ML_spatial_anchors`].
+    ///
+    /// [`XR_ML_spatial_anchors`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors
+    pub fn spatial_anchor_confidence_ml(&self) -> Result<SpatialAnchorConfidenceML> {
+        let fp = SpatialAnchorsFnML::cached(&self.session)?;
+        let mut out = sys::SpatialAnchorStateML::out(ptr::null_mut());
+        unsafe {
+            cvt((fp.get_spatial_anchor_state)(
+                self.as_raw(),
+                out.as_mut_ptr(),
+            ))?;
+            Ok(out.assume_init().confidence)
+        }
+    }
+}
+
+/// Storage for spatial anchors, shared with other devices localized into the same map
+///
+/// Requires [`XR_ML_spatial_anchors_storage`].
+///
+/// [`XR_ML_spatial_anchors_storage`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage
+pub struct SpatialAnchorsStorageML {
+    inner: Arc<StorageInner>,
+}
+
+impl SpatialAnchorsStorageML {
+    #[inline]
+    pub fn as_raw(&self) -> sys::SpatialAnchorsStorageML {
+        self.inner.handle
+    }
+
+    /// Begin finding published anchors within `radius` meters of `center` in `base`, as located
+    /// at `time`
+    pub fn query(
+        &self,
+        base: &Space,
+        center: Vector3f,
+        time: Time,
+        radius: f32,
+    ) -> Result<QuerySpatialAnchorsFutureML> {
+        let info = sys::SpatialAnchorsQueryInfoRadiusML {
+            ty: sys::SpatialAnchorsQueryInfoRadiusML::TYPE,
+            next: ptr::null(),
+            base_space: base.as_raw(),
+            center,
+            time,
+            radius,
+        };
+        let mut future = sys::FutureEXT::NULL;
+        unsafe {
+            cvt((self.inner.storage_fp.query_spatial_anchors_async)(
+                self.as_raw(),
+                &info as *const _ as *const sys::SpatialAnchorsQueryInfoBaseHeaderML,
+                &mut future,
+            ))?;
+            Ok(QuerySpatialAnchorsFutureML {
+                future: FutureEXT::from_raw(&self.inner.session.instance, future)?,
+                storage: self.inner.clone(),
+            })
+        }
+    }
+
+    /// Begin creating spaces for the published anchors identified by `uuids`
+    pub fn create_anchors(
+        &self,
+        uuids: &[SpatialAnchorUuidML],
+    ) -> Result<CreateSpatialAnchorsFutureML> {
+        let uuids = uuids.iter().map(|x| x.into_raw()).collect::<Vec<_>>();
+        let info = sys::SpatialAnchorsCreateInfoFromUuidsML {
+            ty: sys::SpatialAnchorsCreateInfoFromUuidsML::TYPE,
+            next: ptr::null(),
+            storage: self.as_raw(),
+            uuid_count: uuids.len() as u32,
+            uuids: uuids.as_ptr(),
+        };
+        unsafe {
+            CreateSpatialAnchorsFutureML::new(
+                self.inner.session.clone(),
+                self.inner.fp,
+                &info as *const _ as *const sys::SpatialAnchorsCreateInfoBaseHeaderML,
+                uuids.len(),
+            )
+        }
+    }
+
+    /// Begin publishing `anchors` so they persist and can be found by [`Self::query`]
+    ///
+    /// `expiration` is the number of seconds since the epoch after which the anchors may be
+    /// deleted, or 0 to keep them indefinitely.
+    pub fn publish(
+        &self,
+        anchors: &[&Space],
+        expiration: u64,
+    ) -> Result<PublishSpatialAnchorsFutureML> {
+        let anchors = anchors.iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+        let info = sys::SpatialAnchorsPublishInfoML {
+            ty: sys::SpatialAnchorsPublishInfoML::TYPE,
+            next: ptr::null(),
+            anchor_count: anchors.len() as u32,
+            anchors: anchors.as_ptr(),
+            expiration,
+        };
+        let mut future = sys::FutureEXT::NULL;
+        unsafe {
+            cvt((self.inner.storage_fp.publish_spatial_anchors_async)(
+                self.as_raw(),
+                &info,
+                &mut future,
+            ))?;
+            Ok(PublishSpatialAnchorsFutureML {
+                future: FutureEXT::from_raw(&self.inner.session.instance, future)?,
+                storage: self.inner.clone(),
+                count: anchors.len(),
+            })
+        }
+    }
+
+    /// Begin removing the published anchors identified by `uuids`
+    pub fn delete(&self, uuids: &[SpatialAnchorUuidML]) -> Result<DeleteSpatialAnchorsFutureML> {
+        let uuids = uuids.iter().map(|x| x.into_raw()).collect::<Vec<_>>();
+        let info = sys::SpatialAnchorsDeleteInfoML {
+            ty: sys::SpatialAnchorsDeleteInfoML::TYPE,
+            next: ptr::null(),
+            uuid_count: uuids.len() as u32,
+            uuids: uuids.as_ptr(),
+        };
+        let mut future = sys::FutureEXT::NULL;
+        unsafe {
+            cvt((self.inner.storage_fp.delete_spatial_anchors_async)(
+                self.as_raw(),
+                &info,
+                &mut future,
+            ))?;
+            Ok(DeleteSpatialAnchorsFutureML {
+                future: FutureEXT::from_raw(&self.inner.session.instance, future)?,
+                storage: self.inner.clone(),
+            })
+        }
+    }
+}
+
+struct StorageInner {
+    session: Arc<session::SessionInner>,
+    fp: SpatialAnchorsFnML,
+    storage_fp: SpatialAnchorsStorageFnML,
+    handle: sys::SpatialAnchorsStorageML,
+}
+
+impl Drop for StorageInner {
+    fn drop(&mut self) {
+        unsafe {
+            (self.storage_fp.destroy_spatial_anchors_storage)(self.handle);
+        }
+    }
+}
+
+/// Creation of spatial anchors in progress
+pub struct CreateSpatialAnchorsFutureML {
+    future: FutureEXT,
+    session: Arc<session::SessionInner>,
+    fp: SpatialAnchorsFnML,
+    count: usize,
+}
+
+impl CreateSpatialAnchorsFutureML {
+    unsafe fn new(
+        session: Arc<session::SessionInner>,
+        fp: SpatialAnchorsFnML,
+        info: *const sys::SpatialAnchorsCreateInfoBaseHeaderML,
+        count: usize,
+    ) -> Result<Self> {
+        let mut future = sys::FutureEXT::NULL;
+        cvt((fp.create_spatial_anchors_async)(
+            session.handle,
+            info,
+            &mut future,
+        ))?;
+        Ok(Self {
+            future: FutureEXT::from_raw(&session.instance, future)?,
+            session,
+            fp,
+            count,
+        })
+    }
+
+    #[inline]
+    pub fn future(&self) -> &FutureEXT {
+        &self.future
+    }
+
+    /// The new anchors, in the order they were requested, or `None` if not yet ready
+    pub fn complete(&mut self) -> Result<Option<Vec<Space>>> {
+        let (session, fp, count) = (&self.session, self.fp, self.count);
+        self.future.complete_with(|future| unsafe {
+            let mut spaces = vec![sys::Space::NULL; count];
+            let mut completion = sys::CreateSpatialAnchorsCompletionML::out(ptr::null_mut());
+            (*completion.as_mut_ptr()).space_count = count as u32;
+            (*completion.as_mut_ptr()).spaces = spaces.as_mut_ptr();
+            cvt((fp.create_spatial_anchors_complete)(
+                session.handle,
+                future,
+                completion.as_mut_ptr(),
+            ))?;
+            let completion = completion.assume_init();
+            // Take ownership before checking the result, so that anchors created before a failure
+            // are destroyed rather than leaked
+            let spaces = spaces
+                .into_iter()
+                .filter(|&x| x != sys::Space::NULL)
+                .map(|x| Space::from_session_raw(session.clone(), x))
+                .collect::<Vec<_>>();
+            cvt(completion.future_result)?;
+            Ok(spaces)
+        })
+    }
+}
+
+/// A search for published spatial anchors in progress
+pub struct QuerySpatialAnchorsFutureML {
+    future: FutureEXT,
+    storage: Arc<StorageInner>,
+}
+
+impl QuerySpatialAnchorsFutureML {
+    #[inline]
+    pub fn future(&self) -> &FutureEXT {
+        &self.future
+    }
+
+    /// The anchors found, or `None` if not yet ready
+    ///
+    /// Pass the result to [`SpatialAnchorsStorageML::create_anchors`] to locate them.
+    pub fn complete(&mut self) -> Result<Option<Vec<SpatialAnchorUuidML>>> {
+        let storage = &self.storage;
+        self.future.complete_with(|future| unsafe {
+            let mut completion = sys::SpatialAnchorsQueryCompletionML::out(ptr::null_mut());
+            (*completion.as_mut_ptr()).uuid_capacity_input = 0;
+            (*completion.as_mut_ptr()).uuids = ptr::null_mut();
+            cvt((storage.storage_fp.query_spatial_anchors_complete)(
+                storage.handle,
+                future,
+                completion.as_mut_ptr(),
+            ))?;
+            let mut completion = completion.assume_init();
+            cvt(completion.future_result)?;
+            let mut uuids = vec![
+                UuidEXT {
+                    data: [0; sys::UUID_SIZE_EXT]
+                };
+                completion.uuid_count_output as usize
+            ];
+            completion.uuid_capacity_input = uuids.len() as u32;
+            completion.uuids = uuids.as_mut_ptr();
+            cvt((storage.storage_fp.query_spatial_anchors_complete)(
+                storage.handle,
+                future,
+                &mut completion,
+            ))?;
+            cvt(completion.future_result)?;
+            uuids.truncate(completion.uuid_count_output as usize);
+            Ok(uuids
+                .into_iter()
+                .map(SpatialAnchorUuidML::from_raw)
+                .collect())
+        })
+    }
+}
+
+/// Publication of spatial anchors in progress
+pub struct PublishSpatialAnchorsFutureML {
+    future: FutureEXT,
+    storage: Arc<StorageInner>,
+    count: usize,
+}
+
+impl PublishSpatialAnchorsFutureML {
+    #[inline]
+    pub fn future(&self) -> &FutureEXT {
+        &self.future
+    }
+
+    /// The IDs of the published anchors, in the order they were given, or `None` if not yet
+    /// ready
+    pub fn complete(&mut self) -> Result<Option<Vec<SpatialAnchorUuidML>>> {
+        let (storage, count) = (&self.storage, self.count);
+        self.future.complete_with(|future| unsafe {
+            let mut uuids = vec![
+                UuidEXT {
+                    data: [0; sys::UUID_SIZE_EXT]
+                };
+                count
+            ];
+            let mut completion = sys::SpatialAnchorsPublishCompletionML::out(ptr::null_mut());
+            (*completion.as_mut_ptr()).uuid_count = count as u32;
+            (*completion.as_mut_ptr()).uuids = uuids.as_mut_ptr();
+            cvt((storage.storage_fp.publish_spatial_anchors_complete)(
+                storage.handle,
+                future,
+                completion.as_mut_ptr(),
+            ))?;
+            cvt(completion.assume_init().future_result)?;
+            Ok(uuids
+                .into_iter()
+                .map(SpatialAnchorUuidML::from_raw)
+                .collect())
+        })
+    }
+}
+
+/// Deletion of published spatial anchors in progress
+pub struct DeleteSpatialAnchorsFutureML {
+    future: FutureEXT,
+    storage: Arc<StorageInner>,
+}
+
+impl DeleteSpatialAnchorsFutureML {
+    #[inline]
+    pub fn future(&self) -> &FutureEXT {
+        &self.future
+    }
+
+    /// `Some(())` once the anchors have been deleted, or `None` if not yet ready
+    pub fn complete(&mut self) -> Result<Option<()>> {
+        let storage = &self.storage;
+        self.future.complete_with(|future| unsafe {
+            let mut completion = sys::SpatialAnchorsDeleteCompletionML::out(ptr::null_mut());
+            cvt((storage.storage_fp.delete_spatial_anchors_complete)(
+                storage.handle,
+                future,
+                completion.as_mut_ptr(),
+            ))?;
+            cvt(completion.assume_init().future_result)?;
+            Ok(())
+        })
+    }
+}
+
+/// Function pointers for `XR_ML_spatial_anchors`, which the generated bindings omit
+#[derive(Copy, Clone)]
+pub(crate) struct SpatialAnchorsFnML {
+    create_spatial_anchors_async: pfn::CreateSpatialAnchorsAsyncML,
+    create_spatial_anchors_complete: pfn::CreateSpatialAnchorsCompleteML,
+    get_spatial_anchor_state: pfn::GetSpatialAnchorStateML,
+}
+
+impl SpatialAnchorsFnML {
+    /// Reuse the function pointers already loaded for `session`, loading them if needed
+    fn cached(session: &session::SessionInner) -> Result<Self> {
+        let mut cached = session.spatial_anchors.lock().unwrap();
+        if let Some(fp) = *cached {
+            return Ok(fp);
+        }
+        let fp = unsafe { Self::load(&session.instance)? };
+        *cached = Some(fp);
+        Ok(fp)
+    }
+
+    unsafe fn load(instance: &Instance) -> Result<Self> {
+        Ok(Self {
+            create_spatial_anchors_async: load_ext_fn(
+                instance,
+                b"xrCreateSpatialAnchorsAsyncML\0",
+            )?,
+            create_spatial_anchors_complete: load_ext_fn(
+                instance,
+                b"xrCreateSpatialAnchorsCompleteML\0",
+            )?,
+            get_spatial_anchor_state: load_ext_fn(instance, b"xrGetSpatialAnchorStateML\0")?,
+        })
+    }
+}
+
+/// The `XR_ML_spatial_anchors` function pointers, once loaded
+pub(crate) type SpatialAnchorsCache = Mutex<Option<SpatialAnchorsFnML>>;
+
+/// Function pointers for `XR_ML_spatial_anchors_storage`, which the generated bindings omit
+#[derive(Copy, Clone)]
+struct SpatialAnchorsStorageFnML {
+    create_spatial_anchors_storage: pfn::CreateSpatialAnchorsStorageML,
+    destroy_spatial_anchors_storage: pfn::DestroySpatialAnchorsStorageML,
+    query_spatial_anchors_async: pfn::QuerySpatialAnchorsAsyncML,
+    query_spatial_anchors_complete: pfn::QuerySpatialAnchorsCompleteML,
+    publish_spatial_anchors_async: pfn::PublishSpatialAnchorsAsyncML,
+    publish_spatial_anchors_complete: pfn::PublishSpatialAnchorsCompleteML,
+    delete_spatial_anchors_async: pfn::DeleteSpatialAnchorsAsyncML,
+    delete_spatial_anchors_complete: pfn::DeleteSpatialAnchorsCompleteML,
+}
+
+impl SpatialAnchorsStorageFnML {
+    unsafe fn load(instance: &Instance) -> Result<Self> {
+        Ok(Self {
+            create_spatial_anchors_storage: load_ext_fn(
+                instance,
+                b"xrCreateSpatialAnchorsStorageML\0",
+            )?,
+            destroy_spatial_anchors_storage: load_ext_fn(
+                instance,
+                b"xrDestroySpatialAnchorsStorageML\0",
+            )?,
+            query_spatial_anchors_async: load_ext_fn(instance, b"xrQuerySpatialAnchorsAsyncML\0")?,
+            query_spatial_anchors_complete: load_ext_fn(
+                instance,
+                b"xrQuerySpatialAnchorsCompleteML\0",
+            )?,
+            publish_spatial_anchors_async: load_ext_fn(
+                instance,
+                b"xrPublishSpatialAnchorsAsyncML\0",
+            )?,
+            publish_spatial_anchors_complete: load_ext_fn(
+                instance,
+                b"xrPublishSpatialAnchorsCompleteML\0",
+            )?,
+            delete_spatial_anchors_async: load_ext_fn(
+                instance,
+                b"xrDeleteSpatialAnchorsAsyncML\0",
+            )?,
+            delete_spatial_anchors_complete: load_ext_fn(
+                instance,
+                b"xrDeleteSpatialAnchorsCompleteML\0",
+            )?,
+        })
+    }
+}
+
+mod pfn {
+    use crate::sys;
+
+    pub type CreateSpatialAnchorsAsyncML = unsafe extern "system" fn(
+        sys::Session,
+        *const sys::SpatialAnchorsCreateInfoBaseHeaderML,
+        *mut sys::FutureEXT,
+    ) -> sys::Result;
+    pub type CreateSpatialAnchorsCompleteML = unsafe extern "system" fn(
+        sys::Session,
+        sys::FutureEXT,
+        *mut sys::CreateSpatialAnchorsCompletionML,
+    ) -> sys::Result;
+    pub type GetSpatialAnchorStateML =
+        unsafe extern "system" fn(sys::Space, *mut sys::SpatialAnchorStateML) -> sys::Result;
+    pub type CreateSpatialAnchorsStorageML = unsafe extern "system" fn(
+        sys::Session,
+        *const sys::SpatialAnchorsCreateStorageInfoML,
+        *mut sys::SpatialAnchorsStorageML,
+    ) -> sys::Result;
+    pub type DestroySpatialAnchorsStorageML =
+        unsafe extern "system" fn(sys::SpatialAnchorsStorageML) -> sys::Result;
+    pub type QuerySpatialAnchorsAsyncML = unsafe extern "system" fn(
+        sys::SpatialAnchorsStorageML,
+        *const sys::SpatialAnchorsQueryInfoBaseHeaderML,
+        *mut sys::FutureEXT,
+    ) -> sys::Result;
+    pub type QuerySpatialAnchorsCompleteML = unsafe extern "system" fn(
+        sys::SpatialAnchorsStorageML,
+        sys::FutureEXT,
+        *mut sys::SpatialAnchorsQueryCompletionML,
+    ) -> sys::Result;
+    pub type PublishSpatialAnchorsAsyncML = unsafe extern "system" fn(
+        sys::SpatialAnchorsStorageML,
+        *const sys::SpatialAnchorsPublishInfoML,
+        *mut sys::FutureEXT,
+    ) -> sys::Result;
+    pub type PublishSpatialAnchorsCompleteML = unsafe extern "system" fn(
+        sys::SpatialAnchorsStorageML,
+        sys::FutureEXT,
+        *mut sys::SpatialAnchorsPublishCompletionML,
+    ) -> sys::Result;
+    pub type DeleteSpatialAnchorsAsyncML = unsafe extern "system" fn(
+        sys::SpatialAnchorsStorageML,
+        *const sys::SpatialAnchorsDeleteInfoML,
+        *mut sys::FutureEXT,
+    ) -> sys::Result;
+    pub type DeleteSpatialAnchorsCompleteML = unsafe extern "system" fn(
+        sys::SpatialAnchorsStorageML,
+        sys::FutureEXT,
+        *mut sys::SpatialAnchorsDeleteCompletionML,
+    ) -> sys::Result;
+}
@@ -0,0 +1,376 @@
+//! Sharing spatial anchors with other users via [`XR_FB_spatial_entity_sharing`] and
+//! [`XR_FB_spatial_entity_user`], or with groups via [`XR_META_spatial_entity_sharing`] and
+//! [`XR_META_spatial_entity_group_sharing`].
+//!
+//! The META extensions must be [enabled by name](crate#extensions-without-extensionset-fields).
+//! Their completion events arrive as [`Event::Raw`]; decode them with
+//! [`ShareSpacesCompleteMETA::from_event`].
+//!
+//! [`XR_FB_spatial_entity_sharing`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_FB_spatial_entity_sharing
+//! [`XR_FB_spatial_entity_user`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_FB_spatial_entity_user
+//! [`XR_META_spatial_entity_sharing`]: https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_spatial_entity_sharing
+//! [`XR_META_spatial_entity_group_sharing`]: https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_spatial_entity_group_sharing
+
+use std::{
+    fmt, mem, ptr,
+    sync::{Arc, Mutex},
+};
+
+pub use sys::SpaceUserIdFB;
+
+use crate::*;
+
+/// Someone spatial anchors can be shared with
+#[derive(Debug, Copy, Clone)]
+pub enum ShareRecipientFB {
+    /// A user, by platform-specific ID
+    ///
+    /// Requires XR_FB_spatial_entity_sharing and XR_FB_spatial_entity_user.
+    User(SpaceUserIdFB),
+    /// Everyone in a group, identified by a UUID the application chooses
+    ///
+    /// Requires XR_META_spatial_entity_sharing and XR_META_spatial_entity_group_sharing.
+    Group(UuidEXT),
+}
+
+/// A user that spatial entities can be shared with
+pub struct SpaceUserFB {
+    session: Arc<session::SessionInner>,
+    handle: sys::SpaceUserFB,
+}
+
+impl SpaceUserFB {
+    #[inline]
+    pub fn as_raw(&self) -> sys::SpaceUserFB {
+        self.handle
+    }
+
+    /// Give up ownership of the handle without destroying it
+    ///
+    /// The caller becomes responsible for destroying the returned handle.
+    #[inline]
+    pub fn into_raw(self) -> sys::SpaceUserFB {
+        let mut this = mem::ManuallyDrop::new(self);
+        // Release everything but the handle, which `Drop` would destroy
+        unsafe {
+            ptr::drop_in_place(&mut this.session);
+        }
+        this.handle
+    }
+
+    /// Take ownership of an existing space user
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid space user handle associated with `session`.
+    #[inline]
+    pub unsafe fn from_raw<G>(session: &Session<G>, handle: sys::SpaceUserFB) -> Self {
+        Self {
+            session: session.inner.clone(),
+            handle,
+        }
+    }
+
+    /// The platform-specific ID this user was created from
+    pub fn id(&self) -> Result<SpaceUserIdFB> {
+        let mut out = 0;
+        unsafe {
+            cvt((self.fp().get_space_user_id)(self.handle, &mut out))?;
+        }
+        Ok(out)
+    }
+
+    #[inline]
+    fn fp(&self) -> &raw::SpatialEntityUserFB {
+        self.session
+            .instance
+            .exts()
+            .fb_spatial_entity_user
+            .as_ref()
+            .expect("Somehow created SpaceUserFB without XR_FB_spatial_entity_user being enabled")
+    }
+}
+
+impl Drop for SpaceUserFB {
+    fn drop(&mut self) {
+        unsafe {
+            (self.fp().destroy_space_user)(self.handle);
+        }
+    }
+}
+
+impl<G> Session<G> {
+    /// Requires XR_FB_spatial_entity_user.
+    pub fn create_space_user_fb(&self, user_id: SpaceUserIdFB) -> Result<SpaceUserFB> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_spatial_entity_user
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::SpaceUserCreateInfoFB {
+            ty: sys::SpaceUserCreateInfoFB::TYPE,
+            next: ptr::null(),
+            user_id,
+        };
+        let mut handle = sys::SpaceUserFB::NULL;
+        unsafe {
+            cvt((fp.create_space_user)(self.as_raw(), &info, &mut handle))?;
+            Ok(SpaceUserFB::from_raw(self, handle))
+        }
+    }
+
+    /// Share each of `anchors` with every user and group in `recipients`
+    ///
+    /// Each anchor is shared by separate requests for users and for groups, so that failures can
+    /// be attributed to individual anchors. Pass the events returned by `Instance::poll_event` to
+    /// [`AnchorShareFB::handle_event`] to collect the results.
+    ///
+    /// Requires the extensions listed on each kind of [`ShareRecipientFB`] in `recipients`.
+    pub fn share_anchors_fb(
+        &self,
+        anchors: &[Space],
+        recipients: &[ShareRecipientFB],
+    ) -> Result<AnchorShareFB> {
+        if recipients.is_empty() {
+            return Err(sys::Result::ERROR_VALIDATION_FAILURE);
+        }
+        let mut groups = recipients
+            .iter()
+            .filter_map(|x| match *x {
+                ShareRecipientFB::Group(x) => Some(x),
+                ShareRecipientFB::User(_) => None,
+            })
+            .collect::<Vec<_>>();
+        let users = recipients
+            .iter()
+            .filter_map(|x| match *x {
+                ShareRecipientFB::User(x) => Some(self.create_space_user_fb(x)),
+                ShareRecipientFB::Group(_) => None,
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let share_with_users = if users.is_empty() {
+            None
+        } else {
+            let fp = self
+                .instance()
+                .exts()
+                .fb_spatial_entity_sharing
+                .as_ref()
+                .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+            Some(fp.share_spaces)
+        };
+        let share_with_groups = if groups.is_empty() {
+            None
+        } else {
+            Some(self.share_spaces_meta_fn()?)
+        };
+        let mut user_handles = users.iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+        let group_recipients = sys::ShareSpacesRecipientGroupsMETA {
+            ty: sys::ShareSpacesRecipientGroupsMETA::TYPE,
+            next: ptr::null(),
+            group_count: groups.len() as u32,
+            groups: groups.as_mut_ptr(),
+        };
+        let mut share = AnchorShareFB {
+            _users: users,
+            requests: Vec::new(),
+            results: Vec::with_capacity(anchors.len()),
+        };
+        for (index, anchor) in anchors.iter().enumerate() {
+            let mut space = anchor.as_raw();
+            share.results.push(None);
+            if let Some(share_spaces) = share_with_users {
+                let info = sys::SpaceShareInfoFB {
+                    ty: sys::SpaceShareInfoFB::TYPE,
+                    next: ptr::null(),
+                    space_count: 1,
+                    spaces: &mut space,
+                    user_count: user_handles.len() as u32,
+                    users: user_handles.as_mut_ptr(),
+                };
+                let mut request = AsyncRequestIdFB::default();
+                match unsafe { cvt(share_spaces(self.as_raw(), &info, &mut request)) } {
+                    Ok(_) => share.requests.push((request, index)),
+                    Err(e) => {
+                        share.results[index] = Some(Err(e));
+                        continue;
+                    }
+                }
+            }
+            if let Some(share_spaces) = share_with_groups {
+                let info = sys::ShareSpacesInfoMETA {
+                    ty: sys::ShareSpacesInfoMETA::TYPE,
+                    next: ptr::null(),
+                    space_count: 1,
+                    spaces: &mut space,
+                    recipient_info: &group_recipients as *const _ as _,
+                };
+                let mut request = AsyncRequestIdFB::default();
+                match unsafe { cvt(share_spaces(self.as_raw(), &info, &mut request)) } {
+                    Ok(_) => share.requests.push((request, index)),
+                    Err(e) => share.results[index] = Some(Err(e)),
+                }
+            }
+        }
+        Ok(share)
+    }
+
+    fn share_spaces_meta_fn(&self) -> Result<pfn::ShareSpacesMETA> {
+        let mut cached = self.inner.share_spaces_meta.lock().unwrap();
+        if let Some(fp) = *cached {
+            return Ok(fp);
+        }
+        let fp = unsafe { load_ext_fn(self.instance(), b"xrShareSpacesMETA\0")? };
+        *cached = Some(fp);
+        Ok(fp)
+    }
+}
+
+/// The group sharing function, once loaded
+pub(crate) type ShareSpacesCache = Mutex<Option<pfn::ShareSpacesMETA>>;
+
+/// Progress of a [`Session::share_anchors_fb`] call
+pub struct AnchorShareFB {
+    /// Kept alive until the requests referencing them complete
+    _users: Vec<SpaceUserFB>,
+    /// Outstanding requests, with the index of the anchor each shares
+    requests: Vec<(AsyncRequestIdFB, usize)>,
+    results: Vec<Option<Result<()>>>,
+}
+
+impl AnchorShareFB {
+    /// Record the outcome of a share request belonging to this operation
+    ///
+    /// Returns whether `event` was consumed.
+    pub fn handle_event(&mut self, event: &Event<'_>) -> bool {
+        let (request_id, result) = match *event {
+            Event::SpaceShareCompleteFB(x) => (x.request_id(), x.result()),
+            Event::Raw(x) => match ShareSpacesCompleteMETA::from_event(x) {
+                Some(x) => (x.request_id(), x.result()),
+                None => return false,
+            },
+            _ => return false,
+        };
+        let position = match self.requests.iter().position(|&(x, _)| x == request_id) {
+            Some(x) => x,
+            None => return false,
+        };
+        let (_, index) = self.requests.swap_remove(position);
+        let pending = self.requests.iter().any(|&(_, x)| x == index);
+        let outcome = &mut self.results[index];
+        // An anchor's first failure stands, even while its other request is pending
+        if outcome.is_none() {
+            match cvt(result) {
+                Ok(_) if pending => {}
+                Ok(_) => *outcome = Some(Ok(())),
+                Err(e) => *outcome = Some(Err(e)),
+            }
+        }
+        true
+    }
+
+    /// Whether every anchor's share requests have completed
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.results.iter().all(|x| x.is_some())
+    }
+
+    /// Per-anchor results, in the order the anchors were passed, or `None` while pending
+    ///
+    /// An anchor shared with both users and groups succeeds once both requests do.
+    #[inline]
+    pub fn results(&self) -> &[Option<Result<()>>] {
+        &self.results
+    }
+}
+
+/// Completion of a request to share spaces with groups, made by [`Session::share_anchors_fb`]
+///
+/// The generated [`Event`] doesn't cover this extension, so these arrive as [`Event::Raw`].
+#[derive(Copy, Clone)]
+pub struct ShareSpacesCompleteMETA<'a>(&'a sys::EventDataShareSpacesCompleteMETA);
+
+impl<'a> ShareSpacesCompleteMETA<'a> {
+    /// # Safety
+    ///
+    /// `inner` must be valid event data according to the OpenXR spec. Refer to
+    /// [sys::EventDataShareSpacesCompleteMETA] for more information.
+    #[inline]
+    pub unsafe fn new(inner: &'a sys::EventDataShareSpacesCompleteMETA) -> Self {
+        Self(inner)
+    }
+
+    /// Decode `event`, if it is the completion of a share request
+    pub fn from_event(event: RawEvent<'a>) -> Option<Self> {
+        if event.ty() != sys::EventDataShareSpacesCompleteMETA::TYPE {
+            return None;
+        }
+        // Safety: the runtime populated the buffer with a structure of this type
+        unsafe {
+            let raw = event.as_raw() as *const sys::EventDataBuffer;
+            Some(Self::new(
+                &*(raw as *const sys::EventDataShareSpacesCompleteMETA),
+            ))
+        }
+    }
+
+    #[inline]
+    pub fn request_id(self) -> AsyncRequestIdFB {
+        self.0.request_id
+    }
+
+    #[inline]
+    pub fn result(self) -> sys::Result {
+        self.0.result
+    }
+}
+
+impl fmt::Debug for ShareSpacesCompleteMETA<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("ShareSpacesCompleteMETA")
+            .field("request_id", &event_fields::Field(&self.request_id()))
+            .field("result", &event_fields::Field(&self.result()))
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ShareSpacesCompleteMETA<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("ShareSpacesCompleteMETA", 2)?;
+        s.serialize_field("request_id", &event_fields::Field(&self.request_id()))?;
+        s.serialize_field("result", &event_fields::Field(&self.result()))?;
+        s.end()
+    }
+}
+
+impl<C> EventDispatcher<C> {
+    /// Call `handler` for each [`ShareSpacesCompleteMETA`] event
+    ///
+    /// These are [`Event::Raw`] events, so handlers registered with [`EventDispatcher::on_raw`]
+    /// see them too.
+    pub fn on_share_spaces_complete_meta(
+        self,
+        mut handler: impl FnMut(&mut C, ShareSpacesCompleteMETA<'_>) -> Result<()> + 'static,
+    ) -> Self {
+        self.on_raw(move |cx, e| match ShareSpacesCompleteMETA::from_event(e) {
+            Some(e) => handler(cx, e),
+            None => Ok(()),
+        })
+    }
+}
+
+mod pfn {
+    use crate::sys;
+
+    pub type ShareSpacesMETA = unsafe extern "system" fn(
+        sys::Session,
+        *const sys::ShareSpacesInfoMETA,
+        *mut sys::AsyncRequestIdFB,
+    ) -> sys::Result;
+}
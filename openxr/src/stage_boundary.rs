@@ -0,0 +1,107 @@
+//! Visualizing the bounds of the stage reference space.
+//!
+//! Runtimes report the area users are expected to stay within as a rectangle centered on the
+//! origin of the `STAGE` reference space, through [`Session::reference_space_bounds_rect`].
+//! [`StageBoundary`] turns it into walls standing on the floor, suitable for drawing as a safety
+//! overlay, and rebuilds them when the runtime reports that the stage has changed.
+
+use crate::*;
+
+/// Walls standing on the edges of a stage's bounds, in `STAGE` space
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundaryMesh {
+    /// Vertices forming a triangle strip, alternating between floor and top edge and returning
+    /// to the first corner to close the loop
+    ///
+    /// Walls are seen from inside the bounds or outside depending on the side, so back-face
+    /// culling should be disabled when drawing them.
+    pub vertices: Vec<Vector3f>,
+}
+
+impl BoundaryMesh {
+    /// Walls `height` meters tall around a rectangle of `extent` centered on the origin
+    pub fn walls(extent: Extent2Df, height: f32) -> Self {
+        let mut vertices = Vec::with_capacity(10);
+        let outline = Self::outline(extent);
+        for corner in outline.iter().chain(outline.first()) {
+            vertices.push(*corner);
+            vertices.push(Vector3f {
+                y: height,
+                ..*corner
+            });
+        }
+        Self { vertices }
+    }
+
+    /// Corners of a rectangle of `extent` centered on the origin, at floor level and
+    /// counterclockwise as seen from above
+    pub fn outline(extent: Extent2Df) -> [Vector3f; 4] {
+        let (x, z) = (extent.width / 2.0, extent.height / 2.0);
+        let corner = |x, z| Vector3f { x, y: 0.0, z };
+        [corner(-x, -z), corner(-x, z), corner(x, z), corner(x, -z)]
+    }
+}
+
+/// Tracks the bounds of a session's `STAGE` reference space as a [`BoundaryMesh`]
+///
+/// Pass every event from `Instance::poll_event` to [`StageBoundary::handle_event`] to keep the
+/// mesh current.
+pub struct StageBoundary<G> {
+    session: Session<G>,
+    height: f32,
+    extent: Option<Extent2Df>,
+    mesh: Option<BoundaryMesh>,
+}
+
+impl<G> StageBoundary<G> {
+    /// Track the bounds of `session`'s stage, drawn as walls `height` meters tall
+    pub fn new(session: &Session<G>, height: f32) -> Result<Self> {
+        let mut out = Self {
+            session: session.clone(),
+            height,
+            extent: None,
+            mesh: None,
+        };
+        out.refresh()?;
+        Ok(out)
+    }
+
+    /// Size of the bounds, or `None` if the runtime doesn't currently know them
+    #[inline]
+    pub fn extent(&self) -> Option<Extent2Df> {
+        self.extent
+    }
+
+    /// Walls around the bounds, or `None` if the runtime doesn't currently know them
+    #[inline]
+    pub fn mesh(&self) -> Option<&BoundaryMesh> {
+        self.mesh.as_ref()
+    }
+
+    /// Query the bounds again and rebuild the mesh
+    pub fn refresh(&mut self) -> Result<()> {
+        self.extent = self
+            .session
+            .reference_space_bounds_rect(ReferenceSpaceType::STAGE)?;
+        self.mesh = self
+            .extent
+            .map(|extent| BoundaryMesh::walls(extent, self.height));
+        Ok(())
+    }
+
+    /// Refresh the bounds if `event` reports a change to this session's stage
+    ///
+    /// Returns whether the bounds were refreshed.
+    pub fn handle_event(&mut self, event: &Event<'_>) -> Result<bool> {
+        match event {
+            Event::ReferenceSpaceChangePending(e)
+                if e.session() == self.session.as_raw()
+                    && e.reference_space_type() == ReferenceSpaceType::STAGE =>
+            {
+                self.refresh()?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
@@ -1,12 +1,17 @@
-use std::{ffi::CString, marker::PhantomData, ptr};
+use std::{ffi::CString, fmt, marker::PhantomData, mem, ptr, time::Instant};
 
 use crate::*;
 
 /// A set of images to be rendered to using a particular graphics API `G`
+///
+/// `Swapchain` is `Send` and `Sync` for every `G`, so it can be moved to or shared with a render
+/// thread directly. OpenXR requires calls that acquire, wait on, or release a swapchain's images
+/// to be externally synchronized; those take `&mut self`, so ownership or a `&mut` borrow already
+/// provides that synchronization without wrapping the swapchain in a `Mutex`.
 pub struct Swapchain<G: Graphics> {
     session: Session<G>,
     handle: sys::Swapchain,
-    _marker: PhantomData<G>,
+    _marker: PhantomData<fn() -> G>,
     /// Whether `wait_image` was called more recently than `release_image`
     waited: bool,
 }
@@ -33,6 +38,19 @@ impl<G: Graphics> Swapchain<G> {
         self.handle
     }
 
+    /// Give up ownership of the handle without destroying it
+    ///
+    /// The caller becomes responsible for destroying the returned handle.
+    #[inline]
+    pub fn into_raw(self) -> sys::Swapchain {
+        let mut this = mem::ManuallyDrop::new(self);
+        // Release everything but the handle, which `Drop` would destroy
+        unsafe {
+            ptr::drop_in_place(&mut this.session);
+        }
+        this.handle
+    }
+
     /// Access the `Instance` self is descended from
     #[inline]
     pub fn instance(&self) -> &Instance {
@@ -82,8 +100,11 @@ impl<G: Graphics> Swapchain<G> {
     }
 
     /// Wait for the compositor to finish reading from the oldest unwaited acquired image
+    ///
+    /// Returns `TIMEOUT_EXPIRED` if the image didn't become available within `timeout`, in which
+    /// case `wait_image` must be called again before rendering to it.
     #[inline]
-    pub fn wait_image(&mut self, timeout: Duration) -> Result<()> {
+    pub fn wait_image(&mut self, timeout: Duration) -> Result<sys::Result> {
         assert!(
             !self.waited,
             "release_image must be called before wait_image can be called again"
@@ -93,11 +114,37 @@ impl<G: Graphics> Swapchain<G> {
             next: ptr::null_mut(),
             timeout,
         };
-        unsafe {
-            cvt((self.fp().wait_swapchain_image)(self.as_raw(), &info))?;
+        let status = unsafe { cvt((self.fp().wait_swapchain_image)(self.as_raw(), &info))? };
+        self.waited = status != sys::Result::TIMEOUT_EXPIRED;
+        Ok(status)
+    }
+
+    /// Wait for the oldest unwaited acquired image, retrying timed out waits according to `policy`
+    ///
+    /// Unlike waiting with `Duration::INFINITE`, a compositor that stops releasing images produces
+    /// a [`SwapchainWaitError::TimedOut`] rather than hanging the caller forever.
+    pub fn wait_image_with_policy(
+        &mut self,
+        policy: &SwapchainWaitPolicy,
+    ) -> std::result::Result<SwapchainWaitStats, SwapchainWaitError> {
+        let start = Instant::now();
+        let mut stats = SwapchainWaitStats {
+            timeouts: 0,
+            elapsed: std::time::Duration::default(),
+        };
+        loop {
+            let status = self
+                .wait_image(policy.timeout)
+                .map_err(SwapchainWaitError::Runtime)?;
+            stats.elapsed = start.elapsed();
+            if status != sys::Result::TIMEOUT_EXPIRED {
+                return Ok(stats);
+            }
+            stats.timeouts += 1;
+            if stats.timeouts > policy.max_retries {
+                return Err(SwapchainWaitError::TimedOut(stats));
+            }
         }
-        self.waited = true;
-        Ok(())
     }
 
     /// Release the oldest acquired image
@@ -124,6 +171,76 @@ impl<G: Graphics> Swapchain<G> {
     }
 }
 
+/// Controls how long [`Swapchain::wait_image_with_policy`] waits for a slow compositor
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SwapchainWaitPolicy {
+    /// Timeout for each individual wait
+    pub timeout: Duration,
+    /// Number of timed out waits to retry before giving up
+    pub max_retries: u32,
+}
+
+impl SwapchainWaitPolicy {
+    /// Wait forever, as with `Duration::INFINITE`
+    pub const INFINITE: Self = Self {
+        timeout: Duration::INFINITE,
+        max_retries: 0,
+    };
+}
+
+impl Default for SwapchainWaitPolicy {
+    /// Retry 100ms waits for up to a second in total
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_nanos(100_000_000),
+            max_retries: 9,
+        }
+    }
+}
+
+/// Telemetry from [`Swapchain::wait_image_with_policy`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SwapchainWaitStats {
+    /// Number of individual waits that timed out
+    pub timeouts: u32,
+    /// Total time spent waiting
+    pub elapsed: std::time::Duration,
+}
+
+/// Failure of [`Swapchain::wait_image_with_policy`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SwapchainWaitError {
+    /// The runtime reported an error
+    Runtime(sys::Result),
+    /// The image didn't become available before the policy's retries were exhausted
+    TimedOut(SwapchainWaitStats),
+}
+
+impl fmt::Display for SwapchainWaitError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Runtime(e) => e.fmt(fmt),
+            Self::TimedOut(stats) => write!(
+                fmt,
+                "swapchain image unavailable after {} timed out waits ({:?}); the compositor may be hung",
+                stats.timeouts, stats.elapsed
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SwapchainWaitError {}
+
+impl From<SwapchainWaitError> for sys::Result {
+    /// Collapse into an OpenXR result, mapping timeouts to `TIMEOUT_EXPIRED`
+    fn from(e: SwapchainWaitError) -> Self {
+        match e {
+            SwapchainWaitError::Runtime(e) => e,
+            SwapchainWaitError::TimedOut(_) => sys::Result::TIMEOUT_EXPIRED,
+        }
+    }
+}
+
 impl<G: Graphics> Drop for Swapchain<G> {
     fn drop(&mut self) {
         unsafe {
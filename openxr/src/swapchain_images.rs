@@ -0,0 +1,173 @@
+//! Per-image resources for a swapchain.
+//!
+//! Renderers commonly keep resources for each swapchain image, such as framebuffers, command
+//! buffers, or fences guarding the image's last use. Those must be looked up by the index
+//! [`Swapchain::acquire_image`] returns, which isn't guaranteed to cycle through the images in
+//! order, and an image's resources may only be reused once that image was acquired again.
+//! [`SwapchainImages`] owns a swapchain together with its images and one slot of user data per
+//! image, and tracks which images are acquired so that each call is matched to the right image.
+
+use std::collections::VecDeque;
+
+use crate::*;
+
+/// A swapchain, its images, and a user data slot of type `T` per image
+///
+/// Images are acquired with [`SwapchainImages::acquire`], waited on with
+/// [`SwapchainImages::wait`] in the order they were acquired, then released with
+/// [`SwapchainImages::release`], again in the order they were acquired. An image's slot is not
+/// handed out by the runtime again until the image is released, so e.g. a fence stored in it
+/// still guards the image's previous submission when it's next acquired.
+pub struct SwapchainImages<G: Graphics, T> {
+    swapchain: Swapchain<G>,
+    images: Vec<G::SwapchainImage>,
+    data: Vec<T>,
+    /// Indices of acquired images, oldest first
+    acquired: VecDeque<u32>,
+    /// Number of `acquired` images that have been waited on
+    waited: usize,
+}
+
+impl<G: Graphics, T> SwapchainImages<G, T> {
+    /// Enumerate the images of `swapchain`, creating the data for each with `init`
+    ///
+    /// `init` is passed the index and image it creates the data for.
+    pub fn new(
+        swapchain: Swapchain<G>,
+        mut init: impl FnMut(u32, &G::SwapchainImage) -> T,
+    ) -> Result<Self> {
+        let images = swapchain.enumerate_images()?;
+        let data = images
+            .iter()
+            .enumerate()
+            .map(|(i, image)| init(i as u32, image))
+            .collect();
+        Ok(Self {
+            swapchain,
+            images,
+            data,
+            acquired: VecDeque::new(),
+            waited: 0,
+        })
+    }
+
+    /// Access the underlying swapchain
+    #[inline]
+    pub fn swapchain(&self) -> &Swapchain<G> {
+        &self.swapchain
+    }
+
+    /// Take back the swapchain and the per-image data, in image order
+    #[inline]
+    pub fn into_inner(self) -> (Swapchain<G>, Vec<T>) {
+        (self.swapchain, self.data)
+    }
+
+    /// Number of images in the swapchain
+    #[inline]
+    pub fn image_count(&self) -> u32 {
+        self.images.len() as u32
+    }
+
+    /// All images, in index order
+    #[inline]
+    pub fn images(&self) -> &[G::SwapchainImage] {
+        &self.images
+    }
+
+    /// The image at `index`
+    #[inline]
+    pub fn image(&self, index: u32) -> &G::SwapchainImage {
+        &self.images[index as usize]
+    }
+
+    /// The data for the image at `index`
+    #[inline]
+    pub fn data(&self, index: u32) -> &T {
+        &self.data[index as usize]
+    }
+
+    /// Mutably access the data for the image at `index`
+    #[inline]
+    pub fn data_mut(&mut self, index: u32) -> &mut T {
+        &mut self.data[index as usize]
+    }
+
+    /// The image at `index` along with its mutable data
+    #[inline]
+    pub fn get_mut(&mut self, index: u32) -> (&G::SwapchainImage, &mut T) {
+        (&self.images[index as usize], &mut self.data[index as usize])
+    }
+
+    /// Indices of the currently acquired images, oldest first
+    pub fn acquired(&self) -> impl ExactSizeIterator<Item = u32> + '_ {
+        self.acquired.iter().copied()
+    }
+
+    /// Acquire the next image, returning its index
+    ///
+    /// See [`Swapchain::acquire_image`].
+    pub fn acquire(&mut self) -> Result<u32> {
+        let index = self.swapchain.acquire_image()?;
+        debug_assert!(
+            !self.acquired.contains(&index),
+            "runtime acquired image {} twice without releasing it",
+            index
+        );
+        self.acquired.push_back(index);
+        Ok(index)
+    }
+
+    /// Wait for the oldest acquired image that hasn't been waited on, returning its index
+    ///
+    /// Returns `Ok(None)` if the wait timed out, in which case it must be retried before
+    /// rendering to the image. See [`Swapchain::wait_image`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if no image is awaiting a wait, or if the previously waited image hasn't been
+    /// released.
+    pub fn wait(&mut self, timeout: Duration) -> Result<Option<u32>> {
+        let index = self.next_unwaited();
+        if self.swapchain.wait_image(timeout)? == sys::Result::TIMEOUT_EXPIRED {
+            return Ok(None);
+        }
+        self.waited += 1;
+        Ok(Some(index))
+    }
+
+    /// Wait for the oldest acquired image that hasn't been waited on according to `policy`,
+    /// returning its index
+    ///
+    /// See [`Swapchain::wait_image_with_policy`].
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`SwapchainImages::wait`].
+    pub fn wait_with_policy(
+        &mut self,
+        policy: &SwapchainWaitPolicy,
+    ) -> std::result::Result<(u32, SwapchainWaitStats), SwapchainWaitError> {
+        let index = self.next_unwaited();
+        let stats = self.swapchain.wait_image_with_policy(policy)?;
+        self.waited += 1;
+        Ok((index, stats))
+    }
+
+    /// Release the oldest acquired image, returning its index
+    ///
+    /// See [`Swapchain::release_image`].
+    pub fn release(&mut self) -> Result<u32> {
+        assert!(self.waited > 0, "wait must be called before release");
+        self.swapchain.release_image()?;
+        self.waited -= 1;
+        Ok(self.acquired.pop_front().unwrap())
+    }
+
+    fn next_unwaited(&self) -> u32 {
+        *self
+            .acquired
+            .get(self.waited)
+            .expect("acquire must be called before wait")
+    }
+}
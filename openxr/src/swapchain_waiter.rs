@@ -0,0 +1,114 @@
+//! Waiting on swapchain images without blocking the render thread.
+//!
+//! [`Swapchain::wait_image`] blocks until the compositor is done reading an image. Engines that
+//! schedule work through a job system can instead hand the swapchain to a [`SwapchainWaiter`],
+//! which waits on a background thread and gives the swapchain back through a callback or channel
+//! once the image is ready. The swapchain is moved for the duration of the wait, so the runtime's
+//! requirement that calls on a swapchain be externally synchronized is upheld by ownership.
+
+use std::{
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+use crate::*;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A pool of threads waiting on swapchain images on behalf of other threads
+///
+/// Dropping the waiter finishes any queued waits, then joins its threads.
+pub struct SwapchainWaiter {
+    sender: Option<mpsc::Sender<Job>>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+/// A swapchain returned by [`SwapchainWaiter`], along with the outcome of its wait
+pub struct SwapchainWaitOutcome<G: Graphics> {
+    pub swapchain: Swapchain<G>,
+    pub result: std::result::Result<SwapchainWaitStats, SwapchainWaitError>,
+}
+
+impl SwapchainWaiter {
+    /// Spawn `threads` threads to wait on
+    ///
+    /// Waits are run concurrently on up to `threads` swapchains; one per swapchain waited on
+    /// each frame is enough to never delay one wait behind another.
+    pub fn new(threads: usize) -> Self {
+        assert!(threads > 0, "a waiter needs at least one thread");
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..threads)
+            .map(|i| {
+                let receiver = receiver.clone();
+                thread::Builder::new()
+                    .name(format!("openxr swapchain waiter {}", i))
+                    .spawn(move || loop {
+                        let job = match receiver.lock().unwrap().recv() {
+                            Ok(job) => job,
+                            Err(mpsc::RecvError) => break,
+                        };
+                        job();
+                    })
+                    .expect("failed to spawn swapchain waiter thread")
+            })
+            .collect();
+        Self {
+            sender: Some(sender),
+            workers,
+        }
+    }
+
+    /// Wait for the oldest unwaited acquired image of `swapchain` in the background, then pass
+    /// the swapchain and the result of [`Swapchain::wait_image_with_policy`] to `callback`
+    ///
+    /// `callback` runs on one of the waiter's threads.
+    pub fn wait<G, F>(&self, mut swapchain: Swapchain<G>, policy: SwapchainWaitPolicy, callback: F)
+    where
+        G: Graphics + 'static,
+        F: FnOnce(SwapchainWaitOutcome<G>) + Send + 'static,
+    {
+        let job = Box::new(move || {
+            let result = swapchain.wait_image_with_policy(&policy);
+            callback(SwapchainWaitOutcome { swapchain, result });
+        });
+        self.sender
+            .as_ref()
+            .unwrap()
+            .send(job)
+            .expect("swapchain waiter threads exited");
+    }
+
+    /// Like [`SwapchainWaiter::wait`], but deliver the outcome through a channel
+    pub fn wait_channel<G>(
+        &self,
+        swapchain: Swapchain<G>,
+        policy: SwapchainWaitPolicy,
+    ) -> mpsc::Receiver<SwapchainWaitOutcome<G>>
+    where
+        G: Graphics + 'static,
+    {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.wait(swapchain, policy, move |outcome| {
+            // The caller may have lost interest; the swapchain is then destroyed here
+            let _ = sender.send(outcome);
+        });
+        receiver
+    }
+}
+
+impl Default for SwapchainWaiter {
+    /// A single waiting thread
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl Drop for SwapchainWaiter {
+    fn drop(&mut self) {
+        self.sender = None;
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
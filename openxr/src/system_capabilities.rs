@@ -0,0 +1,212 @@
+//! Querying the properties of several extensions at once.
+//!
+//! Each of the `Instance::supports_*` methods makes its own `xrGetSystemProperties` call. A
+//! [`SystemPropertiesQuery`] instead chains the property structs of every requested extension onto
+//! one call and reports them together as [`SystemCapabilities`].
+
+use std::{mem, ptr};
+
+use crate::*;
+
+impl Instance {
+    /// Query the properties of `system`, along with those of each extension requested by `query`
+    ///
+    /// Extensions that aren't enabled are skipped and reported as `None`, except for
+    /// XR_EXT_user_presence, which must be
+    /// [enabled by name](crate#extensions-without-extensionset-fields) before requesting it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn f(instance: &openxr::Instance, system: openxr::SystemId) -> openxr::Result<()> {
+    /// let caps = instance.query_system_properties(
+    ///     system,
+    ///     &openxr::SystemPropertiesQuery::new()
+    ///         .hand_tracking()
+    ///         .eye_gaze_interaction(),
+    /// )?;
+    /// if caps.hand_tracking == Some(true) {
+    ///     println!("{} tracks hands", caps.properties.system_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_system_properties(
+        &self,
+        system: SystemId,
+        query: &SystemPropertiesQuery,
+    ) -> Result<SystemCapabilities> {
+        let exts = self.exts();
+        let hand_tracking = query.hand_tracking && exts.ext_hand_tracking.is_some();
+        let eye_gaze_interaction =
+            query.eye_gaze_interaction && exts.ext_eye_gaze_interaction.is_some();
+        let face_tracking = query.face_tracking_fb && exts.fb_face_tracking2.is_some();
+        let body_tracking = query.body_tracking_fb && exts.fb_body_tracking.is_some();
+        let eye_tracked_foveation =
+            query.eye_tracked_foveation_meta && exts.meta_foveation_eye_tracked.is_some();
+        let passthrough = query.passthrough_fb && exts.fb_passthrough.is_some();
+        let user_presence = query.user_presence_ext;
+
+        unsafe {
+            let mut hand_props = sys::SystemHandTrackingPropertiesEXT {
+                ty: sys::SystemHandTrackingPropertiesEXT::TYPE,
+                ..mem::zeroed()
+            };
+            let mut eye_gaze_props = sys::SystemEyeGazeInteractionPropertiesEXT {
+                ty: sys::SystemEyeGazeInteractionPropertiesEXT::TYPE,
+                ..mem::zeroed()
+            };
+            let mut face_props = sys::SystemFaceTrackingProperties2FB {
+                ty: sys::SystemFaceTrackingProperties2FB::TYPE,
+                ..mem::zeroed()
+            };
+            let mut body_props = sys::SystemBodyTrackingPropertiesFB {
+                ty: sys::SystemBodyTrackingPropertiesFB::TYPE,
+                ..mem::zeroed()
+            };
+            let mut foveation_props = sys::SystemFoveationEyeTrackedPropertiesMETA {
+                ty: sys::SystemFoveationEyeTrackedPropertiesMETA::TYPE,
+                ..mem::zeroed()
+            };
+            let mut passthrough_props = sys::SystemPassthroughProperties2FB {
+                ty: sys::SystemPassthroughProperties2FB::TYPE,
+                next: ptr::null(),
+                capabilities: PassthroughCapabilityFlagsFB::EMPTY,
+            };
+            let mut user_presence_props = sys::SystemUserPresencePropertiesEXT {
+                ty: sys::SystemUserPresencePropertiesEXT::TYPE,
+                ..mem::zeroed()
+            };
+
+            let mut next = ptr::null_mut();
+            push_next(&mut next, hand_tracking, &mut hand_props);
+            push_next(&mut next, eye_gaze_interaction, &mut eye_gaze_props);
+            push_next(&mut next, face_tracking, &mut face_props);
+            push_next(&mut next, body_tracking, &mut body_props);
+            push_next(&mut next, eye_tracked_foveation, &mut foveation_props);
+            push_next(&mut next, passthrough, &mut passthrough_props);
+            push_next(&mut next, user_presence, &mut user_presence_props);
+
+            let mut p = sys::SystemProperties::out(next);
+            cvt((self.fp().get_system_properties)(
+                self.as_raw(),
+                system,
+                p.as_mut_ptr(),
+            ))?;
+            Ok(SystemCapabilities {
+                properties: SystemProperties::from_raw(&p.assume_init()),
+                hand_tracking: hand_tracking.then_some(hand_props.supports_hand_tracking.into()),
+                eye_gaze_interaction: eye_gaze_interaction
+                    .then_some(eye_gaze_props.supports_eye_gaze_interaction.into()),
+                visual_face_tracking_fb: face_tracking
+                    .then_some(face_props.supports_visual_face_tracking.into()),
+                audio_face_tracking_fb: face_tracking
+                    .then_some(face_props.supports_audio_face_tracking.into()),
+                body_tracking_fb: body_tracking.then_some(body_props.supports_body_tracking.into()),
+                eye_tracked_foveation_meta: eye_tracked_foveation
+                    .then_some(foveation_props.supports_foveation_eye_tracked.into()),
+                passthrough_capabilities_fb: passthrough.then_some(passthrough_props.capabilities),
+                user_presence_ext: user_presence
+                    .then_some(user_presence_props.supports_user_presence.into()),
+            })
+        }
+    }
+}
+
+/// The extension properties to read in [`Instance::query_system_properties`]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SystemPropertiesQuery {
+    hand_tracking: bool,
+    eye_gaze_interaction: bool,
+    face_tracking_fb: bool,
+    body_tracking_fb: bool,
+    eye_tracked_foveation_meta: bool,
+    passthrough_fb: bool,
+    user_presence_ext: bool,
+}
+
+impl SystemPropertiesQuery {
+    /// Query only the base system properties
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Query every extension listed here, except XR_EXT_user_presence
+    #[inline]
+    pub fn all() -> Self {
+        Self::new()
+            .hand_tracking()
+            .eye_gaze_interaction()
+            .face_tracking_fb()
+            .body_tracking_fb()
+            .eye_tracked_foveation_meta()
+            .passthrough_fb()
+    }
+
+    /// XR_EXT_hand_tracking
+    #[inline]
+    pub fn hand_tracking(mut self) -> Self {
+        self.hand_tracking = true;
+        self
+    }
+
+    /// XR_EXT_eye_gaze_interaction
+    #[inline]
+    pub fn eye_gaze_interaction(mut self) -> Self {
+        self.eye_gaze_interaction = true;
+        self
+    }
+
+    /// XR_FB_face_tracking2
+    #[inline]
+    pub fn face_tracking_fb(mut self) -> Self {
+        self.face_tracking_fb = true;
+        self
+    }
+
+    /// XR_FB_body_tracking
+    #[inline]
+    pub fn body_tracking_fb(mut self) -> Self {
+        self.body_tracking_fb = true;
+        self
+    }
+
+    /// XR_META_foveation_eye_tracked
+    #[inline]
+    pub fn eye_tracked_foveation_meta(mut self) -> Self {
+        self.eye_tracked_foveation_meta = true;
+        self
+    }
+
+    /// XR_FB_passthrough
+    #[inline]
+    pub fn passthrough_fb(mut self) -> Self {
+        self.passthrough_fb = true;
+        self
+    }
+
+    /// XR_EXT_user_presence, which must have been enabled
+    #[inline]
+    pub fn user_presence_ext(mut self) -> Self {
+        self.user_presence_ext = true;
+        self
+    }
+}
+
+/// The result of [`Instance::query_system_properties`]
+///
+/// Each extension field is `None` if it wasn't requested or its extension isn't enabled.
+#[derive(Debug, Clone)]
+pub struct SystemCapabilities {
+    pub properties: SystemProperties,
+    pub hand_tracking: Option<bool>,
+    pub eye_gaze_interaction: Option<bool>,
+    pub visual_face_tracking_fb: Option<bool>,
+    pub audio_face_tracking_fb: Option<bool>,
+    pub body_tracking_fb: Option<bool>,
+    pub eye_tracked_foveation_meta: Option<bool>,
+    /// Empty if the runtime only implements the first version of XR_FB_passthrough
+    pub passthrough_capabilities_fb: Option<PassthroughCapabilityFlagsFB>,
+    pub user_presence_ext: Option<bool>,
+}
@@ -0,0 +1,188 @@
+//! Release velocities for thrown objects.
+//!
+//! Using the velocity of a single [`Space::relate`] at the moment a grip is released makes throws
+//! feel unreliable: hands decelerate as the grip opens, and individual velocities are noisy. A
+//! [`ThrowEstimator`] instead keeps the locations and velocities of the last fraction of a second
+//! and fits a recency-weighted line through them, extrapolated to the release time.
+
+use std::{collections::VecDeque, time};
+
+use crate::*;
+
+/// Estimates the velocity an object should be thrown with from recent locations of the hand
+/// holding it
+///
+/// Call [`ThrowEstimator::sample`] once per frame while the object is held, then
+/// [`ThrowEstimator::release_velocity`] when it's let go.
+#[derive(Debug, Clone)]
+pub struct ThrowEstimator {
+    window: time::Duration,
+    samples: VecDeque<ThrowSample>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct ThrowSample {
+    time: Time,
+    position: Option<Vector3f>,
+    linear_velocity: Option<Vector3f>,
+    angular_velocity: Option<Vector3f>,
+}
+
+/// Velocities to release a thrown object with, as estimated by [`ThrowEstimator`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ThrowVelocity {
+    /// Meters per second in the base space of the samples
+    pub linear: Vector3f,
+    /// Radians per second in the base space of the samples, if any were reported
+    pub angular: Option<Vector3f>,
+}
+
+impl ThrowEstimator {
+    /// Estimate from the samples taken within `window` of the latest one
+    pub fn new(window: time::Duration) -> Self {
+        assert!(window > time::Duration::ZERO, "window must be nonzero");
+        Self {
+            window,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Locate `space`, e.g. a grip pose action space, in `base` at `time` and record it
+    pub fn sample(&mut self, space: &Space, base: &Space, time: Time) -> Result<()> {
+        let (location, velocity) = space.relate(base, time)?;
+        self.record(time, &location, &velocity);
+        Ok(())
+    }
+
+    /// Record a location and velocity obtained elsewhere
+    ///
+    /// Invalid components are ignored. Samples must be recorded in increasing time order.
+    pub fn record(&mut self, time: Time, location: &SpaceLocation, velocity: &SpaceVelocity) {
+        let position_valid = location
+            .location_flags
+            .contains(SpaceLocationFlags::POSITION_VALID);
+        let linear_valid = velocity
+            .velocity_flags
+            .contains(SpaceVelocityFlags::LINEAR_VALID);
+        let angular_valid = velocity
+            .velocity_flags
+            .contains(SpaceVelocityFlags::ANGULAR_VALID);
+        self.samples.push_back(ThrowSample {
+            time,
+            position: Some(location.pose.position).filter(|_| position_valid),
+            linear_velocity: Some(velocity.linear_velocity).filter(|_| linear_valid),
+            angular_velocity: Some(velocity.angular_velocity).filter(|_| angular_valid),
+        });
+        let window = self.window.as_nanos() as i64;
+        while let Some(oldest) = self.samples.front() {
+            if (time - oldest.time).as_nanos() <= window {
+                break;
+            }
+            self.samples.pop_front();
+        }
+    }
+
+    /// Forget all samples, e.g. when a new object is grabbed
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Estimate the velocity at the latest sample
+    ///
+    /// Runtime-reported linear velocities are preferred, fitted with a line to extrapolate the
+    /// hand's acceleration up to the release. Without at least two of those, the velocity is the
+    /// slope of a line fitted to the sampled positions. Returns `None` if neither is available.
+    pub fn release_velocity(&self) -> Option<ThrowVelocity> {
+        let latest = self.samples.back()?.time;
+        let velocities = self.fit(latest, |x| x.linear_velocity);
+        let linear = match velocities {
+            Some(fit) => fit.value,
+            None => self.fit(latest, |x| x.position)?.slope,
+        };
+        let angular = self
+            .fit(latest, |x| x.angular_velocity)
+            .map(|fit| fit.value);
+        Some(ThrowVelocity { linear, angular })
+    }
+
+    /// Fit a line through the values of the samples for which `f` returns one, weighting recent
+    /// samples more, with time measured in seconds relative to `latest`
+    fn fit(&self, latest: Time, f: impl Fn(&ThrowSample) -> Option<Vector3f>) -> Option<LineFit> {
+        let window = self.window.as_secs_f32();
+        let points = self
+            .samples
+            .iter()
+            .filter_map(|x| {
+                let t = (x.time - latest).as_nanos() as f32 * 1e-9;
+                // Weights fall from 1 for the latest sample to 0.5 at the edge of the window
+                f(x).map(|v| (t, 1.0 + 0.5 * t / window, v))
+            })
+            .collect::<Vec<_>>();
+        if points.len() < 2 {
+            return None;
+        }
+        let total = points.iter().map(|&(_, w, _)| w).sum::<f32>();
+        let mean_t = points.iter().map(|&(t, w, _)| w * t).sum::<f32>() / total;
+        let mean_v = scale(
+            &points.iter().fold(Vector3f::default(), |acc, &(_, w, v)| {
+                add(&acc, &scale(&v, w))
+            }),
+            1.0 / total,
+        );
+        let variance = points
+            .iter()
+            .map(|&(t, w, _)| w * (t - mean_t) * (t - mean_t))
+            .sum::<f32>();
+        let slope = if variance > 0.0 {
+            let covariance = points.iter().fold(Vector3f::default(), |acc, &(t, w, v)| {
+                add(&acc, &scale(&sub(&v, &mean_v), w * (t - mean_t)))
+            });
+            scale(&covariance, 1.0 / variance)
+        } else {
+            Vector3f::default()
+        };
+        Some(LineFit {
+            value: sub(&mean_v, &scale(&slope, mean_t)),
+            slope,
+        })
+    }
+}
+
+impl Default for ThrowEstimator {
+    /// Estimate from the last 100ms of samples
+    fn default() -> Self {
+        Self::new(time::Duration::from_millis(100))
+    }
+}
+
+/// A line fitted by [`ThrowEstimator::fit`]
+struct LineFit {
+    /// Value at the latest sample
+    value: Vector3f,
+    /// Change per second
+    slope: Vector3f,
+}
+
+fn add(a: &Vector3f, b: &Vector3f) -> Vector3f {
+    Vector3f {
+        x: a.x + b.x,
+        y: a.y + b.y,
+        z: a.z + b.z,
+    }
+}
+
+fn sub(a: &Vector3f, b: &Vector3f) -> Vector3f {
+    Vector3f {
+        x: a.x - b.x,
+        y: a.y - b.y,
+        z: a.z - b.z,
+    }
+}
+
+fn scale(v: &Vector3f, s: f32) -> Vector3f {
+    Vector3f {
+        x: v.x * s,
+        y: v.y * s,
+        z: v.z * s,
+    }
+}
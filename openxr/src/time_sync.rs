@@ -0,0 +1,152 @@
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::*;
+
+/// Converts between runtime `Time`s and nanoseconds since the UNIX epoch
+///
+/// Runtime time is only meaningful within a single instance, so networked applications can't
+/// exchange it directly. Wall-clock time is comparable across machines whose clocks are
+/// synchronized (e.g. by NTP or PTP), but is adjusted by the OS while the runtime clock isn't, so
+/// the two slowly drift apart. `UnixTimeSync` records pairs of simultaneous readings of both
+/// clocks and fits a line through the most recent ones, modeling both the offset and the drift.
+///
+/// Call [`UnixTimeSync::sample`] periodically (e.g. once per second) to keep the estimate
+/// current.
+///
+/// Requires KHR_convert_timespec_time, or KHR_win32_convert_performance_counter_time on Windows.
+#[derive(Debug, Clone)]
+pub struct UnixTimeSync {
+    capacity: usize,
+    /// Runtime time and the corresponding UNIX time minus runtime time, in nanoseconds
+    samples: VecDeque<(i64, i64)>,
+    fit: Option<Fit>,
+}
+
+/// `offset(t) = offset + drift * (t - origin)`, relative to a recent sample for precision
+#[derive(Debug, Copy, Clone)]
+struct Fit {
+    origin: i64,
+    offset: i64,
+    drift: f64,
+}
+
+impl UnixTimeSync {
+    /// Create a converter that estimates drift from the last `capacity` samples
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be nonzero");
+        Self {
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+            fit: None,
+        }
+    }
+
+    /// Read both clocks and record the result
+    pub fn sample(&mut self, instance: &Instance) -> Result<()> {
+        #[cfg(not(windows))]
+        let supported = instance.exts().khr_convert_timespec_time.is_some();
+        #[cfg(windows)]
+        let supported = instance
+            .exts()
+            .khr_win32_convert_performance_counter_time
+            .is_some();
+        if !supported {
+            return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+        }
+        // Bracket the runtime reading to halve the error introduced by the call itself
+        let before = unix_now();
+        let time = instance.now()?;
+        let after = unix_now();
+        self.add_sample(time, before + (after - before) / 2);
+        Ok(())
+    }
+
+    /// Record that `time` corresponds to `unix_nanos`
+    ///
+    /// Useful for clock readings obtained by other means, such as from a time server.
+    pub fn add_sample(&mut self, time: Time, unix_nanos: i64) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        let time = time.as_nanos();
+        self.samples.push_back((time, unix_nanos - time));
+        self.fit = Some(self.fit());
+    }
+
+    /// Forget all samples, e.g. after the system clock was stepped
+    pub fn reset(&mut self) {
+        self.samples.clear();
+        self.fit = None;
+    }
+
+    /// Nanoseconds since the UNIX epoch at `time`, or `None` if no samples were recorded yet
+    pub fn to_unix_nanos(&self, time: Time) -> Option<i64> {
+        let fit = self.fit?;
+        Some(time.as_nanos() + fit.offset_at(time.as_nanos()))
+    }
+
+    /// Runtime time at `unix_nanos`, or `None` if no samples were recorded yet
+    pub fn from_unix_nanos(&self, unix_nanos: i64) -> Option<Time> {
+        let fit = self.fit?;
+        // Solve `unix = time + offset + drift * (time - origin)` for `time`, relative to the origin
+        // to keep the division precise
+        let since_origin = (unix_nanos - fit.offset - fit.origin) as f64 / (1.0 + fit.drift);
+        Some(Time::from_nanos(fit.origin + since_origin.round() as i64))
+    }
+
+    /// Estimated rate at which the UNIX clock gains on the runtime clock, in nanoseconds per
+    /// second
+    ///
+    /// Zero until at least two samples were recorded.
+    pub fn drift_nanos_per_second(&self) -> f64 {
+        self.fit.map_or(0.0, |fit| fit.drift * 1e9)
+    }
+
+    /// Number of samples currently contributing to the estimate
+    #[inline]
+    pub fn sample_count(&self) -> usize {
+        self.samples.len()
+    }
+
+    fn fit(&self) -> Fit {
+        let &(origin, offset) = self.samples.back().unwrap();
+        let n = self.samples.len() as f64;
+        let points = || {
+            self.samples
+                .iter()
+                .map(|&(t, o)| ((t - origin) as f64, (o - offset) as f64))
+        };
+        let (sum_x, sum_y) = points().fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+        let (mean_x, mean_y) = (sum_x / n, sum_y / n);
+        let (cov, var) = points().fold((0.0, 0.0), |(c, v), (x, y)| {
+            let dx = x - mean_x;
+            (c + dx * (y - mean_y), v + dx * dx)
+        });
+        let drift = if var > 0.0 { cov / var } else { 0.0 };
+        Fit {
+            origin,
+            offset: offset + (mean_y - drift * mean_x).round() as i64,
+            drift,
+        }
+    }
+}
+
+impl Default for UnixTimeSync {
+    fn default() -> Self {
+        Self::new(32)
+    }
+}
+
+impl Fit {
+    fn offset_at(&self, time: i64) -> i64 {
+        self.offset + (self.drift * (time - self.origin) as f64).round() as i64
+    }
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is set before the UNIX epoch")
+        .as_nanos() as i64
+}
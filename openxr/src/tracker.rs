@@ -0,0 +1,163 @@
+//! Body-worn trackers that aren't hands or controllers, behind a single interface.
+//!
+//! [`Trackers`] discovers trackers from every supported extension and follows their connection
+//! events, so full-body tracking consumers need not handle each vendor separately. Currently
+//! supported:
+//!
+//! - Vive trackers, via [`XR_HTCX_vive_tracker_interaction`]. This experimental extension must be
+//!   [enabled by name](crate#extensions-without-extensionset-fields).
+//!
+//! Other tracker extensions, such as PICO's motion trackers, are newer than the registry these
+//! bindings are generated from. None of the supported extensions report battery levels.
+//!
+//! [`XR_HTCX_vive_tracker_interaction`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_HTCX_vive_tracker_interaction
+
+use std::ptr;
+
+use crate::*;
+
+/// Extension a tracker is exposed through
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum TrackerSource {
+    /// `XR_HTCX_vive_tracker_interaction`
+    ViveHTCX,
+}
+
+impl TrackerSource {
+    /// Interaction profile to suggest bindings for when tracking devices from this source
+    pub fn interaction_profile(self) -> &'static str {
+        match self {
+            TrackerSource::ViveHTCX => "/interaction_profiles/htc/vive_tracker_htcx",
+        }
+    }
+}
+
+/// A tracker known to the runtime
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TrackerInfo {
+    pub source: TrackerSource,
+    /// Identifies the device across sessions
+    pub persistent_path: Path,
+    /// The body part the user assigned the tracker to, if any, e.g.
+    /// `/user/vive_tracker_htcx/role/waist`
+    ///
+    /// Trackers are located by passing this as the subaction path for a pose action; see
+    /// [`Trackers::create_space`].
+    pub role: Option<Path>,
+}
+
+/// A change in the set of known trackers
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TrackerEvent {
+    /// A tracker was connected or had its role changed
+    Connected(TrackerInfo),
+}
+
+/// Discovery of trackers across every supported extension
+pub struct Trackers {
+    instance: Instance,
+    enumerate_vive: Option<sys::pfn::EnumerateViveTrackerPathsHTCX>,
+    known: Vec<TrackerInfo>,
+}
+
+impl Trackers {
+    /// Prepare to discover trackers from whichever supported extensions are enabled on
+    /// `instance`
+    pub fn new(instance: &Instance) -> Self {
+        let enumerate_vive =
+            unsafe { load_ext_fn(instance, b"xrEnumerateViveTrackerPathsHTCX\0").ok() };
+        Self {
+            instance: instance.clone(),
+            enumerate_vive,
+            known: Vec::new(),
+        }
+    }
+
+    /// Sources that trackers can be discovered from on this instance
+    pub fn sources(&self) -> Vec<TrackerSource> {
+        let mut out = Vec::new();
+        if self.enumerate_vive.is_some() {
+            out.push(TrackerSource::ViveHTCX);
+        }
+        out
+    }
+
+    /// Query every source for the trackers currently connected
+    pub fn enumerate(&mut self) -> Result<&[TrackerInfo]> {
+        self.known.clear();
+        if let Some(enumerate_vive) = self.enumerate_vive {
+            let instance = self.instance.as_raw();
+            let paths = get_arr_init(
+                sys::ViveTrackerPathsHTCX::out(ptr::null_mut()),
+                |cap, count, buf| unsafe { enumerate_vive(instance, cap, count, buf as _) },
+            )?;
+            self.known.extend(
+                paths
+                    .into_iter()
+                    .map(|x| vive_tracker_info(unsafe { x.assume_init() }.into())),
+            );
+        }
+        Ok(&self.known)
+    }
+
+    /// Trackers found by the last [`Trackers::enumerate`] or reported by events since
+    #[inline]
+    pub fn trackers(&self) -> &[TrackerInfo] {
+        &self.known
+    }
+
+    /// Update the known trackers from `event`, returning the change if it concerns a tracker
+    pub fn handle_event(&mut self, event: &Event<'_>) -> Option<TrackerEvent> {
+        let info = match *event {
+            Event::ViveTrackerConnectedHTCX(e) => vive_tracker_info(e.paths()),
+            _ => return None,
+        };
+        match self
+            .known
+            .iter_mut()
+            .find(|x| x.source == info.source && x.persistent_path == info.persistent_path)
+        {
+            Some(known) => *known = info,
+            None => self.known.push(info),
+        }
+        Some(TrackerEvent::Connected(info))
+    }
+
+    /// Name of the body part `tracker` is assigned to, e.g. `waist`
+    pub fn role_name(&self, tracker: &TrackerInfo) -> Result<Option<String>> {
+        let role = match tracker.role {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+        let path = self.instance.path_to_string(role)?;
+        Ok(Some(match path.rfind('/') {
+            Some(i) => path[i + 1..].into(),
+            None => path,
+        }))
+    }
+
+    /// Create a space tracking `tracker` through `action`, which must be a pose action bound for
+    /// the tracker's role in the source's interaction profile
+    ///
+    /// Trackers without a role can't be located.
+    pub fn create_space<G>(
+        &self,
+        session: Session<G>,
+        action: &Action<Posef>,
+        tracker: &TrackerInfo,
+        pose_in_action_space: Posef,
+    ) -> Result<Space> {
+        let role = tracker.role.ok_or(sys::Result::ERROR_PATH_INVALID)?;
+        action.create_space(session, role, pose_in_action_space)
+    }
+}
+
+fn vive_tracker_info(paths: ViveTrackerPathsHTCX) -> TrackerInfo {
+    TrackerInfo {
+        source: TrackerSource::ViveHTCX,
+        persistent_path: paths.persistent,
+        role: paths.role,
+    }
+}
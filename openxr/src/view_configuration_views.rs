@@ -0,0 +1,203 @@
+//! View configuration views with per-view extension structs.
+//!
+//! Several extensions report extra data about each view by chaining a struct onto each
+//! `XrViewConfigurationView`. [`Instance::enumerate_view_configuration_views_with`] chains every
+//! struct requested by a [`ViewConfigurationViewsQuery`] onto each view in one enumeration.
+
+use std::{mem, mem::MaybeUninit, ptr};
+
+use crate::*;
+
+impl Instance {
+    /// Like [`Instance::enumerate_view_configuration_views`], but with the extension data requested
+    /// by `query` for each view
+    ///
+    /// Fails with `ERROR_EXTENSION_NOT_PRESENT` if any requested extension isn't enabled.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn f(instance: &openxr::Instance, system: openxr::SystemId) -> openxr::Result<()> {
+    /// let views = instance.enumerate_view_configuration_views_with(
+    ///     system,
+    ///     openxr::ViewConfigurationType::PRIMARY_STEREO,
+    ///     &openxr::ViewConfigurationViewsQuery::new().depth_range_ext(),
+    /// )?;
+    /// for view in &views {
+    ///     let depth = view.depth_range_ext.unwrap();
+    ///     println!("near plane at {}m", depth.recommended_near_z);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn enumerate_view_configuration_views_with(
+        &self,
+        system: SystemId,
+        ty: ViewConfigurationType,
+        query: &ViewConfigurationViewsQuery,
+    ) -> Result<Vec<ViewConfigurationViewExt>> {
+        let exts = self.exts();
+        if (query.depth_range_ext && exts.ext_view_configuration_depth_range.is_none())
+            || (query.fov_epic && exts.epic_view_configuration_fov.is_none())
+            || (query.foveated_varjo.is_some() && exts.varjo_foveated_rendering.is_none())
+        {
+            return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+        }
+        let enumerate = self.fp().enumerate_view_configuration_views;
+        let mut count = 0;
+        unsafe {
+            cvt(enumerate(
+                self.as_raw(),
+                system,
+                ty,
+                0,
+                &mut count,
+                ptr::null_mut(),
+            ))?;
+        }
+        loop {
+            // Written to by the runtime, so each view needs its own
+            let mut outputs = vec![ViewOutputs::new(query); count as usize];
+            let mut views = outputs
+                .iter_mut()
+                .map(|x| sys::ViewConfigurationView::out(unsafe { x.chain(query) }))
+                .collect::<Vec<MaybeUninit<_>>>();
+            let result = unsafe {
+                cvt(enumerate(
+                    self.as_raw(),
+                    system,
+                    ty,
+                    count,
+                    &mut count,
+                    views.as_mut_ptr() as _,
+                ))
+            };
+            match result {
+                Ok(_) => {
+                    return Ok(views
+                        .iter()
+                        .zip(&outputs)
+                        .take(count as usize)
+                        .map(|(view, x)| ViewConfigurationViewExt {
+                            view: ViewConfigurationView::from_raw(unsafe { &*view.as_ptr() }),
+                            depth_range_ext: query.depth_range_ext.then_some(ViewDepthRange {
+                                recommended_near_z: x.depth_range.recommended_near_z,
+                                min_near_z: x.depth_range.min_near_z,
+                                recommended_far_z: x.depth_range.recommended_far_z,
+                                max_far_z: x.depth_range.max_far_z,
+                            }),
+                            fov_epic: query.fov_epic.then_some(ViewConfigurationFovEPIC {
+                                recommended_fov: x.fov.recommended_fov,
+                                max_mutable_fov: x.fov.max_mutable_fov,
+                            }),
+                        })
+                        .collect());
+                }
+                Err(sys::Result::ERROR_SIZE_INSUFFICIENT) => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// The extension structs chained onto one view
+#[derive(Copy, Clone)]
+struct ViewOutputs {
+    depth_range: sys::ViewConfigurationDepthRangeEXT,
+    fov: sys::ViewConfigurationViewFovEPIC,
+    foveated: sys::FoveatedViewConfigurationViewVARJO,
+}
+
+impl ViewOutputs {
+    fn new(query: &ViewConfigurationViewsQuery) -> Self {
+        unsafe {
+            Self {
+                depth_range: sys::ViewConfigurationDepthRangeEXT {
+                    ty: sys::ViewConfigurationDepthRangeEXT::TYPE,
+                    ..mem::zeroed()
+                },
+                fov: sys::ViewConfigurationViewFovEPIC {
+                    ty: sys::ViewConfigurationViewFovEPIC::TYPE,
+                    ..mem::zeroed()
+                },
+                foveated: sys::FoveatedViewConfigurationViewVARJO {
+                    ty: sys::FoveatedViewConfigurationViewVARJO::TYPE,
+                    next: ptr::null_mut(),
+                    foveated_rendering_active: query.foveated_varjo.unwrap_or(false).into(),
+                },
+            }
+        }
+    }
+
+    /// Link the structs requested by `query`, returning the head of the chain
+    ///
+    /// `self` must not move while the chain is in use.
+    unsafe fn chain(&mut self, query: &ViewConfigurationViewsQuery) -> *mut sys::BaseOutStructure {
+        let mut next = ptr::null_mut();
+        push_next(&mut next, query.depth_range_ext, &mut self.depth_range);
+        push_next(&mut next, query.fov_epic, &mut self.fov);
+        push_next(
+            &mut next,
+            query.foveated_varjo.is_some(),
+            &mut self.foveated,
+        );
+        next
+    }
+}
+
+/// The extension structs to chain onto each view in
+/// [`Instance::enumerate_view_configuration_views_with`]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ViewConfigurationViewsQuery {
+    depth_range_ext: bool,
+    fov_epic: bool,
+    foveated_varjo: Option<bool>,
+}
+
+impl ViewConfigurationViewsQuery {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report each view's depth range; requires XR_EXT_view_configuration_depth_range
+    #[inline]
+    pub fn depth_range_ext(mut self) -> Self {
+        self.depth_range_ext = true;
+        self
+    }
+
+    /// Report each view's fields of view; requires XR_EPIC_view_configuration_fov
+    #[inline]
+    pub fn fov_epic(mut self) -> Self {
+        self.fov_epic = true;
+        self
+    }
+
+    /// Recommend sizes for foveated rendering if `foveated` is set; requires
+    /// XR_VARJO_foveated_rendering
+    #[inline]
+    pub fn foveated_varjo(mut self, foveated: bool) -> Self {
+        self.foveated_varjo = Some(foveated);
+        self
+    }
+}
+
+/// A view from [`Instance::enumerate_view_configuration_views_with`]
+///
+/// Each extension field is `None` if it wasn't requested.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ViewConfigurationViewExt {
+    pub view: ViewConfigurationView,
+    pub depth_range_ext: Option<ViewDepthRange>,
+    pub fov_epic: Option<ViewConfigurationFovEPIC>,
+}
+
+/// The depths a view's projection should use, in meters
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ViewDepthRange {
+    pub recommended_near_z: f32,
+    pub min_near_z: f32,
+    pub recommended_far_z: f32,
+    pub max_far_z: f32,
+}
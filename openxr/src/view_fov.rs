@@ -0,0 +1,123 @@
+//! Fields of view other than the ones located for each view.
+//!
+//! When [`ViewConfigurationProperties::fov_mutable`] is set, a projection view may be rendered and
+//! submitted with a different field of view than [`Session::locate_views`] returned, e.g. a
+//! narrower one to reduce motion sickness, or a small one around the gaze point for a
+//! high-resolution foveal inset layer. The functions here derive such fields of view, and
+//! [`ViewConfigurationProperties::resolve_fov`] falls back to the located one when the runtime
+//! doesn't permit changing it. With [`XR_EPIC_view_configuration_fov`], the runtime also reports
+//! the widest field of view it accepts, for use as the `limit` of [`fov_restricted`].
+//!
+//! [`XR_EPIC_view_configuration_fov`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_EPIC_view_configuration_fov
+
+use crate::*;
+
+/// Scale `fov` about its center by `factor` in tangent space, i.e. in image-plane extent
+///
+/// The result covers `factor` times the width and height of `fov`'s image plane, so rendering it
+/// at `factor` times the resolution preserves pixel density.
+pub fn fov_scaled(fov: Fovf, factor: f32) -> Fovf {
+    let [left, right, up, down] = tangents(&fov);
+    let (cx, cy) = ((left + right) / 2.0, (up + down) / 2.0);
+    from_tangents([
+        cx + (left - cx) * factor,
+        cx + (right - cx) * factor,
+        cy + (up - cy) * factor,
+        cy + (down - cy) * factor,
+    ])
+}
+
+/// Restrict `fov` to lie within `limit`, e.g. to narrow the view as a comfort measure
+pub fn fov_restricted(fov: Fovf, limit: Fovf) -> Fovf {
+    Fovf {
+        angle_left: fov.angle_left.max(limit.angle_left),
+        angle_right: fov.angle_right.min(limit.angle_right),
+        angle_up: fov.angle_up.min(limit.angle_up),
+        angle_down: fov.angle_down.max(limit.angle_down),
+    }
+}
+
+/// A region of `fov` covering `factor` of its image-plane width and height, centered as near to
+/// `center` as it can be while staying within `fov`
+///
+/// `center` is a direction in view space, such as a [`GazeRay::direction`] transformed into the
+/// view's space; directions facing away from the view's -Z axis center the region in `fov`.
+pub fn fov_inset(fov: Fovf, center: Vector3f, factor: f32) -> Fovf {
+    let [left, right, up, down] = tangents(&fov);
+    let (cx, cy) = if center.z < 0.0 {
+        (-center.x / center.z, -center.y / center.z)
+    } else {
+        ((left + right) / 2.0, (up + down) / 2.0)
+    };
+    let factor = factor.clamp(0.0, 1.0);
+    let (width, height) = ((right - left) * factor, (up - down) * factor);
+    let x = (cx - width / 2.0).max(left).min(right - width);
+    let y = (cy - height / 2.0).max(down).min(up - height);
+    from_tangents([x, x + width, y + height, y])
+}
+
+impl ViewConfigurationProperties {
+    /// The field of view to render and submit a view with in place of `located`, the one returned
+    /// by [`Session::locate_views`]
+    ///
+    /// Returns `custom` if the runtime permits submitting other fields of view, or `located`
+    /// otherwise.
+    #[inline]
+    pub fn resolve_fov(&self, located: Fovf, custom: Fovf) -> Fovf {
+        if self.fov_mutable {
+            custom
+        } else {
+            located
+        }
+    }
+}
+
+impl Instance {
+    /// Like [`Instance::enumerate_view_configuration_views`], but with the fields of view of each
+    /// view
+    ///
+    /// Requires XR_EPIC_view_configuration_fov.
+    pub fn enumerate_view_configuration_views_fov_epic(
+        &self,
+        system: SystemId,
+        ty: ViewConfigurationType,
+    ) -> Result<Vec<(ViewConfigurationView, ViewConfigurationFovEPIC)>> {
+        let views = self.enumerate_view_configuration_views_with(
+            system,
+            ty,
+            &ViewConfigurationViewsQuery::new().fov_epic(),
+        )?;
+        Ok(views
+            .into_iter()
+            .map(|x| (x.view, x.fov_epic.unwrap()))
+            .collect())
+    }
+}
+
+/// Fields of view of a view, from [`Instance::enumerate_view_configuration_views_fov_epic`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ViewConfigurationFovEPIC {
+    /// The field of view the runtime recommends rendering the view with
+    pub recommended_fov: Fovf,
+    /// The widest field of view the view may be submitted with, if
+    /// [`ViewConfigurationProperties::fov_mutable`] is set
+    pub max_mutable_fov: Fovf,
+}
+
+fn tangents(fov: &Fovf) -> [f32; 4] {
+    [
+        fov.angle_left.tan(),
+        fov.angle_right.tan(),
+        fov.angle_up.tan(),
+        fov.angle_down.tan(),
+    ]
+}
+
+fn from_tangents([left, right, up, down]: [f32; 4]) -> Fovf {
+    Fovf {
+        angle_left: left.atan(),
+        angle_right: right.atan(),
+        angle_up: up.atan(),
+        angle_down: down.atan(),
+    }
+}
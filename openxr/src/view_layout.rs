@@ -0,0 +1,174 @@
+//! Placement of a view configuration's views within a single swapchain.
+//!
+//! Projection layers reference each view's image through a [`SwapchainSubImage`], whose rect and
+//! array index must match where the renderer actually drew that view. [`SwapchainLayout`] derives
+//! the swapchain's dimensions and each view's region from the runtime's recommended view sizes, so
+//! the same values can be used for the swapchain, the renderer's viewports and scissors, and the
+//! submitted layer.
+
+use crate::*;
+
+/// How views share a swapchain
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ViewLayout {
+    /// One array layer per view, e.g. for rendering with `VK_KHR_multiview` or
+    /// `GL_OVR_multiview2`
+    Multiview,
+    /// Views placed left to right in a single layer
+    SideBySide,
+}
+
+/// The swapchain dimensions and per-view regions for a set of views in a [`ViewLayout`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwapchainLayout {
+    layout: ViewLayout,
+    width: u32,
+    height: u32,
+    array_size: u32,
+    views: Vec<ViewRegion>,
+}
+
+impl SwapchainLayout {
+    /// Lay out views at their recommended sizes, as returned by
+    /// [`Instance::enumerate_view_configuration_views`]
+    pub fn new(layout: ViewLayout, views: &[ViewConfigurationView]) -> Self {
+        let extents = views
+            .iter()
+            .map(|view| Extent2Di {
+                width: view.recommended_image_rect_width as i32,
+                height: view.recommended_image_rect_height as i32,
+            })
+            .collect::<Vec<_>>();
+        Self::from_extents(layout, &extents)
+    }
+
+    /// Lay out views of explicit sizes, e.g. recommended sizes scaled for performance
+    pub fn from_extents(layout: ViewLayout, extents: &[Extent2Di]) -> Self {
+        let height = extents.iter().map(|x| x.height).max().unwrap_or(0);
+        let mut x = 0;
+        let views = extents
+            .iter()
+            .enumerate()
+            .map(|(i, &extent)| match layout {
+                ViewLayout::Multiview => ViewRegion {
+                    array_index: i as u32,
+                    rect: Rect2Di {
+                        offset: Offset2Di { x: 0, y: 0 },
+                        extent,
+                    },
+                },
+                ViewLayout::SideBySide => {
+                    let offset = Offset2Di { x, y: 0 };
+                    x += extent.width;
+                    ViewRegion {
+                        array_index: 0,
+                        rect: Rect2Di { offset, extent },
+                    }
+                }
+            })
+            .collect();
+        let (width, array_size) = match layout {
+            ViewLayout::Multiview => (
+                extents.iter().map(|x| x.width).max().unwrap_or(0),
+                extents.len() as u32,
+            ),
+            ViewLayout::SideBySide => (x, 1),
+        };
+        Self {
+            layout,
+            width: width as u32,
+            height: height as u32,
+            array_size,
+            views,
+        }
+    }
+
+    #[inline]
+    pub fn layout(&self) -> ViewLayout {
+        self.layout
+    }
+
+    /// Width to create the swapchain with
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Height to create the swapchain with
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Array size to create the swapchain with
+    #[inline]
+    pub fn array_size(&self) -> u32 {
+        self.array_size
+    }
+
+    /// Where each view is drawn, in view configuration order
+    #[inline]
+    pub fn views(&self) -> &[ViewRegion] {
+        &self.views
+    }
+
+    /// `base` with its dimensions and array size replaced by this layout's
+    pub fn swapchain_create_info<G: Graphics>(
+        &self,
+        base: SwapchainCreateInfo<G>,
+    ) -> SwapchainCreateInfo<G> {
+        SwapchainCreateInfo {
+            width: self.width,
+            height: self.height,
+            array_size: self.array_size,
+            ..base
+        }
+    }
+}
+
+/// Where a single view is drawn within a swapchain image
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ViewRegion {
+    /// Array layer holding the view
+    pub array_index: u32,
+    /// Region of the array layer holding the view
+    pub rect: Rect2Di,
+}
+
+impl ViewRegion {
+    /// The viewport to render the view with, covering [`ViewRegion::rect`]
+    pub fn viewport(&self) -> Viewport {
+        Viewport {
+            x: self.rect.offset.x as f32,
+            y: self.rect.offset.y as f32,
+            width: self.rect.extent.width as f32,
+            height: self.rect.extent.height as f32,
+        }
+    }
+
+    /// The scissor rectangle confining rendering to the view, i.e. [`ViewRegion::rect`]
+    #[inline]
+    pub fn scissor(&self) -> Rect2Di {
+        self.rect
+    }
+
+    /// The sub-image to submit for the view in a projection layer
+    pub fn sub_image<'a, G: Graphics>(
+        &self,
+        swapchain: &'a Swapchain<G>,
+    ) -> SwapchainSubImage<'a, G> {
+        SwapchainSubImage::new()
+            .swapchain(swapchain)
+            .image_array_index(self.array_index)
+            .image_rect(self.rect)
+    }
+}
+
+/// A viewport in pixels, with the origin at the image's top left corner
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Viewport {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
@@ -0,0 +1,186 @@
+//! Locating views with extension inputs and outputs.
+//!
+//! [`Session::locate_views_with`] is [`Session::locate_views`] with the extension data requested
+//! by a [`ViewLocateQuery`], and reports the views' shared state as a [`ViewState`] rather than raw
+//! flags.
+
+use std::ptr;
+
+use crate::*;
+
+impl<G> Session<G> {
+    /// Like [`Session::locate_views`], but with the extension data requested by `query`
+    ///
+    /// Fails with `ERROR_EXTENSION_NOT_PRESENT` if any requested extension isn't enabled.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn f(
+    /// #     session: &openxr::Session<openxr::Headless>,
+    /// #     stage: &openxr::Space,
+    /// #     time: openxr::Time,
+    /// # ) -> openxr::Result<()> {
+    /// let located = session.locate_views_with(
+    ///     openxr::ViewConfigurationType::PRIMARY_STEREO,
+    ///     time,
+    ///     stage,
+    ///     &openxr::ViewLocateQuery::new(),
+    /// )?;
+    /// if located.state.is_pose_valid() {
+    ///     // Render `located.views`
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn locate_views_with(
+        &self,
+        view_configuration_type: ViewConfigurationType,
+        display_time: Time,
+        space: &Space,
+        query: &ViewLocateQuery,
+    ) -> Result<LocatedViews> {
+        let exts = self.instance().exts();
+        if query.foveated_varjo.is_some() && exts.varjo_foveated_rendering.is_none() {
+            return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+        }
+        let foveation_meta = if query.foveation_eye_tracked_meta {
+            Some(
+                exts.meta_foveation_eye_tracked
+                    .as_ref()
+                    .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?,
+            )
+        } else {
+            None
+        };
+
+        let foveation_varjo = sys::ViewLocateFoveatedRenderingVARJO {
+            ty: sys::ViewLocateFoveatedRenderingVARJO::TYPE,
+            next: ptr::null(),
+            foveated_rendering_active: query.foveated_varjo.unwrap_or(false).into(),
+        };
+        let next = match query.foveated_varjo {
+            Some(_) => &foveation_varjo as *const _ as _,
+            None => ptr::null(),
+        };
+        let (flags, views) =
+            self.locate_views_chained(next, view_configuration_type, display_time, space)?;
+
+        let foveation_eye_tracked_meta = match foveation_meta {
+            Some(ext) => unsafe {
+                let mut out = sys::FoveationEyeTrackedStateMETA::out(ptr::null_mut());
+                cvt((ext.get_foveation_eye_tracked_state)(
+                    self.as_raw(),
+                    out.as_mut_ptr(),
+                ))?;
+                let out = out.assume_init();
+                Some(FoveationEyeTrackedStateMETA {
+                    foveation_center: out.foveation_center,
+                    flags: out.flags,
+                })
+            },
+            None => None,
+        };
+        Ok(LocatedViews {
+            state: ViewState(flags),
+            views,
+            foveation_eye_tracked_meta,
+        })
+    }
+}
+
+/// The extension data to pass and read in [`Session::locate_views_with`]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ViewLocateQuery {
+    foveated_varjo: Option<bool>,
+    foveation_eye_tracked_meta: bool,
+}
+
+impl ViewLocateQuery {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Locate foveated focus views if `foveated` is set; requires XR_VARJO_foveated_rendering
+    ///
+    /// Must match the choice made when sizing swapchains with
+    /// [`ViewConfigurationViewsQuery::foveated_varjo`].
+    #[inline]
+    pub fn foveated_varjo(mut self, foveated: bool) -> Self {
+        self.foveated_varjo = Some(foveated);
+        self
+    }
+
+    /// Also read the latest foveation center; requires XR_META_foveation_eye_tracked
+    ///
+    /// The runtime reports this separately from the views, as of the latest frame rather than the
+    /// display time the views are located at.
+    #[inline]
+    pub fn foveation_eye_tracked_meta(mut self) -> Self {
+        self.foveation_eye_tracked_meta = true;
+        self
+    }
+}
+
+/// The result of [`Session::locate_views_with`]
+#[derive(Clone)]
+pub struct LocatedViews {
+    pub state: ViewState,
+    /// Only the parts of each pose indicated valid by `state` are meaningful
+    pub views: Vec<View>,
+    /// `None` if not requested
+    pub foveation_eye_tracked_meta: Option<FoveationEyeTrackedStateMETA>,
+}
+
+/// The validity and tracking state shared by a set of located views
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ViewState(pub ViewStateFlags);
+
+impl ViewState {
+    #[inline]
+    pub fn flags(self) -> ViewStateFlags {
+        self.0
+    }
+
+    #[inline]
+    pub fn is_orientation_valid(self) -> bool {
+        self.0.contains(ViewStateFlags::ORIENTATION_VALID)
+    }
+
+    #[inline]
+    pub fn is_position_valid(self) -> bool {
+        self.0.contains(ViewStateFlags::POSITION_VALID)
+    }
+
+    /// Whether both the orientations and positions of the views are valid
+    #[inline]
+    pub fn is_pose_valid(self) -> bool {
+        self.is_orientation_valid() && self.is_position_valid()
+    }
+
+    #[inline]
+    pub fn is_orientation_tracked(self) -> bool {
+        self.0.contains(ViewStateFlags::ORIENTATION_TRACKED)
+    }
+
+    #[inline]
+    pub fn is_position_tracked(self) -> bool {
+        self.0.contains(ViewStateFlags::POSITION_TRACKED)
+    }
+}
+
+impl From<ViewStateFlags> for ViewState {
+    #[inline]
+    fn from(x: ViewStateFlags) -> Self {
+        Self(x)
+    }
+}
+
+/// Where the user is looking within the views, from XR_META_foveation_eye_tracked
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FoveationEyeTrackedStateMETA {
+    /// The center of foveation in each view, in normalized device coordinates
+    pub foveation_center: [Vector2f; sys::FOVEATION_CENTER_SIZE_META],
+    pub flags: FoveationEyeTrackedStateFlagsMETA,
+}
@@ -1,5 +1,6 @@
 use crate::Path;
 
+#[derive(Debug, Copy, Clone)]
 pub struct ViveTrackerPathsHTCX {
     pub persistent: Path,
     pub role: Option<Path>,
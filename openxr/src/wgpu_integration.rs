@@ -0,0 +1,284 @@
+//! Rendering with [`wgpu`] through `XR_KHR_vulkan_enable2`.
+//!
+//! wgpu can't create a Vulkan instance or device on an OpenXR runtime's behalf, so
+//! [`Instance::create_wgpu_context`] creates them through the runtime and wraps them with
+//! `wgpu-hal`, and [`Swapchain::enumerate_wgpu_textures`] wraps swapchain images the same way.
+//!
+//! Available if the `wgpu` feature is enabled.
+
+use std::{fmt, mem, os::raw::c_char, slice};
+
+use ash::vk::{self, Handle};
+use wgpu::hal;
+
+use crate::*;
+
+/// The Vulkan version requested from the runtime, which guarantees multiview support
+const VK_TARGET_VERSION: u32 = vk::make_api_version(0, 1, 1, 0);
+
+/// A wgpu device created for use with a particular system
+pub struct WgpuContext {
+    pub instance: wgpu::Instance,
+    pub adapter: wgpu::Adapter,
+    pub device: wgpu::Device,
+    pub queue: wgpu::Queue,
+    /// Binds a session to [`WgpuContext::device`] when passed to [`Instance::create_session`]
+    pub session_create_info: vulkan::SessionCreateInfo,
+}
+
+impl Instance {
+    /// Create a wgpu instance, adapter and device suitable for use with `system`
+    ///
+    /// `features` and `limits` are requested from the device as by `wgpu::Adapter::request_device`.
+    /// Stereo rendering with a single pass needs `wgpu::Features::MULTIVIEW`.
+    ///
+    /// Requires `XR_KHR_vulkan_enable2`.
+    ///
+    /// # Safety
+    ///
+    /// The session must be created from [`WgpuContext::session_create_info`] before the device is
+    /// used, and the device must outlive the session.
+    pub unsafe fn create_wgpu_context(
+        &self,
+        system: SystemId,
+        features: wgpu::Features,
+        limits: wgpu::Limits,
+    ) -> Result<WgpuContext, WgpuInitError> {
+        self.exts()
+            .khr_vulkan_enable2
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let requirements = self.graphics_requirements::<Vulkan>(system)?;
+        let target = Version::new(1, 1, 0);
+        if requirements.min_api_version_supported > target
+            || requirements.max_api_version_supported.major() < target.major()
+        {
+            return Err(WgpuInitError::VersionUnsupported(requirements));
+        }
+
+        let vk_entry = ash::Entry::load().map_err(|e| WgpuInitError::Wgpu(e.to_string()))?;
+        let get_instance_proc_addr = mem::transmute::<
+            vk::PFN_vkGetInstanceProcAddr,
+            sys::platform::VkGetInstanceProcAddr,
+        >(vk_entry.static_fn().get_instance_proc_addr);
+        let flags = wgpu::InstanceFlags::from_build_config();
+        let instance_extensions =
+            hal::vulkan::Instance::desired_extensions(&vk_entry, VK_TARGET_VERSION, flags)
+                .map_err(|e| WgpuInitError::Wgpu(e.to_string()))?;
+        let instance_extension_names = extension_names(&instance_extensions);
+        let app_info = vk::ApplicationInfo::builder().api_version(VK_TARGET_VERSION);
+        let vk_instance = self
+            .create_vulkan_instance(
+                system,
+                get_instance_proc_addr,
+                &vk::InstanceCreateInfo::builder()
+                    .application_info(&app_info)
+                    .enabled_extension_names(&instance_extension_names) as *const _
+                    as *const _,
+            )?
+            .map_err(|e| WgpuInitError::Vulkan(vk::Result::from_raw(e)))?;
+        let vk_instance = ash::Instance::load(
+            vk_entry.static_fn(),
+            vk::Instance::from_raw(vk_instance as _),
+        );
+        // wgpu-hal destroys instances given a drop guard, and leaves others to the caller
+        let hal_instance = hal::vulkan::Instance::from_raw(
+            vk_entry,
+            vk_instance.clone(),
+            VK_TARGET_VERSION,
+            0,
+            None,
+            instance_extensions,
+            flags,
+            false,
+            Some(Box::new(())),
+        )
+        .map_err(|e| WgpuInitError::Wgpu(e.to_string()))?;
+
+        let vk_physical_device = vk::PhysicalDevice::from_raw(
+            self.vulkan_graphics_device(system, vk_instance.handle().as_raw() as _)? as _,
+        );
+        let hal_adapter = hal_instance
+            .expose_adapter(vk_physical_device)
+            .ok_or_else(|| WgpuInitError::Wgpu("physical device unsupported by wgpu".into()))?;
+        let queue_family_index = vk_instance
+            .get_physical_device_queue_family_properties(vk_physical_device)
+            .into_iter()
+            .position(|info| info.queue_flags.contains(vk::QueueFlags::GRAPHICS))
+            .ok_or(WgpuInitError::NoGraphicsQueue)? as u32;
+
+        let device_extensions = hal_adapter.adapter.required_device_extensions(features);
+        let device_extension_names = extension_names(&device_extensions);
+        let mut physical_device_features = hal_adapter
+            .adapter
+            .physical_device_features(&device_extensions, features);
+        let queue_info = vk::DeviceQueueCreateInfo::builder()
+            .queue_family_index(queue_family_index)
+            .queue_priorities(&[1.0])
+            .build();
+        let device_info = physical_device_features.add_to_device_create_builder(
+            vk::DeviceCreateInfo::builder()
+                .queue_create_infos(slice::from_ref(&queue_info))
+                .enabled_extension_names(&device_extension_names),
+        );
+        let raw_device = self
+            .create_vulkan_device(
+                system,
+                get_instance_proc_addr,
+                vk_physical_device.as_raw() as _,
+                &device_info as *const _ as *const _,
+            )?
+            .map_err(|e| WgpuInitError::Vulkan(vk::Result::from_raw(e)))?;
+        let vk_device =
+            ash::Device::load(vk_instance.fp_v1_0(), vk::Device::from_raw(raw_device as _));
+        let hal_device = hal_adapter
+            .adapter
+            .device_from_raw(
+                vk_device,
+                true,
+                &device_extensions,
+                features,
+                queue_family_index,
+                0,
+            )
+            .map_err(|e| WgpuInitError::Wgpu(e.to_string()))?;
+
+        let instance = wgpu::Instance::from_hal::<hal::api::Vulkan>(hal_instance);
+        let adapter = instance.create_adapter_from_hal(hal_adapter);
+        let (device, queue) = adapter
+            .create_device_from_hal(
+                hal_device,
+                &wgpu::DeviceDescriptor {
+                    label: None,
+                    required_features: features,
+                    required_limits: limits,
+                },
+                None,
+            )
+            .map_err(|e| WgpuInitError::Wgpu(e.to_string()))?;
+
+        Ok(WgpuContext {
+            instance,
+            adapter,
+            device,
+            queue,
+            session_create_info: vulkan::SessionCreateInfo {
+                instance: vk_instance.handle().as_raw() as _,
+                physical_device: vk_physical_device.as_raw() as _,
+                device: raw_device,
+                queue_family_index,
+                queue_index: 0,
+            },
+        })
+    }
+}
+
+impl Swapchain<Vulkan> {
+    /// Wrap the swapchain's images as textures of `device`
+    ///
+    /// The textures remain owned by the swapchain.
+    ///
+    /// # Safety
+    ///
+    /// `device` must be the [`WgpuContext::device`] the swapchain's session was created with, and
+    /// `desc` must describe the swapchain's images: a size of `width` by `height` by
+    /// `array_size`, with matching format, sample count, mip level count and usage.
+    pub unsafe fn enumerate_wgpu_textures(
+        &self,
+        device: &wgpu::Device,
+        desc: &wgpu::TextureDescriptor<'_>,
+    ) -> Result<Vec<wgpu::Texture>> {
+        let hal_desc = hal::TextureDescriptor {
+            label: desc.label,
+            size: desc.size,
+            mip_level_count: desc.mip_level_count,
+            sample_count: desc.sample_count,
+            dimension: desc.dimension,
+            format: desc.format,
+            usage: hal_texture_uses(desc.format, desc.usage),
+            memory_flags: hal::MemoryFlags::empty(),
+            view_formats: desc.view_formats.to_vec(),
+        };
+        Ok(self
+            .enumerate_images()?
+            .into_iter()
+            .map(|image| {
+                // The drop guard keeps wgpu from destroying images the swapchain owns
+                let hal_texture = hal::vulkan::Device::texture_from_raw(
+                    vk::Image::from_raw(image),
+                    &hal_desc,
+                    Some(Box::new(())),
+                );
+                device.create_texture_from_hal::<hal::api::Vulkan>(hal_texture, desc)
+            })
+            .collect())
+    }
+}
+
+/// Failure of [`Instance::create_wgpu_context`]
+#[derive(Debug)]
+pub enum WgpuInitError {
+    /// The runtime reported an error
+    Runtime(sys::Result),
+    /// Vulkan instance or device creation failed
+    Vulkan(vk::Result),
+    /// The runtime doesn't support Vulkan 1.1
+    VersionUnsupported(vulkan::Requirements),
+    /// The physical device selected by the runtime has no graphics queue
+    NoGraphicsQueue,
+    /// Vulkan couldn't be loaded, or wgpu rejected the instance or device
+    Wgpu(String),
+}
+
+impl fmt::Display for WgpuInitError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Runtime(e) => e.fmt(fmt),
+            Self::Vulkan(e) => e.fmt(fmt),
+            Self::VersionUnsupported(requirements) => write!(
+                fmt,
+                "the runtime requires Vulkan {} to {}, but wgpu targets 1.1",
+                requirements.min_api_version_supported, requirements.max_api_version_supported
+            ),
+            Self::NoGraphicsQueue => fmt.write_str("the Vulkan device has no graphics queue"),
+            Self::Wgpu(e) => e.fmt(fmt),
+        }
+    }
+}
+
+impl std::error::Error for WgpuInitError {}
+
+impl From<sys::Result> for WgpuInitError {
+    fn from(e: sys::Result) -> Self {
+        Self::Runtime(e)
+    }
+}
+
+fn extension_names(extensions: &[&std::ffi::CStr]) -> Vec<*const c_char> {
+    extensions.iter().map(|x| x.as_ptr()).collect()
+}
+
+/// The states wgpu may transition a texture with `usage` into
+fn hal_texture_uses(format: wgpu::TextureFormat, usage: wgpu::TextureUsages) -> hal::TextureUses {
+    let mut out = hal::TextureUses::empty();
+    if usage.contains(wgpu::TextureUsages::COPY_SRC) {
+        out |= hal::TextureUses::COPY_SRC;
+    }
+    if usage.contains(wgpu::TextureUsages::COPY_DST) {
+        out |= hal::TextureUses::COPY_DST;
+    }
+    if usage.contains(wgpu::TextureUsages::TEXTURE_BINDING) {
+        out |= hal::TextureUses::RESOURCE;
+    }
+    if usage.contains(wgpu::TextureUsages::STORAGE_BINDING) {
+        out |= hal::TextureUses::STORAGE_READ | hal::TextureUses::STORAGE_READ_WRITE;
+    }
+    if usage.contains(wgpu::TextureUsages::RENDER_ATTACHMENT) {
+        out |= if format.is_depth_stencil_format() {
+            hal::TextureUses::DEPTH_STENCIL_READ | hal::TextureUses::DEPTH_STENCIL_WRITE
+        } else {
+            hal::TextureUses::COLOR_TARGET
+        };
+    }
+    out
+}
@@ -0,0 +1,173 @@
+use crate::*;
+
+/// A high-level wrapper provided by this crate, and the extension it's built on
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WrapperModule {
+    /// Name of the module implementing the wrapper
+    pub module: &'static str,
+    /// Null-terminated name of the required extension
+    pub extension: &'static [u8],
+    /// Spec version of the extension the wrapper was written against
+    pub version: u32,
+}
+
+impl WrapperModule {
+    /// Name of the required extension, without the trailing null
+    #[inline]
+    pub fn extension_name(&self) -> &'static str {
+        std::str::from_utf8(&self.extension[..self.extension.len() - 1]).unwrap()
+    }
+}
+
+/// Every wrapper module built on a single extension, with the extension it requires
+///
+/// Modules serving any of several extensions, such as `eye_openness`, aren't listed. Names and
+/// versions come from the `openxr-sys` constants, so they track the bindings the crate was built
+/// with.
+pub const WRAPPER_MODULES: &[WrapperModule] = &[
+    WrapperModule {
+        module: "binding_modification",
+        extension: raw::BindingModificationKHR::NAME,
+        version: raw::BindingModificationKHR::VERSION,
+    },
+    WrapperModule {
+        module: "hand_tracker",
+        extension: raw::HandTrackingEXT::NAME,
+        version: raw::HandTrackingEXT::VERSION,
+    },
+    WrapperModule {
+        module: "secondary_view",
+        extension: raw::SecondaryViewConfigurationMSFT::NAME,
+        version: raw::SecondaryViewConfigurationMSFT::VERSION,
+    },
+    WrapperModule {
+        module: "foveation_fb",
+        extension: raw::FoveationFB::NAME,
+        version: raw::FoveationFB::VERSION,
+    },
+    WrapperModule {
+        module: "vive_tracker_paths",
+        extension: sys::HTCX_VIVE_TRACKER_INTERACTION_EXTENSION_NAME,
+        version: sys::HTCX_vive_tracker_interaction_SPEC_VERSION,
+    },
+    WrapperModule {
+        module: "tracker",
+        extension: sys::HTCX_VIVE_TRACKER_INTERACTION_EXTENSION_NAME,
+        version: sys::HTCX_vive_tracker_interaction_SPEC_VERSION,
+    },
+    WrapperModule {
+        module: "display_refresh_rate",
+        extension: raw::DisplayRefreshRateFB::NAME,
+        version: raw::DisplayRefreshRateFB::VERSION,
+    },
+    WrapperModule {
+        module: "passthrough",
+        extension: raw::PassthroughFB::NAME,
+        version: raw::PassthroughFB::VERSION,
+    },
+    WrapperModule {
+        module: "eye_tracking_social",
+        extension: raw::EyeTrackingSocialFB::NAME,
+        version: raw::EyeTrackingSocialFB::VERSION,
+    },
+    WrapperModule {
+        module: "face_tracking_fb",
+        extension: raw::FaceTracking2FB::NAME,
+        version: raw::FaceTracking2FB::VERSION,
+    },
+    WrapperModule {
+        module: "htc_facial_tracking",
+        extension: raw::FacialTrackingHTC::NAME,
+        version: raw::FacialTrackingHTC::VERSION,
+    },
+    WrapperModule {
+        module: "body_tracking_full_body_meta",
+        extension: raw::BodyTrackingFullBodyMETA::NAME,
+        version: raw::BodyTrackingFullBodyMETA::VERSION,
+    },
+    WrapperModule {
+        module: "controller_model_msft",
+        extension: raw::ControllerModelMSFT::NAME,
+        version: raw::ControllerModelMSFT::VERSION,
+    },
+    WrapperModule {
+        module: "scene_understanding_msft",
+        extension: sys::MSFT_SCENE_UNDERSTANDING_EXTENSION_NAME,
+        version: sys::MSFT_scene_understanding_SPEC_VERSION,
+    },
+    WrapperModule {
+        module: "localization_map_ml",
+        extension: sys::ML_LOCALIZATION_MAP_EXTENSION_NAME,
+        version: sys::ML_localization_map_SPEC_VERSION,
+    },
+    WrapperModule {
+        module: "future_ext",
+        extension: sys::EXT_FUTURE_EXTENSION_NAME,
+        version: sys::EXT_future_SPEC_VERSION,
+    },
+    WrapperModule {
+        module: "spatial_anchors_ml",
+        extension: sys::ML_SPATIAL_ANCHORS_EXTENSION_NAME,
+        version: sys::ML_spatial_anchors_SPEC_VERSION,
+    },
+    WrapperModule {
+        module: "haptic_pcm_fb",
+        extension: raw::HapticPcmFB::NAME,
+        version: raw::HapticPcmFB::VERSION,
+    },
+    WrapperModule {
+        module: "quad_views_varjo",
+        extension: raw::QuadViewsVARJO::NAME,
+        version: raw::QuadViewsVARJO::VERSION,
+    },
+    WrapperModule {
+        module: "foveated_rendering_varjo",
+        extension: raw::FoveatedRenderingVARJO::NAME,
+        version: raw::FoveatedRenderingVARJO::VERSION,
+    },
+    WrapperModule {
+        module: "spatial_entity_sharing_fb",
+        extension: raw::SpatialEntitySharingFB::NAME,
+        version: raw::SpatialEntitySharingFB::VERSION,
+    },
+];
+
+/// Whether the runtime can support a wrapper module
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WrapperModuleSupport {
+    pub module: &'static WrapperModule,
+    /// Version of the required extension advertised by the runtime, if any
+    pub runtime_version: Option<u32>,
+}
+
+impl WrapperModuleSupport {
+    /// Whether the runtime advertises the required extension at all
+    #[inline]
+    pub fn is_available(&self) -> bool {
+        self.runtime_version.is_some()
+    }
+
+    /// Whether the runtime's extension is at least as new as the one the wrapper was written
+    /// against
+    #[inline]
+    pub fn is_up_to_date(&self) -> bool {
+        matches!(self.runtime_version, Some(x) if x >= self.module.version)
+    }
+}
+
+impl Entry {
+    /// Determine which of [`WRAPPER_MODULES`] the runtime can support
+    pub fn wrapper_module_support(&self) -> Result<Vec<WrapperModuleSupport>> {
+        let props = self.enumerate_extension_properties()?;
+        Ok(WRAPPER_MODULES
+            .iter()
+            .map(|module| WrapperModuleSupport {
+                module,
+                runtime_version: props
+                    .iter()
+                    .find(|x| fixed_str_bytes(&x.extension_name) == module.extension)
+                    .map(|x| x.extension_version),
+            })
+            .collect())
+    }
+}
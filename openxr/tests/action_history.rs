@@ -0,0 +1,85 @@
+//! `Session::sync_actions` records into every live `ActionHistory`.
+#![cfg(feature = "null-runtime")]
+
+mod common;
+
+use openxr as xr;
+use xr::sys::pfn;
+
+fn create_session(entry: &xr::Entry) -> (xr::Instance, xr::Session<xr::Headless>) {
+    let instance = common::create_instance(entry, "action_history");
+    let session = common::create_session(&instance);
+    (instance, session)
+}
+
+fn create_action(
+    instance: &xr::Instance,
+    session: &xr::Session<xr::Headless>,
+) -> (xr::ActionSet, xr::Action<bool>) {
+    let set = instance.create_action_set("input", "Input", 0).unwrap();
+    let action = set.create_action::<bool>("select", "Select", &[]).unwrap();
+    session.attach_action_sets(&[&set]).unwrap();
+    (set, action)
+}
+
+#[test]
+fn keeps_latest_states() {
+    let (instance, session) = create_session(&xr::Entry::null());
+    let (set, action) = create_action(&instance, &session);
+    let history = xr::ActionHistory::new(&session, &action, xr::Path::NULL, 2);
+    assert!(history.states().is_empty());
+
+    for _ in 0..3 {
+        session
+            .sync_actions(&[xr::ActiveActionSet::new(&set)])
+            .unwrap();
+    }
+    assert_eq!(history.states().len(), 2);
+    assert_eq!(history.capacity(), 2);
+    assert!(!history.latest().unwrap().current_state);
+
+    history.clear();
+    assert!(history.latest().is_none());
+}
+
+#[test]
+fn stops_recording_once_dropped() {
+    let (instance, session) = create_session(&xr::Entry::null());
+    let (set, action) = create_action(&instance, &session);
+    let history = xr::ActionHistory::new(&session, &action, xr::Path::NULL, 4);
+    let clone = history.clone();
+    drop(history);
+    session
+        .sync_actions(&[xr::ActiveActionSet::new(&set)])
+        .unwrap();
+    assert_eq!(clone.states().len(), 1);
+    drop(clone);
+    session
+        .sync_actions(&[xr::ActiveActionSet::new(&set)])
+        .unwrap();
+}
+
+/// Fails, as though the action's state couldn't be queried
+unsafe extern "system" fn get_action_state_boolean(
+    _session: xr::sys::Session,
+    _info: *const xr::sys::ActionStateGetInfo,
+    _state: *mut xr::sys::ActionStateBoolean,
+) -> xr::sys::Result {
+    xr::sys::Result::ERROR_RUNTIME_FAILURE
+}
+
+#[test]
+fn failed_recording_doesnt_fail_sync() {
+    let entry = common::entry_with(vec![common::replace(
+        "xrGetActionStateBoolean",
+        get_action_state_boolean as pfn::GetActionStateBoolean,
+    )]);
+    let (instance, session) = create_session(&entry);
+    let (set, action) = create_action(&instance, &session);
+    let history = xr::ActionHistory::new(&session, &action, xr::Path::NULL, 4);
+    assert_eq!(
+        session.sync_actions(&[xr::ActiveActionSet::new(&set)]),
+        Ok(xr::sys::Result::SESSION_NOT_FOCUSED)
+    );
+    assert!(history.states().is_empty());
+}
@@ -0,0 +1,38 @@
+//! `BindingsBuilder` checks bindings against the known interaction profiles as they're added.
+#![cfg(feature = "null-runtime")]
+
+mod common;
+
+use openxr as xr;
+use xr::interaction_profiles::{OCULUS_TOUCH, VALVE_INDEX_CONTROLLER};
+
+#[test]
+fn binds_vectors_to_identifier_paths() {
+    let instance = common::create_instance(&xr::Entry::null(), "bindings_builder");
+    let set = instance.create_action_set("input", "Input", 0).unwrap();
+    let movement = set
+        .create_action::<xr::Vector2f>("move", "Move", &[])
+        .unwrap();
+    xr::BindingsBuilder::new()
+        .profile(&OCULUS_TOUCH)
+        .bind(&movement, "/user/hand/left/input/thumbstick")
+        .profile(&VALVE_INDEX_CONTROLLER)
+        .bind(&movement, "/user/hand/left/input/thumbstick")
+        .bind(&movement, "/user/hand/right/input/trackpad")
+        .submit(&instance)
+        .unwrap();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "has no binding /user/hand/left/input/trackpad")]
+fn rejects_missing_components() {
+    let instance = common::create_instance(&xr::Entry::null(), "bindings_builder");
+    let set = instance.create_action_set("input", "Input", 0).unwrap();
+    let movement = set
+        .create_action::<xr::Vector2f>("move", "Move", &[])
+        .unwrap();
+    xr::BindingsBuilder::new()
+        .profile(&OCULUS_TOUCH)
+        .bind(&movement, "/user/hand/left/input/trackpad");
+}
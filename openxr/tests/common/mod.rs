@@ -0,0 +1,103 @@
+//! Fixtures for tests running against the null runtime, which needs the `null-runtime` feature.
+#![allow(dead_code)]
+
+use std::{
+    ffi::{CStr, CString},
+    mem,
+    os::raw::c_char,
+    sync::OnceLock,
+};
+
+use openxr as xr;
+
+/// Create an instance with `XR_MND_headless` enabled
+pub fn create_instance(entry: &xr::Entry, name: &str) -> xr::Instance {
+    let mut extensions = xr::ExtensionSet::default();
+    extensions.mnd_headless = true;
+    entry
+        .create_instance(
+            &xr::ApplicationInfo {
+                application_name: name,
+                ..Default::default()
+            },
+            &extensions,
+            &[],
+        )
+        .unwrap()
+}
+
+/// Create a headless session for `instance`'s head-mounted display
+pub fn create_session(instance: &xr::Instance) -> xr::Session<xr::Headless> {
+    let system = instance
+        .system(xr::FormFactor::HEAD_MOUNTED_DISPLAY)
+        .unwrap();
+    let (session, _, _) = unsafe {
+        instance
+            .create_session::<xr::Headless>(system, &())
+            .unwrap()
+    };
+    session
+}
+
+/// A function to load in place of the null runtime's function of the same name
+pub type Override = (&'static str, xr::sys::pfn::VoidFunction);
+
+/// `f` as an override for the function `name`, where `F` is its function pointer type
+pub fn replace<F: Copy>(name: &'static str, f: F) -> Override {
+    assert_eq!(
+        mem::size_of::<F>(),
+        mem::size_of::<xr::sys::pfn::VoidFunction>(),
+        "{} must be given as a function pointer",
+        name
+    );
+    (name, unsafe { mem::transmute_copy(&f) })
+}
+
+static OVERRIDES: OnceLock<Vec<Override>> = OnceLock::new();
+
+/// The null runtime, with `overrides` loaded in place of its own functions
+///
+/// Overrides are installed once per test binary, so every call must pass the same ones.
+pub fn entry_with(overrides: Vec<Override>) -> xr::Entry {
+    let installed = OVERRIDES.get_or_init(|| overrides.clone());
+    assert!(
+        installed
+            .iter()
+            .map(|&(name, f)| (name, f as usize))
+            .eq(overrides.iter().map(|&(name, f)| (name, f as usize))),
+        "a test binary must always use the same overrides"
+    );
+    unsafe { xr::Entry::from_get_instance_proc_addr(get_instance_proc_addr) }.unwrap()
+}
+
+/// The null runtime's own implementation of `name`, for overrides that forward to it
+pub fn null_function(name: &str) -> xr::sys::pfn::VoidFunction {
+    let name = CString::new(name).unwrap();
+    let mut function = None;
+    let result = unsafe {
+        (xr::Entry::null().fp().get_instance_proc_addr)(
+            xr::sys::Instance::NULL,
+            name.as_ptr(),
+            &mut function,
+        )
+    };
+    assert_eq!(result, xr::sys::Result::SUCCESS);
+    function.unwrap()
+}
+
+/// Forwards to the null runtime, except for the installed overrides
+unsafe extern "system" fn get_instance_proc_addr(
+    instance: xr::sys::Instance,
+    name: *const c_char,
+    function: *mut Option<xr::sys::pfn::VoidFunction>,
+) -> xr::sys::Result {
+    let requested = CStr::from_ptr(name).to_bytes();
+    let replacement = OVERRIDES
+        .get()
+        .and_then(|x| x.iter().find(|&&(name, _)| name.as_bytes() == requested));
+    if let Some(&(_, f)) = replacement {
+        *function = Some(f);
+        return xr::sys::Result::SUCCESS;
+    }
+    (xr::Entry::null().fp().get_instance_proc_addr)(instance, name, function)
+}
@@ -0,0 +1,41 @@
+//! Spec versions of enabled extensions, as reported through `InstanceExtensions::versions`.
+#![cfg(feature = "null-runtime")]
+
+mod common;
+
+use std::os::raw::c_char;
+
+use openxr as xr;
+use xr::sys::pfn;
+
+#[test]
+fn enabled_extensions_report_versions() {
+    let instance = common::create_instance(&xr::Entry::null(), "extension_versions");
+    let versions = instance.exts().versions;
+    assert_eq!(
+        versions.mnd_headless,
+        Some(xr::sys::MND_headless_SPEC_VERSION)
+    );
+    assert_eq!(versions.khr_composition_layer_depth, None);
+}
+
+/// Fails, as though the runtime's extensions couldn't be enumerated
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    _layer_name: *const c_char,
+    _capacity: u32,
+    _count: *mut u32,
+    _properties: *mut xr::sys::ExtensionProperties,
+) -> xr::sys::Result {
+    xr::sys::Result::ERROR_RUNTIME_FAILURE
+}
+
+#[test]
+fn unknown_versions_dont_fail_instance_creation() {
+    let entry = common::entry_with(vec![common::replace(
+        "xrEnumerateInstanceExtensionProperties",
+        enumerate_instance_extension_properties as pfn::EnumerateInstanceExtensionProperties,
+    )]);
+    let instance = common::create_instance(&entry, "extension_versions");
+    assert_eq!(instance.exts().versions, xr::ExtensionVersions::default());
+    assert!(instance.exts().mnd_headless.is_some());
+}
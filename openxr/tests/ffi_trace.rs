@@ -0,0 +1,49 @@
+//! Tracing wraps function pointers in process-wide wrappers, so a second `Entry` must not redirect
+//! calls made through the first.
+//!
+//! Also needs the `ffi-trace` feature.
+#![cfg(all(feature = "null-runtime", feature = "ffi-trace"))]
+
+mod common;
+
+use std::{
+    mem,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use openxr as xr;
+use xr::sys::pfn;
+
+/// Number of calls to `counting_create_instance`
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe extern "system" fn counting_create_instance(
+    create_info: *const xr::sys::InstanceCreateInfo,
+    instance: *mut xr::sys::Instance,
+) -> xr::sys::Result {
+    CALLS.fetch_add(1, Ordering::Relaxed);
+    let real = mem::transmute::<pfn::VoidFunction, pfn::CreateInstance>(common::null_function(
+        "xrCreateInstance",
+    ));
+    real(create_info, instance)
+}
+
+#[test]
+fn second_entry_keeps_its_own_functions() {
+    let first = xr::Entry::null();
+    let second = common::entry_with(vec![common::replace(
+        "xrCreateInstance",
+        counting_create_instance as pfn::CreateInstance,
+    )]);
+
+    let calls = CALLS.load(Ordering::Relaxed);
+    let _first_instance = common::create_instance(&first, "ffi_trace");
+    assert_eq!(CALLS.load(Ordering::Relaxed), calls);
+
+    let _second_instance = common::create_instance(&second, "ffi_trace");
+    assert_eq!(CALLS.load(Ordering::Relaxed), calls + 1);
+
+    // The first entry's wrappers still reach the null runtime
+    let _another_first_instance = common::create_instance(&first, "ffi_trace");
+    assert_eq!(CALLS.load(Ordering::Relaxed), calls + 1);
+}
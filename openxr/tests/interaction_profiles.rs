@@ -0,0 +1,43 @@
+//! Component tables of the known interaction profiles.
+
+use openxr::interaction_profiles::{
+    ALL, LEFT_HAND, META_TOUCH_CONTROLLER_PLUS, OCULUS_TOUCH, RIGHT_HAND, VALVE_INDEX_CONTROLLER,
+};
+
+#[test]
+fn identifier_paths() {
+    // `Vector2f` actions bind to the thumbstick or trackpad as a whole
+    assert!(OCULUS_TOUCH.supports(LEFT_HAND, "/input/thumbstick"));
+    assert!(OCULUS_TOUCH.supports_binding("/user/hand/right/input/thumbstick"));
+    assert!(VALVE_INDEX_CONTROLLER.supports_binding("/user/hand/left/input/trackpad"));
+    assert!(OCULUS_TOUCH.supports(LEFT_HAND, "/input/x"));
+    assert!(!OCULUS_TOUCH.supports(RIGHT_HAND, "/input/x"));
+    assert!(!OCULUS_TOUCH.supports(LEFT_HAND, "/input/trackpad"));
+    // Only whole identifiers, not arbitrary prefixes
+    assert!(!OCULUS_TOUCH.supports(LEFT_HAND, "/input"));
+    assert!(!OCULUS_TOUCH.supports(LEFT_HAND, "/input/thumb"));
+    assert!(!OCULUS_TOUCH.supports(LEFT_HAND, "/output"));
+}
+
+#[test]
+fn listed_bindings_are_supported() {
+    for profile in ALL {
+        for binding in profile.bindings() {
+            assert!(
+                profile.supports_binding(&binding),
+                "{} rejects its own {}",
+                profile.path,
+                binding
+            );
+        }
+    }
+}
+
+#[test]
+fn paths_are_unique() {
+    let mut paths = ALL.iter().map(|x| x.path).collect::<Vec<_>>();
+    paths.sort_unstable();
+    paths.dedup();
+    assert_eq!(paths.len(), ALL.len());
+    assert!(ALL.contains(&META_TOUCH_CONTROLLER_PLUS));
+}
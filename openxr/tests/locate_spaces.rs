@@ -0,0 +1,120 @@
+//! `Session::locate_spaces` uses `xrLocateSpacesKHR`, or the OpenXR 1.1 `xrLocateSpaces` where
+//! the runtime only exposes that.
+#![cfg(feature = "null-runtime")]
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use openxr as xr;
+
+fn create_session(entry: &xr::Entry) -> (xr::Instance, xr::Session<xr::Headless>) {
+    let instance = common::create_instance(entry, "locate_spaces");
+    let session = common::create_session(&instance);
+    (instance, session)
+}
+
+fn create_space(session: &xr::Session<xr::Headless>) -> xr::Space {
+    session
+        .create_reference_space(xr::ReferenceSpaceType::LOCAL, xr::Posef::IDENTITY)
+        .unwrap()
+}
+
+#[test]
+fn unsupported_without_either_function() {
+    let (_instance, session) = create_session(&xr::Entry::null());
+    let (space, base) = (create_space(&session), create_space(&session));
+    assert_eq!(
+        session
+            .locate_spaces(&[&space], &base, xr::Time::from_nanos(1))
+            .err(),
+        Some(xr::sys::Result::ERROR_FUNCTION_UNSUPPORTED)
+    );
+}
+
+/// Number of calls to `locate_spaces`
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// The OpenXR 1.1 `xrLocateSpaces`, which shares `xrLocateSpacesKHR`'s signature
+type LocateSpaces = unsafe extern "system" fn(
+    xr::sys::Session,
+    *const xr::sys::SpacesLocateInfoKHR,
+    *mut xr::sys::SpaceLocationsKHR,
+) -> xr::sys::Result;
+
+/// Reports space `i` at `x = i` with only its position valid, leaving a garbage orientation, and
+/// moving at `x = i` meters per second if velocities are requested
+unsafe extern "system" fn locate_spaces(
+    _session: xr::sys::Session,
+    info: *const xr::sys::SpacesLocateInfoKHR,
+    locations: *mut xr::sys::SpaceLocationsKHR,
+) -> xr::sys::Result {
+    CALLS.fetch_add(1, Ordering::Relaxed);
+    let count = (*info).space_count as usize;
+    let locations = &mut *locations;
+    assert_eq!(locations.location_count as usize, count);
+    for i in 0..count {
+        *locations.locations.add(i) = xr::sys::SpaceLocationDataKHR {
+            location_flags: xr::SpaceLocationFlags::POSITION_VALID,
+            pose: xr::Posef {
+                orientation: xr::Quaternionf {
+                    x: f32::NAN,
+                    y: f32::NAN,
+                    z: f32::NAN,
+                    w: f32::NAN,
+                },
+                position: xr::Vector3f {
+                    x: i as f32,
+                    y: 0.0,
+                    z: 0.0,
+                },
+            },
+        };
+    }
+    let velocities = locations.next as *mut xr::sys::SpaceVelocitiesKHR;
+    if !velocities.is_null() {
+        assert_eq!((*velocities).ty, xr::sys::SpaceVelocitiesKHR::TYPE);
+        for i in 0..count {
+            *(*velocities).velocities.add(i) = xr::sys::SpaceVelocityDataKHR {
+                velocity_flags: xr::SpaceVelocityFlags::LINEAR_VALID,
+                linear_velocity: xr::Vector3f {
+                    x: i as f32,
+                    y: 0.0,
+                    z: 0.0,
+                },
+                angular_velocity: xr::Vector3f::default(),
+            };
+        }
+    }
+    xr::sys::Result::SUCCESS
+}
+
+#[test]
+fn falls_back_to_core_function() {
+    let entry = common::entry_with(vec![common::replace(
+        "xrLocateSpaces",
+        locate_spaces as LocateSpaces,
+    )]);
+    let (_instance, session) = create_session(&entry);
+    let spaces = [create_space(&session), create_space(&session)];
+    let spaces = spaces.iter().collect::<Vec<_>>();
+    let base = create_space(&session);
+    let time = xr::Time::from_nanos(1);
+
+    let calls = CALLS.load(Ordering::Relaxed);
+    let locations = session.locate_spaces(&spaces, &base, time).unwrap();
+    assert_eq!(CALLS.load(Ordering::Relaxed), calls + 1);
+    assert_eq!(locations.len(), 2);
+    for (i, location) in locations.iter().enumerate() {
+        assert_eq!(location.pose.position.x, i as f32);
+        // Invalid parts are replaced rather than passed through
+        assert_eq!(location.pose.orientation, xr::Quaternionf::default());
+    }
+
+    let related = session.relate_spaces(&spaces, &base, time).unwrap();
+    assert_eq!(CALLS.load(Ordering::Relaxed), calls + 2);
+    for (i, (location, velocity)) in related.iter().enumerate() {
+        assert_eq!(location.pose.position.x, i as f32);
+        assert_eq!(velocity.linear_velocity.x, i as f32);
+    }
+}
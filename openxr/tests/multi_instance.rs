@@ -0,0 +1,87 @@
+//! Instances created side by side in one process, as when an application and an embedded overlay
+//! library each use OpenXR, must not share state.
+#![cfg(feature = "null-runtime")]
+
+mod common;
+
+use std::thread;
+
+use openxr as xr;
+
+fn create_instance(name: &str) -> xr::Instance {
+    common::create_instance(&xr::Entry::null(), name)
+}
+
+/// Drain `instance`'s events, returning the session states reported
+fn session_states(instance: &xr::Instance) -> Vec<(xr::sys::Session, xr::SessionState)> {
+    let mut storage = xr::EventDataBuffer::new();
+    let mut out = Vec::new();
+    while let Some(event) = instance.poll_event(&mut storage).unwrap() {
+        if let xr::Event::SessionStateChanged(e) = event {
+            out.push((e.session(), e.state()));
+        }
+    }
+    out
+}
+
+#[test]
+fn events_are_per_instance() {
+    let app = create_instance("app");
+    let overlay = create_instance("overlay");
+    let app_session = common::create_session(&app);
+    let overlay_session = common::create_session(&overlay);
+
+    let expected = |session: &xr::Session<xr::Headless>| {
+        vec![
+            (session.as_raw(), xr::SessionState::IDLE),
+            (session.as_raw(), xr::SessionState::READY),
+        ]
+    };
+    assert_eq!(session_states(&app), expected(&app_session));
+    assert_eq!(session_states(&overlay), expected(&overlay_session));
+}
+
+#[test]
+fn destroying_one_instance_leaves_the_other_usable() {
+    let app = create_instance("app");
+    let overlay = create_instance("overlay");
+    let app_session = common::create_session(&app);
+    session_states(&app);
+
+    drop(common::create_session(&overlay));
+    drop(overlay);
+
+    app_session
+        .begin(xr::ViewConfigurationType::PRIMARY_STEREO)
+        .unwrap();
+    assert_eq!(
+        session_states(&app),
+        vec![
+            (app_session.as_raw(), xr::SessionState::SYNCHRONIZED),
+            (app_session.as_raw(), xr::SessionState::VISIBLE),
+            (app_session.as_raw(), xr::SessionState::FOCUSED),
+        ]
+    );
+    let path = app.string_to_path("/user/hand/left").unwrap();
+    assert_eq!(app.path_to_string(path).unwrap(), "/user/hand/left");
+}
+
+#[test]
+fn parallel_creation_and_destruction() {
+    let threads = (0..8)
+        .map(|i| {
+            thread::spawn(move || {
+                for _ in 0..16 {
+                    let instance = create_instance(&format!("instance {}", i));
+                    let session = common::create_session(&instance);
+                    let states = session_states(&instance);
+                    assert_eq!(states.len(), 2);
+                    assert!(states.iter().all(|&(s, _)| s == session.as_raw()));
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+}
@@ -0,0 +1,417 @@
+//! Smoke test of this crate against the installed OpenXR runtime, for checking that a runtime
+//! and crate version work together.
+//!
+//! Run with `cargo test --features smoke-test --test smoke`, adding `null-runtime` to check the
+//! built-in null runtime instead. Prints a JSON report to stdout and exits with a failure status
+//! if any check failed. Sessions are headless, so checks beyond instance creation require a
+//! runtime supporting XR_MND_headless.
+
+use std::{
+    fmt::Write as _,
+    process,
+    time::{Duration, Instant},
+};
+
+use openxr as xr;
+
+/// How long to wait for each session state change
+const STATE_TIMEOUT: Duration = Duration::from_secs(10);
+const FRAMES: u32 = 30;
+const VIEW_TYPE: xr::ViewConfigurationType = xr::ViewConfigurationType::PRIMARY_STEREO;
+
+fn main() {
+    let mut report = Report::default();
+    run(&mut report);
+    println!("{}", report.to_json());
+    if report
+        .checks
+        .iter()
+        .any(|x| matches!(x.outcome, Outcome::Fail(_)))
+    {
+        process::exit(1);
+    }
+}
+
+fn run(report: &mut Report) {
+    let entry = match report.check("entry", load_entry) {
+        Some(x) => x,
+        None => return,
+    };
+    let extensions = match report.check("extensions", |detail| {
+        let extensions = call(
+            entry.enumerate_extensions(),
+            "xrEnumerateInstanceExtensionProperties",
+        )?;
+        write!(detail, "XR_MND_headless: {}", extensions.mnd_headless).unwrap();
+        Ok(extensions)
+    }) {
+        Some(x) => x,
+        None => return,
+    };
+
+    let instance = match report.check("instance", |detail| {
+        let mut enabled = xr::ExtensionSet::default();
+        enabled.mnd_headless = extensions.mnd_headless;
+        let instance = call(
+            entry.create_instance(
+                &xr::ApplicationInfo {
+                    application_name: "openxrs smoke test",
+                    ..Default::default()
+                },
+                &enabled,
+                &[],
+            ),
+            "xrCreateInstance",
+        )?;
+        let props = call(instance.properties(), "xrGetInstanceProperties")?;
+        report_runtime(detail, &props);
+        Ok(instance)
+    }) {
+        Some(x) => x,
+        None => return,
+    };
+
+    let system = match report.check("system", |detail| {
+        let system = call(
+            instance.system(xr::FormFactor::HEAD_MOUNTED_DISPLAY),
+            "xrGetSystem",
+        )?;
+        let props = call(instance.system_properties(system), "xrGetSystemProperties")?;
+        let views = call(
+            instance.enumerate_view_configuration_views(system, VIEW_TYPE),
+            "xrEnumerateViewConfigurationViews",
+        )?;
+        write!(
+            detail,
+            "{} (vendor {:#x}), {} views",
+            props.system_name,
+            props.vendor_id,
+            views.len()
+        )
+        .unwrap();
+        Ok(system)
+    }) {
+        Some(x) => x,
+        None => return,
+    };
+
+    if !extensions.mnd_headless {
+        for name in ["session", "actions", "lifecycle", "swapchain", "exit"] {
+            report.skip(name, "XR_MND_headless is not supported");
+        }
+        return;
+    }
+    let (session, mut frame_waiter, mut frame_stream) = match report.check("session", |_| {
+        call(
+            unsafe { instance.create_session::<xr::Headless>(system, &()) },
+            "xrCreateSession",
+        )
+    }) {
+        Some(x) => x,
+        None => return,
+    };
+
+    let actions = report.check("actions", |detail| {
+        let set = call(
+            instance.create_action_set("smoke", "Smoke test", 0),
+            "xrCreateActionSet",
+        )?;
+        let pose = call(
+            set.create_action::<xr::Posef>("pose", "Pose", &[]),
+            "xrCreateAction",
+        )?;
+        let path = |s| call(instance.string_to_path(s), "xrStringToPath");
+        call(
+            instance.suggest_interaction_profile_bindings(
+                path("/interaction_profiles/khr/simple_controller")?,
+                &[xr::Binding::new(
+                    &pose,
+                    path("/user/hand/right/input/grip/pose")?,
+                )],
+            ),
+            "xrSuggestInteractionProfileBindings",
+        )?;
+        call(
+            session.attach_action_sets(&[&set]),
+            "xrAttachSessionActionSets",
+        )?;
+        let space = call(
+            pose.create_space(session.clone(), xr::Path::NULL, xr::Posef::IDENTITY),
+            "xrCreateActionSpace",
+        )?;
+        detail.push_str("bound /user/hand/right/input/grip/pose");
+        Ok((set, space))
+    });
+
+    let mut events = xr::EventDataBuffer::new();
+    let running = report.check("lifecycle", |detail| {
+        wait_for_state(&instance, &session, &mut events, xr::SessionState::READY)?;
+        call(session.begin(VIEW_TYPE), "xrBeginSession")?;
+        let view = call(
+            session.create_reference_space(xr::ReferenceSpaceType::VIEW, xr::Posef::IDENTITY),
+            "xrCreateReferenceSpace",
+        )?;
+        let local = call(
+            session.create_reference_space(xr::ReferenceSpaceType::LOCAL, xr::Posef::IDENTITY),
+            "xrCreateReferenceSpace",
+        )?;
+        let mut synced = 0;
+        for _ in 0..FRAMES {
+            poll(&instance, &mut events)?;
+            let state = call(frame_waiter.wait(), "xrWaitFrame")?;
+            call(frame_stream.begin(), "xrBeginFrame")?;
+            if let Some((ref set, ref space)) = actions {
+                if call(session.sync_actions(&[set.into()]), "xrSyncActions")?
+                    == xr::sys::Result::SUCCESS
+                {
+                    synced += 1;
+                }
+                call(
+                    space.locate(&local, state.predicted_display_time),
+                    "xrLocateSpace",
+                )?;
+            }
+            call(
+                view.locate(&local, state.predicted_display_time),
+                "xrLocateSpace",
+            )?;
+            call(
+                frame_stream.end(
+                    state.predicted_display_time,
+                    xr::EnvironmentBlendMode::OPAQUE,
+                    &[],
+                ),
+                "xrEndFrame",
+            )?;
+        }
+        write!(detail, "{} frames, actions synced in {}", FRAMES, synced).unwrap();
+        Ok(())
+    });
+    if running.is_none() {
+        for name in ["swapchain", "exit"] {
+            report.skip(name, "requires lifecycle");
+        }
+        return;
+    }
+
+    let formats = call(
+        session.enumerate_swapchain_formats(),
+        "xrEnumerateSwapchainFormats",
+    );
+    if matches!(formats, Ok(ref x) if x.is_empty()) {
+        report.skip("swapchain", "no formats are offered for headless sessions");
+    } else {
+        report.check("swapchain", |detail| {
+            let format = formats?[0];
+            let mut swapchain = call(
+                session.create_swapchain(&xr::SwapchainCreateInfo {
+                    create_flags: xr::SwapchainCreateFlags::EMPTY,
+                    usage_flags: xr::SwapchainUsageFlags::COLOR_ATTACHMENT,
+                    format,
+                    sample_count: 1,
+                    width: 64,
+                    height: 64,
+                    face_count: 1,
+                    array_size: 1,
+                    mip_count: 1,
+                }),
+                "xrCreateSwapchain",
+            )?;
+            let images = call(swapchain.enumerate_images(), "xrEnumerateSwapchainImages")?;
+            for _ in 0..images.len() {
+                call(swapchain.acquire_image(), "xrAcquireSwapchainImage")?;
+                swapchain
+                    .wait_image_with_policy(&xr::SwapchainWaitPolicy::default())
+                    .map_err(|e| format!("xrWaitSwapchainImage failed: {}", e))?;
+                call(swapchain.release_image(), "xrReleaseSwapchainImage")?;
+            }
+            write!(
+                detail,
+                "cycled {} images of format {}",
+                images.len(),
+                format
+            )
+            .unwrap();
+            Ok(())
+        });
+    }
+
+    report.check("exit", |_| {
+        call(session.request_exit(), "xrRequestExitSession")?;
+        wait_for_state(&instance, &session, &mut events, xr::SessionState::STOPPING)?;
+        call(session.end(), "xrEndSession")?;
+        wait_for_state(&instance, &session, &mut events, xr::SessionState::EXITING)
+    });
+}
+
+fn load_entry(detail: &mut String) -> Result<xr::Entry, String> {
+    #[cfg(feature = "null-runtime")]
+    {
+        detail.push_str("null runtime");
+        Ok(xr::Entry::null())
+    }
+    #[cfg(all(not(feature = "null-runtime"), feature = "linked"))]
+    {
+        detail.push_str("linked loader");
+        Ok(xr::Entry::linked())
+    }
+    #[cfg(all(not(feature = "null-runtime"), not(feature = "linked")))]
+    {
+        detail.push_str("loaded loader");
+        unsafe { xr::Entry::load() }.map_err(|e| format!("couldn't load the OpenXR loader: {}", e))
+    }
+}
+
+fn report_runtime(detail: &mut String, props: &xr::InstanceProperties) {
+    write!(detail, "{} {}", props.runtime_name, props.runtime_version).unwrap();
+}
+
+/// Pump events until `session` enters `target`, failing on loss or after `STATE_TIMEOUT`
+fn wait_for_state(
+    instance: &xr::Instance,
+    session: &xr::Session<xr::Headless>,
+    events: &mut xr::EventDataBuffer,
+    target: xr::SessionState,
+) -> Result<(), String> {
+    let deadline = Instant::now() + STATE_TIMEOUT;
+    loop {
+        while let Some(event) = call(instance.poll_event(events), "xrPollEvent")? {
+            match event {
+                xr::Event::SessionStateChanged(e) if e.session() == session.as_raw() => {
+                    if e.state() == target {
+                        return Ok(());
+                    }
+                    if e.state() == xr::SessionState::LOSS_PENDING {
+                        return Err(format!("session lost while waiting for {:?}", target));
+                    }
+                }
+                xr::Event::InstanceLossPending(_) => {
+                    return Err(format!("instance lost while waiting for {:?}", target));
+                }
+                _ => {}
+            }
+        }
+        if Instant::now() > deadline {
+            return Err(format!(
+                "session didn't enter {:?} within {:?}",
+                target, STATE_TIMEOUT
+            ));
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Drain pending events, failing if the session or instance is being lost
+fn poll(instance: &xr::Instance, events: &mut xr::EventDataBuffer) -> Result<(), String> {
+    while let Some(event) = call(instance.poll_event(events), "xrPollEvent")? {
+        match event {
+            xr::Event::SessionStateChanged(e) if e.state() == xr::SessionState::LOSS_PENDING => {
+                return Err("session lost".into());
+            }
+            xr::Event::InstanceLossPending(_) => return Err("instance lost".into()),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn call<T>(result: xr::Result<T>, function: &str) -> Result<T, String> {
+    result.map_err(|e| format!("{} failed: {}", function, e))
+}
+
+enum Outcome {
+    Pass(String),
+    Fail(String),
+    Skip(String),
+}
+
+struct Check {
+    name: &'static str,
+    outcome: Outcome,
+    elapsed: Duration,
+}
+
+#[derive(Default)]
+struct Report {
+    checks: Vec<Check>,
+}
+
+impl Report {
+    /// Run `f`, recording its outcome along with the detail it writes
+    fn check<T>(
+        &mut self,
+        name: &'static str,
+        f: impl FnOnce(&mut String) -> Result<T, String>,
+    ) -> Option<T> {
+        let start = Instant::now();
+        let mut detail = String::new();
+        let result = f(&mut detail);
+        let (outcome, value) = match result {
+            Ok(x) => (Outcome::Pass(detail), Some(x)),
+            Err(e) => (Outcome::Fail(e), None),
+        };
+        self.checks.push(Check {
+            name,
+            outcome,
+            elapsed: start.elapsed(),
+        });
+        value
+    }
+
+    fn skip(&mut self, name: &'static str, reason: &str) {
+        self.checks.push(Check {
+            name,
+            outcome: Outcome::Skip(reason.into()),
+            elapsed: Duration::ZERO,
+        });
+    }
+
+    fn to_json(&self) -> String {
+        let mut out = String::new();
+        write!(
+            out,
+            "{{\"crate_version\":{},\"checks\":[",
+            json_string(env!("CARGO_PKG_VERSION"))
+        )
+        .unwrap();
+        for (i, check) in self.checks.iter().enumerate() {
+            let (status, detail) = match check.outcome {
+                Outcome::Pass(ref x) => ("pass", x),
+                Outcome::Fail(ref x) => ("fail", x),
+                Outcome::Skip(ref x) => ("skip", x),
+            };
+            write!(
+                out,
+                "{}\n  {{\"name\":{},\"status\":\"{}\",\"millis\":{},\"detail\":{}}}",
+                if i == 0 { "" } else { "," },
+                json_string(check.name),
+                status,
+                check.elapsed.as_millis(),
+                json_string(detail)
+            )
+            .unwrap();
+        }
+        let passed = !self
+            .checks
+            .iter()
+            .any(|x| matches!(x.outcome, Outcome::Fail(_)));
+        write!(out, "\n],\"passed\":{}}}", passed).unwrap();
+        out
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,46 @@
+//! Success codes other than `SUCCESS` must reach the application rather than being collapsed.
+#![cfg(feature = "null-runtime")]
+
+mod common;
+
+use openxr as xr;
+
+#[test]
+fn frame_loop() {
+    let instance = common::create_instance(&xr::Entry::null(), "success_codes");
+    let system = instance
+        .system(xr::FormFactor::HEAD_MOUNTED_DISPLAY)
+        .unwrap();
+    let (session, mut waiter, mut stream) = unsafe {
+        instance
+            .create_session::<xr::Headless>(system, &())
+            .unwrap()
+    };
+    session
+        .begin(xr::ViewConfigurationType::PRIMARY_STEREO)
+        .unwrap();
+
+    let state = waiter.wait().unwrap();
+    assert_eq!(state.status, xr::sys::Result::SUCCESS);
+    assert_eq!(stream.begin(), Ok(xr::sys::Result::SUCCESS));
+    // Beginning again without ending the frame discards it
+    assert_eq!(stream.begin(), Ok(xr::sys::Result::FRAME_DISCARDED));
+}
+
+#[test]
+fn action_sync() {
+    let instance = common::create_instance(&xr::Entry::null(), "success_codes");
+    let session = common::create_session(&instance);
+    let set = instance.create_action_set("input", "Input", 0).unwrap();
+    session.attach_action_sets(&[&set]).unwrap();
+    let active = [xr::ActiveActionSet::new(&set)];
+
+    assert_eq!(
+        session.sync_actions(&active),
+        Ok(xr::sys::Result::SESSION_NOT_FOCUSED)
+    );
+    session
+        .begin(xr::ViewConfigurationType::PRIMARY_STEREO)
+        .unwrap();
+    assert_eq!(session.sync_actions(&active), Ok(xr::sys::Result::SUCCESS));
+}
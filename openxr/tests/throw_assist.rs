@@ -0,0 +1,108 @@
+//! `ThrowEstimator` fits release velocities to recorded hand motion.
+
+use std::time::Duration;
+
+use openxr as xr;
+
+const FRAME: i64 = 10_000_000;
+
+fn assert_close(actual: xr::Vector3f, expected: xr::Vector3f) {
+    let d = [
+        actual.x - expected.x,
+        actual.y - expected.y,
+        actual.z - expected.z,
+    ];
+    assert!(
+        d.iter().all(|x| x.abs() < 1e-3),
+        "{:?} is not close to {:?}",
+        actual,
+        expected
+    );
+}
+
+fn vector(x: f32) -> xr::Vector3f {
+    xr::Vector3f { x, y: 0.0, z: 0.0 }
+}
+
+fn location(x: f32) -> xr::SpaceLocation {
+    xr::SpaceLocation {
+        location_flags: xr::SpaceLocationFlags::POSITION_VALID,
+        pose: xr::Posef {
+            orientation: xr::Quaternionf::IDENTITY,
+            position: vector(x),
+        },
+    }
+}
+
+fn velocity(flags: xr::SpaceVelocityFlags, linear: f32, angular: f32) -> xr::SpaceVelocity {
+    xr::SpaceVelocity {
+        velocity_flags: flags,
+        linear_velocity: vector(linear),
+        angular_velocity: vector(angular),
+    }
+}
+
+#[test]
+fn needs_two_samples() {
+    let mut estimator = xr::ThrowEstimator::default();
+    assert_eq!(estimator.release_velocity(), None);
+    estimator.record(
+        xr::Time::from_nanos(FRAME),
+        &location(0.0),
+        &velocity(xr::SpaceVelocityFlags::EMPTY, 0.0, 0.0),
+    );
+    assert_eq!(estimator.release_velocity(), None);
+}
+
+#[test]
+fn extrapolates_reported_velocities() {
+    let mut estimator = xr::ThrowEstimator::default();
+    let flags = xr::SpaceVelocityFlags::LINEAR_VALID | xr::SpaceVelocityFlags::ANGULAR_VALID;
+    // Accelerating at 20 m/s², with a steady spin
+    for i in 0..8 {
+        let t = i as f32 * 0.01;
+        estimator.record(
+            xr::Time::from_nanos(i * FRAME),
+            &location(10.0 * t * t),
+            &velocity(flags, 20.0 * t, 3.0),
+        );
+    }
+    let release = estimator.release_velocity().unwrap();
+    assert_close(release.linear, vector(20.0 * 0.07));
+    assert_close(release.angular.unwrap(), vector(3.0));
+}
+
+#[test]
+fn falls_back_to_positions() {
+    let mut estimator = xr::ThrowEstimator::default();
+    for i in 0..8 {
+        let t = i as f32 * 0.01;
+        estimator.record(
+            xr::Time::from_nanos(i * FRAME),
+            &location(2.0 * t),
+            &velocity(xr::SpaceVelocityFlags::EMPTY, 100.0, 100.0),
+        );
+    }
+    let release = estimator.release_velocity().unwrap();
+    assert_close(release.linear, vector(2.0));
+    assert_eq!(release.angular, None);
+}
+
+#[test]
+fn forgets_samples_outside_window() {
+    let mut estimator = xr::ThrowEstimator::new(Duration::from_millis(50));
+    let flags = xr::SpaceVelocityFlags::LINEAR_VALID;
+    for i in 0..20 {
+        // A wild swing long before the release shouldn't affect it
+        let v = if i < 10 { -50.0 } else { 1.0 };
+        estimator.record(
+            xr::Time::from_nanos(i * FRAME),
+            &location(0.0),
+            &velocity(flags, v, 0.0),
+        );
+    }
+    assert_close(estimator.release_velocity().unwrap().linear, vector(1.0));
+
+    estimator.clear();
+    assert_eq!(estimator.release_velocity(), None);
+}
@@ -0,0 +1,97 @@
+//! `UnixTimeSync` converts between runtime and UNIX time, following drift between the clocks.
+
+#[cfg(feature = "null-runtime")]
+mod common;
+
+use openxr as xr;
+
+const SECOND: i64 = 1_000_000_000;
+/// An arbitrary UNIX time at runtime time zero
+const EPOCH_OFFSET: i64 = 1_700_000_000 * SECOND;
+
+fn assert_close(actual: i64, expected: i64) {
+    assert!(
+        (actual - expected).abs() <= 1,
+        "{} is not close to {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn nothing_without_samples() {
+    let sync = xr::UnixTimeSync::default();
+    assert_eq!(sync.to_unix_nanos(xr::Time::from_nanos(SECOND)), None);
+    assert_eq!(sync.from_unix_nanos(EPOCH_OFFSET), None);
+    assert_eq!(sync.drift_nanos_per_second(), 0.0);
+}
+
+#[test]
+fn constant_offset() {
+    let mut sync = xr::UnixTimeSync::default();
+    sync.add_sample(xr::Time::from_nanos(5 * SECOND), EPOCH_OFFSET + 5 * SECOND);
+    let time = xr::Time::from_nanos(7 * SECOND + 3);
+    let unix = sync.to_unix_nanos(time).unwrap();
+    assert_eq!(unix, EPOCH_OFFSET + 7 * SECOND + 3);
+    assert_eq!(sync.from_unix_nanos(unix), Some(time));
+    assert_eq!(sync.drift_nanos_per_second(), 0.0);
+}
+
+#[test]
+fn follows_drift() {
+    // The UNIX clock gains 50µs per second
+    let drift = 50_000;
+    let mut sync = xr::UnixTimeSync::default();
+    for i in 0..10 {
+        sync.add_sample(
+            xr::Time::from_nanos(i * SECOND),
+            EPOCH_OFFSET + i * (SECOND + drift),
+        );
+    }
+    assert!((sync.drift_nanos_per_second() - drift as f64).abs() < 1.0);
+
+    // Extrapolated past the last sample
+    let time = xr::Time::from_nanos(20 * SECOND);
+    let unix = sync.to_unix_nanos(time).unwrap();
+    assert_close(unix, EPOCH_OFFSET + 20 * (SECOND + drift));
+    assert_close(
+        sync.from_unix_nanos(unix).unwrap().as_nanos(),
+        time.as_nanos(),
+    );
+}
+
+#[test]
+fn old_samples_expire() {
+    let mut sync = xr::UnixTimeSync::new(3);
+    for i in 0..3 {
+        sync.add_sample(xr::Time::from_nanos(i * SECOND), EPOCH_OFFSET + i * SECOND);
+    }
+    // The system clock is stepped forward by a second
+    for i in 3..6 {
+        sync.add_sample(
+            xr::Time::from_nanos(i * SECOND),
+            EPOCH_OFFSET + (i + 1) * SECOND,
+        );
+    }
+    assert_eq!(sync.sample_count(), 3);
+    assert_eq!(
+        sync.to_unix_nanos(xr::Time::from_nanos(10 * SECOND)),
+        Some(EPOCH_OFFSET + 11 * SECOND)
+    );
+
+    sync.reset();
+    assert_eq!(sync.sample_count(), 0);
+    assert_eq!(sync.to_unix_nanos(xr::Time::from_nanos(SECOND)), None);
+}
+
+#[cfg(feature = "null-runtime")]
+#[test]
+fn sampling_requires_conversion_extension() {
+    let instance = common::create_instance(&xr::Entry::null(), "time_sync");
+    let mut sync = xr::UnixTimeSync::default();
+    assert_eq!(
+        sync.sample(&instance),
+        Err(xr::sys::Result::ERROR_EXTENSION_NOT_PRESENT)
+    );
+    assert_eq!(sync.sample_count(), 0);
+}
@@ -58,7 +58,7 @@ pub const MAX_EXTERNAL_CAMERA_NAME_SIZE_OCULUS: usize = 32usize;
 pub const UUID_SIZE_EXT: usize = 16usize;
 #[doc = "Structure type enumerant - see [XrStructureType](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrStructureType)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct StructureType(i32);
 impl StructureType {
     pub const UNKNOWN: StructureType = Self(0i32);
@@ -390,7 +390,7 @@ impl StructureType {
     pub const PLANE_DETECTOR_LOCATION_EXT: StructureType = Self(1000429005i32);
     pub const PLANE_DETECTOR_POLYGON_BUFFER_EXT: StructureType = Self(1000429006i32);
     pub const SYSTEM_PLANE_DETECTION_PROPERTIES_EXT: StructureType = Self(1000429007i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -907,14 +907,14 @@ impl fmt::Debug for StructureType {
             Self::SYSTEM_PLANE_DETECTION_PROPERTIES_EXT => {
                 Some("SYSTEM_PLANE_DETECTION_PROPERTIES_EXT")
             }
-            _ => None,
+            _ => crate::manual::structure_type_name(*self),
         };
         fmt_enum(fmt, self.0, name)
     }
 }
 #[doc = "Error and return codes - see [XrResult](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrResult)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Result(i32);
 impl Result {
     #[doc = "Function successfully completed."]
@@ -1108,7 +1108,7 @@ impl Result {
     pub const ERROR_SPACE_NOT_LOCATABLE_EXT: Result = Self(-1000429000i32);
     #[doc = "The permission for this resource was not granted."]
     pub const ERROR_PLANE_DETECTION_PERMISSION_DENIED_EXT: Result = Self(-1000429001i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1275,25 +1275,24 @@ impl fmt::Debug for Result {
             Self::ERROR_PLANE_DETECTION_PERMISSION_DENIED_EXT => {
                 Some("ERROR_PLANE_DETECTION_PERMISSION_DENIED_EXT")
             }
-            _ => None,
+            _ => crate::manual::result_name(*self),
         };
         fmt_enum(fmt, self.0, name)
     }
 }
 impl fmt::Display for Result {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let reason = match * self { Self :: SUCCESS => Some ("function successfully completed") , Self :: TIMEOUT_EXPIRED => Some ("the specified timeout time occurred before the operation could complete") , Self :: SESSION_LOSS_PENDING => Some ("the session will be lost soon") , Self :: EVENT_UNAVAILABLE => Some ("no event was available") , Self :: SPACE_BOUNDS_UNAVAILABLE => Some ("the space's bounds are not known at the moment") , Self :: SESSION_NOT_FOCUSED => Some ("the session is not in the focused state") , Self :: FRAME_DISCARDED => Some ("a frame has been discarded from composition") , Self :: ERROR_VALIDATION_FAILURE => Some ("the function usage was invalid in some way") , Self :: ERROR_RUNTIME_FAILURE => Some ("the runtime failed to handle the function in an unexpected way that is not covered by another error result") , Self :: ERROR_OUT_OF_MEMORY => Some ("a memory allocation has failed") , Self :: ERROR_API_VERSION_UNSUPPORTED => Some ("the runtime does not support the requested API version") , Self :: ERROR_INITIALIZATION_FAILED => Some ("initialization of object could not be completed") , Self :: ERROR_FUNCTION_UNSUPPORTED => Some ("the requested function was not found or is otherwise unsupported") , Self :: ERROR_FEATURE_UNSUPPORTED => Some ("the requested feature is not supported") , Self :: ERROR_EXTENSION_NOT_PRESENT => Some ("a requested extension is not supported") , Self :: ERROR_LIMIT_REACHED => Some ("the runtime supports no more of the requested resource") , Self :: ERROR_SIZE_INSUFFICIENT => Some ("the supplied size was smaller than required") , Self :: ERROR_HANDLE_INVALID => Some ("a supplied object handle was invalid") , Self :: ERROR_INSTANCE_LOST => Some ("the XrInstance was lost or could not be found. It will need to be destroyed and optionally recreated") , Self :: ERROR_SESSION_RUNNING => Some ("the session is already running") , Self :: ERROR_SESSION_NOT_RUNNING => Some ("the session is not yet running") , Self :: ERROR_SESSION_LOST => Some ("the XrSession was lost. It will need to be destroyed and optionally recreated") , Self :: ERROR_SYSTEM_INVALID => Some ("the provided XrSystemId was invalid") , Self :: ERROR_PATH_INVALID => Some ("the provided XrPath was not valid") , Self :: ERROR_PATH_COUNT_EXCEEDED => Some ("the maximum number of supported semantic paths has been reached") , Self :: ERROR_PATH_FORMAT_INVALID => Some ("the semantic path character format is invalid") , Self :: ERROR_PATH_UNSUPPORTED => Some ("the semantic path is unsupported") , Self :: ERROR_LAYER_INVALID => Some ("the layer was NULL or otherwise invalid") , Self :: ERROR_LAYER_LIMIT_EXCEEDED => Some ("the number of specified layers is greater than the supported number") , Self :: ERROR_SWAPCHAIN_RECT_INVALID => Some ("the image rect was negatively sized or otherwise invalid") , Self :: ERROR_SWAPCHAIN_FORMAT_UNSUPPORTED => Some ("the image format is not supported by the runtime or platform") , Self :: ERROR_ACTION_TYPE_MISMATCH => Some ("the API used to retrieve an action's state does not match the action's type") , Self :: ERROR_SESSION_NOT_READY => Some ("the session is not in the ready state") , Self :: ERROR_SESSION_NOT_STOPPING => Some ("the session is not in the stopping state") , Self :: ERROR_TIME_INVALID => Some ("the provided XrTime was zero, negative, or out of range") , Self :: ERROR_REFERENCE_SPACE_UNSUPPORTED => Some ("the specified reference space is not supported by the runtime or system") , Self :: ERROR_FILE_ACCESS_ERROR => Some ("the file could not be accessed") , Self :: ERROR_FILE_CONTENTS_INVALID => Some ("the file's contents were invalid") , Self :: ERROR_FORM_FACTOR_UNSUPPORTED => Some ("the specified form factor is not supported by the current runtime or platform") , Self :: ERROR_FORM_FACTOR_UNAVAILABLE => Some ("the specified form factor is supported, but the device is currently not available, e.g. not plugged in or powered off") , Self :: ERROR_API_LAYER_NOT_PRESENT => Some ("a requested API layer is not present or could not be loaded") , Self :: ERROR_CALL_ORDER_INVALID => Some ("the call was made without having made a previously required call") , Self :: ERROR_GRAPHICS_DEVICE_INVALID => Some ("the given graphics device is not in a valid state. The graphics device could be lost or initialized without meeting graphics requirements") , Self :: ERROR_POSE_INVALID => Some ("the supplied pose was invalid with respect to the requirements") , Self :: ERROR_INDEX_OUT_OF_RANGE => Some ("the supplied index was outside the range of valid indices") , Self :: ERROR_VIEW_CONFIGURATION_TYPE_UNSUPPORTED => Some ("the specified view configuration type is not supported by the runtime or platform") , Self :: ERROR_ENVIRONMENT_BLEND_MODE_UNSUPPORTED => Some ("the specified environment blend mode is not supported by the runtime or platform") , Self :: ERROR_NAME_DUPLICATED => Some ("the name provided was a duplicate of an already-existing resource") , Self :: ERROR_NAME_INVALID => Some ("the name provided was invalid") , Self :: ERROR_ACTIONSET_NOT_ATTACHED => Some ("a referenced action set is not attached to the session") , Self :: ERROR_ACTIONSETS_ALREADY_ATTACHED => Some ("the session already has attached action sets") , Self :: ERROR_LOCALIZED_NAME_DUPLICATED => Some ("the localized name provided was a duplicate of an already-existing resource") , Self :: ERROR_LOCALIZED_NAME_INVALID => Some ("the localized name provided was invalid") , Self :: ERROR_GRAPHICS_REQUIREMENTS_CALL_MISSING => Some ("the xrGetGraphicsRequirements* call was not made before calling xrCreateSession") , Self :: ERROR_RUNTIME_UNAVAILABLE => Some ("the loader was unable to find or load a runtime") , Self :: ERROR_ANDROID_THREAD_SETTINGS_ID_INVALID_KHR => Some ("xrSetAndroidApplicationThreadKHR failed as thread id is invalid") , Self :: ERROR_ANDROID_THREAD_SETTINGS_FAILURE_KHR => Some ("xrSetAndroidApplicationThreadKHR failed setting the thread attributes/priority") , Self :: ERROR_CREATE_SPATIAL_ANCHOR_FAILED_MSFT => Some ("spatial anchor could not be created at that location") , Self :: ERROR_SECONDARY_VIEW_CONFIGURATION_TYPE_NOT_ENABLED_MSFT => Some ("the secondary view configuration was not enabled when creating the session") , Self :: ERROR_CONTROLLER_MODEL_KEY_INVALID_MSFT => Some ("the controller model key is invalid") , Self :: ERROR_REPROJECTION_MODE_UNSUPPORTED_MSFT => Some ("the reprojection mode is not supported") , Self :: ERROR_COMPUTE_NEW_SCENE_NOT_COMPLETED_MSFT => Some ("compute new scene not completed") , Self :: ERROR_SCENE_COMPONENT_ID_INVALID_MSFT => Some ("scene component id invalid") , Self :: ERROR_SCENE_COMPONENT_TYPE_MISMATCH_MSFT => Some ("scene component type mismatch") , Self :: ERROR_SCENE_MESH_BUFFER_ID_INVALID_MSFT => Some ("scene mesh buffer id invalid") , Self :: ERROR_SCENE_COMPUTE_FEATURE_INCOMPATIBLE_MSFT => Some ("scene compute feature incompatible") , Self :: ERROR_SCENE_COMPUTE_CONSISTENCY_MISMATCH_MSFT => Some ("scene compute consistency mismatch") , Self :: ERROR_DISPLAY_REFRESH_RATE_UNSUPPORTED_FB => Some ("the display refresh rate is not supported by the platform") , Self :: ERROR_COLOR_SPACE_UNSUPPORTED_FB => Some ("the color space is not supported by the runtime") , Self :: ERROR_SPACE_COMPONENT_NOT_SUPPORTED_FB => Some ("the component type is not supported for this space") , Self :: ERROR_SPACE_COMPONENT_NOT_ENABLED_FB => Some ("the required component is not enabled for this space") , Self :: ERROR_SPACE_COMPONENT_STATUS_PENDING_FB => Some ("a request to set the component's status is currently pending") , Self :: ERROR_SPACE_COMPONENT_STATUS_ALREADY_SET_FB => Some ("the component is already set to the requested value") , Self :: ERROR_UNEXPECTED_STATE_PASSTHROUGH_FB => Some ("the object state is unexpected for the issued command") , Self :: ERROR_FEATURE_ALREADY_CREATED_PASSTHROUGH_FB => Some ("trying to create an MR feature when one was already created and only one instance is allowed") , Self :: ERROR_FEATURE_REQUIRED_PASSTHROUGH_FB => Some ("requested functionality requires a feature to be created first") , Self :: ERROR_NOT_PERMITTED_PASSTHROUGH_FB => Some ("requested functionality is not permitted - application is not allowed to perform the requested operation") , Self :: ERROR_INSUFFICIENT_RESOURCES_PASSTHROUGH_FB => Some ("there weren't sufficient resources available to perform an operation") , Self :: ERROR_UNKNOWN_PASSTHROUGH_FB => Some ("unknown Passthrough error (no further details provided)") , Self :: ERROR_RENDER_MODEL_KEY_INVALID_FB => Some ("the model key is invalid") , Self :: RENDER_MODEL_UNAVAILABLE_FB => Some ("the model is unavailable") , Self :: ERROR_MARKER_NOT_TRACKED_VARJO => Some ("marker tracking is disabled or the specified marker is not currently tracked") , Self :: ERROR_MARKER_ID_INVALID_VARJO => Some ("the specified marker ID is not valid") , Self :: ERROR_SPATIAL_ANCHOR_NAME_NOT_FOUND_MSFT => Some ("a spatial anchor was not found associated with the spatial anchor name provided") , Self :: ERROR_SPATIAL_ANCHOR_NAME_INVALID_MSFT => Some ("the spatial anchor name provided was not valid") , Self :: SCENE_MARKER_DATA_NOT_STRING_MSFT => Some ("marker does not encode a string") , Self :: ERROR_SPACE_MAPPING_INSUFFICIENT_FB => Some ("anchor import from cloud or export from device failed") , Self :: ERROR_SPACE_LOCALIZATION_FAILED_FB => Some ("anchors were downloaded from the cloud but failed to be imported/aligned on the device") , Self :: ERROR_SPACE_NETWORK_TIMEOUT_FB => Some ("timeout occurred while waiting for network request to complete") , Self :: ERROR_SPACE_NETWORK_REQUEST_FAILED_FB => Some ("the network request failed") , Self :: ERROR_SPACE_CLOUD_STORAGE_DISABLED_FB => Some ("cloud storage is required for this operation but is currently disabled") , Self :: ERROR_PASSTHROUGH_COLOR_LUT_BUFFER_SIZE_MISMATCH_META => Some ("the provided data buffer did not match the required size") , Self :: ERROR_HINT_ALREADY_SET_QCOM => Some ("tracking optimization hint is already set for the domain") , Self :: ERROR_SPACE_NOT_LOCATABLE_EXT => Some ("the space passed to the function was not locatable") , Self :: ERROR_PLANE_DETECTION_PERMISSION_DENIED_EXT => Some ("the permission for this resource was not granted") , _ => None , } ;
-        if let Some(reason) = reason {
-            fmt.pad(reason)
-        } else {
-            write!(fmt, "unknown error (code {})", self.0)
+        let reason = match * self { Self :: SUCCESS => Some ("function successfully completed (SUCCESS)") , Self :: TIMEOUT_EXPIRED => Some ("the specified timeout time occurred before the operation could complete (TIMEOUT_EXPIRED)") , Self :: SESSION_LOSS_PENDING => Some ("the session will be lost soon (SESSION_LOSS_PENDING)") , Self :: EVENT_UNAVAILABLE => Some ("no event was available (EVENT_UNAVAILABLE)") , Self :: SPACE_BOUNDS_UNAVAILABLE => Some ("the space's bounds are not known at the moment (SPACE_BOUNDS_UNAVAILABLE)") , Self :: SESSION_NOT_FOCUSED => Some ("the session is not in the focused state (SESSION_NOT_FOCUSED)") , Self :: FRAME_DISCARDED => Some ("a frame has been discarded from composition (FRAME_DISCARDED)") , Self :: ERROR_VALIDATION_FAILURE => Some ("the function usage was invalid in some way (ERROR_VALIDATION_FAILURE)") , Self :: ERROR_RUNTIME_FAILURE => Some ("the runtime failed to handle the function in an unexpected way that is not covered by another error result (ERROR_RUNTIME_FAILURE)") , Self :: ERROR_OUT_OF_MEMORY => Some ("a memory allocation has failed (ERROR_OUT_OF_MEMORY)") , Self :: ERROR_API_VERSION_UNSUPPORTED => Some ("the runtime does not support the requested API version (ERROR_API_VERSION_UNSUPPORTED)") , Self :: ERROR_INITIALIZATION_FAILED => Some ("initialization of object could not be completed (ERROR_INITIALIZATION_FAILED)") , Self :: ERROR_FUNCTION_UNSUPPORTED => Some ("the requested function was not found or is otherwise unsupported (ERROR_FUNCTION_UNSUPPORTED)") , Self :: ERROR_FEATURE_UNSUPPORTED => Some ("the requested feature is not supported (ERROR_FEATURE_UNSUPPORTED)") , Self :: ERROR_EXTENSION_NOT_PRESENT => Some ("a requested extension is not supported (ERROR_EXTENSION_NOT_PRESENT)") , Self :: ERROR_LIMIT_REACHED => Some ("the runtime supports no more of the requested resource (ERROR_LIMIT_REACHED)") , Self :: ERROR_SIZE_INSUFFICIENT => Some ("the supplied size was smaller than required (ERROR_SIZE_INSUFFICIENT)") , Self :: ERROR_HANDLE_INVALID => Some ("a supplied object handle was invalid (ERROR_HANDLE_INVALID)") , Self :: ERROR_INSTANCE_LOST => Some ("the XrInstance was lost or could not be found. It will need to be destroyed and optionally recreated (ERROR_INSTANCE_LOST)") , Self :: ERROR_SESSION_RUNNING => Some ("the session is already running (ERROR_SESSION_RUNNING)") , Self :: ERROR_SESSION_NOT_RUNNING => Some ("the session is not yet running (ERROR_SESSION_NOT_RUNNING)") , Self :: ERROR_SESSION_LOST => Some ("the XrSession was lost. It will need to be destroyed and optionally recreated (ERROR_SESSION_LOST)") , Self :: ERROR_SYSTEM_INVALID => Some ("the provided XrSystemId was invalid (ERROR_SYSTEM_INVALID)") , Self :: ERROR_PATH_INVALID => Some ("the provided XrPath was not valid (ERROR_PATH_INVALID)") , Self :: ERROR_PATH_COUNT_EXCEEDED => Some ("the maximum number of supported semantic paths has been reached (ERROR_PATH_COUNT_EXCEEDED)") , Self :: ERROR_PATH_FORMAT_INVALID => Some ("the semantic path character format is invalid (ERROR_PATH_FORMAT_INVALID)") , Self :: ERROR_PATH_UNSUPPORTED => Some ("the semantic path is unsupported (ERROR_PATH_UNSUPPORTED)") , Self :: ERROR_LAYER_INVALID => Some ("the layer was NULL or otherwise invalid (ERROR_LAYER_INVALID)") , Self :: ERROR_LAYER_LIMIT_EXCEEDED => Some ("the number of specified layers is greater than the supported number (ERROR_LAYER_LIMIT_EXCEEDED)") , Self :: ERROR_SWAPCHAIN_RECT_INVALID => Some ("the image rect was negatively sized or otherwise invalid (ERROR_SWAPCHAIN_RECT_INVALID)") , Self :: ERROR_SWAPCHAIN_FORMAT_UNSUPPORTED => Some ("the image format is not supported by the runtime or platform (ERROR_SWAPCHAIN_FORMAT_UNSUPPORTED)") , Self :: ERROR_ACTION_TYPE_MISMATCH => Some ("the API used to retrieve an action's state does not match the action's type (ERROR_ACTION_TYPE_MISMATCH)") , Self :: ERROR_SESSION_NOT_READY => Some ("the session is not in the ready state (ERROR_SESSION_NOT_READY)") , Self :: ERROR_SESSION_NOT_STOPPING => Some ("the session is not in the stopping state (ERROR_SESSION_NOT_STOPPING)") , Self :: ERROR_TIME_INVALID => Some ("the provided XrTime was zero, negative, or out of range (ERROR_TIME_INVALID)") , Self :: ERROR_REFERENCE_SPACE_UNSUPPORTED => Some ("the specified reference space is not supported by the runtime or system (ERROR_REFERENCE_SPACE_UNSUPPORTED)") , Self :: ERROR_FILE_ACCESS_ERROR => Some ("the file could not be accessed (ERROR_FILE_ACCESS_ERROR)") , Self :: ERROR_FILE_CONTENTS_INVALID => Some ("the file's contents were invalid (ERROR_FILE_CONTENTS_INVALID)") , Self :: ERROR_FORM_FACTOR_UNSUPPORTED => Some ("the specified form factor is not supported by the current runtime or platform (ERROR_FORM_FACTOR_UNSUPPORTED)") , Self :: ERROR_FORM_FACTOR_UNAVAILABLE => Some ("the specified form factor is supported, but the device is currently not available, e.g. not plugged in or powered off (ERROR_FORM_FACTOR_UNAVAILABLE)") , Self :: ERROR_API_LAYER_NOT_PRESENT => Some ("a requested API layer is not present or could not be loaded (ERROR_API_LAYER_NOT_PRESENT)") , Self :: ERROR_CALL_ORDER_INVALID => Some ("the call was made without having made a previously required call (ERROR_CALL_ORDER_INVALID)") , Self :: ERROR_GRAPHICS_DEVICE_INVALID => Some ("the given graphics device is not in a valid state. The graphics device could be lost or initialized without meeting graphics requirements (ERROR_GRAPHICS_DEVICE_INVALID)") , Self :: ERROR_POSE_INVALID => Some ("the supplied pose was invalid with respect to the requirements (ERROR_POSE_INVALID)") , Self :: ERROR_INDEX_OUT_OF_RANGE => Some ("the supplied index was outside the range of valid indices (ERROR_INDEX_OUT_OF_RANGE)") , Self :: ERROR_VIEW_CONFIGURATION_TYPE_UNSUPPORTED => Some ("the specified view configuration type is not supported by the runtime or platform (ERROR_VIEW_CONFIGURATION_TYPE_UNSUPPORTED)") , Self :: ERROR_ENVIRONMENT_BLEND_MODE_UNSUPPORTED => Some ("the specified environment blend mode is not supported by the runtime or platform (ERROR_ENVIRONMENT_BLEND_MODE_UNSUPPORTED)") , Self :: ERROR_NAME_DUPLICATED => Some ("the name provided was a duplicate of an already-existing resource (ERROR_NAME_DUPLICATED)") , Self :: ERROR_NAME_INVALID => Some ("the name provided was invalid (ERROR_NAME_INVALID)") , Self :: ERROR_ACTIONSET_NOT_ATTACHED => Some ("a referenced action set is not attached to the session (ERROR_ACTIONSET_NOT_ATTACHED)") , Self :: ERROR_ACTIONSETS_ALREADY_ATTACHED => Some ("the session already has attached action sets (ERROR_ACTIONSETS_ALREADY_ATTACHED)") , Self :: ERROR_LOCALIZED_NAME_DUPLICATED => Some ("the localized name provided was a duplicate of an already-existing resource (ERROR_LOCALIZED_NAME_DUPLICATED)") , Self :: ERROR_LOCALIZED_NAME_INVALID => Some ("the localized name provided was invalid (ERROR_LOCALIZED_NAME_INVALID)") , Self :: ERROR_GRAPHICS_REQUIREMENTS_CALL_MISSING => Some ("the xrGetGraphicsRequirements* call was not made before calling xrCreateSession (ERROR_GRAPHICS_REQUIREMENTS_CALL_MISSING)") , Self :: ERROR_RUNTIME_UNAVAILABLE => Some ("the loader was unable to find or load a runtime (ERROR_RUNTIME_UNAVAILABLE)") , Self :: ERROR_ANDROID_THREAD_SETTINGS_ID_INVALID_KHR => Some ("xrSetAndroidApplicationThreadKHR failed as thread id is invalid (ERROR_ANDROID_THREAD_SETTINGS_ID_INVALID_KHR)") , Self :: ERROR_ANDROID_THREAD_SETTINGS_FAILURE_KHR => Some ("xrSetAndroidApplicationThreadKHR failed setting the thread attributes/priority (ERROR_ANDROID_THREAD_SETTINGS_FAILURE_KHR)") , Self :: ERROR_CREATE_SPATIAL_ANCHOR_FAILED_MSFT => Some ("spatial anchor could not be created at that location (ERROR_CREATE_SPATIAL_ANCHOR_FAILED_MSFT)") , Self :: ERROR_SECONDARY_VIEW_CONFIGURATION_TYPE_NOT_ENABLED_MSFT => Some ("the secondary view configuration was not enabled when creating the session (ERROR_SECONDARY_VIEW_CONFIGURATION_TYPE_NOT_ENABLED_MSFT)") , Self :: ERROR_CONTROLLER_MODEL_KEY_INVALID_MSFT => Some ("the controller model key is invalid (ERROR_CONTROLLER_MODEL_KEY_INVALID_MSFT)") , Self :: ERROR_REPROJECTION_MODE_UNSUPPORTED_MSFT => Some ("the reprojection mode is not supported (ERROR_REPROJECTION_MODE_UNSUPPORTED_MSFT)") , Self :: ERROR_COMPUTE_NEW_SCENE_NOT_COMPLETED_MSFT => Some ("compute new scene not completed (ERROR_COMPUTE_NEW_SCENE_NOT_COMPLETED_MSFT)") , Self :: ERROR_SCENE_COMPONENT_ID_INVALID_MSFT => Some ("scene component id invalid (ERROR_SCENE_COMPONENT_ID_INVALID_MSFT)") , Self :: ERROR_SCENE_COMPONENT_TYPE_MISMATCH_MSFT => Some ("scene component type mismatch (ERROR_SCENE_COMPONENT_TYPE_MISMATCH_MSFT)") , Self :: ERROR_SCENE_MESH_BUFFER_ID_INVALID_MSFT => Some ("scene mesh buffer id invalid (ERROR_SCENE_MESH_BUFFER_ID_INVALID_MSFT)") , Self :: ERROR_SCENE_COMPUTE_FEATURE_INCOMPATIBLE_MSFT => Some ("scene compute feature incompatible (ERROR_SCENE_COMPUTE_FEATURE_INCOMPATIBLE_MSFT)") , Self :: ERROR_SCENE_COMPUTE_CONSISTENCY_MISMATCH_MSFT => Some ("scene compute consistency mismatch (ERROR_SCENE_COMPUTE_CONSISTENCY_MISMATCH_MSFT)") , Self :: ERROR_DISPLAY_REFRESH_RATE_UNSUPPORTED_FB => Some ("the display refresh rate is not supported by the platform (ERROR_DISPLAY_REFRESH_RATE_UNSUPPORTED_FB)") , Self :: ERROR_COLOR_SPACE_UNSUPPORTED_FB => Some ("the color space is not supported by the runtime (ERROR_COLOR_SPACE_UNSUPPORTED_FB)") , Self :: ERROR_SPACE_COMPONENT_NOT_SUPPORTED_FB => Some ("the component type is not supported for this space (ERROR_SPACE_COMPONENT_NOT_SUPPORTED_FB)") , Self :: ERROR_SPACE_COMPONENT_NOT_ENABLED_FB => Some ("the required component is not enabled for this space (ERROR_SPACE_COMPONENT_NOT_ENABLED_FB)") , Self :: ERROR_SPACE_COMPONENT_STATUS_PENDING_FB => Some ("a request to set the component's status is currently pending (ERROR_SPACE_COMPONENT_STATUS_PENDING_FB)") , Self :: ERROR_SPACE_COMPONENT_STATUS_ALREADY_SET_FB => Some ("the component is already set to the requested value (ERROR_SPACE_COMPONENT_STATUS_ALREADY_SET_FB)") , Self :: ERROR_UNEXPECTED_STATE_PASSTHROUGH_FB => Some ("the object state is unexpected for the issued command (ERROR_UNEXPECTED_STATE_PASSTHROUGH_FB)") , Self :: ERROR_FEATURE_ALREADY_CREATED_PASSTHROUGH_FB => Some ("trying to create an MR feature when one was already created and only one instance is allowed (ERROR_FEATURE_ALREADY_CREATED_PASSTHROUGH_FB)") , Self :: ERROR_FEATURE_REQUIRED_PASSTHROUGH_FB => Some ("requested functionality requires a feature to be created first (ERROR_FEATURE_REQUIRED_PASSTHROUGH_FB)") , Self :: ERROR_NOT_PERMITTED_PASSTHROUGH_FB => Some ("requested functionality is not permitted - application is not allowed to perform the requested operation (ERROR_NOT_PERMITTED_PASSTHROUGH_FB)") , Self :: ERROR_INSUFFICIENT_RESOURCES_PASSTHROUGH_FB => Some ("there weren't sufficient resources available to perform an operation (ERROR_INSUFFICIENT_RESOURCES_PASSTHROUGH_FB)") , Self :: ERROR_UNKNOWN_PASSTHROUGH_FB => Some ("unknown Passthrough error (no further details provided) (ERROR_UNKNOWN_PASSTHROUGH_FB)") , Self :: ERROR_RENDER_MODEL_KEY_INVALID_FB => Some ("the model key is invalid (ERROR_RENDER_MODEL_KEY_INVALID_FB)") , Self :: RENDER_MODEL_UNAVAILABLE_FB => Some ("the model is unavailable (RENDER_MODEL_UNAVAILABLE_FB)") , Self :: ERROR_MARKER_NOT_TRACKED_VARJO => Some ("marker tracking is disabled or the specified marker is not currently tracked (ERROR_MARKER_NOT_TRACKED_VARJO)") , Self :: ERROR_MARKER_ID_INVALID_VARJO => Some ("the specified marker ID is not valid (ERROR_MARKER_ID_INVALID_VARJO)") , Self :: ERROR_SPATIAL_ANCHOR_NAME_NOT_FOUND_MSFT => Some ("a spatial anchor was not found associated with the spatial anchor name provided (ERROR_SPATIAL_ANCHOR_NAME_NOT_FOUND_MSFT)") , Self :: ERROR_SPATIAL_ANCHOR_NAME_INVALID_MSFT => Some ("the spatial anchor name provided was not valid (ERROR_SPATIAL_ANCHOR_NAME_INVALID_MSFT)") , Self :: SCENE_MARKER_DATA_NOT_STRING_MSFT => Some ("marker does not encode a string (SCENE_MARKER_DATA_NOT_STRING_MSFT)") , Self :: ERROR_SPACE_MAPPING_INSUFFICIENT_FB => Some ("anchor import from cloud or export from device failed (ERROR_SPACE_MAPPING_INSUFFICIENT_FB)") , Self :: ERROR_SPACE_LOCALIZATION_FAILED_FB => Some ("anchors were downloaded from the cloud but failed to be imported/aligned on the device (ERROR_SPACE_LOCALIZATION_FAILED_FB)") , Self :: ERROR_SPACE_NETWORK_TIMEOUT_FB => Some ("timeout occurred while waiting for network request to complete (ERROR_SPACE_NETWORK_TIMEOUT_FB)") , Self :: ERROR_SPACE_NETWORK_REQUEST_FAILED_FB => Some ("the network request failed (ERROR_SPACE_NETWORK_REQUEST_FAILED_FB)") , Self :: ERROR_SPACE_CLOUD_STORAGE_DISABLED_FB => Some ("cloud storage is required for this operation but is currently disabled (ERROR_SPACE_CLOUD_STORAGE_DISABLED_FB)") , Self :: ERROR_PASSTHROUGH_COLOR_LUT_BUFFER_SIZE_MISMATCH_META => Some ("the provided data buffer did not match the required size (ERROR_PASSTHROUGH_COLOR_LUT_BUFFER_SIZE_MISMATCH_META)") , Self :: ERROR_HINT_ALREADY_SET_QCOM => Some ("tracking optimization hint is already set for the domain (ERROR_HINT_ALREADY_SET_QCOM)") , Self :: ERROR_SPACE_NOT_LOCATABLE_EXT => Some ("the space passed to the function was not locatable (ERROR_SPACE_NOT_LOCATABLE_EXT)") , Self :: ERROR_PLANE_DETECTION_PERMISSION_DENIED_EXT => Some ("the permission for this resource was not granted (ERROR_PLANE_DETECTION_PERMISSION_DENIED_EXT)") , _ => None , } ;
+        match reason {
+            Some(reason) => fmt.pad(reason),
+            None => crate::manual::fmt_result(*self, fmt),
         }
     }
 }
 impl std::error::Error for Result {}
 #[doc = "Enums to track objects of various types - see [XrObjectType](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrObjectType)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ObjectType(i32);
 impl ObjectType {
     pub const UNKNOWN: ObjectType = Self(0i32);
@@ -1351,7 +1350,7 @@ impl ObjectType {
     pub const PASSTHROUGH_HTC: ObjectType = Self(1000317000i32);
     #[doc = "XrPlaneDetectorEXT"]
     pub const PLANE_DETECTOR_EXT: ObjectType = Self(1000429000i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1398,14 +1397,14 @@ impl fmt::Debug for ObjectType {
 }
 #[doc = "Android Thread Types - see [XrAndroidThreadTypeKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrAndroidThreadTypeKHR)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct AndroidThreadTypeKHR(i32);
 impl AndroidThreadTypeKHR {
     pub const APPLICATION_MAIN: AndroidThreadTypeKHR = Self(1i32);
     pub const APPLICATION_WORKER: AndroidThreadTypeKHR = Self(2i32);
     pub const RENDERER_MAIN: AndroidThreadTypeKHR = Self(3i32);
     pub const RENDERER_WORKER: AndroidThreadTypeKHR = Self(4i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1426,7 +1425,7 @@ impl fmt::Debug for AndroidThreadTypeKHR {
 }
 #[doc = "eye visibility selector - see [XrEyeVisibility](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrEyeVisibility)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct EyeVisibility(i32);
 impl EyeVisibility {
     #[doc = "Display in both eyes."]
@@ -1435,7 +1434,7 @@ impl EyeVisibility {
     pub const LEFT: EyeVisibility = Self(1i32);
     #[doc = "Display in the right eye only."]
     pub const RIGHT: EyeVisibility = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1455,7 +1454,7 @@ impl fmt::Debug for EyeVisibility {
 }
 #[doc = "See [XrActionType](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrActionType)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ActionType(i32);
 impl ActionType {
     pub const BOOLEAN_INPUT: ActionType = Self(1i32);
@@ -1463,7 +1462,7 @@ impl ActionType {
     pub const VECTOR2F_INPUT: ActionType = Self(3i32);
     pub const POSE_INPUT: ActionType = Self(4i32);
     pub const VIBRATION_OUTPUT: ActionType = Self(100i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1485,7 +1484,7 @@ impl fmt::Debug for ActionType {
 }
 #[doc = "See [XrReferenceSpaceType](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrReferenceSpaceType)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ReferenceSpaceType(i32);
 impl ReferenceSpaceType {
     pub const VIEW: ReferenceSpaceType = Self(1i32);
@@ -1494,7 +1493,7 @@ impl ReferenceSpaceType {
     pub const UNBOUNDED_MSFT: ReferenceSpaceType = Self(1000038000i32);
     pub const COMBINED_EYE_VARJO: ReferenceSpaceType = Self(1000121000i32);
     pub const LOCAL_FLOOR_EXT: ReferenceSpaceType = Self(1000426000i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1517,12 +1516,12 @@ impl fmt::Debug for ReferenceSpaceType {
 }
 #[doc = "See [XrFormFactor](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFormFactor)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FormFactor(i32);
 impl FormFactor {
     pub const HEAD_MOUNTED_DISPLAY: FormFactor = Self(1i32);
     pub const HANDHELD_DISPLAY: FormFactor = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1541,7 +1540,7 @@ impl fmt::Debug for FormFactor {
 }
 #[doc = "See [XrViewConfigurationType](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrViewConfigurationType)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ViewConfigurationType(i32);
 impl ViewConfigurationType {
     pub const PRIMARY_MONO: ViewConfigurationType = Self(1i32);
@@ -1549,7 +1548,7 @@ impl ViewConfigurationType {
     pub const PRIMARY_QUAD_VARJO: ViewConfigurationType = Self(1000037000i32);
     pub const SECONDARY_MONO_FIRST_PERSON_OBSERVER_MSFT: ViewConfigurationType =
         Self(1000054000i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1572,13 +1571,13 @@ impl fmt::Debug for ViewConfigurationType {
 }
 #[doc = "See [XrEnvironmentBlendMode](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrEnvironmentBlendMode)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct EnvironmentBlendMode(i32);
 impl EnvironmentBlendMode {
     pub const OPAQUE: EnvironmentBlendMode = Self(1i32);
     pub const ADDITIVE: EnvironmentBlendMode = Self(2i32);
     pub const ALPHA_BLEND: EnvironmentBlendMode = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1598,7 +1597,7 @@ impl fmt::Debug for EnvironmentBlendMode {
 }
 #[doc = "See [XrSessionState](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSessionState)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SessionState(i32);
 impl SessionState {
     pub const UNKNOWN: SessionState = Self(0i32);
@@ -1610,7 +1609,7 @@ impl SessionState {
     pub const STOPPING: SessionState = Self(6i32);
     pub const LOSS_PENDING: SessionState = Self(7i32);
     pub const EXITING: SessionState = Self(8i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1636,14 +1635,14 @@ impl fmt::Debug for SessionState {
 }
 #[doc = "See [XrPerfSettingsDomainEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPerfSettingsDomainEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PerfSettingsDomainEXT(i32);
 impl PerfSettingsDomainEXT {
     #[doc = "Indicates that the performance settings or notification applies to CPU domain"]
     pub const CPU: PerfSettingsDomainEXT = Self(1i32);
     #[doc = "Indicates that the performance settings or notification applies to GPU domain"]
     pub const GPU: PerfSettingsDomainEXT = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1662,7 +1661,7 @@ impl fmt::Debug for PerfSettingsDomainEXT {
 }
 #[doc = "See [XrPerfSettingsSubDomainEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPerfSettingsSubDomainEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PerfSettingsSubDomainEXT(i32);
 impl PerfSettingsSubDomainEXT {
     #[doc = "Indicates that the performance notification originates from the COMPOSITING sub-domain"]
@@ -1671,7 +1670,7 @@ impl PerfSettingsSubDomainEXT {
     pub const RENDERING: PerfSettingsSubDomainEXT = Self(2i32);
     #[doc = "Indicates that the performance notification originates from the THERMAL sub-domain"]
     pub const THERMAL: PerfSettingsSubDomainEXT = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1691,7 +1690,7 @@ impl fmt::Debug for PerfSettingsSubDomainEXT {
 }
 #[doc = "See [XrPerfSettingsLevelEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPerfSettingsLevelEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PerfSettingsLevelEXT(i32);
 impl PerfSettingsLevelEXT {
     #[doc = "Performance settings hint used by the application to indicate that it enters a non-XR section (head-locked / static screen), during which power savings are to be prioritized"]
@@ -1702,7 +1701,7 @@ impl PerfSettingsLevelEXT {
     pub const SUSTAINED_HIGH: PerfSettingsLevelEXT = Self(50i32);
     #[doc = "Performance settings hint used by the application to indicate that the application enters a section with very high complexity, during which the XR Runtime is allowed to step up beyond the thermally sustainable range"]
     pub const BOOST: PerfSettingsLevelEXT = Self(75i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1723,7 +1722,7 @@ impl fmt::Debug for PerfSettingsLevelEXT {
 }
 #[doc = "See [XrPerfSettingsNotificationLevelEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPerfSettingsNotificationLevelEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PerfSettingsNotificationLevelEXT(i32);
 impl PerfSettingsNotificationLevelEXT {
     #[doc = "Notifies that the sub-domain has reached a level where no further actions other than currently applied are necessary"]
@@ -1732,7 +1731,7 @@ impl PerfSettingsNotificationLevelEXT {
     pub const WARNING: PerfSettingsNotificationLevelEXT = Self(25i32);
     #[doc = "Notifies that the sub-domain has reached a critical level with significant performance degradation. The application should take drastic mitigation action"]
     pub const IMPAIRED: PerfSettingsNotificationLevelEXT = Self(75i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1752,7 +1751,7 @@ impl fmt::Debug for PerfSettingsNotificationLevelEXT {
 }
 #[doc = "See [XrVisibilityMaskTypeKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrVisibilityMaskTypeKHR)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct VisibilityMaskTypeKHR(i32);
 impl VisibilityMaskTypeKHR {
     #[doc = "exclusive mesh; indicates that which the viewer cannot see."]
@@ -1761,7 +1760,7 @@ impl VisibilityMaskTypeKHR {
     pub const VISIBLE_TRIANGLE_MESH: VisibilityMaskTypeKHR = Self(2i32);
     #[doc = "line loop; traces the outline of the area the viewer can see."]
     pub const LINE_LOOP: VisibilityMaskTypeKHR = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1781,12 +1780,12 @@ impl fmt::Debug for VisibilityMaskTypeKHR {
 }
 #[doc = "See [XrSpatialGraphNodeTypeMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialGraphNodeTypeMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SpatialGraphNodeTypeMSFT(i32);
 impl SpatialGraphNodeTypeMSFT {
     pub const STATIC: SpatialGraphNodeTypeMSFT = Self(1i32);
     pub const DYNAMIC: SpatialGraphNodeTypeMSFT = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1805,7 +1804,7 @@ impl fmt::Debug for SpatialGraphNodeTypeMSFT {
 }
 #[doc = "See [XrBlendFactorFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrBlendFactorFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct BlendFactorFB(i32);
 impl BlendFactorFB {
     pub const ZERO: BlendFactorFB = Self(0i32);
@@ -1814,7 +1813,7 @@ impl BlendFactorFB {
     pub const ONE_MINUS_SRC_ALPHA: BlendFactorFB = Self(3i32);
     pub const DST_ALPHA: BlendFactorFB = Self(4i32);
     pub const ONE_MINUS_DST_ALPHA: BlendFactorFB = Self(5i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1837,7 +1836,7 @@ impl fmt::Debug for BlendFactorFB {
 }
 #[doc = "See [XrSpaceComponentTypeFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpaceComponentTypeFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SpaceComponentTypeFB(i32);
 impl SpaceComponentTypeFB {
     #[doc = "Enables tracking the 6 DOF pose of the XrSpace with xrLocateSpace."]
@@ -1856,7 +1855,7 @@ impl SpaceComponentTypeFB {
     pub const ROOM_LAYOUT: SpaceComponentTypeFB = Self(6i32);
     #[doc = "Space container component."]
     pub const SPACE_CONTAINER: SpaceComponentTypeFB = Self(7i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1881,7 +1880,7 @@ impl fmt::Debug for SpaceComponentTypeFB {
 }
 #[doc = "See [XrWindingOrderFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrWindingOrderFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct WindingOrderFB(i32);
 impl WindingOrderFB {
     #[doc = "Winding order is unknown and the runtime cannot make any assumptions on the triangle orientation"]
@@ -1890,7 +1889,7 @@ impl WindingOrderFB {
     pub const CW: WindingOrderFB = Self(1i32);
     #[doc = "Counter-clockwise winding order"]
     pub const CCW: WindingOrderFB = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1910,7 +1909,7 @@ impl fmt::Debug for WindingOrderFB {
 }
 #[doc = "See [XrPassthroughLayerPurposeFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPassthroughLayerPurposeFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PassthroughLayerPurposeFB(i32);
 impl PassthroughLayerPurposeFB {
     #[doc = "Reconstruction passthrough (full screen environment)"]
@@ -1921,7 +1920,7 @@ impl PassthroughLayerPurposeFB {
     pub const TRACKED_KEYBOARD_HANDS: PassthroughLayerPurposeFB = Self(1000203001i32);
     #[doc = "Passthrough layer purpose for keyboard hands presence with keyboard masked hand transitions (i.e passthrough hands rendered only when they are over the keyboard)."]
     pub const TRACKED_KEYBOARD_MASKED_HANDS: PassthroughLayerPurposeFB = Self(1000203002i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1942,12 +1941,12 @@ impl fmt::Debug for PassthroughLayerPurposeFB {
 }
 #[doc = "See [XrSpaceQueryActionFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpaceQueryActionFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SpaceQueryActionFB(i32);
 impl SpaceQueryActionFB {
     #[doc = "Tells the query to perform a load operation on any XrSpace returned by the query."]
     pub const LOAD: SpaceQueryActionFB = Self(0i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1965,7 +1964,7 @@ impl fmt::Debug for SpaceQueryActionFB {
 }
 #[doc = "See [XrSpaceStorageLocationFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpaceStorageLocationFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SpaceStorageLocationFB(i32);
 impl SpaceStorageLocationFB {
     #[doc = "Invalid storage location"]
@@ -1974,7 +1973,7 @@ impl SpaceStorageLocationFB {
     pub const LOCAL: SpaceStorageLocationFB = Self(1i32);
     #[doc = "Cloud storage"]
     pub const CLOUD: SpaceStorageLocationFB = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -1994,14 +1993,14 @@ impl fmt::Debug for SpaceStorageLocationFB {
 }
 #[doc = "See [XrSpacePersistenceModeFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpacePersistenceModeFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SpacePersistenceModeFB(i32);
 impl SpacePersistenceModeFB {
     #[doc = "Invalid storage persistence"]
     pub const INVALID: SpacePersistenceModeFB = Self(0i32);
     #[doc = "Store XrSpace indefinitely, or until erased"]
     pub const INDEFINITE: SpacePersistenceModeFB = Self(1i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2020,7 +2019,7 @@ impl fmt::Debug for SpacePersistenceModeFB {
 }
 #[doc = "See [XrExternalCameraAttachedToDeviceOCULUS](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrExternalCameraAttachedToDeviceOCULUS)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ExternalCameraAttachedToDeviceOCULUS(i32);
 impl ExternalCameraAttachedToDeviceOCULUS {
     #[doc = "External camera is at a fixed point in LOCAL space"]
@@ -2031,7 +2030,7 @@ impl ExternalCameraAttachedToDeviceOCULUS {
     pub const LTOUCH: ExternalCameraAttachedToDeviceOCULUS = Self(2i32);
     #[doc = "External camera is attached to a right Touch controller"]
     pub const RTOUCH: ExternalCameraAttachedToDeviceOCULUS = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2052,12 +2051,12 @@ impl fmt::Debug for ExternalCameraAttachedToDeviceOCULUS {
 }
 #[doc = "See [XrPassthroughColorLutChannelsMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPassthroughColorLutChannelsMETA)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PassthroughColorLutChannelsMETA(i32);
 impl PassthroughColorLutChannelsMETA {
     pub const RGB: PassthroughColorLutChannelsMETA = Self(1i32);
     pub const RGBA: PassthroughColorLutChannelsMETA = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2076,7 +2075,7 @@ impl fmt::Debug for PassthroughColorLutChannelsMETA {
 }
 #[doc = "See [XrPerformanceMetricsCounterUnitMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPerformanceMetricsCounterUnitMETA)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PerformanceMetricsCounterUnitMETA(i32);
 impl PerformanceMetricsCounterUnitMETA {
     #[doc = "the performance counter unit is generic (unspecified)."]
@@ -2089,7 +2088,7 @@ impl PerformanceMetricsCounterUnitMETA {
     pub const BYTES: PerformanceMetricsCounterUnitMETA = Self(3i32);
     #[doc = "the performance counter unit is hertz (Hz)."]
     pub const HERTZ: PerformanceMetricsCounterUnitMETA = Self(4i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2111,14 +2110,14 @@ impl fmt::Debug for PerformanceMetricsCounterUnitMETA {
 }
 #[doc = "See [XrFacialTrackingTypeHTC](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFacialTrackingTypeHTC)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FacialTrackingTypeHTC(i32);
 impl FacialTrackingTypeHTC {
     #[doc = "Specifies this handle will observe eye expressions, with values indexed by XrEyeExpressionHTC whose count is XR_FACIAL_EXPRESSION_EYE_COUNT_HTC."]
     pub const EYE_DEFAULT: FacialTrackingTypeHTC = Self(1i32);
     #[doc = "Specifies this handle will observe lip expressions, with values indexed by XrLipExpressionHTC whose count is XR_FACIAL_EXPRESSION_LIP_COUNT_HTC."]
     pub const LIP_DEFAULT: FacialTrackingTypeHTC = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2137,7 +2136,7 @@ impl fmt::Debug for FacialTrackingTypeHTC {
 }
 #[doc = "See [XrEyeExpressionHTC](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrEyeExpressionHTC)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct EyeExpressionHTC(i32);
 impl EyeExpressionHTC {
     pub const LEFT_BLINK: EyeExpressionHTC = Self(0i32);
@@ -2154,7 +2153,7 @@ impl EyeExpressionHTC {
     pub const RIGHT_OUT: EyeExpressionHTC = Self(11i32);
     pub const LEFT_UP: EyeExpressionHTC = Self(12i32);
     pub const RIGHT_UP: EyeExpressionHTC = Self(13i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2185,7 +2184,7 @@ impl fmt::Debug for EyeExpressionHTC {
 }
 #[doc = "See [XrLipExpressionHTC](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrLipExpressionHTC)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct LipExpressionHTC(i32);
 impl LipExpressionHTC {
     pub const JAW_RIGHT: LipExpressionHTC = Self(0i32);
@@ -2225,7 +2224,7 @@ impl LipExpressionHTC {
     pub const TONGUE_UPLEFT_MORPH: LipExpressionHTC = Self(34i32);
     pub const TONGUE_DOWNRIGHT_MORPH: LipExpressionHTC = Self(35i32);
     pub const TONGUE_DOWNLEFT_MORPH: LipExpressionHTC = Self(36i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2279,14 +2278,14 @@ impl fmt::Debug for LipExpressionHTC {
 }
 #[doc = "See [XrPassthroughFormHTC](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPassthroughFormHTC)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PassthroughFormHTC(i32);
 impl PassthroughFormHTC {
     #[doc = "Presents the passthrough with full of the entire screen."]
     pub const PLANAR: PassthroughFormHTC = Self(0i32);
     #[doc = "Presents the passthrough projecting onto a custom mesh."]
     pub const PROJECTED: PassthroughFormHTC = Self(1i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2305,7 +2304,7 @@ impl fmt::Debug for PassthroughFormHTC {
 }
 #[doc = "See [XrFoveationModeHTC](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFoveationModeHTC)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FoveationModeHTC(i32);
 impl FoveationModeHTC {
     #[doc = "No foveation"]
@@ -2316,7 +2315,7 @@ impl FoveationModeHTC {
     pub const DYNAMIC: FoveationModeHTC = Self(2i32);
     #[doc = "Allow application to set foveation with desired clear FOV, periphery quality, and focal center offset."]
     pub const CUSTOM: FoveationModeHTC = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2337,7 +2336,7 @@ impl fmt::Debug for FoveationModeHTC {
 }
 #[doc = "See [XrFoveationLevelHTC](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFoveationLevelHTC)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FoveationLevelHTC(i32);
 impl FoveationLevelHTC {
     #[doc = "No foveation"]
@@ -2348,7 +2347,7 @@ impl FoveationLevelHTC {
     pub const MEDIUM: FoveationLevelHTC = Self(2i32);
     #[doc = "Heavy periphery pixel density drop and higher performance gain"]
     pub const HIGH: FoveationLevelHTC = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2369,14 +2368,14 @@ impl fmt::Debug for FoveationLevelHTC {
 }
 #[doc = "See [XrLocalDimmingModeMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrLocalDimmingModeMETA)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct LocalDimmingModeMETA(i32);
 impl LocalDimmingModeMETA {
     #[doc = "Local dimming is turned off by default for the current submitted frame. This is the same as not chaining XrLocalDimmingModeMETA."]
     pub const OFF: LocalDimmingModeMETA = Self(0i32);
     #[doc = "Local dimming is turned on for the current submitted frame."]
     pub const ON: LocalDimmingModeMETA = Self(1i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2395,7 +2394,7 @@ impl fmt::Debug for LocalDimmingModeMETA {
 }
 #[doc = "See [XrFaceExpressionFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFaceExpressionFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FaceExpressionFB(i32);
 impl FaceExpressionFB {
     pub const BROW_LOWERER_L: FaceExpressionFB = Self(0i32);
@@ -2462,7 +2461,7 @@ impl FaceExpressionFB {
     pub const UPPER_LIP_RAISER_L: FaceExpressionFB = Self(61i32);
     pub const UPPER_LIP_RAISER_R: FaceExpressionFB = Self(62i32);
     pub const COUNT: FaceExpressionFB = Self(63i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2543,12 +2542,12 @@ impl fmt::Debug for FaceExpressionFB {
 }
 #[doc = "See [XrFaceExpressionSetFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFaceExpressionSetFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FaceExpressionSet2FB(i32);
 impl FaceExpressionSet2FB {
     #[doc = "indicates that the created XrFaceTrackerFB tracks the set of blend shapes described by XrFaceExpressionFB enum, i.e. the xrGetFaceExpressionWeightsFB function returns an array of blend shapes with the count of XR_FACE_EXPRESSION_COUNT_FB and can: be indexed using XrFaceExpressionFB."]
     pub const DEFAULT: FaceExpressionSet2FB = Self(0i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2565,12 +2564,12 @@ impl fmt::Debug for FaceExpressionSet2FB {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FaceTrackingDataSource2FB(i32);
 impl FaceTrackingDataSource2FB {
     pub const VISUAL: FaceTrackingDataSource2FB = Self(0i32);
     pub const AUDIO: FaceTrackingDataSource2FB = Self(1i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2589,13 +2588,13 @@ impl fmt::Debug for FaceTrackingDataSource2FB {
 }
 #[doc = "See [XrFaceConfidenceFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFaceConfidenceFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FaceConfidence2FB(i32);
 impl FaceConfidence2FB {
     pub const LOWER_FACE: FaceConfidence2FB = Self(0i32);
     pub const UPPER_FACE: FaceConfidence2FB = Self(1i32);
     pub const COUNT: FaceConfidence2FB = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2615,7 +2614,7 @@ impl fmt::Debug for FaceConfidence2FB {
 }
 #[doc = "See [XrBodyJointFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrBodyJointFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct BodyJointFB(i32);
 impl BodyJointFB {
     pub const ROOT: BodyJointFB = Self(0i32);
@@ -2690,7 +2689,7 @@ impl BodyJointFB {
     pub const RIGHT_HAND_LITTLE_TIP: BodyJointFB = Self(69i32);
     pub const COUNT: BodyJointFB = Self(70i32);
     pub const NONE: BodyJointFB = Self(-1i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2779,12 +2778,12 @@ impl fmt::Debug for BodyJointFB {
 }
 #[doc = "Describes the set of body joints to track when creating an XrBodyTrackerFB. - see [XrBodyJointSetFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrBodyJointSetFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct BodyJointSetFB(i32);
 impl BodyJointSetFB {
     #[doc = "Indicates that the created XrBodyTrackerFB tracks the set of body joints described by XrBodyJointFB enum, i.e. the xrLocateBodyJointsFB function returns an array of joint locations with the count of XR_BODY_JOINT_COUNT_FB and can be indexed using XrBodyJointFB."]
     pub const DEFAULT: BodyJointSetFB = Self(0i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2801,7 +2800,7 @@ impl fmt::Debug for BodyJointSetFB {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FullBodyJointMETA(i32);
 impl FullBodyJointMETA {
     pub const ROOT: FullBodyJointMETA = Self(0i32);
@@ -2890,7 +2889,7 @@ impl FullBodyJointMETA {
     pub const RIGHT_FOOT_BALL: FullBodyJointMETA = Self(83i32);
     pub const COUNT: FullBodyJointMETA = Self(84i32);
     pub const NONE: FullBodyJointMETA = Self(85i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -2993,13 +2992,13 @@ impl fmt::Debug for FullBodyJointMETA {
 }
 #[doc = "Describes the set of body joints to track when creating an XrFullBodyTrackerMETA."]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct BodyJointSetFullBodyMETA(i32);
 impl BodyJointSetFullBodyMETA {
     #[doc = "Indicates that the created XrBodyTrackerFB tracks the set of body joints described by XrBodyJointFB enum, i.e. the xrLocateBodyJointsFB function returns an array of joint locations with the count of XR_BODY_JOINT_COUNT_FB and can be indexed using XrBodyJointFB."]
     pub const DEFAULT: BodyJointSetFullBodyMETA = Self(0i32);
     pub const FULL_BODY: BodyJointSetFullBodyMETA = Self(1000274000i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3017,7 +3016,7 @@ impl fmt::Debug for BodyJointSetFullBodyMETA {
 }
 #[doc = "See [XrEyePositionFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrEyePositionFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct EyePositionFB(i32);
 impl EyePositionFB {
     #[doc = "Specifies the position of the left eye."]
@@ -3025,7 +3024,7 @@ impl EyePositionFB {
     #[doc = "Specifies the position of the right eye."]
     pub const RIGHT: EyePositionFB = Self(1i32);
     pub const COUNT: EyePositionFB = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3045,12 +3044,12 @@ impl fmt::Debug for EyePositionFB {
 }
 #[doc = "See [XrTrackingOptimizationSettingsDomainQCOM](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrTrackingOptimizationSettingsDomainQCOM)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct TrackingOptimizationSettingsDomainQCOM(i32);
 impl TrackingOptimizationSettingsDomainQCOM {
     #[doc = "Setting applies to all QCOM tracking extensions."]
     pub const ALL: TrackingOptimizationSettingsDomainQCOM = Self(1i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3068,7 +3067,7 @@ impl fmt::Debug for TrackingOptimizationSettingsDomainQCOM {
 }
 #[doc = "See [XrTrackingOptimizationSettingsHintQCOM](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrTrackingOptimizationSettingsHintQCOM)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct TrackingOptimizationSettingsHintQCOM(i32);
 impl TrackingOptimizationSettingsHintQCOM {
     #[doc = "Used by the application to indicate that it does not have a preference to optimize for. The run-time is understood to choose a balanced approach."]
@@ -3081,7 +3080,7 @@ impl TrackingOptimizationSettingsHintQCOM {
     pub const LOW_POWER_PRIORIZATION: TrackingOptimizationSettingsHintQCOM = Self(3i32);
     #[doc = "Used by the application to indicate that it prefers tracking to be optimized for increased tracking performance, possibly at the cost of increased power consumption."]
     pub const HIGH_POWER_PRIORIZATION: TrackingOptimizationSettingsHintQCOM = Self(4i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3103,7 +3102,7 @@ impl fmt::Debug for TrackingOptimizationSettingsHintQCOM {
 }
 #[doc = "See [XrForceFeedbackCurlLocationMNDX](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrForceFeedbackCurlLocationMNDX)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ForceFeedbackCurlLocationMNDX(i32);
 impl ForceFeedbackCurlLocationMNDX {
     #[doc = "force feedback for thumb curl"]
@@ -3116,7 +3115,7 @@ impl ForceFeedbackCurlLocationMNDX {
     pub const RING_CURL: ForceFeedbackCurlLocationMNDX = Self(3i32);
     #[doc = "force feedback for little finger curl"]
     pub const LITTLE_CURL: ForceFeedbackCurlLocationMNDX = Self(4i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3138,7 +3137,7 @@ impl fmt::Debug for ForceFeedbackCurlLocationMNDX {
 }
 #[doc = "See [XrPlaneDetectionStateEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPlaneDetectionStateEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PlaneDetectionStateEXT(i32);
 impl PlaneDetectionStateEXT {
     pub const NONE: PlaneDetectionStateEXT = Self(0i32);
@@ -3146,7 +3145,7 @@ impl PlaneDetectionStateEXT {
     pub const DONE: PlaneDetectionStateEXT = Self(2i32);
     pub const ERROR: PlaneDetectionStateEXT = Self(3i32);
     pub const FATAL: PlaneDetectionStateEXT = Self(4i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3168,7 +3167,7 @@ impl fmt::Debug for PlaneDetectionStateEXT {
 }
 #[doc = "See [XrPlaneDetectorOrientationEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPlaneDetectorOrientationEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PlaneDetectorOrientationEXT(i32);
 impl PlaneDetectorOrientationEXT {
     #[doc = "The detected plane is horizontal and faces upward (e.g. floor)."]
@@ -3179,7 +3178,7 @@ impl PlaneDetectorOrientationEXT {
     pub const VERTICAL: PlaneDetectorOrientationEXT = Self(2i32);
     #[doc = "The detected plane has an arbitrary, non-vertical and non-horizontal orientation."]
     pub const ARBITRARY: PlaneDetectorOrientationEXT = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3200,7 +3199,7 @@ impl fmt::Debug for PlaneDetectorOrientationEXT {
 }
 #[doc = "See [XrPlaneDetectorSemanticTypeEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPlaneDetectorSemanticTypeEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PlaneDetectorSemanticTypeEXT(i32);
 impl PlaneDetectorSemanticTypeEXT {
     #[doc = "The runtime was unable to classify this plane."]
@@ -3213,7 +3212,7 @@ impl PlaneDetectorSemanticTypeEXT {
     pub const WALL: PlaneDetectorSemanticTypeEXT = Self(3i32);
     #[doc = "The detected plane is a platform, like a table."]
     pub const PLATFORM: PlaneDetectorSemanticTypeEXT = Self(4i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3235,7 +3234,7 @@ impl fmt::Debug for PlaneDetectorSemanticTypeEXT {
 }
 #[doc = "See [XrVirtualKeyboardLocationTypeMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrVirtualKeyboardLocationTypeMETA)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct VirtualKeyboardLocationTypeMETA(i32);
 impl VirtualKeyboardLocationTypeMETA {
     #[doc = "Indicates that the application will provide the position and scale of the keyboard."]
@@ -3244,7 +3243,7 @@ impl VirtualKeyboardLocationTypeMETA {
     pub const FAR: VirtualKeyboardLocationTypeMETA = Self(1i32);
     #[doc = "Indicates that the runtime will set the position and scale for direct interaction keyboard."]
     pub const DIRECT: VirtualKeyboardLocationTypeMETA = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3264,7 +3263,7 @@ impl fmt::Debug for VirtualKeyboardLocationTypeMETA {
 }
 #[doc = "See [XrVirtualKeyboardInputSourceMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrVirtualKeyboardInputSourceMETA)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct VirtualKeyboardInputSourceMETA(i32);
 impl VirtualKeyboardInputSourceMETA {
     #[doc = "Left controller ray."]
@@ -3283,7 +3282,7 @@ impl VirtualKeyboardInputSourceMETA {
     pub const HAND_DIRECT_INDEX_TIP_LEFT: VirtualKeyboardInputSourceMETA = Self(7i32);
     #[doc = "Right hand direct touch."]
     pub const HAND_DIRECT_INDEX_TIP_RIGHT: VirtualKeyboardInputSourceMETA = Self(8i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3308,7 +3307,7 @@ impl fmt::Debug for VirtualKeyboardInputSourceMETA {
 }
 #[doc = "See [XrHeadsetFitStatusML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrHeadsetFitStatusML)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct HeadsetFitStatusML(i32);
 impl HeadsetFitStatusML {
     #[doc = "Headset fit status not available for unknown reason."]
@@ -3319,7 +3318,7 @@ impl HeadsetFitStatusML {
     pub const GOOD_FIT: HeadsetFitStatusML = Self(2i32);
     #[doc = "Bad fit."]
     pub const BAD_FIT: HeadsetFitStatusML = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3340,7 +3339,7 @@ impl fmt::Debug for HeadsetFitStatusML {
 }
 #[doc = "See [XrEyeCalibrationStatusML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrEyeCalibrationStatusML)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct EyeCalibrationStatusML(i32);
 impl EyeCalibrationStatusML {
     #[doc = "Eye calibration status not available for unknown reason."]
@@ -3351,7 +3350,7 @@ impl EyeCalibrationStatusML {
     pub const COARSE: EyeCalibrationStatusML = Self(2i32);
     #[doc = "Eye calibration is of higher accuracy."]
     pub const FINE: EyeCalibrationStatusML = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3372,12 +3371,12 @@ impl fmt::Debug for EyeCalibrationStatusML {
 }
 #[doc = "See [XrHandEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrHandEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct HandEXT(i32);
 impl HandEXT {
     pub const LEFT: HandEXT = Self(1i32);
     pub const RIGHT: HandEXT = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3396,7 +3395,7 @@ impl fmt::Debug for HandEXT {
 }
 #[doc = "See [XrHandJointEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrHandJointEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct HandJointEXT(i32);
 impl HandJointEXT {
     pub const PALM: HandJointEXT = Self(0i32);
@@ -3425,7 +3424,7 @@ impl HandJointEXT {
     pub const LITTLE_INTERMEDIATE: HandJointEXT = Self(23i32);
     pub const LITTLE_DISTAL: HandJointEXT = Self(24i32);
     pub const LITTLE_TIP: HandJointEXT = Self(25i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3468,12 +3467,12 @@ impl fmt::Debug for HandJointEXT {
 }
 #[doc = "See [XrHandJointSetEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrHandJointSetEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct HandJointSetEXT(i32);
 impl HandJointSetEXT {
     pub const DEFAULT: HandJointSetEXT = Self(0i32);
     pub const HAND_WITH_FOREARM_ULTRA: HandJointSetEXT = Self(1000149000i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3492,12 +3491,12 @@ impl fmt::Debug for HandJointSetEXT {
 }
 #[doc = "See [XrHandJointsMotionRangeEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrHandJointsMotionRangeEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct HandJointsMotionRangeEXT(i32);
 impl HandJointsMotionRangeEXT {
     pub const UNOBSTRUCTED: HandJointsMotionRangeEXT = Self(1i32);
     pub const CONFORMING_TO_CONTROLLER: HandJointsMotionRangeEXT = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3516,14 +3515,14 @@ impl fmt::Debug for HandJointsMotionRangeEXT {
 }
 #[doc = "See [XrHandTrackingDataSourceEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrHandTrackingDataSourceEXT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct HandTrackingDataSourceEXT(i32);
 impl HandTrackingDataSourceEXT {
     #[doc = "This data source value indicates individual fingers and joints are tracked from unobstructed data source such as optical hand tracking, data gloves, or motion capture devices."]
     pub const UNOBSTRUCTED: HandTrackingDataSourceEXT = Self(1i32);
     #[doc = "This data source value indicates hand joints are inferred based on motion controller state."]
     pub const CONTROLLER: HandTrackingDataSourceEXT = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3542,12 +3541,12 @@ impl fmt::Debug for HandTrackingDataSourceEXT {
 }
 #[doc = "See [XrHandPoseTypeMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrHandPoseTypeMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct HandPoseTypeMSFT(i32);
 impl HandPoseTypeMSFT {
     pub const TRACKED: HandPoseTypeMSFT = Self(0i32);
     pub const REFERENCE_OPEN_PALM: HandPoseTypeMSFT = Self(1i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3566,7 +3565,7 @@ impl fmt::Debug for HandPoseTypeMSFT {
 }
 #[doc = "See [XrSceneObjectTypeMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneObjectTypeMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SceneObjectTypeMSFT(i32);
 impl SceneObjectTypeMSFT {
     pub const UNCATEGORIZED: SceneObjectTypeMSFT = Self(-1i32);
@@ -3576,7 +3575,7 @@ impl SceneObjectTypeMSFT {
     pub const CEILING: SceneObjectTypeMSFT = Self(4i32);
     pub const PLATFORM: SceneObjectTypeMSFT = Self(5i32);
     pub const INFERRED: SceneObjectTypeMSFT = Self(6i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3600,13 +3599,13 @@ impl fmt::Debug for SceneObjectTypeMSFT {
 }
 #[doc = "See [XrScenePlaneAlignmentTypeMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrScenePlaneAlignmentTypeMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ScenePlaneAlignmentTypeMSFT(i32);
 impl ScenePlaneAlignmentTypeMSFT {
     pub const NON_ORTHOGONAL: ScenePlaneAlignmentTypeMSFT = Self(0i32);
     pub const HORIZONTAL: ScenePlaneAlignmentTypeMSFT = Self(1i32);
     pub const VERTICAL: ScenePlaneAlignmentTypeMSFT = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3626,14 +3625,14 @@ impl fmt::Debug for ScenePlaneAlignmentTypeMSFT {
 }
 #[doc = "See [XrSceneComputeStateMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneComputeStateMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SceneComputeStateMSFT(i32);
 impl SceneComputeStateMSFT {
     pub const NONE: SceneComputeStateMSFT = Self(0i32);
     pub const UPDATING: SceneComputeStateMSFT = Self(1i32);
     pub const COMPLETED: SceneComputeStateMSFT = Self(2i32);
     pub const COMPLETED_WITH_ERROR: SceneComputeStateMSFT = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3654,7 +3653,7 @@ impl fmt::Debug for SceneComputeStateMSFT {
 }
 #[doc = "See [XrSceneComputeFeatureMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneComputeFeatureMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SceneComputeFeatureMSFT(i32);
 impl SceneComputeFeatureMSFT {
     pub const PLANE: SceneComputeFeatureMSFT = Self(1i32);
@@ -3663,7 +3662,7 @@ impl SceneComputeFeatureMSFT {
     pub const COLLIDER_MESH: SceneComputeFeatureMSFT = Self(4i32);
     pub const SERIALIZE_SCENE: SceneComputeFeatureMSFT = Self(1000098000i32);
     pub const MARKER: SceneComputeFeatureMSFT = Self(1000147000i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3686,13 +3685,13 @@ impl fmt::Debug for SceneComputeFeatureMSFT {
 }
 #[doc = "See [XrSceneComputeConsistencyMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneComputeConsistencyMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SceneComputeConsistencyMSFT(i32);
 impl SceneComputeConsistencyMSFT {
     pub const SNAPSHOT_COMPLETE: SceneComputeConsistencyMSFT = Self(1i32);
     pub const SNAPSHOT_INCOMPLETE_FAST: SceneComputeConsistencyMSFT = Self(2i32);
     pub const OCCLUSION_OPTIMIZED: SceneComputeConsistencyMSFT = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3712,7 +3711,7 @@ impl fmt::Debug for SceneComputeConsistencyMSFT {
 }
 #[doc = "See [XrSceneComponentTypeMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneComponentTypeMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SceneComponentTypeMSFT(i32);
 impl SceneComponentTypeMSFT {
     pub const INVALID: SceneComponentTypeMSFT = Self(-1i32);
@@ -3722,7 +3721,7 @@ impl SceneComponentTypeMSFT {
     pub const COLLIDER_MESH: SceneComponentTypeMSFT = Self(4i32);
     pub const SERIALIZED_SCENE_FRAGMENT: SceneComponentTypeMSFT = Self(1000098000i32);
     pub const MARKER: SceneComponentTypeMSFT = Self(1000147000i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3746,14 +3745,14 @@ impl fmt::Debug for SceneComponentTypeMSFT {
 }
 #[doc = "See [XrMeshComputeLodMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrMeshComputeLodMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct MeshComputeLodMSFT(i32);
 impl MeshComputeLodMSFT {
     pub const COARSE: MeshComputeLodMSFT = Self(1i32);
     pub const MEDIUM: MeshComputeLodMSFT = Self(2i32);
     pub const FINE: MeshComputeLodMSFT = Self(3i32);
     pub const UNLIMITED: MeshComputeLodMSFT = Self(4i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3774,11 +3773,11 @@ impl fmt::Debug for MeshComputeLodMSFT {
 }
 #[doc = "See [XrSceneMarkerTypeMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneMarkerTypeMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SceneMarkerTypeMSFT(i32);
 impl SceneMarkerTypeMSFT {
     pub const QR_CODE: SceneMarkerTypeMSFT = Self(1i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3796,12 +3795,12 @@ impl fmt::Debug for SceneMarkerTypeMSFT {
 }
 #[doc = "See [XrSceneMarkerQRCodeSymbolTypeMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneMarkerQRCodeSymbolTypeMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SceneMarkerQRCodeSymbolTypeMSFT(i32);
 impl SceneMarkerQRCodeSymbolTypeMSFT {
     pub const QR_CODE: SceneMarkerQRCodeSymbolTypeMSFT = Self(1i32);
     pub const MICRO_QR_CODE: SceneMarkerQRCodeSymbolTypeMSFT = Self(2i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3820,7 +3819,7 @@ impl fmt::Debug for SceneMarkerQRCodeSymbolTypeMSFT {
 }
 #[doc = "See [XrColorSpaceFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrColorSpaceFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ColorSpaceFB(i32);
 impl ColorSpaceFB {
     pub const UNMANAGED: ColorSpaceFB = Self(0i32);
@@ -3831,7 +3830,7 @@ impl ColorSpaceFB {
     pub const QUEST: ColorSpaceFB = Self(5i32);
     pub const P3: ColorSpaceFB = Self(6i32);
     pub const ADOBE_RGB: ColorSpaceFB = Self(7i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3856,7 +3855,7 @@ impl fmt::Debug for ColorSpaceFB {
 }
 #[doc = "See [XrFoveationLevelFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFoveationLevelFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FoveationLevelFB(i32);
 impl FoveationLevelFB {
     #[doc = "No foveation"]
@@ -3867,7 +3866,7 @@ impl FoveationLevelFB {
     pub const MEDIUM: FoveationLevelFB = Self(2i32);
     #[doc = "High foveation (lower periphery visual fidelity, higher performance)"]
     pub const HIGH: FoveationLevelFB = Self(3i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3888,14 +3887,14 @@ impl fmt::Debug for FoveationLevelFB {
 }
 #[doc = "See [XrFoveationDynamicFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFoveationDynamicFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FoveationDynamicFB(i32);
 impl FoveationDynamicFB {
     #[doc = "Static foveation at the maximum desired level"]
     pub const DISABLED: FoveationDynamicFB = Self(0i32);
     #[doc = "Dynamic changing foveation based on performance headroom available up to the maximum desired level"]
     pub const LEVEL_ENABLED: FoveationDynamicFB = Self(1i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3914,14 +3913,14 @@ impl fmt::Debug for FoveationDynamicFB {
 }
 #[doc = "See [XrReprojectionModeMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrReprojectionModeMSFT)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ReprojectionModeMSFT(i32);
 impl ReprojectionModeMSFT {
     pub const DEPTH: ReprojectionModeMSFT = Self(1i32);
     pub const PLANAR_FROM_DEPTH: ReprojectionModeMSFT = Self(2i32);
     pub const PLANAR_MANUAL: ReprojectionModeMSFT = Self(3i32);
     pub const ORIENTATION_ONLY: ReprojectionModeMSFT = Self(4i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -3942,7 +3941,7 @@ impl fmt::Debug for ReprojectionModeMSFT {
 }
 #[doc = "See [XrHandForearmJointULTRALEAP](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrHandForearmJointULTRALEAP)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct HandForearmJointULTRALEAP(i32);
 impl HandForearmJointULTRALEAP {
     pub const PALM: HandForearmJointULTRALEAP = Self(0i32);
@@ -3972,7 +3971,7 @@ impl HandForearmJointULTRALEAP {
     pub const LITTLE_DISTAL: HandForearmJointULTRALEAP = Self(24i32);
     pub const LITTLE_TIP: HandForearmJointULTRALEAP = Self(25i32);
     pub const ELBOW: HandForearmJointULTRALEAP = Self(26i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -4016,7 +4015,7 @@ impl fmt::Debug for HandForearmJointULTRALEAP {
 }
 #[doc = "See [XrCompareOpFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrCompareOpFB)"]
 #[repr(transparent)]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct CompareOpFB(i32);
 impl CompareOpFB {
     #[doc = "Comparison is never true."]
@@ -4035,7 +4034,7 @@ impl CompareOpFB {
     pub const GREATER_OR_EQUAL: CompareOpFB = Self(6i32);
     #[doc = "Comparison is always true."]
     pub const ALWAYS: CompareOpFB = Self(7i32);
-    pub fn from_raw(x: i32) -> Self {
+    pub const fn from_raw(x: i32) -> Self {
         Self(x)
     }
     pub fn into_raw(self) -> i32 {
@@ -4060,19 +4059,19 @@ impl fmt::Debug for CompareOpFB {
 }
 #[doc = "See [XrInstanceCreateFlagBits](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrInstanceCreateFlagBits)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct InstanceCreateFlags(u64);
 impl InstanceCreateFlags {}
 bitmask!(InstanceCreateFlags);
 #[doc = "See [XrSessionCreateFlagBits](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSessionCreateFlagBits)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SessionCreateFlags(u64);
 impl SessionCreateFlags {}
 bitmask!(SessionCreateFlags);
 #[doc = "See [XrSwapchainCreateFlagBits](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSwapchainCreateFlagBits)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SwapchainCreateFlags(u64);
 impl SwapchainCreateFlags {
     #[doc = "Content will be protected from CPU access"]
@@ -4083,7 +4082,7 @@ impl SwapchainCreateFlags {
 bitmask!(SwapchainCreateFlags);
 #[doc = "See [XrSwapchainUsageFlagBits](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSwapchainUsageFlagBits)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SwapchainUsageFlags(u64);
 impl SwapchainUsageFlags {
     #[doc = "Specifies that the image may: be a color rendering target."]
@@ -4106,7 +4105,7 @@ impl SwapchainUsageFlags {
 bitmask!(SwapchainUsageFlags);
 #[doc = "See [XrViewStateFlagBits](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrViewStateFlagBits)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ViewStateFlags(u64);
 impl ViewStateFlags {
     #[doc = "Indicates validity of all XrView orientations"]
@@ -4121,7 +4120,7 @@ impl ViewStateFlags {
 bitmask!(ViewStateFlags);
 #[doc = "See [XrCompositionLayerFlagBits](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrCompositionLayerFlagBits)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct CompositionLayerFlags(u64);
 impl CompositionLayerFlags {
     #[doc = "Enables chromatic aberration correction when not done by default. This flag has no effect on any known conformant runtime, and is planned for deprecation for OpenXR 1.1"]
@@ -4134,7 +4133,7 @@ impl CompositionLayerFlags {
 bitmask!(CompositionLayerFlags);
 #[doc = "See [XrSpaceLocationFlagBits](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpaceLocationFlagBits)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SpaceLocationFlags(u64);
 impl SpaceLocationFlags {
     #[doc = "Indicates that the orientation member contains valid data"]
@@ -4149,7 +4148,7 @@ impl SpaceLocationFlags {
 bitmask!(SpaceLocationFlags);
 #[doc = "See [XrSpaceVelocityFlagBits](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpaceVelocityFlagBits)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SpaceVelocityFlags(u64);
 impl SpaceVelocityFlags {
     #[doc = "Indicates that the linearVelocity member contains valid data. Applications must: not read the linearVelocity field if this flag is unset."]
@@ -4160,7 +4159,7 @@ impl SpaceVelocityFlags {
 bitmask!(SpaceVelocityFlags);
 #[doc = "See [XrInputSourceLocalizedNameFlagBits](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrInputSourceLocalizedNameFlagBits)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct InputSourceLocalizedNameFlags(u64);
 impl InputSourceLocalizedNameFlags {
     #[doc = "Asks for the part of the string which indicates the top level user path the source represents"]
@@ -4173,19 +4172,19 @@ impl InputSourceLocalizedNameFlags {
 bitmask!(InputSourceLocalizedNameFlags);
 #[doc = "See [XrVulkanInstanceCreateFlagsKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrVulkanInstanceCreateFlagsKHR)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct VulkanInstanceCreateFlagsKHR(u64);
 impl VulkanInstanceCreateFlagsKHR {}
 bitmask!(VulkanInstanceCreateFlagsKHR);
 #[doc = "See [XrVulkanDeviceCreateFlagsKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrVulkanDeviceCreateFlagsKHR)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct VulkanDeviceCreateFlagsKHR(u64);
 impl VulkanDeviceCreateFlagsKHR {}
 bitmask!(VulkanDeviceCreateFlagsKHR);
 #[doc = "See [XrDebugUtilsMessageSeverityFlagsEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrDebugUtilsMessageSeverityFlagsEXT)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct DebugUtilsMessageSeverityFlagsEXT(u64);
 impl DebugUtilsMessageSeverityFlagsEXT {
     #[doc = "Most verbose output severity, typically used for debugging."]
@@ -4200,7 +4199,7 @@ impl DebugUtilsMessageSeverityFlagsEXT {
 bitmask!(DebugUtilsMessageSeverityFlagsEXT);
 #[doc = "See [XrDebugUtilsMessageTypeFlagsEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrDebugUtilsMessageTypeFlagsEXT)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct DebugUtilsMessageTypeFlagsEXT(u64);
 impl DebugUtilsMessageTypeFlagsEXT {
     #[doc = "Indicates this is a general message"]
@@ -4215,7 +4214,7 @@ impl DebugUtilsMessageTypeFlagsEXT {
 bitmask!(DebugUtilsMessageTypeFlagsEXT);
 #[doc = "See [XrOverlayMainSessionFlagsEXTX](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrOverlayMainSessionFlagsEXTX)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct OverlayMainSessionFlagsEXTX(u64);
 impl OverlayMainSessionFlagsEXTX {
     #[doc = "Indicates the main session enabled `XR_KHR_composition_layer_depth`"]
@@ -4224,13 +4223,13 @@ impl OverlayMainSessionFlagsEXTX {
 bitmask!(OverlayMainSessionFlagsEXTX);
 #[doc = "See [XrOverlaySessionCreateFlagsEXTX](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrOverlaySessionCreateFlagsEXTX)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct OverlaySessionCreateFlagsEXTX(u64);
 impl OverlaySessionCreateFlagsEXTX {}
 bitmask!(OverlaySessionCreateFlagsEXTX);
 #[doc = "See [XrAndroidSurfaceSwapchainFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrAndroidSurfaceSwapchainFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct AndroidSurfaceSwapchainFlagsFB(u64);
 impl AndroidSurfaceSwapchainFlagsFB {
     #[doc = "Create the underlying BufferQueue in synchronous mode"]
@@ -4241,7 +4240,7 @@ impl AndroidSurfaceSwapchainFlagsFB {
 bitmask!(AndroidSurfaceSwapchainFlagsFB);
 #[doc = "See [XrCompositionLayerImageLayoutFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrCompositionLayerImageLayoutFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct CompositionLayerImageLayoutFlagsFB(u64);
 impl CompositionLayerImageLayoutFlagsFB {
     #[doc = "The coordinate origin of the swapchain image must be considered to be flipped vertically."]
@@ -4250,7 +4249,7 @@ impl CompositionLayerImageLayoutFlagsFB {
 bitmask!(CompositionLayerImageLayoutFlagsFB);
 #[doc = "See [XrCompositionLayerSecureContentFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrCompositionLayerSecureContentFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct CompositionLayerSecureContentFlagsFB(u64);
 impl CompositionLayerSecureContentFlagsFB {
     #[doc = "Indicates the layer will only be visible inside the HMD, and not visible to external sources"]
@@ -4261,7 +4260,7 @@ impl CompositionLayerSecureContentFlagsFB {
 bitmask!(CompositionLayerSecureContentFlagsFB);
 #[doc = "See [XrSwapchainCreateFoveationFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSwapchainCreateFoveationFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SwapchainCreateFoveationFlagsFB(u64);
 impl SwapchainCreateFoveationFlagsFB {
     #[doc = "Explicitly create the swapchain with scaled bin foveation support. The application must ensure that the swapchain is using the OpenGL graphics API and that the QCOM_texture_foveated extension is supported and enabled."]
@@ -4272,19 +4271,19 @@ impl SwapchainCreateFoveationFlagsFB {
 bitmask!(SwapchainCreateFoveationFlagsFB);
 #[doc = "See [XrSwapchainStateFoveationFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSwapchainStateFoveationFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SwapchainStateFoveationFlagsFB(u64);
 impl SwapchainStateFoveationFlagsFB {}
 bitmask!(SwapchainStateFoveationFlagsFB);
 #[doc = "See [XrFoveationEyeTrackedProfileCreateFlagsMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFoveationEyeTrackedProfileCreateFlagsMETA)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FoveationEyeTrackedProfileCreateFlagsMETA(u64);
 impl FoveationEyeTrackedProfileCreateFlagsMETA {}
 bitmask!(FoveationEyeTrackedProfileCreateFlagsMETA);
 #[doc = "See [XrFoveationEyeTrackedStateFlagsMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFoveationEyeTrackedStateFlagsMETA)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FoveationEyeTrackedStateFlagsMETA(u64);
 impl FoveationEyeTrackedStateFlagsMETA {
     #[doc = "Indicates whether or not foveation data is valid. This can happen if the eye tracker is obscured, the camera has dirt, or eye lid is closed, etc."]
@@ -4293,7 +4292,7 @@ impl FoveationEyeTrackedStateFlagsMETA {
 bitmask!(FoveationEyeTrackedStateFlagsMETA);
 #[doc = "See [XrTriangleMeshFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrTriangleMeshFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct TriangleMeshFlagsFB(u64);
 impl TriangleMeshFlagsFB {
     #[doc = "The triangle mesh is mutable (can be modified after it is created)."]
@@ -4302,7 +4301,7 @@ impl TriangleMeshFlagsFB {
 bitmask!(TriangleMeshFlagsFB);
 #[doc = "See [XrPassthroughFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPassthroughFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PassthroughFlagsFB(u64);
 impl PassthroughFlagsFB {
     #[doc = "The object (passthrough, layer) is running at creation."]
@@ -4313,7 +4312,7 @@ impl PassthroughFlagsFB {
 bitmask!(PassthroughFlagsFB);
 #[doc = "See [XrPassthroughStateChangedFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPassthroughStateChangedFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PassthroughStateChangedFlagsFB(u64);
 impl PassthroughStateChangedFlagsFB {
     #[doc = "Passthrough system requires reinitialization."]
@@ -4328,7 +4327,7 @@ impl PassthroughStateChangedFlagsFB {
 bitmask!(PassthroughStateChangedFlagsFB);
 #[doc = "See [XrPassthroughCapabilityFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPassthroughCapabilityFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PassthroughCapabilityFlagsFB(u64);
 impl PassthroughCapabilityFlagsFB {
     #[doc = "The system supports passthrough."]
@@ -4341,7 +4340,7 @@ impl PassthroughCapabilityFlagsFB {
 bitmask!(PassthroughCapabilityFlagsFB);
 #[doc = "See [XrSemanticLabelsSupportFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSemanticLabelsSupportFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SemanticLabelsSupportFlagsFB(u64);
 impl SemanticLabelsSupportFlagsFB {
     #[doc = "If set, and the runtime reports the extensionVersion as 2 or greater, the runtime may: return multiple semantic labels separated by a comma without spaces. Otherwise, the runtime must: return a single semantic label."]
@@ -4352,7 +4351,7 @@ impl SemanticLabelsSupportFlagsFB {
 bitmask!(SemanticLabelsSupportFlagsFB);
 #[doc = "See [XrHandTrackingAimFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrHandTrackingAimFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct HandTrackingAimFlagsFB(u64);
 impl HandTrackingAimFlagsFB {
     #[doc = "Aiming data is computed from additional sources beyond the hand data in the base structure"]
@@ -4377,7 +4376,7 @@ impl HandTrackingAimFlagsFB {
 bitmask!(HandTrackingAimFlagsFB);
 #[doc = "See [XrKeyboardTrackingFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrKeyboardTrackingFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct KeyboardTrackingFlagsFB(u64);
 impl KeyboardTrackingFlagsFB {
     #[doc = "indicates that the system has a physically tracked keyboard to report.  If not set then no other bits should be considered to be valid or meaningful.  If set either XR_KEYBOARD_TRACKING_LOCAL_BIT_FB or XR_KEYBOARD_TRACKING_REMOTE_BIT_FB must also be set."]
@@ -4392,7 +4391,7 @@ impl KeyboardTrackingFlagsFB {
 bitmask!(KeyboardTrackingFlagsFB);
 #[doc = "See [XrKeyboardTrackingQueryFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrKeyboardTrackingQueryFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct KeyboardTrackingQueryFlagsFB(u64);
 impl KeyboardTrackingQueryFlagsFB {
     #[doc = "indicates the query is for the physically tracked keyboard that is intended to be used in a local pairing with the System. Mutally exclusive with XR_KEYBOARD_TRACKING_QUERY_REMOTE_BIT_FB."]
@@ -4403,7 +4402,7 @@ impl KeyboardTrackingQueryFlagsFB {
 bitmask!(KeyboardTrackingQueryFlagsFB);
 #[doc = "See [XrCompositionLayerSpaceWarpInfoFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrCompositionLayerSpaceWarpInfoFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct CompositionLayerSpaceWarpInfoFlagsFB(u64);
 impl CompositionLayerSpaceWarpInfoFlagsFB {
     #[doc = "Skip current frame's space warp extrapolation"]
@@ -4412,7 +4411,7 @@ impl CompositionLayerSpaceWarpInfoFlagsFB {
 bitmask!(CompositionLayerSpaceWarpInfoFlagsFB);
 #[doc = "See [XrRenderModelFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrRenderModelFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct RenderModelFlagsFB(u64);
 impl RenderModelFlagsFB {
     #[doc = "Minimal level of support.  Can only contain a single mesh.  Can only contain a single texture.  Can not contain transparency.  Assumes unlit rendering.  Requires Extension KHR_texturebasisu."]
@@ -4423,7 +4422,7 @@ impl RenderModelFlagsFB {
 bitmask!(RenderModelFlagsFB);
 #[doc = "See [XrDigitalLensControlFlagsALMALENCE](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrDigitalLensControlFlagsALMALENCE)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct DigitalLensControlFlagsALMALENCE(u64);
 impl DigitalLensControlFlagsALMALENCE {
     #[doc = "disables Digital Lens processing of render textures"]
@@ -4432,7 +4431,7 @@ impl DigitalLensControlFlagsALMALENCE {
 bitmask!(DigitalLensControlFlagsALMALENCE);
 #[doc = "See [XrCompositionLayerSettingsFlagsFB](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrCompositionLayerSettingsFlagsFB)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct CompositionLayerSettingsFlagsFB(u64);
 impl CompositionLayerSettingsFlagsFB {
     #[doc = "Indicates compositor may: use layer texture supersampling."]
@@ -4447,7 +4446,7 @@ impl CompositionLayerSettingsFlagsFB {
 bitmask!(CompositionLayerSettingsFlagsFB);
 #[doc = "See [XrExternalCameraStatusFlagsOCULUS](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrExternalCameraStatusFlagsOCULUS)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ExternalCameraStatusFlagsOCULUS(u64);
 impl ExternalCameraStatusFlagsOCULUS {
     #[doc = "External camera is connected"]
@@ -4464,7 +4463,7 @@ impl ExternalCameraStatusFlagsOCULUS {
 bitmask!(ExternalCameraStatusFlagsOCULUS);
 #[doc = "See [XrPerformanceMetricsCounterFlagsMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPerformanceMetricsCounterFlagsMETA)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PerformanceMetricsCounterFlagsMETA(u64);
 impl PerformanceMetricsCounterFlagsMETA {
     #[doc = "Indicates any of the values in XrPerformanceMetricsCounterMETA is valid."]
@@ -4477,7 +4476,7 @@ impl PerformanceMetricsCounterFlagsMETA {
 bitmask!(PerformanceMetricsCounterFlagsMETA);
 #[doc = "See [XrPassthroughPreferenceFlagsMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPassthroughPreferenceFlagsMETA)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PassthroughPreferenceFlagsMETA(u64);
 impl PassthroughPreferenceFlagsMETA {
     #[doc = "Indicates that the runtime recommends apps to default to a mixed reality experience with passthrough (if supported)."]
@@ -4486,7 +4485,7 @@ impl PassthroughPreferenceFlagsMETA {
 bitmask!(PassthroughPreferenceFlagsMETA);
 #[doc = "See [XrFoveationDynamicFlagsHTC](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFoveationDynamicFlagsHTC)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FoveationDynamicFlagsHTC(u64);
 impl FoveationDynamicFlagsHTC {
     #[doc = "Allow system to set periphery pixel density dynamically."]
@@ -4499,7 +4498,7 @@ impl FoveationDynamicFlagsHTC {
 bitmask!(FoveationDynamicFlagsHTC);
 #[doc = "See [XrFrameEndInfoFlagsML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFrameEndInfoFlagsML)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FrameEndInfoFlagsML(u64);
 impl FrameEndInfoFlagsML {
     #[doc = "Indicates that the content for this frame is protected and should not be recorded or captured outside the graphics system."]
@@ -4510,7 +4509,7 @@ impl FrameEndInfoFlagsML {
 bitmask!(FrameEndInfoFlagsML);
 #[doc = "See [XrGlobalDimmerFrameEndInfoFlagsML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrGlobalDimmerFrameEndInfoFlagsML)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct GlobalDimmerFrameEndInfoFlagsML(u64);
 impl GlobalDimmerFrameEndInfoFlagsML {
     #[doc = "Indicates that the global dimmer should: be enabled and controlled by dimmerValue."]
@@ -4519,7 +4518,7 @@ impl GlobalDimmerFrameEndInfoFlagsML {
 bitmask!(GlobalDimmerFrameEndInfoFlagsML);
 #[doc = "See [XrPlaneDetectorFlagsEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPlaneDetectorFlagsEXT)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PlaneDetectorFlagsEXT(u64);
 impl PlaneDetectorFlagsEXT {
     #[doc = "populate the plane contour information"]
@@ -4528,7 +4527,7 @@ impl PlaneDetectorFlagsEXT {
 bitmask!(PlaneDetectorFlagsEXT);
 #[doc = "See [XrPlaneDetectionCapabilityFlagsEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrPlaneDetectionCapabilityFlagsEXT)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PlaneDetectionCapabilityFlagsEXT(u64);
 impl PlaneDetectionCapabilityFlagsEXT {
     #[doc = "plane detection is supported"]
@@ -4549,7 +4548,7 @@ impl PlaneDetectionCapabilityFlagsEXT {
 bitmask!(PlaneDetectionCapabilityFlagsEXT);
 #[doc = "See [XrVirtualKeyboardInputStateFlagsMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrVirtualKeyboardInputStateFlagsMETA)"]
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct VirtualKeyboardInputStateFlagsMETA(u64);
 impl VirtualKeyboardInputStateFlagsMETA {
     #[doc = "If the input source is considered 'pressed' at all. Pinch for hands, Primary button for controllers."]
@@ -8503,6 +8502,18 @@ pub struct SpaceContainerFB {
 }
 impl SpaceContainerFB {
     pub const TYPE: StructureType = StructureType::SPACE_CONTAINER_FB;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> MaybeUninit<Self> {
+        let mut x = MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
 }
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
@@ -8539,6 +8550,18 @@ pub struct SemanticLabelsFB {
 }
 impl SemanticLabelsFB {
     pub const TYPE: StructureType = StructureType::SEMANTIC_LABELS_FB;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> MaybeUninit<Self> {
+        let mut x = MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
 }
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
@@ -8554,6 +8577,18 @@ pub struct RoomLayoutFB {
 }
 impl RoomLayoutFB {
     pub const TYPE: StructureType = StructureType::ROOM_LAYOUT_FB;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> MaybeUninit<Self> {
+        let mut x = MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
 }
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
@@ -8567,6 +8602,18 @@ pub struct Boundary2DFB {
 }
 impl Boundary2DFB {
     pub const TYPE: StructureType = StructureType::BOUNDARY_2D_FB;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> MaybeUninit<Self> {
+        let mut x = MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
 }
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
@@ -9040,6 +9087,18 @@ pub struct FacialExpressionsHTC {
 }
 impl FacialExpressionsHTC {
     pub const TYPE: StructureType = StructureType::FACIAL_EXPRESSIONS_HTC;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> MaybeUninit<Self> {
+        let mut x = MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
 }
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
@@ -9292,6 +9351,18 @@ pub struct ExternalCameraOCULUS {
 }
 impl ExternalCameraOCULUS {
     pub const TYPE: StructureType = StructureType::EXTERNAL_CAMERA_OCULUS;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> MaybeUninit<Self> {
+        let mut x = MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
 }
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
@@ -9317,6 +9388,18 @@ pub struct PerformanceMetricsCounterMETA {
 }
 impl PerformanceMetricsCounterMETA {
     pub const TYPE: StructureType = StructureType::PERFORMANCE_METRICS_COUNTER_META;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> MaybeUninit<Self> {
+        let mut x = MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
 }
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
@@ -9328,6 +9411,18 @@ pub struct PassthroughPreferencesMETA {
 }
 impl PassthroughPreferencesMETA {
     pub const TYPE: StructureType = StructureType::PASSTHROUGH_PREFERENCES_META;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> MaybeUninit<Self> {
+        let mut x = MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
 }
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
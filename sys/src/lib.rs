@@ -1,18 +1,26 @@
-use std::{convert::TryFrom, fmt};
+use std::{
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+};
 
 #[macro_use]
 mod support;
 mod generated;
 pub mod loader;
+mod manual;
+mod math;
 pub mod platform;
 
 #[cfg(feature = "mint")]
 mod mint_impls;
+#[cfg(feature = "serde")]
+mod serde_impls;
 
 // Hand-written bindings for cases which are too few or weird to bother automating
 
 wrapper! {
-    #[derive(Copy, Clone, Eq, PartialEq, Default)]
+    #[derive(Copy, Clone, Eq, PartialEq, Default, Hash)]
     Bool32(u32)
 }
 pub const TRUE: Bool32 = Bool32(1);
@@ -41,7 +49,7 @@ impl From<bool> for Bool32 {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[repr(transparent)]
 pub struct Time(i64);
 impl Time {
@@ -68,7 +76,7 @@ impl std::ops::Sub<Time> for Time {
     }
 }
 
-#[derive(Copy, Clone, Default, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[repr(transparent)]
 pub struct Duration(i64);
 impl Duration {
@@ -207,6 +215,7 @@ impl Posef {
 pub const HAND_JOINT_COUNT_EXT: u32 = 26;
 
 pub use generated::*;
+pub use manual::*;
 
 impl<T> std::ops::Index<HandJointEXT> for [T] {
     type Output = T;
@@ -221,10 +230,47 @@ impl<T> std::ops::IndexMut<HandJointEXT> for [T] {
     }
 }
 
+// Generated types containing only integers, so that they can be used as keys
+impl Eq for Offset2Di {}
+impl Eq for Extent2Di {}
+impl Eq for Rect2Di {}
+
+impl Hash for Offset2Di {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.x, self.y).hash(state);
+    }
+}
+
+impl Hash for Extent2Di {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.width, self.height).hash(state);
+    }
+}
+
+impl Hash for Rect2Di {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.offset, self.extent).hash(state);
+    }
+}
+
+// Arrays aren't given comparisons by the generator
+impl PartialEq for UuidEXT {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+impl Eq for UuidEXT {}
+
+impl Hash for UuidEXT {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
 type DevicePcmSampleRateGetInfoFB = DevicePcmSampleRateStateFB;
 
 // Magic Leap
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[repr(C)]
 pub struct MLCoordinateFrameUID {
     pub data: [u64; 2],
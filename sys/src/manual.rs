@@ -0,0 +1,1199 @@
+//! Hand-written bindings for extensions the generator doesn't cover
+//!
+//! Everything here mirrors the layout given in the OpenXR registry. Definitions should be removed
+//! once the generator emits them.
+use std::{
+    fmt,
+    os::raw::{c_char, c_void},
+};
+
+use crate::support::fmt_enum;
+
+use crate::*;
+
+//
+// Values of generated enumerations defined by the extensions below. The generated `Debug` and
+// `Display` impls fall back to the names given here.
+//
+
+/// Define values of [`StructureType`] and name them
+macro_rules! structure_types {
+    ($($name:ident = $value:expr,)*) => {
+        impl StructureType {
+            $(pub const $name: StructureType = StructureType::from_raw($value);)*
+        }
+
+        pub(crate) fn structure_type_name(x: StructureType) -> Option<&'static str> {
+            Some(match x {
+                $(StructureType::$name => stringify!($name),)*
+                _ => return None,
+            })
+        }
+    };
+}
+
+/// Define values of [`Result`] and name and describe them
+macro_rules! results {
+    ($(#[doc = $doc:literal] $name:ident = $value:expr,)*) => {
+        impl Result {
+            $(#[doc = $doc] pub const $name: Result = Result::from_raw($value);)*
+        }
+
+        /// The name and description of `x`
+        fn result_info(x: Result) -> Option<(&'static str, &'static str)> {
+            Some(match x {
+                $(Result::$name => (stringify!($name), $doc),)*
+                _ => return None,
+            })
+        }
+    };
+}
+
+structure_types! {
+    // XR_KHR_metal_enable
+    GRAPHICS_BINDING_METAL_KHR = 1000029000,
+    SWAPCHAIN_IMAGE_METAL_KHR = 1000029001,
+    GRAPHICS_REQUIREMENTS_METAL_KHR = 1000029002,
+    // XR_ML_localization_map
+    LOCALIZATION_MAP_ML = 1000139000,
+    EVENT_DATA_LOCALIZATION_CHANGED_ML = 1000139001,
+    MAP_LOCALIZATION_REQUEST_INFO_ML = 1000139002,
+    LOCALIZATION_MAP_IMPORT_INFO_ML = 1000139003,
+    LOCALIZATION_ENABLE_EVENTS_INFO_ML = 1000139004,
+    // XR_ML_spatial_anchors
+    SPATIAL_ANCHORS_CREATE_INFO_FROM_POSE_ML = 1000140000,
+    CREATE_SPATIAL_ANCHORS_COMPLETION_ML = 1000140001,
+    SPATIAL_ANCHOR_STATE_ML = 1000140002,
+    // XR_ML_spatial_anchors_storage
+    SPATIAL_ANCHORS_CREATE_STORAGE_INFO_ML = 1000141000,
+    SPATIAL_ANCHORS_QUERY_INFO_RADIUS_ML = 1000141001,
+    SPATIAL_ANCHORS_QUERY_COMPLETION_ML = 1000141002,
+    SPATIAL_ANCHORS_CREATE_INFO_FROM_UUIDS_ML = 1000141003,
+    SPATIAL_ANCHORS_PUBLISH_INFO_ML = 1000141004,
+    SPATIAL_ANCHORS_PUBLISH_COMPLETION_ML = 1000141005,
+    SPATIAL_ANCHORS_DELETE_INFO_ML = 1000141006,
+    SPATIAL_ANCHORS_DELETE_COMPLETION_ML = 1000141007,
+    // XR_EXT_future
+    FUTURE_CANCEL_INFO_EXT = 1000469000,
+    FUTURE_POLL_INFO_EXT = 1000469001,
+    FUTURE_COMPLETION_EXT = 1000469002,
+    FUTURE_POLL_RESULT_EXT = 1000469003,
+    // XR_EXT_user_presence
+    EVENT_DATA_USER_PRESENCE_CHANGED_EXT = 1000470000,
+    SYSTEM_USER_PRESENCE_PROPERTIES_EXT = 1000470001,
+    // XR_KHR_locate_spaces
+    SPACES_LOCATE_INFO_KHR = 1000471000,
+    SPACE_LOCATIONS_KHR = 1000471001,
+    SPACE_VELOCITIES_KHR = 1000471002,
+    // XR_META_spatial_entity_sharing
+    SHARE_SPACES_INFO_META = 1000290001,
+    EVENT_DATA_SHARE_SPACES_COMPLETE_META = 1000290002,
+    // XR_META_spatial_entity_group_sharing
+    SHARE_SPACES_RECIPIENT_GROUPS_META = 1000572000,
+}
+
+results! {
+    // XR_ML_localization_map
+    #[doc = "The localization map is incompatible with the current map"]
+    ERROR_LOCALIZATION_MAP_INCOMPATIBLE_ML = -1000139000,
+    #[doc = "The localization map is not available"]
+    ERROR_LOCALIZATION_MAP_UNAVAILABLE_ML = -1000139001,
+    #[doc = "The localization map operation failed"]
+    ERROR_LOCALIZATION_MAP_FAIL_ML = -1000139002,
+    #[doc = "The com.magicleap.permission.SPACE_IMPORT_EXPORT permission was not granted"]
+    ERROR_LOCALIZATION_MAP_IMPORT_EXPORT_PERMISSION_DENIED_ML = -1000139003,
+    #[doc = "The com.magicleap.permission.SPACE_MANAGER permission was not granted"]
+    ERROR_LOCALIZATION_MAP_PERMISSION_DENIED_ML = -1000139004,
+    #[doc = "The map being imported already exists in the system"]
+    ERROR_LOCALIZATION_MAP_ALREADY_EXISTS_ML = -1000139005,
+    #[doc = "The map localization service cannot export cloud based maps"]
+    ERROR_LOCALIZATION_MAP_CANNOT_EXPORT_CLOUD_MAP_ML = -1000139006,
+    // XR_ML_spatial_anchors
+    #[doc = "The com.magicleap.permission.SPATIAL_ANCHOR permission was not granted"]
+    ERROR_SPATIAL_ANCHORS_PERMISSION_DENIED_ML = -1000140000,
+    #[doc = "Operation failed because the system is not localized into a localization map"]
+    ERROR_SPATIAL_ANCHORS_NOT_LOCALIZED_ML = -1000140001,
+    #[doc = "Operation failed because it is performed outside of the localization map"]
+    ERROR_SPATIAL_ANCHORS_OUT_OF_MAP_BOUNDS_ML = -1000140002,
+    #[doc = "Operation failed because the space referenced cannot be located"]
+    ERROR_SPATIAL_ANCHORS_SPACE_NOT_LOCATABLE_ML = -1000140003,
+    // XR_ML_spatial_anchors_storage
+    #[doc = "The anchor references was not found"]
+    ERROR_SPATIAL_ANCHORS_ANCHOR_NOT_FOUND_ML = -1000141000,
+    // XR_EXT_future
+    #[doc = "Returned by completion function to indicate future is not ready"]
+    ERROR_FUTURE_PENDING_EXT = -1000469001,
+    #[doc = "Returned by completion function to indicate future is not valid"]
+    ERROR_FUTURE_INVALID_EXT = -1000469002,
+}
+
+pub(crate) fn result_name(x: Result) -> Option<&'static str> {
+    result_info(x).map(|(name, _)| name)
+}
+
+/// `Display` for values of [`Result`] the generated impl doesn't describe, in the same form
+pub(crate) fn fmt_result(x: Result, fmt: &mut fmt::Formatter) -> fmt::Result {
+    match result_info(x) {
+        Some((name, doc)) => {
+            let mut reason = doc.to_string();
+            reason.get_mut(0..1).unwrap().make_ascii_lowercase();
+            fmt.pad(&format!("{} ({})", reason, name))
+        }
+        None => write!(fmt, "unknown error (code {})", x.into_raw()),
+    }
+}
+
+//
+// XR_MSFT_scene_understanding (disabled in the generator)
+//
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[doc = "See [XrUuidMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrUuidMSFT) - defined by [XR_MSFT_scene_understanding](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding)"]
+pub struct UuidMSFT {
+    pub bytes: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSceneObserverCreateInfoMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneObserverCreateInfoMSFT) - defined by [XR_MSFT_scene_understanding](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding)"]
+pub struct SceneObserverCreateInfoMSFT {
+    pub ty: StructureType,
+    pub next: *const c_void,
+}
+impl SceneObserverCreateInfoMSFT {
+    pub const TYPE: StructureType = StructureType::SCENE_OBSERVER_CREATE_INFO_MSFT;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSceneCreateInfoMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneCreateInfoMSFT) - defined by [XR_MSFT_scene_understanding](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding)"]
+pub struct SceneCreateInfoMSFT {
+    pub ty: StructureType,
+    pub next: *const c_void,
+}
+impl SceneCreateInfoMSFT {
+    pub const TYPE: StructureType = StructureType::SCENE_CREATE_INFO_MSFT;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[doc = "See [XrSceneSphereBoundMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneSphereBoundMSFT) - defined by [XR_MSFT_scene_understanding](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding)"]
+pub struct SceneSphereBoundMSFT {
+    pub center: Vector3f,
+    pub radius: f32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[doc = "See [XrSceneOrientedBoxBoundMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneOrientedBoxBoundMSFT) - defined by [XR_MSFT_scene_understanding](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding)"]
+pub struct SceneOrientedBoxBoundMSFT {
+    pub pose: Posef,
+    pub extents: Vector3f,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[doc = "See [XrSceneFrustumBoundMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneFrustumBoundMSFT) - defined by [XR_MSFT_scene_understanding](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding)"]
+pub struct SceneFrustumBoundMSFT {
+    pub pose: Posef,
+    pub fov: Fovf,
+    pub far_distance: f32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSceneBoundsMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneBoundsMSFT) - defined by [XR_MSFT_scene_understanding](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding)"]
+pub struct SceneBoundsMSFT {
+    pub space: Space,
+    pub time: Time,
+    pub sphere_count: u32,
+    pub spheres: *const SceneSphereBoundMSFT,
+    pub box_count: u32,
+    pub boxes: *const SceneOrientedBoxBoundMSFT,
+    pub frustum_count: u32,
+    pub frustums: *const SceneFrustumBoundMSFT,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrNewSceneComputeInfoMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrNewSceneComputeInfoMSFT) - defined by [XR_MSFT_scene_understanding](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding)"]
+pub struct NewSceneComputeInfoMSFT {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub requested_feature_count: u32,
+    pub requested_features: *const SceneComputeFeatureMSFT,
+    pub consistency: SceneComputeConsistencyMSFT,
+    pub bounds: SceneBoundsMSFT,
+}
+impl NewSceneComputeInfoMSFT {
+    pub const TYPE: StructureType = StructureType::NEW_SCENE_COMPUTE_INFO_MSFT;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSceneComponentMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneComponentMSFT) - defined by [XR_MSFT_scene_understanding](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding)"]
+pub struct SceneComponentMSFT {
+    pub component_type: SceneComponentTypeMSFT,
+    pub id: UuidMSFT,
+    pub parent_id: UuidMSFT,
+    pub update_time: Time,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSceneComponentsMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneComponentsMSFT) - defined by [XR_MSFT_scene_understanding](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding)"]
+pub struct SceneComponentsMSFT {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub component_capacity_input: u32,
+    pub component_count_output: u32,
+    pub components: *mut SceneComponentMSFT,
+}
+impl SceneComponentsMSFT {
+    pub const TYPE: StructureType = StructureType::SCENE_COMPONENTS_MSFT;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSceneComponentsGetInfoMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneComponentsGetInfoMSFT) - defined by [XR_MSFT_scene_understanding](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding)"]
+pub struct SceneComponentsGetInfoMSFT {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub component_type: SceneComponentTypeMSFT,
+}
+impl SceneComponentsGetInfoMSFT {
+    pub const TYPE: StructureType = StructureType::SCENE_COMPONENTS_GET_INFO_MSFT;
+}
+
+//
+// XR_MSFT_scene_understanding_serialization (disabled in the generator)
+//
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSerializedSceneFragmentDataGetInfoMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSerializedSceneFragmentDataGetInfoMSFT) - defined by [XR_MSFT_scene_understanding_serialization](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding_serialization)"]
+pub struct SerializedSceneFragmentDataGetInfoMSFT {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub scene_fragment_id: UuidMSFT,
+}
+impl SerializedSceneFragmentDataGetInfoMSFT {
+    pub const TYPE: StructureType = StructureType::SERIALIZED_SCENE_FRAGMENT_DATA_GET_INFO_MSFT;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrDeserializeSceneFragmentMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrDeserializeSceneFragmentMSFT) - defined by [XR_MSFT_scene_understanding_serialization](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding_serialization)"]
+pub struct DeserializeSceneFragmentMSFT {
+    pub buffer_size: u32,
+    pub buffer: *const u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSceneDeserializeInfoMSFT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSceneDeserializeInfoMSFT) - defined by [XR_MSFT_scene_understanding_serialization](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MSFT_scene_understanding_serialization)"]
+pub struct SceneDeserializeInfoMSFT {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub fragment_count: u32,
+    pub fragments: *const DeserializeSceneFragmentMSFT,
+}
+impl SceneDeserializeInfoMSFT {
+    pub const TYPE: StructureType = StructureType::SCENE_DESERIALIZE_INFO_MSFT;
+}
+
+//
+// XR_ML_localization_map (not yet in the registry the generator uses)
+//
+
+#[allow(non_upper_case_globals)]
+pub const ML_localization_map_SPEC_VERSION: u32 = 1u32;
+pub const ML_LOCALIZATION_MAP_EXTENSION_NAME: &[u8] = b"XR_ML_localization_map\0";
+pub const MAX_LOCALIZATION_MAP_NAME_LENGTH_ML: usize = 64usize;
+
+#[doc = "See [XrExportedLocalizationMapML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrExportedLocalizationMapML)"]
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ExportedLocalizationMapML(u64);
+handle!(ExportedLocalizationMapML);
+
+#[doc = "See [XrLocalizationMapStateML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrLocalizationMapStateML)"]
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct LocalizationMapStateML(i32);
+impl LocalizationMapStateML {
+    pub const NOT_LOCALIZED: LocalizationMapStateML = Self(0i32);
+    pub const LOCALIZED: LocalizationMapStateML = Self(1i32);
+    pub const LOCALIZATION_PENDING: LocalizationMapStateML = Self(2i32);
+    pub const SLEEPING_BEFORE_RETRY: LocalizationMapStateML = Self(3i32);
+    pub const fn from_raw(x: i32) -> Self {
+        Self(x)
+    }
+    pub fn into_raw(self) -> i32 {
+        self.0
+    }
+}
+impl fmt::Debug for LocalizationMapStateML {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Self::NOT_LOCALIZED => Some("NOT_LOCALIZED"),
+            Self::LOCALIZED => Some("LOCALIZED"),
+            Self::LOCALIZATION_PENDING => Some("LOCALIZATION_PENDING"),
+            Self::SLEEPING_BEFORE_RETRY => Some("SLEEPING_BEFORE_RETRY"),
+            _ => None,
+        };
+        fmt_enum(fmt, self.0, name)
+    }
+}
+
+#[doc = "See [XrLocalizationMapTypeML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrLocalizationMapTypeML)"]
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct LocalizationMapTypeML(i32);
+impl LocalizationMapTypeML {
+    pub const ON_DEVICE: LocalizationMapTypeML = Self(0i32);
+    pub const CLOUD: LocalizationMapTypeML = Self(1i32);
+    pub const fn from_raw(x: i32) -> Self {
+        Self(x)
+    }
+    pub fn into_raw(self) -> i32 {
+        self.0
+    }
+}
+impl fmt::Debug for LocalizationMapTypeML {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Self::ON_DEVICE => Some("ON_DEVICE"),
+            Self::CLOUD => Some("CLOUD"),
+            _ => None,
+        };
+        fmt_enum(fmt, self.0, name)
+    }
+}
+
+#[doc = "See [XrLocalizationMapConfidenceML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrLocalizationMapConfidenceML)"]
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct LocalizationMapConfidenceML(i32);
+impl LocalizationMapConfidenceML {
+    pub const POOR: LocalizationMapConfidenceML = Self(0i32);
+    pub const FAIR: LocalizationMapConfidenceML = Self(1i32);
+    pub const GOOD: LocalizationMapConfidenceML = Self(2i32);
+    pub const EXCELLENT: LocalizationMapConfidenceML = Self(3i32);
+    pub const fn from_raw(x: i32) -> Self {
+        Self(x)
+    }
+    pub fn into_raw(self) -> i32 {
+        self.0
+    }
+}
+impl fmt::Debug for LocalizationMapConfidenceML {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Self::POOR => Some("POOR"),
+            Self::FAIR => Some("FAIR"),
+            Self::GOOD => Some("GOOD"),
+            Self::EXCELLENT => Some("EXCELLENT"),
+            _ => None,
+        };
+        fmt_enum(fmt, self.0, name)
+    }
+}
+
+#[doc = "See [XrLocalizationMapErrorFlagsML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrLocalizationMapErrorFlagsML)"]
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct LocalizationMapErrorFlagsML(u64);
+impl LocalizationMapErrorFlagsML {
+    #[doc = "Localization failed for an unknown reason"]
+    pub const UNKNOWN: LocalizationMapErrorFlagsML = Self(1 << 0u64);
+    #[doc = "The device is outside the mapped area"]
+    pub const OUT_OF_MAPPED_AREA: LocalizationMapErrorFlagsML = Self(1 << 1u64);
+    #[doc = "Too few features were detected in the environment"]
+    pub const LOW_FEATURE_COUNT: LocalizationMapErrorFlagsML = Self(1 << 2u64);
+    #[doc = "The device moved too fast to localize"]
+    pub const EXCESSIVE_MOTION: LocalizationMapErrorFlagsML = Self(1 << 3u64);
+    #[doc = "The environment is too dark to localize"]
+    pub const LOW_LIGHT: LocalizationMapErrorFlagsML = Self(1 << 4u64);
+    #[doc = "Head pose tracking failed"]
+    pub const HEADPOSE: LocalizationMapErrorFlagsML = Self(1 << 5u64);
+}
+bitmask!(LocalizationMapErrorFlagsML);
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrLocalizationMapML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrLocalizationMapML) - defined by [XR_ML_localization_map](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map)"]
+pub struct LocalizationMapML {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub name: [c_char; MAX_LOCALIZATION_MAP_NAME_LENGTH_ML],
+    pub map_uuid: UuidEXT,
+    pub map_type: LocalizationMapTypeML,
+}
+impl LocalizationMapML {
+    pub const TYPE: StructureType = StructureType::LOCALIZATION_MAP_ML;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrEventDataLocalizationChangedML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrEventDataLocalizationChangedML) - defined by [XR_ML_localization_map](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map)"]
+pub struct EventDataLocalizationChangedML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub session: Session,
+    pub state: LocalizationMapStateML,
+    pub map: LocalizationMapML,
+    pub confidence: LocalizationMapConfidenceML,
+    pub error_flags: LocalizationMapErrorFlagsML,
+}
+impl EventDataLocalizationChangedML {
+    pub const TYPE: StructureType = StructureType::EVENT_DATA_LOCALIZATION_CHANGED_ML;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrMapLocalizationRequestInfoML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrMapLocalizationRequestInfoML) - defined by [XR_ML_localization_map](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map)"]
+pub struct MapLocalizationRequestInfoML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub map_uuid: UuidEXT,
+}
+impl MapLocalizationRequestInfoML {
+    pub const TYPE: StructureType = StructureType::MAP_LOCALIZATION_REQUEST_INFO_ML;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrLocalizationMapImportInfoML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrLocalizationMapImportInfoML) - defined by [XR_ML_localization_map](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map)"]
+pub struct LocalizationMapImportInfoML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub size: u32,
+    pub data: *const c_char,
+}
+impl LocalizationMapImportInfoML {
+    pub const TYPE: StructureType = StructureType::LOCALIZATION_MAP_IMPORT_INFO_ML;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrLocalizationEnableEventsInfoML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrLocalizationEnableEventsInfoML) - defined by [XR_ML_localization_map](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_localization_map)"]
+pub struct LocalizationEnableEventsInfoML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub enabled: Bool32,
+}
+impl LocalizationEnableEventsInfoML {
+    pub const TYPE: StructureType = StructureType::LOCALIZATION_ENABLE_EVENTS_INFO_ML;
+}
+
+//
+// XR_EXT_future (not yet in the registry the generator uses)
+//
+
+#[allow(non_upper_case_globals)]
+pub const EXT_future_SPEC_VERSION: u32 = 1u32;
+pub const EXT_FUTURE_EXTENSION_NAME: &[u8] = b"XR_EXT_future\0";
+
+#[doc = "See [XrFutureEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFutureEXT)"]
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct FutureEXT(u64);
+handle!(FutureEXT);
+
+#[doc = "See [XrFutureStateEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFutureStateEXT)"]
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct FutureStateEXT(i32);
+impl FutureStateEXT {
+    pub const PENDING: FutureStateEXT = Self(1i32);
+    pub const READY: FutureStateEXT = Self(2i32);
+    pub const fn from_raw(x: i32) -> Self {
+        Self(x)
+    }
+    pub fn into_raw(self) -> i32 {
+        self.0
+    }
+}
+impl fmt::Debug for FutureStateEXT {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Self::PENDING => Some("PENDING"),
+            Self::READY => Some("READY"),
+            _ => None,
+        };
+        fmt_enum(fmt, self.0, name)
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrFutureCancelInfoEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFutureCancelInfoEXT) - defined by [XR_EXT_future](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_EXT_future)"]
+pub struct FutureCancelInfoEXT {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub future: FutureEXT,
+}
+impl FutureCancelInfoEXT {
+    pub const TYPE: StructureType = StructureType::FUTURE_CANCEL_INFO_EXT;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrFuturePollInfoEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFuturePollInfoEXT) - defined by [XR_EXT_future](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_EXT_future)"]
+pub struct FuturePollInfoEXT {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub future: FutureEXT,
+}
+impl FuturePollInfoEXT {
+    pub const TYPE: StructureType = StructureType::FUTURE_POLL_INFO_EXT;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrFutureCompletionBaseHeaderEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFutureCompletionBaseHeaderEXT) - defined by [XR_EXT_future](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_EXT_future)"]
+pub struct FutureCompletionBaseHeaderEXT {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub future_result: Result,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrFutureCompletionEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFutureCompletionEXT) - defined by [XR_EXT_future](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_EXT_future)"]
+pub struct FutureCompletionEXT {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub future_result: Result,
+}
+impl FutureCompletionEXT {
+    pub const TYPE: StructureType = StructureType::FUTURE_COMPLETION_EXT;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrFuturePollResultEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrFuturePollResultEXT) - defined by [XR_EXT_future](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_EXT_future)"]
+pub struct FuturePollResultEXT {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub state: FutureStateEXT,
+}
+impl FuturePollResultEXT {
+    pub const TYPE: StructureType = StructureType::FUTURE_POLL_RESULT_EXT;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+//
+// XR_EXT_user_presence (not yet in the registry the generator uses)
+//
+
+#[allow(non_upper_case_globals)]
+pub const EXT_user_presence_SPEC_VERSION: u32 = 1u32;
+pub const EXT_USER_PRESENCE_EXTENSION_NAME: &[u8] = b"XR_EXT_user_presence\0";
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrEventDataUserPresenceChangedEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrEventDataUserPresenceChangedEXT) - defined by [XR_EXT_user_presence](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_EXT_user_presence)"]
+pub struct EventDataUserPresenceChangedEXT {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub session: Session,
+    pub is_user_present: Bool32,
+}
+impl EventDataUserPresenceChangedEXT {
+    pub const TYPE: StructureType = StructureType::EVENT_DATA_USER_PRESENCE_CHANGED_EXT;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSystemUserPresencePropertiesEXT](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSystemUserPresencePropertiesEXT) - defined by [XR_EXT_user_presence](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_EXT_user_presence)"]
+pub struct SystemUserPresencePropertiesEXT {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub supports_user_presence: Bool32,
+}
+impl SystemUserPresencePropertiesEXT {
+    pub const TYPE: StructureType = StructureType::SYSTEM_USER_PRESENCE_PROPERTIES_EXT;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+//
+// XR_KHR_locate_spaces (not yet in the registry the generator uses)
+//
+
+#[allow(non_upper_case_globals)]
+pub const KHR_locate_spaces_SPEC_VERSION: u32 = 1u32;
+pub const KHR_LOCATE_SPACES_EXTENSION_NAME: &[u8] = b"XR_KHR_locate_spaces\0";
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpacesLocateInfoKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpacesLocateInfoKHR) - defined by [XR_KHR_locate_spaces](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_locate_spaces)"]
+pub struct SpacesLocateInfoKHR {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub base_space: Space,
+    pub time: Time,
+    pub space_count: u32,
+    pub spaces: *const Space,
+}
+impl SpacesLocateInfoKHR {
+    pub const TYPE: StructureType = StructureType::SPACES_LOCATE_INFO_KHR;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[doc = "See [XrSpaceLocationDataKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpaceLocationDataKHR) - defined by [XR_KHR_locate_spaces](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_locate_spaces)"]
+pub struct SpaceLocationDataKHR {
+    pub location_flags: SpaceLocationFlags,
+    pub pose: Posef,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpaceLocationsKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpaceLocationsKHR) - defined by [XR_KHR_locate_spaces](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_locate_spaces)"]
+pub struct SpaceLocationsKHR {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub location_count: u32,
+    pub locations: *mut SpaceLocationDataKHR,
+}
+impl SpaceLocationsKHR {
+    pub const TYPE: StructureType = StructureType::SPACE_LOCATIONS_KHR;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[doc = "See [XrSpaceVelocityDataKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpaceVelocityDataKHR) - defined by [XR_KHR_locate_spaces](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_locate_spaces)"]
+pub struct SpaceVelocityDataKHR {
+    pub velocity_flags: SpaceVelocityFlags,
+    pub linear_velocity: Vector3f,
+    pub angular_velocity: Vector3f,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpaceVelocitiesKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpaceVelocitiesKHR) - defined by [XR_KHR_locate_spaces](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_locate_spaces)"]
+pub struct SpaceVelocitiesKHR {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub velocity_count: u32,
+    pub velocities: *mut SpaceVelocityDataKHR,
+}
+impl SpaceVelocitiesKHR {
+    pub const TYPE: StructureType = StructureType::SPACE_VELOCITIES_KHR;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+//
+// XR_ML_spatial_anchors (not yet in the registry the generator uses)
+//
+
+#[allow(non_upper_case_globals)]
+pub const ML_spatial_anchors_SPEC_VERSION: u32 = 1u32;
+pub const ML_SPATIAL_ANCHORS_EXTENSION_NAME: &[u8] = b"XR_ML_spatial_anchors\0";
+
+#[doc = "See [XrSpatialAnchorConfidenceML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorConfidenceML)"]
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SpatialAnchorConfidenceML(i32);
+impl SpatialAnchorConfidenceML {
+    pub const LOW: SpatialAnchorConfidenceML = Self(0i32);
+    pub const MEDIUM: SpatialAnchorConfidenceML = Self(1i32);
+    pub const HIGH: SpatialAnchorConfidenceML = Self(2i32);
+    pub const fn from_raw(x: i32) -> Self {
+        Self(x)
+    }
+    pub fn into_raw(self) -> i32 {
+        self.0
+    }
+}
+impl fmt::Debug for SpatialAnchorConfidenceML {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Self::LOW => Some("LOW"),
+            Self::MEDIUM => Some("MEDIUM"),
+            Self::HIGH => Some("HIGH"),
+            _ => None,
+        };
+        fmt_enum(fmt, self.0, name)
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorsCreateInfoBaseHeaderML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsCreateInfoBaseHeaderML) - defined by [XR_ML_spatial_anchors](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors)"]
+pub struct SpatialAnchorsCreateInfoBaseHeaderML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorsCreateInfoFromPoseML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsCreateInfoFromPoseML) - defined by [XR_ML_spatial_anchors](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors)"]
+pub struct SpatialAnchorsCreateInfoFromPoseML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub base_space: Space,
+    pub pose_in_base_space: Posef,
+    pub time: Time,
+}
+impl SpatialAnchorsCreateInfoFromPoseML {
+    pub const TYPE: StructureType = StructureType::SPATIAL_ANCHORS_CREATE_INFO_FROM_POSE_ML;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrCreateSpatialAnchorsCompletionML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrCreateSpatialAnchorsCompletionML) - defined by [XR_ML_spatial_anchors](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors)"]
+pub struct CreateSpatialAnchorsCompletionML {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub future_result: Result,
+    pub space_count: u32,
+    pub spaces: *mut Space,
+}
+impl CreateSpatialAnchorsCompletionML {
+    pub const TYPE: StructureType = StructureType::CREATE_SPATIAL_ANCHORS_COMPLETION_ML;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorStateML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorStateML) - defined by [XR_ML_spatial_anchors](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors)"]
+pub struct SpatialAnchorStateML {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub confidence: SpatialAnchorConfidenceML,
+}
+impl SpatialAnchorStateML {
+    pub const TYPE: StructureType = StructureType::SPATIAL_ANCHOR_STATE_ML;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+//
+// XR_ML_spatial_anchors_storage (not yet in the registry the generator uses)
+//
+
+#[allow(non_upper_case_globals)]
+pub const ML_spatial_anchors_storage_SPEC_VERSION: u32 = 1u32;
+pub const ML_SPATIAL_ANCHORS_STORAGE_EXTENSION_NAME: &[u8] = b"XR_ML_spatial_anchors_storage\0";
+
+#[doc = "See [XrSpatialAnchorsStorageML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsStorageML)"]
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SpatialAnchorsStorageML(u64);
+handle!(SpatialAnchorsStorageML);
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorsCreateStorageInfoML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsCreateStorageInfoML) - defined by [XR_ML_spatial_anchors_storage](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage)"]
+pub struct SpatialAnchorsCreateStorageInfoML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+}
+impl SpatialAnchorsCreateStorageInfoML {
+    pub const TYPE: StructureType = StructureType::SPATIAL_ANCHORS_CREATE_STORAGE_INFO_ML;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorsQueryInfoBaseHeaderML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsQueryInfoBaseHeaderML) - defined by [XR_ML_spatial_anchors_storage](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage)"]
+pub struct SpatialAnchorsQueryInfoBaseHeaderML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorsQueryInfoRadiusML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsQueryInfoRadiusML) - defined by [XR_ML_spatial_anchors_storage](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage)"]
+pub struct SpatialAnchorsQueryInfoRadiusML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub base_space: Space,
+    pub center: Vector3f,
+    pub time: Time,
+    pub radius: f32,
+}
+impl SpatialAnchorsQueryInfoRadiusML {
+    pub const TYPE: StructureType = StructureType::SPATIAL_ANCHORS_QUERY_INFO_RADIUS_ML;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorsQueryCompletionML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsQueryCompletionML) - defined by [XR_ML_spatial_anchors_storage](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage)"]
+pub struct SpatialAnchorsQueryCompletionML {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub future_result: Result,
+    pub uuid_capacity_input: u32,
+    pub uuid_count_output: u32,
+    pub uuids: *mut UuidEXT,
+}
+impl SpatialAnchorsQueryCompletionML {
+    pub const TYPE: StructureType = StructureType::SPATIAL_ANCHORS_QUERY_COMPLETION_ML;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorsCreateInfoFromUuidsML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsCreateInfoFromUuidsML) - defined by [XR_ML_spatial_anchors_storage](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage)"]
+pub struct SpatialAnchorsCreateInfoFromUuidsML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub storage: SpatialAnchorsStorageML,
+    pub uuid_count: u32,
+    pub uuids: *const UuidEXT,
+}
+impl SpatialAnchorsCreateInfoFromUuidsML {
+    pub const TYPE: StructureType = StructureType::SPATIAL_ANCHORS_CREATE_INFO_FROM_UUIDS_ML;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorsPublishInfoML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsPublishInfoML) - defined by [XR_ML_spatial_anchors_storage](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage)"]
+pub struct SpatialAnchorsPublishInfoML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub anchor_count: u32,
+    pub anchors: *const Space,
+    pub expiration: u64,
+}
+impl SpatialAnchorsPublishInfoML {
+    pub const TYPE: StructureType = StructureType::SPATIAL_ANCHORS_PUBLISH_INFO_ML;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorsPublishCompletionML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsPublishCompletionML) - defined by [XR_ML_spatial_anchors_storage](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage)"]
+pub struct SpatialAnchorsPublishCompletionML {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub future_result: Result,
+    pub uuid_count: u32,
+    pub uuids: *mut UuidEXT,
+}
+impl SpatialAnchorsPublishCompletionML {
+    pub const TYPE: StructureType = StructureType::SPATIAL_ANCHORS_PUBLISH_COMPLETION_ML;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorsDeleteInfoML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsDeleteInfoML) - defined by [XR_ML_spatial_anchors_storage](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage)"]
+pub struct SpatialAnchorsDeleteInfoML {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub uuid_count: u32,
+    pub uuids: *const UuidEXT,
+}
+impl SpatialAnchorsDeleteInfoML {
+    pub const TYPE: StructureType = StructureType::SPATIAL_ANCHORS_DELETE_INFO_ML;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSpatialAnchorsDeleteCompletionML](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSpatialAnchorsDeleteCompletionML) - defined by [XR_ML_spatial_anchors_storage](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_ML_spatial_anchors_storage)"]
+pub struct SpatialAnchorsDeleteCompletionML {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub future_result: Result,
+}
+impl SpatialAnchorsDeleteCompletionML {
+    pub const TYPE: StructureType = StructureType::SPATIAL_ANCHORS_DELETE_COMPLETION_ML;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+//
+// XR_META_spatial_entity_sharing (not yet in the registry the generator uses)
+//
+
+#[allow(non_upper_case_globals)]
+pub const META_spatial_entity_sharing_SPEC_VERSION: u32 = 1u32;
+pub const META_SPATIAL_ENTITY_SHARING_EXTENSION_NAME: &[u8] = b"XR_META_spatial_entity_sharing\0";
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrShareSpacesRecipientBaseHeaderMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrShareSpacesRecipientBaseHeaderMETA) - defined by [XR_META_spatial_entity_sharing](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_META_spatial_entity_sharing)"]
+pub struct ShareSpacesRecipientBaseHeaderMETA {
+    pub ty: StructureType,
+    pub next: *const c_void,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrShareSpacesInfoMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrShareSpacesInfoMETA) - defined by [XR_META_spatial_entity_sharing](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_META_spatial_entity_sharing)"]
+pub struct ShareSpacesInfoMETA {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub space_count: u32,
+    pub spaces: *mut Space,
+    pub recipient_info: *const ShareSpacesRecipientBaseHeaderMETA,
+}
+impl ShareSpacesInfoMETA {
+    pub const TYPE: StructureType = StructureType::SHARE_SPACES_INFO_META;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrEventDataShareSpacesCompleteMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrEventDataShareSpacesCompleteMETA) - defined by [XR_META_spatial_entity_sharing](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_META_spatial_entity_sharing)"]
+pub struct EventDataShareSpacesCompleteMETA {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub request_id: AsyncRequestIdFB,
+    pub result: Result,
+}
+impl EventDataShareSpacesCompleteMETA {
+    pub const TYPE: StructureType = StructureType::EVENT_DATA_SHARE_SPACES_COMPLETE_META;
+}
+
+//
+// XR_META_spatial_entity_group_sharing (not yet in the registry the generator uses)
+//
+
+#[allow(non_upper_case_globals)]
+pub const META_spatial_entity_group_sharing_SPEC_VERSION: u32 = 1u32;
+pub const META_SPATIAL_ENTITY_GROUP_SHARING_EXTENSION_NAME: &[u8] =
+    b"XR_META_spatial_entity_group_sharing\0";
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrShareSpacesRecipientGroupsMETA](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrShareSpacesRecipientGroupsMETA) - defined by [XR_META_spatial_entity_group_sharing](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_META_spatial_entity_group_sharing)"]
+pub struct ShareSpacesRecipientGroupsMETA {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub group_count: u32,
+    pub groups: *mut UuidEXT,
+}
+impl ShareSpacesRecipientGroupsMETA {
+    pub const TYPE: StructureType = StructureType::SHARE_SPACES_RECIPIENT_GROUPS_META;
+}
+
+//
+// XR_MNDX_egl_enable (the generator emits its structs but not its name)
+//
+
+#[allow(non_upper_case_globals)]
+pub const MNDX_egl_enable_SPEC_VERSION: u32 = 1u32;
+pub const MNDX_EGL_ENABLE_EXTENSION_NAME: &[u8] = b"XR_MNDX_egl_enable\0";
+
+//
+// XR_HTCX_vive_tracker_interaction (the generator emits its structs but not its name)
+//
+
+#[allow(non_upper_case_globals)]
+pub const HTCX_vive_tracker_interaction_SPEC_VERSION: u32 = 3u32;
+pub const HTCX_VIVE_TRACKER_INTERACTION_EXTENSION_NAME: &[u8] =
+    b"XR_HTCX_vive_tracker_interaction\0";
+
+//
+// XR_KHR_metal_enable (not yet in the registry the generator uses)
+//
+
+#[cfg(target_vendor = "apple")]
+#[allow(non_upper_case_globals)]
+pub const KHR_metal_enable_SPEC_VERSION: u32 = 1u32;
+#[cfg(target_vendor = "apple")]
+pub const KHR_METAL_ENABLE_EXTENSION_NAME: &[u8] = b"XR_KHR_metal_enable\0";
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrGraphicsBindingMetalKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrGraphicsBindingMetalKHR) - defined by [XR_KHR_metal_enable](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_metal_enable)"]
+#[cfg(target_vendor = "apple")]
+pub struct GraphicsBindingMetalKHR {
+    pub ty: StructureType,
+    pub next: *const c_void,
+    pub command_queue: *mut c_void,
+}
+#[cfg(target_vendor = "apple")]
+impl GraphicsBindingMetalKHR {
+    pub const TYPE: StructureType = StructureType::GRAPHICS_BINDING_METAL_KHR;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrSwapchainImageMetalKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrSwapchainImageMetalKHR) - defined by [XR_KHR_metal_enable](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_metal_enable)"]
+#[cfg(target_vendor = "apple")]
+pub struct SwapchainImageMetalKHR {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub texture: *mut c_void,
+}
+#[cfg(target_vendor = "apple")]
+impl SwapchainImageMetalKHR {
+    pub const TYPE: StructureType = StructureType::SWAPCHAIN_IMAGE_METAL_KHR;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[doc = "See [XrGraphicsRequirementsMetalKHR](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XrGraphicsRequirementsMetalKHR) - defined by [XR_KHR_metal_enable](https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_metal_enable)"]
+#[cfg(target_vendor = "apple")]
+pub struct GraphicsRequirementsMetalKHR {
+    pub ty: StructureType,
+    pub next: *mut c_void,
+    pub metal_device: *mut c_void,
+}
+#[cfg(target_vendor = "apple")]
+impl GraphicsRequirementsMetalKHR {
+    pub const TYPE: StructureType = StructureType::GRAPHICS_REQUIREMENTS_METAL_KHR;
+    #[doc = r" Construct a partially-initialized value suitable for passing to OpenXR"]
+    #[inline]
+    pub fn out(next: *mut BaseOutStructure) -> std::mem::MaybeUninit<Self> {
+        let mut x = std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            (x.as_mut_ptr() as *mut BaseOutStructure).write(BaseOutStructure {
+                ty: Self::TYPE,
+                next,
+            });
+        }
+        x
+    }
+}
@@ -0,0 +1,205 @@
+//! Hand-written math helpers for plain data types
+
+use crate::{Fovf, Quaternionf, Vector3f};
+
+impl Fovf {
+    /// A right-handed projection matrix mapping depths from `near` to `far` onto 0 to 1, as in
+    /// Direct3D, Vulkan and Metal
+    ///
+    /// The view looks down -Z with +Y up, as OpenXR views do. `far` may be `f32::INFINITY`. The
+    /// matrix is column-major and leaves +Y up in clip space; Vulkan applications typically flip
+    /// their viewport to match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use openxr_sys::Fovf;
+    /// let quarter = std::f32::consts::FRAC_PI_4;
+    /// let fov = Fovf {
+    ///     angle_left: -quarter,
+    ///     angle_right: quarter,
+    ///     angle_up: quarter,
+    ///     angle_down: -quarter,
+    /// };
+    /// let m = fov.projection_rh(0.1, f32::INFINITY);
+    /// assert!((m[0] - 1.0).abs() < 1e-6 && (m[5] - 1.0).abs() < 1e-6);
+    /// assert_eq!((m[10], m[11], m[14]), (-1.0, -1.0, -0.1));
+    /// ```
+    pub fn projection_rh(&self, near: f32, far: f32) -> [f32; 16] {
+        let (a, b) = if far.is_infinite() {
+            (-1.0, -near)
+        } else {
+            (-far / (far - near), -far * near / (far - near))
+        };
+        self.projection(-1.0, a, b)
+    }
+
+    /// A right-handed projection matrix mapping depths from `near` to `far` onto -1 to 1, as in
+    /// OpenGL
+    ///
+    /// Otherwise as [`Fovf::projection_rh`].
+    pub fn projection_rh_gl(&self, near: f32, far: f32) -> [f32; 16] {
+        let (a, b) = if far.is_infinite() {
+            (-1.0, -2.0 * near)
+        } else {
+            (
+                -(far + near) / (far - near),
+                -2.0 * far * near / (far - near),
+            )
+        };
+        self.projection(-1.0, a, b)
+    }
+
+    /// A right-handed projection matrix mapping depths from `near` to `far` onto 1 to 0
+    ///
+    /// Reversing depth spreads floating-point precision much more evenly than
+    /// [`Fovf::projection_rh`], and with an infinite `far` avoids clipping distant geometry
+    /// entirely. Otherwise as [`Fovf::projection_rh`].
+    pub fn projection_rh_reversed_z(&self, near: f32, far: f32) -> [f32; 16] {
+        let (a, b) = if far.is_infinite() {
+            (0.0, near)
+        } else {
+            (near / (far - near), far * near / (far - near))
+        };
+        self.projection(-1.0, a, b)
+    }
+
+    /// A left-handed projection matrix mapping depths from `near` to `far` onto 0 to 1
+    ///
+    /// The view looks down +Z with +Y up, for engines using left-handed coordinates; positions and
+    /// orientations from OpenXR must be converted to match. Otherwise as [`Fovf::projection_rh`].
+    pub fn projection_lh(&self, near: f32, far: f32) -> [f32; 16] {
+        let (a, b) = if far.is_infinite() {
+            (1.0, -near)
+        } else {
+            (far / (far - near), -far * near / (far - near))
+        };
+        self.projection(1.0, a, b)
+    }
+
+    /// A left-handed projection matrix mapping depths from `near` to `far` onto 1 to 0
+    ///
+    /// As [`Fovf::projection_lh`], with depth reversed as in [`Fovf::projection_rh_reversed_z`].
+    pub fn projection_lh_reversed_z(&self, near: f32, far: f32) -> [f32; 16] {
+        let (a, b) = if far.is_infinite() {
+            (0.0, near)
+        } else {
+            (-near / (far - near), far * near / (far - near))
+        };
+        self.projection(1.0, a, b)
+    }
+
+    /// `forward` is the sign of view-space Z in front of the view; `a` and `b` map view-space Z to
+    /// clip-space Z as `a * z + b`
+    fn projection(&self, forward: f32, a: f32, b: f32) -> [f32; 16] {
+        let left = self.angle_left.tan();
+        let right = self.angle_right.tan();
+        let up = self.angle_up.tan();
+        let down = self.angle_down.tan();
+        let width = right - left;
+        let height = up - down;
+        [
+            2.0 / width,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            2.0 / height,
+            0.0,
+            0.0,
+            -forward * (right + left) / width,
+            -forward * (up + down) / height,
+            a,
+            forward,
+            0.0,
+            0.0,
+            b,
+            0.0,
+        ]
+    }
+}
+
+impl Quaternionf {
+    /// A rotation of `angle` radians counterclockwise about `axis`, as seen looking back along it
+    ///
+    /// `axis` need not be normalized, but must not be zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use openxr_sys::{Quaternionf, Vector3f};
+    /// // Tilt a grip pose down by 40 degrees to point like a typical aim pose
+    /// let x_axis = Vector3f { x: 1.0, y: 0.0, z: 0.0 };
+    /// let offset = Quaternionf::from_axis_angle(x_axis, (-40.0f32).to_radians());
+    /// let forward = offset.rotate_vector(Vector3f { x: 0.0, y: 0.0, z: -1.0 });
+    /// assert!(forward.y < 0.0);
+    /// ```
+    pub fn from_axis_angle(axis: Vector3f, angle: f32) -> Self {
+        let len = (axis.x * axis.x + axis.y * axis.y + axis.z * axis.z).sqrt();
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        let s = sin / len;
+        Self {
+            x: axis.x * s,
+            y: axis.y * s,
+            z: axis.z * s,
+            w: cos,
+        }
+    }
+
+    /// Scale to unit length, as rotations must be, e.g. after accumulating rounding error
+    ///
+    /// Returns [`Quaternionf::IDENTITY`] for a zero quaternion.
+    pub fn normalize(self) -> Self {
+        let len = (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt();
+        if len == 0.0 {
+            return Self::IDENTITY;
+        }
+        Self {
+            x: self.x / len,
+            y: self.y / len,
+            z: self.z / len,
+            w: self.w / len,
+        }
+    }
+
+    /// The inverse rotation, for a unit quaternion
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Rotate `v` by this unit quaternion
+    pub fn rotate_vector(self, v: Vector3f) -> Vector3f {
+        // v + 2w(q × v) + 2q × (q × v), with q the vector part
+        let q = Vector3f {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        };
+        let t = cross(q, v);
+        let t = Vector3f {
+            x: 2.0 * t.x,
+            y: 2.0 * t.y,
+            z: 2.0 * t.z,
+        };
+        let u = cross(q, t);
+        Vector3f {
+            x: v.x + self.w * t.x + u.x,
+            y: v.y + self.w * t.y + u.y,
+            z: v.z + self.w * t.z + u.z,
+        }
+    }
+}
+
+fn cross(a: Vector3f, b: Vector3f) -> Vector3f {
+    Vector3f {
+        x: a.y * b.z - a.z * b.y,
+        y: a.z * b.x - a.x * b.z,
+        z: a.x * b.y - a.y * b.x,
+    }
+}
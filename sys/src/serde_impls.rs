@@ -0,0 +1,155 @@
+//! `Serialize` and `Deserialize` for plain data types
+//!
+//! Structs are represented by their fields, enumerations by their raw value so that values unknown
+//! to this version of the bindings survive a round trip, and times and durations in nanoseconds.
+//! Flags are covered by the `bitmask!` macro. Handles, paths and other values only meaningful to
+//! one instance are deliberately left out.
+
+use std::fmt;
+
+use serde::{
+    de::{self, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+
+use crate::*;
+
+// Not `crate::Result`
+use std::result::Result;
+
+/// Structs, as their fields in order
+macro_rules! plain_structs {
+    ($($ty:ident { $($field:ident),* })*) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                const FIELDS: &[&str] = &[$(stringify!($field)),*];
+                let mut s = serializer.serialize_struct(stringify!($ty), FIELDS.len())?;
+                $(s.serialize_field(stringify!($field), &self.$field)?;)*
+                s.end()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct StructVisitor;
+
+                impl<'de> Visitor<'de> for StructVisitor {
+                    type Value = $ty;
+
+                    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                        fmt.write_str(concat!("struct ", stringify!($ty)))
+                    }
+
+                    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<$ty, A::Error> {
+                        let mut len = 0;
+                        $(
+                            let $field = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(len, &self))?;
+                            len += 1;
+                        )*
+                        let _ = len;
+                        Ok($ty { $($field),* })
+                    }
+
+                    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<$ty, A::Error> {
+                        $(let mut $field = None;)*
+                        while let Some(key) = map.next_key::<String>()? {
+                            match &key[..] {
+                                $(stringify!($field) => {
+                                    if $field.is_some() {
+                                        return Err(de::Error::duplicate_field(stringify!($field)));
+                                    }
+                                    $field = Some(map.next_value()?);
+                                })*
+                                _ => {
+                                    map.next_value::<IgnoredAny>()?;
+                                }
+                            }
+                        }
+                        $(
+                            let $field = $field
+                                .ok_or_else(|| de::Error::missing_field(stringify!($field)))?;
+                        )*
+                        Ok($ty { $($field),* })
+                    }
+                }
+
+                const FIELDS: &[&str] = &[$(stringify!($field)),*];
+                deserializer.deserialize_struct(stringify!($ty), FIELDS, StructVisitor)
+            }
+        }
+    )*};
+}
+
+plain_structs! {
+    Vector2f { x, y }
+    Vector3f { x, y, z }
+    Vector4f { x, y, z, w }
+    Quaternionf { x, y, z, w }
+    Posef { orientation, position }
+    Fovf { angle_left, angle_right, angle_up, angle_down }
+    Offset2Df { x, y }
+    Offset2Di { x, y }
+    Extent2Df { width, height }
+    Extent2Di { width, height }
+    Rect2Df { offset, extent }
+    Rect2Di { offset, extent }
+    Color4f { r, g, b, a }
+    UuidEXT { data }
+    HandJointLocationEXT { location_flags, pose, radius }
+    HandJointVelocityEXT { velocity_flags, linear_velocity, angular_velocity }
+    BodyJointLocationFB { location_flags, pose }
+}
+
+/// Types represented by a single value, converted with the given functions
+macro_rules! newtypes {
+    ($($ty:ident($raw:ty, $into:expr, $from:expr))*) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let into: fn($ty) -> $raw = $into;
+                into(*self).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let from: fn($raw) -> $ty = $from;
+                <$raw>::deserialize(deserializer).map(from)
+            }
+        }
+    )*};
+}
+
+newtypes! {
+    Bool32(bool, bool::from, Bool32::from)
+    Time(i64, Time::as_nanos, Time::from_nanos)
+    Duration(i64, Duration::as_nanos, Duration::from_nanos)
+    Version(u64, Version::into_raw, Version::from_raw)
+}
+
+/// Enumerations, as their raw value
+macro_rules! enums {
+    ($($ty:ident),*) => {
+        newtypes! {
+            $($ty(i32, $ty::into_raw, $ty::from_raw))*
+        }
+    };
+}
+
+enums!(
+    FormFactor,
+    ViewConfigurationType,
+    EnvironmentBlendMode,
+    ReferenceSpaceType,
+    SessionState,
+    HandEXT,
+    HandJointEXT,
+    HandJointSetEXT,
+    BodyJointFB,
+    BodyJointSetFB,
+    FullBodyJointMETA,
+    EyePositionFB,
+    ColorSpaceFB
+);
@@ -106,6 +106,26 @@ macro_rules! bitmask {
                 Self(!self.0)
             }
         }
+
+        /// As the raw value, so that flags unknown to this version of the bindings are preserved
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_u64(self.0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                <u64 as serde::Deserialize>::deserialize(deserializer).map(Self)
+            }
+        }
     };
 }
 
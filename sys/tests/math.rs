@@ -0,0 +1,199 @@
+//! Hand-written math helpers on plain data types.
+
+use openxr_sys as sys;
+
+const EPSILON: f32 = 1e-5;
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < EPSILON,
+        "{} is not close to {}",
+        actual,
+        expected
+    );
+}
+
+/// An asymmetric field of view, as real headsets report
+fn fov() -> sys::Fovf {
+    sys::Fovf {
+        angle_left: (-50.0f32).to_radians(),
+        angle_right: 40.0f32.to_radians(),
+        angle_up: 45.0f32.to_radians(),
+        angle_down: (-55.0f32).to_radians(),
+    }
+}
+
+/// Normalized device coordinates of the view-space point `p`, transformed by the column-major `m`
+fn project(m: &[f32; 16], p: [f32; 3]) -> [f32; 3] {
+    let v = [p[0], p[1], p[2], 1.0];
+    let mut clip = [0.0; 4];
+    for (row, out) in clip.iter_mut().enumerate() {
+        *out = (0..4).map(|col| m[col * 4 + row] * v[col]).sum();
+    }
+    [clip[0] / clip[3], clip[1] / clip[3], clip[2] / clip[3]]
+}
+
+/// The view-space point at distance `d` in front of the view along the bottom-left edge of `fov`,
+/// where `forward` is the sign of view-space Z in front of the view
+fn bottom_left(fov: &sys::Fovf, d: f32, forward: f32) -> [f32; 3] {
+    [
+        fov.angle_left.tan() * d,
+        fov.angle_down.tan() * d,
+        forward * d,
+    ]
+}
+
+#[test]
+fn projection_rh() {
+    let fov = fov();
+    let m = fov.projection_rh(0.1, 100.0);
+    let near = project(&m, bottom_left(&fov, 0.1, -1.0));
+    assert_close(near[0], -1.0);
+    assert_close(near[1], -1.0);
+    assert_close(near[2], 0.0);
+    assert_close(project(&m, bottom_left(&fov, 100.0, -1.0))[2], 1.0);
+
+    let m = fov.projection_rh(0.1, f32::INFINITY);
+    assert_close(project(&m, bottom_left(&fov, 0.1, -1.0))[2], 0.0);
+    assert_close(project(&m, bottom_left(&fov, 1e6, -1.0))[2], 1.0);
+}
+
+#[test]
+fn projection_rh_gl() {
+    let fov = fov();
+    let m = fov.projection_rh_gl(0.1, 100.0);
+    let near = project(&m, bottom_left(&fov, 0.1, -1.0));
+    assert_close(near[0], -1.0);
+    assert_close(near[1], -1.0);
+    assert_close(near[2], -1.0);
+    assert_close(project(&m, bottom_left(&fov, 100.0, -1.0))[2], 1.0);
+
+    let m = fov.projection_rh_gl(0.1, f32::INFINITY);
+    assert_close(project(&m, bottom_left(&fov, 0.1, -1.0))[2], -1.0);
+    assert_close(project(&m, bottom_left(&fov, 1e6, -1.0))[2], 1.0);
+}
+
+#[test]
+fn projection_rh_reversed_z() {
+    let fov = fov();
+    let m = fov.projection_rh_reversed_z(0.1, 100.0);
+    let near = project(&m, bottom_left(&fov, 0.1, -1.0));
+    assert_close(near[0], -1.0);
+    assert_close(near[1], -1.0);
+    assert_close(near[2], 1.0);
+    assert_close(project(&m, bottom_left(&fov, 100.0, -1.0))[2], 0.0);
+
+    let m = fov.projection_rh_reversed_z(0.1, f32::INFINITY);
+    assert_close(project(&m, bottom_left(&fov, 0.1, -1.0))[2], 1.0);
+    assert_close(project(&m, bottom_left(&fov, 1e6, -1.0))[2], 0.0);
+}
+
+#[test]
+fn projection_lh() {
+    let fov = fov();
+    let m = fov.projection_lh(0.1, 100.0);
+    let near = project(&m, bottom_left(&fov, 0.1, 1.0));
+    assert_close(near[0], -1.0);
+    assert_close(near[1], -1.0);
+    assert_close(near[2], 0.0);
+    assert_close(project(&m, bottom_left(&fov, 100.0, 1.0))[2], 1.0);
+
+    let m = fov.projection_lh(0.1, f32::INFINITY);
+    assert_close(project(&m, bottom_left(&fov, 1e6, 1.0))[2], 1.0);
+}
+
+#[test]
+fn projection_lh_reversed_z() {
+    let fov = fov();
+    let m = fov.projection_lh_reversed_z(0.1, 100.0);
+    let near = project(&m, bottom_left(&fov, 0.1, 1.0));
+    assert_close(near[0], -1.0);
+    assert_close(near[1], -1.0);
+    assert_close(near[2], 1.0);
+    assert_close(project(&m, bottom_left(&fov, 100.0, 1.0))[2], 0.0);
+
+    let m = fov.projection_lh_reversed_z(0.1, f32::INFINITY);
+    assert_close(project(&m, bottom_left(&fov, 1e6, 1.0))[2], 0.0);
+}
+
+fn assert_vector_close(actual: sys::Vector3f, expected: sys::Vector3f) {
+    assert_close(actual.x, expected.x);
+    assert_close(actual.y, expected.y);
+    assert_close(actual.z, expected.z);
+}
+
+const X: sys::Vector3f = sys::Vector3f {
+    x: 1.0,
+    y: 0.0,
+    z: 0.0,
+};
+const Y: sys::Vector3f = sys::Vector3f {
+    x: 0.0,
+    y: 1.0,
+    z: 0.0,
+};
+const Z: sys::Vector3f = sys::Vector3f {
+    x: 0.0,
+    y: 0.0,
+    z: 1.0,
+};
+
+#[test]
+fn quaternion_from_axis_angle() {
+    // Counterclockwise about +Z, looking back along it, takes +X to +Y
+    let q = sys::Quaternionf::from_axis_angle(Z, 90.0f32.to_radians());
+    assert_vector_close(q.rotate_vector(X), Y);
+    // The axis needn't be normalized
+    let scaled = sys::Vector3f {
+        x: 0.0,
+        y: 0.0,
+        z: 3.0,
+    };
+    let q = sys::Quaternionf::from_axis_angle(scaled, 90.0f32.to_radians());
+    assert_close(q.x * q.x + q.y * q.y + q.z * q.z + q.w * q.w, 1.0);
+    assert_vector_close(q.rotate_vector(X), Y);
+    // Points on the axis stay put
+    assert_vector_close(q.rotate_vector(Z), Z);
+}
+
+#[test]
+fn quaternion_conjugate_inverts() {
+    let q = sys::Quaternionf::from_axis_angle(
+        sys::Vector3f {
+            x: 1.0,
+            y: 2.0,
+            z: -0.5,
+        },
+        1.3,
+    );
+    let v = sys::Vector3f {
+        x: 0.3,
+        y: -2.0,
+        z: 4.0,
+    };
+    assert_vector_close(q.conjugate().rotate_vector(q.rotate_vector(v)), v);
+    assert_vector_close(sys::Quaternionf::IDENTITY.rotate_vector(v), v);
+}
+
+#[test]
+fn quaternion_normalize() {
+    let q = sys::Quaternionf {
+        x: 0.0,
+        y: 0.0,
+        z: 2.0,
+        w: 2.0,
+    }
+    .normalize();
+    let expected = std::f32::consts::FRAC_1_SQRT_2;
+    assert_close(q.z, expected);
+    assert_close(q.w, expected);
+    assert_vector_close(q.rotate_vector(X), Y);
+
+    let zero = sys::Quaternionf {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 0.0,
+    };
+    assert_eq!(zero.normalize(), sys::Quaternionf::IDENTITY);
+}
@@ -0,0 +1,128 @@
+//! Representation of plain data types under the `serde` feature.
+#![cfg(feature = "serde")]
+
+use openxr_sys as sys;
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+#[test]
+fn structs_as_fields() {
+    let pose = sys::Posef {
+        orientation: sys::Quaternionf {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+            w: 0.0,
+        },
+        position: sys::Vector3f {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        },
+    };
+    assert_tokens(
+        &pose,
+        &[
+            Token::Struct {
+                name: "Posef",
+                len: 2,
+            },
+            Token::Str("orientation"),
+            Token::Struct {
+                name: "Quaternionf",
+                len: 4,
+            },
+            Token::Str("x"),
+            Token::F32(0.0),
+            Token::Str("y"),
+            Token::F32(1.0),
+            Token::Str("z"),
+            Token::F32(0.0),
+            Token::Str("w"),
+            Token::F32(0.0),
+            Token::StructEnd,
+            Token::Str("position"),
+            Token::Struct {
+                name: "Vector3f",
+                len: 3,
+            },
+            Token::Str("x"),
+            Token::F32(1.0),
+            Token::Str("y"),
+            Token::F32(2.0),
+            Token::Str("z"),
+            Token::F32(3.0),
+            Token::StructEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn structs_from_sequences() {
+    let extent = sys::Extent2Di {
+        width: 640,
+        height: 480,
+    };
+    assert_de_tokens(
+        &extent,
+        &[
+            Token::Seq { len: Some(2) },
+            Token::I32(640),
+            Token::I32(480),
+            Token::SeqEnd,
+        ],
+    );
+    assert_de_tokens_error::<sys::Extent2Di>(
+        &[Token::Seq { len: Some(1) }, Token::I32(640), Token::SeqEnd],
+        "invalid length 1, expected struct Extent2Di",
+    );
+}
+
+#[test]
+fn unknown_fields_are_ignored() {
+    let offset = sys::Offset2Di { x: 1, y: 2 };
+    assert_de_tokens(
+        &offset,
+        &[
+            Token::Struct {
+                name: "Offset2Di",
+                len: 3,
+            },
+            Token::Str("x"),
+            Token::I32(1),
+            Token::Str("comment"),
+            Token::Str("ignored"),
+            Token::Str("y"),
+            Token::I32(2),
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn enums_as_raw_values() {
+    assert_tokens(&sys::FormFactor::HEAD_MOUNTED_DISPLAY, &[Token::I32(1)]);
+    // Values unknown to these bindings survive a round trip
+    assert_tokens(
+        &sys::ReferenceSpaceType::from_raw(0x7fff),
+        &[Token::I32(0x7fff)],
+    );
+}
+
+#[test]
+fn flags_as_raw_values() {
+    let flags =
+        sys::SpaceLocationFlags::ORIENTATION_VALID | sys::SpaceLocationFlags::from_raw(1 << 40);
+    assert_tokens(&flags, &[Token::U64(1 | 1 << 40)]);
+}
+
+#[test]
+fn scalars() {
+    assert_tokens(&sys::Time::from_nanos(-5), &[Token::I64(-5)]);
+    assert_tokens(&sys::Duration::from_nanos(7), &[Token::I64(7)]);
+    assert_tokens(&sys::Bool32::from(true), &[Token::Bool(true)]);
+    assert_tokens(
+        &sys::Version::new(1, 1, 0),
+        &[Token::U64(1 << 48 | 1 << 32)],
+    );
+}